@@ -129,6 +129,7 @@ fn create_l2_config() -> L2Config {
         default_ttl: None,
         max_key_length: 256,
         max_value_size: 1024 * 1024 * 10,
+        ..Default::default()
     }
 }
 
@@ -311,6 +312,7 @@ fn bench_two_level_cache(c: &mut Criterion) {
         warmup: None,
         max_key_length: Some(256),
         max_value_size: Some(1024 * 1024 * 10),
+        ..Default::default()
     };
 
     let cache = rt.block_on(async {
@@ -382,6 +384,7 @@ fn bench_cache_hit_ratio(c: &mut Criterion) {
         warmup: None,
         max_key_length: Some(256),
         max_value_size: Some(1024 * 1024 * 10),
+        ..Default::default()
     };
 
     let cache = rt.block_on(async {
@@ -468,6 +471,7 @@ fn bench_l1_size_impact(c: &mut Criterion) {
         warmup: None,
         max_key_length: Some(256),
         max_value_size: Some(1024 * 1024 * 10),
+        ..Default::default()
     };
 
     let l1_empty = Arc::new(L1Backend::new(10000));
@@ -579,6 +583,7 @@ fn benchmark_latency(c: &mut Criterion) {
         default_ttl: None,
         max_key_length: 256,
         max_value_size: 1024 * 1024 * 10,
+        ..Default::default()
     };
     let l2 = rt.block_on(async {
         Arc::new(
@@ -598,6 +603,7 @@ fn benchmark_latency(c: &mut Criterion) {
         warmup: None,
         max_key_length: Some(256),
         max_value_size: Some(1024 * 1024 * 10),
+        ..Default::default()
     };
 
     let client = rt.block_on(async {
@@ -29,6 +29,7 @@ fn create_l2_config() -> L2Config {
         default_ttl: None,
         max_key_length: 256,
         max_value_size: 1024 * 1024 * 10,
+        ..Default::default()
     }
 }
 
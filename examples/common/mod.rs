@@ -37,7 +37,9 @@ pub fn create_default_config(service_name: &str, max_capacity: usize) -> Config
                 warmup: None,
                 max_key_length: Some(1024),
                 max_value_size: Some(1024 * 1024),
+                ..Default::default()
             }),
+            ..Default::default()
         },
     );
 
@@ -124,7 +124,7 @@ impl TestMetrics {
             } else {
                 0.0
             },
-            avg_latency_ns: if total > 0 { total_latency / total } else { 0 },
+            avg_latency_ns: total_latency.checked_div(total).unwrap_or(0),
             max_latency_ns: max_latency,
             min_latency_ns: min_latency,
             throughput: if total > 0 { total as f64 / 60.0 } else { 0.0 }, // ops per second
@@ -331,6 +331,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         warmup: None,
         max_key_length: Some(1024),
         max_value_size: Some(1024 * 1024),
+        ..Default::default()
     };
 
     let client = Arc::new(
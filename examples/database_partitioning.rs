@@ -67,8 +67,11 @@ async fn main() -> Result<()> {
     match oxcache::backend::l2::L2Backend::new(&l2_config).await {
         Ok(l2_backend) => {
             println!("   开始重放WAL日志...");
-            let replayed_count = wal_manager.replay_all(&l2_backend).await?;
-            println!("   重放完成，共重放 {} 个条目", replayed_count);
+            let report = wal_manager.replay_all(&l2_backend).await?;
+            println!(
+                "   重放完成，共重放 {} 个条目（失败 {}，跳过 {}）",
+                report.replayed, report.failed, report.skipped
+            );
         }
         Err(e) => {
             println!("   L2缓存创建失败: {}，跳过重放步骤", e);
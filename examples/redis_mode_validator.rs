@@ -91,6 +91,8 @@ impl RedisModeValidator {
                     env::var("REDIS_SENTINEL_URL_3")
                         .unwrap_or_else(|_| "redis://127.0.0.1:26381".to_string()),
                 ],
+                sentinel_password: None,
+                master_discovery_timeout_ms: None,
             }),
             ..Default::default()
         };
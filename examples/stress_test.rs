@@ -64,6 +64,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         warmup: None,
         max_key_length: Some(1024),
         max_value_size: Some(1024 * 1024),
+        ..Default::default()
     };
 
     let client = Arc::new(
@@ -7,9 +7,63 @@
 use proc_macro::TokenStream;
 use quote::quote;
 use syn::{
-    parse::Parser, parse_macro_input, punctuated::Punctuated, Expr, ItemFn, Lit, Meta, Token,
+    parse::Parser, parse_macro_input, punctuated::Punctuated, Data, DeriveInput, Expr, ItemFn, Lit,
+    LitStr, Meta, Token,
 };
 
+/// `write_retries` 允许的最大重试次数，避免缓存写入拖慢被注解函数的返回
+const MAX_WRITE_RETRIES: u32 = 5;
+
+/// 写入重试的基础退避时间（毫秒），第n次重试前等待 `10 * 2^n` 毫秒并附加抖动
+const WRITE_RETRY_BASE_BACKOFF_MS: u64 = 10;
+
+/// 生成按 `cache_type` 写入缓存的语句
+///
+/// `write_retries` 大于0时，瞬时故障导致的写入失败按带抖动的指数退避重试；
+/// 重试耗尽后放弃写入，不影响函数返回值。
+fn cache_write(
+    key: proc_macro2::TokenStream,
+    cache_type: &proc_macro2::TokenStream,
+    ttl: &proc_macro2::TokenStream,
+    write_retries: u32,
+) -> proc_macro2::TokenStream {
+    if write_retries == 0 {
+        return quote! {
+            let _ = match #cache_type {
+                "l1-only" => client.set_l1_bytes(&#key, bytes, #ttl).await,
+                "l2-only" => client.set_l2_bytes(&#key, bytes, #ttl).await,
+                _ => client.set_bytes(&#key, bytes, #ttl).await,
+            };
+        };
+    }
+
+    quote! {
+        let retry_policy = oxcache::config::RetryConfig {
+            max_retries: #write_retries,
+            base_backoff_ms: #WRITE_RETRY_BASE_BACKOFF_MS,
+            jitter: true,
+        };
+        let mut attempt = 0u32;
+        loop {
+            let write = match #cache_type {
+                "l1-only" => client.set_l1_bytes(&#key, bytes.clone(), #ttl).await,
+                "l2-only" => client.set_l2_bytes(&#key, bytes.clone(), #ttl).await,
+                _ => client.set_bytes(&#key, bytes.clone(), #ttl).await,
+            };
+            match write {
+                Err(e) if e.is_transient() && attempt < retry_policy.max_retries => {
+                    oxcache::tokio::time::sleep(
+                        oxcache::backend::retry::backoff_delay(&retry_policy, attempt),
+                    )
+                    .await;
+                    attempt += 1;
+                }
+                _ => break,
+            }
+        }
+    }
+}
+
 #[proc_macro_attribute]
 pub fn cached(args: TokenStream, item: TokenStream) -> TokenStream {
     let parser = Punctuated::<Meta, Token![,]>::parse_terminated;
@@ -19,7 +73,11 @@ pub fn cached(args: TokenStream, item: TokenStream) -> TokenStream {
     let mut service_name = "default".to_string();
     let mut ttl = quote! { None };
     let mut key_pattern = None;
+    let mut key_builder = None;
+    let mut cache_errors = None;
     let mut cache_type = quote! { "two-level" };
+    let mut write_retries = 0;
+    let mut trace_key = false;
 
     for arg in args {
         if let Meta::NameValue(nv) = arg {
@@ -30,18 +88,21 @@ pub fn cached(args: TokenStream, item: TokenStream) -> TokenStream {
                     }
                 }
             } else if nv.path.is_ident("ttl") {
-                if let Expr::Lit(expr_lit) = nv.value {
-                    if let Lit::Int(lit) = expr_lit.lit {
-                        let val = lit.base10_parse::<u64>().unwrap();
-                        ttl = quote! { Some(#val) };
-                    }
-                }
+                // 接受任意表达式（字面量、常量路径、函数调用），并在编译期约束为 u64
+                let expr = nv.value;
+                ttl = quote! { Some::<u64>(#expr) };
             } else if nv.path.is_ident("key") {
                 if let Expr::Lit(expr_lit) = nv.value {
                     if let Lit::Str(lit) = expr_lit.lit {
                         key_pattern = Some(lit.value());
                     }
                 }
+            } else if nv.path.is_ident("key_builder") {
+                // 键构造函数的路径，接收各参数的引用并返回 String
+                key_builder = Some(nv.value);
+            } else if nv.path.is_ident("cache_errors") {
+                // 针对绑定的 `err`（错误值的引用）求值的谓词，为真时对该错误做负缓存
+                cache_errors = Some(nv.value);
             } else if nv.path.is_ident("cache_type") {
                 if let Expr::Lit(expr_lit) = nv.value {
                     if let Lit::Str(lit) = expr_lit.lit {
@@ -49,18 +110,89 @@ pub fn cached(args: TokenStream, item: TokenStream) -> TokenStream {
                         cache_type = quote! { #val };
                     }
                 }
+            } else if nv.path.is_ident("write_retries") {
+                // 缓存写入失败时的重试次数，默认不重试
+                let parsed = match &nv.value {
+                    Expr::Lit(expr_lit) => match &expr_lit.lit {
+                        Lit::Int(lit) => lit.base10_parse::<u32>().ok(),
+                        _ => None,
+                    },
+                    _ => None,
+                };
+                match parsed {
+                    Some(retries) if retries <= MAX_WRITE_RETRIES => write_retries = retries,
+                    _ => {
+                        let message = format!(
+                            "`write_retries` must be an integer literal between 0 and {}",
+                            MAX_WRITE_RETRIES
+                        );
+                        return syn::Error::new_spanned(&nv.value, message)
+                            .to_compile_error()
+                            .into();
+                    }
+                }
+            } else if nv.path.is_ident("trace_key") {
+                // 输出计算出的缓存键并生成同名的键预览函数，便于排查键不符合预期的问题
+                match &nv.value {
+                    Expr::Lit(syn::ExprLit {
+                        lit: Lit::Bool(lit),
+                        ..
+                    }) => trace_key = lit.value,
+                    _ => {
+                        return syn::Error::new_spanned(
+                            &nv.value,
+                            "`trace_key` must be a boolean literal",
+                        )
+                        .to_compile_error()
+                        .into();
+                    }
+                }
             }
         }
     }
 
+    if let (Some(_), Some(builder)) = (&key_pattern, &key_builder) {
+        return syn::Error::new_spanned(builder, "`key` and `key_builder` cannot be used together")
+            .to_compile_error()
+            .into();
+    }
+
     let fn_name = &input.sig.ident;
     let fn_args = &input.sig.inputs;
     let fn_output = &input.sig.output;
+    // 显式标注结果类型，使 `cache_errors` 谓词中对 `err` 的方法调用可以推断类型
+    let result_ty = match fn_output {
+        syn::ReturnType::Type(_, ty) if !matches!(**ty, syn::Type::ImplTrait(_)) => {
+            quote! { : #ty }
+        }
+        _ => quote! {},
+    };
     let fn_block = &input.block;
     let vis = &input.vis;
+    let attrs = &input.attrs;
+    // 保留泛型参数（含生命周期）与 where 子句，返回值的序列化约束由调用处的泛型约束提供
+    let generics = &input.sig.generics;
+    let where_clause = &input.sig.generics.where_clause;
+
+    let arg_names: Vec<_> = fn_args
+        .iter()
+        .filter_map(|arg| {
+            if let syn::FnArg::Typed(pat_type) = arg {
+                if let syn::Pat::Ident(pat_ident) = &*pat_type.pat {
+                    return Some(&pat_ident.ident);
+                }
+            }
+            None
+        })
+        .collect();
 
     // Generate key logic
-    let key_gen = if let Some(pattern) = key_pattern {
+    let key_gen = if let Some(builder) = key_builder {
+        // 以参数引用组成的元组调用自定义键构造器
+        quote! {
+            oxcache::KeyBuilder::build_key(&#builder, (#(&#arg_names,)*))
+        }
+    } else if let Some(pattern) = key_pattern {
         // We allow the user to use the format string syntax directly, e.g. "user_{id}" where id is an arg.
         // This works because we are in the scope of the function arguments.
         quote! {
@@ -68,33 +200,86 @@ pub fn cached(args: TokenStream, item: TokenStream) -> TokenStream {
         }
     } else {
         // Default key generation: service:fn_name:arg1:arg2...
-        // We need to capture argument names.
-        let arg_names: Vec<_> = fn_args
-            .iter()
-            .filter_map(|arg| {
-                if let syn::FnArg::Typed(pat_type) = arg {
-                    if let syn::Pat::Ident(pat_ident) = &*pat_type.pat {
-                        return Some(&pat_ident.ident);
-                    }
-                }
-                None
-            })
-            .collect();
-
         if arg_names.is_empty() {
             quote! { format!("{}:{}", #service_name, stringify!(#fn_name)) }
         } else {
+            // 以引用组成元组，避免在执行函数体之前移走非 Copy 参数；键的格式与按值时相同
             quote! {
-                format!("{}:{}:{:?}", #service_name, stringify!(#fn_name), (#(#arg_names),*))
+                format!("{}:{}:{:?}", #service_name, stringify!(#fn_name), (#(&#arg_names),*))
             }
         }
     };
 
+    // `trace_key = true` 时生成 `<name>_cache_key` 函数，参数与被注解函数相同，返回将使用的缓存键
+    let (key_trace, key_preview) = if trace_key {
+        let preview_name = syn::Ident::new(&format!("{}_cache_key", fn_name), fn_name.span());
+        // 去掉 `mut` 等绑定修饰，预览函数只读取参数
+        let preview_args = fn_args.iter().map(|arg| match arg {
+            syn::FnArg::Typed(pat_type) => match &*pat_type.pat {
+                syn::Pat::Ident(pat_ident) => {
+                    let ident = &pat_ident.ident;
+                    let ty = &pat_type.ty;
+                    quote! { #ident: #ty }
+                }
+                _ => quote! { #arg },
+            },
+            syn::FnArg::Receiver(_) => quote! { #arg },
+        });
+        let doc = format!("返回 `{}` 使用的缓存键", fn_name);
+        (
+            quote! {
+                oxcache::tracing::debug!(cache_key = %cache_key, "cached {} computed cache key", stringify!(#fn_name));
+            },
+            quote! {
+                #[doc = #doc]
+                #[allow(dead_code, unused_variables)]
+                #vis fn #preview_name #generics (#(#preview_args),*) -> String #where_clause {
+                    #key_gen
+                }
+            },
+        )
+    } else {
+        (quote! {}, quote! {})
+    };
+
+    let value_write = cache_write(quote! { cache_key }, &cache_type, &ttl, write_retries);
+
+    // 负缓存：错误值以独立的键存储，不影响正常值的缓存格式
+    let (error_lookup, error_store) = match cache_errors {
+        Some(predicate) => {
+            let error_write = cache_write(quote! { error_key }, &cache_type, &ttl, write_retries);
+            (
+                quote! {
+                    let error_key = format!("{}:err", cache_key);
+                    if let Ok(Some(bytes)) = client.get_bytes(&error_key).await {
+                         use oxcache::serialization::Serializer;
+                         if let Ok(err) = client.serializer().deserialize(&bytes) {
+                             return Err(err);
+                         }
+                    }
+                },
+                quote! {
+                    if let Err(ref err) = result {
+                         if #predicate {
+                             use oxcache::serialization::Serializer;
+                             if let Ok(bytes) = client.serializer().serialize(err) {
+                                #error_write
+                             }
+                         }
+                    }
+                },
+            )
+        }
+        None => (quote! {}, quote! {}),
+    };
+
     let output = quote! {
-        #vis async fn #fn_name(#fn_args) #fn_output {
+        #(#attrs)*
+        #vis async fn #fn_name #generics (#fn_args) #fn_output #where_clause {
             use oxcache::{get_client, CacheOps};
 
             let cache_key = #key_gen;
+            #key_trace
 
             // Try to get client, if fails, run original function
             let client = match get_client(#service_name) {
@@ -110,25 +295,100 @@ pub fn cached(args: TokenStream, item: TokenStream) -> TokenStream {
                      return Ok(val);
                  }
             }
+            #error_lookup
 
             // Run original function
-            let result = async { #fn_block }.await;
+            let result #result_ty = async { #fn_block }.await;
 
-            // Cache result if Ok
+            // Cache result if Ok; errors are only cached when `cache_errors` matches
             if let Ok(ref val) = result {
                  use oxcache::serialization::Serializer;
                  if let Ok(bytes) = client.serializer().serialize(val) {
-                    let _ = match #cache_type {
-                        "l1-only" => client.set_l1_bytes(&cache_key, bytes, #ttl).await,
-                        "l2-only" => client.set_l2_bytes(&cache_key, bytes, #ttl).await,
-                        _ => client.set_bytes(&cache_key, bytes, #ttl).await,
-                    };
+                    #value_write
                  }
             }
+            #error_store
 
             result
         }
+
+        #key_preview
     };
 
     output.into()
 }
+
+/// 为结构体生成 `oxcache::CacheKey` 实现
+///
+/// 键以类型名开头，按声明顺序编码各字段（见 `oxcache::key_builder::CacheKeyEncoder`），
+/// 字段值需要实现 `Display`。支持的字段属性：
+///
+/// * `#[cache_key(skip)]` - 不参与键的生成
+/// * `#[cache_key(rename = "...")]` - 在键中使用指定的名称
+#[proc_macro_derive(CacheKey, attributes(cache_key))]
+pub fn derive_cache_key(item: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(item as DeriveInput);
+    match expand_cache_key(&input) {
+        Ok(output) => output.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+fn expand_cache_key(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "CacheKey can only be derived for structs",
+        ));
+    };
+
+    let mut fields = Vec::new();
+    for (index, field) in data.fields.iter().enumerate() {
+        let mut skip = false;
+        let mut rename = None;
+        for attr in field
+            .attrs
+            .iter()
+            .filter(|a| a.path().is_ident("cache_key"))
+        {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("skip") {
+                    skip = true;
+                    Ok(())
+                } else if meta.path.is_ident("rename") {
+                    rename = Some(meta.value()?.parse::<LitStr>()?.value());
+                    Ok(())
+                } else {
+                    Err(meta.error("expected `skip` or `rename = \"...\"`"))
+                }
+            })?;
+        }
+        if skip {
+            continue;
+        }
+
+        // 元组结构体以字段序号作为名称
+        let (member, name) = match &field.ident {
+            Some(ident) => (quote! { #ident }, ident.to_string()),
+            None => {
+                let member = syn::Index::from(index);
+                (quote! { #member }, index.to_string())
+            }
+        };
+        let name = rename.unwrap_or(name);
+        fields.push(quote! { .field(#name, &self.#member) });
+    }
+
+    let ident = &input.ident;
+    let type_name = ident.to_string();
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics oxcache::key_builder::CacheKey for #ident #ty_generics #where_clause {
+            fn cache_key(&self) -> String {
+                oxcache::key_builder::CacheKeyEncoder::new(#type_name)
+                    #(#fields)*
+                    .finish()
+            }
+        }
+    })
+}
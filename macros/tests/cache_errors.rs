@@ -0,0 +1,81 @@
+//! Copyright (c) 2025-2026, Kirky.X
+//!
+//! MIT License
+//!
+//! cached 宏错误负缓存测试
+
+use oxcache::config::{Config, ServiceConfig};
+use oxcache::{get_client, CacheManager, Deserialize, Serialize};
+use oxcache_macros::cached;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static NOT_FOUND_CALLS: AtomicUsize = AtomicUsize::new(0);
+static TIMEOUT_CALLS: AtomicUsize = AtomicUsize::new(0);
+static PLAIN_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(crate = "oxcache::serde")]
+enum AppError {
+    NotFound(u64),
+    Timeout,
+}
+
+#[cached(
+    service = "cache_errors_test",
+    ttl = 60,
+    cache_errors = matches!(err, AppError::NotFound(_))
+)]
+async fn find_user(id: u64) -> Result<String, AppError> {
+    if id == 404 {
+        NOT_FOUND_CALLS.fetch_add(1, Ordering::SeqCst);
+        Err(AppError::NotFound(id))
+    } else {
+        TIMEOUT_CALLS.fetch_add(1, Ordering::SeqCst);
+        Err(AppError::Timeout)
+    }
+}
+
+#[cached(service = "cache_errors_test", ttl = 60)]
+async fn find_plain(id: u64) -> Result<String, AppError> {
+    PLAIN_CALLS.fetch_add(1, Ordering::SeqCst);
+    Err(AppError::NotFound(id))
+}
+
+#[tokio::test]
+async fn test_cached_negative_caches_only_matching_errors() {
+    let mut services = HashMap::new();
+    services.insert(
+        "cache_errors_test".to_string(),
+        ServiceConfig::builder().l1_only().build().unwrap(),
+    );
+    CacheManager::init(Config {
+        config_version: None,
+        global: Default::default(),
+        services,
+    })
+    .await
+    .unwrap();
+
+    // NotFound 命中负缓存，第二次调用不再执行函数体
+    assert_eq!(find_user(404).await, Err(AppError::NotFound(404)));
+    assert_eq!(find_user(404).await, Err(AppError::NotFound(404)));
+    assert_eq!(NOT_FOUND_CALLS.load(Ordering::SeqCst), 1);
+
+    // Timeout 不满足谓词，每次都重新执行
+    assert_eq!(find_user(1).await, Err(AppError::Timeout));
+    assert_eq!(find_user(1).await, Err(AppError::Timeout));
+    assert_eq!(TIMEOUT_CALLS.load(Ordering::SeqCst), 2);
+
+    // 未配置 cache_errors 时不缓存任何错误
+    assert_eq!(find_plain(7).await, Err(AppError::NotFound(7)));
+    assert_eq!(find_plain(7).await, Err(AppError::NotFound(7)));
+    assert_eq!(PLAIN_CALLS.load(Ordering::SeqCst), 2);
+
+    let client = get_client("cache_errors_test").unwrap();
+    assert!(client
+        .get_bytes("cache_errors_test:find_plain:7:err")
+        .await
+        .unwrap()
+        .is_none());
+}
@@ -0,0 +1,59 @@
+//! Copyright (c) 2025-2026, Kirky.X
+//!
+//! MIT License
+//!
+//! cached 宏容器返回类型测试
+
+use oxcache::config::{Config, ServiceConfig};
+use oxcache::{CacheManager, Deserialize, Serialize};
+use oxcache_macros::cached;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(crate = "oxcache::serde")]
+struct Member {
+    name: String,
+    roles: Vec<String>,
+}
+
+#[cached(service = "container_returns_test", ttl = 60)]
+async fn members<'a, T>(team: &'a str, extra: T) -> Result<HashMap<String, Member>, String>
+where
+    T: std::fmt::Debug + Into<String>,
+{
+    CALLS.fetch_add(1, Ordering::SeqCst);
+    let mut map = HashMap::new();
+    map.insert(
+        team.to_string(),
+        Member {
+            name: extra.into(),
+            roles: vec!["admin".to_string()],
+        },
+    );
+    Ok(map)
+}
+
+#[tokio::test]
+async fn test_cached_round_trips_generic_container_return() {
+    let mut services = HashMap::new();
+    services.insert(
+        "container_returns_test".to_string(),
+        ServiceConfig::builder().l1_only().build().unwrap(),
+    );
+    CacheManager::init(Config {
+        config_version: None,
+        global: Default::default(),
+        services,
+    })
+    .await
+    .unwrap();
+
+    let first = members("core", "alice").await.unwrap();
+    let second = members("core", "alice").await.unwrap();
+    assert_eq!(first, second);
+    assert_eq!(first["core"].roles, vec!["admin".to_string()]);
+    assert_eq!(CALLS.load(Ordering::SeqCst), 1);
+}
@@ -0,0 +1,60 @@
+//! Copyright (c) 2025-2026, Kirky.X
+//!
+//! MIT License
+//!
+//! cached 宏自定义键构造器测试
+
+use oxcache::config::{Config, ServiceConfig};
+use oxcache::{get_client, CacheManager};
+use oxcache_macros::cached;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+/// 未实现 Debug 的参数类型
+struct Account {
+    tenant: String,
+    id: u64,
+}
+
+fn account_key(account: &Account, verbose: &bool) -> String {
+    format!("account:{}:{}:{}", account.tenant, account.id, verbose)
+}
+
+#[cached(service = "key_builder_test", key_builder = account_key, ttl = 60)]
+async fn load_balance(account: Account, verbose: bool) -> Result<u64, String> {
+    CALLS.fetch_add(1, Ordering::SeqCst);
+    Ok(account.id * 100 + verbose as u64)
+}
+
+#[tokio::test]
+async fn test_cached_uses_custom_key_builder() {
+    let mut services = HashMap::new();
+    services.insert(
+        "key_builder_test".to_string(),
+        ServiceConfig::builder().l1_only().build().unwrap(),
+    );
+    CacheManager::init(Config {
+        config_version: None,
+        global: Default::default(),
+        services,
+    })
+    .await
+    .unwrap();
+
+    let account = || Account {
+        tenant: "acme".to_string(),
+        id: 7,
+    };
+    assert_eq!(load_balance(account(), true).await.unwrap(), 701);
+    assert_eq!(load_balance(account(), true).await.unwrap(), 701);
+    assert_eq!(CALLS.load(Ordering::SeqCst), 1);
+
+    let client = get_client("key_builder_test").unwrap();
+    assert!(client
+        .get_bytes("account:acme:7:true")
+        .await
+        .unwrap()
+        .is_some());
+}
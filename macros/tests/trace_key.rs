@@ -0,0 +1,53 @@
+//! Copyright (c) 2025-2026, Kirky.X
+//!
+//! MIT License
+//!
+//! cached 宏缓存键预览测试
+
+use oxcache::config::{Config, ServiceConfig};
+use oxcache::{get_client, CacheManager};
+use oxcache_macros::cached;
+use std::collections::HashMap;
+
+#[cached(service = "trace_key_test", trace_key = true, ttl = 60)]
+async fn load_profile(id: u64, region: String) -> Result<String, String> {
+    Ok(format!("{}@{}", id, region))
+}
+
+#[cached(service = "trace_key_test", key = "order:{id}", trace_key = true)]
+async fn load_order(id: u64) -> Result<u64, String> {
+    Ok(id * 2)
+}
+
+#[test]
+fn test_generated_cache_key_format() {
+    assert_eq!(
+        load_profile_cache_key(7, "eu".to_string()),
+        r#"trace_key_test:load_profile:(7, "eu")"#
+    );
+    assert_eq!(load_order_cache_key(42), "order:42");
+}
+
+#[tokio::test]
+async fn test_cached_value_stored_under_previewed_key() {
+    let mut services = HashMap::new();
+    services.insert(
+        "trace_key_test".to_string(),
+        ServiceConfig::builder().l1_only().build().unwrap(),
+    );
+    CacheManager::init(Config {
+        config_version: None,
+        global: Default::default(),
+        services,
+    })
+    .await
+    .unwrap();
+
+    assert_eq!(load_order(21).await.unwrap(), 42);
+    let client = get_client("trace_key_test").unwrap();
+    assert!(client
+        .get_bytes(&load_order_cache_key(21))
+        .await
+        .unwrap()
+        .is_some());
+}
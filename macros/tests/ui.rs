@@ -0,0 +1,52 @@
+//! Copyright (c) 2025-2026, Kirky.X
+//!
+//! MIT License
+//!
+//! cached 宏编译测试
+
+#[test]
+fn test_cached_ttl_expressions() {
+    let t = trybuild::TestCases::new();
+    t.pass("tests/ui/ttl_literal.rs");
+    t.pass("tests/ui/ttl_const.rs");
+    t.pass("tests/ui/ttl_fn_call.rs");
+}
+
+#[test]
+fn test_cached_key_builder() {
+    let t = trybuild::TestCases::new();
+    t.pass("tests/ui/key_builder.rs");
+    t.compile_fail("tests/ui/key_and_key_builder.rs");
+}
+
+#[test]
+fn test_cached_cache_errors() {
+    let t = trybuild::TestCases::new();
+    t.pass("tests/ui/cache_errors.rs");
+}
+
+#[test]
+fn test_cached_container_returns() {
+    let t = trybuild::TestCases::new();
+    t.pass("tests/ui/container_returns.rs");
+}
+
+#[test]
+fn test_derive_cache_key() {
+    let t = trybuild::TestCases::new();
+    t.pass("tests/ui/cache_key_simple.rs");
+    t.pass("tests/ui/cache_key_skip.rs");
+    t.pass("tests/ui/cache_key_rename.rs");
+}
+
+#[test]
+fn test_cached_write_retries() {
+    let t = trybuild::TestCases::new();
+    t.pass("tests/ui/write_retries.rs");
+}
+
+#[test]
+fn test_cached_trace_key() {
+    let t = trybuild::TestCases::new();
+    t.pass("tests/ui/trace_key.rs");
+}
@@ -0,0 +1,32 @@
+use oxcache::{Deserialize, Serialize};
+use oxcache_macros::cached;
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(crate = "oxcache::serde")]
+enum LookupError {
+    NotFound,
+    Timeout,
+}
+
+#[cached(service = "ui_test", cache_errors = matches!(err, LookupError::NotFound))]
+async fn lookup(id: u64) -> Result<u64, LookupError> {
+    match id {
+        0 => Err(LookupError::NotFound),
+        1 => Err(LookupError::Timeout),
+        _ => Ok(id),
+    }
+}
+
+fn is_cacheable(err: &LookupError) -> bool {
+    !matches!(err, LookupError::Timeout)
+}
+
+#[cached(service = "ui_test", ttl = 30, cache_errors = is_cacheable(err))]
+async fn lookup_with_fn(id: u64) -> Result<u64, LookupError> {
+    Ok(id)
+}
+
+fn main() {
+    let _ = lookup(1);
+    let _ = lookup_with_fn(1);
+}
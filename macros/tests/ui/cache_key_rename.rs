@@ -0,0 +1,17 @@
+use oxcache::CacheKey;
+use oxcache_macros::CacheKey;
+
+#[derive(CacheKey)]
+struct Session {
+    #[cache_key(rename = "uid")]
+    user_id: u64,
+    region: &'static str,
+}
+
+fn main() {
+    let session = Session {
+        user_id: 42,
+        region: "eu",
+    };
+    assert_eq!(session.cache_key(), "Session:uid:2:42:region:2:eu");
+}
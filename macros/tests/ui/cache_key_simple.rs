@@ -0,0 +1,26 @@
+use oxcache::CacheKey;
+use oxcache_macros::{cached, CacheKey};
+
+#[derive(CacheKey)]
+struct Account {
+    tenant: String,
+    id: u64,
+}
+
+#[derive(CacheKey)]
+struct Pair(&'static str, u32);
+
+#[cached(service = "ui_test", key_builder = oxcache::key_builder::cache_key)]
+async fn load_account(account: Account) -> Result<u64, String> {
+    Ok(account.id)
+}
+
+fn main() {
+    let account = Account {
+        tenant: "acme".to_string(),
+        id: 7,
+    };
+    assert_eq!(account.cache_key(), "Account:tenant:4:acme:id:1:7");
+    assert_eq!(Pair("a:b", 1).cache_key(), "Pair:0:3:a:b:1:1:1");
+    let _ = load_account(account);
+}
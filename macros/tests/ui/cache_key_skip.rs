@@ -0,0 +1,21 @@
+use oxcache::CacheKey;
+use oxcache_macros::CacheKey;
+
+#[derive(CacheKey)]
+struct Query {
+    table: String,
+    #[cache_key(skip)]
+    #[allow(dead_code)]
+    trace_id: u64,
+    page: u32,
+}
+
+fn main() {
+    let query = |trace_id| Query {
+        table: "users".to_string(),
+        trace_id,
+        page: 2,
+    };
+    assert_eq!(query(1).cache_key(), "Query:table:5:users:page:1:2");
+    assert_eq!(query(1).cache_key(), query(2).cache_key());
+}
@@ -0,0 +1,50 @@
+use oxcache::{Deserialize, Serialize};
+use oxcache_macros::cached;
+use std::collections::HashMap;
+
+// 未实现 Debug 的值类型
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(crate = "oxcache::serde")]
+struct Profile {
+    name: String,
+    tags: Vec<String>,
+}
+
+#[cached(service = "ui_test")]
+async fn list_ids(page: u32) -> Result<Vec<u64>, String> {
+    Ok((0..page as u64).collect())
+}
+
+#[cached(service = "ui_test", ttl = 60)]
+async fn profiles(prefix: &str) -> Result<HashMap<String, Profile>, String> {
+    let mut map = HashMap::new();
+    map.insert(
+        prefix.to_string(),
+        Profile {
+            name: prefix.to_string(),
+            tags: Vec::new(),
+        },
+    );
+    Ok(map)
+}
+
+#[cached(service = "ui_test", key = "blob_{id}")]
+async fn blob(id: u64) -> Result<Option<Vec<u8>>, String> {
+    Ok((id > 0).then(|| vec![id as u8]))
+}
+
+#[cached(service = "ui_test")]
+async fn first_n<'a, T>(items: &'a [T], n: usize) -> Result<Vec<T>, String>
+where
+    T: Clone + std::fmt::Debug + Serialize + for<'de> Deserialize<'de>,
+{
+    let parsed: usize = n.to_string().parse().map_err(|_| "bad".to_string())?;
+    Ok(items.iter().take(parsed).cloned().collect())
+}
+
+fn main() {
+    let _ = list_ids(3);
+    let _ = profiles("eu");
+    let _ = blob(1);
+    let _ = first_n(&[1u64, 2, 3], 2);
+}
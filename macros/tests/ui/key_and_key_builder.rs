@@ -0,0 +1,14 @@
+use oxcache_macros::cached;
+
+fn user_key(id: &u64) -> String {
+    format!("user:{}", id)
+}
+
+#[cached(service = "ui_test", key = "user_{id}", key_builder = user_key)]
+async fn load_user(id: u64) -> Result<u64, String> {
+    Ok(id)
+}
+
+fn main() {
+    let _ = load_user(1);
+}
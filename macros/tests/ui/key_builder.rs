@@ -0,0 +1,36 @@
+use oxcache_macros::cached;
+
+// 未实现 Debug 的参数类型
+struct Filter {
+    region: String,
+}
+
+fn search_key(filter: &Filter, page: &u32) -> String {
+    format!("search:{}:{}", filter.region, page)
+}
+
+#[cached(service = "ui_test", key_builder = search_key)]
+async fn search(filter: Filter, page: u32) -> Result<u64, String> {
+    Ok(filter.region.len() as u64 + page as u64)
+}
+
+mod keys {
+    pub fn constant() -> String {
+        "constant".to_string()
+    }
+}
+
+#[cached(service = "ui_test", key_builder = keys::constant, ttl = 60)]
+async fn load_constant() -> Result<u64, String> {
+    Ok(1)
+}
+
+fn main() {
+    let _ = search(
+        Filter {
+            region: "eu".to_string(),
+        },
+        1,
+    );
+    let _ = load_constant();
+}
@@ -0,0 +1,25 @@
+use oxcache_macros::cached;
+
+#[cached(service = "ui_test", trace_key = true)]
+async fn load_user(id: u64, mut name: String) -> Result<String, String> {
+    name.push('!');
+    Ok(format!("{}:{}", id, name))
+}
+
+#[cached(service = "ui_test", key = "order:{id}", trace_key = true, ttl = 60)]
+async fn load_order(id: u64, verbose: bool) -> Result<u64, String> {
+    Ok(id + verbose as u64)
+}
+
+#[cached(service = "ui_test", trace_key = false)]
+async fn load_plain(id: u64) -> Result<u64, String> {
+    Ok(id)
+}
+
+fn main() {
+    let _: String = load_user_cache_key(1, "a".to_string());
+    let _: String = load_order_cache_key(1, true);
+    let _ = load_user(1, "a".to_string());
+    let _ = load_order(1, true);
+    let _ = load_plain(1);
+}
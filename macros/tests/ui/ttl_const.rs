@@ -0,0 +1,12 @@
+use oxcache_macros::cached;
+
+const SESSION_TTL: u64 = 600;
+
+#[cached(service = "ui_test", ttl = SESSION_TTL)]
+async fn load_session(id: u64) -> Result<u64, String> {
+    Ok(id)
+}
+
+fn main() {
+    let _ = load_session(1);
+}
@@ -0,0 +1,18 @@
+use oxcache_macros::cached;
+
+fn ttl_for(kind: &str) -> u64 {
+    if kind == "short" {
+        60
+    } else {
+        3600
+    }
+}
+
+#[cached(service = "ui_test", ttl = ttl_for("short"))]
+async fn load_item(id: u64) -> Result<u64, String> {
+    Ok(id)
+}
+
+fn main() {
+    let _ = load_item(1);
+}
@@ -0,0 +1,10 @@
+use oxcache_macros::cached;
+
+#[cached(service = "ui_test", ttl = 300)]
+async fn load_user(id: u64) -> Result<u64, String> {
+    Ok(id)
+}
+
+fn main() {
+    let _ = load_user(1);
+}
@@ -0,0 +1,16 @@
+use oxcache_macros::cached;
+
+#[cached(service = "ui_test", ttl = 300, write_retries = 2)]
+async fn load_user(id: u64) -> Result<u64, String> {
+    Ok(id)
+}
+
+#[cached(service = "ui_test", write_retries = 1, cache_errors = err.is_empty())]
+async fn load_name(id: u64) -> Result<String, String> {
+    Ok(id.to_string())
+}
+
+fn main() {
+    let _ = load_user(1);
+    let _ = load_name(1);
+}
@@ -0,0 +1,115 @@
+//! Copyright (c) 2025-2026, Kirky.X
+//!
+//! MIT License
+//!
+//! cached 宏缓存写入重试测试
+
+use async_trait::async_trait;
+use oxcache::error::{CacheError, Result};
+use oxcache::manager::MANAGER;
+use oxcache::serialization::{JsonSerializer, SerializerEnum};
+use oxcache::CacheOps;
+use oxcache_macros::cached;
+use std::any::Any;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+/// 前 `failures` 次写入返回瞬时错误的缓存
+struct FlakyCache {
+    failures: AtomicUsize,
+    writes: AtomicUsize,
+    entries: Mutex<HashMap<String, Vec<u8>>>,
+    serializer: SerializerEnum,
+}
+
+impl FlakyCache {
+    fn new(failures: usize) -> Self {
+        Self {
+            failures: AtomicUsize::new(failures),
+            writes: AtomicUsize::new(0),
+            entries: Mutex::new(HashMap::new()),
+            serializer: SerializerEnum::Json(JsonSerializer::new()),
+        }
+    }
+}
+
+#[async_trait]
+impl CacheOps for FlakyCache {
+    async fn get_bytes(&self, key: &str) -> Result<Option<Vec<u8>>> {
+        Ok(self.entries.lock().unwrap().get(key).cloned())
+    }
+
+    async fn set_bytes(&self, key: &str, value: Vec<u8>, _ttl: Option<u64>) -> Result<()> {
+        self.writes.fetch_add(1, Ordering::SeqCst);
+        let remaining = self.failures.load(Ordering::SeqCst);
+        if remaining > 0 {
+            self.failures.store(remaining - 1, Ordering::SeqCst);
+            return Err(CacheError::BackendError(
+                "transient write failure".to_string(),
+            ));
+        }
+        self.entries.lock().unwrap().insert(key.to_string(), value);
+        Ok(())
+    }
+
+    async fn delete(&self, key: &str) -> Result<()> {
+        self.entries.lock().unwrap().remove(key);
+        Ok(())
+    }
+
+    fn serializer(&self) -> &SerializerEnum {
+        &self.serializer
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn into_any_arc(self: Arc<Self>) -> Arc<dyn Any + Send + Sync> {
+        self
+    }
+}
+
+static RETRY_CALLS: AtomicUsize = AtomicUsize::new(0);
+static NO_RETRY_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+#[cached(service = "write_retries_test", ttl = 60, write_retries = 3)]
+async fn load_with_retry(id: u64) -> Result<String> {
+    RETRY_CALLS.fetch_add(1, Ordering::SeqCst);
+    Ok(format!("user-{}", id))
+}
+
+#[cached(service = "write_no_retries_test", ttl = 60)]
+async fn load_without_retry(id: u64) -> Result<String> {
+    NO_RETRY_CALLS.fetch_add(1, Ordering::SeqCst);
+    Ok(format!("user-{}", id))
+}
+
+#[tokio::test]
+async fn test_cached_retries_failed_write() {
+    let cache = Arc::new(FlakyCache::new(1));
+    MANAGER.insert("write_retries_test".to_string(), cache.clone());
+
+    assert_eq!(load_with_retry(1).await.unwrap(), "user-1");
+    // 首次写入失败后重试成功，值已缓存，第二次调用不再执行函数体
+    assert_eq!(cache.writes.load(Ordering::SeqCst), 2);
+    assert_eq!(load_with_retry(1).await.unwrap(), "user-1");
+    assert_eq!(RETRY_CALLS.load(Ordering::SeqCst), 1);
+
+    MANAGER.remove("write_retries_test");
+}
+
+#[tokio::test]
+async fn test_cached_without_write_retries_drops_failed_write() {
+    let cache = Arc::new(FlakyCache::new(1));
+    MANAGER.insert("write_no_retries_test".to_string(), cache.clone());
+
+    // 未配置重试时写入失败不影响返回值，但值未被缓存
+    assert_eq!(load_without_retry(1).await.unwrap(), "user-1");
+    assert_eq!(cache.writes.load(Ordering::SeqCst), 1);
+    assert_eq!(load_without_retry(1).await.unwrap(), "user-1");
+    assert_eq!(NO_RETRY_CALLS.load(Ordering::SeqCst), 2);
+
+    MANAGER.remove("write_no_retries_test");
+}
@@ -0,0 +1,141 @@
+//! Copyright (c) 2025-2026, Kirky.X
+//!
+//! MIT License
+//!
+//! 该模块定义了L2值的压缩帧格式。
+//!
+//! 帧格式：`[编码字节][数据]`，编码字节取值见 [`CompressionCodec::id`]。
+//! 读取时只依据编码字节解码，与当前配置的编码无关，因此可以随时切换编码而无需清空缓存。
+
+use crate::config::CompressionCodec;
+use crate::error::{CacheError, Result};
+
+impl CompressionCodec {
+    /// 写入帧头部的编码字节
+    pub fn id(self) -> u8 {
+        match self {
+            CompressionCodec::None => 0,
+            CompressionCodec::Zstd => 1,
+            CompressionCodec::Lz4 => 2,
+            CompressionCodec::Gzip => 3,
+        }
+    }
+
+    /// 根据编码字节解析编码
+    pub fn from_id(id: u8) -> Option<Self> {
+        match id {
+            0 => Some(CompressionCodec::None),
+            1 => Some(CompressionCodec::Zstd),
+            2 => Some(CompressionCodec::Lz4),
+            3 => Some(CompressionCodec::Gzip),
+            _ => None,
+        }
+    }
+}
+
+/// 将值编码为压缩帧
+///
+/// 压缩后没有变小（或编码未编译进当前构建）的值以不压缩的形式存储
+///
+/// # 参数
+///
+/// * `codec` - 首选的压缩编码
+/// * `value` - 原始值
+///
+/// # 返回值
+///
+/// 返回带编码字节的帧
+pub fn encode_frame(codec: CompressionCodec, value: &[u8]) -> Result<Vec<u8>> {
+    if let Some(compressed) = compress(codec, value)? {
+        if compressed.len() < value.len() {
+            let mut frame = Vec::with_capacity(compressed.len() + 1);
+            frame.push(codec.id());
+            frame.extend_from_slice(&compressed);
+            return Ok(frame);
+        }
+    }
+
+    let mut frame = Vec::with_capacity(value.len() + 1);
+    frame.push(CompressionCodec::None.id());
+    frame.extend_from_slice(value);
+    Ok(frame)
+}
+
+/// 解码压缩帧
+///
+/// # 参数
+///
+/// * `frame` - 带编码字节的帧
+///
+/// # 返回值
+///
+/// 返回原始值；帧为空、编码字节未知或数据损坏时返回错误
+pub fn decode_frame(frame: &[u8]) -> Result<Vec<u8>> {
+    let (&id, data) = frame
+        .split_first()
+        .ok_or_else(|| CacheError::Serialization("Empty compression frame".to_string()))?;
+    let codec = CompressionCodec::from_id(id).ok_or_else(|| {
+        CacheError::Serialization(format!("Unknown compression codec byte: {}", id))
+    })?;
+    decompress(codec, data)
+}
+
+/// 压缩数据，编码未编译进当前构建时返回None
+fn compress(codec: CompressionCodec, value: &[u8]) -> Result<Option<Vec<u8>>> {
+    match codec {
+        CompressionCodec::None => Ok(None),
+        #[cfg(feature = "zstd")]
+        CompressionCodec::Zstd => zstd::encode_all(value, 0)
+            .map(Some)
+            .map_err(|e| CacheError::Serialization(e.to_string())),
+        #[cfg(feature = "lz4_flex")]
+        CompressionCodec::Lz4 => Ok(Some(lz4_flex::compress_prepend_size(value))),
+        #[cfg(feature = "flate2")]
+        CompressionCodec::Gzip => {
+            use flate2::write::GzEncoder;
+            use flate2::Compression;
+            use std::io::Write;
+
+            let mut encoder = GzEncoder::new(Vec::new(), Compression::fast());
+            encoder
+                .write_all(value)
+                .map_err(|e| CacheError::Serialization(e.to_string()))?;
+            encoder
+                .finish()
+                .map(Some)
+                .map_err(|e| CacheError::Serialization(e.to_string()))
+        }
+        #[allow(unreachable_patterns)]
+        _ => Ok(None),
+    }
+}
+
+/// 解压数据
+fn decompress(codec: CompressionCodec, data: &[u8]) -> Result<Vec<u8>> {
+    match codec {
+        CompressionCodec::None => Ok(data.to_vec()),
+        #[cfg(feature = "zstd")]
+        CompressionCodec::Zstd => {
+            zstd::decode_all(data).map_err(|e| CacheError::Serialization(e.to_string()))
+        }
+        #[cfg(feature = "lz4_flex")]
+        CompressionCodec::Lz4 => lz4_flex::decompress_size_prepended(data)
+            .map_err(|e| CacheError::Serialization(e.to_string())),
+        #[cfg(feature = "flate2")]
+        CompressionCodec::Gzip => {
+            use flate2::read::GzDecoder;
+            use std::io::Read;
+
+            let mut decoded = Vec::new();
+            GzDecoder::new(data)
+                .read_to_end(&mut decoded)
+                .map_err(|e| CacheError::Serialization(e.to_string()))?;
+            Ok(decoded)
+        }
+        #[allow(unreachable_patterns)]
+        other => Err(CacheError::NotSupported(format!(
+            "Compression codec {:?} is not enabled in this build",
+            other
+        ))),
+    }
+}
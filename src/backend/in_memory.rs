@@ -0,0 +1,717 @@
+//! Copyright (c) 2025-2026, Kirky.X
+//!
+//! MIT License
+//!
+//! 测试用的内存L2存储
+//!
+//! [`InMemoryStore`] 基于 `DashMap` 模拟Redis的键空间，[`InMemoryConnection`]
+//! 实现 `redis::aio::ConnectionLike`，L2后端发出的命令、管道（包括事务）
+//! 和Lua脚本都在内存中执行，无需真实的Redis服务器。
+//! 过期时间按注入的 [`Clock`] 判断，读取时惰性删除已过期的键。
+
+use crate::backend::l2::{
+    GET_SET_SCRIPT, GET_WITH_VERSION_SCRIPT, INCR_BY_CHECKED_SCRIPT, SET_WITH_VERSION_SCRIPT,
+    TAKE_SCRIPT, UNLOCK_SCRIPT,
+};
+use crate::utils::clock::Clock;
+use dashmap::DashMap;
+use redis::{Arg, Cmd, ErrorKind, Pipeline, RedisError, RedisFuture, RedisResult, Value};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::{Duration, Instant};
+
+/// 存储的值类型
+#[derive(Debug, Clone)]
+enum StoredValue {
+    String(Vec<u8>),
+    Hash(HashMap<Vec<u8>, Vec<u8>>),
+}
+
+/// 键空间中的条目
+#[derive(Debug, Clone)]
+struct Entry {
+    value: StoredValue,
+    expires_at: Option<Instant>,
+}
+
+impl Entry {
+    fn is_expired(&self, now: Instant) -> bool {
+        self.expires_at.is_some_and(|deadline| deadline <= now)
+    }
+}
+
+/// L2后端使用的Lua脚本
+#[derive(Debug, Clone, Copy)]
+enum KnownScript {
+    Unlock,
+    GetWithVersion,
+    SetWithVersion,
+    GetSet,
+    IncrByChecked,
+    Take,
+}
+
+/// 已知脚本的SHA1摘要，`EVALSHA` 按摘要分派到等价的内存实现
+fn known_scripts() -> &'static HashMap<String, KnownScript> {
+    static SCRIPTS: OnceLock<HashMap<String, KnownScript>> = OnceLock::new();
+    SCRIPTS.get_or_init(|| {
+        [
+            (UNLOCK_SCRIPT, KnownScript::Unlock),
+            (GET_WITH_VERSION_SCRIPT, KnownScript::GetWithVersion),
+            (SET_WITH_VERSION_SCRIPT, KnownScript::SetWithVersion),
+            (GET_SET_SCRIPT, KnownScript::GetSet),
+            (INCR_BY_CHECKED_SCRIPT, KnownScript::IncrByChecked),
+            (TAKE_SCRIPT, KnownScript::Take),
+        ]
+        .into_iter()
+        .map(|(code, script)| (redis::Script::new(code).get_hash().to_string(), script))
+        .collect()
+    })
+}
+
+fn response_error(message: &'static str, detail: String) -> RedisError {
+    RedisError::from((ErrorKind::ResponseError, message, detail))
+}
+
+fn wrong_type() -> RedisError {
+    RedisError::from((
+        ErrorKind::TypeError,
+        "WRONGTYPE Operation against a key holding the wrong kind of value",
+    ))
+}
+
+fn syntax_error() -> RedisError {
+    RedisError::from((ErrorKind::ResponseError, "syntax error"))
+}
+
+fn parse_int(arg: &[u8]) -> RedisResult<i64> {
+    std::str::from_utf8(arg)
+        .ok()
+        .and_then(|s| s.parse().ok())
+        .ok_or_else(|| {
+            RedisError::from((
+                ErrorKind::ResponseError,
+                "value is not an integer or out of range",
+            ))
+        })
+}
+
+/// Redis glob 匹配，支持 `*`、`?` 与 `\` 转义
+fn glob_match(pattern: &[u8], key: &[u8]) -> bool {
+    match pattern.split_first() {
+        None => key.is_empty(),
+        Some((b'*', rest)) => (0..=key.len()).any(|i| glob_match(rest, &key[i..])),
+        Some((b'?', rest)) => !key.is_empty() && glob_match(rest, &key[1..]),
+        Some((b'\\', rest)) if !rest.is_empty() => {
+            key.first() == Some(&rest[0]) && glob_match(&rest[1..], &key[1..])
+        }
+        Some((c, rest)) => key.first() == Some(c) && glob_match(rest, &key[1..]),
+    }
+}
+
+/// 内存键空间
+///
+/// 所有命令在同一把锁内同步执行，管道和脚本因此天然具有原子性
+#[derive(Debug)]
+pub struct InMemoryStore {
+    entries: DashMap<Vec<u8>, Entry>,
+    clock: Arc<dyn Clock>,
+    lock: Mutex<()>,
+    unavailable: AtomicBool,
+}
+
+impl InMemoryStore {
+    /// 创建空的内存存储
+    ///
+    /// # 参数
+    ///
+    /// * `clock` - 判断过期时间使用的时钟
+    pub fn new(clock: Arc<dyn Clock>) -> Self {
+        Self {
+            entries: DashMap::new(),
+            clock,
+            lock: Mutex::new(()),
+            unavailable: AtomicBool::new(false),
+        }
+    }
+
+    /// 模拟服务不可用，之后所有命令和管道都返回连接错误，直到恢复
+    ///
+    /// # 参数
+    ///
+    /// * `unavailable` - 是否不可用
+    pub fn set_unavailable(&self, unavailable: bool) {
+        self.unavailable.store(unavailable, Ordering::SeqCst);
+    }
+
+    fn check_available(&self) -> RedisResult<()> {
+        if self.unavailable.load(Ordering::SeqCst) {
+            return Err(RedisError::from((
+                ErrorKind::IoError,
+                "Connection refused",
+                "in-memory store is unavailable".to_string(),
+            )));
+        }
+        Ok(())
+    }
+
+    /// 创建连接到该存储的连接
+    pub fn connection(self: &Arc<Self>) -> InMemoryConnection {
+        InMemoryConnection {
+            store: self.clone(),
+        }
+    }
+
+    /// 未过期的键数量
+    pub fn len(&self) -> usize {
+        let now = self.clock.now();
+        self.entries.retain(|_, entry| !entry.is_expired(now));
+        self.entries.len()
+    }
+
+    /// 是否没有未过期的键
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// 执行单条命令
+    fn execute_cmd(&self, cmd: &Cmd) -> RedisResult<Value> {
+        self.check_available()?;
+        let _guard = self.lock.lock().unwrap_or_else(|e| e.into_inner());
+        self.dispatch(&Self::cmd_args(cmd))
+    }
+
+    /// 执行管道，`offset` 大于0表示事务，结果按 `EXEC` 的格式包装为单个数组
+    fn execute_pipeline(&self, pipeline: &Pipeline, offset: usize) -> RedisResult<Vec<Value>> {
+        self.check_available()?;
+        let _guard = self.lock.lock().unwrap_or_else(|e| e.into_inner());
+        let values = pipeline
+            .cmd_iter()
+            .map(|cmd| self.dispatch(&Self::cmd_args(cmd)))
+            .collect::<RedisResult<Vec<_>>>()?;
+        if offset > 0 {
+            Ok(vec![Value::Array(values)])
+        } else {
+            Ok(values)
+        }
+    }
+
+    fn cmd_args(cmd: &Cmd) -> Vec<&[u8]> {
+        cmd.args_iter()
+            .filter_map(|arg| match arg {
+                Arg::Simple(arg) => Some(arg),
+                Arg::Cursor => None,
+            })
+            .collect()
+    }
+
+    /// 删除已过期的键，返回键是否仍然存在
+    fn purge_expired(&self, key: &[u8]) -> bool {
+        let now = self.clock.now();
+        self.entries
+            .remove_if(key, |_, entry| entry.is_expired(now));
+        self.entries.contains_key(key)
+    }
+
+    fn get_string(&self, key: &[u8]) -> RedisResult<Option<Vec<u8>>> {
+        if !self.purge_expired(key) {
+            return Ok(None);
+        }
+        match self.entries.get(key).map(|entry| entry.value.clone()) {
+            Some(StoredValue::String(value)) => Ok(Some(value)),
+            Some(StoredValue::Hash(_)) => Err(wrong_type()),
+            None => Ok(None),
+        }
+    }
+
+    fn set_string(&self, key: &[u8], value: Vec<u8>, ttl: Option<Duration>) {
+        let expires_at = ttl.map(|ttl| self.clock.now() + ttl);
+        self.entries.insert(
+            key.to_vec(),
+            Entry {
+                value: StoredValue::String(value),
+                expires_at,
+            },
+        );
+    }
+
+    fn remaining(&self, key: &[u8]) -> Option<Option<Duration>> {
+        if !self.purge_expired(key) {
+            return None;
+        }
+        let now = self.clock.now();
+        self.entries.get(key).map(|entry| {
+            entry
+                .expires_at
+                .map(|deadline| deadline.saturating_duration_since(now))
+        })
+    }
+
+    fn set_expiry(&self, key: &[u8], ttl: Duration) -> i64 {
+        if !self.purge_expired(key) {
+            return 0;
+        }
+        if ttl.is_zero() {
+            // 与Redis一致，非正数的过期时间直接删除键
+            self.entries.remove(key);
+            return 1;
+        }
+        let deadline = self.clock.now() + ttl;
+        match self.entries.get_mut(key) {
+            Some(mut entry) => {
+                entry.expires_at = Some(deadline);
+                1
+            }
+            None => 0,
+        }
+    }
+
+    fn dispatch(&self, args: &[&[u8]]) -> RedisResult<Value> {
+        let Some((name, args)) = args.split_first() else {
+            return Err(syntax_error());
+        };
+        let name = String::from_utf8_lossy(name).to_ascii_uppercase();
+        match (name.as_str(), args) {
+            ("PING", _) => Ok(Value::SimpleString("PONG".to_string())),
+            ("GET", [key]) => Ok(self
+                .get_string(key)?
+                .map_or(Value::Nil, Value::BulkString)),
+            ("SET", [key, value, options @ ..]) => self.set(key, value, options),
+            ("DEL" | "UNLINK", keys) if !keys.is_empty() => {
+                let removed = keys
+                    .iter()
+                    .filter(|key| self.purge_expired(key) && self.entries.remove(**key).is_some())
+                    .count();
+                Ok(Value::Int(removed as i64))
+            }
+            ("EXISTS", keys) if !keys.is_empty() => Ok(Value::Int(
+                keys.iter().filter(|key| self.purge_expired(key)).count() as i64,
+            )),
+            ("INCR", [key]) => self.incr_by(key, 1),
+            ("INCRBY", [key, delta]) => self.incr_by(key, parse_int(delta)?),
+            ("EXPIRE", [key, seconds]) => {
+                let seconds = parse_int(seconds)?.max(0) as u64;
+                Ok(Value::Int(
+                    self.set_expiry(key, Duration::from_secs(seconds)),
+                ))
+            }
+            ("PEXPIRE", [key, millis]) => {
+                let millis = parse_int(millis)?.max(0) as u64;
+                Ok(Value::Int(
+                    self.set_expiry(key, Duration::from_millis(millis)),
+                ))
+            }
+            ("PERSIST", [key]) => {
+                if !self.purge_expired(key) {
+                    return Ok(Value::Int(0));
+                }
+                let persisted = self
+                    .entries
+                    .get_mut(*key)
+                    .map(|mut entry| entry.expires_at.take().is_some())
+                    .unwrap_or(false);
+                Ok(Value::Int(persisted as i64))
+            }
+            ("TTL", [key]) => Ok(Value::Int(match self.remaining(key) {
+                None => -2,
+                Some(None) => -1,
+                Some(Some(left)) => ((left.as_millis() + 500) / 1000) as i64,
+            })),
+            ("PTTL", [key]) => Ok(Value::Int(match self.remaining(key) {
+                None => -2,
+                Some(None) => -1,
+                Some(Some(left)) => left.as_millis() as i64,
+            })),
+            ("HGET", [key, field]) => self.hget(key, field),
+            ("HSET", [key, pairs @ ..]) if !pairs.is_empty() && pairs.len() % 2 == 0 => {
+                self.hset(key, pairs)
+            }
+            ("HGETALL", [key]) => self.hgetall(key),
+            ("TYPE", [key]) => {
+                let kind = if !self.purge_expired(key) {
+                    "none"
+                } else {
+                    match self.entries.get(*key).map(|entry| entry.value.clone()) {
+                        Some(StoredValue::String(_)) => "string",
+                        Some(StoredValue::Hash(_)) => "hash",
+                        None => "none",
+                    }
+                };
+                Ok(Value::SimpleString(kind.to_string()))
+            }
+            ("SCAN", [_cursor, options @ ..]) => self.scan(options),
+            ("DBSIZE", []) => Ok(Value::Int(self.len() as i64)),
+            ("INFO", _) => Ok(Value::BulkString(
+                format!(
+                    "# Server\r\nredis_version:in-memory\r\n\r\n# Keyspace\r\ndb0:keys={},expires=0,avg_ttl=0\r\n",
+                    self.len()
+                )
+                .into_bytes(),
+            )),
+            ("MEMORY", [sub, key]) if sub.eq_ignore_ascii_case(b"USAGE") => {
+                if !self.purge_expired(key) {
+                    return Ok(Value::Nil);
+                }
+                let size = match self.entries.get(*key).map(|entry| entry.value.clone()) {
+                    Some(StoredValue::String(value)) => value.len(),
+                    Some(StoredValue::Hash(hash)) => {
+                        hash.iter().map(|(field, value)| field.len() + value.len()).sum()
+                    }
+                    None => 0,
+                };
+                Ok(Value::Int((key.len() + size) as i64))
+            }
+            ("SCRIPT", [sub, code]) if sub.eq_ignore_ascii_case(b"LOAD") => Ok(
+                Value::BulkString(redis::Script::new(&String::from_utf8_lossy(code)).get_hash().as_bytes().to_vec()),
+            ),
+            ("EVALSHA", [sha, numkeys, rest @ ..]) => {
+                let numkeys = parse_int(numkeys)?.max(0) as usize;
+                if rest.len() < numkeys {
+                    return Err(syntax_error());
+                }
+                let (keys, argv) = rest.split_at(numkeys);
+                self.eval_known_script(&String::from_utf8_lossy(sha), keys, argv)
+            }
+            _ => Err(response_error("unknown command", name)),
+        }
+    }
+
+    fn set(&self, key: &[u8], value: &[u8], options: &[&[u8]]) -> RedisResult<Value> {
+        let (mut ttl, mut nx, mut get) = (None, false, false);
+        let mut options = options.iter();
+        while let Some(option) = options.next() {
+            let option = String::from_utf8_lossy(option).to_ascii_uppercase();
+            match option.as_str() {
+                "NX" => nx = true,
+                "GET" => get = true,
+                "EX" | "PX" => {
+                    let amount = parse_int(options.next().ok_or_else(syntax_error)?)?;
+                    if amount <= 0 {
+                        return Err(response_error(
+                            "invalid expire time in 'set' command",
+                            amount.to_string(),
+                        ));
+                    }
+                    ttl = Some(if option == "EX" {
+                        Duration::from_secs(amount as u64)
+                    } else {
+                        Duration::from_millis(amount as u64)
+                    });
+                }
+                _ => return Err(syntax_error()),
+            }
+        }
+
+        let previous = if get { self.get_string(key)? } else { None };
+        if nx && self.purge_expired(key) {
+            return Ok(Value::Nil);
+        }
+        self.set_string(key, value.to_vec(), ttl);
+        Ok(if get {
+            previous.map_or(Value::Nil, Value::BulkString)
+        } else {
+            Value::Okay
+        })
+    }
+
+    fn incr_by(&self, key: &[u8], delta: i64) -> RedisResult<Value> {
+        let current = match self.get_string(key)? {
+            Some(value) => parse_int(&value)?,
+            None => 0,
+        };
+        let next = current.checked_add(delta).ok_or_else(|| {
+            RedisError::from((ErrorKind::ResponseError, "increment would overflow"))
+        })?;
+        // INCR 保留原有的过期时间
+        match self.entries.get_mut(key) {
+            Some(mut entry) => entry.value = StoredValue::String(next.to_string().into_bytes()),
+            None => self.set_string(key, next.to_string().into_bytes(), None),
+        }
+        Ok(Value::Int(next))
+    }
+
+    fn hget(&self, key: &[u8], field: &[u8]) -> RedisResult<Value> {
+        if !self.purge_expired(key) {
+            return Ok(Value::Nil);
+        }
+        match self.entries.get(key).map(|entry| entry.value.clone()) {
+            Some(StoredValue::Hash(hash)) => Ok(hash
+                .get(field)
+                .cloned()
+                .map_or(Value::Nil, Value::BulkString)),
+            Some(StoredValue::String(_)) => Err(wrong_type()),
+            None => Ok(Value::Nil),
+        }
+    }
+
+    fn hset(&self, key: &[u8], pairs: &[&[u8]]) -> RedisResult<Value> {
+        self.purge_expired(key);
+        let mut entry = self.entries.entry(key.to_vec()).or_insert_with(|| Entry {
+            value: StoredValue::Hash(HashMap::new()),
+            expires_at: None,
+        });
+        let StoredValue::Hash(hash) = &mut entry.value else {
+            return Err(wrong_type());
+        };
+        let added = pairs
+            .chunks(2)
+            .filter(|pair| hash.insert(pair[0].to_vec(), pair[1].to_vec()).is_none())
+            .count();
+        Ok(Value::Int(added as i64))
+    }
+
+    fn hgetall(&self, key: &[u8]) -> RedisResult<Value> {
+        if !self.purge_expired(key) {
+            return Ok(Value::Array(Vec::new()));
+        }
+        match self.entries.get(key).map(|entry| entry.value.clone()) {
+            Some(StoredValue::Hash(hash)) => Ok(Value::Array(
+                hash.into_iter()
+                    .flat_map(|(field, value)| [Value::BulkString(field), Value::BulkString(value)])
+                    .collect(),
+            )),
+            Some(StoredValue::String(_)) => Err(wrong_type()),
+            None => Ok(Value::Array(Vec::new())),
+        }
+    }
+
+    /// 一次返回所有匹配的键，游标恒为0
+    fn scan(&self, options: &[&[u8]]) -> RedisResult<Value> {
+        let mut pattern: &[u8] = b"*";
+        let mut options = options.iter();
+        while let Some(option) = options.next() {
+            let value = options.next().ok_or_else(syntax_error)?;
+            if option.eq_ignore_ascii_case(b"MATCH") {
+                pattern = value;
+            } else if !option.eq_ignore_ascii_case(b"COUNT") {
+                return Err(syntax_error());
+            }
+        }
+
+        let now = self.clock.now();
+        self.entries.retain(|_, entry| !entry.is_expired(now));
+        let keys = self
+            .entries
+            .iter()
+            .filter(|entry| glob_match(pattern, entry.key()))
+            .map(|entry| Value::BulkString(entry.key().clone()))
+            .collect();
+        Ok(Value::Array(vec![
+            Value::BulkString(b"0".to_vec()),
+            Value::Array(keys),
+        ]))
+    }
+
+    /// 以命令序列执行与L2后端Lua脚本等价的逻辑
+    fn eval_known_script(&self, sha: &str, keys: &[&[u8]], argv: &[&[u8]]) -> RedisResult<Value> {
+        let script = known_scripts().get(sha).copied().ok_or_else(|| {
+            RedisError::from((ErrorKind::NoScriptError, "NOSCRIPT No matching script"))
+        })?;
+        let key = *keys.first().ok_or_else(syntax_error)?;
+        let version_key = [key, b":version"].concat();
+
+        match script {
+            KnownScript::Unlock => {
+                let expected = *argv.first().ok_or_else(syntax_error)?;
+                if self.get_string(key)?.as_deref() == Some(expected) {
+                    self.dispatch(&[b"DEL", key])
+                } else {
+                    Ok(Value::Int(0))
+                }
+            }
+            KnownScript::GetWithVersion => {
+                let Some(value) = self.get_string(key)? else {
+                    return Ok(Value::Nil);
+                };
+                let version = self
+                    .get_string(&version_key)?
+                    .unwrap_or_else(|| b"0".to_vec());
+                Ok(Value::Array(vec![
+                    Value::BulkString(value),
+                    Value::BulkString(version),
+                ]))
+            }
+            KnownScript::SetWithVersion => {
+                let [value, ttl, ..] = argv else {
+                    return Err(syntax_error());
+                };
+                if parse_int(ttl)? == 0 {
+                    self.dispatch(&[b"SET", key, value])?;
+                    self.dispatch(&[b"INCR", &version_key])?;
+                    self.dispatch(&[b"PERSIST", &version_key])?;
+                } else {
+                    self.dispatch(&[b"SET", key, value, b"EX", ttl])?;
+                    self.dispatch(&[b"INCR", &version_key])?;
+                    self.dispatch(&[b"EXPIRE", &version_key, ttl])?;
+                }
+                Ok(Value::Int(1))
+            }
+            KnownScript::GetSet => {
+                let [value, ttl, ..] = argv else {
+                    return Err(syntax_error());
+                };
+                let previous = self.get_string(key)?;
+                if parse_int(ttl)? == 0 {
+                    self.dispatch(&[b"SET", key, value])?;
+                } else {
+                    self.dispatch(&[b"SET", key, value, b"EX", ttl])?;
+                }
+                Ok(previous.map_or(Value::Nil, Value::BulkString))
+            }
+            KnownScript::IncrByChecked => {
+                let [delta, limit, ..] = argv else {
+                    return Err(syntax_error());
+                };
+                let current = match self.get_string(key)? {
+                    Some(value) => parse_int(&value)?,
+                    None => 0,
+                };
+                let delta = parse_int(delta)?;
+                let within_limit = limit.is_empty() || current <= parse_int(limit)?;
+                let Some(next) = current.checked_add(delta).filter(|_| within_limit) else {
+                    return Ok(Value::Array(vec![
+                        Value::Int(0),
+                        Value::BulkString(current.to_string().into_bytes()),
+                    ]));
+                };
+                self.incr_by(key, delta)?;
+                Ok(Value::Array(vec![
+                    Value::Int(1),
+                    Value::BulkString(next.to_string().into_bytes()),
+                ]))
+            }
+            KnownScript::Take => {
+                if !self.purge_expired(key) {
+                    return Ok(Value::Nil);
+                }
+                // 以一次移除取得旧值，并发调用时只有一个调用方能取到
+                let removed = self.entries.remove_if(key, |_, entry| {
+                    matches!(entry.value, StoredValue::String(_))
+                });
+                match removed.map(|(_, entry)| entry.value) {
+                    Some(StoredValue::String(value)) => {
+                        self.entries.remove(version_key.as_slice());
+                        Ok(Value::BulkString(value))
+                    }
+                    _ if self.entries.contains_key(key) => Err(wrong_type()),
+                    _ => Ok(Value::Nil),
+                }
+            }
+        }
+    }
+}
+
+/// 连接到 [`InMemoryStore`] 的异步连接
+#[derive(Debug, Clone)]
+pub struct InMemoryConnection {
+    store: Arc<InMemoryStore>,
+}
+
+impl redis::aio::ConnectionLike for InMemoryConnection {
+    fn req_packed_command<'a>(&'a mut self, cmd: &'a Cmd) -> RedisFuture<'a, Value> {
+        let result = self.store.execute_cmd(cmd);
+        Box::pin(async move { result })
+    }
+
+    fn req_packed_commands<'a>(
+        &'a mut self,
+        pipeline: &'a Pipeline,
+        offset: usize,
+        _count: usize,
+    ) -> RedisFuture<'a, Vec<Value>> {
+        let result = self.store.execute_pipeline(pipeline, offset);
+        Box::pin(async move { result })
+    }
+
+    fn get_db(&self) -> i64 {
+        0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::clock::MockClock;
+    use redis::AsyncCommands;
+
+    fn store() -> (Arc<MockClock>, Arc<InMemoryStore>) {
+        let clock = Arc::new(MockClock::new());
+        (clock.clone(), Arc::new(InMemoryStore::new(clock)))
+    }
+
+    #[tokio::test]
+    async fn test_keys_expire_with_mock_clock() {
+        let (clock, store) = store();
+        let mut conn = store.connection();
+
+        let _: () = redis::cmd("SET")
+            .arg("k")
+            .arg("v")
+            .arg("EX")
+            .arg(10)
+            .query_async(&mut conn)
+            .await
+            .unwrap();
+        let ttl: i64 = conn.ttl("k").await.unwrap();
+        assert_eq!(ttl, 10);
+
+        clock.advance(Duration::from_secs(9));
+        let value: Option<String> = conn.get("k").await.unwrap();
+        assert_eq!(value.as_deref(), Some("v"));
+
+        clock.advance(Duration::from_secs(1));
+        let value: Option<String> = conn.get("k").await.unwrap();
+        assert_eq!(value, None);
+        assert!(store.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_transaction_returns_non_ignored_results() {
+        let (_, store) = store();
+        let mut conn = store.connection();
+
+        let (count, exists): (i64, bool) = redis::pipe()
+            .atomic()
+            .set("k", "1")
+            .ignore()
+            .incr("k", 1)
+            .exists("k")
+            .query_async(&mut conn)
+            .await
+            .unwrap();
+        assert_eq!((count, exists), (2, true));
+    }
+
+    #[tokio::test]
+    async fn test_set_with_version_script_increments_version() {
+        let (_, store) = store();
+        let mut conn = store.connection();
+        let set = redis::Script::new(SET_WITH_VERSION_SCRIPT);
+        let get = redis::Script::new(GET_WITH_VERSION_SCRIPT);
+
+        for _ in 0..2 {
+            let _: i32 = set
+                .key("k")
+                .arg("v")
+                .arg(60)
+                .invoke_async(&mut conn)
+                .await
+                .unwrap();
+        }
+        let (value, version): (String, String) =
+            get.key("k").invoke_async(&mut conn).await.unwrap();
+        assert_eq!((value.as_str(), version.as_str()), ("v", "2"));
+        let ttl: i64 = conn.ttl("k:version").await.unwrap();
+        assert_eq!(ttl, 60);
+    }
+
+    #[test]
+    fn test_glob_match() {
+        assert!(glob_match(b"svc:*", b"svc:a:version"));
+        assert!(glob_match(b"k?y", b"key"));
+        assert!(!glob_match(b"svc:*", b"other:a"));
+        assert!(glob_match(b"a\\*", b"a*"));
+        assert!(!glob_match(b"a\\*", b"ab"));
+    }
+}
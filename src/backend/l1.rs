@@ -4,18 +4,99 @@
 //!
 //! 该模块定义了L1缓存后端的实现，基于内存的高速缓存。
 
+use crate::config::L1AutoTuneConfig;
 use crate::error::Result;
+use crate::metrics::GLOBAL_METRICS;
+use crate::utils::clock::{Clock, SystemClock};
+use crate::utils::fnv1a_64;
+use crate::utils::task::spawn_named;
 use moka::future::Cache;
+use moka::notification::RemovalCause;
+use moka::ops::compute::{CompResult, Op};
+use moka::Expiry;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, Weak};
 use std::time::{Duration, Instant};
-use tracing::{debug, instrument};
+use tokio::task::JoinHandle;
+use tracing::{debug, info, instrument};
+
+/// 未配置默认TTL时L1条目的过期时间（秒）
+pub const DEFAULT_L1_TTL_SECS: u64 = 300;
+
+/// 条目过期后继续保留的时间（秒），供L2降级时读取过期数据
+pub const L1_STALE_GRACE_SECS: u64 = 60;
+
+/// L1缓存条目: (数据, 版本/时间戳, 过期时间)
+type L1Entry = (Vec<u8>, u64, Option<Instant>);
+
+/// L1内部分片
+type L1Shard = Cache<Vec<u8>, L1Entry>;
+
+/// L1淘汰监听器
+///
+/// 条目因容量或TTL被淘汰时以被淘汰的键调用，显式删除和覆盖写入不会触发；
+/// 非UTF-8的原始键按有损方式转换为字符串
+pub type EvictionListener = Arc<dyn Fn(&str) + Send + Sync>;
+
+/// 内存压力信号
+///
+/// 由宿主提供，返回true表示当前处于内存压力下，自适应调优会收缩L1容量
+pub type MemoryPressureSignal = Arc<dyn Fn() -> bool + Send + Sync>;
+
+/// L1条目过期策略
+///
+/// 按条目自身的过期时间独立淘汰，与容量淘汰互不影响。
+/// 条目在过期时间（软TTL）之后再保留 [`L1_STALE_GRACE_SECS`] 秒才真正移除，
+/// 期间常规读取视为未命中，仅 [`L1Backend::get_allow_stale`] 可读取
+struct L1EntryExpiry;
+
+impl L1EntryExpiry {
+    fn remaining(expire_at: Option<Instant>, now: Instant) -> Option<Duration> {
+        expire_at
+            .map(|at| at.saturating_duration_since(now) + Duration::from_secs(L1_STALE_GRACE_SECS))
+    }
+}
+
+impl Expiry<Vec<u8>, L1Entry> for L1EntryExpiry {
+    fn expire_after_create(
+        &self,
+        _key: &Vec<u8>,
+        value: &L1Entry,
+        created_at: Instant,
+    ) -> Option<Duration> {
+        Self::remaining(value.2, created_at)
+    }
+
+    fn expire_after_update(
+        &self,
+        _key: &Vec<u8>,
+        value: &L1Entry,
+        updated_at: Instant,
+        _duration_until_expiry: Option<Duration>,
+    ) -> Option<Duration> {
+        Self::remaining(value.2, updated_at)
+    }
+}
 
 /// L1缓存后端实现
 ///
-/// 基于内存的高速缓存实现，使用Moka作为底层缓存库
+/// 基于内存的高速缓存实现，使用Moka作为底层缓存库。
+/// 可按键的哈希拆分为多个分片，各分片独立加锁，减少高并发下的锁竞争
 #[derive(Clone)]
 pub struct L1Backend {
-    // 值: (数据, 版本/时间戳, 过期时间)
-    cache: Cache<String, (Vec<u8>, u64, Option<Instant>)>,
+    /// 以键的字节为索引，字符串键与相同字节的原始键指向同一条目；
+    /// 分片数为2的幂，键按哈希固定落在其中一个分片
+    shards: Vec<L1Shard>,
+    /// 构建时的最大容量（各分片容量之和的上限）
+    max_capacity: u64,
+    /// 未指定TTL时使用的默认过期时间（秒）
+    default_ttl: Option<u64>,
+    /// 当前生效的容量，不超过构建时的最大容量
+    capacity: Arc<AtomicU64>,
+    /// 读取命中次数
+    hits: Arc<AtomicU64>,
+    /// 读取未命中次数（包括已过期）
+    misses: Arc<AtomicU64>,
 }
 
 impl L1Backend {
@@ -29,11 +110,214 @@ impl L1Backend {
     ///
     /// 返回新的L1Backend实例
     pub fn new(capacity: u64) -> Self {
+        Self::new_with_default_ttl(capacity, None)
+    }
+
+    /// 创建带默认TTL的L1缓存后端实例
+    ///
+    /// # 参数
+    ///
+    /// * `capacity` - 缓存最大容量（字节）
+    /// * `default_ttl` - 写入时未指定TTL所使用的过期时间（秒），None表示使用300秒
+    ///
+    /// # 返回值
+    ///
+    /// 返回新的L1Backend实例
+    pub fn new_with_default_ttl(capacity: u64, default_ttl: Option<u64>) -> Self {
+        Self::new_with_eviction_listener(capacity, default_ttl, None)
+    }
+
+    /// 创建带淘汰监听器的L1缓存后端实例
+    ///
+    /// # 参数
+    ///
+    /// * `capacity` - 缓存最大容量（字节）
+    /// * `default_ttl` - 写入时未指定TTL所使用的过期时间（秒），None表示使用300秒
+    /// * `listener` - 条目因容量或TTL被淘汰时调用的监听器
+    ///
+    /// # 返回值
+    ///
+    /// 返回新的L1Backend实例
+    pub fn new_with_eviction_listener(
+        capacity: u64,
+        default_ttl: Option<u64>,
+        listener: Option<EvictionListener>,
+    ) -> Self {
+        Self::new_sharded(capacity, default_ttl, listener, 1)
+    }
+
+    /// 创建分片的L1缓存后端实例
+    ///
+    /// 容量平均分配到各分片，每个分片独立淘汰，因此键分布不均时
+    /// 条目可能在总数达到容量之前被淘汰
+    ///
+    /// # 参数
+    ///
+    /// * `capacity` - 缓存最大容量（字节）
+    /// * `default_ttl` - 写入时未指定TTL所使用的过期时间（秒），None表示使用300秒
+    /// * `listener` - 条目因容量或TTL被淘汰时调用的监听器
+    /// * `shards` - 分片数，不是2的幂时向上取整到2的幂，0视为1
+    ///
+    /// # 返回值
+    ///
+    /// 返回新的L1Backend实例
+    pub fn new_sharded(
+        capacity: u64,
+        default_ttl: Option<u64>,
+        listener: Option<EvictionListener>,
+        shards: usize,
+    ) -> Self {
+        let shard_count = shards.max(1).next_power_of_two();
+        let shard_capacity = capacity.div_ceil(shard_count as u64);
+        let shards = (0..shard_count)
+            .map(|_| {
+                let mut builder = Cache::builder()
+                    .max_capacity(shard_capacity)
+                    .expire_after(L1EntryExpiry);
+
+                if let Some(listener) = listener.clone() {
+                    builder = builder.eviction_listener(
+                        move |key: Arc<Vec<u8>>, _value: L1Entry, cause: RemovalCause| {
+                            if cause.was_evicted() {
+                                listener(&String::from_utf8_lossy(&key));
+                            }
+                        },
+                    );
+                }
+                builder.build()
+            })
+            .collect();
+
         Self {
-            cache: Cache::builder().max_capacity(capacity).build(),
+            shards,
+            max_capacity: capacity,
+            default_ttl,
+            capacity: Arc::new(AtomicU64::new(capacity)),
+            hits: Arc::new(AtomicU64::new(0)),
+            misses: Arc::new(AtomicU64::new(0)),
         }
     }
 
+    /// 设置初始生效容量
+    ///
+    /// 用于自适应调优：以容量上限构建后端，再从较小的容量开始调整
+    ///
+    /// # 参数
+    ///
+    /// * `capacity` - 初始容量，超过构建时的最大容量时取最大容量
+    ///
+    /// # 返回值
+    ///
+    /// 返回设置了初始容量的L1Backend实例
+    pub fn with_initial_capacity(self, capacity: u64) -> Self {
+        self.capacity
+            .store(capacity.min(self.max_capacity()), Ordering::Relaxed);
+        self
+    }
+
+    /// 获取构建时的最大容量，当前容量只能在此范围内调整
+    pub fn max_capacity(&self) -> u64 {
+        self.max_capacity
+    }
+
+    /// 获取内部分片数
+    pub fn shard_count(&self) -> usize {
+        self.shards.len()
+    }
+
+    /// 获取键所在的分片
+    fn shard(&self, key: &[u8]) -> &L1Shard {
+        &self.shards[shard_index(key, self.shards.len())]
+    }
+
+    /// 遍历所有分片中的条目
+    fn iter(&self) -> impl Iterator<Item = (Arc<Vec<u8>>, L1Entry)> + '_ {
+        self.shards.iter().flat_map(|shard| shard.iter())
+    }
+
+    /// 处理所有分片挂起的维护任务（淘汰、失效）
+    async fn run_pending_tasks(&self) {
+        for shard in &self.shards {
+            shard.run_pending_tasks().await;
+        }
+    }
+
+    /// 获取当前生效的容量
+    pub fn capacity(&self) -> u64 {
+        self.capacity.load(Ordering::Relaxed)
+    }
+
+    /// 获取累计的读取命中与未命中次数
+    ///
+    /// # 返回值
+    ///
+    /// 返回 `(命中次数, 未命中次数)`
+    pub fn access_counts(&self) -> (u64, u64) {
+        (
+            self.hits.load(Ordering::Relaxed),
+            self.misses.load(Ordering::Relaxed),
+        )
+    }
+
+    /// 记录一次读取的命中情况
+    fn record_access(&self, hit: bool) {
+        let counter = if hit { &self.hits } else { &self.misses };
+        counter.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// 调整当前生效的容量
+    ///
+    /// 底层缓存的最大容量在构建后不能修改，因此收缩时立即淘汰超出新容量的条目（不区分新旧），
+    /// 之后每次写入同样淘汰超出的条目，条目数不会再增长到构建时的最大容量
+    ///
+    /// # 参数
+    ///
+    /// * `capacity` - 新容量，超过构建时的最大容量时取最大容量
+    ///
+    /// # 返回值
+    ///
+    /// 返回实际生效的容量
+    pub async fn set_capacity(&self, capacity: u64) -> u64 {
+        let capacity = capacity.min(self.max_capacity());
+        self.capacity.store(capacity, Ordering::Relaxed);
+        self.trim_to_capacity(None).await;
+        capacity
+    }
+
+    /// 淘汰超出当前生效容量的条目
+    ///
+    /// 当前容量等于构建时的最大容量时由底层缓存负责淘汰，直接返回
+    ///
+    /// # 参数
+    ///
+    /// * `keep` - 不参与淘汰的键，写入后调用时为刚写入的键
+    async fn trim_to_capacity(&self, keep: Option<&[u8]>) {
+        let capacity = self.capacity();
+        if capacity >= self.max_capacity() {
+            return;
+        }
+
+        let excess = self.len().await.saturating_sub(capacity);
+        if excess == 0 {
+            return;
+        }
+        let victims: Vec<Arc<Vec<u8>>> = self
+            .iter()
+            .map(|(key, _)| key)
+            .filter(|key| Some(key.as_slice()) != keep)
+            .take(excess as usize)
+            .collect();
+        for key in victims {
+            self.shard(&key).invalidate(key.as_slice()).await;
+        }
+        debug!("L1 trim: capacity={}, trimmed={}", capacity, excess);
+    }
+
+    /// 获取写入时未指定TTL所使用的过期时间（秒）
+    pub fn default_ttl(&self) -> u64 {
+        self.default_ttl.unwrap_or(DEFAULT_L1_TTL_SECS)
+    }
+
     /// 获取带有元数据的缓存值
     ///
     /// # 参数
@@ -45,21 +329,23 @@ impl L1Backend {
     /// 返回缓存值和版本号的元组，如果不存在则返回None
     #[instrument(skip(self), level = "debug")]
     pub async fn get_with_metadata(&self, key: &str) -> Result<Option<(Vec<u8>, u64)>> {
-        let result = self.cache.get(key).await;
+        let result = self.shard(key.as_bytes()).get(key.as_bytes()).await;
         match result {
             Some((bytes, version, expire_at)) => {
                 if let Some(expire_time) = expire_at {
                     if Instant::now() >= expire_time {
-                        self.cache.remove(key).await;
-                        debug!("L1 get_with_metadata: key={}, expired=true, removed", key);
+                        debug!("L1 get_with_metadata: key={}, expired=true", key);
+                        self.record_access(false);
                         return Ok(None);
                     }
                 }
                 debug!("L1 get_with_metadata: key={}, found=true", key);
+                self.record_access(true);
                 Ok(Some((bytes, version)))
             }
             None => {
                 debug!("L1 get_with_metadata: key={}, found=false", key);
+                self.record_access(false);
                 Ok(None)
             }
         }
@@ -76,21 +362,74 @@ impl L1Backend {
     /// 返回缓存值，如果不存在则返回None
     #[instrument(skip(self), level = "debug")]
     pub async fn get_bytes(&self, key: &str) -> Result<Option<Vec<u8>>> {
-        let result = self.cache.get(key).await;
+        let result = self.shard(key.as_bytes()).get(key.as_bytes()).await;
         match result {
             Some((bytes, _, expire_at)) => {
                 if let Some(expire_time) = expire_at {
                     if Instant::now() >= expire_time {
-                        self.cache.remove(key).await;
-                        debug!("L1 get_bytes: key={}, expired=true, removed", key);
+                        debug!("L1 get_bytes: key={}, expired=true", key);
+                        self.record_access(false);
                         return Ok(None);
                     }
                 }
                 debug!("L1 get_bytes: key={}, found=true", key);
+                self.record_access(true);
                 Ok(Some(bytes))
             }
             None => {
                 debug!("L1 get_bytes: key={}, found=false", key);
+                self.record_access(false);
+                Ok(None)
+            }
+        }
+    }
+
+    /// 以二进制原始键获取缓存值
+    ///
+    /// 原始键不要求是合法的UTF-8，与相同字节的字符串键指向同一条目
+    ///
+    /// # 参数
+    ///
+    /// * `key` - 原始键
+    ///
+    /// # 返回值
+    ///
+    /// 返回缓存值，如果不存在或已过期则返回None
+    #[instrument(skip(self), level = "debug")]
+    pub async fn get_bytes_by_raw(&self, key: &[u8]) -> Result<Option<Vec<u8>>> {
+        let result = self.shard(key).get(key).await;
+        let found = result.and_then(|(bytes, _, expire_at)| {
+            (!expire_at.is_some_and(|at| Instant::now() >= at)).then_some(bytes)
+        });
+        debug!(
+            "L1 get_bytes_by_raw: key_len={}, found={}",
+            key.len(),
+            found.is_some()
+        );
+        self.record_access(found.is_some());
+        Ok(found)
+    }
+
+    /// 获取缓存值，允许返回已过期但仍在保留期内的数据
+    ///
+    /// # 参数
+    ///
+    /// * `key` - 缓存键
+    ///
+    /// # 返回值
+    ///
+    /// 返回缓存值及其是否已过期，如果不存在则返回None
+    #[instrument(skip(self), level = "debug")]
+    pub async fn get_allow_stale(&self, key: &str) -> Result<Option<(Vec<u8>, bool)>> {
+        let result = self.shard(key.as_bytes()).get(key.as_bytes()).await;
+        match result {
+            Some((bytes, _, expire_at)) => {
+                let was_stale = expire_at.is_some_and(|at| Instant::now() >= at);
+                debug!("L1 get_allow_stale: key={}, stale={}", key, was_stale);
+                Ok(Some((bytes, was_stale)))
+            }
+            None => {
+                debug!("L1 get_allow_stale: key={}, found=false", key);
                 Ok(None)
             }
         }
@@ -102,7 +441,8 @@ impl L1Backend {
     ///
     /// * `key` - 缓存键
     /// * `value` - 缓存值（字节数组）
-    /// * `ttl` - 过期时间（秒），None表示使用默认值300秒
+    /// * `ttl` - 过期时间（秒），None表示使用配置的默认TTL（未配置时为300秒），
+    ///   [`PERSISTENT_TTL`](crate::backend::PERSISTENT_TTL) 表示永不过期
     ///
     /// # 返回值
     ///
@@ -115,17 +455,75 @@ impl L1Backend {
             value.len(),
             ttl
         );
-        self.set_with_metadata(key, value, ttl.unwrap_or(300), 0)
+        self.set_with_metadata(key, value, ttl.unwrap_or(self.default_ttl()), 0)
             .await
     }
 
+    /// 以毫秒精度的TTL设置缓存值
+    ///
+    /// # 参数
+    ///
+    /// * `key` - 缓存键
+    /// * `value` - 缓存值（字节数组）
+    /// * `ttl` - 过期时间
+    ///
+    /// # 返回值
+    ///
+    /// 返回操作结果
+    #[instrument(skip(self, value), level = "debug")]
+    pub async fn set_bytes_ms(&self, key: &str, value: Vec<u8>, ttl: Duration) -> Result<()> {
+        debug!(
+            "L1 set_bytes_ms: key={}, value_len={}, ttl={:?}",
+            key,
+            value.len(),
+            ttl
+        );
+        self.shard(key.as_bytes())
+            .insert(
+                key.as_bytes().to_vec(),
+                (value, 0, Some(Instant::now() + ttl)),
+            )
+            .await;
+        self.trim_to_capacity(Some(key.as_bytes())).await;
+        Ok(())
+    }
+
+    /// 以二进制原始键设置缓存值
+    ///
+    /// # 参数
+    ///
+    /// * `key` - 原始键
+    /// * `value` - 缓存值（字节数组）
+    /// * `ttl` - 过期时间（秒），None表示使用配置的默认TTL，
+    ///   [`PERSISTENT_TTL`](crate::backend::PERSISTENT_TTL) 表示永不过期
+    ///
+    /// # 返回值
+    ///
+    /// 返回操作结果
+    #[instrument(skip(self, value), level = "debug", fields(value_len = value.len()))]
+    pub async fn set_bytes_by_raw(
+        &self,
+        key: &[u8],
+        value: Vec<u8>,
+        ttl: Option<u64>,
+    ) -> Result<()> {
+        let ttl = ttl.unwrap_or(self.default_ttl());
+        let expire_at = (ttl != crate::backend::PERSISTENT_TTL)
+            .then(|| Instant::now() + Duration::from_secs(ttl));
+        self.shard(key)
+            .insert(key.to_vec(), (value, 0, expire_at))
+            .await;
+        self.trim_to_capacity(Some(key)).await;
+        Ok(())
+    }
+
     /// 设置带有元数据的缓存值
     ///
     /// # 参数
     ///
     /// * `key` - 缓存键
     /// * `value` - 缓存值（字节数组）
-    /// * `ttl` - 过期时间（秒）
+    /// * `ttl` - 过期时间（秒），0表示不过期（仅受容量淘汰影响）
     /// * `version` - 版本号
     ///
     /// # 返回值
@@ -151,13 +549,50 @@ impl L1Backend {
         } else {
             None
         };
-        self.cache
-            .insert(key.to_string(), (value, version, expire_at))
+        self.shard(key.as_bytes())
+            .insert(key.as_bytes().to_vec(), (value, version, expire_at))
             .await;
+        self.trim_to_capacity(Some(key.as_bytes())).await;
         debug!("L1 set_with_metadata: key={} 插入完成", key);
         Ok(())
     }
 
+    /// 刷新缓存项的过期时间
+    ///
+    /// 原子地替换条目的过期时间，值与版本号保持不变，不需要重新序列化。
+    /// 已过期（处于保留期）的条目视为不存在
+    ///
+    /// # 参数
+    ///
+    /// * `key` - 缓存键
+    /// * `ttl` - 新的过期时间（秒），[`PERSISTENT_TTL`](crate::backend::PERSISTENT_TTL) 表示永不过期
+    ///
+    /// # 返回值
+    ///
+    /// 键存在并已刷新时返回true，否则返回false
+    #[instrument(skip(self), level = "debug")]
+    pub async fn touch(&self, key: &str, ttl: u64) -> Result<bool> {
+        let expire_at = (ttl != crate::backend::PERSISTENT_TTL)
+            .then(|| Instant::now() + Duration::from_secs(ttl));
+        let result = self
+            .shard(key.as_bytes())
+            .entry(key.as_bytes().to_vec())
+            .and_compute_with(|entry| async move {
+                match entry.map(|entry| entry.into_value()) {
+                    Some((bytes, version, old_expire_at))
+                        if !old_expire_at.is_some_and(|at| Instant::now() >= at) =>
+                    {
+                        Op::Put((bytes, version, expire_at))
+                    }
+                    _ => Op::Nop,
+                }
+            })
+            .await;
+        let touched = matches!(result, CompResult::ReplacedWith(_));
+        debug!("L1 touch: key={}, ttl={}, touched={}", key, ttl, touched);
+        Ok(touched)
+    }
+
     /// 删除缓存项
     ///
     /// # 参数
@@ -170,7 +605,7 @@ impl L1Backend {
     #[instrument(skip(self), level = "debug")]
     pub async fn delete(&self, key: &str) -> Result<()> {
         debug!("L1 delete: key={}", key);
-        self.cache.remove(key).await;
+        self.shard(key.as_bytes()).remove(key.as_bytes()).await;
         debug!("L1 delete: key={} 删除完成", key);
         Ok(())
     }
@@ -183,8 +618,232 @@ impl L1Backend {
     #[instrument(skip(self), level = "debug")]
     pub fn clear(&self) -> Result<()> {
         debug!("L1 clear: 清空所有缓存项");
-        self.cache.invalidate_all();
+        for shard in &self.shards {
+            shard.invalidate_all();
+        }
         debug!("L1 clear: 缓存已清空");
         Ok(())
     }
+
+    /// 获取当前缓存条目数
+    ///
+    /// 先处理挂起的维护任务（淘汰、失效），保证计数反映最新状态
+    ///
+    /// # 返回值
+    ///
+    /// 返回所有分片的条目数之和
+    pub async fn len(&self) -> u64 {
+        self.run_pending_tasks().await;
+        self.shards.iter().map(|shard| shard.entry_count()).sum()
+    }
+
+    /// 判断缓存是否为空
+    pub async fn is_empty(&self) -> bool {
+        self.len().await == 0
+    }
+
+    /// 获取缓存占用的近似内存大小（字节）
+    ///
+    /// 按键和值的字节长度累加估算，不包含缓存内部结构的开销
+    ///
+    /// # 返回值
+    ///
+    /// 返回近似字节数
+    pub async fn weighted_size(&self) -> u64 {
+        self.run_pending_tasks().await;
+        self.iter()
+            .map(|(key, entry)| (key.len() + entry.0.len()) as u64)
+            .sum()
+    }
+}
+
+/// 计算键所在的分片下标
+///
+/// 使用FNV-1a哈希，结果不依赖进程的随机种子，同一个键总是落在同一个分片
+///
+/// # 参数
+///
+/// * `key` - 键的字节
+/// * `shard_count` - 分片数，必须是2的幂
+///
+/// # 返回值
+///
+/// 返回分片下标
+fn shard_index(key: &[u8], shard_count: usize) -> usize {
+    if shard_count == 1 {
+        return 0;
+    }
+    (fnv1a_64(key) as usize) & (shard_count - 1)
+}
+
+/// 调优器在两个周期之间保存的状态
+struct TunerState {
+    /// 上次调优的时间
+    last_tick: Instant,
+    /// 上次调优时的累计命中次数
+    hits: u64,
+    /// 上次调优时的累计未命中次数
+    misses: u64,
+}
+
+/// L1容量自适应调优器
+///
+/// 每个周期按本周期的命中率与内存压力信号调整 [`L1Backend`] 的当前容量：
+/// 处于内存压力时按 `step_ratio` 收缩，直到 `min_capacity`；
+/// 无压力且命中率低于 `target_hit_ratio` 时按同样的幅度扩容，直到 `max_capacity`；
+/// 其余情况保持不变。调优器只持有后端的弱引用，后端被释放后后台任务自动退出
+pub struct L1AutoTuner {
+    l1: Weak<L1Backend>,
+    config: L1AutoTuneConfig,
+    pressure: Option<MemoryPressureSignal>,
+    clock: Arc<dyn Clock>,
+    service_name: Option<String>,
+    state: Mutex<TunerState>,
+}
+
+impl L1AutoTuner {
+    /// 创建L1容量自适应调优器
+    ///
+    /// # 参数
+    ///
+    /// * `l1` - 被调优的L1缓存后端
+    /// * `config` - 调优配置
+    ///
+    /// # 返回值
+    ///
+    /// 返回新的调优器，默认使用系统时钟且没有内存压力信号
+    pub fn new(l1: &Arc<L1Backend>, config: L1AutoTuneConfig) -> Self {
+        let clock: Arc<dyn Clock> = Arc::new(SystemClock);
+        let (hits, misses) = l1.access_counts();
+        Self {
+            l1: Arc::downgrade(l1),
+            config,
+            pressure: None,
+            state: Mutex::new(TunerState {
+                last_tick: clock.now(),
+                hits,
+                misses,
+            }),
+            clock,
+            service_name: None,
+        }
+    }
+
+    /// 设置内存压力信号
+    ///
+    /// # 参数
+    ///
+    /// * `pressure` - 宿主提供的内存压力信号
+    ///
+    /// # 返回值
+    ///
+    /// 返回设置了内存压力信号的调优器
+    pub fn with_pressure_signal(mut self, pressure: MemoryPressureSignal) -> Self {
+        self.pressure = Some(pressure);
+        self
+    }
+
+    /// 设置判断调优周期使用的时钟
+    ///
+    /// # 参数
+    ///
+    /// * `clock` - 时钟，测试中可注入 [`MockClock`](crate::utils::clock::MockClock)
+    ///
+    /// # 返回值
+    ///
+    /// 返回设置了时钟的调优器
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.state
+            .get_mut()
+            .unwrap_or_else(|e| e.into_inner())
+            .last_tick = clock.now();
+        self.clock = clock;
+        self
+    }
+
+    /// 设置服务名称，设置后每次调优把当前容量记录到 `l1_capacity` 指标
+    ///
+    /// # 参数
+    ///
+    /// * `service_name` - 服务名称
+    ///
+    /// # 返回值
+    ///
+    /// 返回设置了服务名称的调优器
+    pub fn with_service_name(mut self, service_name: String) -> Self {
+        self.service_name = Some(service_name);
+        self
+    }
+
+    /// 执行一次调优
+    ///
+    /// 距上次调优不足 `interval_secs`（按注入的时钟判断）时不做任何调整
+    ///
+    /// # 返回值
+    ///
+    /// 执行了调优时返回调整后的容量；周期未到或后端已释放时返回None
+    pub async fn tick(&self) -> Option<u64> {
+        let l1 = self.l1.upgrade()?;
+        let now = self.clock.now();
+        let (hits, misses) = l1.access_counts();
+        let hit_ratio = {
+            let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+            if now < state.last_tick + Duration::from_secs(self.config.interval_secs) {
+                return None;
+            }
+            let (period_hits, period_misses) = (hits - state.hits, misses - state.misses);
+            *state = TunerState {
+                last_tick: now,
+                hits,
+                misses,
+            };
+            let total = period_hits + period_misses;
+            (total > 0).then(|| period_hits as f64 / total as f64)
+        };
+
+        let under_pressure = self.pressure.as_ref().is_some_and(|pressure| pressure());
+        let current = l1.capacity();
+        let step = ((current as f64 * self.config.step_ratio) as u64).max(1);
+        let target = if under_pressure {
+            current.saturating_sub(step).max(self.config.min_capacity)
+        } else if hit_ratio.is_some_and(|ratio| ratio < self.config.target_hit_ratio) {
+            current.saturating_add(step).min(self.config.max_capacity)
+        } else {
+            current
+        };
+
+        let capacity = if target == current {
+            current
+        } else {
+            let capacity = l1.set_capacity(target).await;
+            info!(
+                "L1 auto-tune: capacity {} -> {} (hit_ratio={:?}, under_pressure={})",
+                current, capacity, hit_ratio, under_pressure
+            );
+            capacity
+        };
+        if let Some(service) = &self.service_name {
+            GLOBAL_METRICS.set_l1_capacity(service, capacity);
+        }
+        Some(capacity)
+    }
+
+    /// 在后台按 `interval_secs` 周期执行调优，后端被释放后任务退出
+    ///
+    /// # 返回值
+    ///
+    /// 返回后台任务句柄
+    pub fn spawn(self) -> JoinHandle<()> {
+        if let (Some(service), Some(l1)) = (&self.service_name, self.l1.upgrade()) {
+            GLOBAL_METRICS.set_l1_capacity(service, l1.capacity());
+        }
+        let interval = Duration::from_secs(self.config.interval_secs);
+        let service = self.service_name.clone().unwrap_or_default();
+        spawn_named("l1-autotune", &service, async move {
+            while self.l1.strong_count() > 0 {
+                tokio::time::sleep(interval).await;
+                self.tick().await;
+            }
+        })
+    }
 }
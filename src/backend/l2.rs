@@ -4,14 +4,160 @@
 //!
 //! 该模块定义了L2缓存后端的实现，基于Redis的分布式缓存。
 
+use crate::backend::compression;
+#[cfg(any(test, feature = "test-util"))]
+use crate::backend::in_memory::InMemoryStore;
 use crate::backend::redis_provider::{DefaultRedisProvider, RedisProvider};
-use crate::config::{L2Config, RedisMode};
+use crate::backend::retry::retry_with_backoff;
+use crate::backend::sharding::HashRing;
+use crate::config::{CompressionCodec, HealthConfig, L2Config, RedisMode, RetryConfig};
 use crate::error::{CacheError, Result};
 use dashmap::DashMap;
+use futures::{Stream, TryStreamExt};
 use redis::{aio::ConnectionManager, AsyncCommands, Client};
+use secrecy::ExposeSecret;
+use std::collections::HashMap;
+use std::future::Future;
 use std::sync::Arc;
+use std::time::Duration;
 use tracing::{debug, instrument, warn};
 
+/// 写入时未指定TTL所使用的默认过期时间（秒）
+pub const DEFAULT_L2_TTL_SECS: u64 = 3600;
+
+/// `clear` 与 `scan_keys` 每次 SCAN 迭代建议返回的键数量
+const SCAN_BATCH_COUNT: usize = 500;
+
+/// 获取集群中所有主节点的地址
+///
+/// 未指定响应策略时，多节点路由的结果以 `host:port` 为键返回，据此解析主节点地址
+///
+/// # 参数
+///
+/// * `conn` - 集群连接
+///
+/// # 返回值
+///
+/// 返回 `(host, port)` 列表
+async fn cluster_master_addresses(
+    conn: &mut redis::cluster_async::ClusterConnection,
+) -> Result<Vec<(String, u16)>> {
+    use redis::cluster_routing::{MultipleNodeRoutingInfo, RoutingInfo};
+
+    let masters = conn
+        .route_command(
+            &redis::cmd("PING"),
+            RoutingInfo::MultiNode((MultipleNodeRoutingInfo::AllMasters, None)),
+        )
+        .await?;
+    match masters {
+        redis::Value::Map(entries) => Ok(entries
+            .iter()
+            .filter_map(|(addr, _)| {
+                let addr: String = redis::from_redis_value(addr).ok()?;
+                let (host, port) = addr.rsplit_once(':')?;
+                Some((host.to_string(), port.parse().ok()?))
+            })
+            .collect()),
+        other => Err(CacheError::BackendError(format!(
+            "Unexpected cluster master list: {:?}",
+            other
+        ))),
+    }
+}
+
+/// 集群中一段连续槽位及其所在主节点
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ClusterSlotRange {
+    start: u16,
+    end: u16,
+    host: String,
+    port: u16,
+}
+
+/// 通过 `CLUSTER SLOTS` 获取槽位到主节点的映射
+///
+/// # 参数
+///
+/// * `conn` - 集群连接
+///
+/// # 返回值
+///
+/// 返回槽位区间列表
+async fn cluster_slot_ranges(
+    conn: &mut redis::cluster_async::ClusterConnection,
+) -> Result<Vec<ClusterSlotRange>> {
+    let value: redis::Value = redis::cmd("CLUSTER").arg("SLOTS").query_async(conn).await?;
+    parse_cluster_slots(&value)
+}
+
+/// 解析 `CLUSTER SLOTS` 的响应，每项为 `[start, end, [host, port, ...], 副本...]`
+fn parse_cluster_slots(value: &redis::Value) -> Result<Vec<ClusterSlotRange>> {
+    let invalid =
+        || CacheError::BackendError(format!("Unexpected CLUSTER SLOTS reply: {:?}", value));
+    let redis::Value::Array(entries) = value else {
+        return Err(invalid());
+    };
+    entries
+        .iter()
+        .map(|entry| {
+            let redis::Value::Array(fields) = entry else {
+                return Err(invalid());
+            };
+            let (Some(start), Some(end), Some(redis::Value::Array(master))) =
+                (fields.first(), fields.get(1), fields.get(2))
+            else {
+                return Err(invalid());
+            };
+            let (Some(host), Some(port)) = (master.first(), master.get(1)) else {
+                return Err(invalid());
+            };
+            Ok(ClusterSlotRange {
+                start: redis::from_redis_value(start)?,
+                end: redis::from_redis_value(end)?,
+                host: redis::from_redis_value(host)?,
+                port: redis::from_redis_value(port)?,
+            })
+        })
+        .collect()
+}
+
+/// 主节点地址 `(host, port)` 及分配到该节点的键下标
+type NodeKeyGroup = ((String, u16), Vec<usize>);
+
+/// 值及其版本号
+type VersionedValue = (Vec<u8>, u64);
+
+/// 按键所在槽位的主节点对键分组
+///
+/// # 参数
+///
+/// * `keys` - 缓存键列表
+/// * `ranges` - 槽位区间列表
+///
+/// # 返回值
+///
+/// 返回 `((host, port), 键在输入中的下标)` 列表，节点顺序按首次出现排列；
+/// 存在未被任何区间覆盖的槽位时返回错误
+fn group_keys_by_node(keys: &[&str], ranges: &[ClusterSlotRange]) -> Result<Vec<NodeKeyGroup>> {
+    let mut groups: Vec<NodeKeyGroup> = Vec::new();
+    for (index, key) in keys.iter().enumerate() {
+        let slot = redis::cluster_routing::get_slot(key.as_bytes());
+        let range = ranges
+            .iter()
+            .find(|range| range.start <= slot && slot <= range.end)
+            .ok_or_else(|| {
+                CacheError::BackendError(format!("Cluster slot {} is not served by any node", slot))
+            })?;
+        let node = (range.host.clone(), range.port);
+        match groups.iter_mut().find(|(existing, _)| *existing == node) {
+            Some((_, indices)) => indices.push(index),
+            None => groups.push((node, vec![index])),
+        }
+    }
+    Ok(groups)
+}
+
 /// 验证Redis缓存键是否安全
 /// 防止Redis命令注入和协议污染攻击
 ///
@@ -94,22 +240,362 @@ fn ensure_safe_key(key: &str) -> Result<&str> {
     Ok(key)
 }
 
+/// 校验Redis连接字符串格式
+///
+/// 在建立连接前检查协议、主机和端口，便于尽早发现配置错误。
+/// 错误信息中不包含连接字符串本身，避免密码泄露到日志。
+///
+/// # 参数
+/// * `connection_string` - Redis连接字符串
+///
+/// # 返回值
+/// * `Ok(())` - 格式有效
+/// * `Err(CacheError::Configuration)` - 格式无效
+fn validate_redis_connection_string(connection_string: &str) -> Result<()> {
+    let invalid = |reason: String| {
+        CacheError::Configuration(format!("Invalid Redis connection string: {}", reason))
+    };
+
+    let s = connection_string.trim();
+    if s.is_empty() {
+        return Err(invalid("connection string is empty".to_string()));
+    }
+
+    let (scheme, rest) = s
+        .split_once("://")
+        .ok_or_else(|| invalid("missing scheme, expected 'redis://' or 'rediss://'".to_string()))?;
+
+    match scheme.to_ascii_lowercase().as_str() {
+        "redis" | "rediss" => {}
+        "unix" | "redis+unix" => {
+            if rest.is_empty() {
+                return Err(invalid("missing unix socket path".to_string()));
+            }
+            return Ok(());
+        }
+        other => {
+            return Err(invalid(format!(
+                "unsupported scheme '{}', expected 'redis', 'rediss', 'unix' or 'redis+unix'",
+                other
+            )))
+        }
+    }
+
+    // 取出 authority 部分并去掉用户信息
+    let authority = rest.split(['/', '?', '#']).next().unwrap_or_default();
+    let host_port = authority
+        .rsplit_once('@')
+        .map_or(authority, |(_, host_port)| host_port);
+
+    let (host, port) = if let Some(ipv6) = host_port.strip_prefix('[') {
+        let (host, tail) = ipv6
+            .split_once(']')
+            .ok_or_else(|| invalid("unterminated IPv6 host".to_string()))?;
+        let port = match tail {
+            "" => None,
+            _ => Some(
+                tail.strip_prefix(':')
+                    .ok_or_else(|| invalid("unexpected characters after IPv6 host".to_string()))?,
+            ),
+        };
+        (host, port)
+    } else {
+        match host_port.split_once(':') {
+            Some((host, port)) => (host, Some(port)),
+            None => (host_port, None),
+        }
+    };
+
+    if host.is_empty() {
+        return Err(invalid("missing host".to_string()));
+    }
+
+    if let Some(port) = port {
+        match port.parse::<u16>() {
+            Ok(p) if p != 0 => {}
+            _ => {
+                return Err(invalid(format!(
+                    "invalid port '{}', expected a number between 1 and 65535",
+                    port
+                )))
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// 解析 `INFO` 命令输出为键值对
+///
+/// 忽略空行与以 `#` 开头的分区标题行
+fn parse_info(raw: &str) -> HashMap<String, String> {
+    raw.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(|line| line.split_once(':'))
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect()
+}
+
+/// 被改名的Redis命令映射
+///
+/// 安全加固的部署会用 `rename-command` 把 `SCAN` 等命令改成随机名称，
+/// 扫描与清理时按该映射构建命令，未配置别名的命令使用原名
+#[derive(Clone, Debug, Default)]
+pub struct CommandAliases(Arc<HashMap<String, String>>);
+
+impl CommandAliases {
+    /// 根据配置创建命令映射，原命令名不区分大小写
+    fn new(aliases: &HashMap<String, String>) -> Self {
+        Self(Arc::new(
+            aliases
+                .iter()
+                .map(|(name, alias)| (name.to_ascii_uppercase(), alias.clone()))
+                .collect(),
+        ))
+    }
+
+    /// 构建命令，配置了别名时使用别名
+    ///
+    /// # 参数
+    ///
+    /// * `name` - 大写的原命令名，如 `SCAN`
+    fn cmd(&self, name: &str) -> redis::Cmd {
+        redis::cmd(self.0.get(name).map_or(name, String::as_str))
+    }
+}
+
+/// 在单个节点上执行一次 `SCAN ... MATCH` 迭代
+///
+/// # 参数
+///
+/// * `conn` - 节点连接
+/// * `aliases` - 命令别名
+/// * `cursor` - 游标，0表示开始新的遍历
+/// * `pattern` - Redis glob 模式
+/// * `count` - 建议本次返回的键数量
+///
+/// # 返回值
+///
+/// 返回下一个游标（0表示遍历结束）和本次匹配的键
+async fn scan_node_page(
+    conn: &mut (impl redis::aio::ConnectionLike + Send),
+    aliases: &CommandAliases,
+    cursor: u64,
+    pattern: &str,
+    count: usize,
+) -> Result<(u64, Vec<String>)> {
+    Ok(aliases
+        .cmd("SCAN")
+        .arg(cursor)
+        .arg("MATCH")
+        .arg(pattern)
+        .arg("COUNT")
+        .arg(count)
+        .query_async(conn)
+        .await?)
+}
+
+/// 分页扫描的游标状态
+#[derive(Default)]
+struct ScanState {
+    /// 待扫描的节点，首次拉取时确定；集群模式下为主节点地址
+    targets: Option<Vec<Option<(String, u16)>>>,
+    /// 当前节点下标
+    node: usize,
+    /// 当前节点上的游标
+    cursor: u64,
+    /// 已扫描但尚未返回的键，不超过两页
+    pending: Vec<String>,
+}
+
+/// 在单个节点上分批 `SCAN` 并 `UNLINK` 匹配的键
+///
+/// # 参数
+///
+/// * `conn` - 节点连接
+/// * `aliases` - 命令别名
+/// * `pattern` - Redis glob 模式
+///
+/// # 返回值
+///
+/// 返回删除的键数量
+async fn clear_node(
+    conn: &mut (impl redis::aio::ConnectionLike + Send),
+    aliases: &CommandAliases,
+    pattern: &str,
+) -> Result<usize> {
+    let mut removed = 0usize;
+    let mut cursor = 0u64;
+    loop {
+        let (next_cursor, keys) =
+            scan_node_page(conn, aliases, cursor, pattern, SCAN_BATCH_COUNT).await?;
+
+        if !keys.is_empty() {
+            let mut pipe = redis::pipe();
+            for key in &keys {
+                let mut unlink = aliases.cmd("UNLINK");
+                unlink.arg(key);
+                pipe.add_command(unlink).ignore();
+            }
+            pipe.query_async::<()>(conn).await?;
+            removed += keys.len();
+        }
+
+        cursor = next_cursor;
+        if cursor == 0 {
+            break;
+        }
+    }
+    Ok(removed)
+}
+
+/// 释放锁的Lua脚本：值匹配时才删除键
+pub(crate) const UNLOCK_SCRIPT: &str = r#"
+            if redis.call("get", KEYS[1]) == ARGV[1] then
+                return redis.call("del", KEYS[1])
+            else
+                return 0
+            end
+            "#;
+
+/// 读取值及其版本号的Lua脚本，版本键不存在时版本号为0
+pub(crate) const GET_WITH_VERSION_SCRIPT: &str = r#"
+            local val = redis.call('GET', KEYS[1])
+            if not val then
+                return nil
+            end
+            local ver = redis.call('GET', KEYS[1] .. ':version')
+            if not ver then
+                ver = "0"
+            end
+            return {val, ver}
+            "#;
+
+/// 原子写入值并递增版本号的Lua脚本，TTL为0时持久化写入
+pub(crate) const SET_WITH_VERSION_SCRIPT: &str = r#"
+            if tonumber(ARGV[2]) == 0 then
+                redis.call('SET', KEYS[1], ARGV[1])
+                redis.call('INCR', KEYS[1] .. ':version')
+                redis.call('PERSIST', KEYS[1] .. ':version')
+            else
+                redis.call('SET', KEYS[1], ARGV[1], 'EX', ARGV[2])
+                redis.call('INCR', KEYS[1] .. ':version')
+                redis.call('EXPIRE', KEYS[1] .. ':version', ARGV[2])
+            end
+            return 1
+            "#;
+
+/// 原子读取旧值并写入新值的Lua脚本（不修改版本键）
+pub(crate) const GET_SET_SCRIPT: &str = r#"
+            local previous = redis.call('GET', KEYS[1])
+            if tonumber(ARGV[2]) == 0 then
+                redis.call('SET', KEYS[1], ARGV[1])
+            else
+                redis.call('SET', KEYS[1], ARGV[1], 'EX', ARGV[2])
+            end
+            return previous
+            "#;
+
+/// 原子读取并删除值及其版本键的Lua脚本（等价于 `GETDEL`，不依赖Redis 6.2+）
+pub(crate) const TAKE_SCRIPT: &str = r#"
+            local val = redis.call('GET', KEYS[1])
+            if val then
+                redis.call('DEL', KEYS[1])
+                redis.call('DEL', KEYS[1] .. ':version')
+            end
+            return val
+            "#;
+
+/// 带上限检查的自增Lua脚本
+///
+/// `ARGV[1]` 为增量，`ARGV[2]` 为当前值允许的最大值（即上限减去增量，空字符串表示不检查）。
+/// 比较按十进制字符串进行，避免Lua双精度数在接近 `i64::MAX` 时丢失精度。
+/// 返回 `{1, 新值}`，越过上限或溢出时返回 `{0, 当前值}` 且不修改键
+pub(crate) const INCR_BY_CHECKED_SCRIPT: &str = r#"
+            local function greater(a, b)
+                local a_neg, b_neg = a:sub(1, 1) == '-', b:sub(1, 1) == '-'
+                if a_neg ~= b_neg then
+                    return b_neg
+                end
+                if #a ~= #b then
+                    return (#a > #b) ~= a_neg
+                end
+                return a ~= b and ((a > b) ~= a_neg)
+            end
+            local current = redis.call('GET', KEYS[1]) or '0'
+            if not string.match(current, '^-?%d+$') then
+                return redis.error_reply('ERR value is not an integer or out of range')
+            end
+            if ARGV[2] ~= '' and greater(current, ARGV[2]) then
+                return {0, current}
+            end
+            local result = redis.pcall('INCRBY', KEYS[1], ARGV[1])
+            if type(result) == 'table' and result.err then
+                if string.find(result.err, 'overflow', 1, true) then
+                    return {0, current}
+                end
+                return result
+            end
+            return {1, redis.call('GET', KEYS[1])}
+            "#;
+
 /// L2缓存后端实现
 ///
 /// 基于Redis的分布式缓存实现
 #[derive(Clone)]
 pub enum L2Backend {
     Standalone {
-        client: Client,
+        client: Box<Client>,
         manager: ConnectionManager,
         read_manager: Box<Option<ConnectionManager>>,
         command_timeout_ms: u64,
+        retry: RetryConfig,
+        health: HealthConfig,
+        versioning: bool,
         version_cache: Arc<DashMap<String, u64>>,
+        compression: Option<CompressionCodec>,
+        default_ttl: u64,
+        aliases: CommandAliases,
     },
     Cluster {
         client: redis::cluster::ClusterClient,
         command_timeout_ms: u64,
+        retry: RetryConfig,
+        health: HealthConfig,
+        versioning: bool,
         version_cache: Arc<DashMap<String, u64>>,
+        compression: Option<CompressionCodec>,
+        default_ttl: u64,
+        aliases: CommandAliases,
+    },
+    /// 客户端分片：多个独立的单机实例，键按一致性哈希路由到节点。
+    /// 批量操作按节点分组执行，不支持跨节点事务
+    Sharded {
+        clients: Arc<Vec<Client>>,
+        managers: Arc<Vec<ConnectionManager>>,
+        ring: Arc<HashRing>,
+        command_timeout_ms: u64,
+        retry: RetryConfig,
+        health: HealthConfig,
+        versioning: bool,
+        version_cache: Arc<DashMap<String, u64>>,
+        compression: Option<CompressionCodec>,
+        default_ttl: u64,
+        aliases: CommandAliases,
+    },
+    /// 测试用的内存存储，命令在进程内执行，过期时间由注入的时钟决定
+    #[cfg(any(test, feature = "test-util"))]
+    InMemory {
+        store: Arc<InMemoryStore>,
+        command_timeout_ms: u64,
+        retry: RetryConfig,
+        health: HealthConfig,
+        versioning: bool,
+        version_cache: Arc<DashMap<String, u64>>,
+        compression: Option<CompressionCodec>,
+        default_ttl: u64,
+        aliases: CommandAliases,
     },
 }
 
@@ -118,6 +604,11 @@ impl std::fmt::Debug for L2Backend {
         match self {
             Self::Standalone { .. } => write!(f, "L2Backend::Standalone"),
             Self::Cluster { .. } => write!(f, "L2Backend::Cluster"),
+            Self::Sharded { managers, .. } => {
+                write!(f, "L2Backend::Sharded({} nodes)", managers.len())
+            }
+            #[cfg(any(test, feature = "test-util"))]
+            Self::InMemory { .. } => write!(f, "L2Backend::InMemory"),
         }
     }
 }
@@ -132,9 +623,132 @@ impl L2Backend {
             L2Backend::Cluster {
                 command_timeout_ms, ..
             } => *command_timeout_ms,
+            L2Backend::Sharded {
+                command_timeout_ms, ..
+            } => *command_timeout_ms,
+            #[cfg(any(test, feature = "test-util"))]
+            L2Backend::InMemory {
+                command_timeout_ms, ..
+            } => *command_timeout_ms,
+        }
+    }
+
+    /// 获取瞬时故障重试配置
+    pub fn retry_config(&self) -> &RetryConfig {
+        match self {
+            L2Backend::Standalone { retry, .. } => retry,
+            L2Backend::Cluster { retry, .. } => retry,
+            L2Backend::Sharded { retry, .. } => retry,
+            #[cfg(any(test, feature = "test-util"))]
+            L2Backend::InMemory { retry, .. } => retry,
+        }
+    }
+
+    /// 获取健康检查探测配置
+    pub fn health_config(&self) -> &HealthConfig {
+        match self {
+            L2Backend::Standalone { health, .. } => health,
+            L2Backend::Cluster { health, .. } => health,
+            L2Backend::Sharded { health, .. } => health,
+            #[cfg(any(test, feature = "test-util"))]
+            L2Backend::InMemory { health, .. } => health,
+        }
+    }
+
+    /// 是否维护 `:version` 版本键
+    pub fn versioning_enabled(&self) -> bool {
+        match self {
+            L2Backend::Standalone { versioning, .. } => *versioning,
+            L2Backend::Cluster { versioning, .. } => *versioning,
+            L2Backend::Sharded { versioning, .. } => *versioning,
+            #[cfg(any(test, feature = "test-util"))]
+            L2Backend::InMemory { versioning, .. } => *versioning,
+        }
+    }
+
+    /// 值的压缩编码，None表示不写入编码字节
+    pub fn compression(&self) -> Option<CompressionCodec> {
+        match self {
+            L2Backend::Standalone { compression, .. } => *compression,
+            L2Backend::Cluster { compression, .. } => *compression,
+            L2Backend::Sharded { compression, .. } => *compression,
+            #[cfg(any(test, feature = "test-util"))]
+            L2Backend::InMemory { compression, .. } => *compression,
+        }
+    }
+
+    /// 获取写入时未指定TTL所使用的过期时间（秒）
+    pub fn default_ttl(&self) -> u64 {
+        match self {
+            L2Backend::Standalone { default_ttl, .. } => *default_ttl,
+            L2Backend::Cluster { default_ttl, .. } => *default_ttl,
+            L2Backend::Sharded { default_ttl, .. } => *default_ttl,
+            #[cfg(any(test, feature = "test-util"))]
+            L2Backend::InMemory { default_ttl, .. } => *default_ttl,
+        }
+    }
+
+    /// 被改名的Redis命令映射
+    fn aliases(&self) -> &CommandAliases {
+        match self {
+            L2Backend::Standalone { aliases, .. } => aliases,
+            L2Backend::Cluster { aliases, .. } => aliases,
+            L2Backend::Sharded { aliases, .. } => aliases,
+            #[cfg(any(test, feature = "test-util"))]
+            L2Backend::InMemory { aliases, .. } => aliases,
+        }
+    }
+
+    /// 按配置的压缩编码为写入的值加上帧头部
+    fn encode_value(&self, value: Vec<u8>) -> Result<Vec<u8>> {
+        match self.compression() {
+            Some(codec) => compression::encode_frame(codec, &value),
+            None => Ok(value),
+        }
+    }
+
+    /// 按帧头部的编码字节还原读取的值
+    fn decode_value(&self, value: Vec<u8>) -> Result<Vec<u8>> {
+        match self.compression() {
+            Some(_) => compression::decode_frame(&value),
+            None => Ok(value),
+        }
+    }
+
+    /// 本地版本号缓存
+    fn version_cache(&self) -> &DashMap<String, u64> {
+        match self {
+            L2Backend::Standalone { version_cache, .. } => version_cache,
+            L2Backend::Cluster { version_cache, .. } => version_cache,
+            L2Backend::Sharded { version_cache, .. } => version_cache,
+            #[cfg(any(test, feature = "test-util"))]
+            L2Backend::InMemory { version_cache, .. } => version_cache,
         }
     }
 
+    /// 获取键所属分片节点的连接管理器
+    fn shard_manager(
+        managers: &[ConnectionManager],
+        ring: &HashRing,
+        key: &(impl AsRef<[u8]> + ?Sized),
+    ) -> ConnectionManager {
+        managers[ring.node_for(key)].clone()
+    }
+
+    /// 按重试配置执行单条命令，总耗时不超过命令超时时间
+    async fn with_retry<T, F, Fut>(&self, op: F) -> Result<T>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        retry_with_backoff(
+            self.retry_config(),
+            std::time::Duration::from_millis(self.command_timeout_ms()),
+            op,
+        )
+        .await
+    }
+
     /// 创建新的L2缓存后端实例
     ///
     /// # 参数
@@ -164,16 +778,32 @@ impl L2Backend {
         config: &L2Config,
         provider: Arc<dyn RedisProvider>,
     ) -> Result<Self> {
+        let backend = Self::connect(config, provider).await?;
+        if config.eager_connect {
+            backend.warm_up_connections().await?;
+        }
+        Ok(backend)
+    }
+
+    /// 按配置的Redis模式创建后端，不发送任何命令
+    async fn connect(config: &L2Config, provider: Arc<dyn RedisProvider>) -> Result<Self> {
         debug!("Initializing L2Backend with mode: {:?}", config.mode);
         match config.mode {
             RedisMode::Standalone => {
+                validate_redis_connection_string(config.connection_string.expose_secret())?;
                 let (client, manager) = provider.get_standalone_client(config).await?;
                 Ok(L2Backend::Standalone {
-                    client,
+                    client: Box::new(client),
                     manager,
                     read_manager: Box::new(None),
                     command_timeout_ms: config.command_timeout_ms,
+                    retry: config.retry.clone(),
+                    health: config.health.clone(),
+                    versioning: config.enable_versioning,
                     version_cache: Arc::new(DashMap::new()),
+                    compression: config.compression,
+                    default_ttl: config.default_ttl.unwrap_or(DEFAULT_L2_TTL_SECS),
+                    aliases: CommandAliases::new(&config.command_aliases),
                 })
             }
             RedisMode::Cluster => {
@@ -181,22 +811,85 @@ impl L2Backend {
                 Ok(L2Backend::Cluster {
                     client,
                     command_timeout_ms: config.command_timeout_ms,
+                    retry: config.retry.clone(),
+                    health: config.health.clone(),
+                    versioning: config.enable_versioning,
                     version_cache: Arc::new(DashMap::new()),
+                    compression: config.compression,
+                    default_ttl: config.default_ttl.unwrap_or(DEFAULT_L2_TTL_SECS),
+                    aliases: CommandAliases::new(&config.command_aliases),
                 })
             }
             RedisMode::Sentinel => {
                 let (client, manager, read_manager) = provider.get_sentinel_client(config).await?;
                 Ok(L2Backend::Standalone {
-                    client,
+                    client: Box::new(client),
                     manager,
                     read_manager: Box::new(read_manager),
                     command_timeout_ms: config.command_timeout_ms,
+                    retry: config.retry.clone(),
+                    health: config.health.clone(),
+                    versioning: config.enable_versioning,
+                    version_cache: Arc::new(DashMap::new()),
+                    compression: config.compression,
+                    default_ttl: config.default_ttl.unwrap_or(DEFAULT_L2_TTL_SECS),
+                    aliases: CommandAliases::new(&config.command_aliases),
+                })
+            }
+            RedisMode::Sharded => {
+                let sharded = config.sharded.as_ref().ok_or_else(|| {
+                    CacheError::Configuration(
+                        "Sharded mode requires sharded configuration".to_string(),
+                    )
+                })?;
+                if sharded.nodes.is_empty() {
+                    return Err(CacheError::Configuration(
+                        "Sharded mode requires at least one node".to_string(),
+                    ));
+                }
+                for node in &sharded.nodes {
+                    validate_redis_connection_string(node)?;
+                }
+                let (clients, managers) = provider
+                    .get_sharded_clients(config)
+                    .await?
+                    .into_iter()
+                    .unzip();
+                Ok(L2Backend::Sharded {
+                    clients: Arc::new(clients),
+                    managers: Arc::new(managers),
+                    ring: Arc::new(HashRing::new(&sharded.nodes, sharded.virtual_nodes)),
+                    command_timeout_ms: config.command_timeout_ms,
+                    retry: config.retry.clone(),
+                    health: config.health.clone(),
+                    versioning: config.enable_versioning,
                     version_cache: Arc::new(DashMap::new()),
+                    compression: config.compression,
+                    default_ttl: config.default_ttl.unwrap_or(DEFAULT_L2_TTL_SECS),
+                    aliases: CommandAliases::new(&config.command_aliases),
                 })
             }
         }
     }
 
+    /// 预先建立到所有节点（包括读副本）的连接
+    ///
+    /// 集群客户端按需建连，单机与分片模式的连接管理器在断线后也会延迟重连，
+    /// 通过 `PING` 确保首个用户请求不承担建连延迟
+    async fn warm_up_connections(&self) -> Result<()> {
+        let start = std::time::Instant::now();
+        self.ping().await?;
+        if let L2Backend::Standalone { read_manager, .. } = self {
+            if let Some(read_manager) = read_manager.as_ref() {
+                redis::cmd("PING")
+                    .query_async::<String>(&mut read_manager.clone())
+                    .await?;
+            }
+        }
+        debug!("L2 connections warmed up in {:?}", start.elapsed());
+        Ok(())
+    }
+
     #[cfg(test)]
     pub async fn new_failing(config: &L2Config) -> Result<Self> {
         use redis::ConnectionAddr;
@@ -216,17 +909,61 @@ impl L2Backend {
 
         let manager = ConnectionManager::new(client.clone())
             .await
-            .map_err(CacheError::RedisError)?;
+            .map_err(CacheError::from)?;
 
         Ok(L2Backend::Standalone {
-            client,
+            client: Box::new(client),
             manager,
             read_manager: Box::new(None),
             command_timeout_ms: config.command_timeout_ms,
+            retry: config.retry.clone(),
+            health: config.health.clone(),
+            versioning: config.enable_versioning,
             version_cache: Arc::new(DashMap::new()),
+            compression: config.compression,
+            default_ttl: config.default_ttl.unwrap_or(DEFAULT_L2_TTL_SECS),
+            aliases: CommandAliases::new(&config.command_aliases),
         })
     }
 
+    /// 创建基于内存存储的L2后端，仅用于测试
+    ///
+    /// 命令在进程内执行，不连接Redis；TTL按传入的时钟计算，
+    /// 配合 [`MockClock`](crate::utils::clock::MockClock) 可以在测试中模拟过期。
+    /// 不支持失效通知的发布/订阅
+    ///
+    /// # 参数
+    ///
+    /// * `config` - L2缓存配置，仅使用超时、重试、健康检查、版本键与压缩设置（忽略命令别名）
+    /// * `clock` - 判断过期时间使用的时钟
+    ///
+    /// # 返回值
+    ///
+    /// 返回内存L2后端实例
+    #[cfg(any(test, feature = "test-util"))]
+    pub fn in_memory(config: &L2Config, clock: Arc<dyn crate::utils::clock::Clock>) -> Self {
+        L2Backend::InMemory {
+            store: Arc::new(InMemoryStore::new(clock)),
+            command_timeout_ms: config.command_timeout_ms,
+            retry: config.retry.clone(),
+            health: config.health.clone(),
+            versioning: config.enable_versioning,
+            version_cache: Arc::new(DashMap::new()),
+            compression: config.compression,
+            default_ttl: config.default_ttl.unwrap_or(DEFAULT_L2_TTL_SECS),
+            aliases: CommandAliases::default(),
+        }
+    }
+
+    /// 内存后端使用的存储，其余模式返回None
+    #[cfg(any(test, feature = "test-util"))]
+    pub fn in_memory_store(&self) -> Option<&Arc<InMemoryStore>> {
+        match self {
+            L2Backend::InMemory { store, .. } => Some(store),
+            _ => None,
+        }
+    }
+
     /// 尝试获取分布式锁
     ///
     /// 使用 SET NX PX 实现
@@ -277,6 +1014,43 @@ impl L2Backend {
                 );
                 Ok(result.is_some())
             }
+            L2Backend::Sharded { managers, ring, .. } => {
+                let mut conn = Self::shard_manager(managers, ring, key);
+                let result: Option<String> = redis::cmd("SET")
+                    .arg(key)
+                    .arg(value)
+                    .arg("NX")
+                    .arg("PX")
+                    .arg(ttl_ms)
+                    .query_async(&mut conn)
+                    .await
+                    .map_err(|e| CacheError::BackendError(e.to_string()))?;
+                debug!(
+                    "Lock acquisition result: success={}, result={:?}",
+                    result.is_some(),
+                    result
+                );
+                Ok(result.is_some())
+            }
+            #[cfg(any(test, feature = "test-util"))]
+            L2Backend::InMemory { store, .. } => {
+                let mut conn = store.connection();
+                let result: Option<String> = redis::cmd("SET")
+                    .arg(key)
+                    .arg(value)
+                    .arg("NX")
+                    .arg("PX")
+                    .arg(ttl_ms)
+                    .query_async(&mut conn)
+                    .await
+                    .map_err(|e| CacheError::BackendError(e.to_string()))?;
+                debug!(
+                    "Lock acquisition result: success={}, result={:?}",
+                    result.is_some(),
+                    result
+                );
+                Ok(result.is_some())
+            }
         }
     }
 
@@ -285,15 +1059,7 @@ impl L2Backend {
     /// 使用 Lua 脚本保证原子性
     #[instrument(skip(self), level = "debug")]
     pub async fn unlock(&self, key: &str, value: &str) -> Result<bool> {
-        let script = redis::Script::new(
-            r#"
-            if redis.call("get", KEYS[1]) == ARGV[1] then
-                return redis.call("del", KEYS[1])
-            else
-                return 0
-            end
-            "#,
-        );
+        let script = redis::Script::new(UNLOCK_SCRIPT);
 
         match self {
             L2Backend::Standalone { manager, .. } => {
@@ -319,118 +1085,201 @@ impl L2Backend {
                     .map_err(|e| CacheError::BackendError(e.to_string()))?;
                 Ok(result == 1)
             }
-        }
-    }
-
-    /// 获取带版本号的缓存值
-    ///
-    /// # 参数
-    ///
+            L2Backend::Sharded { managers, ring, .. } => {
+                let mut conn = Self::shard_manager(managers, ring, key);
+                let result: i32 = script
+                    .key(key)
+                    .arg(value)
+                    .invoke_async(&mut conn)
+                    .await
+                    .map_err(|e| CacheError::BackendError(e.to_string()))?;
+                Ok(result == 1)
+            }
+            #[cfg(any(test, feature = "test-util"))]
+            L2Backend::InMemory { store, .. } => {
+                let mut conn = store.connection();
+                let result: i32 = script
+                    .key(key)
+                    .arg(value)
+                    .invoke_async(&mut conn)
+                    .await
+                    .map_err(|e| CacheError::BackendError(e.to_string()))?;
+                Ok(result == 1)
+            }
+        }
+    }
+
+    /// 获取带版本号的缓存值
+    ///
+    /// # 参数
+    ///
     /// * `key` - 缓存键
     ///
     /// # 返回值
     ///
-    /// 返回缓存值和版本号的元组，如果不存在则返回None
+    /// 返回缓存值和版本号的元组，如果不存在则返回None；未启用版本键时版本号恒为0
     #[instrument(skip(self), level = "debug")]
     pub async fn get_with_version(&self, key: &str) -> Result<Option<(Vec<u8>, u64)>> {
+        if !self.versioning_enabled() {
+            return self.read_value(key.as_bytes()).await;
+        }
+
         // 先尝试从缓存获取版本号（无锁读取）
-        let _cached_version = match self {
-            L2Backend::Standalone { version_cache, .. } => {
-                version_cache.get(key).map(|v| *v.value())
+        let _cached_version = self.version_cache().get(key).map(|v| *v.value());
+
+        let result = self.read_value(key.as_bytes()).await?;
+        if let Some((_, version)) = &result {
+            // 更新缓存（无锁写入）
+            let version_cache = self.version_cache();
+            // 使用 LRU 策略：如果缓存超过 10000，移除 1000 个最旧的条目
+            if version_cache.len() > 10000 {
+                let mut to_remove = Vec::new();
+                for entry in version_cache.iter() {
+                    to_remove.push(entry.key().clone());
+                    if to_remove.len() >= 1000 {
+                        break;
+                    }
+                }
+                for key in to_remove {
+                    version_cache.remove(&key);
+                }
             }
-            L2Backend::Cluster { version_cache, .. } => version_cache.get(key).map(|v| *v.value()),
-        };
-
-        let script = redis::Script::new(
-            r#"
-            local val = redis.call('GET', KEYS[1])
-            if not val then
-                return nil
-            end
-            local ver = redis.call('GET', KEYS[1] .. ':version')
-            if not ver then
-                ver = "0"
-            end
-            return {val, ver}
-            "#,
-        );
+            version_cache.insert(key.to_string(), *version);
+        }
+        Ok(result)
+    }
 
-        let result: Option<(Vec<u8>, String)> = match self {
-            L2Backend::Standalone {
-                manager,
-                read_manager,
-                ..
-            } => {
-                let mut conn = if let Some(rm) = read_manager.as_ref() {
-                    rm.clone()
-                } else {
-                    manager.clone()
-                };
-                script.key(key).invoke_async(&mut conn).await?
-            }
-            L2Backend::Cluster { client, .. } => {
-                script
-                    .key(key)
-                    .invoke_async(&mut client.get_async_connection().await?)
-                    .await?
-            }
-        };
+    /// 读取值及其版本号，不更新本地版本缓存
+    ///
+    /// 键按字节处理，字符串键与二进制原始键共用；未启用版本键时使用普通的 `GET`，版本号恒为0
+    async fn read_value(&self, key: &[u8]) -> Result<Option<(Vec<u8>, u64)>> {
+        if !self.versioning_enabled() {
+            return match self.get_plain(key).await? {
+                Some(value) => Ok(Some((self.decode_value(value)?, 0))),
+                None => Ok(None),
+            };
+        }
 
-        match result {
-            Some((v, s)) => {
-                let version = s.parse().unwrap_or(0);
-                // 更新缓存（无锁写入）
-                match self {
-                    L2Backend::Standalone { version_cache, .. } => {
-                        // 使用 LRU 策略：如果缓存超过 10000，移除 1000 个最旧的条目
-                        if version_cache.len() > 10000 {
-                            let mut to_remove = Vec::new();
-                            for entry in version_cache.iter() {
-                                to_remove.push(entry.key().clone());
-                                if to_remove.len() >= 1000 {
-                                    break;
-                                }
-                            }
-                            for key in to_remove {
-                                version_cache.remove(&key);
-                            }
-                        }
-                        version_cache.insert(key.to_string(), version);
+        let script = redis::Script::new(GET_WITH_VERSION_SCRIPT);
+
+        let script = &script;
+        let result: Option<(Vec<u8>, String)> = self
+            .with_retry(|| async move {
+                Ok(match self {
+                    L2Backend::Standalone {
+                        manager,
+                        read_manager,
+                        ..
+                    } => {
+                        let mut conn = if let Some(rm) = read_manager.as_ref() {
+                            rm.clone()
+                        } else {
+                            manager.clone()
+                        };
+                        script.key(key).invoke_async(&mut conn).await?
                     }
-                    L2Backend::Cluster { version_cache, .. } => {
-                        // 使用 LRU 策略：如果缓存超过 10000，移除 1000 个最旧的条目
-                        if version_cache.len() > 10000 {
-                            let mut to_remove = Vec::new();
-                            for entry in version_cache.iter() {
-                                to_remove.push(entry.key().clone());
-                                if to_remove.len() >= 1000 {
-                                    break;
-                                }
-                            }
-                            for key in to_remove {
-                                version_cache.remove(&key);
-                            }
-                        }
-                        version_cache.insert(key.to_string(), version);
+                    L2Backend::Cluster { client, .. } => {
+                        script
+                            .key(key)
+                            .invoke_async(&mut client.get_async_connection().await?)
+                            .await?
                     }
-                }
-                Ok(Some((v, version)))
-            }
+                    L2Backend::Sharded { managers, ring, .. } => {
+                        script
+                            .key(key)
+                            .invoke_async(&mut Self::shard_manager(managers, ring, key))
+                            .await?
+                    }
+                    #[cfg(any(test, feature = "test-util"))]
+                    L2Backend::InMemory { store, .. } => {
+                        script
+                            .key(key)
+                            .invoke_async(&mut store.connection())
+                            .await?
+                    }
+                })
+            })
+            .await?;
+
+        match result {
+            Some((v, s)) => Ok(Some((self.decode_value(v)?, s.parse().unwrap_or(0)))),
             None => Ok(None),
         }
     }
 
+    /// 不读取版本键的普通 `GET`，有读副本时优先从副本读取
+    async fn get_plain(&self, key: &[u8]) -> Result<Option<Vec<u8>>> {
+        self.with_retry(|| async move {
+            Ok(match self {
+                L2Backend::Standalone {
+                    manager,
+                    read_manager,
+                    ..
+                } => {
+                    let mut conn = read_manager
+                        .as_ref()
+                        .clone()
+                        .unwrap_or_else(|| manager.clone());
+                    conn.get(key).await?
+                }
+                L2Backend::Cluster { client, .. } => {
+                    client.get_async_connection().await?.get(key).await?
+                }
+                L2Backend::Sharded { managers, ring, .. } => {
+                    Self::shard_manager(managers, ring, key).get(key).await?
+                }
+                #[cfg(any(test, feature = "test-util"))]
+                L2Backend::InMemory { store, .. } => store.connection().get(key).await?,
+            })
+        })
+        .await
+    }
+
+    /// 不写入版本键的普通 `SET`，TTL为0时不设置过期时间
+    async fn set_plain(&self, key: &[u8], value: &[u8], ttl: u64) -> Result<()> {
+        self.with_retry(|| async move {
+            let mut cmd = redis::cmd("SET");
+            cmd.arg(key).arg(value);
+            if ttl != crate::backend::PERSISTENT_TTL {
+                cmd.arg("EX").arg(ttl);
+            }
+            match self {
+                L2Backend::Standalone { manager, .. } => {
+                    cmd.query_async::<()>(&mut manager.clone()).await?
+                }
+                L2Backend::Cluster { client, .. } => {
+                    cmd.query_async::<()>(&mut client.get_async_connection().await?)
+                        .await?
+                }
+                L2Backend::Sharded { managers, ring, .. } => {
+                    cmd.query_async::<()>(&mut Self::shard_manager(managers, ring, key))
+                        .await?
+                }
+                #[cfg(any(test, feature = "test-util"))]
+                L2Backend::InMemory { store, .. } => {
+                    cmd.query_async::<()>(&mut store.connection()).await?
+                }
+            }
+            Ok(())
+        })
+        .await
+    }
+
     /// 设置带版本号的缓存值
     ///
     /// # 参数
     ///
     /// * `key` - 缓存键
     /// * `value` - 缓存值（字节数组）
-    /// * `ttl` - 过期时间（秒），None表示使用默认值3600秒
+    /// * `ttl` - 过期时间（秒），None表示使用默认值3600秒，
+    ///   [`PERSISTENT_TTL`](crate::backend::PERSISTENT_TTL) 表示永不过期（值与版本键均不设置过期时间）
     ///
     /// # 返回值
     ///
     /// 返回操作结果
+    ///
+    /// 未启用版本键时退化为普通的 `SET`，不写入 `:version` 键；
+    /// 配置了 [`compression`](Self::compression) 时值以带编码字节的压缩帧存储
     #[instrument(skip(self, value), level = "debug", fields(value_len = value.len()))]
     pub async fn set_with_version(
         &self,
@@ -439,77 +1288,321 @@ impl L2Backend {
         ttl: Option<u64>,
     ) -> Result<()> {
         debug!("Setting key: {} with ttl: {:?}", key, ttl);
-        let ttl = ttl.unwrap_or(3600);
-
-        // Lua脚本用于原子设置+版本递增
-        let script = redis::Script::new(
-            r#"
-            redis.call('SET', KEYS[1], ARGV[1], 'EX', ARGV[2])
-            redis.call('INCR', KEYS[1] .. ':version')
-            redis.call('EXPIRE', KEYS[1] .. ':version', ARGV[2])
-            return 1
-            "#,
-        );
+        self.write_value(key.as_bytes(), value, ttl).await?;
+        if self.versioning_enabled() {
+            self.bump_cached_version(key);
+        }
+        Ok(())
+    }
 
-        let _: i32 = match self {
-            L2Backend::Standalone { manager, .. } => {
-                script
-                    .clone()
-                    .key(key)
-                    .arg(&value)
-                    .arg(ttl)
-                    .invoke_async(&mut manager.clone())
-                    .await?
-            }
-            L2Backend::Cluster { client, .. } => {
-                script
-                    .clone()
-                    .key(key)
-                    .arg(&value)
-                    .arg(ttl)
-                    .invoke_async(&mut client.get_async_connection().await?)
-                    .await?
-            }
-        };
+    /// 写入值并递增版本号，不更新本地版本缓存
+    ///
+    /// 键按字节处理，字符串键与二进制原始键共用；未启用版本键时退化为普通的 `SET`
+    async fn write_value(&self, key: &[u8], value: Vec<u8>, ttl: Option<u64>) -> Result<()> {
+        let ttl = ttl.unwrap_or(self.default_ttl());
+        let value = self.encode_value(value)?;
+        if !self.versioning_enabled() {
+            return self.set_plain(key, &value, ttl).await;
+        }
 
-        // 更新版本缓存（无锁写入）
-        match self {
-            L2Backend::Standalone { version_cache, .. } => {
-                // 使用 LRU 策略：如果缓存超过 10000，移除 1000 个最旧的条目
-                if version_cache.len() > 10000 {
-                    let mut to_remove = Vec::new();
-                    for entry in version_cache.iter() {
-                        to_remove.push(entry.key().clone());
-                        if to_remove.len() >= 1000 {
-                            break;
-                        }
+        // Lua脚本用于原子设置+版本递增，TTL为0时持久化写入
+        let script = redis::Script::new(SET_WITH_VERSION_SCRIPT);
+
+        let (script, value) = (&script, value.as_slice());
+        let _: i32 = self
+            .with_retry(|| async move {
+                Ok(match self {
+                    L2Backend::Standalone { manager, .. } => {
+                        script
+                            .key(key)
+                            .arg(value)
+                            .arg(ttl)
+                            .invoke_async(&mut manager.clone())
+                            .await?
+                    }
+                    L2Backend::Cluster { client, .. } => {
+                        script
+                            .key(key)
+                            .arg(value)
+                            .arg(ttl)
+                            .invoke_async(&mut client.get_async_connection().await?)
+                            .await?
                     }
-                    for key in to_remove {
-                        version_cache.remove(&key);
+                    L2Backend::Sharded { managers, ring, .. } => {
+                        script
+                            .key(key)
+                            .arg(value)
+                            .arg(ttl)
+                            .invoke_async(&mut Self::shard_manager(managers, ring, key))
+                            .await?
+                    }
+                    #[cfg(any(test, feature = "test-util"))]
+                    L2Backend::InMemory { store, .. } => {
+                        script
+                            .key(key)
+                            .arg(value)
+                            .arg(ttl)
+                            .invoke_async(&mut store.connection())
+                            .await?
+                    }
+                })
+            })
+            .await?;
+        Ok(())
+    }
+
+    /// 写入新值并返回旧值
+    ///
+    /// 在事务中执行 `SET key value GET EX ttl`（需要Redis 6.2+），启用版本键时同时递增版本号。
+    /// 旧版本服务器不支持 `GET` 选项，事务中的 `SET` 会以语法错误失败（版本号已在同一事务中递增），
+    /// 此时改用Lua脚本原子地读取旧值并写入新值
+    ///
+    /// # 参数
+    ///
+    /// * `key` - 缓存键
+    /// * `value` - 新值（字节数组）
+    /// * `ttl` - 过期时间（秒），None表示使用默认值3600秒，
+    ///   [`PERSISTENT_TTL`](crate::backend::PERSISTENT_TTL) 表示永不过期
+    ///
+    /// # 返回值
+    ///
+    /// 返回写入前的值，键不存在时返回None
+    #[instrument(skip(self, value), level = "debug", fields(value_len = value.len()))]
+    pub async fn get_set(
+        &self,
+        key: &str,
+        value: Vec<u8>,
+        ttl: Option<u64>,
+    ) -> Result<Option<Vec<u8>>> {
+        let ttl = ttl.unwrap_or(self.default_ttl());
+        let value = self.encode_value(value)?;
+        let versioning = self.versioning_enabled();
+        let version_key = format!("{}:version", key);
+        let (version_key, value) = (version_key.as_str(), value.as_slice());
+
+        let result = self
+            .with_retry(|| async move {
+                let mut pipe = redis::pipe();
+                pipe.atomic();
+                let set = pipe.cmd("SET").arg(key).arg(value).arg("GET");
+                if ttl != crate::backend::PERSISTENT_TTL {
+                    set.arg("EX").arg(ttl);
+                }
+                if versioning {
+                    pipe.incr(version_key, 1).ignore();
+                    if ttl == crate::backend::PERSISTENT_TTL {
+                        pipe.persist(version_key).ignore();
+                    } else {
+                        pipe.expire(version_key, ttl as i64).ignore();
                     }
                 }
-                let new_version = version_cache.get(key).map(|v| *v.value() + 1).unwrap_or(1);
-                version_cache.insert(key.to_string(), new_version);
-            }
-            L2Backend::Cluster { version_cache, .. } => {
-                // 使用 LRU 策略：如果缓存超过 10000，移除 1000 个最旧的条目
-                if version_cache.len() > 10000 {
-                    let mut to_remove = Vec::new();
-                    for entry in version_cache.iter() {
-                        to_remove.push(entry.key().clone());
-                        if to_remove.len() >= 1000 {
-                            break;
-                        }
+                let (previous,): (Option<Vec<u8>>,) = match self {
+                    L2Backend::Standalone { manager, .. } => {
+                        pipe.query_async(&mut manager.clone()).await?
+                    }
+                    L2Backend::Cluster { client, .. } => {
+                        pipe.query_async(&mut client.get_async_connection().await?)
+                            .await?
                     }
-                    for key in to_remove {
-                        version_cache.remove(&key);
+                    L2Backend::Sharded { managers, ring, .. } => {
+                        pipe.query_async(&mut Self::shard_manager(managers, ring, key))
+                            .await?
                     }
+                    #[cfg(any(test, feature = "test-util"))]
+                    L2Backend::InMemory { store, .. } => {
+                        pipe.query_async(&mut store.connection()).await?
+                    }
+                };
+                Ok(previous)
+            })
+            .await;
+
+        let previous = match result {
+            Err(CacheError::RedisError(e))
+                if e.kind() == redis::ErrorKind::ResponseError
+                    && e.to_string().contains("syntax error") =>
+            {
+                debug!("SET ... GET not supported by server, falling back to script");
+                self.get_set_script(key, value, ttl).await?
+            }
+            result => result?,
+        };
+
+        if versioning {
+            self.bump_cached_version(key);
+        }
+        previous.map(|v| self.decode_value(v)).transpose()
+    }
+
+    /// 在键所在的节点上执行命令管道
+    ///
+    /// 供基于L2存储的扩展结构（如共享布隆过滤器）使用，管道中的所有键必须路由到同一节点
+    ///
+    /// # 参数
+    ///
+    /// * `key` - 用于选择节点的键
+    /// * `pipe` - 要执行的命令管道
+    ///
+    /// # 返回值
+    ///
+    /// 返回管道中未忽略命令的结果
+    pub(crate) async fn query_pipeline<T: redis::FromRedisValue>(
+        &self,
+        key: &str,
+        pipe: &redis::Pipeline,
+    ) -> Result<T> {
+        self.with_retry(|| async move {
+            Ok(match self {
+                L2Backend::Standalone { manager, .. } => {
+                    pipe.query_async(&mut manager.clone()).await?
+                }
+                L2Backend::Cluster { client, .. } => {
+                    pipe.query_async(&mut client.get_async_connection().await?)
+                        .await?
+                }
+                L2Backend::Sharded { managers, ring, .. } => {
+                    pipe.query_async(&mut Self::shard_manager(managers, ring, key))
+                        .await?
                 }
-                let new_version = version_cache.get(key).map(|v| *v.value() + 1).unwrap_or(1);
-                version_cache.insert(key.to_string(), new_version);
+                #[cfg(any(test, feature = "test-util"))]
+                L2Backend::InMemory { store, .. } => {
+                    pipe.query_async(&mut store.connection()).await?
+                }
+            })
+        })
+        .await
+    }
+
+    /// 使用Lua脚本原子地读取旧值并写入新值（不修改版本键）
+    async fn get_set_script(&self, key: &str, value: &[u8], ttl: u64) -> Result<Option<Vec<u8>>> {
+        let script = redis::Script::new(GET_SET_SCRIPT);
+
+        let script = &script;
+        self.with_retry(|| async move {
+            Ok(match self {
+                L2Backend::Standalone { manager, .. } => {
+                    script
+                        .key(key)
+                        .arg(value)
+                        .arg(ttl)
+                        .invoke_async(&mut manager.clone())
+                        .await?
+                }
+                L2Backend::Cluster { client, .. } => {
+                    script
+                        .key(key)
+                        .arg(value)
+                        .arg(ttl)
+                        .invoke_async(&mut client.get_async_connection().await?)
+                        .await?
+                }
+                L2Backend::Sharded { managers, ring, .. } => {
+                    script
+                        .key(key)
+                        .arg(value)
+                        .arg(ttl)
+                        .invoke_async(&mut Self::shard_manager(managers, ring, key))
+                        .await?
+                }
+                #[cfg(any(test, feature = "test-util"))]
+                L2Backend::InMemory { store, .. } => {
+                    script
+                        .key(key)
+                        .arg(value)
+                        .arg(ttl)
+                        .invoke_async(&mut store.connection())
+                        .await?
+                }
+            })
+        })
+        .await
+    }
+
+    /// 写入成功后递增本地版本缓存（无锁写入）
+    fn bump_cached_version(&self, key: &str) {
+        let version_cache = self.version_cache();
+        // 使用 LRU 策略：如果缓存超过 10000，移除 1000 个最旧的条目
+        if version_cache.len() > 10000 {
+            let mut to_remove = Vec::new();
+            for entry in version_cache.iter() {
+                to_remove.push(entry.key().clone());
+                if to_remove.len() >= 1000 {
+                    break;
+                }
+            }
+            for key in to_remove {
+                version_cache.remove(&key);
             }
         }
+        let new_version = version_cache.get(key).map(|v| *v.value() + 1).unwrap_or(1);
+        version_cache.insert(key.to_string(), new_version);
+    }
 
+    /// 以毫秒精度的TTL设置缓存值
+    ///
+    /// 使用 `SET ... PX`，适用于亚秒级过期的短期数据；启用版本键时版本键同样以 `PEXPIRE` 过期
+    ///
+    /// # 参数
+    ///
+    /// * `key` - 缓存键
+    /// * `value` - 缓存值（字节数组）
+    /// * `ttl` - 过期时间，按毫秒取整，必须至少为1毫秒
+    ///
+    /// # 返回值
+    ///
+    /// 返回操作结果，TTL不足1毫秒时返回 `CacheError::InvalidInput`
+    #[instrument(skip(self, value), level = "debug", fields(value_len = value.len()))]
+    pub async fn set_bytes_ms(&self, key: &str, value: Vec<u8>, ttl: Duration) -> Result<()> {
+        let ttl_ms = ttl.as_millis() as u64;
+        if ttl_ms == 0 {
+            return Err(CacheError::InvalidInput(
+                "TTL must be at least 1 millisecond".to_string(),
+            ));
+        }
+
+        let value = self.encode_value(value)?;
+        let versioning = self.versioning_enabled();
+        let version_key = format!("{}:version", key);
+        let (version_key, value) = (version_key.as_str(), value.as_slice());
+        self.with_retry(|| async move {
+            let mut pipe = redis::pipe();
+            pipe.atomic()
+                .cmd("SET")
+                .arg(key)
+                .arg(value)
+                .arg("PX")
+                .arg(ttl_ms)
+                .ignore();
+            if versioning {
+                pipe.incr(version_key, 1)
+                    .ignore()
+                    .pexpire(version_key, ttl_ms as i64)
+                    .ignore();
+            }
+            match self {
+                L2Backend::Standalone { manager, .. } => {
+                    pipe.query_async::<()>(&mut manager.clone()).await?
+                }
+                L2Backend::Cluster { client, .. } => {
+                    pipe.query_async::<()>(&mut client.get_async_connection().await?)
+                        .await?
+                }
+                L2Backend::Sharded { managers, ring, .. } => {
+                    pipe.query_async::<()>(&mut Self::shard_manager(managers, ring, key))
+                        .await?
+                }
+                #[cfg(any(test, feature = "test-util"))]
+                L2Backend::InMemory { store, .. } => {
+                    pipe.query_async::<()>(&mut store.connection()).await?
+                }
+            }
+            Ok(())
+        })
+        .await?;
+
+        if versioning {
+            self.bump_cached_version(key);
+        }
         Ok(())
     }
 
@@ -525,41 +1618,379 @@ impl L2Backend {
     #[instrument(skip(self), level = "debug")]
     pub async fn delete(&self, key: &str) -> Result<()> {
         debug!("Deleting key: {}", key);
+        let versioning = self.versioning_enabled();
         let version_key = format!("{}:version", key);
+        let version_key = version_key.as_str();
+        self.with_retry(|| async move {
+            let mut pipe = redis::pipe();
+            pipe.del(key);
+            if versioning {
+                pipe.del(version_key);
+            }
+            match self {
+                L2Backend::Standalone { manager, .. } => {
+                    pipe.query_async::<()>(&mut manager.clone()).await?;
+                }
+                L2Backend::Cluster { client, .. } => {
+                    pipe.query_async::<()>(&mut client.get_async_connection().await?)
+                        .await?;
+                }
+                L2Backend::Sharded { managers, ring, .. } => {
+                    pipe.query_async::<()>(&mut Self::shard_manager(managers, ring, key))
+                        .await?;
+                }
+                #[cfg(any(test, feature = "test-util"))]
+                L2Backend::InMemory { store, .. } => {
+                    pipe.query_async::<()>(&mut store.connection()).await?;
+                }
+            }
+            Ok(())
+        })
+        .await?;
+
+        // 从版本缓存中移除（无锁删除）
+        self.version_cache().remove(key);
+        Ok(())
+    }
+
+    /// 原子地读取并删除缓存项
+    ///
+    /// 使用Lua脚本在同一次调用中读取值并删除数据键与 `:version` 键，
+    /// 并发调用时只有一个调用方能取得值
+    ///
+    /// # 参数
+    ///
+    /// * `key` - 缓存键
+    ///
+    /// # 返回值
+    ///
+    /// 返回删除前的值，键不存在时返回None
+    #[instrument(skip(self), level = "debug")]
+    pub async fn take(&self, key: &str) -> Result<Option<Vec<u8>>> {
+        let script = redis::Script::new(TAKE_SCRIPT);
+
+        let script = &script;
+        let value: Option<Vec<u8>> = self
+            .with_retry(|| async move {
+                Ok(match self {
+                    L2Backend::Standalone { manager, .. } => {
+                        script.key(key).invoke_async(&mut manager.clone()).await?
+                    }
+                    L2Backend::Cluster { client, .. } => {
+                        script
+                            .key(key)
+                            .invoke_async(&mut client.get_async_connection().await?)
+                            .await?
+                    }
+                    L2Backend::Sharded { managers, ring, .. } => {
+                        script
+                            .key(key)
+                            .invoke_async(&mut Self::shard_manager(managers, ring, key))
+                            .await?
+                    }
+                    #[cfg(any(test, feature = "test-util"))]
+                    L2Backend::InMemory { store, .. } => {
+                        script
+                            .key(key)
+                            .invoke_async(&mut store.connection())
+                            .await?
+                    }
+                })
+            })
+            .await?;
+
+        self.version_cache().remove(key);
+        debug!("L2 take: key={}, found={}", key, value.is_some());
+        value.map(|v| self.decode_value(v)).transpose()
+    }
+
+    /// 刷新缓存项的过期时间
+    ///
+    /// 使用 `EXPIRE` 刷新数据键，启用版本键时同时刷新 `:version` 键，值保持不变；
+    /// TTL为 [`PERSISTENT_TTL`](crate::backend::PERSISTENT_TTL) 时改用 `PERSIST` 移除过期时间
+    ///
+    /// # 参数
+    ///
+    /// * `key` - 缓存键
+    /// * `ttl` - 新的过期时间（秒）
+    ///
+    /// # 返回值
+    ///
+    /// 键存在并已刷新时返回true，键不存在时返回false
+    #[instrument(skip(self), level = "debug")]
+    pub async fn touch(&self, key: &str, ttl: u64) -> Result<bool> {
+        let versioning = self.versioning_enabled();
+        let version_key = format!("{}:version", key);
+        let version_key = version_key.as_str();
+        let (touched,): (bool,) = self
+            .with_retry(|| async move {
+                let mut pipe = redis::pipe();
+                if ttl == crate::backend::PERSISTENT_TTL {
+                    // PERSIST 对没有过期时间的键也返回0，因此用 EXISTS 判断键是否存在
+                    pipe.exists(key).persist(key).ignore();
+                    if versioning {
+                        pipe.persist(version_key).ignore();
+                    }
+                } else {
+                    pipe.expire(key, ttl as i64);
+                    if versioning {
+                        pipe.expire(version_key, ttl as i64).ignore();
+                    }
+                }
+                Ok(match self {
+                    L2Backend::Standalone { manager, .. } => {
+                        pipe.query_async(&mut manager.clone()).await?
+                    }
+                    L2Backend::Cluster { client, .. } => {
+                        pipe.query_async(&mut client.get_async_connection().await?)
+                            .await?
+                    }
+                    L2Backend::Sharded { managers, ring, .. } => {
+                        pipe.query_async(&mut Self::shard_manager(managers, ring, key))
+                            .await?
+                    }
+                    #[cfg(any(test, feature = "test-util"))]
+                    L2Backend::InMemory { store, .. } => {
+                        pipe.query_async(&mut store.connection()).await?
+                    }
+                })
+            })
+            .await?;
+        Ok(touched)
+    }
+
+    /// 获取缓存项的剩余生存时间
+    ///
+    /// # 参数
+    ///
+    /// * `key` - 缓存键
+    ///
+    /// # 返回值
+    ///
+    /// 返回剩余生存时间（秒），如果不存在则返回None
+    #[instrument(skip(self), level = "debug")]
+    pub async fn ttl(&self, key: &str) -> Result<Option<u64>> {
+        let ttl: i64 = self
+            .with_retry(|| async move {
+                Ok(match self {
+                    L2Backend::Standalone { manager, .. } => manager.clone().ttl(key).await?,
+                    L2Backend::Cluster { client, .. } => {
+                        client.get_async_connection().await?.ttl(key).await?
+                    }
+                    L2Backend::Sharded { managers, ring, .. } => {
+                        Self::shard_manager(managers, ring, key).ttl(key).await?
+                    }
+                    #[cfg(any(test, feature = "test-util"))]
+                    L2Backend::InMemory { store, .. } => store.connection().ttl(key).await?,
+                })
+            })
+            .await?;
+        if ttl > 0 {
+            Ok(Some(ttl as u64))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// 获取缓存项的剩余生存时间（毫秒精度）
+    ///
+    /// 使用 `PTTL`，不会像 [`ttl`](Self::ttl) 一样把亚秒级的剩余时间舍入为0
+    ///
+    /// # 参数
+    ///
+    /// * `key` - 缓存键
+    ///
+    /// # 返回值
+    ///
+    /// 返回剩余生存时间（毫秒），键不存在或未设置过期时间时返回None
+    #[instrument(skip(self), level = "debug")]
+    pub async fn pttl(&self, key: &str) -> Result<Option<u64>> {
+        let pttl: i64 = self
+            .with_retry(|| async move {
+                Ok(match self {
+                    L2Backend::Standalone { manager, .. } => manager.clone().pttl(key).await?,
+                    L2Backend::Cluster { client, .. } => {
+                        client.get_async_connection().await?.pttl(key).await?
+                    }
+                    L2Backend::Sharded { managers, ring, .. } => {
+                        Self::shard_manager(managers, ring, key).pttl(key).await?
+                    }
+                    #[cfg(any(test, feature = "test-util"))]
+                    L2Backend::InMemory { store, .. } => store.connection().pttl(key).await?,
+                })
+            })
+            .await?;
+        if pttl > 0 {
+            Ok(Some(pttl as u64))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// 检查连接是否正常
+    ///
+    /// # 返回值
+    ///
+    /// 返回操作结果，成功表示连接正常
+    #[instrument(skip(self), level = "debug")]
+    pub async fn ping(&self) -> Result<()> {
         match self {
-            L2Backend::Standalone {
-                manager,
-                version_cache,
-                ..
-            } => {
+            L2Backend::Standalone { manager, .. } => {
+                tracing::debug!("L2Backend ping: 尝试连接Redis...");
                 let mut conn = manager.clone();
-                let _: () = redis::pipe()
-                    .del(key)
-                    .del(&version_key)
-                    .query_async(&mut conn)
+                tracing::debug!("L2Backend ping: 获取连接管理器克隆");
+                match redis::cmd("PING").query_async::<String>(&mut conn).await {
+                    Ok(response) => {
+                        tracing::debug!("L2Backend ping: Redis响应成功: {}", response);
+                        Ok(())
+                    }
+                    Err(e) => {
+                        tracing::debug!("L2Backend ping: Redis连接失败: {}", e);
+                        Err(e.into())
+                    }
+                }
+            }
+            L2Backend::Cluster { client, .. } => {
+                tracing::debug!("L2Backend ping: 尝试连接Redis集群...");
+                match redis::cmd("PING")
+                    .query_async::<String>(&mut client.get_async_connection().await?)
+                    .await
+                {
+                    Ok(response) => {
+                        tracing::debug!("L2Backend ping: Redis集群响应成功: {}", response);
+                        Ok(())
+                    }
+                    Err(e) => {
+                        tracing::debug!("L2Backend ping: Redis集群连接失败: {}", e);
+                        Err(e.into())
+                    }
+                }
+            }
+            L2Backend::Sharded { managers, .. } => {
+                tracing::debug!("L2Backend ping: 尝试连接 {} 个分片节点...", managers.len());
+                // 任一节点不可用即视为连接异常
+                futures::future::try_join_all(managers.iter().map(|manager| {
+                    let mut conn = manager.clone();
+                    async move { redis::cmd("PING").query_async::<String>(&mut conn).await }
+                }))
+                .await?;
+                Ok(())
+            }
+            #[cfg(any(test, feature = "test-util"))]
+            L2Backend::InMemory { store, .. } => {
+                redis::cmd("PING")
+                    .query_async::<String>(&mut store.connection())
                     .await?;
-                // 从版本缓存中移除（无锁删除）
-                version_cache.remove(key);
+                Ok(())
+            }
+        }
+    }
+
+    /// 获取Redis数据库中的键数量
+    ///
+    /// 使用 `DBSIZE` 命令，统计范围为整个Redis数据库（集群模式下为所有主节点之和，分片模式下为所有节点之和），
+    /// 而非单个服务的键
+    ///
+    /// # 返回值
+    ///
+    /// 返回键数量
+    #[instrument(skip(self), level = "debug")]
+    pub async fn dbsize(&self) -> Result<u64> {
+        let size = match self {
+            L2Backend::Standalone { manager, .. } => {
+                let mut conn = manager.clone();
+                redis::cmd("DBSIZE").query_async(&mut conn).await?
+            }
+            L2Backend::Cluster { client, .. } => {
+                let mut conn = client.get_async_connection().await?;
+                redis::cmd("DBSIZE").query_async(&mut conn).await?
+            }
+            L2Backend::Sharded { managers, .. } => {
+                let sizes = futures::future::try_join_all(managers.iter().map(|manager| {
+                    let mut conn = manager.clone();
+                    async move { redis::cmd("DBSIZE").query_async::<u64>(&mut conn).await }
+                }))
+                .await?;
+                sizes.into_iter().sum()
+            }
+            #[cfg(any(test, feature = "test-util"))]
+            L2Backend::InMemory { store, .. } => {
+                redis::cmd("DBSIZE")
+                    .query_async(&mut store.connection())
+                    .await?
+            }
+        };
+        Ok(size)
+    }
+
+    /// 获取Redis服务器信息
+    ///
+    /// 执行 `INFO` 命令并解析为键值对。
+    /// 集群模式和分片模式下命令会发送到所有（主）节点，返回的键以节点地址为前缀，
+    /// 形如 `127.0.0.1:7000/redis_version`，以便区分各节点的统计数据。
+    ///
+    /// # 参数
+    ///
+    /// * `section` - INFO分区名称（如 `memory`、`keyspace`），None表示默认分区
+    ///
+    /// # 返回值
+    ///
+    /// 返回解析后的信息键值对
+    #[instrument(skip(self), level = "debug")]
+    pub async fn info(&self, section: Option<&str>) -> Result<HashMap<String, String>> {
+        let mut cmd = redis::cmd("INFO");
+        if let Some(section) = section {
+            cmd.arg(section);
+        }
+
+        match self {
+            L2Backend::Standalone { manager, .. } => {
+                let raw: String = cmd.query_async(&mut manager.clone()).await?;
+                Ok(parse_info(&raw))
             }
-            L2Backend::Cluster {
-                client,
-                version_cache,
-                ..
-            } => {
+            L2Backend::Cluster { client, .. } => {
                 let mut conn = client.get_async_connection().await?;
-                let _: () = redis::pipe()
-                    .del(key)
-                    .del(&version_key)
-                    .query_async(&mut conn)
-                    .await?;
-                // 从版本缓存中移除（无锁删除）
-                version_cache.remove(key);
+                let per_node: HashMap<String, String> = cmd.query_async(&mut conn).await?;
+                Ok(per_node
+                    .iter()
+                    .flat_map(|(node, raw)| {
+                        parse_info(raw)
+                            .into_iter()
+                            .map(move |(k, v)| (format!("{}/{}", node, k), v))
+                    })
+                    .collect())
+            }
+            L2Backend::Sharded {
+                clients, managers, ..
+            } => {
+                let cmd = &cmd;
+                let per_node = futures::future::try_join_all(managers.iter().map(|manager| {
+                    let mut conn = manager.clone();
+                    async move { cmd.query_async::<String>(&mut conn).await }
+                }))
+                .await?;
+                Ok(clients
+                    .iter()
+                    .zip(per_node)
+                    .flat_map(|(client, raw)| {
+                        let node = client.get_connection_info().addr.to_string();
+                        parse_info(&raw)
+                            .into_iter()
+                            .map(move |(k, v)| (format!("{}/{}", node, k), v))
+                    })
+                    .collect())
+            }
+            #[cfg(any(test, feature = "test-util"))]
+            L2Backend::InMemory { store, .. } => {
+                let raw: String = cmd.query_async(&mut store.connection()).await?;
+                Ok(parse_info(&raw))
             }
         }
-        Ok(())
     }
 
-    /// 获取缓存项的剩余生存时间
+    /// 获取键占用的内存字节数
+    ///
+    /// 使用 `MEMORY USAGE` 命令，结果包含Redis内部的元数据开销
     ///
     /// # 参数
     ///
@@ -567,62 +1998,198 @@ impl L2Backend {
     ///
     /// # 返回值
     ///
-    /// 返回剩余生存时间（秒），如果不存在则返回None
+    /// 返回占用字节数，键不存在时返回0
     #[instrument(skip(self), level = "debug")]
-    pub async fn ttl(&self, key: &str) -> Result<Option<u64>> {
-        let ttl: i64 = match self {
-            L2Backend::Standalone { manager, .. } => manager.clone().ttl(key).await?,
+    pub async fn memory_usage(&self, key: &str) -> Result<u64> {
+        let mut cmd = redis::cmd("MEMORY");
+        cmd.arg("USAGE").arg(key);
+
+        let usage: Option<u64> = match self {
+            L2Backend::Standalone { manager, .. } => cmd.query_async(&mut manager.clone()).await?,
             L2Backend::Cluster { client, .. } => {
-                client.get_async_connection().await?.ttl(key).await?
+                // 子命令 USAGE 会被误当作键参与路由，这里按键所在槽位显式路由到主节点
+                use redis::cluster_routing::{
+                    get_slot, Route, RoutingInfo, SingleNodeRoutingInfo, SlotAddr,
+                };
+                let routing = RoutingInfo::SingleNode(SingleNodeRoutingInfo::SpecificNode(
+                    Route::new(get_slot(key.as_bytes()), SlotAddr::Master),
+                ));
+                let value = client
+                    .get_async_connection()
+                    .await?
+                    .route_command(&cmd, routing)
+                    .await?;
+                redis::from_redis_value(&value)?
+            }
+            L2Backend::Sharded { managers, ring, .. } => {
+                cmd.query_async(&mut Self::shard_manager(managers, ring, key))
+                    .await?
             }
+            #[cfg(any(test, feature = "test-util"))]
+            L2Backend::InMemory { store, .. } => cmd.query_async(&mut store.connection()).await?,
         };
-        if ttl > 0 {
-            Ok(Some(ttl as u64))
-        } else {
-            Ok(None)
-        }
+        Ok(usage.unwrap_or(0))
     }
 
-    /// 检查连接是否正常
+    /// 批量获取缓存值
+    ///
+    /// 单机模式使用一个管道；分片模式按节点分组并发执行；集群模式按槽位所在的
+    /// 主节点分组，每个节点一个管道，避免逐键往返。读取不校验版本号
+    ///
+    /// # 参数
+    ///
+    /// * `keys` - 缓存键列表
     ///
     /// # 返回值
     ///
-    /// 返回操作结果，成功表示连接正常
-    #[instrument(skip(self), level = "debug")]
-    pub async fn ping(&self) -> Result<()> {
-        match self {
-            L2Backend::Standalone { manager, .. } => {
-                tracing::debug!("L2Backend ping: 尝试连接Redis...");
-                let mut conn = manager.clone();
-                tracing::debug!("L2Backend ping: 获取连接管理器克隆");
-                match redis::cmd("PING").query_async::<String>(&mut conn).await {
-                    Ok(response) => {
-                        tracing::debug!("L2Backend ping: Redis响应成功: {}", response);
-                        Ok(())
+    /// 按输入顺序返回各键的值，不存在的键为None
+    #[instrument(skip(self, keys), level = "debug", fields(key_count = keys.len()))]
+    pub async fn get_many_bytes(&self, keys: &[&str]) -> Result<Vec<Option<Vec<u8>>>> {
+        self.get_many_raw(keys)
+            .await?
+            .into_iter()
+            .map(|value| value.map(|value| self.decode_value(value)).transpose())
+            .collect()
+    }
+
+    /// 批量获取缓存值及其版本号
+    ///
+    /// 与 [`get_many_bytes`](Self::get_many_bytes) 使用相同的批量读取，同时读取各键的
+    /// `:version` 键；未启用版本键或版本键不存在时版本号为0
+    ///
+    /// # 参数
+    ///
+    /// * `keys` - 缓存键列表
+    ///
+    /// # 返回值
+    ///
+    /// 按输入顺序返回各键的值与版本号，不存在的键为None
+    #[instrument(skip(self, keys), level = "debug", fields(key_count = keys.len()))]
+    pub async fn get_many_with_version(
+        &self,
+        keys: &[&str],
+    ) -> Result<Vec<Option<VersionedValue>>> {
+        if !self.versioning_enabled() {
+            let values = self.get_many_bytes(keys).await?;
+            return Ok(values
+                .into_iter()
+                .map(|value| value.map(|value| (value, 0)))
+                .collect());
+        }
+
+        let version_keys: Vec<String> = keys.iter().map(|key| format!("{}:version", key)).collect();
+        let interleaved: Vec<&str> = keys
+            .iter()
+            .zip(&version_keys)
+            .flat_map(|(key, version_key)| [*key, version_key.as_str()])
+            .collect();
+        let mut replies = self.get_many_raw(&interleaved).await?.into_iter();
+        let mut values = Vec::with_capacity(keys.len());
+        while let (Some(value), Some(version)) = (replies.next(), replies.next()) {
+            let version = version
+                .and_then(|version| String::from_utf8(version).ok())
+                .and_then(|version| version.parse().ok())
+                .unwrap_or(0);
+            values.push(match value {
+                Some(value) => Some((self.decode_value(value)?, version)),
+                None => None,
+            });
+        }
+        Ok(values)
+    }
+
+    /// 批量读取原始值，不解码压缩帧
+    async fn get_many_raw(&self, keys: &[&str]) -> Result<Vec<Option<Vec<u8>>>> {
+        if keys.is_empty() {
+            return Ok(Vec::new());
+        }
+        self.with_retry(|| async move {
+            match self {
+                L2Backend::Standalone {
+                    manager,
+                    read_manager,
+                    ..
+                } => {
+                    let mut conn = read_manager
+                        .as_ref()
+                        .clone()
+                        .unwrap_or_else(|| manager.clone());
+                    let mut pipe = redis::pipe();
+                    for key in keys {
+                        pipe.get(*key);
                     }
-                    Err(e) => {
-                        tracing::debug!("L2Backend ping: Redis连接失败: {}", e);
-                        Err(e.into())
+                    Ok(pipe.query_async(&mut conn).await?)
+                }
+                L2Backend::Cluster { client, .. } => {
+                    let mut conn = client.get_async_connection().await?;
+                    let ranges = cluster_slot_ranges(&mut conn).await?;
+                    let groups = group_keys_by_node(keys, &ranges)?;
+                    let replies = futures::future::try_join_all(groups.iter().map(
+                        |((host, port), indices)| {
+                            let mut conn = conn.clone();
+                            let mut pipe = redis::pipe();
+                            for index in indices {
+                                pipe.get(keys[*index]);
+                            }
+                            let route = redis::cluster_routing::SingleNodeRoutingInfo::ByAddress {
+                                host: host.clone(),
+                                port: *port,
+                            };
+                            async move { conn.route_pipeline(&pipe, 0, indices.len(), route).await }
+                        },
+                    ))
+                    .await?;
+                    let mut values = vec![None; keys.len()];
+                    for ((_, indices), reply) in groups.iter().zip(replies) {
+                        for (index, value) in indices.iter().zip(reply) {
+                            values[*index] = redis::from_redis_value(&value)?;
+                        }
                     }
+                    Ok(values)
                 }
-            }
-            L2Backend::Cluster { client, .. } => {
-                tracing::debug!("L2Backend ping: 尝试连接Redis集群...");
-                match redis::cmd("PING")
-                    .query_async::<String>(&mut client.get_async_connection().await?)
-                    .await
-                {
-                    Ok(response) => {
-                        tracing::debug!("L2Backend ping: Redis集群响应成功: {}", response);
-                        Ok(())
+                L2Backend::Sharded { managers, ring, .. } => {
+                    let mut groups: Vec<Vec<usize>> = vec![Vec::new(); managers.len()];
+                    for (index, key) in keys.iter().enumerate() {
+                        groups[ring.node_for(key)].push(index);
                     }
-                    Err(e) => {
-                        tracing::debug!("L2Backend ping: Redis集群连接失败: {}", e);
-                        Err(e.into())
+                    let replies = futures::future::try_join_all(
+                        groups
+                            .iter()
+                            .zip(managers.iter())
+                            .filter(|(indices, _)| !indices.is_empty())
+                            .map(|(indices, manager)| {
+                                let mut conn = manager.clone();
+                                let mut pipe = redis::pipe();
+                                for index in indices {
+                                    pipe.get(keys[*index]);
+                                }
+                                async move {
+                                    let values: Vec<Option<Vec<u8>>> =
+                                        pipe.query_async(&mut conn).await?;
+                                    Ok::<_, CacheError>((indices, values))
+                                }
+                            }),
+                    )
+                    .await?;
+                    let mut values = vec![None; keys.len()];
+                    for (indices, reply) in replies {
+                        for (index, value) in indices.iter().zip(reply) {
+                            values[*index] = value;
+                        }
+                    }
+                    Ok(values)
+                }
+                #[cfg(any(test, feature = "test-util"))]
+                L2Backend::InMemory { store, .. } => {
+                    let mut pipe = redis::pipe();
+                    for key in keys {
+                        pipe.get(*key);
                     }
+                    Ok(pipe.query_async(&mut store.connection()).await?)
                 }
             }
-        }
+        })
+        .await
     }
 
     /// 批量设置缓存项
@@ -640,26 +2207,30 @@ impl L2Backend {
         items: Vec<(String, Vec<u8>, Option<u64>)>,
     ) -> Result<()> {
         debug!("Pipeline batch set with {} items", items.len());
-        let mut pipe = redis::pipe();
+        let versioning = self.versioning_enabled();
+        let mut pipes = self.batch_pipelines();
 
         for (key, value, ttl) in items {
-            let ttl = ttl.unwrap_or(3600);
-            let ttl_i64 = ttl.try_into().unwrap_or(3600);
-            pipe.set(&key, value).arg("EX").arg(ttl_i64).ignore();
-            pipe.incr(format!("{}:version", key), 1).ignore();
-            pipe.expire(format!("{}:version", key), ttl_i64).ignore();
-        }
-
-        match self {
-            L2Backend::Standalone { manager, .. } => {
-                pipe.query_async::<()>(&mut manager.clone()).await?;
+            let value = self.encode_value(value)?;
+            let pipe = &mut pipes[self.pipeline_index(&key)];
+            let ttl = ttl.unwrap_or(self.default_ttl());
+            if ttl == crate::backend::PERSISTENT_TTL {
+                pipe.set(&key, value).ignore();
+                if versioning {
+                    pipe.incr(format!("{}:version", key), 1).ignore();
+                    pipe.persist(format!("{}:version", key)).ignore();
+                }
+                continue;
             }
-            L2Backend::Cluster { client, .. } => {
-                pipe.query_async::<()>(&mut client.get_async_connection().await?)
-                    .await?;
+            let ttl_i64 = ttl.try_into().unwrap_or(DEFAULT_L2_TTL_SECS as i64);
+            pipe.set(&key, value).arg("EX").arg(ttl_i64).ignore();
+            if versioning {
+                pipe.incr(format!("{}:version", key), 1).ignore();
+                pipe.expire(format!("{}:version", key), ttl_i64).ignore();
             }
         }
-        Ok(())
+
+        self.query_pipelines(pipes).await
     }
 
     /// 批量删除缓存项
@@ -674,23 +2245,18 @@ impl L2Backend {
     #[instrument(skip(self, keys), level = "debug", fields(key_count = keys.len()))]
     pub async fn pipeline_del_batch(&self, keys: Vec<String>) -> Result<()> {
         debug!("Pipeline batch delete with {} keys", keys.len());
-        let mut pipe = redis::pipe();
+        let versioning = self.versioning_enabled();
+        let mut pipes = self.batch_pipelines();
 
         for key in keys {
+            let pipe = &mut pipes[self.pipeline_index(&key)];
             pipe.del(&key).ignore();
-            pipe.del(format!("{}:version", key)).ignore();
-        }
-
-        match self {
-            L2Backend::Standalone { manager, .. } => {
-                pipe.query_async::<()>(&mut manager.clone()).await?;
-            }
-            L2Backend::Cluster { client, .. } => {
-                pipe.query_async::<()>(&mut client.get_async_connection().await?)
-                    .await?;
+            if versioning {
+                pipe.del(format!("{}:version", key)).ignore();
             }
         }
-        Ok(())
+
+        self.query_pipelines(pipes).await
     }
 
     /// 通过管道重放WAL条目
@@ -708,13 +2274,15 @@ impl L2Backend {
         entries: Vec<crate::recovery::wal::WalEntry>,
     ) -> Result<()> {
         debug!("Replaying WAL with {} entries", entries.len());
-        let mut pipe = redis::pipe();
+        let versioning = self.versioning_enabled();
+        let mut pipes = self.batch_pipelines();
 
         for entry in entries {
+            let pipe = &mut pipes[self.pipeline_index(&entry.key)];
             match entry.operation {
                 crate::recovery::wal::Operation::Set => {
                     if let Some(val) = entry.value {
-                        pipe.set(&entry.key, val).ignore();
+                        pipe.set(&entry.key, self.encode_value(val)?).ignore();
                         // 验证 TTL 范围，防止命令注入和 panic
                         if let Some(t) = entry.ttl {
                             // 检查 TTL 是否在合理范围内（1秒 - 30天）
@@ -725,26 +2293,81 @@ impl L2Backend {
                                 );
                                 continue;
                             }
-                            // 直接设置 TTL，忽略错误
-                            pipe.expire(&entry.key, t).ignore();
+                            // 持久化条目不设置过期时间（EXPIRE 0 会直接删除键）
+                            if t > 0 {
+                                pipe.expire(&entry.key, t).ignore();
+                            }
+                        }
+                        if versioning {
+                            pipe.incr(format!("{}:version", entry.key), 1).ignore();
                         }
-                        pipe.incr(format!("{}:version", entry.key), 1).ignore();
                     }
                 }
                 crate::recovery::wal::Operation::Delete => {
                     pipe.del(&entry.key).ignore();
-                    pipe.del(format!("{}:version", entry.key)).ignore();
+                    if versioning {
+                        pipe.del(format!("{}:version", entry.key)).ignore();
+                    }
                 }
             }
         }
 
+        self.query_pipelines(pipes).await
+    }
+
+    /// 为批量操作创建管道
+    ///
+    /// 分片模式下每个节点对应一个管道，其余模式只有一个
+    fn batch_pipelines(&self) -> Vec<redis::Pipeline> {
+        let count = match self {
+            L2Backend::Sharded { managers, .. } => managers.len(),
+            _ => 1,
+        };
+        (0..count).map(|_| redis::pipe()).collect()
+    }
+
+    /// 键所属的批量操作管道下标
+    fn pipeline_index(&self, key: &str) -> usize {
+        match self {
+            L2Backend::Sharded { ring, .. } => ring.node_for(key),
+            _ => 0,
+        }
+    }
+
+    /// 执行 [`batch_pipelines`](Self::batch_pipelines) 创建的管道
+    ///
+    /// 分片模式下各节点的管道并发执行，节点之间不保证原子性
+    async fn query_pipelines(&self, pipes: Vec<redis::Pipeline>) -> Result<()> {
         match self {
             L2Backend::Standalone { manager, .. } => {
-                pipe.query_async::<()>(&mut manager.clone()).await?;
+                for pipe in pipes {
+                    pipe.query_async::<()>(&mut manager.clone()).await?;
+                }
             }
             L2Backend::Cluster { client, .. } => {
-                pipe.query_async::<()>(&mut client.get_async_connection().await?)
-                    .await?;
+                let mut conn = client.get_async_connection().await?;
+                for pipe in pipes {
+                    pipe.query_async::<()>(&mut conn).await?;
+                }
+            }
+            L2Backend::Sharded { managers, .. } => {
+                futures::future::try_join_all(
+                    pipes
+                        .iter()
+                        .zip(managers.iter())
+                        .filter(|(pipe, _)| pipe.cmd_iter().next().is_some())
+                        .map(|(pipe, manager)| {
+                            let mut conn = manager.clone();
+                            async move { pipe.query_async::<()>(&mut conn).await }
+                        }),
+                )
+                .await?;
+            }
+            #[cfg(any(test, feature = "test-util"))]
+            L2Backend::InMemory { store, .. } => {
+                for pipe in pipes {
+                    pipe.query_async::<()>(&mut store.connection()).await?;
+                }
             }
         }
         Ok(())
@@ -752,16 +2375,37 @@ impl L2Backend {
 
     /// 获取原始Redis客户端
     ///
+    /// 分片模式下返回第一个节点的客户端，失效通知的发布/订阅固定使用该节点
+    ///
     /// # 返回值
     ///
     /// 返回Redis客户端实例
     pub fn get_raw_client(&self) -> Result<Client> {
         match self {
-            L2Backend::Standalone { client, .. } => Ok(client.clone()),
+            L2Backend::Standalone { client, .. } => Ok(client.as_ref().clone()),
+            L2Backend::Sharded { clients, .. } => clients
+                .first()
+                .cloned()
+                .ok_or_else(|| CacheError::Configuration("Sharded mode has no nodes".to_string())),
             L2Backend::Cluster { .. } => Err(CacheError::NotSupported(
                 "get_raw_client is not supported in Cluster mode".to_string(),
             )),
+            #[cfg(any(test, feature = "test-util"))]
+            L2Backend::InMemory { .. } => Err(CacheError::NotSupported(
+                "get_raw_client is not supported by the in-memory backend".to_string(),
+            )),
+        }
+    }
+
+    /// 是否支持失效通知使用的发布/订阅
+    ///
+    /// 内存后端没有可供订阅的Redis连接，返回false
+    pub(crate) fn supports_pubsub(&self) -> bool {
+        #[cfg(any(test, feature = "test-util"))]
+        if let L2Backend::InMemory { .. } = self {
+            return false;
         }
+        true
     }
 
     /// 设置字节数组缓存值
@@ -770,59 +2414,347 @@ impl L2Backend {
     ///
     /// * `key` - 缓存键
     /// * `value` - 字节数组值
-    /// * `ttl` - 过期时间（秒），None表示使用默认值3600秒
+    /// * `ttl` - 过期时间（秒），None表示使用默认值3600秒，0表示永不过期
+    ///
+    /// # 返回值
+    ///
+    /// 返回操作结果
+    #[instrument(skip(self, value), level = "debug", fields(value_len = value.len()))]
+    pub async fn set_bytes(&self, key: &str, value: Vec<u8>, ttl: Option<u64>) -> Result<()> {
+        self.set_with_version(key, value, ttl).await
+    }
+
+    /// 获取字节数组缓存值
+    ///
+    /// # 参数
+    ///
+    /// * `key` - 缓存键
+    ///
+    /// # 返回值
+    ///
+    /// 返回字节数组值，如果不存在则返回None
+    #[instrument(skip(self), level = "debug")]
+    pub async fn get_bytes(&self, key: &str) -> Result<Option<Vec<u8>>> {
+        match self.get_with_version(key).await? {
+            Some((value, _version)) => Ok(Some(value)),
+            None => Ok(None),
+        }
+    }
+
+    /// 以二进制原始键获取字节数组缓存值
+    ///
+    /// 原始键不要求是合法的UTF-8，与相同字节的字符串键指向同一个Redis键；不更新本地版本缓存
+    ///
+    /// # 参数
+    ///
+    /// * `key` - 原始键
+    ///
+    /// # 返回值
+    ///
+    /// 返回字节数组值，如果不存在则返回None
+    #[instrument(skip(self), level = "debug")]
+    pub async fn get_bytes_by_raw(&self, key: &[u8]) -> Result<Option<Vec<u8>>> {
+        Ok(self.read_value(key).await?.map(|(value, _)| value))
+    }
+
+    /// 以二进制原始键设置字节数组缓存值
+    ///
+    /// 与 [`set_bytes`](Self::set_bytes) 一样维护版本键和压缩帧，但不更新本地版本缓存
+    ///
+    /// # 参数
+    ///
+    /// * `key` - 原始键
+    /// * `value` - 字节数组值
+    /// * `ttl` - 过期时间（秒），None表示使用默认值3600秒，0表示永不过期
+    ///
+    /// # 返回值
+    ///
+    /// 返回操作结果
+    #[instrument(skip(self, value), level = "debug", fields(value_len = value.len()))]
+    pub async fn set_bytes_by_raw(
+        &self,
+        key: &[u8],
+        value: Vec<u8>,
+        ttl: Option<u64>,
+    ) -> Result<()> {
+        self.write_value(key, value, ttl).await
+    }
+
+    /// 检查键是否存在
+    ///
+    /// # 参数
+    ///
+    /// * `key` - 缓存键
+    ///
+    /// # 返回值
+    ///
+    /// 返回键是否存在
+    #[instrument(skip(self), level = "debug")]
+    pub async fn exists(&self, key: &str) -> Result<bool> {
+        // 验证缓存键，防止命令注入
+        ensure_safe_key(key)?;
+
+        self.with_retry(|| async move {
+            match self {
+                L2Backend::Standalone { manager, .. } => {
+                    let mut conn = manager.clone();
+                    let exists: bool = redis::cmd("EXISTS").arg(key).query_async(&mut conn).await?;
+                    Ok(exists)
+                }
+                L2Backend::Cluster { client, .. } => {
+                    let mut conn = client.get_async_connection().await?;
+                    let exists: bool = redis::cmd("EXISTS").arg(key).query_async(&mut conn).await?;
+                    Ok(exists)
+                }
+                L2Backend::Sharded { managers, ring, .. } => {
+                    let mut conn = Self::shard_manager(managers, ring, key);
+                    let exists: bool = redis::cmd("EXISTS").arg(key).query_async(&mut conn).await?;
+                    Ok(exists)
+                }
+                #[cfg(any(test, feature = "test-util"))]
+                L2Backend::InMemory { store, .. } => {
+                    let mut conn = store.connection();
+                    let exists: bool = redis::cmd("EXISTS").arg(key).query_async(&mut conn).await?;
+                    Ok(exists)
+                }
+            }
+        })
+        .await
+    }
+
+    /// 批量检查键是否存在
+    ///
+    /// 按节点分组后每个节点一个 `EXISTS` 管道，分组方式与
+    /// [`get_many_bytes`](Self::get_many_bytes) 相同
+    ///
+    /// # 参数
+    ///
+    /// * `keys` - 缓存键列表
+    ///
+    /// # 返回值
+    ///
+    /// 按输入顺序返回各键是否存在
+    #[instrument(skip(self, keys), level = "debug", fields(key_count = keys.len()))]
+    pub async fn exists_many(&self, keys: &[&str]) -> Result<Vec<bool>> {
+        if keys.is_empty() {
+            return Ok(Vec::new());
+        }
+        for key in keys {
+            // 验证缓存键，防止命令注入
+            ensure_safe_key(key)?;
+        }
+
+        self.with_retry(|| async move {
+            match self {
+                L2Backend::Standalone {
+                    manager,
+                    read_manager,
+                    ..
+                } => {
+                    let mut conn = read_manager
+                        .as_ref()
+                        .clone()
+                        .unwrap_or_else(|| manager.clone());
+                    let mut pipe = redis::pipe();
+                    for key in keys {
+                        pipe.exists(*key);
+                    }
+                    Ok(pipe.query_async(&mut conn).await?)
+                }
+                L2Backend::Cluster { client, .. } => {
+                    let mut conn = client.get_async_connection().await?;
+                    let ranges = cluster_slot_ranges(&mut conn).await?;
+                    let groups = group_keys_by_node(keys, &ranges)?;
+                    let replies = futures::future::try_join_all(groups.iter().map(
+                        |((host, port), indices)| {
+                            let mut conn = conn.clone();
+                            let mut pipe = redis::pipe();
+                            for index in indices {
+                                pipe.exists(keys[*index]);
+                            }
+                            let route = redis::cluster_routing::SingleNodeRoutingInfo::ByAddress {
+                                host: host.clone(),
+                                port: *port,
+                            };
+                            async move { conn.route_pipeline(&pipe, 0, indices.len(), route).await }
+                        },
+                    ))
+                    .await?;
+                    let mut exists = vec![false; keys.len()];
+                    for ((_, indices), reply) in groups.iter().zip(replies) {
+                        for (index, value) in indices.iter().zip(reply) {
+                            exists[*index] = redis::from_redis_value(&value)?;
+                        }
+                    }
+                    Ok(exists)
+                }
+                L2Backend::Sharded { managers, ring, .. } => {
+                    let mut groups: Vec<Vec<usize>> = vec![Vec::new(); managers.len()];
+                    for (index, key) in keys.iter().enumerate() {
+                        groups[ring.node_for(key)].push(index);
+                    }
+                    let replies = futures::future::try_join_all(
+                        groups
+                            .iter()
+                            .zip(managers.iter())
+                            .filter(|(indices, _)| !indices.is_empty())
+                            .map(|(indices, manager)| {
+                                let mut conn = manager.clone();
+                                let mut pipe = redis::pipe();
+                                for index in indices {
+                                    pipe.exists(keys[*index]);
+                                }
+                                async move {
+                                    let exists: Vec<bool> = pipe.query_async(&mut conn).await?;
+                                    Ok::<_, CacheError>((indices, exists))
+                                }
+                            }),
+                    )
+                    .await?;
+                    let mut exists = vec![false; keys.len()];
+                    for (indices, reply) in replies {
+                        for (index, value) in indices.iter().zip(reply) {
+                            exists[*index] = value;
+                        }
+                    }
+                    Ok(exists)
+                }
+                #[cfg(any(test, feature = "test-util"))]
+                L2Backend::InMemory { store, .. } => {
+                    let mut pipe = redis::pipe();
+                    for key in keys {
+                        pipe.exists(*key);
+                    }
+                    Ok(pipe.query_async(&mut store.connection()).await?)
+                }
+            }
+        })
+        .await
+    }
+
+    /// 获取哈希表中的字段值
+    ///
+    /// # 参数
+    ///
+    /// * `key` - 哈希表的缓存键
+    /// * `field` - 字段名
     ///
     /// # 返回值
     ///
-    /// 返回操作结果
-    #[instrument(skip(self, value), level = "debug", fields(value_len = value.len()))]
-    pub async fn set_bytes(&self, key: &str, value: Vec<u8>, ttl: Option<u64>) -> Result<()> {
-        self.set_with_version(key, value, ttl).await
+    /// 返回字段值，如果键或字段不存在则返回None
+    #[instrument(skip(self), level = "debug")]
+    pub async fn hget(&self, key: &str, field: &str) -> Result<Option<Vec<u8>>> {
+        ensure_safe_key(key)?;
+
+        self.with_retry(|| async move {
+            Ok(match self {
+                L2Backend::Standalone { manager, .. } => manager.clone().hget(key, field).await?,
+                L2Backend::Cluster { client, .. } => {
+                    client
+                        .get_async_connection()
+                        .await?
+                        .hget(key, field)
+                        .await?
+                }
+                L2Backend::Sharded { managers, ring, .. } => {
+                    Self::shard_manager(managers, ring, key)
+                        .hget(key, field)
+                        .await?
+                }
+                #[cfg(any(test, feature = "test-util"))]
+                L2Backend::InMemory { store, .. } => store.connection().hget(key, field).await?,
+            })
+        })
+        .await
     }
 
-    /// 获取字节数组缓存值
+    /// 设置哈希表中的字段值
+    ///
+    /// 哈希表的所有字段共享同一个键的TTL，每次写入都会刷新整个哈希表的过期时间。
     ///
     /// # 参数
     ///
-    /// * `key` - 缓存键
+    /// * `key` - 哈希表的缓存键
+    /// * `field` - 字段名
+    /// * `value` - 字段值
+    /// * `ttl` - 整个哈希表的过期时间（秒），None使用默认值，
+    ///   [`PERSISTENT_TTL`](crate::backend::PERSISTENT_TTL) 表示永不过期
     ///
     /// # 返回值
     ///
-    /// 返回字节数组值，如果不存在则返回None
-    #[instrument(skip(self), level = "debug")]
-    pub async fn get_bytes(&self, key: &str) -> Result<Option<Vec<u8>>> {
-        match self.get_with_version(key).await? {
-            Some((value, _version)) => Ok(Some(value)),
-            None => Ok(None),
+    /// 返回操作结果
+    #[instrument(skip(self, value), level = "debug", fields(value_len = value.len()))]
+    pub async fn hset(
+        &self,
+        key: &str,
+        field: &str,
+        value: Vec<u8>,
+        ttl: Option<u64>,
+    ) -> Result<()> {
+        ensure_safe_key(key)?;
+        let ttl = ttl.unwrap_or(self.default_ttl());
+
+        let mut pipe = redis::pipe();
+        pipe.atomic().hset(key, field, value).ignore();
+        if ttl == crate::backend::PERSISTENT_TTL {
+            pipe.persist(key).ignore();
+        } else {
+            pipe.expire(key, ttl as i64).ignore();
         }
+
+        let pipe = &pipe;
+        self.with_retry(|| async move {
+            match self {
+                L2Backend::Standalone { manager, .. } => {
+                    pipe.query_async::<()>(&mut manager.clone()).await?;
+                }
+                L2Backend::Cluster { client, .. } => {
+                    pipe.query_async::<()>(&mut client.get_async_connection().await?)
+                        .await?;
+                }
+                L2Backend::Sharded { managers, ring, .. } => {
+                    pipe.query_async::<()>(&mut Self::shard_manager(managers, ring, key))
+                        .await?;
+                }
+                #[cfg(any(test, feature = "test-util"))]
+                L2Backend::InMemory { store, .. } => {
+                    pipe.query_async::<()>(&mut store.connection()).await?;
+                }
+            }
+            Ok(())
+        })
+        .await
     }
 
-    /// 检查键是否存在
+    /// 获取哈希表中的所有字段
     ///
     /// # 参数
     ///
-    /// * `key` - 缓存键
+    /// * `key` - 哈希表的缓存键
     ///
     /// # 返回值
     ///
-    /// 返回键是否存在
+    /// 返回字段名到字段值的映射，键不存在时返回空映射
     #[instrument(skip(self), level = "debug")]
-    pub async fn exists(&self, key: &str) -> Result<bool> {
-        // 验证缓存键，防止命令注入
+    pub async fn hgetall(&self, key: &str) -> Result<HashMap<String, Vec<u8>>> {
         ensure_safe_key(key)?;
 
-        match self {
-            L2Backend::Standalone { manager, .. } => {
-                let mut conn = manager.clone();
-                let exists: bool = redis::cmd("EXISTS").arg(key).query_async(&mut conn).await?;
-                Ok(exists)
-            }
-            L2Backend::Cluster { client, .. } => {
-                let mut conn = client.get_async_connection().await?;
-                let exists: bool = redis::cmd("EXISTS").arg(key).query_async(&mut conn).await?;
-                Ok(exists)
-            }
-        }
+        self.with_retry(|| async move {
+            Ok(match self {
+                L2Backend::Standalone { manager, .. } => manager.clone().hgetall(key).await?,
+                L2Backend::Cluster { client, .. } => {
+                    client.get_async_connection().await?.hgetall(key).await?
+                }
+                L2Backend::Sharded { managers, ring, .. } => {
+                    Self::shard_manager(managers, ring, key)
+                        .hgetall(key)
+                        .await?
+                }
+                #[cfg(any(test, feature = "test-util"))]
+                L2Backend::InMemory { store, .. } => store.connection().hgetall(key).await?,
+            })
+        })
+        .await
     }
 
     /// 仅当键不存在时设置值
@@ -891,6 +2823,49 @@ impl L2Backend {
                 };
                 Ok(result.is_some())
             }
+            L2Backend::Sharded { managers, ring, .. } => {
+                let mut conn = Self::shard_manager(managers, ring, key);
+                let result: Option<String> = if let Some(ttl) = ttl {
+                    redis::cmd("SET")
+                        .arg(key)
+                        .arg(value)
+                        .arg("NX")
+                        .arg("EX")
+                        .arg(ttl)
+                        .query_async(&mut conn)
+                        .await?
+                } else {
+                    redis::cmd("SET")
+                        .arg(key)
+                        .arg(value)
+                        .arg("NX")
+                        .query_async(&mut conn)
+                        .await?
+                };
+                Ok(result.is_some())
+            }
+            #[cfg(any(test, feature = "test-util"))]
+            L2Backend::InMemory { store, .. } => {
+                let mut conn = store.connection();
+                let result: Option<String> = if let Some(ttl) = ttl {
+                    redis::cmd("SET")
+                        .arg(key)
+                        .arg(value)
+                        .arg("NX")
+                        .arg("EX")
+                        .arg(ttl)
+                        .query_async(&mut conn)
+                        .await?
+                } else {
+                    redis::cmd("SET")
+                        .arg(key)
+                        .arg(value)
+                        .arg("NX")
+                        .query_async(&mut conn)
+                        .await?
+                };
+                Ok(result.is_some())
+            }
         }
     }
 
@@ -919,7 +2894,85 @@ impl L2Backend {
                 let result: i64 = redis::cmd("INCR").arg(key).query_async(&mut conn).await?;
                 Ok(result)
             }
+            L2Backend::Sharded { managers, ring, .. } => {
+                let mut conn = Self::shard_manager(managers, ring, key);
+                let result: i64 = redis::cmd("INCR").arg(key).query_async(&mut conn).await?;
+                Ok(result)
+            }
+            #[cfg(any(test, feature = "test-util"))]
+            L2Backend::InMemory { store, .. } => {
+                let mut conn = store.connection();
+                let result: i64 = redis::cmd("INCR").arg(key).query_async(&mut conn).await?;
+                Ok(result)
+            }
+        }
+    }
+
+    /// 按增量原子地自增整数键，可选地限制结果不超过上限
+    ///
+    /// 键不存在时从0开始。结果越过 `max` 或超出 `i64` 范围时不修改键，返回
+    /// `CacheError::LimitExceeded`。键中存储的必须是未经压缩或加密的十进制整数
+    ///
+    /// # 参数
+    ///
+    /// * `key` - 缓存键
+    /// * `delta` - 增量，负数表示自减
+    /// * `max` - 结果允许的最大值，None表示只检查 `i64` 范围
+    ///
+    /// # 返回值
+    ///
+    /// 返回自增后的值
+    #[instrument(skip(self), level = "debug")]
+    pub async fn incr_by_checked(&self, key: &str, delta: i64, max: Option<i64>) -> Result<i64> {
+        ensure_safe_key(key)?;
+
+        let exceeded = |current: &str| {
+            let bound = match max {
+                Some(max) => format!("the ceiling {}", max),
+                None => "the i64 range".to_string(),
+            };
+            CacheError::LimitExceeded(format!(
+                "incrementing '{}' (currently {}) by {} would exceed {}",
+                key, current, delta, bound
+            ))
+        };
+        // 当前值不超过 max - delta 时结果不越过上限；差值溢出说明上限不可能（或必然）被越过
+        let limit = match max.map(|max| max.checked_sub(delta)) {
+            None => String::new(),
+            Some(Some(limit)) => limit.to_string(),
+            Some(None) if delta < 0 => String::new(),
+            Some(None) => return Err(exceeded("unknown")),
+        };
+
+        let script = redis::Script::new(INCR_BY_CHECKED_SCRIPT);
+        let mut invocation = script.key(key);
+        invocation.arg(delta).arg(limit);
+        let (applied, value): (i64, String) = match self {
+            L2Backend::Standalone { manager, .. } => {
+                invocation.invoke_async(&mut manager.clone()).await?
+            }
+            L2Backend::Cluster { client, .. } => {
+                invocation
+                    .invoke_async(&mut client.get_async_connection().await?)
+                    .await?
+            }
+            L2Backend::Sharded { managers, ring, .. } => {
+                invocation
+                    .invoke_async(&mut Self::shard_manager(managers, ring, key))
+                    .await?
+            }
+            #[cfg(any(test, feature = "test-util"))]
+            L2Backend::InMemory { store, .. } => {
+                invocation.invoke_async(&mut store.connection()).await?
+            }
+        };
+
+        if applied != 1 {
+            return Err(exceeded(&value));
         }
+        value.parse().map_err(|_| {
+            CacheError::L2Error(format!("Counter '{}' holds a non-integer value", key))
+        })
     }
 
     /// 设置键的过期时间
@@ -956,6 +3009,25 @@ impl L2Backend {
                     .await?;
                 Ok(result)
             }
+            L2Backend::Sharded { managers, ring, .. } => {
+                let mut conn = Self::shard_manager(managers, ring, key);
+                let result: bool = redis::cmd("EXPIRE")
+                    .arg(key)
+                    .arg(ttl)
+                    .query_async(&mut conn)
+                    .await?;
+                Ok(result)
+            }
+            #[cfg(any(test, feature = "test-util"))]
+            L2Backend::InMemory { store, .. } => {
+                let mut conn = store.connection();
+                let result: bool = redis::cmd("EXPIRE")
+                    .arg(key)
+                    .arg(ttl)
+                    .query_async(&mut conn)
+                    .await?;
+                Ok(result)
+            }
         }
     }
 
@@ -984,12 +3056,160 @@ impl L2Backend {
                 let result: String = redis::cmd("TYPE").arg(key).query_async(&mut conn).await?;
                 Ok(result)
             }
+            L2Backend::Sharded { managers, ring, .. } => {
+                let mut conn = Self::shard_manager(managers, ring, key);
+                let result: String = redis::cmd("TYPE").arg(key).query_async(&mut conn).await?;
+                Ok(result)
+            }
+            #[cfg(any(test, feature = "test-util"))]
+            L2Backend::InMemory { store, .. } => {
+                let mut conn = store.connection();
+                let result: String = redis::cmd("TYPE").arg(key).query_async(&mut conn).await?;
+                Ok(result)
+            }
+        }
+    }
+
+    /// 扫描匹配模式的键
+    ///
+    /// 通过 `SCAN ... MATCH` 分批游标遍历，不会像 `KEYS` 一样阻塞 Redis；
+    /// 集群模式下逐个主节点、分片模式下逐个节点执行 SCAN。只读取键名，不修改任何数据。
+    /// 所有匹配的键都会收集到内存中，键数量很大时使用 [`scan_pages`](Self::scan_pages)
+    ///
+    /// # 参数
+    ///
+    /// * `pattern` - Redis glob 模式，如 `user:*`
+    ///
+    /// # 返回值
+    ///
+    /// 返回所有匹配的键
+    #[instrument(skip(self), level = "debug")]
+    pub async fn scan_keys(&self, pattern: &str) -> Result<Vec<String>> {
+        self.scan_pages(pattern, SCAN_BATCH_COUNT)
+            .try_concat()
+            .await
+    }
+
+    /// 按页流式扫描匹配模式的键
+    ///
+    /// 与 [`scan_keys`](Self::scan_keys) 使用相同的 `SCAN` 遍历，但每次只拉取下一页所需的键，
+    /// 消费方处理完一页后才会继续扫描，内存占用只与页大小相关。
+    /// 每页最多 `page_size` 个键（最后一页可能更少），不返回空页；
+    /// 遍历期间被修改的键可能被重复返回或遗漏，与 `SCAN` 的语义一致
+    ///
+    /// # 参数
+    ///
+    /// * `pattern` - Redis glob 模式，如 `user:*`
+    /// * `page_size` - 每页的最大键数量，0按1处理
+    ///
+    /// # 返回值
+    ///
+    /// 返回逐页产出匹配键的流
+    pub fn scan_pages<'a>(
+        &'a self,
+        pattern: &'a str,
+        page_size: usize,
+    ) -> impl Stream<Item = Result<Vec<String>>> + Send + 'a {
+        let page_size = page_size.max(1);
+        futures::stream::try_unfold(ScanState::default(), move |mut state| async move {
+            let targets = match state.targets.take() {
+                Some(targets) => targets,
+                None => self.scan_targets().await?,
+            };
+            while state.pending.len() < page_size && state.node < targets.len() {
+                let (next_cursor, keys) = self
+                    .scan_page(
+                        state.node,
+                        &targets[state.node],
+                        state.cursor,
+                        pattern,
+                        page_size,
+                    )
+                    .await?;
+                state.pending.extend(keys);
+                if next_cursor == 0 {
+                    state.node += 1;
+                }
+                state.cursor = next_cursor;
+            }
+            if state.pending.is_empty() {
+                return Ok(None);
+            }
+            let page: Vec<String> = state
+                .pending
+                .drain(..state.pending.len().min(page_size))
+                .collect();
+            state.targets = Some(targets);
+            Ok(Some((page, state)))
+        })
+    }
+
+    /// 分页扫描需要遍历的节点，集群模式下返回主节点地址，其余模式按下标对应连接
+    async fn scan_targets(&self) -> Result<Vec<Option<(String, u16)>>> {
+        Ok(match self {
+            L2Backend::Sharded { managers, .. } => vec![None; managers.len()],
+            L2Backend::Cluster { client, .. } => {
+                let mut conn = client.get_async_connection().await?;
+                cluster_master_addresses(&mut conn)
+                    .await?
+                    .into_iter()
+                    .map(Some)
+                    .collect()
+            }
+            _ => vec![None],
+        })
+    }
+
+    /// 在指定节点上执行一次 `SCAN` 迭代
+    async fn scan_page(
+        &self,
+        node: usize,
+        target: &Option<(String, u16)>,
+        cursor: u64,
+        pattern: &str,
+        count: usize,
+    ) -> Result<(u64, Vec<String>)> {
+        let aliases = self.aliases();
+        match self {
+            L2Backend::Standalone { manager, .. } => {
+                scan_node_page(&mut manager.clone(), aliases, cursor, pattern, count).await
+            }
+            L2Backend::Sharded { managers, .. } => {
+                scan_node_page(&mut managers[node].clone(), aliases, cursor, pattern, count).await
+            }
+            #[cfg(any(test, feature = "test-util"))]
+            L2Backend::InMemory { store, .. } => {
+                scan_node_page(&mut store.connection(), aliases, cursor, pattern, count).await
+            }
+            L2Backend::Cluster { client, .. } => {
+                use redis::cluster_routing::{RoutingInfo, SingleNodeRoutingInfo};
+
+                let (host, port) = target.clone().ok_or_else(|| {
+                    CacheError::L2Error("Missing cluster node for SCAN".to_string())
+                })?;
+                let routing =
+                    RoutingInfo::SingleNode(SingleNodeRoutingInfo::ByAddress { host, port });
+                let mut scan = aliases.cmd("SCAN");
+                scan.arg(cursor)
+                    .arg("MATCH")
+                    .arg(pattern)
+                    .arg("COUNT")
+                    .arg(count);
+                let value = client
+                    .get_async_connection()
+                    .await?
+                    .route_command(&scan, routing)
+                    .await?;
+                Ok(redis::from_redis_value(&value)?)
+            }
         }
     }
 
     /// 清空 L2 缓存
     ///
-    /// 注意：此操作会删除所有以服务名为前缀的缓存键
+    /// 仅删除以 `{service_name}:` 为前缀的键（包括对应的版本键），不会使用 FLUSHDB 影响其他服务的数据。
+    /// 通过 `SCAN ... COUNT 500` 分批游标遍历并使用 UNLINK 异步删除，避免阻塞 Redis；
+    /// 集群模式下逐个主节点执行 SCAN，确保覆盖所有槽位；分片模式下逐个节点执行。
     ///
     /// # 参数
     ///
@@ -1001,68 +3221,172 @@ impl L2Backend {
     #[instrument(skip(self), level = "debug")]
     pub async fn clear(&self, service_name: &str) -> Result<()> {
         debug!("L2 clear: 清空服务 {} 的所有缓存项", service_name);
-        let pattern = format!("{}:*", service_name);
+        let prefix = format!("{}:", service_name);
+        let pattern = format!("{}*", prefix);
+        let aliases = self.aliases();
+        let mut removed = 0usize;
 
         match self {
-            L2Backend::Standalone { manager, .. } => {
-                let mut conn = manager.clone();
-                let mut cursor = 0i64;
-                loop {
-                    let (next_cursor, keys): (i64, Vec<String>) = redis::cmd("SCAN")
-                        .arg(cursor)
-                        .arg("MATCH")
-                        .arg(&pattern)
-                        .arg("COUNT")
-                        .arg(1000)
-                        .query_async(&mut conn)
-                        .await?;
-
-                    if !keys.is_empty() {
-                        let mut pipe = redis::pipe();
-                        for key in &keys {
-                            pipe.del(key).ignore();
-                            pipe.del(format!("{}:version", key)).ignore();
-                        }
-                        pipe.query_async::<()>(&mut conn).await?;
-                    }
-
-                    cursor = next_cursor;
-                    if cursor == 0 {
-                        break;
-                    }
+            L2Backend::Standalone {
+                manager,
+                version_cache,
+                ..
+            } => {
+                removed = clear_node(&mut manager.clone(), aliases, &pattern).await?;
+                version_cache.retain(|key, _| !key.starts_with(&prefix));
+            }
+            L2Backend::Sharded {
+                managers,
+                version_cache,
+                ..
+            } => {
+                for manager in managers.iter() {
+                    removed += clear_node(&mut manager.clone(), aliases, &pattern).await?;
                 }
+                version_cache.retain(|key, _| !key.starts_with(&prefix));
             }
-            L2Backend::Cluster { client, .. } => {
-                let mut cursor = 0i64;
-                loop {
-                    let mut conn = client.get_async_connection().await?;
-                    let (next_cursor, keys): (i64, Vec<String>) = redis::cmd("SCAN")
-                        .arg(cursor)
-                        .arg("MATCH")
-                        .arg(&pattern)
-                        .arg("COUNT")
-                        .arg(1000)
-                        .query_async(&mut conn)
+            #[cfg(any(test, feature = "test-util"))]
+            L2Backend::InMemory {
+                store,
+                version_cache,
+                ..
+            } => {
+                removed = clear_node(&mut store.connection(), aliases, &pattern).await?;
+                version_cache.retain(|key, _| !key.starts_with(&prefix));
+            }
+            L2Backend::Cluster {
+                client,
+                version_cache,
+                ..
+            } => {
+                use redis::cluster_routing::{RoutingInfo, SingleNodeRoutingInfo};
+
+                let mut conn = client.get_async_connection().await?;
+
+                for (host, port) in cluster_master_addresses(&mut conn).await? {
+                    let routing =
+                        RoutingInfo::SingleNode(SingleNodeRoutingInfo::ByAddress { host, port });
+                    let mut cursor = 0u64;
+                    loop {
+                        let mut cmd = aliases.cmd("SCAN");
+                        cmd.arg(cursor)
+                            .arg("MATCH")
+                            .arg(&pattern)
+                            .arg("COUNT")
+                            .arg(SCAN_BATCH_COUNT);
+                        let value = conn.route_command(&cmd, routing.clone()).await?;
+                        let (next_cursor, keys): (u64, Vec<String>) =
+                            redis::from_redis_value(&value)?;
+
+                        // 同一批键可能分布在不同槽位，逐键发送 UNLINK 由集群按键路由
+                        futures::future::try_join_all(keys.iter().map(|key| {
+                            let mut conn = conn.clone();
+                            let mut unlink = aliases.cmd("UNLINK");
+                            unlink.arg(key);
+                            async move { unlink.query_async::<()>(&mut conn).await }
+                        }))
                         .await?;
+                        removed += keys.len();
 
-                    if !keys.is_empty() {
-                        let mut pipe = redis::pipe();
-                        for key in &keys {
-                            pipe.del(key).ignore();
-                            pipe.del(format!("{}:version", key)).ignore();
+                        cursor = next_cursor;
+                        if cursor == 0 {
+                            break;
                         }
-                        pipe.query_async::<()>(&mut conn).await?;
-                    }
-
-                    cursor = next_cursor;
-                    if cursor == 0 {
-                        break;
                     }
                 }
+                version_cache.retain(|key, _| !key.starts_with(&prefix));
             }
         }
 
-        debug!("L2 clear: 缓存已清空");
+        debug!("L2 clear: 已删除 {} 个键", removed);
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use redis::Value;
+
+    fn slot_entry(start: i64, end: i64, port: i64) -> Value {
+        Value::Array(vec![
+            Value::Int(start),
+            Value::Int(end),
+            Value::Array(vec![
+                Value::BulkString(b"127.0.0.1".to_vec()),
+                Value::Int(port),
+                Value::BulkString(b"node-id".to_vec()),
+            ]),
+            Value::Array(vec![
+                Value::BulkString(b"127.0.0.1".to_vec()),
+                Value::Int(port + 3),
+            ]),
+        ])
+    }
+
+    fn three_node_ranges() -> Vec<ClusterSlotRange> {
+        parse_cluster_slots(&Value::Array(vec![
+            slot_entry(0, 5460, 7000),
+            slot_entry(5461, 10922, 7001),
+            slot_entry(10923, 16383, 7002),
+        ]))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_parse_cluster_slots_uses_master_address() {
+        let ranges = three_node_ranges();
+        assert_eq!(ranges.len(), 3);
+        assert_eq!(
+            ranges[1],
+            ClusterSlotRange {
+                start: 5461,
+                end: 10922,
+                host: "127.0.0.1".to_string(),
+                port: 7001,
+            }
+        );
+        assert!(parse_cluster_slots(&Value::Nil).is_err());
+    }
+
+    #[test]
+    fn test_group_keys_by_node_builds_one_group_per_node() {
+        let ranges = three_node_ranges();
+        let keys: Vec<String> = (0..50).map(|i| format!("batch:{}", i)).collect();
+        let keys: Vec<&str> = keys.iter().map(String::as_str).collect();
+
+        let groups = group_keys_by_node(&keys, &ranges).unwrap();
+        assert_eq!(groups.len(), 3);
+        let mut indices: Vec<usize> = groups.iter().flat_map(|(_, i)| i.clone()).collect();
+        indices.sort_unstable();
+        assert_eq!(indices, (0..50).collect::<Vec<_>>());
+
+        for ((_, port), indices) in &groups {
+            let range = ranges.iter().find(|r| r.port == *port).unwrap();
+            for index in indices {
+                let slot = redis::cluster_routing::get_slot(keys[*index].as_bytes());
+                assert!(range.start <= slot && slot <= range.end);
+            }
+        }
+    }
+
+    #[test]
+    fn test_group_keys_by_node_rejects_uncovered_slot() {
+        let ranges = parse_cluster_slots(&Value::Array(vec![slot_entry(0, 0, 7000)])).unwrap();
+        assert!(group_keys_by_node(&["batch:1"], &ranges).is_err());
+    }
+
+    #[test]
+    fn test_command_aliases_remap_configured_commands_only() {
+        let aliases =
+            CommandAliases::new(&HashMap::from([("scan".to_string(), "X_SCAN".to_string())]));
+        let first_arg = |cmd: redis::Cmd| match cmd.args_iter().next() {
+            Some(redis::Arg::Simple(arg)) => arg.to_vec(),
+            _ => Vec::new(),
+        };
+
+        assert_eq!(first_arg(aliases.cmd("SCAN")), b"X_SCAN");
+        assert_eq!(first_arg(aliases.cmd("UNLINK")), b"UNLINK");
+        assert_eq!(first_arg(CommandAliases::default().cmd("SCAN")), b"SCAN");
+    }
+}
@@ -4,6 +4,19 @@
 //!
 //! 该模块定义了缓存系统的后端提供者，包括L1和L2缓存后端。
 
+pub mod compression;
+#[cfg(any(test, feature = "test-util"))]
+pub mod in_memory;
 pub mod l1;
 pub mod l2;
 pub mod redis_provider;
+pub mod retry;
+pub mod sharding;
+
+/// 永不过期的TTL取值
+///
+/// 写入接口中 `ttl` 参数的两种含义需要区分：
+/// - `None`：使用后端默认TTL（L1为配置的默认值，L2为3600秒）
+/// - `Some(PERSISTENT_TTL)`：持久化写入，L1条目仅受容量淘汰影响，
+///   L2使用不带 `EX` 的 `SET`，且 `:version` 键同样不过期
+pub const PERSISTENT_TTL: u64 = 0;
@@ -5,12 +5,17 @@
 //! 该模块定义了Redis提供者接口和默认实现。
 
 use crate::{
-    config::L2Config,
+    config::{L2Config, MAX_REDIS_DATABASE},
     error::{CacheError, Result},
+    utils::redaction::redact_connection_string,
 };
 use async_trait::async_trait;
-use redis::{aio::ConnectionManager, Client};
-use secrecy::ExposeSecret;
+use redis::sentinel::{Sentinel, SentinelNodeConnectionInfo};
+use redis::{
+    aio::ConnectionManager, Client, ClientTlsConfig, ConnectionInfo, IntoConnectionInfo,
+    RedisConnectionInfo, TlsCertificates,
+};
+use secrecy::{ExposeSecret, SecretString};
 use tokio::time::{timeout, Duration};
 
 #[async_trait]
@@ -22,47 +27,156 @@ pub trait RedisProvider: Send + Sync {
         &self,
         config: &L2Config,
     ) -> Result<(Client, ConnectionManager, Option<ConnectionManager>)>;
+
+    /// 为分片模式的每个节点创建单机客户端
+    ///
+    /// 默认实现以节点地址替换 `connection_string` 后逐个调用 [`get_standalone_client`](Self::get_standalone_client)，
+    /// 其余连接参数（TLS、数据库编号、超时）对所有节点一致
+    ///
+    /// # 参数
+    ///
+    /// * `config` - L2缓存配置，`sharded` 必须存在
+    ///
+    /// # 返回值
+    ///
+    /// 返回与 `sharded.nodes` 顺序一致的客户端与连接管理器
+    async fn get_sharded_clients(
+        &self,
+        config: &L2Config,
+    ) -> Result<Vec<(Client, ConnectionManager)>> {
+        let sharded = config.sharded.as_ref().ok_or_else(|| {
+            CacheError::Configuration("Sharded mode requires sharded configuration".to_string())
+        })?;
+        let mut clients = Vec::with_capacity(sharded.nodes.len());
+        for node in &sharded.nodes {
+            let node_config = L2Config {
+                connection_string: SecretString::from(node.clone()),
+                ..config.clone()
+            };
+            clients.push(self.get_standalone_client(&node_config).await?);
+        }
+        Ok(clients)
+    }
 }
 
 pub struct DefaultRedisProvider;
 
+/// 解析单机模式的连接地址并确定是否启用TLS
+///
+/// `rediss://` 协议总是启用TLS，无论 `enable_tls` 如何设置；
+/// `enable_tls=true` 但协议为 `redis://` 时升级为 `rediss://` 并输出警告。
+///
+/// # 参数
+///
+/// * `config` - L2缓存配置
+///
+/// # 返回值
+///
+/// 返回实际用于连接的地址
+fn resolve_standalone_url(config: &L2Config) -> String {
+    let connection_string = config.connection_string.expose_secret().trim();
+    let has_scheme = |scheme: &str| {
+        connection_string
+            .get(..scheme.len())
+            .is_some_and(|prefix| prefix.eq_ignore_ascii_case(scheme))
+    };
+
+    if has_scheme("rediss://") {
+        if !config.enable_tls {
+            tracing::info!("Connection string uses rediss:// scheme, enabling TLS");
+        }
+        connection_string.to_string()
+    } else if config.enable_tls && has_scheme("redis://") {
+        tracing::warn!(
+            "enable_tls=true but connection string uses redis:// scheme, upgrading to rediss://"
+        );
+        format!("rediss://{}", &connection_string["redis://".len()..])
+    } else {
+        connection_string.to_string()
+    }
+}
+
+/// 解析连接地址并应用配置的数据库编号
+///
+/// 数据库编号写入连接信息后，客户端在每次建立连接（包括自动重连）时都会执行 `SELECT`。
+///
+/// # 参数
+///
+/// * `url` - 连接地址
+/// * `config` - L2缓存配置
+///
+/// # 返回值
+///
+/// 返回连接信息，数据库编号超出范围时返回配置错误
+fn connection_info(url: &str, config: &L2Config) -> Result<ConnectionInfo> {
+    let mut info = url.into_connection_info()?;
+    if let Some(database) = config.database {
+        if database > MAX_REDIS_DATABASE {
+            return Err(CacheError::Configuration(format!(
+                "database must be between 0 and {}, got {}",
+                MAX_REDIS_DATABASE, database
+            )));
+        }
+        info.redis.db = i64::from(database);
+    }
+    Ok(info)
+}
+
+/// 将配置中的认证信息写入连接信息
+///
+/// 配置了密码时覆盖连接字符串中的认证信息；同时配置了用户名时以ACL用户认证（`AUTH username password`），
+/// 否则以默认用户认证。
+///
+/// # 参数
+///
+/// * `info` - 连接信息
+/// * `config` - L2缓存配置
+///
+/// # 返回值
+///
+/// 返回操作结果，只配置用户名而没有密码时返回配置错误
+fn apply_credentials(info: &mut RedisConnectionInfo, config: &L2Config) -> Result<()> {
+    match (&config.username, &config.password) {
+        (Some(_), None) => Err(CacheError::Configuration(
+            "Redis username requires a password".to_string(),
+        )),
+        (username, Some(password)) => {
+            info.username = username.clone();
+            info.password = Some(password.expose_secret().to_string());
+            Ok(())
+        }
+        (None, None) => Ok(()),
+    }
+}
+
 #[async_trait]
 impl RedisProvider for DefaultRedisProvider {
     async fn get_standalone_client(
         &self,
         config: &L2Config,
     ) -> Result<(Client, ConnectionManager)> {
-        let connection_string_secret = &config.connection_string;
-        let connection_string = if config.enable_tls
-            && !connection_string_secret
-                .expose_secret()
-                .starts_with("rediss://")
-        {
-            connection_string_secret
-                .expose_secret()
-                .replace("redis://", "rediss://")
-        } else {
-            connection_string_secret.expose_secret().to_string()
-        };
+        let connection_string = resolve_standalone_url(config);
+        // 错误信息与日志只使用脱敏后的地址，连接字符串中可能包含密码
+        let target = redact_connection_string(&connection_string);
+        tracing::debug!("Connecting to Redis at {}", target);
+        let mut info = connection_info(&connection_string, config)?;
+        apply_credentials(&mut info.redis, config)?;
 
-        let client = Client::open(connection_string.as_str())?;
+        let client = match load_tls_certificates(config)? {
+            Some(certs) => build_tls_client(info, certs)?,
+            None => Client::open(info)?,
+        };
         let manager = match timeout(
             Duration::from_millis(config.connection_timeout_ms),
             client.get_connection_manager(),
         )
         .await
         {
-            Ok(res) => res?,
+            Ok(res) => res.map_err(|e| connection_error(e, &target))?,
             Err(_) => {
-                // Try again with longer timeout?
-                // Or maybe the sentinel gave us an internal IP that is not reachable?
-                // In docker compose, containers share a network so 172.x.x.x should be reachable.
-                // But connection_timeout_ms might be too short for initial handshake.
-
-                // Let's print the error context if possible, but timeout doesn't give error.
                 return Err(CacheError::L2Error(format!(
                     "Connection timed out after {}ms. Target: {}",
-                    config.connection_timeout_ms, connection_string
+                    config.connection_timeout_ms, target
                 )));
             }
         };
@@ -74,12 +188,30 @@ impl RedisProvider for DefaultRedisProvider {
             CacheError::Configuration("Cluster configuration is missing".to_string())
         })?;
 
+        if config.database.is_some() {
+            return Err(CacheError::Configuration(
+                "Redis Cluster does not support SELECT, database must not be set".to_string(),
+            ));
+        }
+
         let mut builder = redis::cluster::ClusterClient::builder(cluster_config.nodes.clone());
 
+        if config.username.is_some() && config.password.is_none() {
+            return Err(CacheError::Configuration(
+                "Redis username requires a password".to_string(),
+            ));
+        }
+        if let Some(username) = &config.username {
+            builder = builder.username(username.clone());
+        }
         if let Some(password) = &config.password {
             builder = builder.password(password.expose_secret().to_string());
         }
 
+        if let Some(certs) = load_tls_certificates(config)? {
+            builder = builder.certs(certs);
+        }
+
         // Enable read from replicas for better read scalability
         builder = builder.read_from_replicas();
 
@@ -107,34 +239,23 @@ impl RedisProvider for DefaultRedisProvider {
             CacheError::Configuration("Sentinel configuration is missing".to_string())
         })?;
 
-        tracing::info!("Initializing Sentinel client with automatic failover support");
-
-        // Construct the Sentinel URL: redis+sentinel://host:port[,host:port][/service_name]
-        // 注意：不在 URL 中包含密码，避免密码泄露到日志中
-        let mut url = "redis+sentinel://".to_string();
+        tracing::info!("Initializing Sentinel client");
 
-        // Add sentinel nodes
-        let nodes: Vec<String> = sentinel_config
+        // 哨兵与主节点的认证信息都写入连接信息而不是URL，避免密码泄露到日志中
+        let nodes = sentinel_config
             .nodes
             .iter()
-            .map(|n| {
-                // Strip scheme if present
-                n.trim_start_matches("redis://")
-                    .trim_start_matches("redis+sentinel://")
-                    .trim_start_matches("http://")
-                    .to_string()
-            })
-            .collect();
-
+            .map(|node| sentinel_node_info(node, sentinel_config.sentinel_password.as_ref()))
+            .collect::<Result<Vec<_>>>()?;
         if nodes.is_empty() {
             return Err(CacheError::Configuration(
                 "No sentinel nodes provided".to_string(),
             ));
         }
-
-        url.push_str(&nodes.join(","));
-        url.push('/');
-        url.push_str(&sentinel_config.master_name);
+        let node_info = SentinelNodeConnectionInfo {
+            tls_mode: None,
+            redis_connection_info: Some(master_connection_info(config)?),
+        };
 
         // 记录连接信息（不包含密码）
         tracing::info!(
@@ -143,14 +264,22 @@ impl RedisProvider for DefaultRedisProvider {
             nodes.len()
         );
 
-        // Note: We removed the manual map_addr logic because using redis+sentinel://
-        // is required for automatic failover support in ConnectionManager.
-        // In test environments with NAT/Docker, ensure Sentinels report reachable IPs
-        // or use host networking.
-
-        let client = Client::open(url)?;
+        // 主节点地址在建立连接时向哨兵查询，依次尝试各哨兵节点
+        let discovery_timeout = master_discovery_timeout(config);
+        let mut sentinel = Sentinel::build(nodes)?;
+        let client = timeout(
+            discovery_timeout,
+            sentinel.async_master_for(&sentinel_config.master_name, Some(&node_info)),
+        )
+        .await
+        .map_err(|_| {
+            CacheError::Timeout(format!(
+                "Sentinel master discovery for '{}' timed out after {}ms",
+                sentinel_config.master_name,
+                discovery_timeout.as_millis()
+            ))
+        })??;
 
-        // Create connection manager which handles reconnection and failover automatically
         let manager = timeout(
             Duration::from_millis(config.connection_timeout_ms),
             client.get_connection_manager(),
@@ -162,18 +291,10 @@ impl RedisProvider for DefaultRedisProvider {
                 config.connection_timeout_ms
             ))
         })??;
-
-        // 单独进行密码认证（如果配置了密码）
-        // 这样避免了将密码包含在 URL 中，防止密码泄露到日志
-        if let Some(password) = &config.password {
-            let mut conn = manager.clone();
-            let _: String = redis::cmd("AUTH")
-                .arg(password.expose_secret())
-                .query_async(&mut conn)
-                .await
-                .map_err(|e| CacheError::L2Error(format!("Redis authentication failed: {}", e)))?;
-            tracing::info!("Redis authentication successful (sentinel mode)");
-        }
+        tracing::info!(
+            "Connected to Sentinel master: {}",
+            sentinel_config.master_name
+        );
 
         // For slave/replica connection, we can create a separate connection if needed.
         // Currently we return None as the primary requirement is master failover.
@@ -181,3 +302,426 @@ impl RedisProvider for DefaultRedisProvider {
         Ok((client, manager, None))
     }
 }
+
+/// 为建立连接时的Redis错误附加脱敏后的目标地址
+///
+/// 保留错误的分类（连接被拒绝、超时、认证失败），其余I/O错误映射为可重试的后端错误
+///
+/// # 参数
+///
+/// * `e` - Redis错误
+/// * `target` - 脱敏后的目标地址
+///
+/// # 返回值
+///
+/// 返回附加了目标地址的缓存错误
+fn connection_error(e: redis::RedisError, target: &str) -> CacheError {
+    match CacheError::from(e) {
+        CacheError::ConnectionRefused(msg) => {
+            CacheError::ConnectionRefused(format!("{} (target: {})", msg, target))
+        }
+        CacheError::Timeout(msg) => CacheError::Timeout(format!("{} (target: {})", msg, target)),
+        CacheError::AuthenticationFailed(msg) => {
+            CacheError::AuthenticationFailed(format!("{} (target: {})", msg, target))
+        }
+        CacheError::RedisError(e) if e.is_io_error() => {
+            CacheError::BackendError(format!("{} (target: {})", e, target))
+        }
+        other => other,
+    }
+}
+
+/// 通过哨兵发现主节点的超时时间
+///
+/// 未配置 `master_discovery_timeout_ms` 时使用 `connection_timeout_ms`
+///
+/// # 参数
+///
+/// * `config` - L2缓存配置
+///
+/// # 返回值
+///
+/// 返回发现超时时间
+fn master_discovery_timeout(config: &L2Config) -> Duration {
+    let timeout_ms = config
+        .sentinel
+        .as_ref()
+        .and_then(|sentinel| sentinel.master_discovery_timeout_ms)
+        .unwrap_or(config.connection_timeout_ms);
+    Duration::from_millis(timeout_ms)
+}
+
+/// 解析哨兵节点地址并设置哨兵密码
+///
+/// 节点地址可以带或不带 `redis://` 前缀
+///
+/// # 参数
+///
+/// * `node` - 哨兵节点地址
+/// * `password` - 哨兵密码
+///
+/// # 返回值
+///
+/// 返回哨兵节点的连接信息
+fn sentinel_node_info(node: &str, password: Option<&SecretString>) -> Result<ConnectionInfo> {
+    let address = node
+        .trim()
+        .trim_start_matches("redis://")
+        .trim_start_matches("redis+sentinel://")
+        .trim_start_matches("http://");
+    let mut info = format!("redis://{}", address).into_connection_info()?;
+    if let Some(password) = password {
+        info.redis.password = Some(password.expose_secret().to_string());
+    }
+    Ok(info)
+}
+
+/// 构建哨兵模式下主节点的连接参数
+///
+/// 包含数据库编号与 `username`/`password` 认证信息，客户端在每次连接主节点时使用
+///
+/// # 参数
+///
+/// * `config` - L2缓存配置
+///
+/// # 返回值
+///
+/// 返回主节点的连接参数，数据库编号超出范围或只配置了用户名时返回配置错误
+fn master_connection_info(config: &L2Config) -> Result<RedisConnectionInfo> {
+    let mut info = RedisConnectionInfo::default();
+    if let Some(database) = config.database {
+        if database > MAX_REDIS_DATABASE {
+            return Err(CacheError::Configuration(format!(
+                "database must be between 0 and {}, got {}",
+                MAX_REDIS_DATABASE, database
+            )));
+        }
+        info.db = i64::from(database);
+    }
+    apply_credentials(&mut info, config)?;
+    Ok(info)
+}
+
+/// 读取PEM文件内容
+fn read_pem_file(path: &str, description: &str) -> Result<Vec<u8>> {
+    std::fs::read(path).map_err(|e| {
+        CacheError::Configuration(format!(
+            "Failed to read TLS {} '{}': {}",
+            description, path, e
+        ))
+    })
+}
+
+/// 根据配置加载TLS证书
+///
+/// 客户端证书与私钥必须同时配置；未配置任何证书路径时返回 `None`。
+///
+/// # 参数
+///
+/// * `config` - L2缓存配置
+///
+/// # 返回值
+///
+/// 返回TLS证书集合，文件缺失或不可读时返回配置错误
+fn load_tls_certificates(config: &L2Config) -> Result<Option<TlsCertificates>> {
+    let client_tls = match (&config.tls_client_cert_path, &config.tls_client_key_path) {
+        (Some(cert_path), Some(key_path)) => Some(ClientTlsConfig {
+            client_cert: read_pem_file(cert_path, "client certificate")?,
+            client_key: read_pem_file(key_path, "client key")?,
+        }),
+        (None, None) => None,
+        (Some(_), None) => {
+            return Err(CacheError::Configuration(
+                "tls_client_cert_path is set but tls_client_key_path is missing".to_string(),
+            ))
+        }
+        (None, Some(_)) => {
+            return Err(CacheError::Configuration(
+                "tls_client_key_path is set but tls_client_cert_path is missing".to_string(),
+            ))
+        }
+    };
+
+    let root_cert = config
+        .tls_ca_cert_path
+        .as_deref()
+        .map(|path| read_pem_file(path, "CA certificate"))
+        .transpose()?;
+
+    if client_tls.is_none() && root_cert.is_none() {
+        return Ok(None);
+    }
+
+    Ok(Some(TlsCertificates {
+        client_tls,
+        root_cert,
+    }))
+}
+
+/// 使用自定义证书构建TLS客户端
+///
+/// # 参数
+///
+/// * `info` - 连接信息，必须使用 `rediss://` 协议
+/// * `certs` - TLS证书集合
+///
+/// # 返回值
+///
+/// 返回Redis客户端，证书内容无效时返回配置错误
+fn build_tls_client(info: ConnectionInfo, certs: TlsCertificates) -> Result<Client> {
+    Client::build_with_tls(info, certs).map_err(|e| {
+        CacheError::Configuration(format!("Failed to build TLS client configuration: {}", e))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use redis::ConnectionAddr;
+    use std::io::Write;
+    use std::sync::{Arc, Mutex};
+
+    /// 收集日志输出的写入器
+    #[derive(Clone, Default)]
+    struct LogBuffer(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for LogBuffer {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    /// 解析地址并返回 (地址, 是否使用TLS, 日志输出)
+    fn resolve(connection_string: &str, enable_tls: bool) -> (String, bool, String) {
+        let config = L2Config {
+            connection_string: connection_string.to_string().into(),
+            enable_tls,
+            ..Default::default()
+        };
+
+        let logs = LogBuffer::default();
+        let subscriber = tracing_subscriber::fmt()
+            .with_writer({
+                let logs = logs.clone();
+                move || logs.clone()
+            })
+            .with_ansi(false)
+            .finish();
+        let url = tracing::subscriber::with_default(subscriber, || resolve_standalone_url(&config));
+
+        let client = Client::open(url.as_str()).unwrap();
+        let tls = matches!(
+            client.get_connection_info().addr,
+            ConnectionAddr::TcpTls { .. }
+        );
+        let output = String::from_utf8(logs.0.lock().unwrap().clone()).unwrap();
+        (url, tls, output)
+    }
+
+    #[test]
+    fn test_rediss_scheme_forces_tls() {
+        let (url, tls, output) = resolve("rediss://127.0.0.1:6380/0", false);
+        assert_eq!(url, "rediss://127.0.0.1:6380/0");
+        assert!(tls);
+        assert!(!output.contains("WARN"));
+    }
+
+    #[test]
+    fn test_enable_tls_with_redis_scheme_warns() {
+        let (url, tls, output) = resolve("redis://127.0.0.1:6379/0", true);
+        assert_eq!(url, "rediss://127.0.0.1:6379/0");
+        assert!(tls);
+        assert!(output.contains("WARN"));
+        assert!(output.contains("enable_tls=true but connection string uses redis:// scheme"));
+    }
+
+    #[test]
+    fn test_plain_redis_scheme_without_tls() {
+        let (url, tls, output) = resolve("redis://127.0.0.1:6379", false);
+        assert_eq!(url, "redis://127.0.0.1:6379");
+        assert!(!tls);
+        assert!(output.is_empty());
+    }
+
+    /// 测试证书目录
+    fn fixture(name: &str) -> String {
+        format!("{}/tests/fixtures/tls/{}", env!("CARGO_MANIFEST_DIR"), name)
+    }
+
+    fn mtls_config() -> L2Config {
+        L2Config {
+            connection_string: "rediss://127.0.0.1:6380".to_string().into(),
+            tls_client_cert_path: Some(fixture("client.pem")),
+            tls_client_key_path: Some(fixture("client.key")),
+            tls_ca_cert_path: Some(fixture("ca.pem")),
+            retry: Default::default(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_build_mtls_client_from_paths() {
+        let config = mtls_config();
+        let certs = load_tls_certificates(&config).unwrap().unwrap();
+        assert!(certs.client_tls.is_some());
+        assert!(certs.root_cert.is_some());
+
+        let info = connection_info(&resolve_standalone_url(&config), &config).unwrap();
+        let client = build_tls_client(info, certs).unwrap();
+        assert!(matches!(
+            client.get_connection_info().addr,
+            ConnectionAddr::TcpTls {
+                tls_params: Some(_),
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_database_overrides_connection_string() {
+        let config = L2Config {
+            connection_string: "redis://127.0.0.1:6379/2".to_string().into(),
+            database: Some(5),
+            ..Default::default()
+        };
+        let info = connection_info(&resolve_standalone_url(&config), &config).unwrap();
+        assert_eq!(info.redis.db, 5);
+
+        let config = L2Config {
+            database: Some(MAX_REDIS_DATABASE + 1),
+            ..Default::default()
+        };
+        assert!(matches!(
+            connection_info(&resolve_standalone_url(&config), &config),
+            Err(CacheError::Configuration(_))
+        ));
+    }
+
+    #[test]
+    fn test_acl_username_flows_into_connection_info() {
+        let config = L2Config {
+            connection_string: "redis://127.0.0.1:6379".to_string().into(),
+            username: Some("app".to_string()),
+            password: Some("secret".to_string().into()),
+            ..Default::default()
+        };
+        let mut info = connection_info(&resolve_standalone_url(&config), &config).unwrap();
+        apply_credentials(&mut info.redis, &config).unwrap();
+        assert_eq!(info.redis.username.as_deref(), Some("app"));
+        assert_eq!(info.redis.password.as_deref(), Some("secret"));
+
+        // 只配置密码时以默认用户认证
+        let config = L2Config {
+            username: None,
+            ..config
+        };
+        let mut info = connection_info(&resolve_standalone_url(&config), &config).unwrap();
+        apply_credentials(&mut info.redis, &config).unwrap();
+        assert_eq!(info.redis.username, None);
+        assert_eq!(info.redis.password.as_deref(), Some("secret"));
+
+        let config = L2Config {
+            username: Some("app".to_string()),
+            password: None,
+            ..Default::default()
+        };
+        let mut info = connection_info(&resolve_standalone_url(&config), &config).unwrap();
+        assert!(matches!(
+            apply_credentials(&mut info.redis, &config),
+            Err(CacheError::Configuration(_))
+        ));
+    }
+
+    fn sentinel_config(master_discovery_timeout_ms: Option<u64>) -> L2Config {
+        L2Config {
+            connection_timeout_ms: 3000,
+            sentinel: Some(crate::config::SentinelConfig {
+                master_name: "mymaster".to_string(),
+                nodes: vec!["redis://127.0.0.1:26379".to_string()],
+                sentinel_password: Some("sentinel-secret".to_string().into()),
+                master_discovery_timeout_ms,
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_master_discovery_timeout() {
+        assert_eq!(
+            master_discovery_timeout(&sentinel_config(Some(250))),
+            Duration::from_millis(250)
+        );
+        // 未配置时使用连接超时
+        assert_eq!(
+            master_discovery_timeout(&sentinel_config(None)),
+            Duration::from_millis(3000)
+        );
+    }
+
+    #[test]
+    fn test_sentinel_auth_flows_into_connection_info() {
+        let config = L2Config {
+            username: Some("app".to_string()),
+            password: Some("master-secret".to_string().into()),
+            database: Some(3),
+            ..sentinel_config(None)
+        };
+        let sentinel = config.sentinel.as_ref().unwrap();
+
+        let node =
+            sentinel_node_info(&sentinel.nodes[0], sentinel.sentinel_password.as_ref()).unwrap();
+        assert_eq!(
+            node.addr,
+            ConnectionAddr::Tcp("127.0.0.1".to_string(), 26379)
+        );
+        assert_eq!(node.redis.password.as_deref(), Some("sentinel-secret"));
+        assert_eq!(node.redis.username, None);
+
+        // 主节点使用L2配置中的认证信息与数据库编号
+        let master = master_connection_info(&config).unwrap();
+        assert_eq!(master.username.as_deref(), Some("app"));
+        assert_eq!(master.password.as_deref(), Some("master-secret"));
+        assert_eq!(master.db, 3);
+    }
+
+    #[test]
+    fn test_no_tls_paths_returns_none() {
+        assert!(load_tls_certificates(&L2Config::default())
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn test_missing_client_cert_file_fails() {
+        let config = L2Config {
+            tls_client_cert_path: Some(fixture("missing.pem")),
+            ..mtls_config()
+        };
+
+        match load_tls_certificates(&config) {
+            Err(CacheError::Configuration(msg)) => {
+                assert!(msg.contains("Failed to read TLS client certificate"));
+                assert!(msg.contains("missing.pem"));
+            }
+            other => panic!("expected Configuration error, got {:?}", other.err()),
+        }
+    }
+
+    #[test]
+    fn test_client_cert_without_key_fails() {
+        let config = L2Config {
+            tls_client_key_path: None,
+            ..mtls_config()
+        };
+
+        assert!(matches!(
+            load_tls_certificates(&config),
+            Err(CacheError::Configuration(msg)) if msg.contains("tls_client_key_path")
+        ));
+    }
+}
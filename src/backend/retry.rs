@@ -0,0 +1,93 @@
+//! Copyright (c) 2025-2026, Kirky.X
+//!
+//! MIT License
+//!
+//! 该模块定义了L2命令的重试与退避机制。
+
+use crate::config::RetryConfig;
+use crate::error::{CacheError, Result};
+use crate::sync::common::calculate_retry_delay;
+use crate::utils::random_u64;
+use std::future::Future;
+use std::time::Duration;
+use tokio::time::Instant;
+use tracing::debug;
+
+/// 计算第 `attempt` 次重试前的退避时间
+///
+/// 以 `base_backoff_ms * 2^attempt` 为上限；启用抖动时在上限的一半到上限之间随机取值。
+///
+/// # 参数
+///
+/// * `policy` - 重试配置
+/// * `attempt` - 重试序号（从0开始）
+///
+/// # 返回值
+///
+/// 返回退避时间
+pub fn backoff_delay(policy: &RetryConfig, attempt: u32) -> Duration {
+    let delay = calculate_retry_delay(attempt.min(16) as usize, policy.base_backoff_ms);
+    if !policy.jitter || delay.is_zero() {
+        return delay;
+    }
+
+    let half = delay / 2;
+    let jitter_ms = random_u64() % (half.as_millis() as u64 + 1);
+    half + Duration::from_millis(jitter_ms)
+}
+
+/// 按重试策略执行操作
+///
+/// 仅当错误为瞬时故障（[`CacheError::is_transient`]）时重试。每次尝试都受剩余时间预算约束，
+/// 剩余时间不足以完成退避等待时直接返回最后一次的错误。
+///
+/// # 参数
+///
+/// * `policy` - 重试配置
+/// * `budget` - 包含所有重试在内的总时间预算
+/// * `op` - 要执行的操作，每次尝试调用一次
+///
+/// # 返回值
+///
+/// 返回操作结果；预算耗尽时返回超时错误
+pub async fn retry_with_backoff<T, F, Fut>(
+    policy: &RetryConfig,
+    budget: Duration,
+    mut op: F,
+) -> Result<T>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T>>,
+{
+    let deadline = Instant::now() + budget;
+    let mut attempt = 0;
+
+    loop {
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        let result = match tokio::time::timeout(remaining, op()).await {
+            Ok(result) => result,
+            Err(_) => Err(CacheError::Timeout(format!(
+                "L2 command exceeded {}ms",
+                budget.as_millis()
+            ))),
+        };
+
+        let err = match result {
+            Ok(value) => return Ok(value),
+            Err(e) if e.is_transient() && attempt < policy.max_retries => e,
+            Err(e) => return Err(e),
+        };
+
+        let delay = backoff_delay(policy, attempt);
+        if Instant::now() + delay >= deadline {
+            return Err(err);
+        }
+
+        attempt += 1;
+        debug!(
+            "Transient L2 error, retrying in {:?} (attempt {}/{}): {}",
+            delay, attempt, policy.max_retries, err
+        );
+        tokio::time::sleep(delay).await;
+    }
+}
@@ -0,0 +1,82 @@
+//! Copyright (c) 2025-2026, Kirky.X
+//!
+//! MIT License
+//!
+//! 该模块定义了客户端分片使用的一致性哈希环。
+
+use std::io::Cursor;
+
+/// 版本键后缀，与数据键路由到同一节点
+const VERSION_SUFFIX: &str = ":version";
+
+/// 计算64位哈希值
+fn hash64(data: &[u8]) -> u64 {
+    murmur3::murmur3_x64_128(&mut Cursor::new(data), 0).unwrap_or(0) as u64
+}
+
+/// 一致性哈希环
+///
+/// 每个节点在环上占据 `virtual_nodes` 个位置，键顺时针落到第一个虚拟节点所属的节点。
+/// 增减节点时只有相邻区间的键需要迁移
+#[derive(Debug, Clone)]
+pub struct HashRing {
+    /// 按哈希值排序的 `(哈希值, 节点下标)`
+    ring: Vec<(u64, usize)>,
+    /// 节点数量
+    node_count: usize,
+}
+
+impl HashRing {
+    /// 创建哈希环
+    ///
+    /// # 参数
+    ///
+    /// * `nodes` - 节点标识（通常为连接地址），决定虚拟节点在环上的位置
+    /// * `virtual_nodes` - 每个节点的虚拟节点数
+    ///
+    /// # 返回值
+    ///
+    /// 返回新的哈希环
+    pub fn new(nodes: &[String], virtual_nodes: usize) -> Self {
+        let mut ring: Vec<(u64, usize)> = nodes
+            .iter()
+            .enumerate()
+            .flat_map(|(index, node)| {
+                (0..virtual_nodes.max(1))
+                    .map(move |i| (hash64(format!("{}#{}", node, i).as_bytes()), index))
+            })
+            .collect();
+        ring.sort_unstable();
+        Self {
+            ring,
+            node_count: nodes.len(),
+        }
+    }
+
+    /// 节点数量
+    pub fn node_count(&self) -> usize {
+        self.node_count
+    }
+
+    /// 计算键所属的节点
+    ///
+    /// `key:version` 版本键按数据键路由，保证两者位于同一节点
+    ///
+    /// # 参数
+    ///
+    /// * `key` - 缓存键，可以是字符串键或二进制原始键
+    ///
+    /// # 返回值
+    ///
+    /// 返回节点下标
+    pub fn node_for(&self, key: &(impl AsRef<[u8]> + ?Sized)) -> usize {
+        let key = key.as_ref();
+        let key = key.strip_suffix(VERSION_SUFFIX.as_bytes()).unwrap_or(key);
+        let hash = hash64(key);
+        let pos = self.ring.partition_point(|(h, _)| *h < hash);
+        self.ring
+            .get(pos)
+            .or_else(|| self.ring.first())
+            .map_or(0, |(_, index)| *index)
+    }
+}
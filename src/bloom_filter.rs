@@ -4,6 +4,8 @@
 //!
 //! 布隆过滤器实现 - 用于缓存穿透防护
 
+use crate::backend::l2::L2Backend;
+use crate::error::{CacheError, Result};
 use murmur3::murmur3_32;
 use std::collections::HashMap;
 use std::sync::Arc;
@@ -73,12 +75,7 @@ impl BloomFilter {
         let size = options.optimal_size();
         let num_hashes = options.optimal_num_hashes();
 
-        let mut seeds = Vec::with_capacity(num_hashes);
-        let mut seed = 0xc3f3e5f3u32;
-        for _ in 0..num_hashes {
-            seeds.push(seed);
-            seed = seed.wrapping_mul(0xc13fa9a9u32);
-        }
+        let seeds = hash_seeds(num_hashes);
 
         // 创建哈希缓存
         let hash_cache = Arc::new(RwLock::new(HashMap::new()));
@@ -99,15 +96,8 @@ impl BloomFilter {
         self.bit_array.len()
     }
 
-    fn calculate_positions(&self, mut item: &[u8]) -> Vec<usize> {
-        let bit_array_len = self.bit_array.len();
-        let mut positions = Vec::with_capacity(self.seeds.len());
-        for &seed in &self.seeds {
-            let hash = murmur3_32(&mut item, seed).unwrap_or(0);
-            let pos = (hash as usize) % (bit_array_len * 8);
-            positions.push(pos);
-        }
-        positions
+    fn calculate_positions(&self, item: &[u8]) -> Vec<usize> {
+        bit_positions(item, &self.seeds, self.bit_array.len() * 8)
     }
 
     pub fn contains(&self, item: &[u8]) -> bool {
@@ -306,6 +296,243 @@ impl BloomFilter {
     }
 }
 
+/// 生成各哈希函数使用的种子
+fn hash_seeds(num_hashes: usize) -> Vec<u32> {
+    let mut seeds = Vec::with_capacity(num_hashes);
+    let mut seed = 0xc3f3e5f3u32;
+    for _ in 0..num_hashes {
+        seeds.push(seed);
+        seed = seed.wrapping_mul(0xc13fa9a9u32);
+    }
+    seeds
+}
+
+/// 计算元素在位数组中对应的位置
+fn bit_positions(mut item: &[u8], seeds: &[u32], num_bits: usize) -> Vec<usize> {
+    seeds
+        .iter()
+        .map(|&seed| {
+            let hash = murmur3_32(&mut item, seed).unwrap_or(0);
+            (hash as usize) % num_bits
+        })
+        .collect()
+}
+
+/// Redis布隆过滤器的存储方式
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum RedisBloomMode {
+    /// RedisBloom模块的 `BF.*` 命令
+    Module,
+    /// 基于 `SETBIT`/`GETBIT` 的位图
+    Bitmap,
+}
+
+/// Redis布隆过滤器
+///
+/// 过滤器保存在Redis中，所有实例共享同一份数据，一个实例添加的键对其他实例立即可见。
+/// 检测到RedisBloom模块时使用 `BF.*` 命令，否则以位图实现，哈希方式与 [`BloomFilter`] 相同。
+/// 每次查询都需要访问Redis，可启用本地镜像以减少已存在键的查询延迟
+pub struct RedisBloomFilter {
+    options: BloomFilterOptions,
+    key: String,
+    backend: Arc<L2Backend>,
+    mode: RedisBloomMode,
+    seeds: Vec<u32>,
+    num_bits: usize,
+    mirror: Option<BloomFilterShared>,
+}
+
+impl RedisBloomFilter {
+    /// 创建Redis布隆过滤器
+    ///
+    /// 数据保存在 `oxcache:bloom:{name}` 键中。键已以位图形式存在时沿用位图，
+    /// 否则尝试 `BF.RESERVE`，服务器不支持该命令时退化为位图
+    ///
+    /// # 参数
+    ///
+    /// * `options` - 布隆过滤器参数
+    /// * `backend` - L2后端
+    /// * `mirror_local` - 是否在本地镜像一份过滤器
+    ///
+    /// # 返回值
+    ///
+    /// 返回创建的过滤器，检测存储方式失败时返回错误
+    pub async fn new(
+        options: BloomFilterOptions,
+        backend: Arc<L2Backend>,
+        mirror_local: bool,
+    ) -> Result<Self> {
+        let key = format!("oxcache:bloom:{}", options.name);
+        let mode = Self::detect_mode(&backend, &key, &options).await?;
+        let num_bits = (options.optimal_size() * 8).max(8);
+        let seeds = hash_seeds(options.optimal_num_hashes().max(1));
+        let mirror =
+            mirror_local.then(|| BloomFilterShared::new(BloomFilter::new(options.clone())));
+        Ok(Self {
+            options,
+            key,
+            backend,
+            mode,
+            seeds,
+            num_bits,
+            mirror,
+        })
+    }
+
+    /// 检测服务器支持的存储方式
+    async fn detect_mode(
+        backend: &L2Backend,
+        key: &str,
+        options: &BloomFilterOptions,
+    ) -> Result<RedisBloomMode> {
+        let mut pipe = redis::pipe();
+        pipe.cmd("TYPE").arg(key);
+        let (key_type,): (String,) = backend.query_pipeline(key, &pipe).await?;
+        if key_type == "string" {
+            return Ok(RedisBloomMode::Bitmap);
+        }
+
+        let mut pipe = redis::pipe();
+        pipe.cmd("BF.RESERVE")
+            .arg(key)
+            .arg(options.false_positive_rate)
+            .arg(options.expected_elements)
+            .ignore();
+        match backend.query_pipeline::<()>(key, &pipe).await {
+            Ok(()) => Ok(RedisBloomMode::Module),
+            Err(CacheError::RedisError(e)) if e.to_string().contains("item exists") => {
+                Ok(RedisBloomMode::Module)
+            }
+            Err(CacheError::RedisError(e))
+                if e.to_string().to_lowercase().contains("unknown command") =>
+            {
+                Ok(RedisBloomMode::Bitmap)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// 判断元素是否可能存在
+    ///
+    /// # 参数
+    ///
+    /// * `item` - 要检查的元素
+    ///
+    /// # 返回值
+    ///
+    /// 可能存在时返回true，一定不存在时返回false
+    pub async fn contains(&self, item: &[u8]) -> Result<bool> {
+        if let Some(mirror) = &self.mirror {
+            if mirror.contains(item) {
+                return Ok(true);
+            }
+        }
+
+        let mut pipe = redis::pipe();
+        let exists = match self.mode {
+            RedisBloomMode::Module => {
+                pipe.cmd("BF.EXISTS").arg(&self.key).arg(item);
+                let (exists,): (bool,) = self.backend.query_pipeline(&self.key, &pipe).await?;
+                exists
+            }
+            RedisBloomMode::Bitmap => {
+                for pos in bit_positions(item, &self.seeds, self.num_bits) {
+                    pipe.getbit(&self.key, pos);
+                }
+                let bits: Vec<bool> = self.backend.query_pipeline(&self.key, &pipe).await?;
+                bits.into_iter().all(|bit| bit)
+            }
+        };
+
+        if exists {
+            if let Some(mirror) = &self.mirror {
+                mirror.add(item).await;
+            }
+        }
+        Ok(exists)
+    }
+
+    /// 添加元素
+    ///
+    /// # 参数
+    ///
+    /// * `item` - 要添加的元素
+    ///
+    /// # 返回值
+    ///
+    /// 返回操作结果
+    pub async fn add(&self, item: &[u8]) -> Result<()> {
+        let mut pipe = redis::pipe();
+        match self.mode {
+            RedisBloomMode::Module => {
+                pipe.cmd("BF.ADD").arg(&self.key).arg(item).ignore();
+            }
+            RedisBloomMode::Bitmap => {
+                for pos in bit_positions(item, &self.seeds, self.num_bits) {
+                    pipe.setbit(&self.key, pos, true).ignore();
+                }
+            }
+        }
+        self.backend.query_pipeline::<()>(&self.key, &pipe).await?;
+
+        if let Some(mirror) = &self.mirror {
+            mirror.add(item).await;
+        }
+        Ok(())
+    }
+
+    /// 是否使用RedisBloom模块
+    pub fn uses_module(&self) -> bool {
+        self.mode == RedisBloomMode::Module
+    }
+
+    /// 过滤器在Redis中的键
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    pub fn name(&self) -> &str {
+        &self.options.name
+    }
+}
+
+/// 缓存客户端使用的布隆过滤器
+///
+/// 统一进程内与Redis两种后端。Redis不可用时查询按“可能存在”处理，
+/// 避免过滤器故障导致所有读取都被判定为不存在
+#[derive(Clone)]
+pub enum CacheBloomFilter {
+    /// 进程内过滤器
+    Local(BloomFilterShared),
+    /// 多实例共享的Redis过滤器
+    Redis(Arc<RedisBloomFilter>),
+}
+
+impl CacheBloomFilter {
+    /// 判断元素是否可能存在
+    pub async fn contains(&self, item: &[u8]) -> bool {
+        match self {
+            CacheBloomFilter::Local(filter) => filter.contains(item),
+            CacheBloomFilter::Redis(filter) => filter.contains(item).await.unwrap_or_else(|e| {
+                tracing::warn!("Bloom filter {} check failed: {}", filter.name(), e);
+                true
+            }),
+        }
+    }
+
+    /// 添加元素
+    pub async fn add(&self, item: &[u8]) {
+        match self {
+            CacheBloomFilter::Local(filter) => filter.add(item).await,
+            CacheBloomFilter::Redis(filter) => {
+                if let Err(e) = filter.add(item).await {
+                    tracing::warn!("Bloom filter {} add failed: {}", filter.name(), e);
+                }
+            }
+        }
+    }
+}
+
 /// 布隆过滤器统计信息
 #[derive(Clone, Debug)]
 pub struct BloomFilterStats {
@@ -4,23 +4,232 @@
 //!
 //! 该模块定义了管理员操作命令的实现。
 
+use crate::client::two_level::TwoLevelClient;
 use crate::client::CacheOps;
 use crate::manager::get_typed_client;
-use crate::sync::warmup::WarmupStatus;
+use crate::sync::warmup::{WarmupStatus, WARMUP_STATUS_ALL};
 use anyhow::{Context, Result};
 use clap::{Parser, Subcommand};
+use futures::{Stream, TryStreamExt};
+use std::io::Write;
+use std::path::PathBuf;
+use tracing::warn;
 
 pub async fn execute(args: &AdminArgs) -> Result<()> {
     match &args.command {
         AdminSubcommand::Clean(clean_args) => execute_clean(clean_args).await,
+        AdminSubcommand::Export(export_args) => execute_export(export_args).await,
         AdminSubcommand::Warmup(warmup_args) => execute_warmup(warmup_args).await,
     }
 }
 
+/// 试运行时展示的匹配键样例数量
+const DRY_RUN_SAMPLE_SIZE: usize = 10;
+
+/// 按模式清理和导出时每页扫描的键数
+pub const SCAN_PAGE_SIZE: usize = 1000;
+
+/// 流式处理时每处理多少个键输出一次进度
+pub const PROGRESS_INTERVAL: usize = 10_000;
+
+/// 累计已处理的键数，每跨过 `PROGRESS_INTERVAL` 的整数倍时调用一次回调
+struct Progress<F: FnMut(usize)> {
+    processed: usize,
+    report: F,
+}
+
+impl<F: FnMut(usize)> Progress<F> {
+    fn new(report: F) -> Self {
+        Self {
+            processed: 0,
+            report,
+        }
+    }
+
+    fn advance(&mut self, count: usize) {
+        let before = self.processed / PROGRESS_INTERVAL;
+        self.processed += count;
+        if self.processed / PROGRESS_INTERVAL > before {
+            (self.report)(self.processed);
+        }
+    }
+}
+
+/// 在标准错误输出打印进度，不干扰导出到标准输出的数据
+fn print_progress(processed: usize) {
+    eprintln!("  ... {} keys processed", processed);
+}
+
+/// 逐页删除键流中的键
+///
+/// 处理完一页后才拉取下一页，内存中最多只有一页键
+///
+/// # 参数
+///
+/// * `client` - 缓存客户端
+/// * `pages` - 按页产出键的流，见 [`TwoLevelClient::scan`]
+/// * `progress` - 每处理 `PROGRESS_INTERVAL` 个键以累计数调用一次
+///
+/// # 返回值
+///
+/// 返回删除的键数量
+pub async fn clean_pages<S>(
+    client: &dyn CacheOps,
+    pages: S,
+    progress: impl FnMut(usize),
+) -> Result<usize>
+where
+    S: Stream<Item = crate::error::Result<Vec<String>>>,
+{
+    let mut pages = std::pin::pin!(pages);
+    let mut progress = Progress::new(progress);
+    while let Some(page) = pages.try_next().await? {
+        let keys: Vec<&str> = page.iter().map(String::as_str).collect();
+        client.delete_many(&keys).await?;
+        progress.advance(keys.len());
+    }
+    Ok(progress.processed)
+}
+
+/// 逐页导出键流中的键及其L2中的值
+///
+/// 每行一个键，格式为 `<键>\t<值的十六进制>`；读取失败（如哈希类型的键）或已不存在的键被跳过。
+/// 处理完一页后才拉取下一页，内存中最多只有一页键
+///
+/// # 参数
+///
+/// * `client` - 缓存客户端
+/// * `pages` - 按页产出键的流，见 [`TwoLevelClient::scan`]
+/// * `out` - 导出目标
+/// * `progress` - 每处理 `PROGRESS_INTERVAL` 个键以累计数调用一次
+///
+/// # 返回值
+///
+/// 返回导出的键数量
+pub async fn export_pages<S>(
+    client: &dyn CacheOps,
+    pages: S,
+    out: &mut impl Write,
+    progress: impl FnMut(usize),
+) -> Result<usize>
+where
+    S: Stream<Item = crate::error::Result<Vec<String>>>,
+{
+    let mut pages = std::pin::pin!(pages);
+    let mut progress = Progress::new(progress);
+    let mut exported = 0;
+    while let Some(page) = pages.try_next().await? {
+        for key in &page {
+            match client.get_l2_bytes(key).await {
+                Ok(Some(value)) => {
+                    let hex: String = value.iter().map(|b| format!("{:02x}", b)).collect();
+                    writeln!(out, "{}\t{}", key, hex)?;
+                    exported += 1;
+                }
+                Ok(None) => {}
+                Err(e) => warn!("Skipping key {} during export: {}", key, e),
+            }
+        }
+        progress.advance(page.len());
+    }
+    out.flush()?;
+    Ok(exported)
+}
+
+/// 清理操作的试运行结果
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CleanPreview {
+    /// 按模式清理时匹配到的键
+    Pattern {
+        /// 匹配的键总数
+        count: usize,
+        /// 匹配键的样例，最多 `DRY_RUN_SAMPLE_SIZE` 个
+        sample: Vec<String>,
+    },
+    /// 全量清理时受影响的范围，未选择的层级为None
+    Full {
+        /// L1缓存条目数
+        l1_entries: Option<u64>,
+        /// L2所在Redis数据库的键数量（`DBSIZE`）
+        l2_dbsize: Option<u64>,
+    },
+}
+
+/// 计算清理操作将影响的范围，不删除任何数据
+///
+/// # 参数
+///
+/// * `client` - 缓存客户端
+/// * `args` - 清理参数
+///
+/// # 返回值
+///
+/// 返回试运行结果
+pub async fn preview_clean(client: &TwoLevelClient, args: &CleanArgs) -> Result<CleanPreview> {
+    if let Some(pattern) = &args.pattern {
+        let mut pages = std::pin::pin!(client.scan(pattern, SCAN_PAGE_SIZE));
+        let mut count = 0;
+        let mut sample = Vec::new();
+        while let Some(page) = pages.try_next().await? {
+            count += page.len();
+            let remaining = DRY_RUN_SAMPLE_SIZE - sample.len();
+            sample.extend(page.into_iter().take(remaining));
+        }
+        return Ok(CleanPreview::Pattern { count, sample });
+    }
+
+    let l1_entries = if args.l1 {
+        Some(client.l1_len().await)
+    } else {
+        None
+    };
+    let l2_dbsize = if args.l2 {
+        Some(client.l2_dbsize().await?)
+    } else {
+        None
+    };
+    Ok(CleanPreview::Full {
+        l1_entries,
+        l2_dbsize,
+    })
+}
+
+fn display_clean_preview(service: &str, preview: &CleanPreview) {
+    println!("=== Dry run for '{}' (nothing deleted) ===\n", service);
+    match preview {
+        CleanPreview::Pattern { count, sample } => {
+            println!("Matching keys:   {}", count);
+            for key in sample {
+                println!("  - {}", key);
+            }
+            if *count > sample.len() {
+                println!("  ... and {} more", count - sample.len());
+            }
+        }
+        CleanPreview::Full {
+            l1_entries,
+            l2_dbsize,
+        } => {
+            if let Some(entries) = l1_entries {
+                println!("L1 entries:      {}", entries);
+            }
+            if let Some(size) = l2_dbsize {
+                println!("L2 DBSIZE:       {}", size);
+            }
+        }
+    }
+}
+
 async fn execute_clean(args: &CleanArgs) -> Result<()> {
     let client = get_typed_client(&args.service)
         .with_context(|| format!("Service '{}' not found", args.service))?;
 
+    if args.dry_run {
+        let preview = preview_clean(client.as_ref(), args).await?;
+        display_clean_preview(&args.service, &preview);
+        return Ok(());
+    }
+
     if args.confirm {
         println!("Preparing to clean cache for service: {}", args.service);
         if args.l1 {
@@ -32,6 +241,9 @@ async fn execute_clean(args: &CleanArgs) -> Result<()> {
         if args.wal {
             println!("  - WAL logs");
         }
+        if let Some(pattern) = &args.pattern {
+            println!("  - Keys matching '{}'", pattern);
+        }
         print!("\nDo you want to continue? [y/N]: ");
 
         let mut input = String::new();
@@ -43,6 +255,19 @@ async fn execute_clean(args: &CleanArgs) -> Result<()> {
         }
     }
 
+    if let Some(pattern) = &args.pattern {
+        println!("Cleaning keys matching '{}'...", pattern);
+        let removed = clean_pages(
+            client.as_ref(),
+            client.scan(pattern, SCAN_PAGE_SIZE),
+            print_progress,
+        )
+        .await?;
+        println!("{} keys removed.", removed);
+        println!("\n✅ Cleanup completed for service: {}", args.service);
+        return Ok(());
+    }
+
     if args.l1 {
         println!("Cleaning L1 cache...");
         client.clear_l1().await?;
@@ -66,6 +291,32 @@ async fn execute_clean(args: &CleanArgs) -> Result<()> {
     Ok(())
 }
 
+async fn execute_export(args: &ExportArgs) -> Result<()> {
+    let client = get_typed_client(&args.service)
+        .with_context(|| format!("Service '{}' not found", args.service))?;
+    let pattern = args
+        .pattern
+        .clone()
+        .unwrap_or_else(|| format!("{}:*", args.service));
+    let pages = client.scan(&pattern, SCAN_PAGE_SIZE);
+
+    eprintln!("Exporting keys matching '{}'...", pattern);
+    let exported = match &args.output {
+        Some(path) => {
+            let file = std::fs::File::create(path)
+                .with_context(|| format!("Cannot create {}", path.display()))?;
+            let mut out = std::io::BufWriter::new(file);
+            export_pages(client.as_ref(), pages, &mut out, print_progress).await?
+        }
+        None => {
+            let mut out = std::io::stdout();
+            export_pages(client.as_ref(), pages, &mut out, print_progress).await?
+        }
+    };
+    eprintln!("{} keys exported.", exported);
+    Ok(())
+}
+
 async fn execute_warmup(args: &WarmupArgs) -> Result<()> {
     let client = get_typed_client(&args.service)
         .with_context(|| format!("Service '{}' not found", args.service))?;
@@ -73,7 +324,7 @@ async fn execute_warmup(args: &WarmupArgs) -> Result<()> {
     if args.status {
         let warmup_mgr = client.warmup_manager();
         if let Some(mgr) = warmup_mgr {
-            let status = mgr.get_status("all").await;
+            let status = mgr.get_status(WARMUP_STATUS_ALL).await;
             println!("=== Warmup Status for '{}' ===\n", args.service);
             display_warmup_status(&status);
         } else {
@@ -143,6 +394,27 @@ pub struct CleanArgs {
 
     #[arg(short, long, help = "Skip confirmation")]
     pub confirm: bool,
+
+    #[arg(long, help = "Only clean keys matching the glob pattern")]
+    pub pattern: Option<String>,
+
+    #[arg(long, help = "Report what would be cleaned without deleting anything")]
+    pub dry_run: bool,
+}
+
+#[derive(Parser, Debug)]
+pub struct ExportArgs {
+    #[arg(short, long, help = "Service name")]
+    pub service: String,
+
+    #[arg(
+        long,
+        help = "Only export keys matching the glob pattern (default: <service>:*)"
+    )]
+    pub pattern: Option<String>,
+
+    #[arg(short, long, help = "Write to this file instead of stdout")]
+    pub output: Option<PathBuf>,
 }
 
 #[derive(Parser, Debug)]
@@ -165,6 +437,9 @@ pub enum AdminSubcommand {
     #[command(name = "clean", about = "Clear cache data")]
     Clean(CleanArgs),
 
+    #[command(name = "export", about = "Export cached keys and values")]
+    Export(ExportArgs),
+
     #[command(name = "warmup", about = "Control cache warmup")]
     Warmup(WarmupArgs),
 }
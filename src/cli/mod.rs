@@ -20,7 +20,7 @@ pub enum Commands {
     #[command(name = "status", about = "Query cache service status")]
     Status(StatusArgs),
 
-    #[command(name = "admin", about = "Admin operations (clean, warmup)")]
+    #[command(name = "admin", about = "Admin operations (clean, export, warmup)")]
     Admin(AdminArgs),
 
     #[command(name = "metrics", about = "Get cache metrics")]
@@ -52,7 +52,10 @@ mod admin;
 mod metrics;
 mod status;
 
-pub use admin::{AdminArgs, AdminSubcommand, CleanArgs, WarmupArgs};
+pub use admin::{
+    clean_pages, export_pages, preview_clean, AdminArgs, AdminSubcommand, CleanArgs, CleanPreview,
+    ExportArgs, WarmupArgs, PROGRESS_INTERVAL, SCAN_PAGE_SIZE,
+};
 
 pub async fn run() -> Result<()> {
     let cli = Cli::parse();
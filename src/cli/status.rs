@@ -5,7 +5,7 @@
 //! 该模块定义了状态查询命令的实现。
 
 use crate::cli::StatusArgs;
-use crate::manager::{get_typed_client, MANAGER};
+use crate::manager::{get_typed_client, CacheManager};
 use crate::recovery::health::HealthState;
 use anyhow::{Context, Result};
 
@@ -19,13 +19,13 @@ pub async fn execute(args: &StatusArgs) -> Result<()> {
     } else {
         println!("=== Cache Services Status ===\n");
 
-        if MANAGER.is_empty() {
+        let service_names = CacheManager::service_names();
+        if service_names.is_empty() {
             println!("No cache services registered.");
             return Ok(());
         }
 
-        for entry in MANAGER.iter() {
-            let service_name = entry.key().clone();
+        for service_name in service_names {
             let client = get_typed_client(&service_name)?;
             let state = client.get_health_state().await;
             print_service_status(&service_name, &state, args.verbose);
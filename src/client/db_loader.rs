@@ -8,7 +8,10 @@
 
 use crate::error::{CacheError, Result};
 use async_trait::async_trait;
+use sea_orm::sea_query::{Alias, Expr, Query, SelectStatement};
+use sea_orm::{ConnectionTrait, DatabaseConnection, QueryResult};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::sync::Arc;
 use tracing::{debug, error, info, instrument, warn};
 
@@ -260,6 +263,50 @@ impl DbFallbackManager {
         }
     }
 
+    /// 批量回源加载多个键，并保留每个键独立的结果
+    ///
+    /// 先通过一次批量查询加载所有键；若批量查询失败，则逐个键回源，
+    /// 保证单个键的失败不会导致整批失败
+    ///
+    /// # 参数
+    ///
+    /// * `keys` - 缓存键列表
+    ///
+    /// # 返回值
+    ///
+    /// 返回每个键对应的加载结果，数据库中不存在的键对应 `Ok(None)`
+    #[instrument(skip(self), level = "info")]
+    pub async fn fallback_load_many(
+        &self,
+        keys: &[&str],
+    ) -> HashMap<String, Result<Option<Vec<u8>>>> {
+        let mut results = HashMap::with_capacity(keys.len());
+        if keys.is_empty() {
+            return results;
+        }
+
+        let batch_keys: Vec<String> = keys.iter().map(|k| k.to_string()).collect();
+        match self.fallback_load_batch(batch_keys).await {
+            Ok(loaded) => {
+                let mut loaded: HashMap<String, Vec<u8>> = loaded.into_iter().collect();
+                for key in keys {
+                    results.insert(key.to_string(), Ok(loaded.remove(*key)));
+                }
+            }
+            Err(e) => {
+                warn!(
+                    "Batch database fallback failed, falling back to per-key loads: {}",
+                    e
+                );
+                for key in keys {
+                    results.insert(key.to_string(), self.fallback_load(key).await);
+                }
+            }
+        }
+
+        results
+    }
+
     /// 使用超时机制尝试加载数据
     async fn try_load_with_timeout(&self, key: &str) -> Result<Option<Vec<u8>>> {
         match tokio::time::timeout(
@@ -406,6 +453,148 @@ impl DbLoader for SqlDbLoader {
     }
 }
 
+/// 缓存键到主键值的映射函数
+pub type KeyToPrimaryKey = dyn Fn(&str) -> Option<sea_orm::Value> + Send + Sync;
+
+/// 基于sea-orm的数据库加载器
+///
+/// 按表名与列名映射，通过缓存键映射出的主键查询一行，返回值列中保存的序列化数据。
+/// 值列必须保存与客户端序列化器一致的数据（如JSON文本），支持BLOB与文本列；
+/// 主键映射失败、行不存在或值为NULL时视为未命中
+pub struct SeaOrmDbLoader {
+    /// 数据库连接
+    db: Arc<DatabaseConnection>,
+    /// 表名（已验证）
+    table_name: String,
+    /// 主键列名（已验证）
+    key_column: String,
+    /// 值列名（已验证）
+    value_column: String,
+    /// 缓存键到主键值的映射
+    key_to_pk: Arc<KeyToPrimaryKey>,
+}
+
+impl std::fmt::Debug for SeaOrmDbLoader {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SeaOrmDbLoader")
+            .field("table_name", &self.table_name)
+            .field("key_column", &self.key_column)
+            .field("value_column", &self.value_column)
+            .finish()
+    }
+}
+
+impl SeaOrmDbLoader {
+    /// 创建新的sea-orm数据库加载器
+    ///
+    /// # 参数
+    ///
+    /// * `db` - 数据库连接
+    /// * `table_name` - 表名
+    /// * `key_column` - 主键列名
+    /// * `value_column` - 值列名
+    /// * `key_to_pk` - 缓存键到主键值的映射，返回None表示该键不对应任何行
+    ///
+    /// # 返回值
+    ///
+    /// 返回新的sea-orm数据库加载器实例，表名或列名不是合法的SQL标识符时返回错误
+    pub fn new<F>(
+        db: Arc<DatabaseConnection>,
+        table_name: String,
+        key_column: String,
+        value_column: String,
+        key_to_pk: F,
+    ) -> Result<Self>
+    where
+        F: Fn(&str) -> Option<sea_orm::Value> + Send + Sync + 'static,
+    {
+        for (kind, identifier) in [
+            ("table", &table_name),
+            ("key column", &key_column),
+            ("value column", &value_column),
+        ] {
+            if !validate_sql_identifier(identifier) {
+                return Err(CacheError::InvalidInput(format!(
+                    "Invalid {} name: {}. Name must be a valid SQL identifier.",
+                    kind, identifier
+                )));
+            }
+        }
+
+        Ok(Self {
+            db,
+            table_name,
+            key_column,
+            value_column,
+            key_to_pk: Arc::new(key_to_pk),
+        })
+    }
+
+    /// 按主键查询值列的语句
+    fn select_by_pk(&self, pk: sea_orm::Value) -> SelectStatement {
+        Query::select()
+            .column(Alias::new(&self.value_column))
+            .from(Alias::new(&self.table_name))
+            .and_where(Expr::col(Alias::new(&self.key_column)).eq(pk))
+            .limit(1)
+            .to_owned()
+    }
+
+    /// 读取值列，依次尝试按二进制和文本解码
+    fn read_value(&self, row: &QueryResult) -> Result<Option<Vec<u8>>> {
+        if let Ok(value) = row.try_get::<Option<Vec<u8>>>("", &self.value_column) {
+            return Ok(value);
+        }
+        row.try_get::<Option<String>>("", &self.value_column)
+            .map(|value| value.map(String::into_bytes))
+            .map_err(|e| {
+                CacheError::DatabaseError(format!(
+                    "Failed to read column {}.{}: {}",
+                    self.table_name, self.value_column, e
+                ))
+            })
+    }
+}
+
+#[async_trait]
+impl DbLoader for SeaOrmDbLoader {
+    #[instrument(skip(self), level = "debug")]
+    async fn load(&self, key: &str) -> Result<Option<Vec<u8>>> {
+        let Some(pk) = (self.key_to_pk)(key) else {
+            debug!("Key {} does not map to a primary key", key);
+            return Ok(None);
+        };
+
+        let statement = self.db.get_database_backend().build(&self.select_by_pk(pk));
+        let row = self
+            .db
+            .query_one(statement)
+            .await
+            .map_err(|e| CacheError::DatabaseError(format!("SQL query failed: {}", e)))?;
+
+        match row {
+            Some(row) => self.read_value(&row),
+            None => Ok(None),
+        }
+    }
+
+    /// 逐个键并发查询，主键类型由映射函数决定，无法从结果行反查缓存键
+    #[instrument(skip(self), level = "debug")]
+    async fn load_batch(&self, keys: Vec<String>) -> Result<Vec<(String, Vec<u8>)>> {
+        let values = futures::future::try_join_all(keys.iter().map(|key| self.load(key))).await?;
+        Ok(keys
+            .into_iter()
+            .zip(values)
+            .filter_map(|(key, value)| value.map(|value| (key, value)))
+            .collect())
+    }
+
+    /// 断线重连由连接池处理
+    fn is_healthy(&self) -> bool {
+        true
+    }
+}
+
 /// 数据库连接池trait
 #[async_trait]
 pub trait DbConnectionPool: Send + Sync + std::fmt::Debug {
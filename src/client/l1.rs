@@ -9,6 +9,7 @@ use crate::backend::l1::L1Backend;
 use crate::error::Result;
 use crate::metrics::GLOBAL_METRICS;
 use crate::serialization::SerializerEnum;
+use crate::utils::validate_raw_cache_key;
 use async_trait::async_trait;
 use std::sync::Arc;
 use tracing::instrument;
@@ -75,6 +76,28 @@ impl CacheOps for L1Client {
         Ok(())
     }
 
+    /// 以二进制原始键获取缓存值（字节）
+    #[instrument(skip(self), level = "debug", fields(service = %self.service_name))]
+    async fn get_bytes_by_raw(&self, key: &[u8]) -> Result<Option<Vec<u8>>> {
+        validate_raw_cache_key(key)?;
+        GLOBAL_METRICS.record_request(&self.service_name, "L1", "get", "attempt");
+        let result = self.l1.get_bytes_by_raw(key).await?;
+        let outcome = if result.is_some() { "hit" } else { "miss" };
+        GLOBAL_METRICS.record_request(&self.service_name, "L1", "get", outcome);
+        Ok(result)
+    }
+
+    /// 以二进制原始键设置缓存值（字节）
+    #[instrument(skip(self, value), level = "debug", fields(service = %self.service_name))]
+    async fn set_bytes_by_raw(&self, key: &[u8], value: Vec<u8>, ttl: Option<u64>) -> Result<()> {
+        validate_raw_cache_key(key)?;
+        let start = std::time::Instant::now();
+        self.l1.set_bytes_by_raw(key, value, ttl).await?;
+        let duration = start.elapsed().as_secs_f64();
+        GLOBAL_METRICS.record_duration(&self.service_name, "L1", "set", duration);
+        Ok(())
+    }
+
     /// 设置 L1 缓存值（字节）
     #[instrument(skip(self, value), level = "debug", fields(service = %self.service_name))]
     async fn set_l1_bytes(&self, key: &str, value: Vec<u8>, ttl: Option<u64>) -> Result<()> {
@@ -93,6 +116,12 @@ impl CacheOps for L1Client {
         Ok(None)
     }
 
+    /// 刷新缓存项的过期时间
+    #[instrument(skip(self), level = "debug", fields(service = %self.service_name))]
+    async fn touch(&self, key: &str, ttl: u64) -> Result<bool> {
+        self.l1.touch(key, ttl).await
+    }
+
     /// 删除缓存项
     #[instrument(skip(self), level = "debug", fields(service = %self.service_name))]
     async fn delete(&self, key: &str) -> Result<()> {
@@ -15,8 +15,14 @@ use crate::recovery::{
 };
 use crate::serialization::SerializerEnum;
 use crate::sync::invalidation::InvalidationPublisher;
+use crate::utils::task::spawn_named;
+use crate::utils::validate_raw_cache_key;
 use async_trait::async_trait;
+use futures::Stream;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::sync::RwLock;
 use tracing::{instrument, warn};
 
@@ -36,9 +42,44 @@ pub struct L2Client {
     wal: Arc<WalManager>,
     /// 失效发布器
     publisher: Option<Arc<InvalidationPublisher>>,
+    /// 是否记录逐操作指标
+    metrics_enabled: AtomicBool,
 }
 
 impl L2Client {
+    /// 获取L2缓存后端
+    pub(crate) fn backend(&self) -> &Arc<L2Backend> {
+        &self.l2
+    }
+
+    /// 替换失效发布器
+    ///
+    /// 嵌入双层缓存时与之共享按 `invalidation_channel` 配置的发布器，
+    /// 避免L2写入的失效通知发布到默认频道
+    pub(crate) fn with_publisher(mut self, publisher: Option<Arc<InvalidationPublisher>>) -> Self {
+        self.publisher = publisher;
+        self
+    }
+
+    /// 设置是否记录逐操作指标
+    pub(crate) fn set_metrics_enabled(&self, enabled: bool) {
+        self.metrics_enabled.store(enabled, Ordering::Relaxed);
+    }
+
+    /// 记录请求指标（逐操作指标关闭时为空操作）
+    fn record_request(&self, layer: &str, op: &str, result: &str) {
+        if self.metrics_enabled.load(Ordering::Relaxed) {
+            GLOBAL_METRICS.record_request(&self.service_name, layer, op, result);
+        }
+    }
+
+    /// 记录耗时指标（逐操作指标关闭时为空操作）
+    fn record_duration(&self, layer: &str, op: &str, duration_secs: f64) {
+        if self.metrics_enabled.load(Ordering::Relaxed) {
+            GLOBAL_METRICS.record_duration(&self.service_name, layer, op, duration_secs);
+        }
+    }
+
     /// 创建新的L2-only缓存客户端
     pub async fn new(
         service_name: String,
@@ -56,8 +97,11 @@ impl L2Client {
             wal.clone(),
             service_name.clone(),
             command_timeout_ms,
-        );
-        tokio::spawn(async move { checker.start().await });
+        )
+        .with_config(l2.health_config().clone());
+        spawn_named("health-checker", &service_name, async move {
+            checker.start().await
+        });
 
         // 默认使用 TwoLevelConfig 的默认值来解析频道名称，
         // 虽然这里只有 L2，但为了复用 resolve_channel_name 逻辑（如果需要的话）
@@ -69,10 +113,14 @@ impl L2Client {
         let config = TwoLevelConfig::default();
         let channel_name = Self::resolve_channel_name(&service_name, &config);
 
-        let publisher = Arc::new(InvalidationPublisher::new(
-            l2.get_raw_client()?.get_connection_manager().await?,
-            channel_name,
-        ));
+        let publisher = if l2.supports_pubsub() {
+            Some(Arc::new(InvalidationPublisher::new(
+                l2.get_raw_client()?.get_connection_manager().await?,
+                channel_name,
+            )))
+        } else {
+            None
+        };
 
         Ok(Self {
             service_name,
@@ -80,7 +128,8 @@ impl L2Client {
             serializer,
             health_state,
             wal,
-            publisher: Some(publisher),
+            publisher,
+            metrics_enabled: AtomicBool::new(true),
         })
     }
 
@@ -104,78 +153,26 @@ impl L2Client {
         }
     }
 
-    /// 处理L2故障
-    async fn handle_l2_failure(&self) {
-        tracing::warn!("L2 failure detected for service: {}", self.service_name);
-
-        // Update health state to degraded
-        let mut state = self.health_state.write().await;
-        *state = crate::recovery::health::HealthState::Degraded {
-            since: std::time::Instant::now(),
-            failure_count: 1,
-        };
-    }
-
-    /// Ping L2 backend to check connectivity
-    #[instrument(skip(self), level = "debug", fields(service = %self.service_name))]
-    pub async fn ping(&self) -> Result<()> {
-        self.l2.ping().await
-    }
-
-    /// 清空 L2 缓存
-    #[instrument(skip(self), level = "debug", fields(service = %self.service_name))]
-    pub async fn clear(&self) -> Result<()> {
-        self.l2.clear(&self.service_name).await
-    }
-}
-
-#[async_trait]
-impl CacheOps for L2Client {
-    /// 获取序列化器
-    fn serializer(&self) -> &SerializerEnum {
-        &self.serializer
-    }
-
-    /// 将 trait object 转换为 Any
-    fn as_any(&self) -> &dyn std::any::Any {
-        self
-    }
-
-    /// 将 `Arc<Trait>` 转换为 `Arc<dyn Any>`
-    fn into_any_arc(self: Arc<Self>) -> Arc<dyn std::any::Any + Send + Sync> {
-        self
-    }
-
-    /// 获取缓存值（字节）
-    #[instrument(skip(self), level = "debug", fields(service = %self.service_name))]
-    async fn get_bytes(&self, key: &str) -> Result<Option<Vec<u8>>> {
-        GLOBAL_METRICS.record_request(&self.service_name, "L2", "get", "attempt");
-        let start = std::time::Instant::now();
-        match self.l2.get_with_version(key).await {
-            Ok(Some((value, _))) => {
-                let duration = start.elapsed().as_secs_f64();
-                GLOBAL_METRICS.record_duration(&self.service_name, "L2", "get", duration);
-                GLOBAL_METRICS.record_request(&self.service_name, "L2", "get", "hit");
-                Ok(Some(value))
-            }
-            Ok(None) => {
-                let duration = start.elapsed().as_secs_f64();
-                GLOBAL_METRICS.record_duration(&self.service_name, "L2", "get", duration);
-                GLOBAL_METRICS.record_request(&self.service_name, "L2", "get", "miss");
-                Ok(None)
-            }
-            Err(e) => {
-                let duration = start.elapsed().as_secs_f64();
-                GLOBAL_METRICS.record_duration(&self.service_name, "L2", "get", duration);
-                self.handle_l2_failure().await;
-                Err(e)
-            }
-        }
-    }
-
-    /// 设置缓存值（字节）
+    /// 写入L2并返回是否已被L2确认
+    ///
+    /// L2不可用或写入失败时改为写入WAL，此时返回 `Ok(false)`
+    ///
+    /// # 参数
+    ///
+    /// * `key` - 缓存键
+    /// * `value` - 缓存值
+    /// * `ttl` - 过期时间（秒）
+    ///
+    /// # 返回值
+    ///
+    /// L2确认写入时返回true，写入WAL时返回false
     #[instrument(skip(self, value), level = "debug", fields(service = %self.service_name))]
-    async fn set_bytes(&self, key: &str, value: Vec<u8>, ttl: Option<u64>) -> Result<()> {
+    pub(crate) async fn write_bytes(
+        &self,
+        key: &str,
+        value: Vec<u8>,
+        ttl: Option<u64>,
+    ) -> Result<bool> {
         let state = self.health_state.read().await;
         tracing::info!("set_bytes: current health state = {:?}", *state);
         match *state {
@@ -194,20 +191,24 @@ impl CacheOps for L2Client {
                 match self.l2.set_with_version(key, value.clone(), ttl).await {
                     Ok(_) => {
                         let duration = start.elapsed().as_secs_f64();
-                        GLOBAL_METRICS.record_duration(&self.service_name, "L2", "set", duration);
+                        self.record_duration("L2", "set", duration);
                         // 只有在更新已存在的key时才发送失效通知
                         if key_exists {
                             if let Some(publisher) = &self.publisher {
                                 let _ = publisher.publish(key).await;
                             }
                         }
-                        Ok(())
+                        Ok(true)
                     }
                     Err(e) => {
                         let duration = start.elapsed().as_secs_f64();
-                        GLOBAL_METRICS.record_duration(&self.service_name, "L2", "set", duration);
+                        self.record_duration("L2", "set", duration);
+                        self.handle_l2_failure(&e).await;
+                        // 认证失败重放也无法成功，直接返回错误而不是写入WAL
+                        if matches!(e, crate::error::CacheError::AuthenticationFailed(_)) {
+                            return Err(e);
+                        }
                         tracing::warn!("L2 set failed during set_bytes, writing to WAL: {}", e);
-                        self.handle_l2_failure().await;
 
                         // Write to WAL on failure
                         self.wal
@@ -221,7 +222,7 @@ impl CacheOps for L2Client {
                             .await?;
 
                         // Return success since operation was written to WAL
-                        Ok(())
+                        Ok(false)
                     }
                 }
             }
@@ -239,7 +240,7 @@ impl CacheOps for L2Client {
                     .await?;
 
                 // Return success since operation was written to WAL
-                Ok(())
+                Ok(false)
             }
             HealthState::WalReplaying { .. } => {
                 tracing::info!(
@@ -257,8 +258,318 @@ impl CacheOps for L2Client {
                     .await?;
 
                 // Return success since operation was written to WAL
+                Ok(false)
+            }
+        }
+    }
+
+    /// 处理L2故障
+    ///
+    /// 认证失败属于配置问题而非瞬时故障，不会使服务进入降级状态
+    async fn handle_l2_failure(&self, error: &crate::error::CacheError) {
+        if matches!(error, crate::error::CacheError::AuthenticationFailed(_)) {
+            tracing::error!(
+                "L2 authentication failed for service {}: {}",
+                self.service_name,
+                error
+            );
+            return;
+        }
+        tracing::warn!("L2 failure detected for service: {}", self.service_name);
+
+        // Update health state to degraded
+        let mut state = self.health_state.write().await;
+        *state = crate::recovery::health::HealthState::Degraded {
+            since: std::time::Instant::now(),
+            failure_count: 1,
+        };
+    }
+
+    /// Ping L2 backend to check connectivity
+    #[instrument(skip(self), level = "debug", fields(service = %self.service_name))]
+    pub async fn ping(&self) -> Result<()> {
+        self.l2.ping().await
+    }
+
+    /// 清空 L2 缓存
+    #[instrument(skip(self), level = "debug", fields(service = %self.service_name))]
+    pub async fn clear(&self) -> Result<()> {
+        self.l2.clear(&self.service_name).await
+    }
+
+    /// 获取键的剩余生存时间（毫秒精度）
+    #[instrument(skip(self), level = "debug", fields(service = %self.service_name))]
+    pub async fn pttl(&self, key: &str) -> Result<Option<u64>> {
+        self.l2.pttl(key).await
+    }
+
+    /// 以毫秒精度的TTL写入L2
+    ///
+    /// WAL仅记录秒级TTL，因此L2不可用时不写入WAL，直接返回错误
+    #[instrument(skip(self, value), level = "debug", fields(service = %self.service_name))]
+    pub async fn set_bytes_ms(&self, key: &str, value: Vec<u8>, ttl: Duration) -> Result<()> {
+        let state = self.health_state.read().await;
+        match *state {
+            HealthState::Healthy | HealthState::Recovering { .. } => drop(state),
+            HealthState::Degraded { .. } | HealthState::WalReplaying { .. } => {
+                return Err(crate::error::CacheError::L2Error(
+                    "L2 is not available for millisecond TTL writes".to_string(),
+                ));
+            }
+        }
+
+        let start = std::time::Instant::now();
+        let result = self.l2.set_bytes_ms(key, value, ttl).await;
+        let duration = start.elapsed().as_secs_f64();
+        self.record_duration("L2", "set", duration);
+        match result {
+            Ok(()) => {
+                if let Some(publisher) = &self.publisher {
+                    let _ = publisher.publish(key).await;
+                }
                 Ok(())
             }
+            Err(e) => {
+                self.handle_l2_failure(&e).await;
+                Err(e)
+            }
+        }
+    }
+
+    /// 写入新值并返回旧值
+    ///
+    /// L2降级时无法原子地取得旧值，直接返回错误而不写入WAL
+    #[instrument(skip(self, value), level = "debug", fields(service = %self.service_name))]
+    pub async fn get_set_bytes(
+        &self,
+        key: &str,
+        value: Vec<u8>,
+        ttl: Option<u64>,
+    ) -> Result<Option<Vec<u8>>> {
+        let state = self.health_state.read().await;
+        match *state {
+            HealthState::Healthy | HealthState::Recovering { .. } => drop(state),
+            HealthState::Degraded { .. } | HealthState::WalReplaying { .. } => {
+                return Err(crate::error::CacheError::L2Error(
+                    "L2 is not available for get-set writes".to_string(),
+                ));
+            }
+        }
+
+        let start = std::time::Instant::now();
+        let result = self.l2.get_set(key, value, ttl).await;
+        let duration = start.elapsed().as_secs_f64();
+        self.record_duration("L2", "set", duration);
+        match result {
+            Ok(previous) => {
+                if let Some(publisher) = &self.publisher {
+                    let _ = publisher.publish(key).await;
+                }
+                Ok(previous)
+            }
+            Err(e) => {
+                self.handle_l2_failure(&e).await;
+                Err(e)
+            }
+        }
+    }
+
+    /// 带上限检查的原子自增，见 [`L2Backend::incr_by_checked`]
+    ///
+    /// L2降级时无法原子地自增，直接返回错误而不写入WAL；越过上限不视为L2故障
+    #[instrument(skip(self), level = "debug", fields(service = %self.service_name))]
+    pub async fn incr_by_checked(&self, key: &str, delta: i64, max: Option<i64>) -> Result<i64> {
+        let state = self.health_state.read().await;
+        match *state {
+            HealthState::Healthy | HealthState::Recovering { .. } => drop(state),
+            HealthState::Degraded { .. } | HealthState::WalReplaying { .. } => {
+                return Err(crate::error::CacheError::L2Error(
+                    "L2 is not available for counter updates".to_string(),
+                ));
+            }
+        }
+
+        let start = std::time::Instant::now();
+        let result = self.l2.incr_by_checked(key, delta, max).await;
+        let duration = start.elapsed().as_secs_f64();
+        self.record_duration("L2", "set", duration);
+        match result {
+            Ok(value) => {
+                if let Some(publisher) = &self.publisher {
+                    let _ = publisher.publish(key).await;
+                }
+                Ok(value)
+            }
+            Err(e @ crate::error::CacheError::LimitExceeded(_)) => Err(e),
+            Err(e) => {
+                self.handle_l2_failure(&e).await;
+                Err(e)
+            }
+        }
+    }
+
+    /// 原子地读取并删除缓存项，见 [`L2Backend::take`]
+    ///
+    /// L2降级时无法保证只被取走一次，直接返回错误而不写入WAL
+    #[instrument(skip(self), level = "debug", fields(service = %self.service_name))]
+    pub async fn take_bytes(&self, key: &str) -> Result<Option<Vec<u8>>> {
+        let state = self.health_state.read().await;
+        match *state {
+            HealthState::Healthy | HealthState::Recovering { .. } => drop(state),
+            HealthState::Degraded { .. } | HealthState::WalReplaying { .. } => {
+                return Err(crate::error::CacheError::L2Error(
+                    "L2 is not available for take".to_string(),
+                ));
+            }
+        }
+
+        let start = std::time::Instant::now();
+        let result = self.l2.take(key).await;
+        let duration = start.elapsed().as_secs_f64();
+        self.record_duration("L2", "delete", duration);
+        match result {
+            Ok(value) => {
+                if let Some(publisher) = &self.publisher {
+                    let _ = publisher.publish(key).await;
+                }
+                Ok(value)
+            }
+            Err(e) => {
+                self.handle_l2_failure(&e).await;
+                Err(e)
+            }
+        }
+    }
+
+    /// 扫描匹配模式的键（只读，不删除数据）
+    #[instrument(skip(self), level = "debug", fields(service = %self.service_name))]
+    pub async fn scan_keys(&self, pattern: &str) -> Result<Vec<String>> {
+        self.l2.scan_keys(pattern).await
+    }
+
+    /// 按页流式扫描匹配模式的键（只读，不删除数据），见 [`L2Backend::scan_pages`]
+    pub fn scan_pages<'a>(
+        &'a self,
+        pattern: &'a str,
+        page_size: usize,
+    ) -> impl Stream<Item = Result<Vec<String>>> + Send + 'a {
+        self.l2.scan_pages(pattern, page_size)
+    }
+
+    /// 获取Redis数据库中的键数量（整个数据库，非服务范围）
+    #[instrument(skip(self), level = "debug", fields(service = %self.service_name))]
+    pub async fn dbsize(&self) -> Result<u64> {
+        self.l2.dbsize().await
+    }
+
+    /// 获取Redis服务器信息（集群模式下键以节点地址为前缀）
+    #[instrument(skip(self), level = "debug", fields(service = %self.service_name))]
+    pub async fn info(&self, section: Option<&str>) -> Result<HashMap<String, String>> {
+        self.l2.info(section).await
+    }
+
+    /// 获取键在Redis中占用的内存字节数
+    #[instrument(skip(self), level = "debug", fields(service = %self.service_name))]
+    pub async fn memory_usage(&self, key: &str) -> Result<u64> {
+        self.l2.memory_usage(key).await
+    }
+
+    /// 获取带版本号的缓存值（字节）
+    ///
+    /// 与 `get_bytes` 记录相同的指标并处理L2故障，额外返回L2中的版本号
+    /// （未启用版本键时恒为0），供推广到L1时保留真实版本
+    ///
+    /// # 参数
+    ///
+    /// * `key` - 缓存键
+    ///
+    /// # 返回值
+    ///
+    /// 返回缓存值和版本号的元组，键不存在时返回None
+    #[instrument(skip(self), level = "debug", fields(service = %self.service_name))]
+    pub async fn get_with_version(&self, key: &str) -> Result<Option<(Vec<u8>, u64)>> {
+        self.record_request("L2", "get", "attempt");
+        let start = std::time::Instant::now();
+        let result = self.l2.get_with_version(key).await;
+        let duration = start.elapsed().as_secs_f64();
+        self.record_duration("L2", "get", duration);
+        match &result {
+            Ok(Some(_)) => self.record_request("L2", "get", "hit"),
+            Ok(None) => self.record_request("L2", "get", "miss"),
+            Err(e) => self.handle_l2_failure(e).await,
+        }
+        result
+    }
+}
+
+#[async_trait]
+impl CacheOps for L2Client {
+    /// 获取序列化器
+    fn serializer(&self) -> &SerializerEnum {
+        &self.serializer
+    }
+
+    /// 将 trait object 转换为 Any
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    /// 将 `Arc<Trait>` 转换为 `Arc<dyn Any>`
+    fn into_any_arc(self: Arc<Self>) -> Arc<dyn std::any::Any + Send + Sync> {
+        self
+    }
+
+    /// 获取缓存值（字节）
+    #[instrument(skip(self), level = "debug", fields(service = %self.service_name))]
+    async fn get_bytes(&self, key: &str) -> Result<Option<Vec<u8>>> {
+        Ok(self.get_with_version(key).await?.map(|(value, _)| value))
+    }
+
+    /// 设置缓存值（字节）
+    #[instrument(skip(self, value), level = "debug", fields(service = %self.service_name))]
+    async fn set_bytes(&self, key: &str, value: Vec<u8>, ttl: Option<u64>) -> Result<()> {
+        self.write_bytes(key, value, ttl).await.map(|_| ())
+    }
+
+    /// 以二进制原始键获取缓存值（字节）
+    #[instrument(skip(self), level = "debug", fields(service = %self.service_name))]
+    async fn get_bytes_by_raw(&self, key: &[u8]) -> Result<Option<Vec<u8>>> {
+        validate_raw_cache_key(key)?;
+        self.record_request("L2", "get", "attempt");
+        let start = std::time::Instant::now();
+        let result = self.l2.get_bytes_by_raw(key).await;
+        let duration = start.elapsed().as_secs_f64();
+        self.record_duration("L2", "get", duration);
+        match &result {
+            Ok(Some(_)) => self.record_request("L2", "get", "hit"),
+            Ok(None) => self.record_request("L2", "get", "miss"),
+            Err(e) => self.handle_l2_failure(e).await,
+        }
+        result
+    }
+
+    /// 以二进制原始键设置缓存值（字节）
+    ///
+    /// 失效通知和WAL只能携带字符串键，因此原始键的写入不发布失效消息；
+    /// L2不可用时直接返回错误而不写入WAL
+    #[instrument(skip(self, value), level = "debug", fields(service = %self.service_name))]
+    async fn set_bytes_by_raw(&self, key: &[u8], value: Vec<u8>, ttl: Option<u64>) -> Result<()> {
+        validate_raw_cache_key(key)?;
+        let state = *self.health_state.read().await;
+        match state {
+            HealthState::Healthy | HealthState::Recovering { .. } => {
+                let start = std::time::Instant::now();
+                let result = self.l2.set_bytes_by_raw(key, value, ttl).await;
+                let duration = start.elapsed().as_secs_f64();
+                self.record_duration("L2", "set", duration);
+                if let Err(e) = &result {
+                    self.handle_l2_failure(e).await;
+                }
+                result
+            }
+            HealthState::Degraded { .. } | HealthState::WalReplaying { .. } => Err(
+                crate::error::CacheError::L2Error("L2 is unavailable".to_string()),
+            ),
         }
     }
 
@@ -280,6 +591,28 @@ impl CacheOps for L2Client {
         self.get_bytes(key).await
     }
 
+    /// 刷新缓存项的过期时间
+    ///
+    /// 值未改变，因此不发布失效消息；L2不可用时直接返回错误而不写入WAL
+    #[instrument(skip(self), level = "debug", fields(service = %self.service_name))]
+    async fn touch(&self, key: &str, ttl: u64) -> Result<bool> {
+        let state = *self.health_state.read().await;
+        match state {
+            HealthState::Healthy | HealthState::Recovering { .. } => {
+                match self.l2.touch(key, ttl).await {
+                    Ok(touched) => Ok(touched),
+                    Err(e) => {
+                        self.handle_l2_failure(&e).await;
+                        Err(e)
+                    }
+                }
+            }
+            HealthState::Degraded { .. } | HealthState::WalReplaying { .. } => Err(
+                crate::error::CacheError::L2Error("L2 is unavailable".to_string()),
+            ),
+        }
+    }
+
     /// 删除缓存项
     #[instrument(skip(self), level = "debug", fields(service = %self.service_name))]
     async fn delete(&self, key: &str) -> Result<()> {
@@ -295,7 +628,7 @@ impl CacheOps for L2Client {
                         Ok(())
                     }
                     Err(e) => {
-                        self.handle_l2_failure().await;
+                        self.handle_l2_failure(&e).await;
                         Err(e)
                     }
                 }
@@ -337,14 +670,14 @@ impl CacheOps for L2Client {
                 match self.l2.lock(key, value, ttl).await {
                     Ok(result) => {
                         if result {
-                            GLOBAL_METRICS.record_request(&self.service_name, "L2", "lock", "hit");
+                            self.record_request("L2", "lock", "hit");
                         } else {
-                            GLOBAL_METRICS.record_request(&self.service_name, "L2", "lock", "miss");
+                            self.record_request("L2", "lock", "miss");
                         }
                         Ok(result)
                     }
                     Err(e) => {
-                        self.handle_l2_failure().await;
+                        self.handle_l2_failure(&e).await;
                         Err(e)
                     }
                 }
@@ -378,24 +711,14 @@ impl CacheOps for L2Client {
                 match self.l2.unlock(key, value).await {
                     Ok(result) => {
                         if result {
-                            GLOBAL_METRICS.record_request(
-                                &self.service_name,
-                                "L2",
-                                "unlock",
-                                "hit",
-                            );
+                            self.record_request("L2", "unlock", "hit");
                         } else {
-                            GLOBAL_METRICS.record_request(
-                                &self.service_name,
-                                "L2",
-                                "unlock",
-                                "miss",
-                            );
+                            self.record_request("L2", "unlock", "miss");
                         }
                         Ok(result)
                     }
                     Err(e) => {
-                        self.handle_l2_failure().await;
+                        self.handle_l2_failure(&e).await;
                         Err(e)
                     }
                 }
@@ -419,11 +742,54 @@ impl CacheOps for L2Client {
         }
     }
 
+    /// 获取哈希表字段值（字节）
+    #[instrument(skip(self), level = "debug", fields(service = %self.service_name))]
+    async fn hget_bytes(&self, key: &str, field: &str) -> Result<Option<Vec<u8>>> {
+        let result = self.l2.hget(key, field).await;
+        if let Err(e) = &result {
+            self.handle_l2_failure(e).await;
+        }
+        result
+    }
+
+    /// 设置哈希表字段值（字节）
+    ///
+    /// WAL无法表示哈希表操作，L2不可用时直接返回错误
+    #[instrument(skip(self, value), level = "debug", fields(service = %self.service_name))]
+    async fn hset_bytes(
+        &self,
+        key: &str,
+        field: &str,
+        value: Vec<u8>,
+        ttl: Option<u64>,
+    ) -> Result<()> {
+        let result = self.l2.hset(key, field, value, ttl).await;
+        if let Err(e) = &result {
+            self.handle_l2_failure(e).await;
+        }
+        result
+    }
+
+    /// 获取哈希表的所有字段（字节）
+    #[instrument(skip(self), level = "debug", fields(service = %self.service_name))]
+    async fn hgetall_bytes(&self, key: &str) -> Result<HashMap<String, Vec<u8>>> {
+        let result = self.l2.hgetall(key).await;
+        if let Err(e) = &result {
+            self.handle_l2_failure(e).await;
+        }
+        result
+    }
+
     /// 清空 L2 缓存
     #[instrument(skip(self), level = "debug", fields(service = %self.service_name))]
     async fn clear_l2(&self) -> Result<()> {
         self.l2.clear(&self.service_name).await?;
-        GLOBAL_METRICS.record_request(&self.service_name, "L2", "clear", "success");
+        self.record_request("L2", "clear", "success");
         Ok(())
     }
+
+    /// 获取当前健康状态
+    async fn health_state(&self) -> HealthState {
+        *self.health_state.read().await
+    }
 }
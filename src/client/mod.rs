@@ -7,11 +7,14 @@
 pub mod db_loader;
 pub mod l1;
 pub mod l2;
+pub mod null;
 pub mod two_level;
 
 use crate::error::Result;
 use async_trait::async_trait;
 use std::any::Any;
+use std::collections::HashMap;
+use std::future::Future;
 
 use crate::serialization::Serializer;
 use serde::{de::DeserializeOwned, Serialize};
@@ -47,6 +50,17 @@ pub trait CacheExt: CacheOps {
         self.set_bytes(key, bytes, ttl).await
     }
 
+    /// 持久化设置缓存值（永不过期）
+    ///
+    /// 等价于以 [`PERSISTENT_TTL`](crate::backend::PERSISTENT_TTL) 调用 [`set`](Self::set)，
+    /// 与传入 `None`（使用默认TTL）不同，写入的值不会因TTL到期而失效。
+    #[instrument(skip(self, value), level = "debug")]
+    async fn set_persistent<T: Serialize + Send + Sync>(&self, key: &str, value: &T) -> Result<()> {
+        let bytes = self.serializer().serialize(value)?;
+        self.set_bytes(key, bytes, Some(crate::backend::PERSISTENT_TTL))
+            .await
+    }
+
     /// 仅设置 L1 缓存（如果支持）
     /// 注意：此实现默认行为是 set_bytes，因为 CacheOps 没有区分 L1/L2。
     /// 如果需要真正的 L1-only，需要底层支持或使用 L1OnlyClient。
@@ -87,6 +101,138 @@ pub trait CacheExt: CacheOps {
         let bytes = self.serializer().serialize(value)?;
         self.set_l2_bytes(key, bytes, ttl).await
     }
+
+    /// 获取哈希表字段值（反序列化）
+    ///
+    /// # 参数
+    ///
+    /// * `key` - 哈希表的缓存键
+    /// * `field` - 字段名
+    ///
+    /// # 返回值
+    ///
+    /// 返回字段值，如果不存在则返回None
+    #[instrument(skip(self), level = "debug")]
+    async fn hget_as<T: DeserializeOwned + Send>(
+        &self,
+        key: &str,
+        field: &str,
+    ) -> Result<Option<T>> {
+        match self.hget_bytes(key, field).await? {
+            Some(data) => Ok(Some(self.serializer().deserialize(&data)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// 设置哈希表字段值（序列化）
+    ///
+    /// 字段值使用客户端配置的序列化器编码。哈希表的所有字段共享同一个键的TTL，
+    /// 每次写入都会刷新整个哈希表的过期时间，无法为单个字段单独设置TTL。
+    ///
+    /// # 参数
+    ///
+    /// * `key` - 哈希表的缓存键
+    /// * `field` - 字段名
+    /// * `value` - 字段值
+    /// * `ttl` - 整个哈希表的过期时间（秒），None表示使用默认值
+    ///
+    /// # 返回值
+    ///
+    /// 返回操作结果
+    #[instrument(skip(self, value), level = "debug")]
+    async fn hset_value<T: Serialize + Send + Sync>(
+        &self,
+        key: &str,
+        field: &str,
+        value: &T,
+        ttl: Option<u64>,
+    ) -> Result<()> {
+        let bytes = self.serializer().serialize(value)?;
+        self.hset_bytes(key, field, bytes, ttl).await
+    }
+
+    /// 获取哈希表的所有字段（反序列化）
+    ///
+    /// # 参数
+    ///
+    /// * `key` - 哈希表的缓存键
+    ///
+    /// # 返回值
+    ///
+    /// 返回字段名到字段值的映射，任一字段反序列化失败时返回错误
+    #[instrument(skip(self), level = "debug")]
+    async fn hgetall_as<T: DeserializeOwned + Send>(
+        &self,
+        key: &str,
+    ) -> Result<HashMap<String, T>> {
+        let fields = self.hgetall_bytes(key).await?;
+        let mut values = HashMap::with_capacity(fields.len());
+        for (field, data) in fields {
+            values.insert(field, self.serializer().deserialize(&data)?);
+        }
+        Ok(values)
+    }
+
+    /// 旁路缓存读取
+    ///
+    /// 先读取缓存，未命中时调用加载函数并将结果写回缓存。
+    /// 不使用分布式锁，适用于无需防止缓存击穿的场景。
+    ///
+    /// # 参数
+    ///
+    /// * `key` - 缓存键
+    /// * `ttl` - 写回缓存时使用的过期时间（秒）
+    /// * `loader` - 缓存未命中时调用的加载函数
+    ///
+    /// # 返回值
+    ///
+    /// 返回缓存值或加载函数的结果
+    #[instrument(skip(self, loader), level = "debug")]
+    async fn cache_aside<T, F, Fut>(&self, key: &str, ttl: Option<u64>, loader: F) -> Result<T>
+    where
+        T: Serialize + DeserializeOwned + Send + Sync,
+        F: FnOnce() -> Fut + Send,
+        Fut: Future<Output = Result<T>> + Send,
+    {
+        if let Some(data) = self.get_bytes(key).await? {
+            return self.serializer().deserialize(&data);
+        }
+
+        let value = loader().await?;
+        let bytes = self.serializer().serialize(&value)?;
+        self.set_bytes(key, bytes, ttl).await?;
+        Ok(value)
+    }
+
+    /// 旁路缓存读取，由加载结果决定过期时间
+    ///
+    /// 与 [`cache_aside`](CacheExt::cache_aside) 相同，但加载函数同时返回写回缓存时使用的TTL，
+    /// 适用于过期时间取决于数据本身的场景（如HTTP响应的 `Cache-Control: max-age`）。
+    ///
+    /// # 参数
+    ///
+    /// * `key` - 缓存键
+    /// * `loader` - 缓存未命中时调用的加载函数，返回值与过期时间（秒，None表示使用默认值）
+    ///
+    /// # 返回值
+    ///
+    /// 返回缓存值或加载函数的结果
+    #[instrument(skip(self, loader), level = "debug")]
+    async fn get_or_set_with<T, F, Fut>(&self, key: &str, loader: F) -> Result<T>
+    where
+        T: Serialize + DeserializeOwned + Send + Sync,
+        F: FnOnce() -> Fut + Send,
+        Fut: Future<Output = Result<(T, Option<u64>)>> + Send,
+    {
+        if let Some(data) = self.get_bytes(key).await? {
+            return self.serializer().deserialize(&data);
+        }
+
+        let (value, ttl) = loader().await?;
+        let bytes = self.serializer().serialize(&value)?;
+        self.set_bytes(key, bytes, ttl).await?;
+        Ok(value)
+    }
 }
 
 impl<T: CacheOps + ?Sized> CacheExt for T {}
@@ -148,6 +294,105 @@ pub trait CacheOps: Send + Sync + Any {
         ))
     }
 
+    /// 以二进制原始键获取缓存值（字节）
+    ///
+    /// 原始键（如哈希摘要）不要求是合法的UTF-8，也不受键字符集限制，只校验长度；
+    /// 与相同字节的字符串键指向同一个缓存项
+    ///
+    /// # 参数
+    ///
+    /// * `key` - 原始键
+    ///
+    /// # 返回值
+    ///
+    /// 返回缓存值，如果不存在则返回None
+    async fn get_bytes_by_raw(&self, _key: &[u8]) -> Result<Option<Vec<u8>>> {
+        Err(crate::error::CacheError::NotSupported(
+            "get_bytes_by_raw".to_string(),
+        ))
+    }
+
+    /// 以二进制原始键设置缓存值（字节）
+    ///
+    /// # 参数
+    ///
+    /// * `key` - 原始键，只校验长度
+    /// * `value` - 缓存值
+    /// * `ttl` - 过期时间（秒），None表示使用默认值
+    ///
+    /// # 返回值
+    ///
+    /// 返回操作结果
+    async fn set_bytes_by_raw(
+        &self,
+        _key: &[u8],
+        _value: Vec<u8>,
+        _ttl: Option<u64>,
+    ) -> Result<()> {
+        Err(crate::error::CacheError::NotSupported(
+            "set_bytes_by_raw".to_string(),
+        ))
+    }
+
+    /// 获取哈希表字段值（字节）
+    ///
+    /// 哈希表仅存储在L2中，不经过L1缓存
+    ///
+    /// # 参数
+    ///
+    /// * `key` - 哈希表的缓存键
+    /// * `field` - 字段名
+    ///
+    /// # 返回值
+    ///
+    /// 返回字段值，如果不存在则返回None
+    async fn hget_bytes(&self, _key: &str, _field: &str) -> Result<Option<Vec<u8>>> {
+        Err(crate::error::CacheError::NotSupported(
+            "hget_bytes".to_string(),
+        ))
+    }
+
+    /// 设置哈希表字段值（字节）
+    ///
+    /// 哈希表的所有字段共享同一个键的TTL，每次写入都会刷新整个哈希表的过期时间
+    ///
+    /// # 参数
+    ///
+    /// * `key` - 哈希表的缓存键
+    /// * `field` - 字段名
+    /// * `value` - 字段值
+    /// * `ttl` - 整个哈希表的过期时间（秒），None表示使用默认值
+    ///
+    /// # 返回值
+    ///
+    /// 返回操作结果
+    async fn hset_bytes(
+        &self,
+        _key: &str,
+        _field: &str,
+        _value: Vec<u8>,
+        _ttl: Option<u64>,
+    ) -> Result<()> {
+        Err(crate::error::CacheError::NotSupported(
+            "hset_bytes".to_string(),
+        ))
+    }
+
+    /// 获取哈希表的所有字段（字节）
+    ///
+    /// # 参数
+    ///
+    /// * `key` - 哈希表的缓存键
+    ///
+    /// # 返回值
+    ///
+    /// 返回字段名到字段值的映射
+    async fn hgetall_bytes(&self, _key: &str) -> Result<HashMap<String, Vec<u8>>> {
+        Err(crate::error::CacheError::NotSupported(
+            "hgetall_bytes".to_string(),
+        ))
+    }
+
     /// 尝试获取分布式锁
     ///
     /// # 参数
@@ -188,6 +433,59 @@ pub trait CacheOps: Send + Sync + Any {
     /// 返回操作结果
     async fn delete(&self, key: &str) -> Result<()>;
 
+    /// 刷新缓存项的过期时间
+    ///
+    /// 只延长（或缩短）过期时间，不读取也不重新写入值，适用于滑动过期的会话等场景
+    ///
+    /// # 参数
+    ///
+    /// * `key` - 缓存键
+    /// * `ttl` - 新的过期时间（秒），[`PERSISTENT_TTL`](crate::backend::PERSISTENT_TTL) 表示永不过期
+    ///
+    /// # 返回值
+    ///
+    /// 键存在并已刷新时返回true，键不存在时返回false
+    async fn touch(&self, _key: &str, _ttl: u64) -> Result<bool> {
+        Err(crate::error::CacheError::NotSupported("touch".to_string()))
+    }
+
+    /// 批量删除缓存项
+    ///
+    /// 默认实现逐个调用 [`delete`](Self::delete)，遇到第一个错误即返回
+    ///
+    /// # 参数
+    ///
+    /// * `keys` - 要删除的缓存键
+    ///
+    /// # 返回值
+    ///
+    /// 返回操作结果
+    async fn delete_many(&self, keys: &[&str]) -> Result<()> {
+        for key in keys {
+            self.delete(key).await?;
+        }
+        Ok(())
+    }
+
+    /// 批量检查缓存项是否存在
+    ///
+    /// 默认实现逐个调用 [`get_bytes`](Self::get_bytes)，遇到第一个错误即返回
+    ///
+    /// # 参数
+    ///
+    /// * `keys` - 要检查的缓存键
+    ///
+    /// # 返回值
+    ///
+    /// 按输入顺序返回各键是否存在
+    async fn exists_many(&self, keys: &[&str]) -> Result<Vec<bool>> {
+        let mut exists = Vec::with_capacity(keys.len());
+        for key in keys {
+            exists.push(self.get_bytes(key).await?.is_some());
+        }
+        Ok(exists)
+    }
+
     /// 获取序列化器
     ///
     /// 返回当前客户端使用的序列化器
@@ -232,6 +530,17 @@ pub trait CacheOps: Send + Sync + Any {
         ))
     }
 
+    /// 获取当前健康状态
+    ///
+    /// 不依赖L2的客户端始终视为健康
+    ///
+    /// # 返回值
+    ///
+    /// 返回客户端当前的健康状态
+    async fn health_state(&self) -> crate::recovery::health::HealthState {
+        crate::recovery::health::HealthState::Healthy
+    }
+
     /// 优雅关闭客户端
     ///
     /// 关闭所有后台任务，释放资源
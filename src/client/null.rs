@@ -0,0 +1,118 @@
+//! Copyright (c) 2025-2026, Kirky.X
+//!
+//! MIT License
+//!
+//! 该模块定义了禁用缓存时使用的空客户端实现。
+
+use super::CacheOps;
+use crate::error::Result;
+use crate::serialization::{JsonSerializer, SerializerEnum};
+use async_trait::async_trait;
+use std::sync::Arc;
+use tracing::instrument;
+
+/// 空缓存客户端实现
+///
+/// 用于禁用缓存的服务：读取总是未命中，写入和删除均为空操作，
+/// 调用方无需额外判断即可绕过缓存
+pub struct NullClient {
+    /// 服务名称
+    service_name: String,
+    /// 序列化器
+    serializer: SerializerEnum,
+}
+
+impl NullClient {
+    /// 创建新的空缓存客户端
+    pub fn new(service_name: String) -> Self {
+        Self {
+            service_name,
+            serializer: SerializerEnum::Json(JsonSerializer::new()),
+        }
+    }
+}
+
+#[async_trait]
+impl CacheOps for NullClient {
+    /// 获取序列化器
+    fn serializer(&self) -> &SerializerEnum {
+        &self.serializer
+    }
+
+    /// 将 trait object 转换为 Any
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    /// 将 `Arc<Trait>` 转换为 `Arc<dyn Any>`
+    fn into_any_arc(self: Arc<Self>) -> Arc<dyn std::any::Any + Send + Sync> {
+        self
+    }
+
+    /// 获取缓存值（总是返回None）
+    #[instrument(skip(self), level = "trace", fields(service = %self.service_name))]
+    async fn get_bytes(&self, _key: &str) -> Result<Option<Vec<u8>>> {
+        Ok(None)
+    }
+
+    /// 获取 L1 缓存值（总是返回None）
+    async fn get_l1_bytes(&self, _key: &str) -> Result<Option<Vec<u8>>> {
+        Ok(None)
+    }
+
+    /// 获取 L2 缓存值（总是返回None）
+    async fn get_l2_bytes(&self, _key: &str) -> Result<Option<Vec<u8>>> {
+        Ok(None)
+    }
+
+    /// 以二进制原始键获取缓存值（总是返回None）
+    async fn get_bytes_by_raw(&self, _key: &[u8]) -> Result<Option<Vec<u8>>> {
+        Ok(None)
+    }
+
+    /// 以二进制原始键设置缓存值（空操作）
+    async fn set_bytes_by_raw(
+        &self,
+        _key: &[u8],
+        _value: Vec<u8>,
+        _ttl: Option<u64>,
+    ) -> Result<()> {
+        Ok(())
+    }
+
+    /// 设置缓存值（空操作）
+    #[instrument(skip(self, _value), level = "trace", fields(service = %self.service_name))]
+    async fn set_bytes(&self, _key: &str, _value: Vec<u8>, _ttl: Option<u64>) -> Result<()> {
+        Ok(())
+    }
+
+    /// 设置 L1 缓存值（空操作）
+    async fn set_l1_bytes(&self, _key: &str, _value: Vec<u8>, _ttl: Option<u64>) -> Result<()> {
+        Ok(())
+    }
+
+    /// 设置 L2 缓存值（空操作）
+    async fn set_l2_bytes(&self, _key: &str, _value: Vec<u8>, _ttl: Option<u64>) -> Result<()> {
+        Ok(())
+    }
+
+    /// 刷新过期时间（键总是不存在）
+    async fn touch(&self, _key: &str, _ttl: u64) -> Result<bool> {
+        Ok(false)
+    }
+
+    /// 删除缓存项（空操作）
+    async fn delete(&self, _key: &str) -> Result<()> {
+        Ok(())
+    }
+
+    /// 清空 L1 缓存（空操作）
+    async fn clear_l1(&self) -> Result<()> {
+        Ok(())
+    }
+
+    /// 清空 L2 缓存（空操作）
+    async fn clear_l2(&self) -> Result<()> {
+        Ok(())
+    }
+}
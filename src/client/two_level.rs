@@ -6,30 +6,140 @@
 
 use super::{db_loader::DbFallbackManager, l2::L2Client, CacheOps};
 use crate::backend::l1::L1Backend;
-use crate::bloom_filter::{BloomFilterManager, BloomFilterOptions, BloomFilterShared};
-use crate::config::TwoLevelConfig;
+use crate::backend::l2::DEFAULT_L2_TTL_SECS;
+use crate::backend::PERSISTENT_TTL;
+use crate::bloom_filter::{
+    BloomFilterManager, BloomFilterOptions, CacheBloomFilter, RedisBloomFilter,
+};
+use crate::config::{BloomFilterBackend, KeyMode, TwoLevelConfig, WriteOrder};
 use crate::error::Result;
-use crate::metrics::GLOBAL_METRICS;
+use crate::metrics::{KeyGrouper, GLOBAL_METRICS};
 use crate::recovery::{
     health::{HealthChecker, HealthState},
-    wal::{Operation, WalEntry, WalManager},
+    wal::{Operation, WalEntry, WalManager, WalReplayReport},
 };
-use crate::serialization::{Serializer, SerializerEnum};
+use crate::serialization::{soft_ttl, Serializer, SerializerEnum};
 use crate::sync::{
     common::{BatchOperation, BatchWriterConfig},
     invalidation::{InvalidationPublisher, InvalidationSubscriber},
     optimized_batch_writer::OptimizedBatchWriter,
-    promotion::PromotionManager,
-    warmup::WarmupManager,
+    promotion::{PromotionManager, PromotionStats},
+    warmup::{WarmupBatch, WarmupManager, WarmupResult},
 };
-use crate::utils::{validate_cache_key, validate_key_length, validate_value_size};
+use crate::utils::clock::{Clock, SystemClock};
+use crate::utils::task::spawn_named;
+use crate::utils::{sanitize_cache_key, validate_key_length, validate_value_size};
 use async_trait::async_trait;
-use std::collections::HashMap;
+use futures::{Stream, StreamExt};
+use moka::future::Cache;
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::Arc;
-use tokio::sync::RwLock;
+use std::time::Duration;
+use tokio::sync::{broadcast, RwLock, Semaphore};
 use tokio::task::JoinHandle;
 use tracing::{debug, info, instrument, warn};
 
+/// 关闭时等待批处理写入器刷新完成的最长时间
+const SHUTDOWN_FLUSH_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(5);
+
+/// L1条目数指标的采集间隔
+const L1_METRICS_INTERVAL: std::time::Duration = std::time::Duration::from_secs(15);
+
+/// 失效通知广播的缓冲容量
+const INVALIDATION_WATCH_CAPACITY: usize = 1024;
+
+/// 缓存纪元在L2中的持久化键前缀，完整的键为 `{前缀}:{服务名称}`
+const EPOCH_KEY_PREFIX: &str = "oxcache:epoch";
+
+/// 服务的缓存纪元在L2中的持久化键
+fn epoch_key(service_name: &str) -> String {
+    format!("{}:{}", EPOCH_KEY_PREFIX, service_name)
+}
+
+/// 当前Unix时间戳（毫秒），软TTL的过期时间以此表示，以便多个实例共享
+fn unix_millis() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_millis() as u64)
+}
+
+/// [`TwoLevelClient::prime`] 写入的缓存层
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum PrimeLayer {
+    /// 只写入L1，用于推测性预热，不影响L2和其他实例
+    L1,
+    /// 同时写入L1和L2，与 `set` 的写入路径相同
+    #[default]
+    Both,
+}
+
+/// 单个键在L1与L2之间的一致性状态
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LayerStatus {
+    /// 两层的值相同（已知版本号时版本号也相同）
+    Consistent,
+    /// 仅存在于L1
+    L1Only,
+    /// 仅存在于L2
+    L2Only,
+    /// 两层均不存在
+    Missing,
+    /// 值相同但版本号不同
+    VersionMismatch {
+        /// L1中的版本号
+        l1_version: u64,
+        /// L2中的版本号
+        l2_version: u64,
+    },
+    /// 两层的值不同
+    ValueMismatch,
+}
+
+/// [`TwoLevelClient::diff_layers`] 对单个键的检查结果
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LayerDiff {
+    /// 缓存键
+    pub key: String,
+    /// 一致性状态
+    pub status: LayerStatus,
+}
+
+/// 写入各层时使用的过期时间
+#[derive(Clone, Copy, Debug)]
+enum LayerTtl {
+    /// 秒级过期时间，None表示使用各层的默认值
+    Secs(Option<u64>),
+    /// 毫秒级过期时间，至少为1毫秒
+    Millis(Duration),
+}
+
+impl LayerTtl {
+    /// 换算为秒级过期时间，毫秒级过期时间向上取整
+    ///
+    /// WAL只保存秒级TTL
+    fn as_secs(self) -> Option<u64> {
+        match self {
+            LayerTtl::Secs(ttl) => ttl,
+            LayerTtl::Millis(ttl) => Some(ttl.as_millis().div_ceil(1000) as u64),
+        }
+    }
+
+    /// 按系数放大过期时间
+    ///
+    /// 未指定TTL时以 `default_secs` 为基准，持久化写入保持不变
+    fn scaled(self, factor: f64, default_secs: u64) -> Self {
+        match self {
+            LayerTtl::Secs(ttl) => match ttl.unwrap_or(default_secs) {
+                PERSISTENT_TTL => self,
+                base => LayerTtl::Secs(Some((base as f64 * factor).round() as u64)),
+            },
+            LayerTtl::Millis(ttl) => LayerTtl::Millis(ttl.mul_f64(factor)),
+        }
+    }
+}
+
 /// 双层缓存客户端实现
 ///
 /// 结合L1（内存）和L2（Redis）缓存，提供高性能和高可用性的缓存解决方案
@@ -43,10 +153,14 @@ pub struct TwoLevelClient {
     l1: Option<Arc<L1Backend>>,
     /// L2缓存客户端
     l2: Option<Arc<L2Client>>,
+    /// 次级L2后端，写入与删除尽力镜像到此后端
+    secondary: Option<Arc<crate::backend::l2::L2Backend>>,
     /// 序列化器
     serializer: SerializerEnum,
     /// 健康状态
     health_state: Arc<RwLock<HealthState>>,
+    /// 是否暂停向L2写入，暂停期间写入与删除转入WAL
+    l2_writes_paused: Arc<AtomicBool>,
     /// WAL管理器
     wal: Arc<WalManager>,
     /// 推广管理器
@@ -55,20 +169,44 @@ pub struct TwoLevelClient {
     batch_writer: Option<Arc<OptimizedBatchWriter>>,
     /// 失效发布器
     publisher: Option<Arc<InvalidationPublisher>>,
+    /// 失效键广播发送端，供外部观察者订阅
+    invalidation_watchers: broadcast::Sender<String>,
     /// 数据库回源管理器
     db_fallback_mgr: Option<Arc<DbFallbackManager>>,
+    /// 数据库回源并发限制
+    fallback_limiter: Option<Arc<Semaphore>>,
+    /// 缓存键校验模式
+    key_mode: KeyMode,
+    /// 缓存纪元，大于0时作为键前缀
+    cache_epoch: Arc<AtomicU64>,
+    /// 指标的键分组提取器
+    key_grouper: Option<Arc<KeyGrouper>>,
+    /// 是否记录逐操作指标
+    metrics_enabled: bool,
+    /// 关闭逐操作指标时是否仍记录关键指标
+    critical_metrics_forced: bool,
     /// 布隆过滤器
-    bloom_filter: Option<BloomFilterShared>,
+    bloom_filter: Option<CacheBloomFilter>,
     /// 布隆过滤器管理器
     bloom_filter_mgr: Option<Arc<BloomFilterManager>>,
     /// 缓存预热管理器
     warmup_mgr: Option<Arc<WarmupManager>>,
+    /// 最近写入的值，用于保证读己之写
+    recent_writes: Option<Cache<String, Vec<u8>>>,
+    /// 判断软TTL使用的时钟
+    clock: Arc<dyn Clock>,
+    /// 设置时钟时的时钟读数及对应的Unix时间戳（毫秒）
+    clock_origin: (std::time::Instant, u64),
     /// 健康检查器任务句柄
     #[allow(dead_code)]
     health_checker_handle: Option<JoinHandle<()>>,
     /// 批处理写入器任务句柄
     #[allow(dead_code)]
     batch_writer_handle: Option<JoinHandle<()>>,
+    /// L1指标采集任务句柄
+    l1_metrics_handle: Option<JoinHandle<()>>,
+    /// 失效订阅任务句柄
+    invalidation_subscriber_handle: Option<JoinHandle<()>>,
 }
 
 impl Clone for TwoLevelClient {
@@ -78,18 +216,32 @@ impl Clone for TwoLevelClient {
             config: self.config.clone(),
             l1: self.l1.clone(),
             l2: self.l2.clone(),
+            secondary: self.secondary.clone(),
             serializer: self.serializer.clone(),
             health_state: self.health_state.clone(),
+            l2_writes_paused: self.l2_writes_paused.clone(),
             wal: self.wal.clone(),
             promotion_mgr: self.promotion_mgr.clone(),
             batch_writer: self.batch_writer.clone(),
             publisher: self.publisher.clone(),
+            invalidation_watchers: self.invalidation_watchers.clone(),
             db_fallback_mgr: self.db_fallback_mgr.clone(),
+            fallback_limiter: self.fallback_limiter.clone(),
+            key_mode: self.key_mode,
+            cache_epoch: self.cache_epoch.clone(),
+            key_grouper: self.key_grouper.clone(),
+            metrics_enabled: self.metrics_enabled,
+            critical_metrics_forced: self.critical_metrics_forced,
             bloom_filter: self.bloom_filter.clone(),
             bloom_filter_mgr: self.bloom_filter_mgr.clone(),
             warmup_mgr: self.warmup_mgr.clone(),
+            recent_writes: self.recent_writes.clone(),
+            clock: self.clock.clone(),
+            clock_origin: self.clock_origin,
             health_checker_handle: None,
             batch_writer_handle: None,
+            l1_metrics_handle: None,
+            invalidation_subscriber_handle: None,
         }
     }
 }
@@ -124,58 +276,80 @@ impl TwoLevelClient {
         let health_state = Arc::new(RwLock::new(HealthState::Healthy));
         let wal = Arc::new(WalManager::new(&service_name).await?);
 
-        // 创建L2客户端
-        let l2 = Arc::new(
-            L2Client::new(service_name.clone(), l2_backend.clone(), serializer.clone()).await?,
-        );
+        // 创建L2客户端，失效发布器在确定频道后替换
+        let l2 =
+            L2Client::new(service_name.clone(), l2_backend.clone(), serializer.clone()).await?;
 
         // 启动健康检查器 - 使用L2Backend进行健康检查
         let command_timeout_ms = l2_backend.command_timeout_ms();
+        let l2_writes_paused = Arc::new(AtomicBool::new(false));
         let checker = HealthChecker::new(
             l2_backend.clone(),
             health_state.clone(),
             wal.clone(),
             service_name.clone(),
             command_timeout_ms,
-        );
-        let health_checker_handle = tokio::spawn(async move { checker.start().await });
+        )
+        .with_config(l2_backend.health_config().clone())
+        .with_replay_paused(l2_writes_paused.clone());
+        let health_checker_handle = spawn_named("health-checker", &service_name, async move {
+            checker.start().await
+        });
 
         // 确定失效频道名称
         let channel_name = Self::resolve_channel_name(&service_name, &config);
 
         // 启动失效订阅器 - 使用L2Backend的原始客户端
-        let sub = InvalidationSubscriber::new(
-            l2_backend.get_raw_client()?,
-            l1.clone(),
-            channel_name.clone(),
-            health_state.clone(),
-        );
-        sub.start().await?;
-
-        let publisher = Arc::new(InvalidationPublisher::new(
-            l2_backend
-                .get_raw_client()?
-                .get_connection_manager()
-                .await?,
-            channel_name,
+        let cache_epoch = Arc::new(AtomicU64::new(
+            Self::load_epoch(&l2_backend, &service_name).await,
         ));
-
-        let promotion_mgr = if config.promote_on_hit {
-            Some(Arc::new(PromotionManager::new(
+        let (invalidation_watchers, _) = broadcast::channel(INVALIDATION_WATCH_CAPACITY);
+        let (invalidation_subscriber_handle, publisher) = if l2_backend.supports_pubsub() {
+            let sub = InvalidationSubscriber::new(
+                l2_backend.get_raw_client()?,
                 l1.clone(),
-                l2_backend.clone(),
+                channel_name.clone(),
                 health_state.clone(),
-            )))
+            )
+            .with_watchers(invalidation_watchers.clone())
+            .with_service_name(service_name.clone())
+            .with_epoch(cache_epoch.clone());
+            let invalidation_subscriber_handle = sub.start().await?;
+
+            let publisher = Arc::new(InvalidationPublisher::new(
+                l2_backend
+                    .get_raw_client()?
+                    .get_connection_manager()
+                    .await?,
+                channel_name,
+            ));
+            (Some(invalidation_subscriber_handle), Some(publisher))
+        } else {
+            (None, None)
+        };
+
+        let promotion_mgr = if config.promote_on_hit {
+            let mgr = PromotionManager::new(l1.clone(), l2_backend.clone(), health_state.clone())
+                .with_service_name(service_name.clone());
+            Some(Arc::new(match config.max_promotion_tasks {
+                Some(max_tasks) => mgr.with_max_tasks(max_tasks),
+                None => mgr,
+            }))
         } else {
             None
         };
 
-        let (batch_writer, batch_writer_handle) = if config.enable_batch_write {
+        let (batch_writer, batch_writer_handle) = if config.uses_batch_writer() {
             let batch_config = crate::sync::optimized_batch_writer::OptimizedBatchWriterConfig {
                 base: BatchWriterConfig {
                     max_batch_size: config.batch_size,
                     flush_interval_ms: config.batch_interval_ms,
                     max_buffer_size: config.batch_size * 10,
+                    max_queue_depth: config
+                        .batch_max_queue_depth
+                        .unwrap_or(config.batch_size * 10),
+                    backpressure: config.batch_backpressure,
+                    block_timeout_ms: config.batch_block_timeout_ms,
                 },
                 max_retry_count: 3,
                 retry_delay_ms: 1000,
@@ -193,7 +367,9 @@ impl TwoLevelClient {
                 wal.clone(),
             ));
             let bw_clone = bw.clone();
-            let handle = tokio::spawn(async move { bw_clone.start().await });
+            let handle = spawn_named("batch-writer", &service_name, async move {
+                bw_clone.start().await
+            });
             (Some(bw), Some(handle))
         } else {
             (None, None)
@@ -205,9 +381,22 @@ impl TwoLevelClient {
                 bloom_config.expected_elements,
                 bloom_config.false_positive_rate,
             );
-            let mgr = Arc::new(BloomFilterManager::new());
-            let filter = mgr.get_or_create(options).await;
-            (Some(filter), Some(mgr))
+            match bloom_config.backend {
+                BloomFilterBackend::Local => {
+                    let mgr = Arc::new(BloomFilterManager::new());
+                    let filter = mgr.get_or_create(options).await;
+                    (Some(CacheBloomFilter::Local(filter)), Some(mgr))
+                }
+                BloomFilterBackend::Redis => {
+                    let filter = RedisBloomFilter::new(
+                        options,
+                        l2_backend.clone(),
+                        bloom_config.mirror_local,
+                    )
+                    .await?;
+                    (Some(CacheBloomFilter::Redis(Arc::new(filter))), None)
+                }
+            }
         } else {
             (None, None)
         };
@@ -219,91 +408,1588 @@ impl TwoLevelClient {
             ))
         });
 
-        Ok(Self {
+        let l1_metrics_handle = Self::spawn_l1_metrics(service_name.clone(), &l1);
+
+        let fallback_limiter = config
+            .max_concurrent_fallbacks
+            .map(|limit| Arc::new(Semaphore::new(limit)));
+
+        let secondary = match &config.secondary {
+            Some(secondary_config) => Some(Arc::new(
+                crate::backend::l2::L2Backend::new(secondary_config).await?,
+            )),
+            None => None,
+        };
+
+        let recent_writes = config.read_your_writes.as_ref().map(|ryw_config| {
+            Cache::builder()
+                .max_capacity(ryw_config.max_entries)
+                .time_to_live(Duration::from_millis(ryw_config.ttl_ms))
+                .build()
+        });
+
+        let client = Self {
             service_name: service_name.to_string(),
             config,
             l1: Some(l1),
-            l2: Some(l2),
+            l2: Some(Arc::new(l2.with_publisher(publisher.clone()))),
+            secondary,
             serializer,
             health_state,
+            l2_writes_paused,
             wal,
             promotion_mgr,
             batch_writer,
-            publisher: Some(publisher),
+            publisher,
+            invalidation_watchers,
             db_fallback_mgr: None,
+            fallback_limiter,
+            key_mode: KeyMode::default(),
+            cache_epoch,
+            key_grouper: None,
+            metrics_enabled: true,
+            critical_metrics_forced: false,
             bloom_filter,
             bloom_filter_mgr,
             warmup_mgr,
+            recent_writes,
+            clock: Arc::new(SystemClock),
+            clock_origin: (std::time::Instant::now(), unix_millis()),
             health_checker_handle: Some(health_checker_handle),
             batch_writer_handle,
+            l1_metrics_handle: Some(l1_metrics_handle),
+            invalidation_subscriber_handle,
+        };
+
+        // 预热在后台执行，进度记录在预热管理器的状态中，不阻塞客户端创建
+        if client
+            .warmup_mgr
+            .as_ref()
+            .is_some_and(|mgr| mgr.should_run_on_init())
+        {
+            let warmup_client = client.clone();
+            spawn_named("warmup", &client.service_name, async move {
+                if let Err(e) = warmup_client.run_warmup().await {
+                    warn!("Warmup on init failed: {}", e);
+                }
+            });
+        }
+
+        Ok(client)
+    }
+
+    /// 启动L1条目数指标的定期采集任务
+    ///
+    /// 任务只持有L1的弱引用，客户端未调用 `shutdown` 就被释放时任务随L1释放而退出
+    fn spawn_l1_metrics(service_name: String, l1: &Arc<L1Backend>) -> JoinHandle<()> {
+        let task_service = service_name.clone();
+        let l1 = Arc::downgrade(l1);
+        spawn_named("l1-metrics", &task_service, async move {
+            let mut interval = tokio::time::interval(L1_METRICS_INTERVAL);
+            loop {
+                interval.tick().await;
+                let Some(l1) = l1.upgrade() else {
+                    break;
+                };
+                GLOBAL_METRICS.set_l1_entries(&service_name, l1.len().await);
+            }
         })
     }
 
-    /// 处理L2故障
-    #[instrument(skip(self), level = "warn")]
-    async fn handle_l2_failure(&self) {
-        warn!("L2 failure detected for service: {}", self.service_name);
+    /// 设置缓存键校验模式
+    ///
+    /// # 参数
+    ///
+    /// * `key_mode` - 键校验模式
+    ///
+    /// # 返回值
+    ///
+    /// 返回设置了键校验模式的客户端
+    pub fn with_key_mode(mut self, key_mode: KeyMode) -> Self {
+        self.key_mode = key_mode;
+        self
+    }
 
-        let mut state_guard = self.health_state.write().await;
-        let current_state = *state_guard;
+    /// 设置缓存纪元
+    ///
+    /// 纪元大于0时所有键加上 `v{epoch}:` 前缀，见 [`bump_epoch`](Self::bump_epoch)。
+    /// L2中持久化的纪元更大时保留持久化的纪元
+    ///
+    /// # 参数
+    ///
+    /// * `epoch` - 缓存纪元
+    ///
+    /// # 返回值
+    ///
+    /// 返回设置了缓存纪元的客户端
+    pub fn with_cache_epoch(self, epoch: u64) -> Self {
+        self.cache_epoch.fetch_max(epoch, Ordering::SeqCst);
+        self
+    }
 
-        match current_state {
-            HealthState::Healthy => {
-                warn!(
-                    "Service {} transitioning from Healthy to Degraded",
-                    self.service_name
-                );
-                *state_guard = HealthState::Degraded {
-                    since: std::time::Instant::now(),
-                    failure_count: 1,
-                };
-                crate::metrics::GLOBAL_METRICS.set_health(&self.service_name, 0);
+    /// 当前的缓存纪元
+    pub fn cache_epoch(&self) -> u64 {
+        self.cache_epoch.load(Ordering::SeqCst)
+    }
+
+    /// 设置判断软TTL使用的时钟
+    ///
+    /// # 参数
+    ///
+    /// * `clock` - 时钟，测试中可注入 [`MockClock`](crate::utils::clock::MockClock)
+    ///
+    /// # 返回值
+    ///
+    /// 返回设置了时钟的客户端
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock_origin = (clock.now(), unix_millis());
+        self.clock = clock;
+        self
+    }
+
+    /// 按客户端时钟推算的当前Unix时间戳（毫秒）
+    fn now_millis(&self) -> u64 {
+        let (origin, origin_millis) = self.clock_origin;
+        origin_millis
+            + self
+                .clock
+                .now()
+                .saturating_duration_since(origin)
+                .as_millis() as u64
+    }
+
+    /// 设置次级L2后端
+    ///
+    /// 替换由 `TwoLevelConfig::secondary` 创建的后端，写入与删除会尽力镜像到该后端
+    ///
+    /// # 参数
+    ///
+    /// * `secondary` - 次级L2后端
+    ///
+    /// # 返回值
+    ///
+    /// 返回设置了次级L2的客户端
+    pub fn with_secondary(mut self, secondary: Arc<crate::backend::l2::L2Backend>) -> Self {
+        self.secondary = Some(secondary);
+        self
+    }
+
+    /// 获取次级L2后端
+    pub fn secondary(&self) -> Option<&Arc<crate::backend::l2::L2Backend>> {
+        self.secondary.as_ref()
+    }
+
+    /// 交换主次L2
+    ///
+    /// 之后的读写访问原次级L2，写入与删除镜像到原主L2。健康检查、失效订阅与L2命中推广
+    /// 仍使用创建客户端时的主L2，完成迁移后应以新配置重新创建客户端。
+    /// 批量写入器绑定创建时的主L2，启用批量写入时不支持交换
+    ///
+    /// # 返回值
+    ///
+    /// 未配置次级L2或启用了批量写入时返回 `CacheError::NotSupported`
+    #[instrument(skip(self), level = "info", fields(service = %self.service_name))]
+    pub async fn promote_secondary(&mut self) -> Result<()> {
+        let Some(secondary) = self.secondary.clone() else {
+            return Err(crate::error::CacheError::NotSupported(
+                "no secondary L2 configured".to_string(),
+            ));
+        };
+        if self.batch_writer.is_some() {
+            return Err(crate::error::CacheError::NotSupported(
+                "cannot promote secondary L2 while batch writes are enabled".to_string(),
+            ));
+        }
+
+        let promoted = L2Client::new(
+            self.service_name.clone(),
+            secondary,
+            self.serializer.clone(),
+        )
+        .await?
+        .with_publisher(self.publisher.clone());
+        promoted.set_metrics_enabled(self.metrics_enabled);
+        let previous = self.l2.replace(Arc::new(promoted));
+        self.secondary = previous.map(|l2| l2.backend().clone());
+        info!(
+            "Promoted secondary L2 to primary, service={}",
+            self.service_name
+        );
+        Ok(())
+    }
+
+    /// 将写入尽力镜像到次级L2，失败只记录日志与指标
+    ///
+    /// 用于迁移Redis时的双写：写入与删除在主L2成功后镜像到次级L2，读取仍只访问主L2，
+    /// 切换时调用 [`promote_secondary`](Self::promote_secondary) 交换主次L2
+    async fn mirror_set(&self, key: &str, value: Vec<u8>, ttl: LayerTtl) {
+        if let Some(secondary) = &self.secondary {
+            let result = match ttl {
+                LayerTtl::Secs(ttl) => secondary.set_with_version(key, value, ttl).await,
+                LayerTtl::Millis(ttl) => secondary.set_bytes_ms(key, value, ttl).await,
+            };
+            match result {
+                Ok(()) => self.record_request("L2Secondary", "set", "success"),
+                Err(e) => {
+                    warn!(
+                        "Failed to mirror write of key {} to secondary L2: {}",
+                        key, e
+                    );
+                    self.record_request("L2Secondary", "set", "error");
+                }
             }
-            HealthState::Degraded {
-                since,
-                failure_count,
-            } => {
-                let new_failure_count = failure_count + 1;
-                warn!(
-                    "Service {} remains Degraded, failure count increased: {} -> {}",
-                    self.service_name, failure_count, new_failure_count
-                );
-                *state_guard = HealthState::Degraded {
-                    since,
-                    failure_count: new_failure_count,
-                };
+        }
+    }
+
+    /// 将删除尽力镜像到次级L2，失败只记录日志与指标
+    async fn mirror_delete(&self, key: &str) {
+        if let Some(secondary) = &self.secondary {
+            match secondary.delete(key).await {
+                Ok(_) => self.record_request("L2Secondary", "delete", "success"),
+                Err(e) => {
+                    warn!(
+                        "Failed to mirror delete of key {} to secondary L2: {}",
+                        key, e
+                    );
+                    self.record_request("L2Secondary", "delete", "error");
+                }
             }
-            HealthState::Recovering {
-                since: _,
-                success_count: _,
-            } => {
+        }
+    }
+
+    /// 读取L2中持久化的缓存纪元
+    ///
+    /// # 返回值
+    ///
+    /// 返回持久化的纪元，不存在或读取失败时返回0
+    async fn load_epoch(l2: &crate::backend::l2::L2Backend, service_name: &str) -> u64 {
+        match l2.get_bytes(&epoch_key(service_name)).await {
+            Ok(Some(bytes)) => String::from_utf8_lossy(&bytes).parse().unwrap_or_else(|_| {
+                warn!("Ignoring malformed cache epoch of service {}", service_name);
+                0
+            }),
+            Ok(None) => 0,
+            Err(e) => {
                 warn!(
-                    "Service {} recovery failed, transitioning back to Degraded from Recovering",
-                    self.service_name
+                    "Failed to load cache epoch of service {}: {}",
+                    service_name, e
                 );
-                *state_guard = HealthState::Degraded {
-                    since: std::time::Instant::now(),
-                    failure_count: 1,
-                };
-                crate::metrics::GLOBAL_METRICS.set_health(&self.service_name, 0);
+                0
             }
-            HealthState::WalReplaying { .. } => {
-                warn!(
-                    "Service {} is replaying WAL, ignoring failure during replay",
-                    self.service_name
-                );
+        }
+    }
+
+    /// 以 `INCR` 递增L2中持久化的缓存纪元
+    ///
+    /// 持久化的纪元落后于本地纪元（如本地纪元由配置指定）时补齐到 `next`
+    ///
+    /// # 参数
+    ///
+    /// * `l2` - L2缓存后端
+    /// * `next` - 本地纪元加一
+    ///
+    /// # 返回值
+    ///
+    /// 返回持久化后的纪元
+    async fn persist_epoch(&self, l2: &crate::backend::l2::L2Backend, next: u64) -> Result<u64> {
+        let key = epoch_key(&self.service_name);
+        let mut epoch = l2.incr(&key).await?;
+        let next = i64::try_from(next).unwrap_or(i64::MAX);
+        if epoch < next {
+            epoch = l2.incr_by_checked(&key, next - epoch, None).await?;
+        }
+        Ok(epoch.max(0) as u64)
+    }
+
+    /// 递增缓存纪元，使之前写入的所有键失效
+    ///
+    /// 新的纪元持久化在L2中，重启或新建的客户端从L2加载，不会回到旧纪元。
+    /// 之后的读写使用新的键前缀，旧键不再被访问并按TTL自然过期。
+    /// 本地L1立即清空，并通过失效频道通知其他实例更新纪元、清空L1
+    ///
+    /// # 返回值
+    ///
+    /// 返回新的纪元，写入L2失败时返回错误（本地纪元不变）；
+    /// 发布通知失败时返回错误（本地纪元已更新）
+    #[instrument(skip(self), level = "info", fields(service = %self.service_name))]
+    pub async fn bump_epoch(&self) -> Result<u64> {
+        let next = self.cache_epoch.load(Ordering::SeqCst) + 1;
+        let epoch = match &self.l2 {
+            Some(l2) => self.persist_epoch(l2.backend(), next).await?,
+            None => next,
+        };
+        self.cache_epoch.fetch_max(epoch, Ordering::SeqCst);
+        self.clear_l1().await?;
+        info!(
+            "Cache epoch of service {} bumped to {}",
+            self.service_name, epoch
+        );
+        if let Some(publisher) = &self.publisher {
+            publisher.publish_epoch(epoch).await?;
+        }
+        Ok(epoch)
+    }
+
+    /// 设置指标的键分组提取器
+    ///
+    /// 设置后读取相关的指标额外按键分组统计，见 [`Metrics::record_key_group_request`]
+    ///
+    /// # 参数
+    ///
+    /// * `key_grouper` - 键分组提取器
+    ///
+    /// # 返回值
+    ///
+    /// 返回设置了键分组的客户端
+    ///
+    /// [`Metrics::record_key_group_request`]: crate::metrics::Metrics::record_key_group_request
+    pub fn with_key_grouper(mut self, key_grouper: KeyGrouper) -> Self {
+        self.key_grouper = Some(Arc::new(key_grouper));
+        self
+    }
+
+    /// 设置是否记录逐操作指标
+    ///
+    /// 关闭后本客户端及其内部L2客户端的请求计数、耗时与L1条目数等指标不再记录
+    /// （同时停止L1条目数采集任务），
+    /// 健康状态与TTL偏离等关键指标只有在 `force_critical` 为true时继续记录
+    ///
+    /// # 参数
+    ///
+    /// * `enabled` - 是否记录逐操作指标
+    /// * `force_critical` - 关闭逐操作指标时是否仍记录关键指标
+    ///
+    /// # 返回值
+    ///
+    /// 返回设置了指标开关的客户端
+    pub fn with_metrics(mut self, enabled: bool, force_critical: bool) -> Self {
+        self.metrics_enabled = enabled;
+        self.critical_metrics_forced = force_critical;
+        if let Some(l2) = &self.l2 {
+            l2.set_metrics_enabled(enabled);
+        }
+        if !enabled {
+            if let Some(handle) = self.l1_metrics_handle.take() {
+                handle.abort();
+            }
+        }
+        self
+    }
+
+    /// 是否记录健康状态与TTL偏离等关键指标
+    fn critical_metrics_enabled(&self) -> bool {
+        self.metrics_enabled || self.critical_metrics_forced
+    }
+
+    /// 记录请求指标（逐操作指标关闭时为空操作）
+    fn record_request(&self, layer: &str, op: &str, result: &str) {
+        if self.metrics_enabled {
+            GLOBAL_METRICS.record_request(&self.service_name, layer, op, result);
+        }
+    }
+
+    /// 记录耗时指标（逐操作指标关闭时为空操作）
+    fn record_duration(&self, layer: &str, op: &str, duration_secs: f64) {
+        if self.metrics_enabled {
+            GLOBAL_METRICS.record_duration(&self.service_name, layer, op, duration_secs);
+        }
+    }
+
+    /// 记录与缓存键相关的请求指标，配置了键分组时同时按分组统计
+    fn record_key_request(&self, key: &str, layer: &str, op: &str, result: &str) {
+        if !self.metrics_enabled {
+            return;
+        }
+        GLOBAL_METRICS.record_request(&self.service_name, layer, op, result);
+        if let Some(grouper) = &self.key_grouper {
+            GLOBAL_METRICS.record_key_group_request(
+                &self.service_name,
+                layer,
+                op,
+                result,
+                grouper.group(key),
+            );
+        }
+    }
+
+    /// 将键添加到布隆过滤器（未启用时为空操作）
+    async fn add_to_bloom_filter(&self, key: &str) {
+        if let Some(bloom_filter) = &self.bloom_filter {
+            bloom_filter.add(key.as_bytes()).await;
+            self.record_request("BloomFilter", "set", "add");
+        }
+    }
+
+    /// 检查本次写入的L1有效TTL是否明显长于L2
+    ///
+    /// L1条目比L2存活更久时，L1会继续提供L2中已过期的数据。超过
+    /// [`ttl_divergence_factor`](TwoLevelConfig::ttl_divergence_factor) 倍时输出告警并计入
+    /// `ttl_divergence_total` 指标，仅作提示，不影响写入
+    fn check_ttl_divergence(&self, key: &str, ttl: Option<u64>) {
+        let Some(l1) = &self.l1 else {
+            return;
+        };
+        let l1_ttl = ttl.unwrap_or_else(|| l1.default_ttl());
+        let l2_ttl = ttl.unwrap_or_else(|| self.l2_default_ttl());
+        let diverged = match (l1_ttl, l2_ttl) {
+            (_, PERSISTENT_TTL) => false,
+            (PERSISTENT_TTL, _) => true,
+            (l1_ttl, l2_ttl) => l1_ttl as f64 > l2_ttl as f64 * self.config.ttl_divergence_factor,
+        };
+        if diverged {
+            warn!(
+                "L1 TTL {}s exceeds L2 TTL {}s (factor {}) for key: {}, L1 may serve data already expired in L2",
+                l1_ttl, l2_ttl, self.config.ttl_divergence_factor, key
+            );
+            if self.critical_metrics_enabled() {
+                GLOBAL_METRICS.record_ttl_divergence(&self.service_name);
             }
         }
+    }
+
+    /// L2写入时未指定TTL所使用的过期时间（秒），取自L2配置的 `default_ttl`
+    fn l2_default_ttl(&self) -> u64 {
+        self.l2
+            .as_ref()
+            .map_or(DEFAULT_L2_TTL_SECS, |l2| l2.backend().default_ttl())
+    }
+
+    /// L2恢复期间按 [`recovering_ttl_factor`](TwoLevelConfig::recovering_ttl_factor) 放大写入TTL
+    ///
+    /// 放大TTL可以减少L2抖动期间因TTL较短导致的频繁回源。未指定TTL时以L2配置的默认TTL
+    /// 为基准放大，持久化写入保持不变；其他健康状态下返回原TTL
+    async fn adaptive_ttl(&self, ttl: LayerTtl) -> LayerTtl {
+        let Some(factor) = self.config.recovering_ttl_factor else {
+            return ttl;
+        };
+        if !matches!(
+            *self.health_state.read().await,
+            HealthState::Recovering { .. }
+        ) {
+            return ttl;
+        }
+        let extended = ttl.scaled(factor, self.l2_default_ttl());
+        debug!(
+            "L2 recovering, extending TTL {:?} -> {:?} for service {}",
+            ttl, extended, self.service_name
+        );
+        extended
+    }
+
+    /// 将已规范化、已校验的键值写入L1和L2
+    ///
+    /// TTL按 [`adaptive_ttl`](Self::adaptive_ttl) 调整，写入成功后按配置记入最近写入缓冲
+    /// 并镜像到次级L2
+    async fn write_layers(&self, key: &str, bytes: Vec<u8>, ttl: LayerTtl) -> Result<()> {
+        let ttl = self.adaptive_ttl(ttl).await;
+        let recent = self.recent_writes.as_ref().map(|_| bytes.clone());
+        let mirrored = self.secondary.as_ref().map(|_| bytes.clone());
+        self.write_to_layers(key, bytes, ttl).await?;
+        if let Some(bytes) = recent {
+            self.remember_write(key, bytes).await;
+        }
+        if let Some(bytes) = mirrored {
+            self.mirror_set(key, bytes, ttl).await;
+        }
+        Ok(())
+    }
+
+    /// 按配置将写入记入最近写入缓冲（未启用时为空操作）
+    ///
+    /// 读取在L1与L2均未命中时先查该缓冲，保证L2降级、写入只进入WAL期间
+    /// 调用方仍能读到自己刚写入的值
+    async fn remember_write(&self, key: &str, bytes: Vec<u8>) {
+        if let Some(recent_writes) = &self.recent_writes {
+            recent_writes.insert(key.to_string(), bytes).await;
+        }
+    }
+
+    /// 按写入顺序与健康状态写入L1、L2或WAL
+    ///
+    /// L2降级或正在重放WAL时写入WAL，按配置经由批量写入器写入L2。
+    /// 批量写入器只支持秒级TTL，毫秒级TTL的写入直接写入L2
+    async fn write_to_layers(&self, key: &str, bytes: Vec<u8>, ttl: LayerTtl) -> Result<()> {
+        self.check_ttl_divergence(key, ttl.as_secs());
+        // Two-level mode
+        if let (Some(l1), Some(l2)) = (&self.l1, &self.l2) {
+            if self.config.write_order == WriteOrder::L2First {
+                return self.set_bytes_l2_first(l1, l2, key, bytes, ttl).await;
+            }
+
+            // 1. 写入L1
+            let start = std::time::Instant::now();
+            debug!("Writing to L1: key={}", key);
+            self.admit_l1(l1, key, bytes.clone(), ttl).await?;
+            let duration = start.elapsed().as_secs_f64();
+            self.record_duration("L1", "set", duration);
+            debug!("L1 write successful: key={}", key);
+
+            // 2. 检查L2健康状态
+            let state = self.health_state.read().await;
+            let current_state = *state;
+            let paused = self.is_l2_writes_paused();
+            debug!(
+                "Current health state: {:?}, l2_writes_paused={}",
+                current_state, paused
+            );
+            match current_state {
+                HealthState::Healthy | HealthState::Recovering { .. } if !paused => {
+                    drop(state);
+                    match ttl {
+                        LayerTtl::Secs(ttl) if self.config.uses_batch_writer() => {
+                            if let Some(batch_writer) = &self.batch_writer {
+                                batch_writer
+                                    .enqueue_operation(
+                                        BatchOperation::Set {
+                                            key: key.to_string(),
+                                            value: bytes,
+                                            ttl,
+                                        },
+                                        100, // default priority
+                                    )
+                                    .await?;
+                            }
+                        }
+                        // 使用L2客户端的set_bytes方法，它会处理健康状态检查
+                        LayerTtl::Secs(ttl) => l2.set_bytes(key, bytes, ttl).await?,
+                        LayerTtl::Millis(ttl) => l2.set_bytes_ms(key, bytes, ttl).await?,
+                    }
+                }
+                HealthState::Healthy
+                | HealthState::Recovering { .. }
+                | HealthState::Degraded { .. } => {
+                    drop(state);
+                    debug!(
+                        "L2 is degraded or writes are paused, writing to WAL: key={}",
+                        key
+                    );
+                    self.wal
+                        .append(WalEntry {
+                            timestamp: std::time::SystemTime::now(),
+                            operation: Operation::Set,
+                            key: key.to_string(),
+                            value: Some(bytes),
+                            ttl: ttl.as_secs().map(|t| t as i64),
+                        })
+                        .await?;
+                    debug!("WAL write successful: key={}", key);
+                }
+                HealthState::WalReplaying { .. } => {
+                    drop(state);
+                    debug!("L2 is replaying WAL, writing to WAL: key={}", key);
+                    self.wal
+                        .append(WalEntry {
+                            timestamp: std::time::SystemTime::now(),
+                            operation: Operation::Set,
+                            key: key.to_string(),
+                            value: Some(bytes),
+                            ttl: ttl.as_secs().map(|t| t as i64),
+                        })
+                        .await?;
+                    debug!("WAL write successful: key={}", key);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// 按服务的键校验模式规范化缓存键，缓存纪元大于0时加上纪元前缀
+    fn resolve_key<'a>(&self, key: &'a str) -> Result<Cow<'a, str>> {
+        let key = sanitize_cache_key(
+            key,
+            self.key_mode,
+            self.config.max_key_length.unwrap_or(256),
+        )?;
+        match self.cache_epoch.load(Ordering::SeqCst) {
+            0 => Ok(key),
+            epoch => Ok(Cow::Owned(format!("v{}:{}", epoch, key))),
+        }
+    }
+
+    /// 仅根据布隆过滤器判断键是否可能存在
+    ///
+    /// 不访问L1和L2，开销只有一次过滤器查询：
+    /// 返回false表示该键从未通过本客户端写入（确定不存在）；
+    /// 返回true只表示"可能存在"，可能是误判，也可能已被删除或过期。
+    /// 未配置布隆过滤器时无法排除任何键，总是返回true；键不合法时返回false
+    ///
+    /// # 参数
+    ///
+    /// * `key` - 缓存键
+    ///
+    /// # 返回值
+    ///
+    /// 返回键是否可能存在
+    #[instrument(skip(self), level = "debug", fields(service = %self.service_name))]
+    pub async fn probably_contains(&self, key: &str) -> bool {
+        let Ok(key) = self.resolve_key(key) else {
+            return false;
+        };
+        match &self.bloom_filter {
+            Some(bloom_filter) => bloom_filter.contains(key.as_bytes()).await,
+            None => true,
+        }
+    }
+
+    /// 确认键当前是否存在
+    ///
+    /// 先用布隆过滤器排除确定不存在的键，过滤器判定可能存在时依次检查L1和L2，
+    /// 不读取L2中的值也不回源数据库。结果只反映调用时刻的状态
+    ///
+    /// # 参数
+    ///
+    /// * `key` - 缓存键
+    ///
+    /// # 返回值
+    ///
+    /// 返回键是否存在；L2检查失败时返回错误
+    #[instrument(skip(self), level = "debug", fields(service = %self.service_name))]
+    pub async fn definitely_contains(&self, key: &str) -> Result<bool> {
+        let key = self.resolve_key(key)?;
+        if let Some(bloom_filter) = &self.bloom_filter {
+            if !bloom_filter.contains(key.as_bytes()).await {
+                return Ok(false);
+            }
+        }
+
+        if let Some(l1) = &self.l1 {
+            if l1.get_bytes(&key).await?.is_some() {
+                return Ok(true);
+            }
+        }
+
+        match &self.l2 {
+            Some(l2) => l2.backend().exists(&key).await,
+            None => Ok(false),
+        }
+    }
+
+    /// 获取L2命中推广到L1的统计
+    ///
+    /// # 返回值
+    ///
+    /// 返回推广统计快照，未启用命中推广时返回None
+    pub fn promotion_stats(&self) -> Option<PromotionStats> {
+        self.promotion_mgr.as_ref().map(|mgr| mgr.stats())
+    }
+
+    /// 获取L1缓存当前条目数
+    ///
+    /// # 返回值
+    ///
+    /// 返回条目数，未启用L1时返回0
+    pub async fn l1_len(&self) -> u64 {
+        match &self.l1 {
+            Some(l1) => {
+                let len = l1.len().await;
+                if self.metrics_enabled {
+                    GLOBAL_METRICS.set_l1_entries(&self.service_name, len);
+                }
+                len
+            }
+            None => 0,
+        }
+    }
+
+    /// 获取L1缓存占用的近似内存大小（字节）
+    ///
+    /// # 返回值
+    ///
+    /// 返回键和值的字节数之和，未启用L1时返回0
+    pub async fn l1_weighted_size(&self) -> u64 {
+        match &self.l1 {
+            Some(l1) => l1.weighted_size().await,
+            None => 0,
+        }
+    }
+
+    /// 获取Redis数据库中的键数量
+    ///
+    /// 注意：`DBSIZE` 统计整个Redis数据库，包含其他服务的键，不是本服务的条目数
+    ///
+    /// # 返回值
+    ///
+    /// 返回键数量或错误
+    pub async fn l2_dbsize(&self) -> Result<u64> {
+        match &self.l2 {
+            Some(l2) => l2.dbsize().await,
+            None => Err(crate::error::CacheError::L2Error(
+                "L2 client not available".to_string(),
+            )),
+        }
+    }
+
+    /// 获取键在L2中的剩余生存时间（毫秒精度）
+    ///
+    /// # 参数
+    ///
+    /// * `key` - 缓存键
+    ///
+    /// # 返回值
+    ///
+    /// 返回剩余毫秒数，键不存在或未设置过期时间时返回None，未启用L2时返回错误
+    pub async fn pttl(&self, key: &str) -> Result<Option<u64>> {
+        let key = self.resolve_key(key)?;
+        match &self.l2 {
+            Some(l2) => l2.pttl(&key).await,
+            None => Err(crate::error::CacheError::L2Error(
+                "L2 client not available".to_string(),
+            )),
+        }
+    }
+
+    /// 以毫秒精度的TTL写入L1和L2
+    ///
+    /// 适用于亚秒级过期的短期数据。与 `set_bytes` 经过相同的写入路径（健康检查、暂停写入、
+    /// 次级L2镜像与最近写入缓冲），但不经过批量写入器，也不按恢复期间的策略放大TTL。
+    /// L2降级、暂停写入或正在重放WAL时写入WAL，WAL只保存秒级TTL，过期时间向上取整为秒
+    ///
+    /// # 参数
+    ///
+    /// * `key` - 缓存键
+    /// * `value` - 缓存值（字节数组）
+    /// * `ttl` - 过期时间，必须至少为1毫秒
+    ///
+    /// # 返回值
+    ///
+    /// 返回操作结果，TTL不足1毫秒时返回 `CacheError::InvalidInput`
+    #[instrument(skip(self, value), level = "debug", fields(service = %self.service_name))]
+    pub async fn set_bytes_ms(&self, key: &str, value: Vec<u8>, ttl: Duration) -> Result<()> {
+        let key = self.resolve_key(key)?;
+        let key = key.as_ref();
+
+        if ttl.as_millis() == 0 {
+            return Err(crate::error::CacheError::InvalidInput(
+                "TTL must be at least 1 millisecond".to_string(),
+            ));
+        }
+        let max_value_size = self.config.max_value_size.unwrap_or(10 * 1024 * 1024);
+        validate_value_size(&value, max_value_size)?;
+
+        self.add_to_bloom_filter(key).await;
+
+        self.write_layers(key, value, LayerTtl::Millis(ttl)).await
+    }
+
+    /// 写入新值并返回旧值（`GETSET` 语义）
+    ///
+    /// 旧值由L2原子地返回，写入成功后与普通写入一样按准入阈值更新L1、记入最近写入缓冲
+    /// 并镜像到次级L2，失效通知经由客户端配置的失效频道发布。启用批量写入时先刷新缓冲区，
+    /// 确保返回的旧值包含尚未写出的写入。旧值无法从WAL取得，因此未启用L2、L2降级
+    /// 或暂停L2写入时返回错误
+    ///
+    /// # 参数
+    ///
+    /// * `key` - 缓存键
+    /// * `value` - 新值
+    /// * `ttl` - 过期时间（秒）
+    ///
+    /// # 返回值
+    ///
+    /// 返回反序列化后的旧值，键不存在时返回None
+    #[instrument(skip(self, value), level = "debug", fields(service = %self.service_name))]
+    pub async fn get_set<T>(&self, key: &str, value: &T, ttl: Option<u64>) -> Result<Option<T>>
+    where
+        T: serde::Serialize + serde::de::DeserializeOwned + Send + Sync,
+    {
+        let key = self.resolve_key(key)?;
+        let key = key.as_ref();
+
+        let bytes = self.serializer.serialize(value)?;
+        let max_value_size = self.config.max_value_size.unwrap_or(10 * 1024 * 1024);
+        validate_value_size(&bytes, max_value_size)?;
+
+        let l2 = self.l2.as_ref().ok_or_else(|| {
+            crate::error::CacheError::L2Error("L2 client not available".to_string())
+        })?;
+        let state = *self.health_state.read().await;
+        if self.is_l2_writes_paused()
+            || !matches!(state, HealthState::Healthy | HealthState::Recovering { .. })
+        {
+            return Err(crate::error::CacheError::L2Error(
+                "L2 is not available for get-set writes".to_string(),
+            ));
+        }
+
+        self.add_to_bloom_filter(key).await;
+        if let Some(batch_writer) = &self.batch_writer {
+            batch_writer.flush().await?;
+        }
+
+        let ttl = self.adaptive_ttl(LayerTtl::Secs(ttl)).await.as_secs();
+        self.check_ttl_divergence(key, ttl);
+        let previous = l2.get_set_bytes(key, bytes.clone(), ttl).await?;
+        self.set_l1_resolved(key, bytes.clone(), ttl).await?;
+        if self.recent_writes.is_some() {
+            self.remember_write(key, bytes.clone()).await;
+        }
+        self.mirror_set(key, bytes, LayerTtl::Secs(ttl)).await;
+        previous
+            .map(|bytes| self.serializer.deserialize(&bytes))
+            .transpose()
+    }
+
+    /// 原子地按增量自增计数器，可选地限制结果不超过上限
+    ///
+    /// 计数器以十进制整数保存在L2中，自增成功后移除L1中的旧值，经由客户端配置的失效频道
+    /// 发布失效通知，并按主L2中剩余的TTL将新值镜像到次级L2。结果越过 `max`
+    /// 或超出 `i64` 范围时计数器保持不变，返回 `CacheError::LimitExceeded`。
+    /// 未启用L2或L2降级时返回错误
+    ///
+    /// # 参数
+    ///
+    /// * `key` - 缓存键
+    /// * `delta` - 增量，负数表示自减
+    /// * `max` - 结果允许的最大值，None表示只检查 `i64` 范围
+    ///
+    /// # 返回值
+    ///
+    /// 返回自增后的值
+    #[instrument(skip(self), level = "debug", fields(service = %self.service_name))]
+    pub async fn incr_by_checked(&self, key: &str, delta: i64, max: Option<i64>) -> Result<i64> {
+        let key = self.resolve_key(key)?;
+        let key = key.as_ref();
+
+        let l2 = self.l2.as_ref().ok_or_else(|| {
+            crate::error::CacheError::L2Error("L2 client not available".to_string())
+        })?;
+
+        let value = l2.incr_by_checked(key, delta, max).await?;
+        if let Some(recent_writes) = &self.recent_writes {
+            recent_writes.invalidate(key).await;
+        }
+        if let Some(l1) = &self.l1 {
+            l1.delete(key).await?;
+        }
+        if self.secondary.is_some() {
+            // 自增保留计数器原有的过期时间，镜像时沿用主L2中剩余的TTL
+            match l2.pttl(key).await {
+                Ok(remaining) => {
+                    let ttl = match remaining {
+                        Some(ms) => LayerTtl::Millis(Duration::from_millis(ms)),
+                        None => LayerTtl::Secs(Some(PERSISTENT_TTL)),
+                    };
+                    self.mirror_set(key, value.to_string().into_bytes(), ttl)
+                        .await;
+                }
+                Err(e) => warn!(
+                    "Failed to read TTL of counter {} for secondary mirroring: {}",
+                    key, e
+                ),
+            }
+        }
+        Ok(value)
+    }
+
+    /// 原子地读取并删除缓存项
+    ///
+    /// 适用于一次性令牌等只能使用一次的值：由L2原子地取走值并删除其版本键，
+    /// 并发调用时只有一个调用方能取得值。L1与次级L2中的副本同时移除，
+    /// 并经由客户端配置的失效频道广播失效消息。
+    /// 启用批量写入时先刷新缓冲区，确保尚未写出的值也能被取走。
+    /// 未启用L2或L2降级时返回错误
+    ///
+    /// # 参数
+    ///
+    /// * `key` - 缓存键
+    ///
+    /// # 返回值
+    ///
+    /// 返回反序列化后的值，键不存在时返回None
+    #[instrument(skip(self), level = "debug", fields(service = %self.service_name))]
+    pub async fn take<T>(&self, key: &str) -> Result<Option<T>>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let key = self.resolve_key(key)?;
+        let key = key.as_ref();
+
+        let l2 = self.l2.as_ref().ok_or_else(|| {
+            crate::error::CacheError::L2Error("L2 client not available".to_string())
+        })?;
+
+        if let Some(batch_writer) = &self.batch_writer {
+            batch_writer.flush().await?;
+        }
+        if let Some(recent_writes) = &self.recent_writes {
+            recent_writes.invalidate(key).await;
+        }
+        if let Some(l1) = &self.l1 {
+            l1.delete(key).await?;
+        }
+
+        let value = l2.take_bytes(key).await?;
+        self.mirror_delete(key).await;
+        value
+            .map(|bytes| self.serializer.deserialize(&bytes))
+            .transpose()
+    }
+
+    /// 扫描L2中匹配模式的键
+    ///
+    /// 使用 `SCAN` 游标遍历，只读取键名，不删除任何数据
+    ///
+    /// # 参数
+    ///
+    /// * `pattern` - Redis glob 模式，如 `user:*`
+    ///
+    /// # 返回值
+    ///
+    /// 返回匹配的键，未启用L2时返回错误
+    pub async fn scan_keys(&self, pattern: &str) -> Result<Vec<String>> {
+        match &self.l2 {
+            Some(l2) => l2.scan_keys(pattern).await,
+            None => Err(crate::error::CacheError::L2Error(
+                "L2 client not available".to_string(),
+            )),
+        }
+    }
+
+    /// 按页流式扫描L2中匹配模式的键
+    ///
+    /// 与 [`scan_keys`](Self::scan_keys) 不同，不会把所有匹配的键收集到内存中：
+    /// 每页最多 `page_size` 个键，消费完一页后才继续扫描，适合键数量很大的服务
+    ///
+    /// # 参数
+    ///
+    /// * `pattern` - Redis glob 模式，如 `user:*`
+    /// * `page_size` - 每页的最大键数量
+    ///
+    /// # 返回值
+    ///
+    /// 返回逐页产出匹配键的流，未启用L2时流只产出一个错误
+    pub fn scan<'a>(
+        &'a self,
+        pattern: &'a str,
+        page_size: usize,
+    ) -> impl Stream<Item = Result<Vec<String>>> + Send + 'a {
+        match &self.l2 {
+            Some(l2) => l2.scan_pages(pattern, page_size).left_stream(),
+            None => futures::stream::once(async {
+                Err(crate::error::CacheError::L2Error(
+                    "L2 client not available".to_string(),
+                ))
+            })
+            .right_stream(),
+        }
+    }
+
+    /// 获取Redis服务器信息
+    ///
+    /// 集群模式下返回所有主节点的信息，键以节点地址为前缀，详见 [`L2Backend::info`]
+    ///
+    /// # 参数
+    ///
+    /// * `section` - INFO分区名称，None表示默认分区
+    ///
+    /// # 返回值
+    ///
+    /// 返回解析后的信息键值对或错误
+    ///
+    /// [`L2Backend::info`]: crate::backend::l2::L2Backend::info
+    pub async fn l2_info(&self, section: Option<&str>) -> Result<HashMap<String, String>> {
+        match &self.l2 {
+            Some(l2) => l2.info(section).await,
+            None => Err(crate::error::CacheError::L2Error(
+                "L2 client not available".to_string(),
+            )),
+        }
+    }
+
+    /// 获取键在Redis中占用的内存字节数
+    ///
+    /// # 参数
+    ///
+    /// * `key` - 缓存键
+    ///
+    /// # 返回值
+    ///
+    /// 返回占用字节数（键不存在时为0）或错误
+    pub async fn l2_memory_usage(&self, key: &str) -> Result<u64> {
+        let key = self.resolve_key(key)?;
+        match &self.l2 {
+            Some(l2) => l2.memory_usage(&key).await,
+            None => Err(crate::error::CacheError::L2Error(
+                "L2 client not available".to_string(),
+            )),
+        }
+    }
+
+    /// 依次从L1和L2读取缓存值（不进行数据库回源）
+    ///
+    /// # 参数
+    ///
+    /// * `l1` - L1缓存后端
+    /// * `l2` - L2缓存客户端
+    /// * `key` - 缓存键
+    ///
+    /// # 返回值
+    ///
+    /// 返回命中的缓存值，L1和L2均未命中时返回None
+    async fn get_from_layers(
+        &self,
+        l1: &L1Backend,
+        l2: &L2Client,
+        key: &str,
+    ) -> Result<Option<Vec<u8>>> {
+        // 1. 尝试L1
+        if let Some(bytes) = self.lookup_l1(l1, key).await? {
+            return Ok(Some(bytes));
+        }
+
+        // 2. 检查健康状态 - 如果L2降级，仍然尝试L1，但跳过L2
+        let is_degraded = self.is_degraded().await;
+
+        // 3. 尝试L2（仅当L2健康时）
+        if !is_degraded {
+            self.record_request("L2", "get", "attempt");
+            let start = std::time::Instant::now();
+            match l2.get_with_version(key).await {
+                Ok(Some((value, version))) => {
+                    let duration = start.elapsed().as_secs_f64();
+                    self.record_duration("L2", "get", duration);
+                    self.record_key_request(key, "L2", "get", "hit");
+
+                    self.promote_to_l1(key, &value, version);
+                    return Ok(Some(value));
+                }
+                Ok(None) => {
+                    let duration = start.elapsed().as_secs_f64();
+                    self.record_duration("L2", "get", duration);
+                    self.record_key_request(key, "L2", "get", "miss");
+                    // L2未命中，继续尝试数据库回源
+                }
+                Err(e) => {
+                    let duration = start.elapsed().as_secs_f64();
+                    self.record_duration("L2", "get", duration);
+                    self.handle_l2_failure(&e).await;
+                    // 认证失败直接返回，不能被回源结果掩盖
+                    if matches!(e, crate::error::CacheError::AuthenticationFailed(_)) {
+                        return Err(e);
+                    }
+                    // L2失败时继续尝试数据库回源
+                }
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// 从L1读取并记录指标
+    async fn lookup_l1(&self, l1: &L1Backend, key: &str) -> Result<Option<Vec<u8>>> {
+        self.record_request("L1", "get", "attempt");
+        let start = std::time::Instant::now();
+        let result = l1.get_with_metadata(key).await?.map(|(bytes, _)| bytes);
+        let duration = start.elapsed().as_secs_f64();
+        self.record_duration("L1", "get", duration);
+        let outcome = if result.is_some() { "hit" } else { "miss" };
+        self.record_key_request(key, "L1", "get", outcome);
+        Ok(result)
+    }
+
+    /// 按配置在后台将L2命中的值推广到L1
+    ///
+    /// `version` 为L2中的版本号，写入L1元数据，供基于版本的失效比较使用
+    fn promote_to_l1(&self, key: &str, value: &[u8], version: u64) {
+        if self.config.promote_on_hit {
+            if let Some(promotion_mgr) = &self.promotion_mgr {
+                // 推广任务数达到上限时丢弃本次推广，避免突发命中产生大量后台任务
+                let Some(task) = promotion_mgr.try_start_task() else {
+                    return;
+                };
+                let promo = promotion_mgr.clone();
+                let k = key.to_string();
+                let v = value.to_vec();
+                spawn_named("promotion", &self.service_name, async move {
+                    let _ = promo.promote(k, v, version).await;
+                    drop(task);
+                });
+            }
+        }
+    }
+
+    /// 从L2批量读取L1未命中的键
+    ///
+    /// 所有键通过一次批量读取获取（集群模式下每个节点一个管道），
+    /// L2降级或读取失败时各键按未命中处理，认证失败直接返回错误
+    async fn get_many_from_l2(&self, l2: &L2Client, keys: &[&str]) -> Result<Vec<Option<Vec<u8>>>> {
+        if keys.is_empty() || self.is_degraded().await {
+            return Ok(vec![None; keys.len()]);
+        }
+
+        self.record_request("L2", "get_many", "attempt");
+        let start = std::time::Instant::now();
+        let result = l2.backend().get_many_with_version(keys).await;
+        let duration = start.elapsed().as_secs_f64();
+        self.record_duration("L2", "get_many", duration);
+        match result {
+            Ok(values) => Ok(keys
+                .iter()
+                .zip(values)
+                .map(|(key, value)| match value {
+                    Some((value, version)) => {
+                        self.record_key_request(key, "L2", "get", "hit");
+                        self.promote_to_l1(key, &value, version);
+                        Some(value)
+                    }
+                    None => {
+                        self.record_key_request(key, "L2", "get", "miss");
+                        None
+                    }
+                })
+                .collect()),
+            Err(e) => {
+                self.handle_l2_failure(&e).await;
+                if matches!(e, crate::error::CacheError::AuthenticationFailed(_)) {
+                    return Err(e);
+                }
+                Ok(vec![None; keys.len()])
+            }
+        }
+    }
+
+    /// 处理L2故障
+    ///
+    /// 认证失败属于配置问题而非瞬时故障，不会使服务进入降级状态
+    #[instrument(skip(self), level = "warn")]
+    async fn handle_l2_failure(&self, error: &crate::error::CacheError) {
+        if matches!(error, crate::error::CacheError::AuthenticationFailed(_)) {
+            tracing::error!(
+                "L2 authentication failed for service {}: {}",
+                self.service_name,
+                error
+            );
+            return;
+        }
+        warn!("L2 failure detected for service: {}", self.service_name);
+
+        let mut state_guard = self.health_state.write().await;
+        let current_state = *state_guard;
+
+        match current_state {
+            HealthState::Healthy => {
+                warn!(
+                    "Service {} transitioning from Healthy to Degraded",
+                    self.service_name
+                );
+                *state_guard = HealthState::Degraded {
+                    since: std::time::Instant::now(),
+                    failure_count: 1,
+                };
+                if self.critical_metrics_enabled() {
+                    crate::metrics::GLOBAL_METRICS.set_health(&self.service_name, 0);
+                }
+            }
+            HealthState::Degraded {
+                since,
+                failure_count,
+            } => {
+                let new_failure_count = failure_count + 1;
+                warn!(
+                    "Service {} remains Degraded, failure count increased: {} -> {}",
+                    self.service_name, failure_count, new_failure_count
+                );
+                *state_guard = HealthState::Degraded {
+                    since,
+                    failure_count: new_failure_count,
+                };
+            }
+            HealthState::Recovering {
+                since: _,
+                success_count: _,
+            } => {
+                warn!(
+                    "Service {} recovery failed, transitioning back to Degraded from Recovering",
+                    self.service_name
+                );
+                *state_guard = HealthState::Degraded {
+                    since: std::time::Instant::now(),
+                    failure_count: 1,
+                };
+                if self.critical_metrics_enabled() {
+                    crate::metrics::GLOBAL_METRICS.set_health(&self.service_name, 0);
+                }
+            }
+            HealthState::WalReplaying { .. } => {
+                warn!(
+                    "Service {} is replaying WAL, ignoring failure during replay",
+                    self.service_name
+                );
+            }
+        }
+
+        info!(
+            "Service {} degradation strategy applied, current state: {:?}",
+            self.service_name, *state_guard
+        );
+    }
+
+    /// L1剩余容量占当前生效容量的比例
+    ///
+    /// 由L1条目数与当前生效容量（未启用自适应调优时即最大容量）计算，用于准入控制
+    ///
+    /// # 返回值
+    ///
+    /// 返回0.0–1.0之间的比例；没有L1或容量为0时返回0.0
+    pub async fn l1_headroom(&self) -> f64 {
+        match &self.l1 {
+            Some(l1) => Self::headroom(l1).await,
+            None => 0.0,
+        }
+    }
+
+    async fn headroom(l1: &L1Backend) -> f64 {
+        let capacity = l1.capacity();
+        if capacity == 0 {
+            return 0.0;
+        }
+        (1.0 - l1.len().await as f64 / capacity as f64).clamp(0.0, 1.0)
+    }
+
+    /// 按准入阈值写入L1
+    ///
+    /// 剩余容量低于 [`admission_threshold`](TwoLevelConfig::admission_threshold) 时跳过L1写入，
+    /// 避免在L1接近满载时用新值挤出已有的热点数据；同时移除L1中的旧值，避免读取到过时的副本
+    async fn admit_l1(
+        &self,
+        l1: &L1Backend,
+        key: &str,
+        value: Vec<u8>,
+        ttl: LayerTtl,
+    ) -> Result<()> {
+        if let Some(threshold) = self.config.admission_threshold {
+            if Self::headroom(l1).await < threshold {
+                debug!(
+                    "L1 headroom below admission threshold, skipping L1: key={}",
+                    key
+                );
+                self.record_request("L1", "set", "rejected");
+                return l1.delete(key).await;
+            }
+        }
+        match ttl {
+            LayerTtl::Secs(ttl) => l1.set_bytes(key, value, ttl).await,
+            LayerTtl::Millis(ttl) => l1.set_bytes_ms(key, value, ttl).await,
+        }
+    }
+
+    /// 以已解析的键写入L1并记录耗时
+    async fn set_l1_resolved(&self, key: &str, value: Vec<u8>, ttl: Option<u64>) -> Result<()> {
+        if let Some(l1) = &self.l1 {
+            let start = std::time::Instant::now();
+            self.admit_l1(l1, key, value, LayerTtl::Secs(ttl)).await?;
+            let duration = start.elapsed().as_secs_f64();
+            self.record_duration("L1", "set", duration);
+        }
+        Ok(())
+    }
+
+    /// 按L2优先顺序写入
+    ///
+    /// 仅在L2确认写入后才更新L1。L2不可用或写入失败时由L2客户端写入WAL，
+    /// 同时移除L1中的旧值，避免L1持有未持久化或已过时的数据。
+    /// 该模式需要L2的同步确认，因此不经过批量写入器
+    async fn set_bytes_l2_first(
+        &self,
+        l1: &L1Backend,
+        l2: &L2Client,
+        key: &str,
+        bytes: Vec<u8>,
+        ttl: LayerTtl,
+    ) -> Result<()> {
+        let state = *self.health_state.read().await;
+        let confirmed = match state {
+            HealthState::Healthy | HealthState::Recovering { .. }
+                if !self.is_l2_writes_paused() =>
+            {
+                match ttl {
+                    LayerTtl::Secs(ttl) => l2.write_bytes(key, bytes.clone(), ttl).await?,
+                    LayerTtl::Millis(ttl) => {
+                        l2.set_bytes_ms(key, bytes.clone(), ttl).await?;
+                        true
+                    }
+                }
+            }
+            _ => {
+                debug!(
+                    "L2 is unavailable or writes are paused, writing to WAL: key={}",
+                    key
+                );
+                self.wal
+                    .append(WalEntry {
+                        timestamp: std::time::SystemTime::now(),
+                        operation: Operation::Set,
+                        key: key.to_string(),
+                        value: Some(bytes.clone()),
+                        ttl: ttl.as_secs().map(|t| t as i64),
+                    })
+                    .await?;
+                false
+            }
+        };
+
+        if confirmed {
+            let start = std::time::Instant::now();
+            self.admit_l1(l1, key, bytes, ttl).await?;
+            let duration = start.elapsed().as_secs_f64();
+            self.record_duration("L1", "set", duration);
+        } else {
+            debug!("L2 write not confirmed, evicting L1 entry: key={}", key);
+            l1.delete(key).await?;
+        }
+        Ok(())
+    }
+
+    /// 订阅缓存失效通知
+    ///
+    /// 返回的流依次产出从失效频道收到的键，支持多个观察者同时订阅，且不影响L1的失效清理。
+    /// 观察者消费过慢导致积压超过缓冲容量时，积压的消息会被跳过
+    ///
+    /// # 返回值
+    ///
+    /// 返回失效键的流
+    pub fn watch_invalidations(&self) -> impl Stream<Item = String> + Send + 'static {
+        let receiver = self.invalidation_watchers.subscribe();
+        futures::stream::unfold(receiver, |mut receiver| async move {
+            loop {
+                match receiver.recv().await {
+                    Ok(key) => return Some((key, receiver)),
+                    Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                        warn!("Invalidation watcher lagged, skipped {} keys", skipped);
+                    }
+                    Err(broadcast::error::RecvError::Closed) => return None,
+                }
+            }
+        })
+    }
+
+    /// 获取当前健康状态
+    pub async fn get_health_state(&self) -> HealthState {
+        *self.health_state.read().await
+    }
+
+    /// 判断L2是否处于降级状态
+    pub async fn is_degraded(&self) -> bool {
+        matches!(
+            *self.health_state.read().await,
+            HealthState::Degraded { .. }
+        )
+    }
+
+    /// 手动设置健康状态
+    ///
+    /// 用于维护窗口或测试中强制切换降级状态，后台健康检查器仍会按实际情况继续更新
+    ///
+    /// # 参数
+    ///
+    /// * `state` - 新的健康状态
+    #[instrument(skip(self), level = "info", fields(service = %self.service_name))]
+    pub async fn set_health_state(&self, state: HealthState) {
+        *self.health_state.write().await = state;
+    }
+
+    /// 获取缓存值（字节），L2降级时允许返回已过期的L1数据
+    ///
+    /// L2降级时仅读取L1，若条目已超过其TTL但仍在保留期内也会返回，并标记为过期；
+    /// L2健康时等同于 `get_bytes`。两种情况下，以 [`set_with_soft_ttl`](Self::set_with_soft_ttl)
+    /// 写入且已超过软TTL的值同样标记为过期
+    ///
+    /// # 参数
+    ///
+    /// * `key` - 缓存键
+    ///
+    /// # 返回值
+    ///
+    /// 返回缓存值（已去除软TTL头部）及是否为过期数据，如果不存在则返回None
+    #[instrument(skip(self), level = "debug", fields(service = %self.service_name))]
+    pub async fn get_allow_stale_bytes(&self, key: &str) -> Result<Option<(Vec<u8>, bool)>> {
+        let result = if !self.is_degraded().await {
+            self.get_stored_bytes(key)
+                .await?
+                .map(|bytes| (bytes, false))
+        } else {
+            let Some(l1) = &self.l1 else {
+                return Ok(None);
+            };
+
+            let cache_key = self.resolve_key(key)?;
+            let result = l1.get_allow_stale(&cache_key).await?;
+            let outcome = match &result {
+                Some((_, true)) => "stale_hit",
+                Some((_, false)) => "hit",
+                None => "miss",
+            };
+            self.record_key_request(&cache_key, "L1", "get_allow_stale", outcome);
+            result
+        };
+
+        Ok(result.map(|(bytes, was_stale)| {
+            let (deadline, payload) = soft_ttl::decode(&bytes);
+            let past_soft_ttl = deadline.is_some_and(|deadline| self.now_millis() >= deadline);
+            (payload.to_vec(), was_stale || past_soft_ttl)
+        }))
+    }
+
+    /// 写入带软TTL的缓存值
+    ///
+    /// 硬TTL作为L1与L2中的过期时间，软过期时间作为头部随值一起保存。超过软TTL但未超过硬TTL时，
+    /// [`get_allow_stale`](Self::get_allow_stale) 仍返回该值并标记为过期，调用方可据此在后台刷新。
+    /// 类型化读取与字节读取接口都会去除该头部。
+    /// 之后以普通写入覆盖该键时，头部随旧值一起被替换，软TTL随之失效
+    ///
+    /// # 参数
+    ///
+    /// * `key` - 缓存键
+    /// * `value` - 缓存值
+    /// * `soft_ttl` - 软过期时间（秒），必须大于0且不超过硬TTL
+    /// * `hard_ttl` - 硬过期时间（秒）
+    ///
+    /// # 返回值
+    ///
+    /// 返回操作结果，TTL不合法时返回 `CacheError::InvalidInput`
+    #[instrument(skip(self, value), level = "debug", fields(service = %self.service_name))]
+    pub async fn set_with_soft_ttl<T>(
+        &self,
+        key: &str,
+        value: &T,
+        soft_ttl: u64,
+        hard_ttl: u64,
+    ) -> Result<()>
+    where
+        T: serde::Serialize + Sync,
+    {
+        if soft_ttl == 0 || soft_ttl > hard_ttl {
+            return Err(crate::error::CacheError::InvalidInput(format!(
+                "soft_ttl ({}s) must be positive and not exceed hard_ttl ({}s)",
+                soft_ttl, hard_ttl
+            )));
+        }
+
+        let bytes = soft_ttl::encode(
+            self.now_millis() + soft_ttl.saturating_mul(1000),
+            &self.serializer.serialize(value)?,
+        );
+        CacheOps::set_bytes(self, key, bytes, Some(hard_ttl)).await
+    }
+
+    /// 在指定时间内获取缓存值（字节）
+    ///
+    /// 覆盖服务级的 `command_timeout_ms`，超时范围包含L2读取与数据库回源。
+    /// 超时后若L1中仍有该键（包括保留期内已过期的条目），则返回L1中的值。
+    ///
+    /// # 参数
+    ///
+    /// * `key` - 缓存键
+    /// * `timeout` - 本次操作的超时时间
+    ///
+    /// # 返回值
+    ///
+    /// 返回缓存值，如果不存在则返回None；超时且L1无可用数据时返回 `CacheError::Timeout`
+    #[instrument(skip(self), level = "debug", fields(service = %self.service_name))]
+    pub async fn get_bytes_with_timeout(
+        &self,
+        key: &str,
+        timeout: Duration,
+    ) -> Result<Option<Vec<u8>>> {
+        if let Ok(result) = tokio::time::timeout(timeout, CacheOps::get_bytes(self, key)).await {
+            return result;
+        }
+
+        let cache_key = self.resolve_key(key)?;
+        self.record_key_request(&cache_key, "L2", "get", "timeout");
+        if let Some(l1) = &self.l1 {
+            if let Some((bytes, was_stale)) = l1.get_allow_stale(&cache_key).await? {
+                debug!(
+                    "get_bytes_with_timeout: key={} timed out, serving L1 value (stale={})",
+                    key, was_stale
+                );
+                return Ok(Some(soft_ttl::strip(bytes)));
+            }
+        }
+
+        Err(crate::error::CacheError::Timeout(format!(
+            "get '{}' exceeded {}ms",
+            key,
+            timeout.as_millis()
+        )))
+    }
+
+    /// 在指定时间内获取缓存值（反序列化）
+    ///
+    /// # 参数
+    ///
+    /// * `key` - 缓存键
+    /// * `timeout` - 本次操作的超时时间
+    ///
+    /// # 返回值
+    ///
+    /// 返回缓存值，如果不存在则返回None；超时规则同 [`get_bytes_with_timeout`](Self::get_bytes_with_timeout)
+    pub async fn get_with_timeout<T: serde::de::DeserializeOwned + Send>(
+        &self,
+        key: &str,
+        timeout: Duration,
+    ) -> Result<Option<T>> {
+        match self.get_bytes_with_timeout(key, timeout).await? {
+            Some(bytes) => Ok(Some(self.serializer.deserialize(&bytes)?)),
+            None => Ok(None),
+        }
+    }
 
-        info!(
-            "Service {} degradation strategy applied, current state: {:?}",
-            self.service_name, *state_guard
-        );
+    /// 在指定时间内设置缓存值（字节）
+    ///
+    /// 超时后L2写入被取消，但L1可能已经写入新值
+    ///
+    /// # 参数
+    ///
+    /// * `key` - 缓存键
+    /// * `value` - 字节数组值
+    /// * `ttl` - 过期时间（秒），None表示使用默认值
+    /// * `timeout` - 本次操作的超时时间
+    ///
+    /// # 返回值
+    ///
+    /// 返回操作结果，超时时返回 `CacheError::Timeout`
+    #[instrument(skip(self, value), level = "debug", fields(service = %self.service_name))]
+    pub async fn set_bytes_with_timeout(
+        &self,
+        key: &str,
+        value: Vec<u8>,
+        ttl: Option<u64>,
+        timeout: Duration,
+    ) -> Result<()> {
+        match tokio::time::timeout(timeout, CacheOps::set_bytes(self, key, value, ttl)).await {
+            Ok(result) => result,
+            Err(_) => {
+                self.record_request("L2", "set", "timeout");
+                Err(crate::error::CacheError::Timeout(format!(
+                    "set '{}' exceeded {}ms",
+                    key,
+                    timeout.as_millis()
+                )))
+            }
+        }
     }
 
-    /// 获取当前健康状态
-    pub async fn get_health_state(&self) -> HealthState {
-        *self.health_state.read().await
+    /// 获取缓存值（反序列化），L2降级时允许返回已过期的L1数据
+    ///
+    /// 过期规则同 [`get_allow_stale_bytes`](Self::get_allow_stale_bytes)
+    ///
+    /// # 参数
+    ///
+    /// * `key` - 缓存键
+    ///
+    /// # 返回值
+    ///
+    /// 返回缓存值及是否为过期数据（`was_stale`），如果不存在则返回None
+    pub async fn get_allow_stale<T: serde::de::DeserializeOwned + Send>(
+        &self,
+        key: &str,
+    ) -> Result<Option<(T, bool)>> {
+        match self.get_allow_stale_bytes(key).await? {
+            Some((bytes, was_stale)) => Ok(Some((self.serializer.deserialize(&bytes)?, was_stale))),
+            None => Ok(None),
+        }
     }
 
     /// 解决失效频道名称
@@ -348,6 +2034,90 @@ impl TwoLevelClient {
         Ok(None)
     }
 
+    /// 获取缓存中保存的值（字节），保留软TTL头部
+    ///
+    /// 依次查询L1、L2、最近写入缓冲与数据库回源
+    async fn get_stored_bytes(&self, key: &str) -> Result<Option<Vec<u8>>> {
+        // 缓存层使用规范化后的键，数据库回源仍使用调用方传入的原始键
+        let cache_key = self.resolve_key(key)?;
+
+        // Two-level mode
+        if let (Some(l1), Some(l2)) = (&self.l1, &self.l2) {
+            // 布隆过滤器检查 - 防止缓存穿透
+            if let Some(bloom_filter) = &self.bloom_filter {
+                let key_bytes = cache_key.as_bytes();
+                if !bloom_filter.contains(key_bytes).await {
+                    self.record_key_request(&cache_key, "BloomFilter", "get", "miss");
+                    return Ok(None);
+                }
+                self.record_key_request(&cache_key, "BloomFilter", "get", "hit");
+            }
+
+            // 1-3. 依次尝试L1和L2
+            if let Some(bytes) = self.get_from_layers(l1, l2, &cache_key).await? {
+                return Ok(Some(bytes));
+            }
+
+            // 读己之写：L2降级时写入只进入WAL，由最近写入缓冲兜底
+            if let Some(recent_writes) = &self.recent_writes {
+                if let Some(bytes) = recent_writes.get(cache_key.as_ref()).await {
+                    self.record_key_request(&cache_key, "RecentWrites", "get", "hit");
+                    return Ok(Some(bytes));
+                }
+            }
+
+            // 4. 数据库回源（当L1和L2都未命中时）
+            if let Some(db_fallback_mgr) = &self.db_fallback_mgr {
+                // 回源并发达到上限时等待许可，等待超时按未命中处理以保护数据库
+                let _permit = match &self.fallback_limiter {
+                    Some(limiter) => {
+                        let timeout = Duration::from_millis(self.config.fallback_permit_timeout_ms);
+                        match tokio::time::timeout(timeout, limiter.clone().acquire_owned()).await {
+                            Ok(Ok(permit)) => Some(permit),
+                            _ => {
+                                self.record_request("DB", "fallback", "throttled");
+                                warn!("Database fallback throttled for key: {}", key);
+                                return Ok(None);
+                            }
+                        }
+                    }
+                    None => None,
+                };
+
+                self.record_request("DB", "fallback", "attempt");
+                let start = std::time::Instant::now();
+
+                match db_fallback_mgr.fallback_load(key).await {
+                    Ok(Some(data)) => {
+                        let duration = start.elapsed().as_secs_f64();
+                        self.record_duration("DB", "fallback", duration);
+                        self.record_key_request(key, "DB", "fallback", "hit");
+
+                        // 将数据回写到L1和L2缓存
+                        if let Err(e) = self.set_bytes(key, data.clone(), None).await {
+                            warn!("Failed to write fallback data to cache: {}", e);
+                        }
+
+                        return Ok(Some(data));
+                    }
+                    Ok(None) => {
+                        let duration = start.elapsed().as_secs_f64();
+                        self.record_duration("DB", "fallback", duration);
+                        self.record_key_request(key, "DB", "fallback", "miss");
+                        debug!("Database fallback miss for key: {}", key);
+                    }
+                    Err(e) => {
+                        let duration = start.elapsed().as_secs_f64();
+                        self.record_duration("DB", "fallback", duration);
+                        warn!("Database fallback failed for key {}: {}", key, e);
+                    }
+                }
+            }
+        }
+
+        Ok(None)
+    }
+
     #[allow(dead_code)]
     #[instrument(skip(self), level = "debug")]
     async fn get_from_l2(&self, key: &str) -> Result<Option<Vec<u8>>> {
@@ -370,63 +2140,86 @@ impl TwoLevelClient {
     /// * `keys` - 需要预热的键列表
     /// * `loader` - 数据加载函数，接收键列表，返回 (key, value) 对列表
     /// * `ttl` - 缓存过期时间
+    ///
+    /// # 返回值
+    ///
+    /// 返回预热结果，`failures` 中列出加载函数未返回或写入失败的键；加载函数本身出错时返回错误
     #[instrument(skip(self, loader), level = "info", fields(key_count = keys.len()))]
     pub async fn warmup<T, F, Fut>(
         &self,
         keys: Vec<String>,
         loader: F,
         ttl: Option<u64>,
-    ) -> Result<()>
+    ) -> Result<WarmupResult>
     where
         T: serde::Serialize + Send + Sync,
         F: Fn(Vec<String>) -> Fut + Send + Sync,
         Fut: std::future::Future<Output = Result<Vec<(String, T)>>> + Send,
     {
+        let mut result = WarmupResult::empty();
         if keys.is_empty() {
-            return Ok(());
+            return Ok(result);
         }
 
         // 1. 调用加载函数获取数据
-        let data = loader(keys).await?;
+        let mut missing: HashSet<String> = keys.iter().cloned().collect();
+        let data = loader(keys.clone()).await?;
 
         // 2. 批量写入缓存
         // 由于我们需要序列化，且 set 接口是单个的，我们循环调用 set
         // 对于大量数据，可以使用 pipeline 优化，但 CacheOps 没有 batch_set 接口
         // 如果开启了 batch_writer，set 会自动批处理
+        // 单个键写入失败不中断预热，记录在结果中
         for (key, value) in data {
-            // 使用 set_bytes 避免重复序列化逻辑（虽然 set 也会序列化）
-            // 这里我们直接用 self.set
-            self.set(&key, &value, ttl).await?;
+            missing.remove(&key);
+            match self.set(&key, &value, ttl).await {
+                Ok(()) => result.loaded = result.loaded.saturating_add(1),
+                Err(e) => {
+                    warn!("Warmup: failed to set key {}: {}", key, e);
+                    result.record_failure(key, e.to_string());
+                }
+            }
         }
 
-        Ok(())
+        // 按请求顺序记录加载函数未返回的键
+        for key in keys.into_iter().filter(|key| missing.contains(key)) {
+            result.record_failure(key, "not returned by loader".to_string());
+        }
+
+        Ok(result)
     }
 
     /// 异步执行预热
     ///
     /// 使用配置的预热管理器执行预热
-    pub async fn run_warmup(&self) -> Result<()> {
+    ///
+    /// # 返回值
+    ///
+    /// 返回预热结果，未配置预热时返回跳过的结果
+    pub async fn run_warmup(&self) -> Result<WarmupResult> {
         if let Some(warmup_mgr) = &self.warmup_mgr {
             let client: Arc<Self> = Arc::new(self.clone());
             let result = warmup_mgr
                 .run_warmup(move |keys: Vec<String>| {
                     let client = Arc::clone(&client);
                     Box::pin(async move {
-                        let mut result = HashMap::new();
+                        let mut batch = WarmupBatch::default();
                         for key in keys {
-                            match client.get_bytes(&key).await {
+                            match client.get_stored_bytes(&key).await {
                                 Ok(Some(value)) => {
-                                    result.insert(key, value);
+                                    batch.loaded.insert(key, value);
                                 }
                                 Ok(None) => {
                                     debug!("Warmup: key not found in L2: {}", key);
+                                    batch.failures.insert(key, "not found".to_string());
                                 }
                                 Err(e) => {
                                     warn!("Warmup: failed to get key {} from L2: {}", key, e);
+                                    batch.failures.insert(key, e.to_string());
                                 }
                             }
                         }
-                        Ok(result)
+                        Ok(batch)
                     })
                 })
                 .await?;
@@ -441,8 +2234,9 @@ impl TwoLevelClient {
                     result.loaded, result.failed
                 );
             }
+            return Ok(result);
         }
-        Ok(())
+        Ok(WarmupResult::skipped())
     }
 
     /// 获取预热管理器
@@ -450,6 +2244,96 @@ impl TwoLevelClient {
         self.warmup_mgr.as_ref()
     }
 
+    /// 获取最近一次WAL重放的结果
+    ///
+    /// L2从降级中恢复时由健康检查器触发重放，尚未重放过时返回None
+    ///
+    /// # 返回值
+    ///
+    /// 返回重放、失败与跳过的条目数
+    pub fn last_wal_replay(&self) -> Option<WalReplayReport> {
+        self.wal.last_replay()
+    }
+
+    /// 立即将WAL中的条目重放到L2
+    ///
+    /// 不等待健康检查器，在L2恢复后手动触发重放。与自动重放共用WAL的重放锁，
+    /// 并发调用不会重复重放同一条目
+    ///
+    /// # 返回值
+    ///
+    /// 返回重放结果；L2仍处于降级状态时返回 `CacheError::L2Error`，不执行重放
+    #[instrument(skip(self), level = "info", fields(service = %self.service_name))]
+    pub async fn replay_wal_now(&self) -> Result<WalReplayReport> {
+        let l2 = self.l2.as_ref().ok_or_else(|| {
+            crate::error::CacheError::NotSupported("WAL replay requires L2".to_string())
+        })?;
+        if self.is_degraded().await {
+            return Err(crate::error::CacheError::L2Error(
+                "L2 is degraded, WAL replay skipped".to_string(),
+            ));
+        }
+        // 先落盘缓冲中的条目，使其参与本次重放
+        self.wal.flush().await?;
+        self.wal.replay_all(l2.backend()).await
+    }
+
+    /// 暂停向L2写入，用于Redis故障转移等维护窗口
+    ///
+    /// 暂停期间写入与删除在更新L1后转入WAL，与L2降级时相同，但不改变健康状态，
+    /// 读取照常访问L1和L2；健康检查器也推迟WAL重放，直到恢复写入。
+    /// 暂停状态记录到 `l2_writes_paused` 指标。已进入批量写入器缓冲区的写入不受影响
+    #[instrument(skip(self), level = "info", fields(service = %self.service_name))]
+    pub fn pause_l2_writes(&self) {
+        self.l2_writes_paused.store(true, Ordering::SeqCst);
+        if self.critical_metrics_enabled() {
+            GLOBAL_METRICS.set_l2_writes_paused(&self.service_name, true);
+        }
+        tracing::info!("L2 writes paused, service={}", self.service_name);
+    }
+
+    /// 恢复向L2写入，并立即重放暂停期间写入WAL的条目
+    ///
+    /// # 返回值
+    ///
+    /// 返回重放结果；L2处于降级状态时写入仍会恢复，但返回 `CacheError::L2Error`，
+    /// WAL中的条目由健康检查器在L2恢复后重放
+    #[instrument(skip(self), level = "info", fields(service = %self.service_name))]
+    pub async fn resume_l2_writes(&self) -> Result<WalReplayReport> {
+        self.l2_writes_paused.store(false, Ordering::SeqCst);
+        if self.critical_metrics_enabled() {
+            GLOBAL_METRICS.set_l2_writes_paused(&self.service_name, false);
+        }
+        tracing::info!("L2 writes resumed, service={}", self.service_name);
+        self.replay_wal_now().await
+    }
+
+    /// 是否已暂停向L2写入
+    pub fn is_l2_writes_paused(&self) -> bool {
+        self.l2_writes_paused.load(Ordering::SeqCst)
+    }
+
+    /// 获取仍在运行的后台任务数量
+    ///
+    /// 统计健康检查器、批处理写入器、L1指标采集与失效订阅任务，
+    /// 克隆出的客户端不持有后台任务，始终返回0
+    ///
+    /// # 返回值
+    ///
+    /// 返回尚未结束的后台任务数量
+    pub fn running_background_tasks(&self) -> usize {
+        [
+            &self.health_checker_handle,
+            &self.batch_writer_handle,
+            &self.l1_metrics_handle,
+            &self.invalidation_subscriber_handle,
+        ]
+        .into_iter()
+        .flatten()
+        .filter(|handle| !handle.is_finished())
+        .count()
+    }
+
     /// 优雅关闭客户端
     ///
     /// 停止所有后台任务，释放资源
@@ -463,12 +2347,33 @@ impl TwoLevelClient {
             handle.abort();
         }
 
+        // 刷新批处理写入器中尚未写入L2的条目
+        if let Some(batch_writer) = &self.batch_writer {
+            info!("刷新批处理写入器");
+            match tokio::time::timeout(SHUTDOWN_FLUSH_TIMEOUT, batch_writer.flush()).await {
+                Ok(Ok(())) => {}
+                Ok(Err(e)) => warn!("关闭时刷新批处理写入器失败: {}", e),
+                Err(_) => warn!("关闭时刷新批处理写入器超时 ({:?})", SHUTDOWN_FLUSH_TIMEOUT),
+            }
+            batch_writer.stop().await;
+        }
+
         // 停止批处理写入器
         if let Some(handle) = &self.batch_writer_handle {
             info!("停止批处理写入器");
             handle.abort();
         }
 
+        // 停止L1指标采集
+        if let Some(handle) = &self.l1_metrics_handle {
+            handle.abort();
+        }
+
+        // 停止失效订阅，避免连接关闭后继续重连
+        if let Some(handle) = &self.invalidation_subscriber_handle {
+            handle.abort();
+        }
+
         // 关闭L1缓存连接
         if let Some(_l1) = &self.l1 {
             info!("关闭L1缓存");
@@ -508,10 +2413,8 @@ impl CacheOps for TwoLevelClient {
 
     #[instrument(skip(self), level = "debug", fields(service = %self.service_name))]
     async fn lock(&self, key: &str, value: &str, ttl: u64) -> Result<bool> {
-        validate_cache_key(key)?;
-
-        let max_key_length = self.config.max_key_length.unwrap_or(256);
-        validate_key_length(key, max_key_length)?;
+        let key = self.resolve_key(key)?;
+        let key = key.as_ref();
 
         if value.is_empty() {
             return Err(crate::error::CacheError::InvalidInput(
@@ -541,10 +2444,8 @@ impl CacheOps for TwoLevelClient {
 
     #[instrument(skip(self), level = "debug", fields(service = %self.service_name))]
     async fn unlock(&self, key: &str, value: &str) -> Result<bool> {
-        validate_cache_key(key)?;
-
-        let max_key_length = self.config.max_key_length.unwrap_or(256);
-        validate_key_length(key, max_key_length)?;
+        let key = self.resolve_key(key)?;
+        let key = key.as_ref();
 
         if value.is_empty() {
             return Err(crate::error::CacheError::InvalidInput(
@@ -560,248 +2461,112 @@ impl CacheOps for TwoLevelClient {
     }
 
     /// 获取缓存值（字节）
+    ///
+    /// 以 [`set_with_soft_ttl`](TwoLevelClient::set_with_soft_ttl) 写入的值会去除软TTL头部
     #[instrument(skip(self), level = "debug", fields(service = %self.service_name))]
     async fn get_bytes(&self, key: &str) -> Result<Option<Vec<u8>>> {
-        validate_cache_key(key)?;
-
-        let max_key_length = self.config.max_key_length.unwrap_or(256);
-        validate_key_length(key, max_key_length)?;
-
-        // Two-level mode
-        if let (Some(l1), Some(l2)) = (&self.l1, &self.l2) {
-            // 布隆过滤器检查 - 防止缓存穿透
-            if let Some(bloom_filter) = &self.bloom_filter {
-                let key_bytes = key.as_bytes();
-                if !bloom_filter.contains(key_bytes) {
-                    GLOBAL_METRICS.record_request(&self.service_name, "BloomFilter", "get", "miss");
-                    return Ok(None);
-                }
-                GLOBAL_METRICS.record_request(&self.service_name, "BloomFilter", "get", "hit");
-            }
-
-            GLOBAL_METRICS.record_request(&self.service_name, "L1", "get", "attempt");
-
-            let start = std::time::Instant::now();
-            // 1. 尝试L1
-            if let Some((bytes, _)) = l1.get_with_metadata(key).await? {
-                let duration = start.elapsed().as_secs_f64();
-                GLOBAL_METRICS.record_duration(&self.service_name, "L1", "get", duration);
-                GLOBAL_METRICS.record_request(&self.service_name, "L1", "get", "hit");
-                return Ok(Some(bytes));
-            }
-            let duration = start.elapsed().as_secs_f64();
-            GLOBAL_METRICS.record_duration(&self.service_name, "L1", "get", duration);
-            GLOBAL_METRICS.record_request(&self.service_name, "L1", "get", "miss");
-
-            // 2. 检查健康状态 - 如果L2降级，仍然尝试L1，但跳过L2
-            let state = self.health_state.read().await;
-            let is_degraded = matches!(*state, HealthState::Degraded { .. });
-            drop(state);
-
-            // 3. 尝试L2（仅当L2健康时）
-            if !is_degraded {
-                GLOBAL_METRICS.record_request(&self.service_name, "L2", "get", "attempt");
-                let start = std::time::Instant::now();
-                match l2.get_bytes(key).await {
-                    Ok(Some(value)) => {
-                        let duration = start.elapsed().as_secs_f64();
-                        GLOBAL_METRICS.record_duration(&self.service_name, "L2", "get", duration);
-                        GLOBAL_METRICS.record_request(&self.service_name, "L2", "get", "hit");
-
-                        // 注意：L2Client的get_bytes不返回版本信息，所以promotion逻辑需要调整
-                        // 如果需要版本信息，我们需要在L2Client中暴露get_with_version方法
-                        if self.config.promote_on_hit {
-                            if let Some(promotion_mgr) = &self.promotion_mgr {
-                                let promo = promotion_mgr.clone();
-                                let k = key.to_string();
-                                let v = value.clone();
-                                // 使用版本0作为默认值，因为get_bytes不返回版本
-                                tokio::spawn(async move {
-                                    let _ = promo.promote(k, v, 0).await;
-                                });
-                            }
-                        }
-
-                        return Ok(Some(value));
-                    }
-                    Ok(None) => {
-                        let duration = start.elapsed().as_secs_f64();
-                        GLOBAL_METRICS.record_duration(&self.service_name, "L2", "get", duration);
-                        GLOBAL_METRICS.record_request(&self.service_name, "L2", "get", "miss");
-                        // L2未命中，继续尝试数据库回源
-                    }
-                    Err(_e) => {
-                        let duration = start.elapsed().as_secs_f64();
-                        GLOBAL_METRICS.record_duration(&self.service_name, "L2", "get", duration);
-                        self.handle_l2_failure().await;
-                        // L2失败时继续尝试数据库回源
-                    }
-                }
-            }
-
-            // 4. 数据库回源（当L1和L2都未命中时）
-            if let Some(db_fallback_mgr) = &self.db_fallback_mgr {
-                GLOBAL_METRICS.record_request(&self.service_name, "DB", "fallback", "attempt");
-                let start = std::time::Instant::now();
-
-                match db_fallback_mgr.fallback_load(key).await {
-                    Ok(Some(data)) => {
-                        let duration = start.elapsed().as_secs_f64();
-                        GLOBAL_METRICS.record_duration(
-                            &self.service_name,
-                            "DB",
-                            "fallback",
-                            duration,
-                        );
-                        GLOBAL_METRICS.record_request(&self.service_name, "DB", "fallback", "hit");
-
-                        // 将数据回写到L1和L2缓存
-                        if let Err(e) = self.set_bytes(key, data.clone(), None).await {
-                            warn!("Failed to write fallback data to cache: {}", e);
-                        }
-
-                        return Ok(Some(data));
-                    }
-                    Ok(None) => {
-                        let duration = start.elapsed().as_secs_f64();
-                        GLOBAL_METRICS.record_duration(
-                            &self.service_name,
-                            "DB",
-                            "fallback",
-                            duration,
-                        );
-                        GLOBAL_METRICS.record_request(&self.service_name, "DB", "fallback", "miss");
-                        debug!("Database fallback miss for key: {}", key);
-                    }
-                    Err(e) => {
-                        let duration = start.elapsed().as_secs_f64();
-                        GLOBAL_METRICS.record_duration(
-                            &self.service_name,
-                            "DB",
-                            "fallback",
-                            duration,
-                        );
-                        warn!("Database fallback failed for key {}: {}", key, e);
-                    }
-                }
-            }
-        }
-
-        Ok(None)
+        Ok(self.get_stored_bytes(key).await?.map(soft_ttl::strip))
     }
 
     /// 设置缓存值（字节）
+    ///
+    /// 写入任何后端之前先按服务配置的 `max_key_length` 与 `max_value_size` 校验，
+    /// 超出限制时返回 `CacheError::InvalidInput`。L2恢复期间TTL按
+    /// [`recovering_ttl_factor`](TwoLevelConfig::recovering_ttl_factor) 放大
     #[instrument(skip(self, value), level = "debug", fields(service = %self.service_name))]
     async fn set_bytes(&self, key: &str, value: Vec<u8>, ttl: Option<u64>) -> Result<()> {
-        validate_cache_key(key)?;
-
-        let max_key_length = self.config.max_key_length.unwrap_or(256);
-        validate_key_length(key, max_key_length)?;
+        let key = self.resolve_key(key)?;
+        let key = key.as_ref();
 
         let max_value_size = self.config.max_value_size.unwrap_or(10 * 1024 * 1024);
         validate_value_size(&value, max_value_size)?;
 
-        let bytes = value;
-
         // 自动将键添加到布隆过滤器
-        if let Some(bloom_filter) = &self.bloom_filter {
-            let key_bytes = key.as_bytes().to_vec();
-            bloom_filter.add(&key_bytes).await;
-            GLOBAL_METRICS.record_request(&self.service_name, "BloomFilter", "set", "add");
-        }
+        self.add_to_bloom_filter(key).await;
 
-        // Two-level mode
-        if let (Some(l1), Some(l2)) = (&self.l1, &self.l2) {
-            // 1. 写入L1
-            let start = std::time::Instant::now();
-            debug!("Writing to L1: key={}", key);
-            l1.set_bytes(key, bytes.clone(), ttl).await?;
-            let duration = start.elapsed().as_secs_f64();
-            GLOBAL_METRICS.record_duration(&self.service_name, "L1", "set", duration);
-            debug!("L1 write successful: key={}", key);
+        self.write_layers(key, value, LayerTtl::Secs(ttl)).await
+    }
 
-            // 2. 检查L2健康状态
-            let state = self.health_state.read().await;
-            let current_state = *state;
-            debug!("Current health state: {:?}", current_state);
-            match current_state {
-                HealthState::Healthy | HealthState::Recovering { .. } => {
-                    drop(state);
-                    if self.config.enable_batch_write {
-                        if let Some(batch_writer) = &self.batch_writer {
-                            batch_writer
-                                .enqueue_operation(
-                                    BatchOperation::Set {
-                                        key: key.to_string(),
-                                        value: bytes,
-                                        ttl,
-                                    },
-                                    100, // default priority
-                                )
-                                .await?;
-                        }
-                    } else {
-                        // 使用L2客户端的set_bytes方法，它会处理健康状态检查
-                        l2.set_bytes(key, bytes, ttl).await?;
-                    }
-                }
-                HealthState::Degraded { .. } => {
-                    drop(state);
-                    debug!("L2 is degraded, writing to WAL: key={}", key);
-                    self.wal
-                        .append(WalEntry {
-                            timestamp: std::time::SystemTime::now(),
-                            operation: Operation::Set,
-                            key: key.to_string(),
-                            value: Some(bytes),
-                            ttl: ttl.map(|t| t as i64),
-                        })
-                        .await?;
-                    debug!("WAL write successful: key={}", key);
-                }
-                HealthState::WalReplaying { .. } => {
-                    drop(state);
-                    debug!("L2 is replaying WAL, writing to WAL: key={}", key);
-                    self.wal
-                        .append(WalEntry {
-                            timestamp: std::time::SystemTime::now(),
-                            operation: Operation::Set,
-                            key: key.to_string(),
-                            value: Some(bytes),
-                            ttl: ttl.map(|t| t as i64),
-                        })
-                        .await?;
-                    debug!("WAL write successful: key={}", key);
+    /// 以二进制原始键获取缓存值（字节）
+    ///
+    /// 原始键只校验长度，先查L1再查L2，L2命中时回写L1；不经过布隆过滤器与数据库回源
+    #[instrument(skip(self), level = "debug", fields(service = %self.service_name))]
+    async fn get_bytes_by_raw(&self, key: &[u8]) -> Result<Option<Vec<u8>>> {
+        validate_key_length(key, self.config.max_key_length.unwrap_or(256))?;
+
+        if let Some(l1) = &self.l1 {
+            self.record_request("L1", "get", "attempt");
+            let start = std::time::Instant::now();
+            let result = l1.get_bytes_by_raw(key).await?;
+            self.record_duration("L1", "get", start.elapsed().as_secs_f64());
+            if result.is_some() {
+                self.record_request("L1", "get", "hit");
+                return Ok(result.map(soft_ttl::strip));
+            }
+            self.record_request("L1", "get", "miss");
+        }
+
+        if self.is_degraded().await {
+            return Ok(None);
+        }
+
+        if let Some(l2) = &self.l2 {
+            if let Some(bytes) = l2.get_bytes_by_raw(key).await? {
+                if let Some(l1) = &self.l1 {
+                    l1.set_bytes_by_raw(key, bytes.clone(), None).await?;
                 }
+                return Ok(Some(soft_ttl::strip(bytes)));
             }
         }
 
-        Ok(())
+        Ok(None)
     }
 
-    /// 设置 L1 缓存值（字节）
+    /// 以二进制原始键设置缓存值（字节）
+    ///
+    /// 先同步写入L2再写入L1。批量写入、WAL与失效广播只支持字符串键，原始键的写入会绕过它们，
+    /// 因此L2不可用时直接返回错误，其他实例的L1副本只能等待过期
     #[instrument(skip(self, value), level = "debug", fields(service = %self.service_name))]
-    async fn set_l1_bytes(&self, key: &str, value: Vec<u8>, ttl: Option<u64>) -> Result<()> {
+    async fn set_bytes_by_raw(&self, key: &[u8], value: Vec<u8>, ttl: Option<u64>) -> Result<()> {
+        validate_key_length(key, self.config.max_key_length.unwrap_or(256))?;
+        let max_value_size = self.config.max_value_size.unwrap_or(10 * 1024 * 1024);
+        validate_value_size(&value, max_value_size)?;
+
+        if let Some(l2) = &self.l2 {
+            l2.set_bytes_by_raw(key, value.clone(), ttl).await?;
+        }
         if let Some(l1) = &self.l1 {
             let start = std::time::Instant::now();
-            l1.set_bytes(key, value, ttl).await?;
-            let duration = start.elapsed().as_secs_f64();
-            GLOBAL_METRICS.record_duration(&self.service_name, "L1", "set", duration);
+            l1.set_bytes_by_raw(key, value, ttl).await?;
+            self.record_duration("L1", "set", start.elapsed().as_secs_f64());
         }
         Ok(())
     }
 
+    /// 设置 L1 缓存值（字节）
+    #[instrument(skip(self, value), level = "debug", fields(service = %self.service_name))]
+    async fn set_l1_bytes(&self, key: &str, value: Vec<u8>, ttl: Option<u64>) -> Result<()> {
+        let key = self.resolve_key(key)?;
+        self.set_l1_resolved(&key, value, ttl).await
+    }
+
     /// 设置 L2 缓存值（字节）
     #[instrument(skip(self, value), level = "debug", fields(service = %self.service_name))]
     async fn set_l2_bytes(&self, key: &str, value: Vec<u8>, ttl: Option<u64>) -> Result<()> {
+        let key = self.resolve_key(key)?;
         if let Some(l2) = &self.l2 {
             // 检查L2健康状态
+            if self.is_l2_writes_paused() {
+                return Err(crate::error::CacheError::L2Error(
+                    "L2 writes are paused".to_string(),
+                ));
+            }
             let state = self.health_state.read().await;
             match *state {
                 HealthState::Healthy | HealthState::Recovering { .. } => {
                     drop(state);
                     // 使用L2客户端的set_bytes方法，它会处理健康状态检查
-                    l2.set_bytes(key, value, ttl).await?;
+                    l2.set_bytes(&key, value, ttl).await?;
                 }
                 HealthState::Degraded { .. } => {
                     // 降级时不支持直接写入 L2，或者我们可以选择写入 WAL？
@@ -824,12 +2589,13 @@ impl CacheOps for TwoLevelClient {
     /// 获取 L1 缓存值（字节）
     #[instrument(skip(self), level = "debug", fields(service = %self.service_name))]
     async fn get_l1_bytes(&self, key: &str) -> Result<Option<Vec<u8>>> {
+        let key = self.resolve_key(key)?;
         if let Some(l1) = &self.l1 {
             let start = std::time::Instant::now();
-            let result = l1.get_bytes(key).await?;
+            let result = l1.get_bytes(&key).await?;
             let duration = start.elapsed().as_secs_f64();
-            GLOBAL_METRICS.record_duration(&self.service_name, "L1", "get", duration);
-            Ok(result)
+            self.record_duration("L1", "get", duration);
+            Ok(result.map(soft_ttl::strip))
         } else {
             Ok(None)
         }
@@ -838,12 +2604,13 @@ impl CacheOps for TwoLevelClient {
     /// 获取 L2 缓存值（字节）
     #[instrument(skip(self), level = "debug", fields(service = %self.service_name))]
     async fn get_l2_bytes(&self, key: &str) -> Result<Option<Vec<u8>>> {
+        let key = self.resolve_key(key)?;
         if let Some(l2) = &self.l2 {
             let start = std::time::Instant::now();
-            let result = l2.get_bytes(key).await?;
+            let result = l2.get_bytes(&key).await?;
             let duration = start.elapsed().as_secs_f64();
-            GLOBAL_METRICS.record_duration(&self.service_name, "L2", "get", duration);
-            Ok(result)
+            self.record_duration("L2", "get", duration);
+            Ok(result.map(soft_ttl::strip))
         } else {
             Ok(None)
         }
@@ -860,20 +2627,26 @@ impl CacheOps for TwoLevelClient {
     /// 返回操作结果
     #[instrument(skip(self), level = "debug", fields(service = %self.service_name))]
     async fn delete(&self, key: &str) -> Result<()> {
-        validate_cache_key(key)?;
+        let key = self.resolve_key(key)?;
+        let key = key.as_ref();
 
-        let max_key_length = self.config.max_key_length.unwrap_or(256);
-        validate_key_length(key, max_key_length)?;
+        if let Some(recent_writes) = &self.recent_writes {
+            recent_writes.invalidate(key).await;
+        }
 
         // Two-level mode
         if let (Some(l1), Some(l2)) = (&self.l1, &self.l2) {
-            // 1. 删除L1
+            // 1. 删除L1，并丢弃批量写入器中尚未写出的旧值，避免删除后被重新写回L2
             l1.delete(key).await?;
+            if let Some(batch_writer) = &self.batch_writer {
+                batch_writer.discard(key);
+            }
 
-            // 2. 检查L2健康状态
+            // 2. 检查L2健康状态，暂停写入时与降级一样转入WAL
             let state = self.health_state.read().await;
+            let paused = self.is_l2_writes_paused();
             match *state {
-                HealthState::Healthy | HealthState::Recovering { .. } => {
+                HealthState::Healthy | HealthState::Recovering { .. } if !paused => {
                     drop(state);
                     match l2.delete(key).await {
                         Ok(_) => {
@@ -882,12 +2655,14 @@ impl CacheOps for TwoLevelClient {
                             }
                         }
                         Err(e) => {
-                            self.handle_l2_failure().await;
+                            self.handle_l2_failure(&e).await;
                             return Err(e);
                         }
                     }
                 }
-                HealthState::Degraded { .. } => {
+                HealthState::Healthy
+                | HealthState::Recovering { .. }
+                | HealthState::Degraded { .. } => {
                     drop(state);
                     self.wal
                         .append(WalEntry {
@@ -912,6 +2687,225 @@ impl CacheOps for TwoLevelClient {
             }
         }
 
+        self.mirror_delete(key).await;
+        Ok(())
+    }
+
+    /// 刷新缓存项的过期时间
+    ///
+    /// 以L2为准：L2中存在该键时同时刷新L1中的副本；L2降级期间只刷新L1
+    ///
+    /// # 参数
+    ///
+    /// * `key` - 缓存键
+    /// * `ttl` - 新的过期时间（秒）
+    ///
+    /// # 返回值
+    ///
+    /// 键存在并已刷新时返回true，键不存在时返回false
+    #[instrument(skip(self), level = "debug", fields(service = %self.service_name))]
+    async fn touch(&self, key: &str, ttl: u64) -> Result<bool> {
+        let key = self.resolve_key(key)?;
+        let key = key.as_ref();
+
+        let l2 = match &self.l2 {
+            Some(l2) => l2,
+            None => match &self.l1 {
+                Some(l1) => return l1.touch(key, ttl).await,
+                None => return Ok(false),
+            },
+        };
+
+        let state = *self.health_state.read().await;
+        match state {
+            HealthState::Healthy | HealthState::Recovering { .. } => {
+                match l2.backend().touch(key, ttl).await {
+                    Ok(touched) => {
+                        if let (true, Some(l1)) = (touched, &self.l1) {
+                            l1.touch(key, ttl).await?;
+                        }
+                        Ok(touched)
+                    }
+                    Err(e) => {
+                        self.handle_l2_failure(&e).await;
+                        Err(e)
+                    }
+                }
+            }
+            HealthState::Degraded { .. } | HealthState::WalReplaying { .. } => match &self.l1 {
+                Some(l1) => l1.touch(key, ttl).await,
+                None => Err(crate::error::CacheError::L2Error(
+                    "L2 is unavailable".to_string(),
+                )),
+            },
+        }
+    }
+
+    /// 批量检查缓存项是否存在
+    ///
+    /// 布隆过滤器判定不存在的键直接返回false，L1命中的键返回true，
+    /// 其余键通过一次 `EXISTS` 管道检查L2（集群模式下每个节点一个管道）。
+    /// 只检查缓存层，不触发数据库回源；L2降级或检查失败时这些键按不存在处理
+    ///
+    /// # 参数
+    ///
+    /// * `keys` - 要检查的缓存键
+    ///
+    /// # 返回值
+    ///
+    /// 按输入顺序返回各键是否存在
+    #[instrument(skip(self, keys), level = "debug", fields(service = %self.service_name, key_count = keys.len()))]
+    async fn exists_many(&self, keys: &[&str]) -> Result<Vec<bool>> {
+        let cache_keys = keys
+            .iter()
+            .map(|key| self.resolve_key(key))
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut exists = vec![false; keys.len()];
+        let (Some(l1), Some(l2)) = (&self.l1, &self.l2) else {
+            return Ok(exists);
+        };
+
+        let mut l2_indices = Vec::new();
+        for (index, cache_key) in cache_keys.iter().enumerate() {
+            if let Some(bloom_filter) = &self.bloom_filter {
+                if !bloom_filter.contains(cache_key.as_bytes()).await {
+                    self.record_key_request(cache_key, "BloomFilter", "get", "miss");
+                    continue;
+                }
+                self.record_key_request(cache_key, "BloomFilter", "get", "hit");
+            }
+
+            if self.lookup_l1(l1, cache_key).await?.is_some() {
+                exists[index] = true;
+                continue;
+            }
+            // 读己之写：L2降级时写入只进入WAL，由最近写入缓冲兜底
+            if let Some(recent_writes) = &self.recent_writes {
+                if recent_writes.get(cache_key.as_ref()).await.is_some() {
+                    exists[index] = true;
+                    continue;
+                }
+            }
+            l2_indices.push(index);
+        }
+
+        if l2_indices.is_empty() || self.is_degraded().await {
+            return Ok(exists);
+        }
+
+        let l2_keys: Vec<&str> = l2_indices
+            .iter()
+            .map(|index| cache_keys[*index].as_ref())
+            .collect();
+        self.record_request("L2", "exists_many", "attempt");
+        let start = std::time::Instant::now();
+        let result = l2.backend().exists_many(&l2_keys).await;
+        let duration = start.elapsed().as_secs_f64();
+        self.record_duration("L2", "exists_many", duration);
+        match result {
+            Ok(l2_exists) => {
+                for (index, found) in l2_indices.into_iter().zip(l2_exists) {
+                    exists[index] = found;
+                }
+            }
+            Err(e) => {
+                self.handle_l2_failure(&e).await;
+                if matches!(e, crate::error::CacheError::AuthenticationFailed(_)) {
+                    return Err(e);
+                }
+            }
+        }
+        Ok(exists)
+    }
+
+    /// 批量删除缓存项
+    ///
+    /// L1逐个删除，L2通过一次管道批量删除，成功后只发布一条包含所有键的失效消息；
+    /// 降级期间每个键各写入一条WAL删除记录
+    ///
+    /// # 参数
+    ///
+    /// * `keys` - 要删除的缓存键
+    ///
+    /// # 返回值
+    ///
+    /// 返回操作结果
+    #[instrument(skip(self, keys), level = "debug", fields(service = %self.service_name, key_count = keys.len()))]
+    async fn delete_many(&self, keys: &[&str]) -> Result<()> {
+        if keys.is_empty() {
+            return Ok(());
+        }
+        let keys = keys
+            .iter()
+            .map(|key| self.resolve_key(key).map(|key| key.into_owned()))
+            .collect::<Result<Vec<String>>>()?;
+
+        if let Some(recent_writes) = &self.recent_writes {
+            for key in &keys {
+                recent_writes.invalidate(key).await;
+            }
+        }
+
+        if let (Some(l1), Some(l2)) = (&self.l1, &self.l2) {
+            // 丢弃批量写入器中尚未写出的旧值，避免删除后被重新写回L2
+            for key in &keys {
+                l1.delete(key).await?;
+                if let Some(batch_writer) = &self.batch_writer {
+                    batch_writer.discard(key);
+                }
+            }
+
+            let state = self.health_state.read().await;
+            let paused = self.is_l2_writes_paused();
+            match *state {
+                HealthState::Healthy | HealthState::Recovering { .. } if !paused => {
+                    drop(state);
+                    match l2.backend().pipeline_del_batch(keys.clone()).await {
+                        Ok(_) => {
+                            if let Some(publisher) = &self.publisher {
+                                let keys: Vec<&str> = keys.iter().map(String::as_str).collect();
+                                let _ = publisher.publish_many(&keys).await;
+                            }
+                        }
+                        Err(e) => {
+                            self.handle_l2_failure(&e).await;
+                            return Err(e);
+                        }
+                    }
+                }
+                HealthState::Healthy
+                | HealthState::Recovering { .. }
+                | HealthState::Degraded { .. } => {
+                    drop(state);
+                    for key in &keys {
+                        self.wal
+                            .append(WalEntry {
+                                timestamp: std::time::SystemTime::now(),
+                                operation: Operation::Delete,
+                                key: key.clone(),
+                                value: None,
+                                ttl: None,
+                            })
+                            .await?;
+                    }
+                }
+                HealthState::WalReplaying { .. } => {
+                    drop(state);
+                    tracing::warn!(
+                        "Cannot delete during WAL replay, service={}",
+                        self.service_name
+                    );
+                    return Err(crate::error::CacheError::L2Error(
+                        "L2 is replaying WAL".to_string(),
+                    ));
+                }
+            }
+        }
+
+        for key in &keys {
+            self.mirror_delete(key).await;
+        }
         Ok(())
     }
 
@@ -922,9 +2916,12 @@ impl CacheOps for TwoLevelClient {
     /// 返回操作结果
     #[instrument(skip(self), level = "debug", fields(service = %self.service_name))]
     async fn clear_l1(&self) -> Result<()> {
+        if let Some(recent_writes) = &self.recent_writes {
+            recent_writes.invalidate_all();
+        }
         if let Some(l1) = &self.l1 {
             l1.clear()?;
-            GLOBAL_METRICS.record_request(&self.service_name, "L1", "clear", "success");
+            self.record_request("L1", "clear", "success");
         }
         Ok(())
     }
@@ -936,13 +2933,61 @@ impl CacheOps for TwoLevelClient {
     /// 返回操作结果
     #[instrument(skip(self), level = "debug", fields(service = %self.service_name))]
     async fn clear_l2(&self) -> Result<()> {
+        if let Some(recent_writes) = &self.recent_writes {
+            recent_writes.invalidate_all();
+        }
         if let Some(l2) = &self.l2 {
             l2.clear().await?;
-            GLOBAL_METRICS.record_request(&self.service_name, "L2", "clear", "success");
+            self.record_request("L2", "clear", "success");
         }
         Ok(())
     }
 
+    /// 获取哈希表字段值（字节）
+    ///
+    /// 哈希表仅存储在L2中，不经过L1缓存
+    #[instrument(skip(self), level = "debug", fields(service = %self.service_name))]
+    async fn hget_bytes(&self, key: &str, field: &str) -> Result<Option<Vec<u8>>> {
+        let key = self.resolve_key(key)?;
+        let key = key.as_ref();
+        match &self.l2 {
+            Some(l2) => l2.hget_bytes(key, field).await,
+            None => Ok(None),
+        }
+    }
+
+    /// 设置哈希表字段值（字节）
+    #[instrument(skip(self, value), level = "debug", fields(service = %self.service_name))]
+    async fn hset_bytes(
+        &self,
+        key: &str,
+        field: &str,
+        value: Vec<u8>,
+        ttl: Option<u64>,
+    ) -> Result<()> {
+        let key = self.resolve_key(key)?;
+        let key = key.as_ref();
+        validate_value_size(
+            &value,
+            self.config.max_value_size.unwrap_or(10 * 1024 * 1024),
+        )?;
+        match &self.l2 {
+            Some(l2) => l2.hset_bytes(key, field, value, ttl).await,
+            None => Ok(()),
+        }
+    }
+
+    /// 获取哈希表的所有字段（字节）
+    #[instrument(skip(self), level = "debug", fields(service = %self.service_name))]
+    async fn hgetall_bytes(&self, key: &str) -> Result<HashMap<String, Vec<u8>>> {
+        let key = self.resolve_key(key)?;
+        let key = key.as_ref();
+        match &self.l2 {
+            Some(l2) => l2.hgetall_bytes(key).await,
+            None => Ok(HashMap::new()),
+        }
+    }
+
     /// 清空 WAL 日志
     ///
     /// # 返回值
@@ -951,9 +2996,19 @@ impl CacheOps for TwoLevelClient {
     #[instrument(skip(self), level = "debug", fields(service = %self.service_name))]
     async fn clear_wal(&self) -> Result<()> {
         self.wal.clear().await?;
-        GLOBAL_METRICS.record_request(&self.service_name, "WAL", "clear", "success");
+        self.record_request("WAL", "clear", "success");
         Ok(())
     }
+
+    /// 获取当前健康状态
+    async fn health_state(&self) -> HealthState {
+        self.get_health_state().await
+    }
+
+    /// 优雅关闭客户端，停止后台任务
+    async fn shutdown(&self) -> Result<()> {
+        TwoLevelClient::shutdown(self).await
+    }
 }
 
 impl TwoLevelClient {
@@ -966,6 +3021,127 @@ impl TwoLevelClient {
         Ok(None)
     }
 
+    /// 批量获取缓存值（字节）
+    ///
+    /// L1未命中的键通过一次L2批量读取获取（集群模式下每个节点一个管道），
+    /// 对L1和L2均未命中的键只发起一次批量数据库回源，
+    /// 加载到的数据通过常规写入路径（启用时经由批量写入器）回写缓存。
+    /// 单个键回源失败只会使该键结果为None，不影响其他键
+    ///
+    /// # 参数
+    ///
+    /// * `keys` - 缓存键列表
+    ///
+    /// # 返回值
+    ///
+    /// 返回键到缓存值的映射，未找到的键对应None
+    #[instrument(skip(self), level = "debug", fields(service = %self.service_name))]
+    pub async fn get_many_bytes(&self, keys: &[&str]) -> Result<HashMap<String, Option<Vec<u8>>>> {
+        // 缓存层使用规范化后的键，返回结果与数据库回源仍使用原始键
+        let cache_keys = keys
+            .iter()
+            .map(|key| self.resolve_key(key))
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut results = HashMap::with_capacity(keys.len());
+        let (Some(l1), Some(l2)) = (&self.l1, &self.l2) else {
+            for key in keys {
+                results.insert(key.to_string(), None);
+            }
+            return Ok(results);
+        };
+
+        // L1未命中的键统一从L2批量读取
+        let mut l1_misses = Vec::new();
+        for (key, cache_key) in keys.iter().zip(&cache_keys) {
+            // 布隆过滤器判定不存在的键不参与回源
+            if let Some(bloom_filter) = &self.bloom_filter {
+                if !bloom_filter.contains(cache_key.as_bytes()).await {
+                    self.record_key_request(cache_key, "BloomFilter", "get", "miss");
+                    results.insert(key.to_string(), None);
+                    continue;
+                }
+                self.record_key_request(cache_key, "BloomFilter", "get", "hit");
+            }
+
+            match self.lookup_l1(l1, cache_key).await? {
+                Some(bytes) => {
+                    results.insert(key.to_string(), Some(soft_ttl::strip(bytes)));
+                }
+                None => l1_misses.push((*key, cache_key.as_ref())),
+            }
+        }
+
+        let l2_keys: Vec<&str> = l1_misses.iter().map(|(_, cache_key)| *cache_key).collect();
+        let l2_values = self.get_many_from_l2(l2, &l2_keys).await?;
+        let mut missing = Vec::new();
+        for ((key, _), value) in l1_misses.into_iter().zip(l2_values) {
+            if value.is_none() {
+                missing.push(key);
+            }
+            results.insert(key.to_string(), value.map(soft_ttl::strip));
+        }
+
+        let Some(db_fallback_mgr) = &self.db_fallback_mgr else {
+            return Ok(results);
+        };
+        if missing.is_empty() {
+            return Ok(results);
+        }
+
+        self.record_request("DB", "fallback_many", "attempt");
+        let start = std::time::Instant::now();
+        let loaded = db_fallback_mgr.fallback_load_many(&missing).await;
+        let duration = start.elapsed().as_secs_f64();
+        self.record_duration("DB", "fallback_many", duration);
+
+        for (key, result) in loaded {
+            match result {
+                Ok(Some(data)) => {
+                    self.record_key_request(&key, "DB", "fallback", "hit");
+                    if let Err(e) = self.set_bytes(&key, data.clone(), None).await {
+                        warn!("Failed to write fallback data to cache: {}", e);
+                    }
+                    results.insert(key, Some(data));
+                }
+                Ok(None) => {
+                    self.record_key_request(&key, "DB", "fallback", "miss");
+                    debug!("Database fallback miss for key: {}", key);
+                }
+                Err(e) => {
+                    warn!("Database fallback failed for key {}: {}", key, e);
+                }
+            }
+        }
+
+        Ok(results)
+    }
+
+    /// 批量获取缓存值（带反序列化）
+    ///
+    /// # 参数
+    ///
+    /// * `keys` - 缓存键列表
+    ///
+    /// # 返回值
+    ///
+    /// 返回键到缓存值的映射，未找到的键对应None
+    #[instrument(skip(self), level = "debug", fields(service = %self.service_name))]
+    pub async fn get_many<T: serde::de::DeserializeOwned + Send>(
+        &self,
+        keys: &[&str],
+    ) -> Result<HashMap<String, Option<T>>> {
+        let mut results = HashMap::with_capacity(keys.len());
+        for (key, bytes) in self.get_many_bytes(keys).await? {
+            let value = match bytes {
+                Some(bytes) => Some(self.serializer.deserialize(&bytes)?),
+                None => None,
+            };
+            results.insert(key, value);
+        }
+        Ok(results)
+    }
+
     /// 设置缓存值（带序列化）
     #[instrument(skip(self, value), level = "debug", fields(service = %self.service_name))]
     pub async fn set<T: serde::Serialize + Send + Sync>(
@@ -990,6 +3166,97 @@ impl TwoLevelClient {
         CacheOps::set_l1_bytes(self, key, bytes, ttl).await
     }
 
+    /// 检查一组键在L1与L2之间是否一致
+    ///
+    /// 用于排查缓存一致性问题：直接读取两层（不经过回源、不推广、不记录命中指标），
+    /// 比较值与版本号。L1中通过普通写入得到的条目版本号为0，视为未知，不参与版本比较
+    ///
+    /// # 参数
+    ///
+    /// * `keys` - 要检查的键
+    ///
+    /// # 返回值
+    ///
+    /// 返回与 `keys` 顺序一致的检查结果
+    #[instrument(skip(self, keys), level = "debug", fields(service = %self.service_name, key_count = keys.len()))]
+    pub async fn diff_layers(&self, keys: &[&str]) -> Result<Vec<LayerDiff>> {
+        let mut diffs = Vec::with_capacity(keys.len());
+        for key in keys {
+            let key = self.resolve_key(key)?;
+            let l1_entry = match &self.l1 {
+                Some(l1) => l1.get_with_metadata(&key).await?,
+                None => None,
+            };
+            let l2_entry = match &self.l2 {
+                Some(l2) => l2.backend().get_with_version(&key).await?,
+                None => None,
+            };
+
+            let status = match (l1_entry, l2_entry) {
+                (None, None) => LayerStatus::Missing,
+                (Some(_), None) => LayerStatus::L1Only,
+                (None, Some(_)) => LayerStatus::L2Only,
+                (Some((l1_value, _)), Some((l2_value, _))) if l1_value != l2_value => {
+                    LayerStatus::ValueMismatch
+                }
+                (Some((_, l1_version)), Some((_, l2_version)))
+                    if l1_version != 0 && l1_version != l2_version =>
+                {
+                    LayerStatus::VersionMismatch {
+                        l1_version,
+                        l2_version,
+                    }
+                }
+                _ => LayerStatus::Consistent,
+            };
+            diffs.push(LayerDiff {
+                key: key.into_owned(),
+                status,
+            });
+        }
+        Ok(diffs)
+    }
+
+    /// 使用已有的值预热单个键
+    ///
+    /// 与 `warmup` 不同，不调用加载函数，直接写入调用方已持有的值
+    ///
+    /// # 参数
+    ///
+    /// * `key` - 缓存键
+    /// * `value` - 缓存值
+    /// * `ttl` - 过期时间（秒），None表示使用默认值
+    /// * `layer` - 写入的缓存层
+    /// * `add_to_bloom` - 是否将键添加到布隆过滤器
+    ///
+    /// # 返回值
+    ///
+    /// 返回操作结果
+    #[instrument(skip(self, value), level = "debug", fields(service = %self.service_name))]
+    pub async fn prime<T: serde::Serialize + Send + Sync>(
+        &self,
+        key: &str,
+        value: &T,
+        ttl: Option<u64>,
+        layer: PrimeLayer,
+        add_to_bloom: bool,
+    ) -> Result<()> {
+        let key = self.resolve_key(key)?;
+        let key = key.as_ref();
+        let bytes = self.serializer.serialize(value)?;
+        let max_value_size = self.config.max_value_size.unwrap_or(10 * 1024 * 1024);
+        validate_value_size(&bytes, max_value_size)?;
+
+        if add_to_bloom {
+            self.add_to_bloom_filter(key).await;
+        }
+
+        match layer {
+            PrimeLayer::L1 => self.set_l1_resolved(key, bytes, ttl).await,
+            PrimeLayer::Both => self.write_layers(key, bytes, LayerTtl::Secs(ttl)).await,
+        }
+    }
+
     /// 仅设置L2缓存（手动控制）
     #[instrument(skip(self, value), level = "debug", fields(service = %self.service_name))]
     pub async fn set_l2_only<T: serde::Serialize + Send + Sync>(
@@ -1026,6 +3293,38 @@ impl TwoLevelClient {
         Ok(None)
     }
 
+    /// 绕过L1直接从L2读取最新值，并用该值刷新L1
+    ///
+    /// 与 [`get_l2_only`](Self::get_l2_only) 不同，读取后会把L1中可能过期的副本
+    /// 替换为L2的值；L2中不存在该键时同时删除L1中的副本
+    ///
+    /// # 参数
+    ///
+    /// * `key` - 缓存键
+    ///
+    /// # 返回值
+    ///
+    /// 返回L2中的值，不存在时返回None
+    #[instrument(skip(self), level = "debug", fields(service = %self.service_name))]
+    pub async fn get_l2_fresh<T: serde::de::DeserializeOwned + Send>(
+        &self,
+        key: &str,
+    ) -> Result<Option<T>> {
+        match CacheOps::get_l2_bytes(self, key).await? {
+            Some(bytes) => {
+                let value = self.serializer.deserialize(&bytes)?;
+                CacheOps::set_l1_bytes(self, key, bytes, None).await?;
+                Ok(Some(value))
+            }
+            None => {
+                if let Some(l1) = &self.l1 {
+                    l1.delete(&self.resolve_key(key)?).await?;
+                }
+                Ok(None)
+            }
+        }
+    }
+
     /// Ping L2 backend to check connectivity
     #[instrument(skip(self), level = "debug", fields(service = %self.service_name))]
     pub async fn ping_l2(&self) -> Result<()> {
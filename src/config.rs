@@ -4,12 +4,22 @@
 //!
 //! 该模块定义了缓存系统的配置结构和解析逻辑。
 
+use crate::sync::common::{BackpressurePolicy, DEFAULT_BLOCK_TIMEOUT_MS};
+use crate::utils::redaction::redact_url_password;
 use secrecy::{ExposeSecret, SecretString};
 use serde::Deserialize;
 use std::collections::HashMap;
 
 pub const CONFIG_VERSION: u32 = 1;
 pub const CONFIG_VERSION_FIELD: &str = "config_version";
+/// `L2Config::database` 允许的最大数据库编号（Redis默认提供16个数据库）
+pub const MAX_REDIS_DATABASE: u8 = 15;
+/// 脱敏配置中替代敏感值的占位符
+const REDACTED: &str = "****";
+/// 等待数据库回源许可的默认超时时间（毫秒）
+pub const DEFAULT_FALLBACK_PERMIT_TIMEOUT_MS: u64 = 5000;
+/// L1有效TTL超过L2有效TTL的默认告警倍数
+pub const DEFAULT_TTL_DIVERGENCE_FACTOR: f64 = 1.0;
 
 #[derive(Debug, Deserialize, Clone, Default)]
 pub struct Config {
@@ -57,12 +67,73 @@ pub struct ServiceConfig {
     pub ttl: Option<u64>,
     /// 序列化类型，可覆盖全局配置
     pub serialization: Option<SerializationType>,
+    /// 读取回退的序列化类型，用于编解码迁移：写入只使用主序列化类型，
+    /// 读取时主序列化类型反序列化失败则按顺序尝试这些类型
+    #[serde(default)]
+    pub read_fallback_serializers: Vec<SerializationType>,
+    /// 静态加密配置（可选），启用后缓存值在写入前加密
+    #[serde(default)]
+    pub encryption: Option<EncryptionConfig>,
     /// L1缓存配置
     pub l1: Option<L1Config>,
     /// L2缓存配置
     pub l2: Option<L2Config>,
     /// 双层缓存配置
     pub two_level: Option<TwoLevelConfig>,
+    /// 缓存键校验模式，默认只接受白名单字符
+    #[serde(default)]
+    pub key_mode: KeyMode,
+    /// 指标的键分组提取方式（可选，仅双层缓存），启用后读取指标额外按 `key_group` 标签统计
+    #[serde(default)]
+    pub key_group: Option<KeyGroupExtractor>,
+    /// 是否记录该服务的逐操作指标（仅双层缓存），默认启用
+    #[serde(default = "default_enable_metrics")]
+    pub enable_metrics: bool,
+    /// 关闭逐操作指标时，是否仍记录健康状态与TTL偏离等关键指标
+    #[serde(default)]
+    pub force_critical_metrics: bool,
+    /// 缓存纪元（仅双层缓存），大于0时所有键加上 `v{epoch}:` 前缀。
+    /// 递增纪元即可让旧键全部失效（旧键按TTL自然过期），无需扫描删除；
+    /// L2中持久化的纪元更大时以持久化的纪元为准
+    #[serde(default)]
+    pub cache_epoch: u64,
+}
+
+fn default_enable_metrics() -> bool {
+    true
+}
+
+/// 指标键分组提取方式
+///
+/// 将缓存键归入粗粒度的逻辑分组，用于按分组统计命中率而不产生按键的高基数标签
+#[derive(Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum KeyGroupExtractor {
+    /// 取键按分隔符切分后的前 `segments` 段作为分组，分隔符数量不足的键归入 `other`
+    Prefix {
+        /// 分隔符
+        delimiter: String,
+        /// 保留的段数
+        segments: usize,
+    },
+    /// 取正则表达式第一个捕获组（没有捕获组时取整个匹配）作为分组，不匹配的键归入 `other`
+    Regex {
+        /// 正则表达式
+        pattern: String,
+    },
+}
+
+/// 缓存键校验模式
+#[derive(Deserialize, Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum KeyMode {
+    /// 拒绝包含白名单以外字符的键
+    #[default]
+    Strict,
+    /// 将包含非法字符的键哈希为确定性的安全形式，合法键保持不变
+    HashInvalid,
+    /// 不检查字符，只限制键长度
+    Permissive,
 }
 
 impl Default for ServiceConfig {
@@ -71,24 +142,288 @@ impl Default for ServiceConfig {
             cache_type: CacheType::TwoLevel,
             ttl: None,
             serialization: None,
+            read_fallback_serializers: Vec::new(),
+            encryption: None,
             l1: Some(L1Config::default()),
             l2: Some(L2Config::default()),
             two_level: Some(TwoLevelConfig::default()),
+            key_mode: KeyMode::default(),
+            key_group: None,
+            enable_metrics: true,
+            force_critical_metrics: false,
+            cache_epoch: 0,
         }
     }
 }
 
+impl ServiceConfig {
+    /// 创建服务配置构建器
+    pub fn builder() -> ServiceConfigBuilder {
+        ServiceConfigBuilder::default()
+    }
+
+    /// 返回隐去敏感信息的配置副本
+    ///
+    /// Redis密码、哨兵密码与加密密钥替换为 `****`，连接字符串及哨兵、集群、分片节点地址中的密码同样隐去，
+    /// 可安全地用于日志输出或运维工具展示
+    pub fn redacted(&self) -> Self {
+        let redact_nodes = |nodes: &mut Vec<String>| {
+            for node in nodes.iter_mut() {
+                *node = redact_url_password(node);
+            }
+        };
+
+        let mut config = self.clone();
+        if let Some(encryption) = &mut config.encryption {
+            encryption.key = SecretString::from(REDACTED);
+        }
+        if let Some(l2) = &mut config.l2 {
+            l2.connection_string =
+                SecretString::from(redact_url_password(l2.connection_string.expose_secret()));
+            if l2.password.is_some() {
+                l2.password = Some(SecretString::from(REDACTED));
+            }
+            if let Some(sentinel) = &mut l2.sentinel {
+                redact_nodes(&mut sentinel.nodes);
+                if sentinel.sentinel_password.is_some() {
+                    sentinel.sentinel_password = Some(SecretString::from(REDACTED));
+                }
+            }
+            if let Some(cluster) = &mut l2.cluster {
+                redact_nodes(&mut cluster.nodes);
+            }
+            if let Some(sharded) = &mut l2.sharded {
+                redact_nodes(&mut sharded.nodes);
+            }
+        }
+        config
+    }
+}
+
+/// 服务配置构建器
+///
+/// 以链式调用构建 [`ServiceConfig`]，`build` 时按缓存类型检查必需的配置项
+#[derive(Clone, Debug, Default)]
+pub struct ServiceConfigBuilder {
+    cache_type: CacheType,
+    ttl: Option<u64>,
+    serialization: Option<SerializationType>,
+    read_fallback_serializers: Vec<SerializationType>,
+    encryption: Option<EncryptionConfig>,
+    l1: Option<L1Config>,
+    l2: Option<L2Config>,
+    two_level: Option<TwoLevelConfig>,
+    bloom_filter: Option<BloomFilterConfig>,
+    key_mode: KeyMode,
+    key_group: Option<KeyGroupExtractor>,
+    enable_metrics: Option<bool>,
+    force_critical_metrics: bool,
+    cache_epoch: u64,
+}
+
+impl ServiceConfigBuilder {
+    /// 使用双层缓存（L1+L2）
+    pub fn two_level(mut self) -> Self {
+        self.cache_type = CacheType::TwoLevel;
+        self
+    }
+
+    /// 仅使用L1缓存
+    pub fn l1_only(mut self) -> Self {
+        self.cache_type = CacheType::L1;
+        self
+    }
+
+    /// 仅使用L2缓存
+    pub fn l2_only(mut self) -> Self {
+        self.cache_type = CacheType::L2;
+        self
+    }
+
+    /// 禁用缓存
+    pub fn disabled(mut self) -> Self {
+        self.cache_type = CacheType::Disabled;
+        self
+    }
+
+    /// 设置缓存过期时间（秒）
+    pub fn ttl(mut self, secs: u64) -> Self {
+        self.ttl = Some(secs);
+        self
+    }
+
+    /// 设置序列化类型
+    pub fn serialization(mut self, serialization: SerializationType) -> Self {
+        self.serialization = Some(serialization);
+        self
+    }
+
+    /// 设置读取回退的序列化类型
+    pub fn read_fallback_serializers(mut self, serializers: Vec<SerializationType>) -> Self {
+        self.read_fallback_serializers = serializers;
+        self
+    }
+
+    /// 设置静态加密配置
+    pub fn encryption(mut self, encryption: EncryptionConfig) -> Self {
+        self.encryption = Some(encryption);
+        self
+    }
+
+    /// 设置L1缓存容量，其余L1配置保持默认值
+    pub fn l1_capacity(mut self, capacity: u64) -> Self {
+        self.l1.get_or_insert_with(L1Config::default).max_capacity = capacity;
+        self
+    }
+
+    /// 设置完整的L1缓存配置
+    pub fn l1(mut self, l1: L1Config) -> Self {
+        self.l1 = Some(l1);
+        self
+    }
+
+    /// 使用单机模式Redis作为L2缓存，其余L2配置保持默认值
+    pub fn l2_standalone(mut self, url: impl Into<String>) -> Self {
+        self.l2 = Some(L2Config {
+            mode: RedisMode::Standalone,
+            connection_string: SecretString::new(url.into().into()),
+            ..Default::default()
+        });
+        self
+    }
+
+    /// 设置完整的L2缓存配置
+    pub fn l2(mut self, l2: L2Config) -> Self {
+        self.l2 = Some(l2);
+        self
+    }
+
+    /// 设置双层缓存行为配置
+    pub fn two_level_config(mut self, two_level: TwoLevelConfig) -> Self {
+        self.two_level = Some(two_level);
+        self
+    }
+
+    /// 设置缓存键校验模式
+    pub fn key_mode(mut self, key_mode: KeyMode) -> Self {
+        self.key_mode = key_mode;
+        self
+    }
+
+    /// 设置指标的键分组提取方式
+    pub fn key_group(mut self, key_group: KeyGroupExtractor) -> Self {
+        self.key_group = Some(key_group);
+        self
+    }
+
+    /// 设置是否记录逐操作指标，默认启用
+    pub fn enable_metrics(mut self, enabled: bool) -> Self {
+        self.enable_metrics = Some(enabled);
+        self
+    }
+
+    /// 关闭逐操作指标时仍记录健康状态与TTL偏离等关键指标
+    pub fn force_critical_metrics(mut self, forced: bool) -> Self {
+        self.force_critical_metrics = forced;
+        self
+    }
+
+    /// 设置缓存纪元（仅双层缓存）
+    pub fn cache_epoch(mut self, epoch: u64) -> Self {
+        self.cache_epoch = epoch;
+        self
+    }
+
+    /// 启用布隆过滤器（仅双层缓存）
+    pub fn with_bloom(mut self, bloom_filter: BloomFilterConfig) -> Self {
+        self.bloom_filter = Some(bloom_filter);
+        self
+    }
+
+    /// 构建服务配置
+    ///
+    /// # 返回值
+    ///
+    /// 返回服务配置；缺少所选缓存类型必需的配置时返回配置错误
+    pub fn build(self) -> crate::error::Result<ServiceConfig> {
+        use crate::error::CacheError;
+
+        if self.bloom_filter.is_some() && self.cache_type != CacheType::TwoLevel {
+            return Err(CacheError::Configuration(
+                "Bloom filter is only supported for two-level caches".to_string(),
+            ));
+        }
+
+        let (l1, l2, two_level) = match self.cache_type {
+            CacheType::TwoLevel => {
+                let l2 = self.l2.ok_or_else(|| {
+                    CacheError::Configuration(
+                        "Two-level cache requires L2 configuration, call l2_standalone() or l2()"
+                            .to_string(),
+                    )
+                })?;
+                let mut two_level = self.two_level.unwrap_or_default();
+                if let Some(bloom_filter) = self.bloom_filter {
+                    two_level.bloom_filter = Some(bloom_filter);
+                }
+                (Some(self.l1.unwrap_or_default()), Some(l2), Some(two_level))
+            }
+            CacheType::L1 => (Some(self.l1.unwrap_or_default()), None, None),
+            CacheType::L2 => {
+                let l2 = self.l2.ok_or_else(|| {
+                    CacheError::Configuration(
+                        "L2 cache requires L2 configuration, call l2_standalone() or l2()"
+                            .to_string(),
+                    )
+                })?;
+                (None, Some(l2), None)
+            }
+            CacheType::Disabled => (None, None, None),
+        };
+
+        Ok(ServiceConfig {
+            cache_type: self.cache_type,
+            ttl: self.ttl,
+            serialization: self.serialization,
+            read_fallback_serializers: self.read_fallback_serializers,
+            encryption: self.encryption,
+            l1,
+            l2,
+            two_level,
+            key_mode: self.key_mode,
+            key_group: self.key_group,
+            enable_metrics: self.enable_metrics.unwrap_or(true),
+            force_critical_metrics: self.force_critical_metrics,
+            cache_epoch: self.cache_epoch,
+        })
+    }
+}
+
 /// 序列化类型枚举
 ///
-/// 支持JSON和Bincode两种序列化方式
+/// 支持JSON、CBOR和Bincode序列化方式
 #[derive(Deserialize, Clone, Debug, PartialEq, Default)]
 #[serde(rename_all = "lowercase")]
 pub enum SerializationType {
     /// JSON序列化
     #[default]
     Json,
+    /// CBOR序列化（自描述格式，便于与非Rust服务互通）
+    Cbor,
     /// Bincode序列化
     Bincode,
+    /// 未知的序列化类型，保留原始名称以便在初始化时报告
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// 静态加密配置
+///
+/// 使用AES-256-GCM对序列化后的缓存值进行加密
+#[derive(Deserialize, Clone, Debug)]
+pub struct EncryptionConfig {
+    /// AES-256密钥，64位十六进制字符串（使用 SecretString 保护）
+    pub key: SecretString,
 }
 
 /// 缓存类型枚举
@@ -104,6 +439,8 @@ pub enum CacheType {
     /// 双层缓存（L1+L2）
     #[default]
     TwoLevel,
+    /// 禁用缓存，读取总是未命中，写入为空操作
+    Disabled,
 }
 
 /// L1缓存配置
@@ -120,6 +457,14 @@ pub struct L1Config {
     pub max_value_size: usize,
     /// 过期清理间隔（秒），0表示禁用自动清理
     pub cleanup_interval_secs: u64,
+    /// 写入时未指定TTL所使用的默认过期时间（秒），None表示使用300秒
+    pub l1_default_ttl: Option<u64>,
+    /// 是否监听淘汰事件并记录 `l1_evictions_total` 指标
+    pub enable_eviction_listener: bool,
+    /// 容量自适应调优配置，None表示关闭（默认）
+    pub auto_tune: Option<L1AutoTuneConfig>,
+    /// 内部分片数，必须是2的幂，键按哈希分散到各分片以减少锁竞争；默认1（不分片）
+    pub shards: usize,
 }
 
 impl Default for L1Config {
@@ -129,6 +474,41 @@ impl Default for L1Config {
             max_key_length: 256,
             max_value_size: 1024 * 1024, // 1MB
             cleanup_interval_secs: 300,  // 5 minutes
+            l1_default_ttl: None,
+            enable_eviction_listener: false,
+            auto_tune: None,
+            shards: 1,
+        }
+    }
+}
+
+/// L1容量自适应调优配置
+///
+/// 启用后L1按 `max_capacity` 构建，当前容量从 [`L1Config::max_capacity`] 开始，
+/// 每个调优周期根据命中率与宿主提供的内存压力信号在 `[min_capacity, max_capacity]` 之间调整
+#[derive(Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct L1AutoTuneConfig {
+    /// 容量下限
+    pub min_capacity: u64,
+    /// 容量上限
+    pub max_capacity: u64,
+    /// 调优间隔（秒）
+    pub interval_secs: u64,
+    /// 目标命中率，无内存压力且本周期命中率低于该值时扩容
+    pub target_hit_ratio: f64,
+    /// 每次调整的幅度，按当前容量的比例计算
+    pub step_ratio: f64,
+}
+
+impl Default for L1AutoTuneConfig {
+    fn default() -> Self {
+        Self {
+            min_capacity: 1000,
+            max_capacity: 100_000,
+            interval_secs: 30,
+            target_hit_ratio: 0.8,
+            step_ratio: 0.25,
         }
     }
 }
@@ -147,10 +527,18 @@ pub struct L2Config {
     pub connection_timeout_ms: u64,
     /// 命令执行超时时间（毫秒）
     pub command_timeout_ms: u64,
+    /// Redis ACL 用户名（可选，需同时设置密码）；未设置时以默认用户认证
+    pub username: Option<String>,
     /// Redis 密码（可选，使用 SecretString 保护）
     pub password: Option<SecretString>,
     /// 是否启用 TLS
     pub enable_tls: bool,
+    /// TLS 客户端证书路径（PEM格式，用于双向TLS）
+    pub tls_client_cert_path: Option<String>,
+    /// TLS 客户端私钥路径（PEM格式，用于双向TLS）
+    pub tls_client_key_path: Option<String>,
+    /// TLS CA证书路径（PEM格式，未设置时使用系统根证书）
+    pub tls_ca_cert_path: Option<String>,
     /// 哨兵配置
     pub sentinel: Option<SentinelConfig>,
     /// 集群配置
@@ -161,6 +549,42 @@ pub struct L2Config {
     pub max_key_length: usize,
     /// 值的最大大小（字节）
     pub max_value_size: usize,
+    /// 瞬时故障重试策略
+    pub retry: RetryConfig,
+    /// 健康检查探测配置
+    pub health: HealthConfig,
+    /// Redis数据库编号（0-15），连接建立及重连时自动执行 `SELECT`；
+    /// None表示使用连接字符串中的编号。集群模式不支持该选项
+    pub database: Option<u8>,
+    /// 是否为每个键维护 `:version` 版本键（默认true）。
+    /// 关闭后读写改用普通的 `GET`/`SET`/`DEL`，读取到的版本号恒为0
+    pub enable_versioning: bool,
+    /// 客户端分片配置（仅 `Sharded` 模式）
+    pub sharded: Option<ShardedConfig>,
+    /// 值压缩编码。设置后每个值都带一个编码字节，读取时按该字节解码，
+    /// 切换编码无需清空缓存；None表示不加帧头，与未启用时写入的数据兼容
+    pub compression: Option<CompressionCodec>,
+    /// 是否在创建后端时立即建立连接（默认false）。
+    /// 启用后初始化时向所有节点发送 `PING`，首个请求无需承担建连延迟，连接失败时初始化报错
+    pub eager_connect: bool,
+    /// 被重命名的Redis命令映射（原命令名 -> 实际命令名），大小写不敏感。
+    /// 用于扫描与清理时发送的 `SCAN`、`UNLINK`，未配置的命令使用原名
+    pub command_aliases: HashMap<String, String>,
+}
+
+/// L2值压缩编码
+#[derive(Deserialize, Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum CompressionCodec {
+    /// 不压缩，仅写入帧头
+    #[default]
+    None,
+    /// Zstandard
+    Zstd,
+    /// LZ4
+    Lz4,
+    /// gzip
+    Gzip,
 }
 
 impl Default for L2Config {
@@ -170,24 +594,91 @@ impl Default for L2Config {
             connection_string: SecretString::new("redis://localhost:6379".to_string().into()),
             connection_timeout_ms: 5000,
             command_timeout_ms: 3000,
+            username: None,
             password: None,
             enable_tls: false,
+            tls_client_cert_path: None,
+            tls_client_key_path: None,
+            tls_ca_cert_path: None,
             sentinel: None,
             cluster: None,
             default_ttl: Some(3600),
             max_key_length: 256,
             max_value_size: 1024 * 1024 * 10, // 10MB
+            retry: RetryConfig::default(),
+            health: HealthConfig::default(),
+            database: None,
+            enable_versioning: true,
+            sharded: None,
+            compression: None,
+            eager_connect: false,
+            command_aliases: HashMap::new(),
+        }
+    }
+}
+
+/// L2命令重试配置
+///
+/// 仅对超时、连接被拒绝、连接断开等瞬时故障重试，认证失败与逻辑错误不会重试。
+/// 包含重试在内的总耗时不超过 `command_timeout_ms`。
+#[derive(Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct RetryConfig {
+    /// 最大重试次数（不含首次执行），0表示不重试
+    pub max_retries: u32,
+    /// 基础退避时间（毫秒），第n次重试前等待 `base_backoff_ms * 2^n`
+    pub base_backoff_ms: u64,
+    /// 是否为退避时间添加随机抖动，避免大量客户端同时重试
+    pub jitter: bool,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            max_retries: 2,
+            base_backoff_ms: 50,
+            jitter: true,
+        }
+    }
+}
+
+/// 健康检查配置
+///
+/// 启用抖动后，首次探测在一个探测间隔内随机延迟，之后每次间隔在
+/// `probe_interval_ms * (1 ± jitter_percent%)` 范围内随机取值，避免大量实例同时启动时集中探测Redis。
+#[derive(Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct HealthConfig {
+    /// 探测间隔（毫秒）
+    pub probe_interval_ms: u64,
+    /// 探测间隔抖动比例（百分比，0-100），0表示固定间隔且立即进行首次探测
+    pub jitter_percent: u8,
+}
+
+impl Default for HealthConfig {
+    fn default() -> Self {
+        Self {
+            probe_interval_ms: 5000,
+            jitter_percent: 0,
         }
     }
 }
 
 /// 哨兵配置
+///
+/// 主节点的认证信息使用 `L2Config` 的 `username` 与 `password`，哨兵自身的认证使用 `sentinel_password`
 #[derive(Deserialize, Clone, Debug)]
 pub struct SentinelConfig {
     /// 主节点名称
     pub master_name: String,
     ////// 哨兵节点列表
     pub nodes: Vec<String>,
+    /// 哨兵节点的密码（哨兵配置了 `requirepass` 时需要），None表示哨兵不需要认证
+    #[serde(default)]
+    pub sentinel_password: Option<SecretString>,
+    /// 通过哨兵发现主节点的超时时间（毫秒），None表示使用 `connection_timeout_ms`
+    #[serde(default)]
+    pub master_discovery_timeout_ms: Option<u64>,
 }
 
 /// 集群配置
@@ -197,7 +688,142 @@ pub struct ClusterConfig {
     pub nodes: Vec<String>,
 }
 
+/// 每个分片节点在哈希环上的默认虚拟节点数
+pub const DEFAULT_VIRTUAL_NODES: usize = 160;
+
+/// 客户端分片配置
+///
+/// 在多个独立的单机Redis实例之间按一致性哈希分布键。
+/// 单键操作路由到键所属节点，批量操作按节点分组发送；
+/// 跨节点的事务与原子性无法保证，键的 `:version` 版本键总是与数据键位于同一节点
+#[derive(Deserialize, Clone, Debug)]
+pub struct ShardedConfig {
+    /// 分片节点连接字符串列表
+    pub nodes: Vec<String>,
+    /// 每个节点在哈希环上的虚拟节点数
+    #[serde(default = "default_virtual_nodes")]
+    pub virtual_nodes: usize,
+}
+
+fn default_virtual_nodes() -> usize {
+    DEFAULT_VIRTUAL_NODES
+}
+
 impl Config {
+    /// 从环境变量构建单服务配置
+    ///
+    /// 支持的环境变量：
+    ///
+    /// * `OXCACHE_SERVICE_NAME` - 服务名称，默认 `default`
+    /// * `OXCACHE_CACHE_TYPE` - 缓存类型（`twolevel`、`l1`、`l2`、`disabled`），默认 `twolevel`
+    /// * `OXCACHE_REDIS_URL` - Redis连接字符串，`twolevel` 和 `l2` 类型必需
+    /// * `OXCACHE_REDIS_PASSWORD` - Redis密码（可选）
+    /// * `OXCACHE_REDIS_TLS` - 是否启用TLS，默认 `false`
+    /// * `OXCACHE_L1_CAPACITY` - L1缓存容量，默认 10000
+    /// * `OXCACHE_TTL` - 缓存过期时间（秒），默认使用全局默认值 300
+    /// * `OXCACHE_SERIALIZATION` - 序列化类型（`json`、`cbor`），默认 `json`
+    ///
+    /// # 返回值
+    ///
+    /// 返回经过验证的配置；缺少必需变量或变量值无效时返回错误
+    pub fn from_env() -> crate::error::Result<Self> {
+        use crate::error::CacheError;
+
+        fn var(name: &str) -> Option<String> {
+            std::env::var(name)
+                .ok()
+                .map(|v| v.trim().to_string())
+                .filter(|v| !v.is_empty())
+        }
+
+        fn parse<T: std::str::FromStr>(name: &str) -> crate::error::Result<Option<T>> {
+            var(name)
+                .map(|v| {
+                    v.parse().map_err(|_| {
+                        CacheError::Configuration(format!(
+                            "Invalid value for environment variable {}: '{}'",
+                            name, v
+                        ))
+                    })
+                })
+                .transpose()
+        }
+
+        let service_name = var("OXCACHE_SERVICE_NAME").unwrap_or_else(|| "default".to_string());
+        let ttl = parse::<u64>("OXCACHE_TTL")?;
+
+        let mut builder = match var("OXCACHE_CACHE_TYPE")
+            .map(|v| v.to_ascii_lowercase())
+            .as_deref()
+        {
+            None | Some("twolevel") | Some("two_level") => ServiceConfig::builder().two_level(),
+            Some("l1") => ServiceConfig::builder().l1_only(),
+            Some("l2") => ServiceConfig::builder().l2_only(),
+            Some("disabled") => ServiceConfig::builder().disabled(),
+            Some(other) => {
+                return Err(CacheError::Configuration(format!(
+                    "Invalid value for environment variable OXCACHE_CACHE_TYPE: '{}'",
+                    other
+                )))
+            }
+        };
+        let cache_type = builder.cache_type.clone();
+
+        let l1_capacity = parse::<u64>("OXCACHE_L1_CAPACITY")?;
+        if matches!(cache_type, CacheType::TwoLevel | CacheType::L1) {
+            let default_l1 = L1Config::default();
+            builder = builder.l1(L1Config {
+                max_capacity: l1_capacity.unwrap_or(default_l1.max_capacity),
+                // 清理间隔不能超过服务TTL
+                cleanup_interval_secs: ttl.map_or(default_l1.cleanup_interval_secs, |ttl| {
+                    ttl.min(default_l1.cleanup_interval_secs)
+                }),
+                ..default_l1
+            });
+        }
+
+        if let Some(ttl) = ttl {
+            builder = builder.ttl(ttl);
+        }
+
+        if let Some(serialization) = var("OXCACHE_SERIALIZATION") {
+            builder = builder.serialization(match serialization.to_ascii_lowercase().as_str() {
+                "json" => SerializationType::Json,
+                "cbor" => SerializationType::Cbor,
+                other => SerializationType::Unknown(other.to_string()),
+            });
+        }
+
+        if matches!(cache_type, CacheType::TwoLevel | CacheType::L2) {
+            let url = var("OXCACHE_REDIS_URL").ok_or_else(|| {
+                CacheError::Configuration(
+                    "Missing required environment variable OXCACHE_REDIS_URL".to_string(),
+                )
+            })?;
+            let default_l2 = L2Config::default();
+            builder = builder.l2(L2Config {
+                mode: RedisMode::Standalone,
+                connection_string: SecretString::new(url.into()),
+                password: var("OXCACHE_REDIS_PASSWORD").map(|p| SecretString::new(p.into())),
+                enable_tls: parse::<bool>("OXCACHE_REDIS_TLS")?.unwrap_or(false),
+                // L2的TTL不能短于服务TTL
+                default_ttl: ttl.max(default_l2.default_ttl),
+                ..default_l2
+            });
+        }
+
+        let mut services = HashMap::new();
+        services.insert(service_name, builder.build()?);
+
+        let config = Config {
+            config_version: Some(CONFIG_VERSION),
+            global: GlobalConfig::default(),
+            services,
+        };
+        config.validate().map_err(CacheError::ConfigError)?;
+        Ok(config)
+    }
+
     /// 验证配置
     ///
     /// 检查配置的有效性，确保所有必需的字段都已设置，并且值在合理范围内
@@ -288,6 +914,55 @@ impl Config {
                     ));
                 }
 
+                // 验证数据库编号
+                if let Some(database) = l2_config.database {
+                    if l2_config.mode == RedisMode::Cluster {
+                        return Err(format!(
+                            "Service '{}' database cannot be set in cluster mode",
+                            name
+                        ));
+                    }
+                    if database > MAX_REDIS_DATABASE {
+                        return Err(format!(
+                            "Service '{}' database must be between 0 and {}",
+                            name, MAX_REDIS_DATABASE
+                        ));
+                    }
+                }
+
+                // 验证命令别名
+                if let Some(command) = l2_config
+                    .command_aliases
+                    .iter()
+                    .find(|(_, alias)| alias.trim().is_empty())
+                    .map(|(command, _)| command)
+                {
+                    return Err(format!(
+                        "Service '{}' command alias for '{}' cannot be empty",
+                        name, command
+                    ));
+                }
+
+                // 验证分片配置
+                if l2_config.mode == RedisMode::Sharded {
+                    match &l2_config.sharded {
+                        Some(sharded) if !sharded.nodes.is_empty() => {
+                            if sharded.virtual_nodes == 0 {
+                                return Err(format!(
+                                    "Service '{}' sharded virtual_nodes cannot be zero",
+                                    name
+                                ));
+                            }
+                        }
+                        _ => {
+                            return Err(format!(
+                                "Service '{}' sharded mode requires at least one node",
+                                name
+                            ));
+                        }
+                    }
+                }
+
                 // 生产环境安全检查：强制使用认证
                 if l2_config.password.is_none() {
                     // 检查是否是生产环境（通过连接字符串判断）
@@ -344,6 +1019,60 @@ impl Config {
                     ));
                 }
 
+                if let Some(l1_default_ttl) = l1_config.l1_default_ttl {
+                    if l1_default_ttl == 0 {
+                        return Err(format!(
+                            "Service '{}' L1 l1_default_ttl cannot be zero",
+                            name
+                        ));
+                    }
+
+                    if l1_default_ttl > 86400 * 30 {
+                        return Err(format!(
+                            "Service '{}' L1 l1_default_ttl cannot exceed 30 days",
+                            name
+                        ));
+                    }
+                }
+
+                if !l1_config.shards.is_power_of_two()
+                    || l1_config.shards as u64 > l1_config.max_capacity
+                {
+                    return Err(format!(
+                        "Service '{}' L1 shards must be a power of two not exceeding max_capacity",
+                        name
+                    ));
+                }
+
+                if let Some(auto_tune) = &l1_config.auto_tune {
+                    if auto_tune.min_capacity == 0
+                        || auto_tune.min_capacity > l1_config.max_capacity
+                        || l1_config.max_capacity > auto_tune.max_capacity
+                    {
+                        return Err(format!(
+                            "Service '{}' L1 auto_tune requires 0 < min_capacity <= max_capacity ({}) <= auto_tune max_capacity",
+                            name, l1_config.max_capacity
+                        ));
+                    }
+
+                    if auto_tune.interval_secs == 0 {
+                        return Err(format!(
+                            "Service '{}' L1 auto_tune interval_secs cannot be zero",
+                            name
+                        ));
+                    }
+
+                    if !((0.0..=1.0).contains(&auto_tune.target_hit_ratio)
+                        && auto_tune.step_ratio > 0.0
+                        && auto_tune.step_ratio < 1.0)
+                    {
+                        return Err(format!(
+                            "Service '{}' L1 auto_tune requires target_hit_ratio in [0, 1] and step_ratio in (0, 1)",
+                            name
+                        ));
+                    }
+                }
+
                 // L1 清理间隔必须小于等于服务 TTL
                 if l1_config.cleanup_interval_secs > 0
                     && l1_config.cleanup_interval_secs > service_ttl
@@ -357,8 +1086,42 @@ impl Config {
 
             // 验证双层缓存配置
             if let Some(two_level_config) = &service.two_level {
+                if two_level_config.write_behind
+                    && two_level_config.write_order == WriteOrder::L2First
+                {
+                    return Err(format!(
+                        "Service '{}' write_behind requires l1_first write_order",
+                        name
+                    ));
+                }
+
+                if let Some(threshold) = two_level_config.admission_threshold {
+                    if !(0.0..=1.0).contains(&threshold) {
+                        return Err(format!(
+                            "Service '{}' admission_threshold must be between 0.0 and 1.0",
+                            name
+                        ));
+                    }
+                }
+
+                if let Some(factor) = two_level_config.recovering_ttl_factor {
+                    if !factor.is_finite() || factor < 1.0 {
+                        return Err(format!(
+                            "Service '{}' recovering_ttl_factor must be a finite number not less than 1.0",
+                            name
+                        ));
+                    }
+                }
+
+                if two_level_config.max_promotion_tasks == Some(0) {
+                    return Err(format!(
+                        "Service '{}' max_promotion_tasks cannot be zero",
+                        name
+                    ));
+                }
+
                 // 验证批量写入配置
-                if two_level_config.enable_batch_write {
+                if two_level_config.uses_batch_writer() {
                     if two_level_config.batch_size == 0 {
                         return Err(format!(
                             "Service '{}' batch_size cannot be zero when batch_write is enabled",
@@ -404,6 +1167,28 @@ impl Config {
                     }
                 }
 
+                if two_level_config.max_concurrent_fallbacks == Some(0) {
+                    return Err(format!(
+                        "Service '{}' max_concurrent_fallbacks cannot be zero",
+                        name
+                    ));
+                }
+
+                if let Some(ryw_config) = &two_level_config.read_your_writes {
+                    if ryw_config.max_entries == 0 {
+                        return Err(format!(
+                            "Service '{}' read_your_writes max_entries cannot be zero",
+                            name
+                        ));
+                    }
+                    if ryw_config.ttl_ms == 0 {
+                        return Err(format!(
+                            "Service '{}' read_your_writes ttl_ms cannot be zero",
+                            name
+                        ));
+                    }
+                }
+
                 // 验证布隆过滤器配置
                 if let Some(bloom_config) = &two_level_config.bloom_filter {
                     if bloom_config.expected_elements == 0 {
@@ -486,6 +1271,102 @@ pub struct TwoLevelConfig {
     pub max_key_length: Option<usize>,
     /// 值的最大大小（字节）
     pub max_value_size: Option<usize>,
+    /// L1与L2的写入顺序
+    #[serde(default)]
+    pub write_order: WriteOrder,
+    /// 同时进行的数据库回源最大数量，None表示不限制
+    #[serde(default)]
+    pub max_concurrent_fallbacks: Option<usize>,
+    /// 回源并发达到上限时等待许可的超时时间（毫秒），超时后按未命中处理
+    #[serde(default = "default_fallback_permit_timeout_ms")]
+    pub fallback_permit_timeout_ms: u64,
+    /// L1有效TTL超过L2有效TTL的告警倍数
+    #[serde(default = "default_ttl_divergence_factor")]
+    pub ttl_divergence_factor: f64,
+    /// 是否启用写后回写（write-behind）模式，仅在L1优先写入顺序下可用
+    #[serde(default)]
+    pub write_behind: bool,
+    /// 读己之写（read-your-writes）缓冲配置，None表示不启用
+    #[serde(default)]
+    pub read_your_writes: Option<ReadYourWritesConfig>,
+    /// 次级L2配置，None表示不启用
+    #[serde(default)]
+    pub secondary: Option<L2Config>,
+    /// L1准入阈值（0.0–1.0），None表示不启用
+    #[serde(default)]
+    pub admission_threshold: Option<f64>,
+    /// L2恢复期间的TTL放大倍数（不小于1.0），None表示不启用
+    #[serde(default)]
+    pub recovering_ttl_factor: Option<f64>,
+    /// 同时进行的L1推广任务最大数量，None表示不限制
+    #[serde(default)]
+    pub max_promotion_tasks: Option<usize>,
+    /// 批量写入队列的最大深度，None表示 `batch_size * 10`
+    #[serde(default)]
+    pub batch_max_queue_depth: Option<usize>,
+    /// 批量写入队列达到最大深度时的背压策略
+    #[serde(default)]
+    pub batch_backpressure: BackpressurePolicy,
+    /// Block背压策略下等待队列空间的最长时间（毫秒）
+    #[serde(default = "default_batch_block_timeout_ms")]
+    pub batch_block_timeout_ms: u64,
+}
+
+impl TwoLevelConfig {
+    /// L2写入是否经由批量写入器
+    ///
+    /// 写后回写模式下写入立即更新L1，L2写入在 `batch_interval_ms` 窗口内按键合并后
+    /// 只写出每个键的最后一个值，适合计数器等高频覆盖写入的场景。代价是L2落后于L1
+    /// 最多一个刷新窗口，进程崩溃时窗口内尚未写出的数据依赖WAL恢复
+    ///
+    /// # 返回值
+    ///
+    /// 启用批量写入或写后回写模式时返回true
+    pub fn uses_batch_writer(&self) -> bool {
+        self.enable_batch_write || self.write_behind
+    }
+}
+
+fn default_fallback_permit_timeout_ms() -> u64 {
+    DEFAULT_FALLBACK_PERMIT_TIMEOUT_MS
+}
+
+fn default_ttl_divergence_factor() -> f64 {
+    DEFAULT_TTL_DIVERGENCE_FACTOR
+}
+
+fn default_batch_block_timeout_ms() -> u64 {
+    DEFAULT_BLOCK_TIMEOUT_MS
+}
+
+/// 双层缓存写入顺序
+#[derive(Deserialize, Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum WriteOrder {
+    /// 先写L1再写L2，L2写入失败时L1中仍保留新值
+    #[default]
+    L1First,
+    /// 先写L2，L2确认写入后才更新L1
+    L2First,
+}
+
+/// 读己之写缓冲配置
+#[derive(Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct ReadYourWritesConfig {
+    /// 缓冲保留的最大条目数，超出后按最近最少使用淘汰
+    pub max_entries: u64,
+    /// 写入后在缓冲中保留的时间（毫秒）
+    pub ttl_ms: u64,
+}
+
+impl Default for ReadYourWritesConfig {
+    fn default() -> Self {
+        Self {
+            max_entries: 1024,
+            ttl_ms: 5000,
+        }
+    }
 }
 
 /// 缓存预热配置
@@ -503,6 +1384,9 @@ pub struct CacheWarmupConfig {
     pub batch_interval_ms: u64,
     /// 预热数据源配置
     pub data_sources: Vec<WarmupDataSource>,
+    /// 是否在客户端创建时于后台自动执行预热，不阻塞初始化
+    #[serde(default)]
+    pub warmup_on_init: bool,
 }
 
 /// 预热数据源配置
@@ -549,6 +1433,7 @@ impl Default for CacheWarmupConfig {
             batch_size: 100,
             batch_interval_ms: 50,
             data_sources: Vec::new(),
+            warmup_on_init: false,
         }
     }
 }
@@ -566,6 +1451,25 @@ pub struct BloomFilterConfig {
     pub auto_add_keys: bool,
     /// 布隆过滤器名称
     pub name: String,
+    /// 存储后端，默认为进程内
+    #[serde(default)]
+    pub backend: BloomFilterBackend,
+    /// 使用Redis后端时，是否在本地镜像一份过滤器
+    ///
+    /// 本地判定存在的键无需访问Redis，降低查询延迟；本地未命中时仍以Redis为准
+    #[serde(default)]
+    pub mirror_local: bool,
+}
+
+/// 布隆过滤器存储后端
+#[derive(Deserialize, Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum BloomFilterBackend {
+    /// 进程内位数组，各实例互不共享
+    #[default]
+    Local,
+    /// 存储在Redis中，所有实例共享同一个过滤器
+    Redis,
 }
 
 impl Default for BloomFilterConfig {
@@ -575,6 +1479,8 @@ impl Default for BloomFilterConfig {
             false_positive_rate: 0.01,
             auto_add_keys: true,
             name: "default_bloom_filter".to_string(),
+            backend: BloomFilterBackend::Local,
+            mirror_local: false,
         }
     }
 }
@@ -606,6 +1512,19 @@ impl Default for TwoLevelConfig {
             warmup: None,
             max_key_length: Some(256),
             max_value_size: Some(1024 * 1024 * 10),
+            write_order: WriteOrder::default(),
+            max_concurrent_fallbacks: None,
+            fallback_permit_timeout_ms: DEFAULT_FALLBACK_PERMIT_TIMEOUT_MS,
+            ttl_divergence_factor: DEFAULT_TTL_DIVERGENCE_FACTOR,
+            write_behind: false,
+            read_your_writes: None,
+            secondary: None,
+            admission_threshold: None,
+            recovering_ttl_factor: None,
+            max_promotion_tasks: None,
+            batch_max_queue_depth: None,
+            batch_backpressure: BackpressurePolicy::default(),
+            batch_block_timeout_ms: DEFAULT_BLOCK_TIMEOUT_MS,
         }
     }
 }
@@ -622,4 +1541,6 @@ pub enum RedisMode {
     Sentinel,
     /// 集群模式
     Cluster,
+    /// 客户端分片模式，按一致性哈希在多个单机实例间分布键
+    Sharded,
 }
@@ -9,12 +9,13 @@ use crate::error::Result;
 use chrono::{DateTime, Datelike, TimeZone, Utc};
 use futures::Future;
 use std::pin::Pin;
+use tracing::{debug, trace};
 
 /// 数据库分区管理的公共工具函数
 pub trait PartitionCommon {
     /// 计算分区保留截止日期
     fn calculate_cutoff_date(&self, retention_months: u32) -> DateTime<Utc> {
-        Utc::now() - chrono::Duration::days((retention_months * 30) as i64)
+        retention_cutoff_date(Utc::now(), retention_months)
     }
 
     /// 获取分区的基础表名（移除日期后缀）
@@ -113,6 +114,30 @@ where
     Ok(())
 }
 
+/// 按自然月计算分区保留截止日期
+///
+/// 使用日历月而非30天近似，目标月份没有对应日期时取该月最后一天（如3月31日往前1个月为2月28/29日）
+///
+/// # 参数
+///
+/// * `now` - 当前时间
+/// * `retention_months` - 保留月数
+///
+/// # 返回值
+///
+/// 返回截止日期，结束时间不晚于该日期的分区视为过期
+pub fn retention_cutoff_date(now: DateTime<Utc>, retention_months: u32) -> DateTime<Utc> {
+    now.checked_sub_months(chrono::Months::new(retention_months))
+        .unwrap_or(DateTime::<Utc>::MIN_UTC)
+}
+
+/// 判断分区是否已超出保留期
+///
+/// 分区数据均早于其结束边界，因此结束边界不晚于截止日期即可整体删除
+pub fn is_partition_expired(partition: &PartitionInfo, cutoff_date: DateTime<Utc>) -> bool {
+    partition.end_date <= cutoff_date
+}
+
 /// 清理过期分区的通用实现
 pub async fn common_cleanup_old_partitions<'a, M, F, G>(
     manager: &'a M,
@@ -142,9 +167,19 @@ where
     let partitions = get_partitions(manager, table_name).await?;
     let cutoff_date = manager.calculate_cutoff_date(retention);
 
+    debug!(
+        "Cleaning partitions of {} older than {} (retention {} months)",
+        table_name, cutoff_date, retention
+    );
+
     let mut dropped_count = 0;
     for partition in partitions {
-        if partition.end_date < cutoff_date {
+        if is_partition_expired(&partition, cutoff_date) {
+            trace!(
+                "Dropping partition {} (ends {})",
+                partition.name,
+                partition.end_date
+            );
             drop_partition(manager, table_name, &partition.name).await?;
             dropped_count += 1;
         }
@@ -24,7 +24,7 @@ pub use connection_string::{
     ParsedConnectionString, ValidationResult,
 };
 pub use mysql::MySQLPartitionManager;
-pub use partition::{PartitionManager, PartitionStrategy};
+pub use partition::{MaintenanceHandle, PartitionManager, PartitionStrategy};
 pub use postgresql::PostgresPartitionManager;
 pub use sqlite::SQLitePartitionManager;
 
@@ -62,6 +62,12 @@ pub struct PartitionConfig {
     pub retention_months: Option<u32>,
     /// 分区表前缀
     pub table_prefix: String,
+    /// 是否启用 WAL 日志模式（`PRAGMA journal_mode=WAL`，仅适用于 SQLite）
+    #[serde(default)]
+    pub sqlite_wal_mode: Option<bool>,
+    /// 数据库忙等待超时（毫秒，`PRAGMA busy_timeout`，仅适用于 SQLite）
+    #[serde(default)]
+    pub sqlite_busy_timeout_ms: Option<u64>,
 }
 
 impl Default for PartitionConfig {
@@ -72,6 +78,8 @@ impl Default for PartitionConfig {
             precreate_months: 3,
             retention_months: Some(12),
             table_prefix: "partitioned_".to_string(),
+            sqlite_wal_mode: None,
+            sqlite_busy_timeout_ms: None,
         }
     }
 }
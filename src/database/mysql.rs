@@ -162,6 +162,10 @@ impl MySQLPartitionManager {
 
 #[async_trait::async_trait]
 impl PartitionManager for MySQLPartitionManager {
+    fn partition_config(&self) -> &PartitionConfig {
+        &self.config
+    }
+
     async fn initialize_table(&self, table_name: &str, schema: &str) -> Result<()> {
         if self.config.enabled {
             // 创建分区表
@@ -262,10 +266,7 @@ impl PartitionManager for MySQLPartitionManager {
             )
         } else {
             // 没有更大的分区，直接添加
-            debug!(
-                "DEBUG: Appending new partition {} at the end",
-                partition_name
-            );
+            debug!("Appending new partition {} at the end", partition_name);
             format!(
                 "ALTER TABLE {} ADD PARTITION (PARTITION {} VALUES LESS THAN ({}))",
                 self.escape_identifier(&base_table),
@@ -0,0 +1,94 @@
+//! Copyright (c) 2025-2026, Kirky.X
+//!
+//! MIT License
+//!
+//! 该模块定义了分区表的后台维护任务。
+
+use super::PartitionManager;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, info, warn};
+
+/// 分区维护任务句柄
+///
+/// 由 [`PartitionManager::start_maintenance`] 返回，调用 [`stop`](Self::stop) 停止后台任务
+pub struct MaintenanceHandle {
+    /// 取消令牌
+    shutdown_token: CancellationToken,
+    /// 后台任务句柄
+    handle: JoinHandle<()>,
+}
+
+impl MaintenanceHandle {
+    /// 启动后台维护任务
+    pub(super) fn spawn<M>(manager: Arc<M>, table_name: String, interval: Duration) -> Self
+    where
+        M: PartitionManager + ?Sized + 'static,
+    {
+        let shutdown_token = CancellationToken::new();
+        let token = shutdown_token.clone();
+
+        let handle = tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            info!(
+                "分区维护任务已启动: table={}, interval={:?}",
+                table_name, interval
+            );
+
+            loop {
+                tokio::select! {
+                    _ = token.cancelled() => break,
+                    _ = ticker.tick() => run_maintenance(manager.as_ref(), &table_name).await,
+                }
+            }
+
+            info!("分区维护任务已停止: table={}", table_name);
+        });
+
+        Self {
+            shutdown_token,
+            handle,
+        }
+    }
+
+    /// 维护任务是否仍在运行
+    pub fn is_running(&self) -> bool {
+        !self.handle.is_finished()
+    }
+
+    /// 停止维护任务并等待当前维护周期结束
+    pub async fn stop(self) {
+        self.shutdown_token.cancel();
+        let _ = self.handle.await;
+    }
+}
+
+/// 执行一次维护：预创建未来分区并清理过期分区，失败时记录日志等待下一周期
+async fn run_maintenance<M>(manager: &M, table_name: &str)
+where
+    M: PartitionManager + ?Sized,
+{
+    let config = manager.partition_config();
+    let months_ahead = config.precreate_months;
+    let retention_months = config.retention_months;
+
+    if let Err(e) = manager.precreate_partitions(table_name, months_ahead).await {
+        warn!(
+            "预创建分区失败，将在下一周期重试: table={}, error={}",
+            table_name, e
+        );
+    }
+
+    if let Some(retention) = retention_months {
+        match manager.cleanup_old_partitions(table_name, retention).await {
+            Ok(dropped) => debug!("清理过期分区: table={}, dropped={}", table_name, dropped),
+            Err(e) => warn!(
+                "清理过期分区失败，将在下一周期重试: table={}, error={}",
+                table_name, e
+            ),
+        }
+    }
+}
@@ -4,13 +4,20 @@
 //!
 //! 分区管理器trait定义
 
+mod maintenance;
+
 use crate::error::Result;
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
+use futures::stream::{self, BoxStream, StreamExt, TryStreamExt};
 use serde::{Deserialize, Serialize};
-use tracing::debug;
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::{debug, trace};
 
-use super::PartitionInfo;
+use super::common::{is_partition_expired, retention_cutoff_date};
+use super::{PartitionConfig, PartitionInfo};
+pub use maintenance::MaintenanceHandle;
 
 /// 分区策略
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -24,6 +31,9 @@ pub enum PartitionStrategy {
 /// 分区管理器trait
 #[async_trait]
 pub trait PartitionManager: Send + Sync {
+    /// 获取分区配置
+    fn partition_config(&self) -> &PartitionConfig;
+
     /// 初始化分区表
     async fn initialize_table(&self, table_name: &str, schema: &str) -> Result<()>;
 
@@ -33,6 +43,23 @@ pub trait PartitionManager: Send + Sync {
     /// 获取所有分区
     async fn get_partitions(&self, table_name: &str) -> Result<Vec<PartitionInfo>>;
 
+    /// 以流的形式获取所有分区
+    ///
+    /// 默认实现包装 [`get_partitions`](Self::get_partitions) 的结果；
+    /// 分区数量较多的后端可以覆盖此方法逐行读取，避免一次性加载全部分区元数据。
+    fn get_partitions_stream<'a>(
+        &'a self,
+        table_name: &'a str,
+    ) -> BoxStream<'a, Result<PartitionInfo>> {
+        stream::once(async move {
+            self.get_partitions(table_name)
+                .await
+                .map(|partitions| stream::iter(partitions.into_iter().map(Ok)))
+        })
+        .try_flatten()
+        .boxed()
+    }
+
     /// 删除分区
     async fn drop_partition(&self, table_name: &str, partition_name: &str) -> Result<()>;
 
@@ -52,30 +79,53 @@ pub trait PartitionManager: Send + Sync {
         table_name: &str,
         retention_months: u32,
     ) -> Result<usize> {
-        let partitions = self.get_partitions(table_name).await?;
-        let cutoff_date = Utc::now() - chrono::Duration::days((retention_months * 30) as i64);
-
+        let cutoff_date = retention_cutoff_date(Utc::now(), retention_months);
         debug!("cleanup_old_partitions - cutoff_date: {}", cutoff_date);
-        debug!("found {} partitions", partitions.len());
-        for (i, partition) in partitions.iter().enumerate() {
-            debug!(
-                "partition[{}] - name: {}, end_date: {}, will_delete: {}",
-                i,
-                partition.name,
-                partition.end_date,
-                partition.end_date < cutoff_date
-            );
-        }
+
+        // 流式读取分区，仅保留需要删除的分区名
+        let expired: Vec<String> = self
+            .get_partitions_stream(table_name)
+            .try_filter_map(|partition| async move {
+                let expired = is_partition_expired(&partition, cutoff_date);
+                trace!(
+                    "partition {} - end_date: {}, will_delete: {}",
+                    partition.name,
+                    partition.end_date,
+                    expired
+                );
+                Ok(expired.then_some(partition.name))
+            })
+            .try_collect()
+            .await?;
 
         let mut dropped_count = 0;
-        for partition in partitions {
-            if partition.end_date < cutoff_date {
-                debug!("dropping partition: {}", partition.name);
-                self.drop_partition(table_name, &partition.name).await?;
-                dropped_count += 1;
-            }
+        for name in expired {
+            debug!("dropping partition: {}", name);
+            self.drop_partition(table_name, &name).await?;
+            dropped_count += 1;
         }
 
         Ok(dropped_count)
     }
+
+    /// 启动后台分区维护任务
+    ///
+    /// 每隔 `interval` 预创建未来 `precreate_months` 个月的分区，并在配置了
+    /// `retention_months` 时清理过期分区。首次维护在启动后立即执行，
+    /// 数据库暂时不可用时仅记录日志，等待下一周期重试。
+    ///
+    /// # 参数
+    ///
+    /// * `table_name` - 分区表名
+    /// * `interval` - 维护间隔
+    ///
+    /// # 返回值
+    ///
+    /// 返回用于停止维护任务的句柄
+    fn start_maintenance(self: Arc<Self>, table_name: &str, interval: Duration) -> MaintenanceHandle
+    where
+        Self: 'static,
+    {
+        MaintenanceHandle::spawn(self, table_name.to_string(), interval)
+    }
 }
@@ -8,7 +8,10 @@
 
 use crate::error::{CacheError, Result};
 use chrono::{DateTime, Datelike, NaiveDate, Utc};
-use sea_orm::{ConnectOptions, ConnectionTrait, Database, DatabaseConnection, Statement};
+use futures::stream::{self, BoxStream, StreamExt, TryStreamExt};
+use sea_orm::{
+    ConnectOptions, ConnectionTrait, Database, DatabaseConnection, Statement, StreamTrait,
+};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 use tokio::sync::Mutex;
@@ -314,6 +317,10 @@ impl PostgresPartitionManager {
 
 #[async_trait::async_trait]
 impl PartitionManager for PostgresPartitionManager {
+    fn partition_config(&self) -> &PartitionConfig {
+        &self.config
+    }
+
     async fn initialize_table(&self, table_name: &str, schema: &str) -> Result<()> {
         if self.config.enabled {
             self.create_partitioned_table(table_name, schema).await
@@ -423,6 +430,48 @@ impl PartitionManager for PostgresPartitionManager {
         Ok(partitions)
     }
 
+    /// 使用流式查询逐行读取分区元数据，不会一次性加载全部分区
+    fn get_partitions_stream<'a>(
+        &'a self,
+        table_name: &'a str,
+    ) -> BoxStream<'a, Result<PartitionInfo>> {
+        let sql = "SELECT
+                child.relname AS partition_name,
+                pg_get_expr(child.relpartbound, child.oid) AS partition_range
+             FROM pg_inherits
+             JOIN pg_class parent ON pg_inherits.inhparent = parent.oid
+             JOIN pg_class child ON pg_inherits.inhrelid = child.oid
+             WHERE parent.relname = $1";
+        let statement = Statement::from_sql_and_values(
+            sea_orm::DatabaseBackend::Postgres,
+            sql,
+            [table_name.into()],
+        );
+
+        stream::once(async move {
+            self.connection
+                .as_ref()
+                .stream(statement)
+                .await
+                .map(|rows| rows.map_err(CacheError::from))
+                .map_err(|e| {
+                    CacheError::DatabaseError(format!(
+                        "Failed to get partitions: {}. Please check if the table exists.",
+                        e
+                    ))
+                })
+        })
+        .try_flatten()
+        .try_filter_map(move |row| async move {
+            let partition_name: String = row.try_get("", "partition_name")?;
+            let partition_range: Option<String> = row.try_get("", "partition_range")?;
+            Ok(partition_range.and_then(|range_str| {
+                self.parse_postgres_partition_range(&partition_name, &range_str, table_name)
+            }))
+        })
+        .boxed()
+    }
+
     async fn drop_partition(&self, _table_name: &str, partition_name: &str) -> Result<()> {
         let conn = self.connection.as_ref();
 
@@ -454,15 +503,22 @@ impl PartitionManager for PostgresPartitionManager {
         table_name: &str,
         retention_months: u32,
     ) -> Result<usize> {
-        common_cleanup_old_partitions(
-            self,
-            table_name,
-            retention_months,
-            &self.config,
-            |m, t| m.get_partitions(t),
-            |m, t, p| m.drop_partition(t, p),
-        )
-        .await
+        let retention = self.config.retention_months.unwrap_or(retention_months);
+        let cutoff_date = self.calculate_cutoff_date(retention);
+
+        // 流式读取分区，仅保留需要删除的分区名
+        let expired: Vec<String> = self
+            .get_partitions_stream(table_name)
+            .try_filter_map(|partition| async move {
+                Ok(is_partition_expired(&partition, cutoff_date).then_some(partition.name))
+            })
+            .try_collect()
+            .await?;
+
+        for name in &expired {
+            self.drop_partition(table_name, name).await?;
+        }
+        Ok(expired.len())
     }
 
     async fn ensure_partition_exists(
@@ -12,6 +12,7 @@ use super::{
 use crate::error::{CacheError, Result};
 use async_trait::async_trait;
 use chrono::{DateTime, Datelike, Timelike, Utc};
+use sea_orm::sqlx::sqlite::SqliteJournalMode;
 use sea_orm::{ConnectOptions, ConnectionTrait, Database, DatabaseConnection, Statement};
 use std::sync::Arc;
 use tracing::debug;
@@ -95,6 +96,20 @@ impl SQLitePartitionManager {
             .min_connections(1)
             .connect_timeout(std::time::Duration::from_secs(30));
 
+        // SQLite 专用选项：每次建立连接时通过 PRAGMA 应用
+        let wal_mode = config.sqlite_wal_mode.unwrap_or(false);
+        let busy_timeout_ms = config.sqlite_busy_timeout_ms;
+        opt.map_sqlx_sqlite_opts(move |mut sqlite_opts| {
+            if wal_mode {
+                sqlite_opts = sqlite_opts.journal_mode(SqliteJournalMode::Wal);
+            }
+            if let Some(timeout_ms) = busy_timeout_ms {
+                sqlite_opts =
+                    sqlite_opts.busy_timeout(std::time::Duration::from_millis(timeout_ms));
+            }
+            sqlite_opts
+        });
+
         let connection = Database::connect(opt)
             .await
             .map_err(|e| CacheError::DatabaseError(format!("Failed to open database: {}", e)))?;
@@ -178,6 +193,10 @@ impl PartitionManagerExt for SQLitePartitionManager {
 
 #[async_trait]
 impl PartitionManager for SQLitePartitionManager {
+    fn partition_config(&self) -> &PartitionConfig {
+        &self.config
+    }
+
     async fn initialize_table(&self, table_name: &str, schema: &str) -> Result<()> {
         // 验证表名
         self.validate_identifier(table_name)?;
@@ -50,7 +50,15 @@ pub enum CacheError {
     /// Redis错误
     #[error("Redis connection failed: {0}. Please ensure Redis server is running and the connection string is correct."
     )]
-    RedisError(#[from] redis::RedisError),
+    RedisError(redis::RedisError),
+
+    /// 连接被拒绝错误
+    #[error("Connection refused: {0}. Please ensure the server is running and reachable.")]
+    ConnectionRefused(String),
+
+    /// 认证失败错误
+    #[error("Authentication failed: {0}. Please check the configured username and password.")]
+    AuthenticationFailed(String),
 
     /// IO错误
     #[error("I/O error: {0}. Check file permissions and disk space.")]
@@ -82,6 +90,10 @@ pub enum CacheError {
     )]
     BufferFull(String),
 
+    /// 背压错误
+    #[error("Backpressure: {0}. The write queue is at its maximum depth. Please retry later.")]
+    Backpressure(String),
+
     /// 无效输入错误
     #[error(
         "Invalid input: {0}. The provided input does not meet the required format or constraints."
@@ -91,6 +103,10 @@ pub enum CacheError {
     /// 无效键错误
     #[error("Invalid key: {0}. The provided key does not meet the required format or contains forbidden characters.")]
     InvalidKey(String),
+
+    /// 超出限制错误
+    #[error("Limit exceeded: {0}. The operation was rejected and the stored value is unchanged.")]
+    LimitExceeded(String),
 }
 
 /// 缓存操作结果类型别名
@@ -98,6 +114,40 @@ pub enum CacheError {
 /// 简化错误处理，所有缓存操作都返回此类型
 pub type Result<T> = std::result::Result<T, CacheError>;
 
+impl CacheError {
+    /// 判断错误是否为可重试的瞬时故障
+    ///
+    /// 超时与连接被拒绝通常在服务恢复后自行消失，认证失败等错误重试无意义
+    ///
+    /// # 返回值
+    ///
+    /// 返回错误是否可重试
+    pub fn is_transient(&self) -> bool {
+        match self {
+            CacheError::Timeout(_)
+            | CacheError::ConnectionRefused(_)
+            | CacheError::BackendError(_) => true,
+            CacheError::RedisError(e) => e.is_io_error() || e.is_connection_dropped(),
+            _ => false,
+        }
+    }
+}
+
+/// 按Redis错误类型映射为具体的缓存错误，便于调用方区分瞬时故障与致命错误
+impl From<redis::RedisError> for CacheError {
+    fn from(e: redis::RedisError) -> Self {
+        if e.kind() == redis::ErrorKind::AuthenticationFailed {
+            CacheError::AuthenticationFailed(e.to_string())
+        } else if e.is_timeout() {
+            CacheError::Timeout(e.to_string())
+        } else if e.is_connection_refusal() {
+            CacheError::ConnectionRefused(e.to_string())
+        } else {
+            CacheError::RedisError(e)
+        }
+    }
+}
+
 impl From<sea_orm::DbErr> for CacheError {
     fn from(e: sea_orm::DbErr) -> Self {
         CacheError::DatabaseError(e.to_string())
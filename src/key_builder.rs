@@ -0,0 +1,153 @@
+//! Copyright (c) 2025-2026, Kirky.X
+//!
+//! MIT License
+//!
+//! 缓存键构造器
+//!
+//! 供 `#[cached(key_builder = ...)]` 使用，由调用方完全控制缓存键的生成方式，
+//! 适用于未实现 `Debug` 或 `Debug` 输出不稳定的参数类型。
+//! 作为缓存键的类型也可以通过 `#[derive(CacheKey)]` 生成稳定的键编码，见 [`CacheKey`]
+
+use std::fmt::{Display, Write};
+
+/// 缓存键构造器
+///
+/// 参数以元组形式传入，元组中的每一项是被缓存函数对应参数的引用。
+/// 任何接收这些引用并返回 `String` 的函数或闭包都自动实现该trait：
+///
+/// ```
+/// use oxcache::KeyBuilder;
+///
+/// struct Query {
+///     table: String,
+///     id: u64,
+/// }
+///
+/// fn query_key(query: &Query, page: &u32) -> String {
+///     format!("query:{}:{}:{}", query.table, query.id, page)
+/// }
+///
+/// let query = Query { table: "users".to_string(), id: 7 };
+/// assert_eq!(query_key.build_key((&query, &2)), "query:users:7:2");
+/// ```
+pub trait KeyBuilder<Args> {
+    /// 根据参数构造缓存键
+    fn build_key(&self, args: Args) -> String;
+}
+
+macro_rules! impl_key_builder {
+    ($($arg:ident),*) => {
+        impl<F, $($arg),*> KeyBuilder<($($arg,)*)> for F
+        where
+            F: Fn($($arg),*) -> String,
+        {
+            #[allow(non_snake_case)]
+            fn build_key(&self, ($($arg,)*): ($($arg,)*)) -> String {
+                self($($arg),*)
+            }
+        }
+    };
+}
+
+impl_key_builder!();
+impl_key_builder!(A);
+impl_key_builder!(A, B);
+impl_key_builder!(A, B, C);
+impl_key_builder!(A, B, C, D);
+impl_key_builder!(A, B, C, D, E);
+impl_key_builder!(A, B, C, D, E, G);
+impl_key_builder!(A, B, C, D, E, G, H);
+impl_key_builder!(A, B, C, D, E, G, H, I);
+
+/// 可作为缓存键的类型
+///
+/// 通常由 `oxcache_macros` 的 `#[derive(CacheKey)]` 生成：键以类型名开头，
+/// 随后按声明顺序写入各字段的名称、值的字节长度与值（[`Display`] 输出），
+/// 例如 `Account:tenant:4:acme:id:1:7`。值带长度前缀，因此不同字段值的拼接不会产生相同的键。
+/// 字段可用 `#[cache_key(skip)]` 排除，或用 `#[cache_key(rename = "...")]` 改变键中的名称。
+///
+/// 配合 [`cache_key`] 可直接用作 `#[cached(key_builder = oxcache::key_builder::cache_key)]`
+///
+/// ```
+/// use oxcache::key_builder::{CacheKey, CacheKeyEncoder};
+///
+/// struct Account {
+///     tenant: String,
+///     id: u64,
+/// }
+///
+/// impl CacheKey for Account {
+///     fn cache_key(&self) -> String {
+///         CacheKeyEncoder::new("Account")
+///             .field("tenant", &self.tenant)
+///             .field("id", &self.id)
+///             .finish()
+///     }
+/// }
+///
+/// let account = Account { tenant: "acme".to_string(), id: 7 };
+/// assert_eq!(account.cache_key(), "Account:tenant:4:acme:id:1:7");
+/// ```
+pub trait CacheKey {
+    /// 生成缓存键
+    fn cache_key(&self) -> String;
+}
+
+/// 以 [`CacheKey`] 生成单个参数的缓存键，可作为 `key_builder` 使用
+///
+/// # 参数
+///
+/// * `value` - 实现了 [`CacheKey`] 的参数
+///
+/// # 返回值
+///
+/// 返回缓存键
+pub fn cache_key<T: CacheKey + ?Sized>(value: &T) -> String {
+    value.cache_key()
+}
+
+/// [`CacheKey`] 的键编码器
+///
+/// 按写入顺序编码字段，每个字段编码为 `:<名称>:<值的字节长度>:<值>`
+#[derive(Debug, Clone)]
+pub struct CacheKeyEncoder {
+    key: String,
+}
+
+impl CacheKeyEncoder {
+    /// 创建以类型名开头的编码器
+    ///
+    /// # 参数
+    ///
+    /// * `type_name` - 类型名称
+    pub fn new(type_name: &str) -> Self {
+        Self {
+            key: type_name.to_string(),
+        }
+    }
+
+    /// 写入一个字段
+    ///
+    /// # 参数
+    ///
+    /// * `name` - 字段在键中的名称
+    /// * `value` - 字段值
+    ///
+    /// # 返回值
+    ///
+    /// 返回写入字段后的编码器
+    pub fn field(mut self, name: &str, value: &(impl Display + ?Sized)) -> Self {
+        let value = value.to_string();
+        let _ = write!(self.key, ":{}:{}:{}", name, value.len(), value);
+        self
+    }
+
+    /// 完成编码
+    ///
+    /// # 返回值
+    ///
+    /// 返回缓存键
+    pub fn finish(self) -> String {
+        self.key
+    }
+}
@@ -13,6 +13,7 @@ pub use serde;
 pub use serde::{Deserialize, Serialize};
 pub use serde_json;
 pub use tokio;
+pub use tracing;
 
 pub mod backend;
 pub mod bloom_filter;
@@ -22,8 +23,11 @@ pub mod config;
 pub mod database;
 pub mod debug_test;
 pub mod error;
+pub mod key_builder;
 pub mod manager;
 pub mod metrics;
+#[cfg(feature = "metrics-server")]
+pub mod metrics_server;
 pub mod rate_limiting;
 pub mod recovery;
 pub mod serialization;
@@ -33,8 +37,9 @@ pub mod utils;
 // Re-export commonly used items
 pub use client::{CacheExt, CacheOps};
 pub use config::Config;
-pub use manager::{get_client, CacheManager};
-pub use sync::warmup::{WarmupManager, WarmupResult, WarmupStatus};
+pub use key_builder::{CacheKey, KeyBuilder};
+pub use manager::{get_client, CacheManager, ShutdownGuard};
+pub use sync::warmup::{WarmupBatch, WarmupManager, WarmupResult, WarmupStatus};
 
 /// oxcache 版本号
 pub const VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -4,14 +4,26 @@
 //!
 //! 该模块定义了缓存管理器，负责初始化和管理所有缓存客户端。
 
-use crate::backend::{l1::L1Backend, l2::L2Backend};
-use crate::client::{l1::L1Client, l2::L2Client, two_level::TwoLevelClient, CacheOps};
-use crate::config::{CacheType, Config, SerializationType};
+use crate::backend::{
+    l1::{EvictionListener, L1AutoTuner, L1Backend, MemoryPressureSignal},
+    l2::L2Backend,
+};
+use crate::client::{
+    l1::L1Client, l2::L2Client, null::NullClient, two_level::TwoLevelClient, CacheOps,
+};
+use crate::config::{CacheType, Config, L1Config, SerializationType, ServiceConfig};
 use crate::error::{CacheError, Result};
-use crate::serialization::{json::JsonSerializer, SerializerEnum};
+use crate::metrics::{KeyGrouper, GLOBAL_METRICS};
+use crate::recovery::health::HealthState;
+use crate::serialization::{
+    cbor::CborSerializer, json::JsonSerializer, EncryptedSerializer, FallbackSerializer,
+    SerializerEnum,
+};
 use dashmap::DashMap;
 use lazy_static::lazy_static;
-use std::sync::Arc;
+use secrecy::ExposeSecret;
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
 use tracing::{info, instrument, warn};
 
 /// 缓存管理器
@@ -26,6 +38,10 @@ pub struct CacheManager {
 
 lazy_static! {
     pub static ref MANAGER: Arc<DashMap<String, Arc<dyn CacheOps>>> = Arc::new(DashMap::new());
+    /// 已注册服务的配置，与 `MANAGER` 中的客户端同步增删
+    static ref SERVICE_CONFIGS: DashMap<String, ServiceConfig> = DashMap::new();
+    /// 宿主注册的内存压力信号，供L1容量自适应调优使用
+    static ref MEMORY_PRESSURE: RwLock<Option<MemoryPressureSignal>> = RwLock::new(None);
 }
 
 impl CacheManager {
@@ -62,17 +78,29 @@ impl CacheManager {
             //
             // 注意：优雅的 shutdown 机制已通过 shutdown_all() 函数实现。
 
-            let serializer = match service_cfg
-                .serialization
-                .as_ref()
-                .unwrap_or(&config.global.serialization)
-            {
-                SerializationType::Json => SerializerEnum::Json(JsonSerializer::new()),
-                SerializationType::Bincode => {
-                    return Err(CacheError::ConfigError(
-                        "Bincode serialization is not currently supported.".to_string(),
-                    ))
-                }
+            let serializer = Self::build_serializer(
+                name,
+                service_cfg
+                    .serialization
+                    .as_ref()
+                    .unwrap_or(&config.global.serialization),
+            )?;
+            let serializer = if service_cfg.read_fallback_serializers.is_empty() {
+                serializer
+            } else {
+                let fallbacks = service_cfg
+                    .read_fallback_serializers
+                    .iter()
+                    .map(|serialization| Self::build_serializer(name, serialization))
+                    .collect::<Result<Vec<_>>>()?;
+                SerializerEnum::WithFallback(FallbackSerializer::new(serializer, fallbacks))
+            };
+            let serializer = match &service_cfg.encryption {
+                Some(encryption) => SerializerEnum::Encrypted(EncryptedSerializer::new(
+                    serializer,
+                    encryption.key.expose_secret(),
+                )?),
+                None => serializer,
             };
 
             let client: Arc<dyn CacheOps> =
@@ -88,25 +116,33 @@ impl CacheManager {
                             CacheError::ConfigError(format!("缺少{}的TwoLevel配置", name))
                         })?;
 
-                        let l1 = Arc::new(L1Backend::new(l1_cfg.max_capacity));
+                        let l1 = Self::build_l1_backend(name, l1_cfg);
                         let l2 = Arc::new(L2Backend::new(l2_cfg).await?);
 
-                        Arc::new(
-                            TwoLevelClient::new(
-                                name.clone(),
-                                two_level_cfg.clone(),
-                                l1,
-                                l2,
-                                serializer,
-                            )
-                            .await?,
+                        let mut client = TwoLevelClient::new(
+                            name.clone(),
+                            two_level_cfg.clone(),
+                            l1,
+                            l2,
+                            serializer,
                         )
+                        .await?
+                        .with_key_mode(service_cfg.key_mode)
+                        .with_cache_epoch(service_cfg.cache_epoch)
+                        .with_metrics(
+                            service_cfg.enable_metrics,
+                            service_cfg.force_critical_metrics,
+                        );
+                        if let Some(extractor) = &service_cfg.key_group {
+                            client = client.with_key_grouper(KeyGrouper::new(extractor)?);
+                        }
+                        Arc::new(client)
                     }
                     CacheType::L1 => {
                         let l1_cfg = service_cfg.l1.as_ref().ok_or_else(|| {
                             CacheError::ConfigError(format!("缺少{}的L1配置", name))
                         })?;
-                        let l1 = Arc::new(L1Backend::new(l1_cfg.max_capacity));
+                        let l1 = Self::build_l1_backend(name, l1_cfg);
                         Arc::new(L1Client::new(name.clone(), l1, serializer))
                     }
                     CacheType::L2 => {
@@ -116,13 +152,259 @@ impl CacheManager {
                         let l2 = Arc::new(L2Backend::new(l2_cfg).await?);
                         Arc::new(L2Client::new(name.clone(), l2, serializer).await?)
                     }
+                    CacheType::Disabled => {
+                        info!("Cache disabled for service: {}", name);
+                        Arc::new(NullClient::new(name.clone()))
+                    }
                 };
 
             manager.insert(name.clone(), client);
+            SERVICE_CONFIGS.insert(name.clone(), service_cfg.clone());
         }
         Ok(())
     }
 
+    /// 在运行时注册单个服务
+    ///
+    /// 与 [`init`](Self::init) 相同地校验配置并创建客户端，服务未指定序列化类型时使用默认全局配置；
+    /// 同名服务已存在时被替换，旧客户端不会被关闭
+    ///
+    /// # 参数
+    ///
+    /// * `name` - 服务名称
+    /// * `config` - 服务配置
+    ///
+    /// # 返回值
+    ///
+    /// 返回注册结果
+    pub async fn register_service(name: impl Into<String>, config: ServiceConfig) -> Result<()> {
+        Self::init(Config {
+            services: HashMap::from([(name.into(), config)]),
+            ..Default::default()
+        })
+        .await
+    }
+
+    /// 注销服务并关闭其客户端
+    ///
+    /// # 参数
+    ///
+    /// * `name` - 服务名称
+    ///
+    /// # 返回值
+    ///
+    /// 服务存在时返回true，关闭客户端失败时返回相应的错误（服务仍会被注销）
+    pub async fn deregister_service(name: &str) -> Result<bool> {
+        SERVICE_CONFIGS.remove(name);
+        let Some((_, client)) = MANAGER.remove(name) else {
+            return Ok(false);
+        };
+        client.shutdown().await?;
+        Ok(true)
+    }
+
+    /// 获取所有已注册服务的名称
+    ///
+    /// 反映运行时注册与注销的服务
+    ///
+    /// # 返回值
+    ///
+    /// 返回按名称排序的服务列表
+    pub fn service_names() -> Vec<String> {
+        let mut names: Vec<String> = MANAGER.iter().map(|entry| entry.key().clone()).collect();
+        names.sort_unstable();
+        names
+    }
+
+    /// 获取已注册服务的配置
+    ///
+    /// # 参数
+    ///
+    /// * `name` - 服务名称
+    ///
+    /// # 返回值
+    ///
+    /// 返回隐去密码与密钥的配置副本（见 [`ServiceConfig::redacted`]），服务不存在时返回None
+    pub fn service_config(name: &str) -> Option<ServiceConfig> {
+        SERVICE_CONFIGS.get(name).map(|config| config.redacted())
+    }
+
+    /// 递增服务的缓存纪元，使该服务之前写入的所有键失效
+    ///
+    /// 见 [`TwoLevelClient::bump_epoch`]，只支持双层缓存服务
+    ///
+    /// # 参数
+    ///
+    /// * `service` - 服务名称
+    ///
+    /// # 返回值
+    ///
+    /// 返回新的纪元，服务不存在或不是双层缓存时返回错误
+    pub async fn bump_epoch(service: &str) -> Result<u64> {
+        let client = get_client(service)?;
+        let client = client
+            .as_any()
+            .downcast_ref::<TwoLevelClient>()
+            .ok_or_else(|| {
+                CacheError::NotSupported(format!(
+                    "Cache epoch is only supported for two-level services, '{}' is not one",
+                    service
+                ))
+            })?;
+        let epoch = client.bump_epoch().await?;
+        if let Some(mut config) = SERVICE_CONFIGS.get_mut(service) {
+            config.cache_epoch = epoch;
+        }
+        Ok(epoch)
+    }
+
+    /// 初始化缓存管理器并返回关闭守卫
+    ///
+    /// 与 [`init`](Self::init) 相同，额外返回持有本次初始化的所有服务客户端的 [`ShutdownGuard`]，
+    /// 守卫被丢弃或调用 `shutdown` 时停止这些客户端的后台任务（客户端不会从管理器中注销）
+    ///
+    /// # 参数
+    ///
+    /// * `config` - 缓存系统配置
+    ///
+    /// # 返回值
+    ///
+    /// 返回关闭守卫，初始化失败时返回相应的错误
+    pub async fn init_with_guard(config: Config) -> Result<ShutdownGuard> {
+        let services: Vec<String> = config.services.keys().cloned().collect();
+        Self::init(config).await?;
+        let clients = services
+            .into_iter()
+            .filter_map(|name| get_client(&name).ok().map(|client| (name, client)))
+            .collect();
+        Ok(ShutdownGuard::new(clients))
+    }
+
+    /// 根据L1配置构建L1缓存后端
+    ///
+    /// 按 `shards` 拆分为多个内部分片；
+    /// 启用淘汰监听时，淘汰事件按服务累加到 `l1_evictions_total` 指标；
+    /// 配置了 `auto_tune` 时以容量上限构建，并启动容量自适应调优任务
+    /// （读取 [`set_memory_pressure_signal`](Self::set_memory_pressure_signal) 注册的信号）
+    ///
+    /// # 参数
+    ///
+    /// * `service` - 服务名称
+    /// * `l1_cfg` - L1缓存配置
+    ///
+    /// # 返回值
+    ///
+    /// 返回L1缓存后端
+    fn build_l1_backend(service: &str, l1_cfg: &L1Config) -> Arc<L1Backend> {
+        let listener = l1_cfg.enable_eviction_listener.then(|| {
+            let service = service.to_string();
+            Arc::new(move |_key: &str| GLOBAL_METRICS.record_l1_eviction(&service))
+                as EvictionListener
+        });
+
+        let Some(auto_tune) = &l1_cfg.auto_tune else {
+            return Arc::new(L1Backend::new_sharded(
+                l1_cfg.max_capacity,
+                l1_cfg.l1_default_ttl,
+                listener,
+                l1_cfg.shards,
+            ));
+        };
+
+        let l1 = Arc::new(
+            L1Backend::new_sharded(
+                auto_tune.max_capacity,
+                l1_cfg.l1_default_ttl,
+                listener,
+                l1_cfg.shards,
+            )
+            .with_initial_capacity(l1_cfg.max_capacity),
+        );
+        L1AutoTuner::new(&l1, auto_tune.clone())
+            .with_service_name(service.to_string())
+            .with_pressure_signal(Arc::new(|| {
+                MEMORY_PRESSURE
+                    .read()
+                    .unwrap_or_else(|e| e.into_inner())
+                    .as_ref()
+                    .is_some_and(|signal| signal())
+            }))
+            .spawn();
+        l1
+    }
+
+    /// 注册内存压力信号
+    ///
+    /// 配置了L1 `auto_tune` 的服务在每个调优周期调用该信号，返回true时收缩L1容量；
+    /// 可在初始化前后任意时间注册或替换
+    ///
+    /// # 参数
+    ///
+    /// * `signal` - 内存压力信号，None表示清除
+    pub fn set_memory_pressure_signal(signal: Option<MemoryPressureSignal>) {
+        *MEMORY_PRESSURE.write().unwrap_or_else(|e| e.into_inner()) = signal;
+    }
+
+    /// 根据配置的序列化类型构建序列化器
+    ///
+    /// # 参数
+    ///
+    /// * `service` - 服务名称，用于错误信息
+    /// * `serialization` - 服务（或全局）配置的序列化类型
+    ///
+    /// # 返回值
+    ///
+    /// 返回对应的序列化器，不支持或未知的类型返回配置错误
+    fn build_serializer(
+        service: &str,
+        serialization: &SerializationType,
+    ) -> Result<SerializerEnum> {
+        match serialization {
+            SerializationType::Json => Ok(SerializerEnum::Json(JsonSerializer::new())),
+            SerializationType::Cbor => Ok(SerializerEnum::Cbor(CborSerializer::new())),
+            SerializationType::Bincode => Err(CacheError::ConfigError(
+                "Bincode serialization is not currently supported.".to_string(),
+            )),
+            SerializationType::Unknown(other) => Err(CacheError::Configuration(format!(
+                "Unknown serialization type '{}' for service '{}'",
+                other, service
+            ))),
+        }
+    }
+
+    /// 获取所有已注册服务的健康状态
+    ///
+    /// # 返回值
+    ///
+    /// 返回服务名称到当前健康状态的映射
+    pub async fn health_report() -> HashMap<String, HealthState> {
+        // 先复制客户端列表，避免在等待状态锁时持有 DashMap 的分片锁
+        let clients: Vec<(String, Arc<dyn CacheOps>)> = MANAGER
+            .iter()
+            .map(|entry| (entry.key().clone(), entry.value().clone()))
+            .collect();
+
+        let mut report = HashMap::with_capacity(clients.len());
+        for (name, client) in clients {
+            report.insert(name, client.health_state().await);
+        }
+        report
+    }
+
+    /// 判断缓存系统是否就绪
+    ///
+    /// 所有服务均处于健康或恢复中状态时视为就绪，可用于就绪探针
+    ///
+    /// # 返回值
+    ///
+    /// 就绪时返回true
+    pub async fn is_ready() -> bool {
+        Self::health_report()
+            .await
+            .values()
+            .all(HealthState::is_ready)
+    }
+
     /// 重置缓存管理器（仅用于测试）
     ///
     /// 清除所有已注册的客户端
@@ -133,6 +415,7 @@ impl CacheManager {
     #[doc(hidden)]
     pub fn reset() {
         MANAGER.clear();
+        SERVICE_CONFIGS.clear();
     }
 }
 
@@ -176,21 +459,93 @@ pub fn get_typed_client(service: &str) -> Result<Arc<TwoLevelClient>> {
     }
 }
 
-/// 优雅关闭所有缓存客户端
+/// 关闭守卫
 ///
-/// 遍历所有已注册的缓存客户端，调用它们的shutdown方法以释放资源
-/// 主要用于应用程序关闭时的清理工作
-#[instrument(level = "info")]
-pub async fn shutdown_all() -> Result<()> {
-    info!("开始关闭所有缓存客户端...");
+/// 持有一组缓存客户端，调用 [`shutdown`](Self::shutdown) 时逐个优雅关闭并返回结果。
+///
+/// `Drop` 不能执行异步操作，因此守卫在未调用 `shutdown` 就被丢弃时，
+/// 只会在当前Tokio运行时上派生一个分离的关闭任务（尽力而为）：
+/// 该任务不会被等待，进程紧接着退出时批量写入器中尚未刷新的条目可能丢失，
+/// 关闭错误也只记录日志；丢弃时不在Tokio运行时内则无法关闭，只输出警告。
+/// 需要确定的关闭时机与错误处理时应显式调用 `shutdown().await`
+#[must_use = "丢弃守卫会立即关闭其持有的客户端"]
+pub struct ShutdownGuard {
+    clients: Vec<(String, Arc<dyn CacheOps>)>,
+}
 
-    let mut errors = Vec::new();
+impl ShutdownGuard {
+    /// 创建关闭守卫
+    ///
+    /// # 参数
+    ///
+    /// * `clients` - 服务名称与缓存客户端列表
+    ///
+    /// # 返回值
+    ///
+    /// 返回新的关闭守卫
+    pub fn new(clients: Vec<(String, Arc<dyn CacheOps>)>) -> Self {
+        Self { clients }
+    }
+
+    /// 为单个客户端创建关闭守卫
+    ///
+    /// # 参数
+    ///
+    /// * `service` - 服务名称
+    /// * `client` - 缓存客户端
+    ///
+    /// # 返回值
+    ///
+    /// 返回新的关闭守卫
+    pub fn for_client(service: impl Into<String>, client: Arc<dyn CacheOps>) -> Self {
+        Self::new(vec![(service.into(), client)])
+    }
+
+    /// 优雅关闭守卫持有的所有客户端
+    ///
+    /// # 返回值
+    ///
+    /// 返回关闭结果，部分客户端关闭失败时返回 `CacheError::ShutdownError`
+    pub async fn shutdown(mut self) -> Result<()> {
+        shutdown_clients(std::mem::take(&mut self.clients)).await
+    }
+}
+
+impl Drop for ShutdownGuard {
+    fn drop(&mut self) {
+        if self.clients.is_empty() {
+            return;
+        }
+        let clients = std::mem::take(&mut self.clients);
+        match tokio::runtime::Handle::try_current() {
+            Ok(handle) => {
+                handle.spawn(async move {
+                    if let Err(e) = shutdown_clients(clients).await {
+                        warn!("关闭守卫后台关闭失败: {}", e);
+                    }
+                });
+            }
+            Err(_) => warn!(
+                "关闭守卫在Tokio运行时之外被丢弃，{} 个客户端的后台任务未能停止",
+                clients.len()
+            ),
+        }
+    }
+}
 
-    // 遍历所有客户端并关闭它们
-    for entry in MANAGER.iter() {
-        let service_name = entry.key();
-        let client = entry.value();
+/// 逐个关闭缓存客户端并汇总错误
+///
+/// # 参数
+///
+/// * `clients` - 服务名称与缓存客户端列表
+///
+/// # 返回值
+///
+/// 返回关闭结果，部分客户端关闭失败时返回 `CacheError::ShutdownError`
+async fn shutdown_clients(clients: Vec<(String, Arc<dyn CacheOps>)>) -> Result<()> {
+    let mut errors = Vec::new();
 
+    for (service_name, client) in clients {
         info!("正在关闭服务: {}", service_name);
 
         match client.shutdown().await {
@@ -204,11 +559,7 @@ pub async fn shutdown_all() -> Result<()> {
         }
     }
 
-    // 清空管理器
-    MANAGER.clear();
-
     if errors.is_empty() {
-        info!("所有缓存客户端已成功关闭");
         Ok(())
     } else {
         Err(CacheError::ShutdownError(format!(
@@ -217,3 +568,28 @@ pub async fn shutdown_all() -> Result<()> {
         )))
     }
 }
+
+/// 优雅关闭所有缓存客户端
+///
+/// 遍历所有已注册的缓存客户端，调用它们的shutdown方法以释放资源
+/// 主要用于应用程序关闭时的清理工作
+#[instrument(level = "info")]
+pub async fn shutdown_all() -> Result<()> {
+    info!("开始关闭所有缓存客户端...");
+
+    // 先复制客户端列表，避免在关闭期间持有 DashMap 的分片锁
+    let clients: Vec<(String, Arc<dyn CacheOps>)> = MANAGER
+        .iter()
+        .map(|entry| (entry.key().clone(), entry.value().clone()))
+        .collect();
+    let result = shutdown_clients(clients).await;
+
+    // 清空管理器
+    MANAGER.clear();
+    SERVICE_CONFIGS.clear();
+
+    if result.is_ok() {
+        info!("所有缓存客户端已成功关闭");
+    }
+    result
+}
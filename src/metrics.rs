@@ -4,10 +4,16 @@
 //!
 //! 该模块定义了缓存系统的指标收集和监控功能。
 
+use crate::config::KeyGroupExtractor;
+use crate::error::{CacheError, Result};
 use dashmap::DashMap;
+use futures::Stream;
 use lazy_static::lazy_static;
+use std::collections::HashMap;
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::MissedTickBehavior;
 use tracing::{span, Level};
 
 /// 原子计数器集合
@@ -75,6 +81,229 @@ pub struct Metrics {
     pub batch_success_rate: Arc<DashMap<String, f64>>,
     /// 批量写入吞吐量 (ops/sec)
     pub batch_throughput: Arc<DashMap<String, f64>>,
+    /// 因背压被丢弃的批量写入条目数
+    pub batch_dropped_total: Arc<DashMap<String, u64>>,
+    /// L1缓存条目数
+    pub l1_entries: Arc<DashMap<String, u64>>,
+    /// L1因容量或TTL淘汰的条目数
+    pub l1_evictions_total: Arc<DashMap<String, u64>>,
+    /// L1当前生效的容量（启用自适应调优时记录）
+    pub l1_capacity: Arc<DashMap<String, u64>>,
+    /// 失效订阅连接状态（1=已连接，0=断开重连中）
+    pub invalidation_subscriber_connected: Arc<DashMap<String, u8>>,
+    /// L2写入暂停状态（1=已暂停，写入转入WAL；0=正常）
+    pub l2_writes_paused: Arc<DashMap<String, u8>>,
+    /// L2命中推广到L1的次数
+    pub promotions_total: Arc<DashMap<String, u64>>,
+    /// 被跳过的推广次数（已在L1中、正在推广或TTL过短）
+    pub promotions_skipped_total: Arc<DashMap<String, u64>>,
+    /// 正在处理的推广任务数
+    pub promotion_queue_depth: Arc<DashMap<String, usize>>,
+    /// 因推广任务数达到上限而被丢弃的推广次数
+    pub promotions_dropped_total: Arc<DashMap<String, u64>>,
+    /// 当前占用的推广任务数
+    pub promotion_active_tasks: Arc<DashMap<String, usize>>,
+    /// WAL重放的条目数，key: "service:outcome"（replayed/failed/skipped）
+    pub wal_replay_entries_total: Arc<DashMap<String, u64>>,
+    /// 按服务统计的读取命中/未命中次数，key: "service:layer:result"（hit/miss）
+    pub get_results_total: Arc<DashMap<String, u64>>,
+    /// 按键分组统计的请求数，key: "service:layer:op:result:key_group"
+    pub key_group_requests_total: Arc<DashMap<String, u64>>,
+    /// L1有效TTL明显长于L2的写入次数
+    pub ttl_divergence_total: Arc<DashMap<String, u64>>,
+}
+
+/// 指标快照
+///
+/// 由 [`Metrics::snapshot`] 或 [`Metrics::snapshot_and_reset`] 生成
+#[derive(Clone, Debug, Default)]
+pub struct MetricsSnapshot {
+    /// L1缓存命中次数
+    pub l1_get_hits: u64,
+    /// L1缓存未命中次数
+    pub l1_get_misses: u64,
+    /// L2缓存命中次数
+    pub l2_get_hits: u64,
+    /// L2缓存未命中次数
+    pub l2_get_misses: u64,
+    /// L1缓存设置次数
+    pub l1_set_total: u64,
+    /// L2缓存设置次数
+    pub l2_set_total: u64,
+    /// L1缓存删除次数
+    pub l1_delete_total: u64,
+    /// L2缓存删除次数
+    pub l2_delete_total: u64,
+    /// 总操作次数
+    pub total_operations: u64,
+    /// 请求总数统计，key: "service:layer:op:result"
+    pub requests_total: HashMap<String, u64>,
+    /// L2健康状态
+    pub l2_health_status: HashMap<String, u8>,
+    /// WAL条目数
+    pub wal_entries: HashMap<String, usize>,
+    /// 操作耗时，key: "service:layer:op" -> (total_duration_secs, count)
+    pub operation_duration: HashMap<String, (f64, u64)>,
+    /// 批量写入缓冲区大小
+    pub batch_buffer_size: HashMap<String, usize>,
+    /// 批量写入成功率
+    pub batch_success_rate: HashMap<String, f64>,
+    /// 批量写入吞吐量 (ops/sec)
+    pub batch_throughput: HashMap<String, f64>,
+    /// 因背压被丢弃的批量写入条目数
+    pub batch_dropped_total: HashMap<String, u64>,
+    /// L1缓存条目数
+    pub l1_entries: HashMap<String, u64>,
+    /// L1因容量或TTL淘汰的条目数
+    pub l1_evictions_total: HashMap<String, u64>,
+    /// L1当前生效的容量
+    pub l1_capacity: HashMap<String, u64>,
+    /// 失效订阅连接状态
+    pub invalidation_subscriber_connected: HashMap<String, u8>,
+    /// L2写入暂停状态
+    pub l2_writes_paused: HashMap<String, u8>,
+    /// L2命中推广到L1的次数
+    pub promotions_total: HashMap<String, u64>,
+    /// 被跳过的推广次数
+    pub promotions_skipped_total: HashMap<String, u64>,
+    /// 正在处理的推广任务数
+    pub promotion_queue_depth: HashMap<String, usize>,
+    /// 被丢弃的推广次数
+    pub promotions_dropped_total: HashMap<String, u64>,
+    /// 当前占用的推广任务数
+    pub promotion_active_tasks: HashMap<String, usize>,
+    /// WAL重放的条目数，key: "service:outcome"
+    pub wal_replay_entries_total: HashMap<String, u64>,
+    /// 按服务统计的读取命中/未命中次数，key: "service:layer:result"
+    pub get_results_total: HashMap<String, u64>,
+    /// 按键分组统计的请求数，key: "service:layer:op:result:key_group"
+    pub key_group_requests_total: HashMap<String, u64>,
+    /// L1有效TTL明显长于L2的写入次数
+    pub ttl_divergence_total: HashMap<String, u64>,
+}
+
+impl MetricsSnapshot {
+    /// 计算服务在指定缓存层的读取命中率
+    ///
+    /// # 参数
+    ///
+    /// * `service` - 服务名称
+    /// * `layer` - 缓存层（L1/L2）
+    ///
+    /// # 返回值
+    ///
+    /// 返回0.0到1.0之间的命中率，没有读取记录时返回None
+    pub fn hit_ratio(&self, service: &str, layer: &str) -> Option<f64> {
+        let count = |result: &str| {
+            self.get_results_total
+                .get(&format!("{}:{}:{}", service, layer, result))
+                .copied()
+                .unwrap_or(0)
+        };
+        let hits = count("hit");
+        let total = hits + count("miss");
+        (total > 0).then(|| hits as f64 / total as f64)
+    }
+
+    /// 计算操作的平均耗时
+    ///
+    /// # 参数
+    ///
+    /// * `service` - 服务名称
+    /// * `layer` - 缓存层
+    /// * `op` - 操作类型
+    ///
+    /// # 返回值
+    ///
+    /// 返回平均耗时（秒），没有耗时记录时返回None
+    pub fn average_latency(&self, service: &str, layer: &str, op: &str) -> Option<f64> {
+        self.operation_duration
+            .get(&format!("{}:{}:{}", service, layer, op))
+            .filter(|(_, count)| *count > 0)
+            .map(|(total, count)| total / *count as f64)
+    }
+}
+
+/// 未匹配任何分组的键所归入的分组
+pub const OTHER_KEY_GROUP: &str = "other";
+
+/// 缓存键分组提取器
+///
+/// 由 [`KeyGroupExtractor`] 配置构建，将缓存键映射为粗粒度的指标分组
+#[derive(Clone, Debug)]
+pub enum KeyGrouper {
+    /// 按分隔符取前若干段
+    Prefix {
+        /// 分隔符
+        delimiter: String,
+        /// 保留的段数
+        segments: usize,
+    },
+    /// 按正则表达式提取
+    Regex(regex::Regex),
+}
+
+impl KeyGrouper {
+    /// 根据配置创建分组提取器
+    ///
+    /// # 参数
+    ///
+    /// * `extractor` - 键分组提取方式
+    ///
+    /// # 返回值
+    ///
+    /// 返回分组提取器；分隔符为空、段数为0或正则表达式无效时返回配置错误
+    pub fn new(extractor: &KeyGroupExtractor) -> Result<Self> {
+        match extractor {
+            KeyGroupExtractor::Prefix {
+                delimiter,
+                segments,
+            } => {
+                if delimiter.is_empty() || *segments == 0 {
+                    return Err(CacheError::Configuration(
+                        "Key group prefix requires a non-empty delimiter and at least one segment"
+                            .to_string(),
+                    ));
+                }
+                Ok(KeyGrouper::Prefix {
+                    delimiter: delimiter.clone(),
+                    segments: *segments,
+                })
+            }
+            KeyGroupExtractor::Regex { pattern } => regex::Regex::new(pattern)
+                .map(KeyGrouper::Regex)
+                .map_err(|e| {
+                    CacheError::Configuration(format!("Invalid key group pattern: {}", e))
+                }),
+        }
+    }
+
+    /// 提取缓存键所属的分组
+    ///
+    /// # 参数
+    ///
+    /// * `key` - 缓存键
+    ///
+    /// # 返回值
+    ///
+    /// 返回分组名称；分隔符数量不足或正则不匹配的键返回 [`OTHER_KEY_GROUP`]，
+    /// 避免未分组的键以完整键名作为标签
+    pub fn group<'a>(&self, key: &'a str) -> &'a str {
+        match self {
+            KeyGrouper::Prefix {
+                delimiter,
+                segments,
+            } => match key.match_indices(delimiter.as_str()).nth(segments - 1) {
+                Some((end, _)) => &key[..end],
+                None => OTHER_KEY_GROUP,
+            },
+            KeyGrouper::Regex(regex) => regex
+                .captures(key)
+                .and_then(|caps| caps.get(1).or_else(|| caps.get(0)))
+                .map(|m| m.as_str())
+                .unwrap_or(OTHER_KEY_GROUP),
+        }
+    }
 }
 
 lazy_static! {
@@ -98,6 +327,13 @@ impl Metrics {
         let span = span!(Level::INFO, "cache_request", service, layer, op, result);
         let _enter = span.enter();
 
+        if op == "get" && matches!(result, "hit" | "miss") {
+            self.get_results_total
+                .entry(format!("{}:{}:{}", service, layer, result))
+                .and_modify(|v| *v += 1)
+                .or_insert(1);
+        }
+
         // 使用原子计数器处理高频指标
         match (layer, op, result) {
             ("L1", "get", "hit") => {
@@ -171,6 +407,33 @@ impl Metrics {
             .or_insert(1);
     }
 
+    /// 按键分组记录请求指标
+    ///
+    /// 与 [`record_request`](Self::record_request) 配合使用，额外以 `key_group` 标签统计，
+    /// 分组由 [`KeyGrouper`] 从缓存键中提取
+    ///
+    /// # 参数
+    ///
+    /// * `service` - 服务名称
+    /// * `layer` - 缓存层（L1/L2）
+    /// * `op` - 操作类型
+    /// * `result` - 操作结果
+    /// * `key_group` - 键分组
+    pub fn record_key_group_request(
+        &self,
+        service: &str,
+        layer: &str,
+        op: &str,
+        result: &str,
+        key_group: &str,
+    ) {
+        let key = format!("{}:{}:{}:{}:{}", service, layer, op, result, key_group);
+        self.key_group_requests_total
+            .entry(key)
+            .and_modify(|v| *v += 1)
+            .or_insert(1);
+    }
+
     /// 记录操作耗时
     pub fn record_duration(&self, service: &str, layer: &str, op: &str, duration_secs: f64) {
         let key = format!("{}:{}:{}", service, layer, op);
@@ -219,6 +482,239 @@ impl Metrics {
             .insert(service.to_string(), throughput);
     }
 
+    /// 记录因背压被丢弃的批量写入条目
+    pub fn record_batch_dropped(&self, service: &str) {
+        self.batch_dropped_total
+            .entry(service.to_string())
+            .and_modify(|v| *v += 1)
+            .or_insert(1);
+    }
+
+    /// 设置L1缓存条目数
+    pub fn set_l1_entries(&self, service: &str, entries: u64) {
+        self.l1_entries.insert(service.to_string(), entries);
+    }
+
+    /// 设置L1当前生效的容量
+    pub fn set_l1_capacity(&self, service: &str, capacity: u64) {
+        self.l1_capacity.insert(service.to_string(), capacity);
+    }
+
+    /// 设置L2写入暂停状态
+    pub fn set_l2_writes_paused(&self, service: &str, paused: bool) {
+        self.l2_writes_paused
+            .insert(service.to_string(), u8::from(paused));
+    }
+
+    /// 记录L1淘汰事件
+    pub fn record_l1_eviction(&self, service: &str) {
+        self.l1_evictions_total
+            .entry(service.to_string())
+            .and_modify(|v| *v += 1)
+            .or_insert(1);
+    }
+
+    /// 记录L1有效TTL明显长于L2的写入
+    pub fn record_ttl_divergence(&self, service: &str) {
+        self.ttl_divergence_total
+            .entry(service.to_string())
+            .and_modify(|v| *v += 1)
+            .or_insert(1);
+    }
+
+    /// 设置失效订阅连接状态
+    pub fn set_invalidation_subscriber_connected(&self, service: &str, connected: bool) {
+        self.invalidation_subscriber_connected
+            .insert(service.to_string(), u8::from(connected));
+    }
+
+    /// 记录一次完成的推广
+    pub fn record_promotion(&self, service: &str) {
+        self.promotions_total
+            .entry(service.to_string())
+            .and_modify(|v| *v += 1)
+            .or_insert(1);
+    }
+
+    /// 记录一次被跳过的推广
+    pub fn record_promotion_skipped(&self, service: &str) {
+        self.promotions_skipped_total
+            .entry(service.to_string())
+            .and_modify(|v| *v += 1)
+            .or_insert(1);
+    }
+
+    /// 设置正在处理的推广任务数
+    pub fn set_promotion_queue_depth(&self, service: &str, depth: usize) {
+        self.promotion_queue_depth
+            .insert(service.to_string(), depth);
+    }
+
+    /// 记录一次因推广任务数达到上限而被丢弃的推广
+    pub fn record_promotion_dropped(&self, service: &str) {
+        self.promotions_dropped_total
+            .entry(service.to_string())
+            .and_modify(|v| *v += 1)
+            .or_insert(1);
+    }
+
+    /// 设置当前占用的推广任务数
+    pub fn set_promotion_active_tasks(&self, service: &str, active: usize) {
+        self.promotion_active_tasks
+            .insert(service.to_string(), active);
+    }
+
+    /// 记录WAL重放的条目数
+    ///
+    /// # 参数
+    ///
+    /// * `service` - 服务名称
+    /// * `outcome` - 重放结果（replayed/failed/skipped）
+    /// * `count` - 条目数
+    pub fn record_wal_replay(&self, service: &str, outcome: &str, count: u64) {
+        self.wal_replay_entries_total
+            .entry(format!("{}:{}", service, outcome))
+            .and_modify(|v| *v += count)
+            .or_insert(count);
+    }
+
+    /// 重置所有指标
+    ///
+    /// 计数器归零，按服务记录的计数与状态全部清空。
+    /// 线程安全：每个计数器和每个条目的清除都是原子的，与并发的记录操作交错时，
+    /// 重置期间记录的值可能保留到下一周期，但不会导致数据结构损坏；
+    /// 各指标之间不保证处于同一时间点
+    pub fn reset(&self) {
+        self.snapshot_and_reset();
+    }
+
+    /// 重置指定服务的指标
+    ///
+    /// 只清除以该服务为标签的条目；全局原子计数器不区分服务，不受影响。
+    /// 线程安全性同 [`reset`](Self::reset)
+    ///
+    /// # 参数
+    ///
+    /// * `service` - 服务名称
+    pub fn reset_service(&self, service: &str) {
+        let prefix = format!("{}:", service);
+        self.requests_total.retain(|k, _| !k.starts_with(&prefix));
+        self.operation_duration
+            .retain(|k, _| !k.starts_with(&prefix));
+        self.l2_health_status.remove(service);
+        self.wal_entries.remove(service);
+        self.batch_buffer_size.remove(service);
+        self.batch_success_rate.remove(service);
+        self.batch_throughput.remove(service);
+        self.batch_dropped_total.remove(service);
+        self.l1_entries.remove(service);
+        self.l1_evictions_total.remove(service);
+        self.l1_capacity.remove(service);
+        self.invalidation_subscriber_connected.remove(service);
+        self.l2_writes_paused.remove(service);
+        self.promotions_total.remove(service);
+        self.promotions_skipped_total.remove(service);
+        self.promotion_queue_depth.remove(service);
+        self.promotions_dropped_total.remove(service);
+        self.promotion_active_tasks.remove(service);
+        self.wal_replay_entries_total
+            .retain(|k, _| !k.starts_with(&prefix));
+        self.get_results_total
+            .retain(|k, _| !k.starts_with(&prefix));
+        self.key_group_requests_total
+            .retain(|k, _| !k.starts_with(&prefix));
+        self.ttl_divergence_total.remove(service);
+    }
+
+    /// 获取当前指标快照
+    ///
+    /// 与 [`snapshot_and_reset`](Self::snapshot_and_reset) 不同，不会修改任何指标。
+    /// 快照中的各指标不保证处于同一时间点
+    ///
+    /// # 返回值
+    ///
+    /// 返回当前的指标值
+    pub fn snapshot(&self) -> MetricsSnapshot {
+        MetricsSnapshot {
+            l1_get_hits: self.counters.l1_get_hits.load(Ordering::Relaxed),
+            l1_get_misses: self.counters.l1_get_misses.load(Ordering::Relaxed),
+            l2_get_hits: self.counters.l2_get_hits.load(Ordering::Relaxed),
+            l2_get_misses: self.counters.l2_get_misses.load(Ordering::Relaxed),
+            l1_set_total: self.counters.l1_set_total.load(Ordering::Relaxed),
+            l2_set_total: self.counters.l2_set_total.load(Ordering::Relaxed),
+            l1_delete_total: self.counters.l1_delete_total.load(Ordering::Relaxed),
+            l2_delete_total: self.counters.l2_delete_total.load(Ordering::Relaxed),
+            total_operations: self.counters.total_operations.load(Ordering::Relaxed),
+            requests_total: collect(&self.requests_total),
+            l2_health_status: collect(&self.l2_health_status),
+            wal_entries: collect(&self.wal_entries),
+            operation_duration: collect(&self.operation_duration),
+            batch_buffer_size: collect(&self.batch_buffer_size),
+            batch_success_rate: collect(&self.batch_success_rate),
+            batch_throughput: collect(&self.batch_throughput),
+            batch_dropped_total: collect(&self.batch_dropped_total),
+            l1_entries: collect(&self.l1_entries),
+            l1_evictions_total: collect(&self.l1_evictions_total),
+            l1_capacity: collect(&self.l1_capacity),
+            invalidation_subscriber_connected: collect(&self.invalidation_subscriber_connected),
+            l2_writes_paused: collect(&self.l2_writes_paused),
+            promotions_total: collect(&self.promotions_total),
+            promotions_skipped_total: collect(&self.promotions_skipped_total),
+            promotion_queue_depth: collect(&self.promotion_queue_depth),
+            promotions_dropped_total: collect(&self.promotions_dropped_total),
+            promotion_active_tasks: collect(&self.promotion_active_tasks),
+            wal_replay_entries_total: collect(&self.wal_replay_entries_total),
+            get_results_total: collect(&self.get_results_total),
+            key_group_requests_total: collect(&self.key_group_requests_total),
+            ttl_divergence_total: collect(&self.ttl_divergence_total),
+        }
+    }
+
+    /// 获取当前指标快照并重置
+    ///
+    /// 供周期性上报使用：计数器通过原子交换取值并归零，按服务的条目逐个移除并收集到快照中，
+    /// 因此并发记录的增量要么计入本次快照，要么保留到下一周期，不会丢失。
+    /// 快照中的各指标不保证处于同一时间点
+    ///
+    /// # 返回值
+    ///
+    /// 返回重置前的指标值
+    pub fn snapshot_and_reset(&self) -> MetricsSnapshot {
+        MetricsSnapshot {
+            l1_get_hits: self.counters.l1_get_hits.swap(0, Ordering::Relaxed),
+            l1_get_misses: self.counters.l1_get_misses.swap(0, Ordering::Relaxed),
+            l2_get_hits: self.counters.l2_get_hits.swap(0, Ordering::Relaxed),
+            l2_get_misses: self.counters.l2_get_misses.swap(0, Ordering::Relaxed),
+            l1_set_total: self.counters.l1_set_total.swap(0, Ordering::Relaxed),
+            l2_set_total: self.counters.l2_set_total.swap(0, Ordering::Relaxed),
+            l1_delete_total: self.counters.l1_delete_total.swap(0, Ordering::Relaxed),
+            l2_delete_total: self.counters.l2_delete_total.swap(0, Ordering::Relaxed),
+            total_operations: self.counters.total_operations.swap(0, Ordering::Relaxed),
+            requests_total: drain(&self.requests_total),
+            l2_health_status: drain(&self.l2_health_status),
+            wal_entries: drain(&self.wal_entries),
+            operation_duration: drain(&self.operation_duration),
+            batch_buffer_size: drain(&self.batch_buffer_size),
+            batch_success_rate: drain(&self.batch_success_rate),
+            batch_throughput: drain(&self.batch_throughput),
+            batch_dropped_total: drain(&self.batch_dropped_total),
+            l1_entries: drain(&self.l1_entries),
+            l1_evictions_total: drain(&self.l1_evictions_total),
+            l1_capacity: drain(&self.l1_capacity),
+            invalidation_subscriber_connected: drain(&self.invalidation_subscriber_connected),
+            l2_writes_paused: drain(&self.l2_writes_paused),
+            promotions_total: drain(&self.promotions_total),
+            promotions_skipped_total: drain(&self.promotions_skipped_total),
+            promotion_queue_depth: drain(&self.promotion_queue_depth),
+            promotions_dropped_total: drain(&self.promotions_dropped_total),
+            promotion_active_tasks: drain(&self.promotion_active_tasks),
+            wal_replay_entries_total: drain(&self.wal_replay_entries_total),
+            get_results_total: drain(&self.get_results_total),
+            key_group_requests_total: drain(&self.key_group_requests_total),
+            ttl_divergence_total: drain(&self.ttl_divergence_total),
+        }
+    }
+
     /// 获取原子计数器的值
     pub fn get_counters(&self) -> (u64, u64, u64, u64, u64, u64, u64, u64, u64) {
         (
@@ -235,6 +731,42 @@ impl Metrics {
     }
 }
 
+/// 复制映射中的条目
+fn collect<V: Clone>(map: &DashMap<String, V>) -> HashMap<String, V> {
+    map.iter()
+        .map(|entry| (entry.key().clone(), entry.value().clone()))
+        .collect()
+}
+
+/// 订阅周期性的全局指标快照
+///
+/// 基于 `tokio::time::interval`，第一个快照立即产生，之后每隔 `period` 产生一个
+/// [`GLOBAL_METRICS`] 的快照；订阅不会重置任何指标。消费者处理过慢时错过的周期不会补发
+///
+/// # 参数
+///
+/// * `period` - 快照间隔，不能为0
+///
+/// # 返回值
+///
+/// 返回无限的快照流，丢弃即取消订阅
+pub fn subscribe(period: Duration) -> impl Stream<Item = MetricsSnapshot> {
+    let mut interval = tokio::time::interval(period);
+    interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+    futures::stream::unfold(interval, |mut interval| async move {
+        interval.tick().await;
+        Some((GLOBAL_METRICS.snapshot(), interval))
+    })
+}
+
+/// 逐个移除并收集映射中的条目
+fn drain<V>(map: &DashMap<String, V>) -> HashMap<String, V> {
+    let keys: Vec<String> = map.iter().map(|entry| entry.key().clone()).collect();
+    keys.into_iter()
+        .filter_map(|key| map.remove(&key))
+        .collect()
+}
+
 /// 获取指标字符串
 ///
 /// 将所有指标格式化为字符串返回，用于监控系统采集
@@ -326,5 +858,139 @@ pub fn get_metrics_string() -> String {
         ));
     }
 
+    for entry in metrics.batch_dropped_total.iter() {
+        output.push_str(&format!(
+            "cache_batch_write_dropped_total{{service=\"{}\"}} {}\n",
+            entry.key(),
+            entry.value()
+        ));
+    }
+
+    for entry in metrics.l1_entries.iter() {
+        output.push_str(&format!(
+            "cache_l1_entries{{service=\"{}\"}} {}\n",
+            entry.key(),
+            entry.value()
+        ));
+    }
+
+    for entry in metrics.l1_evictions_total.iter() {
+        output.push_str(&format!(
+            "cache_l1_evictions_total{{service=\"{}\"}} {}\n",
+            entry.key(),
+            entry.value()
+        ));
+    }
+
+    for entry in metrics.l1_capacity.iter() {
+        output.push_str(&format!(
+            "cache_l1_capacity{{service=\"{}\"}} {}\n",
+            entry.key(),
+            entry.value()
+        ));
+    }
+
+    for entry in metrics.invalidation_subscriber_connected.iter() {
+        output.push_str(&format!(
+            "cache_invalidation_subscriber_connected{{service=\"{}\"}} {}\n",
+            entry.key(),
+            entry.value()
+        ));
+    }
+
+    for entry in metrics.l2_writes_paused.iter() {
+        output.push_str(&format!(
+            "cache_l2_writes_paused{{service=\"{}\"}} {}\n",
+            entry.key(),
+            entry.value()
+        ));
+    }
+
+    for entry in metrics.promotions_total.iter() {
+        output.push_str(&format!(
+            "cache_promotions_total{{service=\"{}\"}} {}\n",
+            entry.key(),
+            entry.value()
+        ));
+    }
+
+    for entry in metrics.ttl_divergence_total.iter() {
+        output.push_str(&format!(
+            "cache_ttl_divergence_total{{service=\"{}\"}} {}\n",
+            entry.key(),
+            entry.value()
+        ));
+    }
+
+    for entry in metrics.promotions_skipped_total.iter() {
+        output.push_str(&format!(
+            "cache_promotions_skipped_total{{service=\"{}\"}} {}\n",
+            entry.key(),
+            entry.value()
+        ));
+    }
+
+    for entry in metrics.promotion_queue_depth.iter() {
+        output.push_str(&format!(
+            "cache_promotion_queue_depth{{service=\"{}\"}} {}\n",
+            entry.key(),
+            entry.value()
+        ));
+    }
+
+    for entry in metrics.promotions_dropped_total.iter() {
+        output.push_str(&format!(
+            "cache_promotions_dropped_total{{service=\"{}\"}} {}\n",
+            entry.key(),
+            entry.value()
+        ));
+    }
+
+    for entry in metrics.promotion_active_tasks.iter() {
+        output.push_str(&format!(
+            "cache_promotion_active_tasks{{service=\"{}\"}} {}\n",
+            entry.key(),
+            entry.value()
+        ));
+    }
+
+    for entry in metrics.get_results_total.iter() {
+        let parts: Vec<&str> = entry.key().rsplitn(3, ':').collect();
+        if parts.len() == 3 {
+            output.push_str(&format!(
+                "cache_get_results_total{{service=\"{}\",layer=\"{}\",result=\"{}\"}} {}\n",
+                parts[2],
+                parts[1],
+                parts[0],
+                entry.value()
+            ));
+        }
+    }
+
+    for entry in metrics.key_group_requests_total.iter() {
+        let parts: Vec<&str> = entry.key().splitn(5, ':').collect();
+        if parts.len() == 5 {
+            output.push_str(&format!(
+                "cache_key_group_requests_total{{service=\"{}\",layer=\"{}\",op=\"{}\",result=\"{}\",key_group=\"{}\"}} {}\n",
+                parts[0],
+                parts[1],
+                parts[2],
+                parts[3],
+                parts[4],
+                entry.value()
+            ));
+        }
+    }
+
+    for entry in metrics.wal_replay_entries_total.iter() {
+        let (service, outcome) = entry.key().rsplit_once(':').unwrap_or((entry.key(), ""));
+        output.push_str(&format!(
+            "cache_wal_replay_entries_total{{service=\"{}\",outcome=\"{}\"}} {}\n",
+            service,
+            outcome,
+            entry.value()
+        ));
+    }
+
     output
 }
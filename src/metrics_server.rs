@@ -0,0 +1,85 @@
+//! Copyright (c) 2025-2026, Kirky.X
+//!
+//! MIT License
+//!
+//! 该模块定义了用于指标采集与就绪探针的轻量HTTP服务。
+//!
+//! 提供以下路径：
+//! - `/metrics`：返回 [`get_metrics_string`] 生成的指标文本
+//! - `/healthz`：所有服务就绪时返回200，否则返回503
+
+use crate::error::Result;
+use crate::manager::CacheManager;
+use crate::metrics::get_metrics_string;
+use std::net::SocketAddr;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::task::JoinHandle;
+use tracing::{debug, info};
+
+/// 启动指标HTTP服务
+///
+/// # 参数
+///
+/// * `addr` - 监听地址，端口为0时由系统分配
+///
+/// # 返回值
+///
+/// 返回实际监听的地址及后台任务句柄
+pub async fn start(addr: &str) -> Result<(SocketAddr, JoinHandle<()>)> {
+    let listener = TcpListener::bind(addr).await?;
+    let local_addr = listener.local_addr()?;
+    info!("Metrics server listening on {}", local_addr);
+
+    let handle = tokio::spawn(async move {
+        while let Ok((stream, _)) = listener.accept().await {
+            tokio::spawn(async move {
+                if let Err(e) = handle_connection(stream).await {
+                    debug!("Metrics server connection error: {}", e);
+                }
+            });
+        }
+    });
+
+    Ok((local_addr, handle))
+}
+
+/// 处理单个HTTP请求，响应后关闭连接
+async fn handle_connection(stream: TcpStream) -> Result<()> {
+    let (reader, mut writer) = stream.into_split();
+    let mut reader = BufReader::new(reader);
+
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line).await?;
+    let path = request_line.split_whitespace().nth(1).unwrap_or("/");
+
+    let (status, body) = match path {
+        "/metrics" => ("200 OK", get_metrics_string()),
+        "/healthz" => {
+            let report = CacheManager::health_report().await;
+            let ready = report.values().all(|state| state.is_ready());
+            let mut lines: Vec<String> = report
+                .iter()
+                .map(|(service, state)| format!("{}: {:?}", service, state))
+                .collect();
+            lines.sort();
+            let body = lines.join("\n");
+            if ready {
+                ("200 OK", body)
+            } else {
+                ("503 Service Unavailable", body)
+            }
+        }
+        _ => ("404 Not Found", String::new()),
+    };
+
+    let response = format!(
+        "HTTP/1.1 {}\r\nContent-Type: text/plain; charset=utf-8\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        body.len(),
+        body
+    );
+    writer.write_all(response.as_bytes()).await?;
+    writer.shutdown().await?;
+    Ok(())
+}
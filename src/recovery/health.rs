@@ -5,10 +5,13 @@
 //! 该模块定义了缓存系统的健康检查和状态恢复机制。
 
 use crate::backend::l2::L2Backend;
+use crate::config::HealthConfig;
 use crate::recovery::wal::WalEntry;
 use crate::recovery::wal::WalManager;
 use crate::recovery::wal::WalReplayableBackend;
 pub use crate::recovery::wal::WalReplayableBackend as WalReplayableBackendTrait;
+use crate::utils::random_u64;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 use tokio::sync::RwLock;
@@ -58,6 +61,35 @@ pub enum HealthState {
     WalReplaying { since: Instant },
 }
 
+impl HealthState {
+    /// 判断该状态下服务是否可以对外提供服务
+    ///
+    /// 健康与恢复中状态视为就绪，降级与WAL重放中视为未就绪
+    pub fn is_ready(&self) -> bool {
+        matches!(self, HealthState::Healthy | HealthState::Recovering { .. })
+    }
+}
+
+/// 计算首次探测前的延迟
+///
+/// 未启用抖动时立即探测，否则在一个探测间隔内随机延迟
+fn initial_probe_delay(config: &HealthConfig) -> Duration {
+    if config.jitter_percent == 0 {
+        return Duration::ZERO;
+    }
+    Duration::from_millis(random_u64() % (config.probe_interval_ms + 1))
+}
+
+/// 计算下一次探测前的间隔，在 `probe_interval_ms * (1 ± jitter_percent%)` 范围内随机取值
+fn next_probe_interval(config: &HealthConfig) -> Duration {
+    let base = config.probe_interval_ms;
+    let spread = base * u64::from(config.jitter_percent.min(100)) / 100;
+    if spread == 0 {
+        return Duration::from_millis(base);
+    }
+    Duration::from_millis(base - spread + random_u64() % (2 * spread + 1))
+}
+
 /// 健康检查器
 ///
 /// 负责定期检查L2缓存的健康状态，并在必要时进行恢复
@@ -72,6 +104,10 @@ pub struct HealthChecker<T: HealthCheckableBackend> {
     service_name: String,
     /// 命令超时时间（毫秒）
     command_timeout_ms: u64,
+    /// 探测间隔配置
+    config: HealthConfig,
+    /// 为true时推迟WAL重放（L2写入暂停期间）
+    replay_paused: Option<Arc<AtomicBool>>,
 }
 
 impl<T: HealthCheckableBackend + WalReplayableBackend> HealthChecker<T> {
@@ -100,17 +136,59 @@ impl<T: HealthCheckableBackend + WalReplayableBackend> HealthChecker<T> {
             wal,
             service_name,
             command_timeout_ms,
+            config: HealthConfig::default(),
+            replay_paused: None,
         }
     }
 
+    /// 设置探测间隔配置
+    ///
+    /// # 参数
+    ///
+    /// * `config` - 健康检查配置
+    ///
+    /// # 返回值
+    ///
+    /// 返回使用新配置的健康检查器
+    pub fn with_config(mut self, config: HealthConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// 设置WAL重放暂停标志
+    ///
+    /// 标志为true时，恢复中的服务保持 `Recovering` 状态而不重放WAL，
+    /// 标志清除后的下一次探测再开始重放
+    ///
+    /// # 参数
+    ///
+    /// * `paused` - 与客户端共享的暂停标志
+    ///
+    /// # 返回值
+    ///
+    /// 返回设置了暂停标志的健康检查器
+    pub fn with_replay_paused(mut self, paused: Arc<AtomicBool>) -> Self {
+        self.replay_paused = Some(paused);
+        self
+    }
+
+    /// 是否推迟WAL重放
+    fn is_replay_paused(&self) -> bool {
+        self.replay_paused
+            .as_ref()
+            .is_some_and(|paused| paused.load(Ordering::SeqCst))
+    }
+
     /// 启动健康检查
     ///
     /// 定期检查L2缓存的健康状态，并根据检查结果更新状态和执行相应操作
     pub async fn start(self) {
-        let mut interval = tokio::time::interval(Duration::from_secs(5));
+        // 按截止时间调度，探测本身的耗时不会累积到后续间隔中
+        let mut next_probe = tokio::time::Instant::now() + initial_probe_delay(&self.config);
 
         loop {
-            interval.tick().await;
+            tokio::time::sleep_until(next_probe).await;
+            next_probe += next_probe_interval(&self.config);
 
             let is_healthy = match timeout(
                 Duration::from_millis(self.command_timeout_ms),
@@ -226,6 +304,12 @@ impl<T: HealthCheckableBackend + WalReplayableBackend> HealthChecker<T> {
                             since: Instant::now(),
                             failure_count: 1,
                         }
+                    } else if success_count >= 3 && self.is_replay_paused() {
+                        tracing::debug!("服务 {} 已暂停L2写入，推迟WAL重放", self.service_name);
+                        HealthState::Recovering {
+                            since,
+                            success_count,
+                        }
                     } else if success_count >= 3 {
                         tracing::info!(
                             "服务 {} 达到恢复条件，开始重放WAL (success_count={})",
@@ -252,11 +336,23 @@ impl<T: HealthCheckableBackend + WalReplayableBackend> HealthChecker<T> {
                         state_guard = self.state.write().await;
 
                         match replay_result {
-                            Ok(count) => {
+                            // 所有待重放条目都失败，L2很可能仍不可用
+                            Ok(report) if report.failed > 0 && report.replayed == 0 => {
+                                tracing::error!(
+                                    "服务 {} WAL重放全部失败: {:?}，状态转换: WalReplaying -> Recovering",
+                                    self.service_name,
+                                    report
+                                );
+                                HealthState::Recovering {
+                                    since: Instant::now(),
+                                    success_count: 1,
+                                }
+                            }
+                            Ok(report) => {
                                 tracing::info!(
-                                    "服务 {} WAL已重放: {} 条目，状态转换: WalReplaying -> Healthy",
+                                    "服务 {} WAL已重放: {:?}，状态转换: WalReplaying -> Healthy",
                                     self.service_name,
-                                    count
+                                    report
                                 );
                                 HealthState::Healthy
                             }
@@ -6,10 +6,12 @@
 
 use crate::database::{is_test_connection_string, normalize_connection_string};
 use crate::error::Result;
+use crate::metrics::GLOBAL_METRICS;
 use sea_orm::{
     ConnectOptions, ConnectionTrait, Database, DatabaseConnection, Statement, TransactionTrait,
     Value,
 };
+use std::collections::HashSet;
 use std::env;
 use std::path::Path;
 use std::sync::Arc;
@@ -42,12 +44,48 @@ pub enum Operation {
     Delete,
 }
 
+/// 可重放条目允许的最大TTL（秒）
+pub const MAX_REPLAY_TTL_SECS: i64 = 30 * 24 * 3600;
+
+/// 每条 DELETE 语句删除的最大条目数，避免超出SQLite的参数数量限制
+const DELETE_CHUNK_SIZE: usize = 500;
+
+impl WalEntry {
+    /// 条目是否可以重放
+    ///
+    /// 缺少值的写入条目以及TTL超出 `0..=MAX_REPLAY_TTL_SECS` 的条目无法重放
+    pub fn is_replayable(&self) -> bool {
+        let ttl_valid = match self.ttl {
+            Some(ttl) => (0..=MAX_REPLAY_TTL_SECS).contains(&ttl),
+            None => true,
+        };
+        match self.operation {
+            Operation::Set => self.value.is_some() && ttl_valid,
+            Operation::Delete => true,
+        }
+    }
+}
+
+/// WAL重放结果
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct WalReplayReport {
+    /// 成功重放的条目数
+    pub replayed: usize,
+    /// 重放失败并保留在WAL中的条目数
+    pub failed: usize,
+    /// 无法重放而被丢弃的条目数
+    pub skipped: usize,
+}
+
 pub struct WalManager {
     db: Arc<DatabaseConnection>,
     service_name: String,
     pending_entries: Arc<Mutex<Vec<WalEntry>>>,
     flush_trigger: Arc<Notify>,
     batch_size: usize,
+    last_replay: std::sync::Mutex<Option<WalReplayReport>>,
+    /// 重放互斥锁，保证健康检查器与手动触发的重放不会重复重放同一批条目
+    replay_lock: Mutex<()>,
 }
 
 impl WalManager {
@@ -154,6 +192,8 @@ impl WalManager {
             pending_entries,
             flush_trigger,
             batch_size,
+            last_replay: std::sync::Mutex::new(None),
+            replay_lock: Mutex::new(()),
         })
     }
 
@@ -177,10 +217,20 @@ impl WalManager {
     }
 
     pub async fn get_entries(&self) -> Result<Vec<WalEntry>> {
+        Ok(self
+            .get_entries_with_ids()
+            .await?
+            .into_iter()
+            .map(|(_, entry)| entry)
+            .collect())
+    }
+
+    /// 读取所有条目及其行ID，按写入时间排序
+    async fn get_entries_with_ids(&self) -> Result<Vec<(i64, WalEntry)>> {
         let query_sql = r#"
-            SELECT timestamp, operation, key, value, ttl FROM wal_entries
+            SELECT id, timestamp, operation, key, value, ttl FROM wal_entries
             WHERE service_name = ?1
-            ORDER BY timestamp ASC
+            ORDER BY timestamp ASC, id ASC
         "#;
 
         let results = self
@@ -195,6 +245,9 @@ impl WalManager {
 
         let mut entries = Vec::new();
         for row in results {
+            let id: i64 = row
+                .try_get("", "id")
+                .map_err(|e| crate::error::CacheError::DatabaseError(e.to_string()))?;
             let timestamp_secs: i64 = row
                 .try_get("", "timestamp")
                 .map_err(|e| crate::error::CacheError::DatabaseError(e.to_string()))?;
@@ -220,18 +273,44 @@ impl WalManager {
                 .try_get("", "ttl")
                 .map_err(|e| crate::error::CacheError::DatabaseError(e.to_string()))?;
 
-            entries.push(WalEntry {
-                timestamp,
-                operation,
-                key,
-                value,
-                ttl,
-            });
+            entries.push((
+                id,
+                WalEntry {
+                    timestamp,
+                    operation,
+                    key,
+                    value,
+                    ttl,
+                },
+            ));
         }
 
         Ok(entries)
     }
 
+    /// 按行ID删除条目
+    async fn delete_entries(&self, ids: &[i64]) -> Result<()> {
+        for chunk in ids.chunks(DELETE_CHUNK_SIZE) {
+            let placeholders = (1..=chunk.len())
+                .map(|i| format!("?{}", i))
+                .collect::<Vec<_>>()
+                .join(", ");
+            let delete_sql = format!("DELETE FROM wal_entries WHERE id IN ({})", placeholders);
+            self.db
+                .execute(Statement::from_sql_and_values(
+                    sea_orm::DatabaseBackend::Sqlite,
+                    delete_sql,
+                    chunk
+                        .iter()
+                        .map(|id| Value::BigInt(Some(*id)))
+                        .collect::<Vec<_>>(),
+                ))
+                .await
+                .map_err(|e| crate::error::CacheError::DatabaseError(e.to_string()))?;
+        }
+        Ok(())
+    }
+
     pub async fn clear_entries(&self) -> Result<()> {
         let delete_sql = format!(
             "DELETE FROM wal_entries WHERE service_name = '{}'",
@@ -362,6 +441,14 @@ impl WalManager {
         self.clear_entries().await
     }
 
+    /// 最近一次重放的结果，尚未重放过时返回None
+    pub fn last_replay(&self) -> Option<WalReplayReport> {
+        *self
+            .last_replay
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
     /// 重放所有 WAL 条目到后端
     ///
     /// # 参数
@@ -370,48 +457,101 @@ impl WalManager {
     ///
     /// # 返回值
     ///
-    /// 返回成功重放的条目数量
+    /// 返回重放结果统计，只有读写WAL本身失败时才返回错误
     ///
     /// # 注意
     ///
-    /// 实现事务性重放：只在确认所有条目都成功后才清空 WAL
-    /// 如果重放失败，WAL 条目将保留以便下次重试
-    pub async fn replay_all<B: WalReplayableBackend>(&self, backend: &B) -> Result<usize> {
-        let entries = self.get_entries().await?;
-        let count = entries.len();
-
-        if entries.is_empty() {
-            return Ok(0);
-        }
-
-        // 记录开始重放
-        tracing::info!(
-            "Starting WAL replay for service '{}': {} entries",
-            self.service_name,
-            count
-        );
-
-        // 尝试重放所有条目
-        match backend.pipeline_replay(entries.clone()).await {
-            Ok(_) => {
-                // 只有在所有条目都成功重放后才清空 WAL
-                tracing::info!(
-                    "WAL replay successful for service '{}': clearing {} entries",
-                    self.service_name,
-                    count
+    /// 先以单个管道批量重放；批量失败时逐条重放，单条失败不会中断其他键的条目。
+    /// 某个键的条目失败后，该键之后的条目不再重放，与失败的条目一起保留并计为失败，
+    /// 保证下次重试时同一个键的操作仍按写入顺序应用。
+    /// 成功重放和无法重放（被跳过）的条目从 WAL 中删除，失败的条目保留以便下次重试。
+    /// 结果同时记录到 `cache_wal_replay_entries_total` 指标，并可通过 [`last_replay`](Self::last_replay) 查询。
+    /// 同一时间只有一个重放在执行，并发调用会等待前一次重放完成后再读取剩余条目
+    pub async fn replay_all<B: WalReplayableBackend>(
+        &self,
+        backend: &B,
+    ) -> Result<WalReplayReport> {
+        let _replay_guard = self.replay_lock.lock().await;
+        let entries = self.get_entries_with_ids().await?;
+        let mut report = WalReplayReport::default();
+
+        if !entries.is_empty() {
+            // 记录开始重放
+            tracing::info!(
+                "Starting WAL replay for service '{}': {} entries",
+                self.service_name,
+                entries.len()
+            );
+
+            let (replayable, skipped): (Vec<_>, Vec<_>) = entries
+                .into_iter()
+                .partition(|(_, entry)| entry.is_replayable());
+            let mut done: Vec<i64> = skipped.iter().map(|(id, _)| *id).collect();
+            report.skipped = skipped.len();
+            for (_, entry) in &skipped {
+                tracing::warn!(
+                    "Skipping invalid WAL entry for key '{}' in service '{}'",
+                    entry.key,
+                    self.service_name
                 );
-                self.clear_entries().await?;
-                Ok(count)
             }
-            Err(e) => {
-                // 重放失败，保留 WAL 条目以便下次重试
-                tracing::error!(
-                    "WAL replay failed for service '{}': {}. WAL entries preserved for retry.",
-                    self.service_name,
-                    e
-                );
-                Err(e)
+
+            let batch = replayable.iter().map(|(_, entry)| entry.clone()).collect();
+            match backend.pipeline_replay(batch).await {
+                Ok(_) => {
+                    report.replayed = replayable.len();
+                    done.extend(replayable.iter().map(|(id, _)| *id));
+                }
+                Err(e) => {
+                    tracing::warn!(
+                        "WAL batch replay failed for service '{}': {}, replaying entries one by one",
+                        self.service_name,
+                        e
+                    );
+                    let mut failed_keys = HashSet::new();
+                    for (id, entry) in replayable {
+                        if failed_keys.contains(&entry.key) {
+                            // 同一个键的较早条目失败，保留以免乱序应用
+                            report.failed += 1;
+                            continue;
+                        }
+                        let key = entry.key.clone();
+                        match backend.pipeline_replay(vec![entry]).await {
+                            Ok(_) => {
+                                report.replayed += 1;
+                                done.push(id);
+                            }
+                            Err(e) => {
+                                // 失败的条目保留在 WAL 中以便下次重试
+                                tracing::error!(
+                                    "WAL replay failed for key '{}' in service '{}': {}",
+                                    key,
+                                    self.service_name,
+                                    e
+                                );
+                                report.failed += 1;
+                                failed_keys.insert(key);
+                            }
+                        }
+                    }
+                }
             }
+
+            self.delete_entries(&done).await?;
+            tracing::info!(
+                "WAL replay finished for service '{}': {:?}",
+                self.service_name,
+                report
+            );
         }
+
+        GLOBAL_METRICS.record_wal_replay(&self.service_name, "replayed", report.replayed as u64);
+        GLOBAL_METRICS.record_wal_replay(&self.service_name, "failed", report.failed as u64);
+        GLOBAL_METRICS.record_wal_replay(&self.service_name, "skipped", report.skipped as u64);
+        *self
+            .last_replay
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()) = Some(report);
+        Ok(report)
     }
 }
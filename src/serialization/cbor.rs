@@ -0,0 +1,54 @@
+//! Copyright (c) 2025-2026, Kirky.X
+//!
+//! MIT License
+//!
+//! 该模块定义了CBOR序列化器的实现。
+
+use super::Serializer;
+use crate::error::{CacheError, Result};
+use serde::{de::DeserializeOwned, Serialize};
+
+/// CBOR序列化器
+///
+/// 实现基于ciborium的序列化和反序列化。
+/// CBOR是自描述格式，只要字段名一致即可读取其他语言（如Go）服务写入的值。
+#[derive(Clone, Default)]
+pub struct CborSerializer;
+
+impl CborSerializer {
+    /// 创建新的CBOR序列化器
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Serializer for CborSerializer {
+    /// 序列化值为CBOR字节数组
+    ///
+    /// # 参数
+    ///
+    /// * `value` - 要序列化的值
+    ///
+    /// # 返回值
+    ///
+    /// 返回序列化后的字节数组或错误
+    fn serialize<T: Serialize>(&self, value: &T) -> Result<Vec<u8>> {
+        let mut buf = Vec::new();
+        ciborium::into_writer(value, &mut buf)
+            .map_err(|e| CacheError::Serialization(e.to_string()))?;
+        Ok(buf)
+    }
+
+    /// 从CBOR字节数组反序列化值
+    ///
+    /// # 参数
+    ///
+    /// * `data` - 要反序列化的字节数组
+    ///
+    /// # 返回值
+    ///
+    /// 返回反序列化后的值或错误
+    fn deserialize<T: DeserializeOwned>(&self, data: &[u8]) -> Result<T> {
+        ciborium::from_reader(data).map_err(|e| CacheError::Serialization(e.to_string()))
+    }
+}
@@ -0,0 +1,220 @@
+//! Copyright (c) 2025-2026, Kirky.X
+//!
+//! MIT License
+//!
+//! 该模块定义了加密序列化器的实现，对内部序列化器的输出进行AES-GCM加密。
+
+use super::{Serializer, SerializerEnum};
+use crate::error::{CacheError, Result};
+use aes_gcm::aead::{Aead, AeadCore, KeyInit, OsRng};
+use aes_gcm::{Aes256Gcm, Nonce};
+use serde::{de::DeserializeOwned, Serialize};
+use std::sync::Arc;
+
+/// 加密数据标记字节
+///
+/// JSON、CBOR及gzip数据都不会以该字节开头，可据此区分迁移前写入的明文值
+const ENCRYPTED_MARKER: u8 = 0xFE;
+
+/// 当前加密格式版本
+const ENCRYPTION_VERSION: u8 = 1;
+
+/// 加密数据头部长度（标记字节 + 版本字节）
+const HEADER_LEN: usize = 2;
+
+/// AES-GCM随机数长度
+const NONCE_LEN: usize = 12;
+
+/// 加密序列化器
+///
+/// 作为装饰器包装其他序列化器，写入时加密、读取时解密。
+/// 数据格式：`[标记][版本][随机数(12字节)][密文+认证标签]`。
+/// 不带加密头部的数据视为迁移前的明文值，直接交给内部序列化器处理。
+#[derive(Clone)]
+pub struct EncryptedSerializer {
+    /// 内部序列化器
+    inner: Box<SerializerEnum>,
+    /// AES-256-GCM 加密器
+    cipher: Arc<Aes256Gcm>,
+}
+
+impl EncryptedSerializer {
+    /// 创建新的加密序列化器
+    ///
+    /// # 参数
+    ///
+    /// * `inner` - 被包装的序列化器
+    /// * `hex_key` - 64位十六进制字符串形式的AES-256密钥
+    ///
+    /// # 返回值
+    ///
+    /// 返回加密序列化器，密钥格式错误时返回配置错误
+    pub fn new(inner: SerializerEnum, hex_key: &str) -> Result<Self> {
+        let key = decode_hex_key(hex_key)?;
+        let cipher = Aes256Gcm::new_from_slice(&key)
+            .map_err(|e| CacheError::Configuration(format!("Invalid encryption key: {}", e)))?;
+
+        Ok(Self {
+            inner: Box::new(inner),
+            cipher: Arc::new(cipher),
+        })
+    }
+
+    /// 获取被包装的序列化器
+    pub fn inner(&self) -> &SerializerEnum {
+        &self.inner
+    }
+}
+
+/// 解析十六进制密钥
+fn decode_hex_key(hex_key: &str) -> Result<Vec<u8>> {
+    let hex_key = hex_key.trim();
+    if hex_key.len() != 64 {
+        return Err(CacheError::Configuration(
+            "Encryption key must be 64 hex characters (32 bytes)".to_string(),
+        ));
+    }
+    // 先校验全部为ASCII十六进制字符，多字节UTF-8字符会使下面按字节切片时panic
+    if !hex_key.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return Err(CacheError::Configuration(
+            "Encryption key contains non-hex characters".to_string(),
+        ));
+    }
+
+    (0..hex_key.len())
+        .step_by(2)
+        .map(|i| {
+            u8::from_str_radix(&hex_key[i..i + 2], 16).map_err(|_| {
+                CacheError::Configuration("Encryption key contains non-hex characters".to_string())
+            })
+        })
+        .collect()
+}
+
+impl Serializer for EncryptedSerializer {
+    /// 序列化并加密值
+    ///
+    /// # 参数
+    ///
+    /// * `value` - 要序列化的值
+    ///
+    /// # 返回值
+    ///
+    /// 返回带加密头部的密文或错误
+    fn serialize<T: Serialize>(&self, value: &T) -> Result<Vec<u8>> {
+        let plaintext = self.inner.serialize(value)?;
+        let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+        let ciphertext = self
+            .cipher
+            .encrypt(&nonce, plaintext.as_ref())
+            .map_err(|e| CacheError::Serialization(format!("Encryption failed: {}", e)))?;
+
+        let mut out = Vec::with_capacity(HEADER_LEN + NONCE_LEN + ciphertext.len());
+        out.push(ENCRYPTED_MARKER);
+        out.push(ENCRYPTION_VERSION);
+        out.extend_from_slice(&nonce);
+        out.extend_from_slice(&ciphertext);
+        Ok(out)
+    }
+
+    /// 解密并反序列化值
+    ///
+    /// # 参数
+    ///
+    /// * `data` - 要反序列化的字节数组
+    ///
+    /// # 返回值
+    ///
+    /// 返回反序列化后的值，密钥不匹配或数据被篡改时返回错误
+    fn deserialize<T: DeserializeOwned>(&self, data: &[u8]) -> Result<T> {
+        if data.first() != Some(&ENCRYPTED_MARKER) {
+            // 迁移前写入的明文值
+            return self.inner.deserialize(data);
+        }
+
+        if data.len() < HEADER_LEN + NONCE_LEN {
+            return Err(CacheError::Serialization(
+                "Encrypted value is truncated".to_string(),
+            ));
+        }
+
+        let version = data[1];
+        if version != ENCRYPTION_VERSION {
+            return Err(CacheError::Serialization(format!(
+                "Unsupported encryption version: {}",
+                version
+            )));
+        }
+
+        let nonce = Nonce::from_slice(&data[HEADER_LEN..HEADER_LEN + NONCE_LEN]);
+        let plaintext = self
+            .cipher
+            .decrypt(nonce, &data[HEADER_LEN + NONCE_LEN..])
+            .map_err(|_| CacheError::Serialization("Decryption failed".to_string()))?;
+
+        self.inner.deserialize(&plaintext)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::serialization::JsonSerializer;
+
+    const KEY: &str = "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f";
+    const OTHER_KEY: &str = "ff0102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f";
+
+    fn json() -> SerializerEnum {
+        SerializerEnum::Json(JsonSerializer::new())
+    }
+
+    #[test]
+    fn test_encrypted_round_trip() {
+        let serializer = EncryptedSerializer::new(json(), KEY).unwrap();
+        let bytes = serializer.serialize(&"secret value").unwrap();
+
+        assert_eq!(bytes[0], ENCRYPTED_MARKER);
+        assert!(!bytes.windows(6).any(|w| w == b"secret"));
+
+        let value: String = serializer.deserialize(&bytes).unwrap();
+        assert_eq!(value, "secret value");
+    }
+
+    #[test]
+    fn test_wrong_key_fails() {
+        let writer = EncryptedSerializer::new(json(), KEY).unwrap();
+        let reader = EncryptedSerializer::new(json(), OTHER_KEY).unwrap();
+
+        let bytes = writer.serialize(&42u64).unwrap();
+        assert!(matches!(
+            reader.deserialize::<u64>(&bytes),
+            Err(CacheError::Serialization(_))
+        ));
+    }
+
+    #[test]
+    fn test_legacy_plaintext_value() {
+        let serializer = EncryptedSerializer::new(json(), KEY).unwrap();
+        let legacy = json().serialize(&vec![1, 2, 3]).unwrap();
+
+        let value: Vec<i32> = serializer.deserialize(&legacy).unwrap();
+        assert_eq!(value, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_invalid_key() {
+        assert!(matches!(
+            EncryptedSerializer::new(json(), "abcd"),
+            Err(CacheError::Configuration(_))
+        ));
+        assert!(matches!(
+            EncryptedSerializer::new(json(), &"zz".repeat(32)),
+            Err(CacheError::Configuration(_))
+        ));
+        // 字节长度为64但含多字节字符的密钥返回错误而不是panic
+        assert!(matches!(
+            EncryptedSerializer::new(json(), &format!("é{}", "0".repeat(62))),
+            Err(CacheError::Configuration(_))
+        ));
+    }
+}
@@ -0,0 +1,72 @@
+//! Copyright (c) 2025-2026, Kirky.X
+//!
+//! MIT License
+//!
+//! 该模块定义了带读取回退的序列化器，用于编解码迁移期间读取旧格式写入的值。
+
+use super::{Serializer, SerializerEnum};
+use crate::error::Result;
+use serde::{de::DeserializeOwned, Serialize};
+use tracing::debug;
+
+/// 带读取回退的序列化器
+///
+/// 写入始终使用主序列化器；读取时主序列化器反序列化失败，依次尝试各回退序列化器，
+/// 全部失败时返回主序列化器的错误。回退只在主序列化器报错时发生，
+/// 旧格式的数据恰好能被主序列化器解析时不会触发回退
+#[derive(Clone)]
+pub struct FallbackSerializer {
+    /// 主序列化器
+    primary: Box<SerializerEnum>,
+    /// 按顺序尝试的回退序列化器
+    fallbacks: Vec<SerializerEnum>,
+}
+
+impl FallbackSerializer {
+    /// 创建新的带读取回退的序列化器
+    ///
+    /// # 参数
+    ///
+    /// * `primary` - 主序列化器，用于写入和首先尝试的读取
+    /// * `fallbacks` - 主序列化器读取失败后按顺序尝试的序列化器
+    ///
+    /// # 返回值
+    ///
+    /// 返回新的序列化器实例
+    pub fn new(primary: SerializerEnum, fallbacks: Vec<SerializerEnum>) -> Self {
+        Self {
+            primary: Box::new(primary),
+            fallbacks,
+        }
+    }
+
+    /// 获取主序列化器
+    pub fn primary(&self) -> &SerializerEnum {
+        &self.primary
+    }
+
+    /// 获取回退序列化器
+    pub fn fallbacks(&self) -> &[SerializerEnum] {
+        &self.fallbacks
+    }
+}
+
+impl Serializer for FallbackSerializer {
+    fn serialize<T: Serialize>(&self, value: &T) -> Result<Vec<u8>> {
+        self.primary.serialize(value)
+    }
+
+    fn deserialize<T: DeserializeOwned>(&self, data: &[u8]) -> Result<T> {
+        let error = match self.primary.deserialize(data) {
+            Ok(value) => return Ok(value),
+            Err(e) => e,
+        };
+        for (index, fallback) in self.fallbacks.iter().enumerate() {
+            if let Ok(value) = fallback.deserialize(data) {
+                debug!("Value deserialized by read fallback serializer #{}", index);
+                return Ok(value);
+            }
+        }
+        Err(error)
+    }
+}
@@ -4,11 +4,18 @@
 //!
 //! 该模块定义了缓存系统的序列化机制，支持多种序列化格式。
 
+pub mod cbor;
+pub mod encryption;
+pub mod fallback;
 pub mod json;
+pub(crate) mod soft_ttl;
 
 use crate::error::Result;
 use serde::{de::DeserializeOwned, Serialize};
 
+pub use cbor::CborSerializer;
+pub use encryption::EncryptedSerializer;
+pub use fallback::FallbackSerializer;
 pub use json::JsonSerializer;
 
 /// 序列化器特征
@@ -28,18 +35,29 @@ pub trait Serializer: Send + Sync {
 #[derive(Clone)]
 pub enum SerializerEnum {
     Json(JsonSerializer),
+    Cbor(CborSerializer),
+    Encrypted(EncryptedSerializer),
+    WithFallback(FallbackSerializer),
 }
 
 impl Serializer for SerializerEnum {
     fn serialize<T: Serialize>(&self, value: &T) -> Result<Vec<u8>> {
         match self {
             SerializerEnum::Json(s) => s.serialize(value),
+            SerializerEnum::Cbor(s) => s.serialize(value),
+            SerializerEnum::Encrypted(s) => s.serialize(value),
+            SerializerEnum::WithFallback(s) => s.serialize(value),
         }
     }
 
     fn deserialize<T: DeserializeOwned>(&self, data: &[u8]) -> Result<T> {
+        // 软TTL头部只用于判断是否过期，反序列化前去除
+        let (_, data) = soft_ttl::decode(data);
         match self {
             SerializerEnum::Json(s) => s.deserialize(data),
+            SerializerEnum::Cbor(s) => s.deserialize(data),
+            SerializerEnum::Encrypted(s) => s.deserialize(data),
+            SerializerEnum::WithFallback(s) => s.deserialize(data),
         }
     }
 }
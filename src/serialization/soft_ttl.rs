@@ -0,0 +1,69 @@
+//! Copyright (c) 2025-2026, Kirky.X
+//!
+//! MIT License
+//!
+//! 该模块定义了软TTL头部的编解码，软过期时间随值一起保存。
+
+/// 软TTL头部标记字节
+///
+/// JSON、CBOR、gzip及加密数据都不会以该字节开头，不带该标记的数据视为没有软TTL
+const SOFT_TTL_MARKER: u8 = 0xFD;
+
+/// 软TTL头部长度（标记字节 + 8字节大端软过期时间）
+const HEADER_LEN: usize = 9;
+
+/// 为序列化后的值加上软TTL头部
+///
+/// 数据格式：`[标记][软过期时间(Unix毫秒, 8字节大端)][序列化后的值]`
+///
+/// # 参数
+///
+/// * `deadline_ms` - 软过期时间（Unix时间戳，毫秒）
+/// * `payload` - 序列化后的值
+///
+/// # 返回值
+///
+/// 返回带头部的字节数组
+pub(crate) fn encode(deadline_ms: u64, payload: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(HEADER_LEN + payload.len());
+    out.push(SOFT_TTL_MARKER);
+    out.extend_from_slice(&deadline_ms.to_be_bytes());
+    out.extend_from_slice(payload);
+    out
+}
+
+/// 拆分软TTL头部
+///
+/// # 参数
+///
+/// * `data` - 读取到的字节数组
+///
+/// # 返回值
+///
+/// 返回软过期时间（没有头部时为None）及去除头部后的值
+pub(crate) fn decode(data: &[u8]) -> (Option<u64>, &[u8]) {
+    match data.split_first() {
+        Some((&SOFT_TTL_MARKER, rest)) if rest.len() >= HEADER_LEN - 1 => {
+            let (deadline, payload) = rest.split_at(HEADER_LEN - 1);
+            let deadline = u64::from_be_bytes(deadline.try_into().unwrap_or_default());
+            (Some(deadline), payload)
+        }
+        _ => (None, data),
+    }
+}
+
+/// 去除软TTL头部，供字节读取接口返回调用方写入的原始值
+///
+/// # 参数
+///
+/// * `data` - 读取到的字节数组
+///
+/// # 返回值
+///
+/// 返回去除头部后的值，没有头部时原样返回
+pub(crate) fn strip(data: Vec<u8>) -> Vec<u8> {
+    match decode(&data) {
+        (Some(_), payload) => payload.to_vec(),
+        (None, _) => data,
+    }
+}
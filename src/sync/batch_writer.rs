@@ -6,12 +6,19 @@
 
 use super::common::*;
 use crate::backend::l2::L2Backend;
-use crate::error::Result;
+use crate::error::{CacheError, Result};
+use crate::recovery::wal::{Operation, WalEntry, WalManager};
+use crate::utils::task::spawn_named;
 
 use dashmap::DashMap;
-use std::sync::Arc;
+use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
 use tokio::sync::{Notify, Semaphore};
+use tokio::time::Instant;
+
+/// 刷新失败后退避时间的上限
+const MAX_FLUSH_BACKOFF: Duration = Duration::from_secs(30);
 
 /// 缓冲区条目
 ///
@@ -19,6 +26,61 @@ use tokio::sync::{Notify, Semaphore};
 struct BufferEntry {
     /// 批量操作
     operation: BatchOperation,
+    /// 入队序号（用于确定最早的条目）
+    seq: u64,
+}
+
+/// 刷新失败后的恢复状态
+///
+/// 记录连续失败的刷新次数与退避结束时间；配置了WAL时，写入失败的条目转入WAL而不是留在缓冲区
+struct FlushRecovery {
+    /// WAL管理器
+    wal: Option<Arc<WalManager>>,
+    /// 连续失败的刷新次数
+    failures: AtomicU32,
+    /// 退避结束时间，在此之前的定期刷新被跳过
+    retry_at: Mutex<Option<Instant>>,
+}
+
+impl FlushRecovery {
+    fn new() -> Self {
+        Self {
+            wal: None,
+            failures: AtomicU32::new(0),
+            retry_at: Mutex::new(None),
+        }
+    }
+
+    /// 是否仍处于退避期
+    fn in_backoff(&self) -> bool {
+        self.retry_at
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .is_some_and(|retry_at| Instant::now() < retry_at)
+    }
+
+    /// 刷新成功，结束退避
+    fn record_success(&self) {
+        self.failures.store(0, Ordering::Relaxed);
+        *self.retry_at.lock().unwrap_or_else(|e| e.into_inner()) = None;
+    }
+
+    /// 刷新失败，按连续失败次数指数退避
+    ///
+    /// # 参数
+    ///
+    /// * `base` - 基础退避时间（刷新间隔）
+    ///
+    /// # 返回值
+    ///
+    /// 返回本次的退避时间
+    fn record_failure(&self, base: Duration) -> Duration {
+        let attempt = self.failures.fetch_add(1, Ordering::Relaxed).min(16);
+        let delay =
+            calculate_retry_delay(attempt as usize, base.as_millis() as u64).min(MAX_FLUSH_BACKOFF);
+        *self.retry_at.lock().unwrap_or_else(|e| e.into_inner()) = Some(Instant::now() + delay);
+        delay
+    }
 }
 
 /// 批量写入器
@@ -31,6 +93,10 @@ pub struct BatchWriter {
     l2: Arc<L2Backend>,
     /// 刷新触发器
     flush_trigger: Arc<Notify>,
+    /// 队列空间释放通知（用于 Block 背压策略）
+    space_available: Arc<Notify>,
+    /// 下一个入队序号
+    next_seq: AtomicU64,
     /// 配置
     config: BatchWriterConfig,
 
@@ -42,6 +108,9 @@ pub struct BatchWriter {
 
     /// 取消令牌（用于优雅关闭）
     shutdown_token: Arc<tokio_util::sync::CancellationToken>,
+
+    /// 刷新失败后的恢复状态
+    recovery: Arc<FlushRecovery>,
 }
 
 impl BatchWriter {
@@ -52,7 +121,6 @@ impl BatchWriter {
     /// * `service_name` - 服务名称
     /// * `l2` - L2缓存后端
     /// * `config` - 批量写入器配置
-    /// * `wal` - WAL管理器
     ///
     /// # 返回值
     ///
@@ -66,13 +134,37 @@ impl BatchWriter {
             buffer: Arc::new(DashMap::new()),
             l2,
             flush_trigger: Arc::new(Notify::new()),
+            space_available: Arc::new(Notify::new()),
+            next_seq: AtomicU64::new(0),
             config,
             service_name,
             backpressure: Arc::new(Semaphore::new(backpressure_permits)),
             shutdown_token: Arc::new(tokio_util::sync::CancellationToken::new()),
+            recovery: Arc::new(FlushRecovery::new()),
         }
     }
 
+    /// 设置刷新失败时使用的WAL
+    ///
+    /// 设置后L2写入失败的条目转入WAL，由健康检查器在L2恢复后重放，不再留在缓冲区；
+    /// 未设置时失败的条目留在缓冲区等待下次刷新。无论是否设置，
+    /// 连续失败后的定期刷新都按刷新间隔指数退避（上限30秒），避免L2不可用时反复重试
+    ///
+    /// # 参数
+    ///
+    /// * `wal` - WAL管理器
+    ///
+    /// # 返回值
+    ///
+    /// 返回设置了WAL的批量写入器，需在 `start` 之前调用
+    pub fn with_wal(mut self, wal: Arc<WalManager>) -> Self {
+        self.recovery = Arc::new(FlushRecovery {
+            wal: Some(wal),
+            ..FlushRecovery::new()
+        });
+        self
+    }
+
     /// 创建带有默认配置的批量写入器
     pub fn new_with_default_config(service_name: String, l2: Arc<L2Backend>) -> Self {
         Self::new(service_name, l2, BatchWriterConfig::default())
@@ -93,6 +185,38 @@ impl BatchWriter {
         tracing::info!("批量写入器已停止: {}", self.service_name);
     }
 
+    /// 立即刷新缓冲区
+    ///
+    /// 循环写出所有待处理条目直到缓冲区清空，用于关闭前保证写入不丢失。
+    /// 不受失败退避的限制，配置了WAL时写入失败的条目转入WAL
+    ///
+    /// # 返回值
+    ///
+    /// 缓冲区清空时返回Ok，L2写入失败导致无法继续时返回错误
+    pub async fn flush(&self) -> Result<()> {
+        while !self.buffer.is_empty() {
+            let before = self.buffer.len();
+            Self::flush_buffer(
+                &self.buffer,
+                &self.l2,
+                &self.config,
+                &self.service_name,
+                &self.space_available,
+                &self.recovery,
+                true,
+            )
+            .await;
+
+            if self.buffer.len() >= before {
+                return Err(CacheError::L2Error(format!(
+                    "批量写入器刷新失败，剩余 {} 个条目",
+                    self.buffer.len()
+                )));
+            }
+        }
+        Ok(())
+    }
+
     /// 启动批量写入器
     ///
     /// 启动后台任务，定期或按需刷新缓冲区
@@ -103,8 +227,10 @@ impl BatchWriter {
         let config = self.config.clone();
         let service_name = self.service_name.clone();
         let shutdown_token = self.shutdown_token.clone();
+        let space_available = self.space_available.clone();
+        let recovery = self.recovery.clone();
 
-        tokio::spawn(async move {
+        spawn_named("batch-writer", &self.service_name, async move {
             let mut interval =
                 tokio::time::interval(Duration::from_millis(config.flush_interval_ms));
 
@@ -113,14 +239,14 @@ impl BatchWriter {
                     _ = shutdown_token.cancelled() => {
                         // 收到取消信号，执行最后一次刷新后退出
                         tracing::info!("批量写入器收到关闭信号，执行最后一次刷新");
-                        Self::flush(&buffer, &l2, &config, &service_name).await;
+                        Self::flush_buffer(&buffer, &l2, &config, &service_name, &space_available, &recovery, true).await;
                         break;
                     }
                     _ = interval.tick() => {
-                        Self::flush(&buffer, &l2, &config, &service_name).await;
+                        Self::flush_buffer(&buffer, &l2, &config, &service_name, &space_available, &recovery, false).await;
                     }
                     _ = trigger.notified() => {
-                        Self::flush(&buffer, &l2, &config, &service_name).await;
+                        Self::flush_buffer(&buffer, &l2, &config, &service_name, &space_available, &recovery, false).await;
                     }
                 }
             }
@@ -129,6 +255,16 @@ impl BatchWriter {
         });
     }
 
+    /// 获取当前待写入的条目数
+    pub fn pending_len(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// 检查键是否仍在待写入队列中
+    pub fn is_pending(&self, key: &str) -> bool {
+        self.buffer.contains_key(key)
+    }
+
     /// 将条目加入缓冲区
     ///
     /// # 参数
@@ -175,26 +311,23 @@ impl BatchWriter {
     pub async fn enqueue_operation(&self, operation: BatchOperation) -> Result<()> {
         // 检查是否已关闭
         if self.shutdown_token.is_cancelled() {
-            return Err(crate::error::CacheError::L2Error(
-                "批量写入器已关闭".to_string(),
-            ));
+            return Err(CacheError::L2Error("批量写入器已关闭".to_string()));
         }
 
         // 背压机制：等待许可，防止 buffer 无限增长
         let permit = tokio::time::timeout(Duration::from_secs(5), self.backpressure.acquire())
             .await
-            .map_err(|_| {
-                crate::error::CacheError::L2Error("批量写入器背压超时：缓冲区已满".to_string())
-            })?
-            .map_err(|_| {
-                crate::error::CacheError::L2Error("批量写入器背压信号量已关闭".to_string())
-            })?;
+            .map_err(|_| CacheError::L2Error("批量写入器背压超时：缓冲区已满".to_string()))?
+            .map_err(|_| CacheError::L2Error("批量写入器背压信号量已关闭".to_string()))?;
 
         let key = match &operation {
             BatchOperation::Set { key, .. } => key.clone(),
             BatchOperation::Delete { key } => key.clone(),
         };
 
+        // 队列达到最大深度时按背压策略处理（覆盖已有键不会增加队列深度）
+        self.apply_backpressure(&key).await?;
+
         // 检查 buffer 大小限制
         if self.buffer.len() >= self.config.max_buffer_size {
             tracing::warn!(
@@ -204,7 +337,8 @@ impl BatchWriter {
             self.flush_trigger.notify_one();
         }
 
-        self.buffer.insert(key, BufferEntry { operation });
+        let seq = self.next_seq.fetch_add(1, Ordering::Relaxed);
+        self.buffer.insert(key, BufferEntry { operation, seq });
 
         // 更新指标
         crate::metrics::GLOBAL_METRICS.set_batch_buffer_size(&self.service_name, self.buffer.len());
@@ -219,9 +353,86 @@ impl BatchWriter {
         Ok(())
     }
 
+    /// 按背压策略为新键腾出队列空间
+    ///
+    /// # 参数
+    ///
+    /// * `key` - 即将加入的缓存键
+    ///
+    /// # 返回值
+    ///
+    /// 队列有空间时返回Ok；Reject策略下队列已满，或Block策略下等待超过 `block_timeout_ms`
+    /// 仍无空间时返回 `CacheError::Backpressure`
+    async fn apply_backpressure(&self, key: &str) -> Result<()> {
+        let max_depth = self.config.max_queue_depth;
+
+        match self.config.backpressure {
+            BackpressurePolicy::Block => {
+                let deadline = tokio::time::Instant::now()
+                    + Duration::from_millis(self.config.block_timeout_ms);
+                loop {
+                    // 先注册通知再检查条件，避免错过刷新后的唤醒
+                    let notified = self.space_available.notified();
+                    if self.buffer.len() < max_depth || self.buffer.contains_key(key) {
+                        return Ok(());
+                    }
+
+                    self.flush_trigger.notify_one();
+                    tokio::select! {
+                        _ = notified => {}
+                        _ = tokio::time::sleep_until(deadline) => {
+                            return Err(CacheError::Backpressure(format!(
+                                "批量写入队列已达到最大深度 {}，等待 {}ms 后仍无空间",
+                                max_depth, self.config.block_timeout_ms
+                            )));
+                        }
+                        _ = self.shutdown_token.cancelled() => {
+                            return Err(CacheError::L2Error("批量写入器已关闭".to_string()));
+                        }
+                    }
+                }
+            }
+            BackpressurePolicy::DropOldest => {
+                while self.buffer.len() >= max_depth && !self.buffer.contains_key(key) {
+                    let oldest = self
+                        .buffer
+                        .iter()
+                        .min_by_key(|entry| entry.value().seq)
+                        .map(|entry| entry.key().clone());
+
+                    match oldest {
+                        Some(oldest) => {
+                            if self.buffer.remove(&oldest).is_some() {
+                                tracing::warn!(
+                                    "批量写入队列已满，丢弃最早的待写入条目: {}",
+                                    oldest
+                                );
+                                crate::metrics::GLOBAL_METRICS
+                                    .record_batch_dropped(&self.service_name);
+                            }
+                        }
+                        None => break,
+                    }
+                }
+                Ok(())
+            }
+            BackpressurePolicy::Reject => {
+                if self.buffer.len() >= max_depth && !self.buffer.contains_key(key) {
+                    self.flush_trigger.notify_one();
+                    return Err(CacheError::Backpressure(format!(
+                        "批量写入队列已达到最大深度 {}",
+                        max_depth
+                    )));
+                }
+                Ok(())
+            }
+        }
+    }
+
     /// 刷新缓冲区
     ///
-    /// 将缓冲区中的所有条目批量写入L2缓存
+    /// 将缓冲区中的条目批量写入L2缓存。写入失败时进入退避，配置了WAL则失败的条目转入WAL，
+    /// 否则留在缓冲区；刷新期间被新操作覆盖的键保留新操作
     ///
     /// # 参数
     ///
@@ -229,42 +440,50 @@ impl BatchWriter {
     /// * `l2` - L2缓存后端
     /// * `config` - 批量写入器配置
     /// * `service_name` - 服务名称
-    async fn flush(
+    /// * `space_available` - 队列空间释放通知
+    /// * `recovery` - 刷新失败后的恢复状态
+    /// * `force` - 是否忽略失败退避（手动刷新与关闭时）
+    async fn flush_buffer(
         buffer: &DashMap<String, BufferEntry>,
         l2: &L2Backend,
         config: &BatchWriterConfig,
         service_name: &str,
+        space_available: &Notify,
+        recovery: &FlushRecovery,
+        force: bool,
     ) {
-        if buffer.is_empty() {
+        if buffer.is_empty() || (!force && recovery.in_backoff()) {
             return;
         }
 
         // 分离set和delete操作
         let mut set_items = Vec::new();
+        let mut set_keys = Vec::new();
         let mut delete_keys = Vec::new();
-        let mut keys_to_remove = Vec::new();
+        let mut delete_seqs = Vec::new();
 
         for entry in buffer.iter() {
             let key = entry.key().clone();
+            let seq = entry.value().seq;
             match &entry.value().operation {
                 BatchOperation::Set { value, ttl, .. } => {
                     set_items.push((key.clone(), value.clone(), *ttl));
-                    keys_to_remove.push(key);
+                    set_keys.push((key, seq));
                 }
                 BatchOperation::Delete { .. } => {
                     delete_keys.push(key.clone());
-                    keys_to_remove.push(key);
+                    delete_seqs.push((key, seq));
                 }
             }
 
             // 达到最大批量大小就停止
-            if keys_to_remove.len() >= config.max_batch_size {
+            if set_keys.len() + delete_seqs.len() >= config.max_batch_size {
                 break;
             }
         }
 
-        // 执行批量操作
-        let mut all_success = true;
+        let mut done = Vec::new();
+        let mut failed = Vec::new();
 
         // 批量设置
         if !set_items.is_empty() {
@@ -272,10 +491,11 @@ impl BatchWriter {
             match l2.pipeline_set_batch(set_items).await {
                 Ok(_) => {
                     tracing::debug!("成功批量设置 {} 个条目", set_len);
+                    done.extend(set_keys);
                 }
                 Err(e) => {
                     tracing::error!("批量设置失败: {}", e);
-                    all_success = false;
+                    failed.extend(set_keys);
                 }
             }
         }
@@ -286,24 +506,100 @@ impl BatchWriter {
             match l2.pipeline_del_batch(delete_keys).await {
                 Ok(_) => {
                     tracing::debug!("成功批量删除 {} 个条目", del_len);
+                    done.extend(delete_seqs);
                 }
                 Err(e) => {
                     tracing::error!("批量删除失败: {}", e);
-                    all_success = false;
+                    failed.extend(delete_seqs);
                 }
             }
         }
 
-        // 如果所有操作都成功，从缓冲区中删除条目
-        if all_success {
-            for key in keys_to_remove {
-                buffer.remove(&key);
+        if failed.is_empty() {
+            recovery.record_success();
+        } else {
+            let delay = recovery.record_failure(Duration::from_millis(config.flush_interval_ms));
+            tracing::warn!(
+                "批量写入器 {} 有 {} 个条目写入L2失败，{:?} 后重试",
+                service_name,
+                failed.len(),
+                delay
+            );
+            if let Some(wal) = &recovery.wal {
+                done.extend(Self::move_to_wal(buffer, wal, failed).await);
             }
         }
 
+        // 从缓冲区中删除已写出（或已转入WAL）的条目
+        let mut removed = false;
+        for (key, seq) in done {
+            removed |= buffer
+                .remove_if(&key, |_, entry| entry.seq == seq)
+                .is_some();
+        }
+        if removed {
+            space_available.notify_waiters();
+        }
+
         // 更新指标
         crate::metrics::GLOBAL_METRICS.set_batch_buffer_size(service_name, buffer.len());
-        crate::metrics::GLOBAL_METRICS
-            .set_wal_size("batch_buffer", if all_success { 0 } else { buffer.len() });
+        crate::metrics::GLOBAL_METRICS.set_wal_size("batch_buffer", buffer.len());
+    }
+
+    /// 将写入失败的条目追加到WAL
+    ///
+    /// WAL的追加只进入其内存缓冲，返回前主动刷新WAL，保证移出缓冲区的条目已持久化
+    ///
+    /// # 参数
+    ///
+    /// * `buffer` - 缓冲区
+    /// * `wal` - WAL管理器
+    /// * `failed` - 写入失败的键及其入队序号
+    ///
+    /// # 返回值
+    ///
+    /// 返回已写入WAL的键及其入队序号，写入WAL失败的条目留在缓冲区
+    async fn move_to_wal(
+        buffer: &DashMap<String, BufferEntry>,
+        wal: &WalManager,
+        failed: Vec<(String, u64)>,
+    ) -> Vec<(String, u64)> {
+        let mut moved = Vec::with_capacity(failed.len());
+        for (key, seq) in failed {
+            let Some(operation) = buffer
+                .get(&key)
+                .filter(|entry| entry.seq == seq)
+                .map(|entry| entry.operation.clone())
+            else {
+                // 刷新期间已被新操作覆盖，新操作留待下次刷新
+                continue;
+            };
+            let entry = match operation {
+                BatchOperation::Set { key, value, ttl } => WalEntry {
+                    timestamp: std::time::SystemTime::now(),
+                    operation: Operation::Set,
+                    key,
+                    value: Some(value),
+                    ttl: ttl.map(|t| t as i64),
+                },
+                BatchOperation::Delete { key } => WalEntry {
+                    timestamp: std::time::SystemTime::now(),
+                    operation: Operation::Delete,
+                    key,
+                    value: None,
+                    ttl: None,
+                },
+            };
+            match wal.append(entry).await {
+                Ok(()) => moved.push((key, seq)),
+                Err(e) => tracing::error!("批量写入失败的条目写入WAL失败 {}: {}", key, e),
+            }
+        }
+        if !moved.is_empty() {
+            if let Err(e) = wal.flush().await {
+                tracing::error!("批量写入失败的条目刷新WAL失败: {}", e);
+            }
+        }
+        moved
     }
 }
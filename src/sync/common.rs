@@ -7,6 +7,7 @@
 use crate::backend::l2::L2Backend;
 use crate::error::Result;
 use crate::recovery::wal::WalManager;
+use serde::Deserialize;
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::sync::Notify;
@@ -24,6 +25,24 @@ pub enum BatchOperation {
     },
 }
 
+/// Block背压策略下等待队列空间的默认最长时间（毫秒）
+pub const DEFAULT_BLOCK_TIMEOUT_MS: u64 = 5000;
+
+/// 背压策略
+///
+/// 定义待写入队列达到最大深度时的处理方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BackpressurePolicy {
+    /// 等待队列腾出空间，超过 `block_timeout_ms` 仍无空间时按 `Reject` 处理
+    #[default]
+    Block,
+    /// 丢弃最早加入的待写入条目
+    DropOldest,
+    /// 拒绝新的写入，返回 `CacheError::Backpressure`
+    Reject,
+}
+
 /// 批量写入器的基本配置
 #[derive(Debug, Clone)]
 pub struct BatchWriterConfig {
@@ -33,6 +52,12 @@ pub struct BatchWriterConfig {
     pub flush_interval_ms: u64,
     /// 最大缓冲区大小（防止内存泄漏）
     pub max_buffer_size: usize,
+    /// 待写入队列的最大深度，达到后按背压策略处理
+    pub max_queue_depth: usize,
+    /// 背压策略
+    pub backpressure: BackpressurePolicy,
+    /// Block策略下等待队列空间的最长时间（毫秒）
+    pub block_timeout_ms: u64,
 }
 
 impl Default for BatchWriterConfig {
@@ -41,6 +66,9 @@ impl Default for BatchWriterConfig {
             max_batch_size: 1000,
             flush_interval_ms: 100,
             max_buffer_size: 10000, // 默认最大缓冲区大小为 10000
+            max_queue_depth: 10000,
+            backpressure: BackpressurePolicy::Block,
+            block_timeout_ms: DEFAULT_BLOCK_TIMEOUT_MS,
         }
     }
 }
@@ -6,15 +6,33 @@
 
 use crate::backend::l1::L1Backend;
 use crate::error::Result;
+use crate::metrics::GLOBAL_METRICS;
 use crate::recovery::health::HealthState;
+use crate::sync::common::calculate_retry_delay;
+use crate::utils::task::spawn_named;
 use futures::stream::StreamExt;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
-use tokio::sync::RwLock;
-use tracing::{debug, instrument};
+use std::time::Duration;
+use tokio::sync::{broadcast, RwLock};
+use tokio::task::JoinHandle;
+use tracing::{debug, info, instrument, warn};
+
+/// 批量失效消息中键之间的分隔符
+const BATCH_KEY_SEPARATOR: &str = "\n";
+
+/// 缓存纪元变更消息的前缀，后接新的纪元号；以NUL开头，不会与缓存键冲突
+const EPOCH_MESSAGE_PREFIX: &str = "\0epoch:";
+
+/// 重连退避的基础时间（毫秒）
+const RECONNECT_BASE_DELAY_MS: u64 = 100;
+/// 重连退避的最长等待时间
+const RECONNECT_MAX_DELAY: Duration = Duration::from_secs(30);
 
 /// 缓存失效订阅者
 ///
-/// 负责订阅Redis频道并处理缓存失效消息
+/// 负责订阅Redis频道并处理缓存失效消息。订阅连接断开后按指数退避自动重连并重新订阅，
+/// 连接状态记录在 `invalidation_subscriber_connected` 指标中
 pub struct InvalidationSubscriber {
     /// Redis客户端
     client: redis::Client,
@@ -24,6 +42,12 @@ pub struct InvalidationSubscriber {
     channel: String,
     /// 健康状态
     health_state: Arc<RwLock<HealthState>>,
+    /// 失效键的广播发送端，用于通知外部观察者
+    watchers: Option<broadcast::Sender<String>>,
+    /// 指标中使用的服务名称，未设置时使用频道名称
+    service_name: Option<String>,
+    /// 缓存纪元，收到纪元变更消息时更新
+    epoch: Option<Arc<AtomicU64>>,
 }
 
 impl InvalidationSubscriber {
@@ -50,64 +74,206 @@ impl InvalidationSubscriber {
             l1,
             channel,
             health_state,
+            watchers: None,
+            service_name: None,
+            epoch: None,
         }
     }
 
+    /// 设置失效键的广播发送端
+    ///
+    /// 收到的每条失效消息都会转发给该通道的所有接收者，与L1清理互不影响
+    ///
+    /// # 参数
+    ///
+    /// * `sender` - 广播发送端
+    ///
+    /// # 返回值
+    ///
+    /// 返回设置了广播发送端的订阅者
+    pub fn with_watchers(mut self, sender: broadcast::Sender<String>) -> Self {
+        self.watchers = Some(sender);
+        self
+    }
+
+    /// 设置指标中使用的服务名称
+    ///
+    /// # 参数
+    ///
+    /// * `service_name` - 服务名称
+    ///
+    /// # 返回值
+    ///
+    /// 返回设置了服务名称的订阅者
+    pub fn with_service_name(mut self, service_name: String) -> Self {
+        self.service_name = Some(service_name);
+        self
+    }
+
+    /// 设置缓存纪元
+    ///
+    /// 收到纪元变更消息时，纪元更新为较大的值并清空L1
+    ///
+    /// # 参数
+    ///
+    /// * `epoch` - 与客户端共享的缓存纪元
+    ///
+    /// # 返回值
+    ///
+    /// 返回设置了缓存纪元的订阅者
+    pub fn with_epoch(mut self, epoch: Arc<AtomicU64>) -> Self {
+        self.epoch = Some(epoch);
+        self
+    }
+
     /// 启动订阅者
     ///
-    /// 开始监听频道中的失效消息并处理
+    /// 首次订阅在当前任务中完成，失败时直接返回错误；之后在后台任务中监听失效消息，
+    /// 连接断开时自动重连
     ///
     /// # 返回值
     ///
-    /// 返回操作结果
+    /// 返回后台任务句柄
     #[instrument(skip(self), level = "debug")]
-    pub async fn start(self) -> Result<()> {
-        #[allow(deprecated)]
-        let conn = self.client.get_async_connection().await?;
-        let mut pubsub = conn.into_pubsub();
+    pub async fn start(self) -> Result<JoinHandle<()>> {
+        let pubsub = self.subscribe().await?;
+        self.set_connected(true);
+        debug!("InvalidationSubscriber: 启动订阅者，频道={}", self.channel);
+        // 未设置服务名称时以频道名称标识任务
+        let service = self
+            .service_name
+            .clone()
+            .unwrap_or_else(|| self.channel.clone());
+        Ok(spawn_named(
+            "invalidation-subscriber",
+            &service,
+            self.run(pubsub),
+        ))
+    }
+
+    /// 建立连接并订阅频道
+    async fn subscribe(&self) -> Result<redis::aio::PubSub> {
+        let mut pubsub = self.client.get_async_pubsub().await?;
         pubsub.subscribe(&self.channel).await?;
+        Ok(pubsub)
+    }
 
-        let _l1 = self.l1.clone();
-        let _health_state = self.health_state.clone();
-        debug!("InvalidationSubscriber: 启动订阅者，频道={}", self.channel);
-        tokio::spawn(async move {
-            let mut stream = pubsub.on_message();
-            while let Some(msg) = stream.next().await {
-                debug!("InvalidationSubscriber: 收到消息");
-                // 检查健康状态，只在Redis健康时处理失效消息
-                let state = _health_state.read().await;
-                debug!("InvalidationSubscriber: 当前健康状态={:?}", *state);
-                match *state {
-                    HealthState::Healthy => {
-                        drop(state);
-                        let payload: String = match msg.get_payload() {
-                            Ok(payload) => payload,
-                            Err(e) => {
-                                debug!("InvalidationSubscriber: 解析消息失败: {}", e);
-                                continue;
-                            }
-                        };
-                        debug!("InvalidationSubscriber: 处理失效消息，key={}", payload);
-                        // 只有在Redis健康时才处理失效消息
-                        let _ = _l1.delete(&payload).await;
-                        debug!("L1键已失效: {}", payload);
-                    }
-                    HealthState::Degraded { .. } | HealthState::Recovering { .. } => {
-                        drop(state);
-                        debug!("Skipping invalidation during Redis outage");
-                    }
-                    HealthState::WalReplaying { .. } => {
-                        drop(state);
-                        debug!("Skipping invalidation during WAL replay");
-                    }
+    /// 处理消息直到连接断开，然后重连并继续处理
+    async fn run(self, mut pubsub: redis::aio::PubSub) {
+        loop {
+            {
+                let mut stream = pubsub.on_message();
+                while let Some(msg) = stream.next().await {
+                    self.handle_message(msg).await;
                 }
             }
-        });
 
-        Ok(())
+            // 消息流结束表示订阅连接已断开
+            self.set_connected(false);
+            warn!(
+                "InvalidationSubscriber: 订阅连接断开，频道={}，准备重连",
+                self.channel
+            );
+            pubsub = self.reconnect().await;
+            self.set_connected(true);
+            info!("InvalidationSubscriber: 已重新订阅频道={}", self.channel);
+        }
+    }
+
+    /// 按指数退避重连，直到重新订阅成功
+    async fn reconnect(&self) -> redis::aio::PubSub {
+        let mut attempt = 0usize;
+        loop {
+            tokio::time::sleep(reconnect_delay(attempt)).await;
+            match self.subscribe().await {
+                Ok(pubsub) => return pubsub,
+                Err(e) => {
+                    attempt += 1;
+                    warn!(
+                        "InvalidationSubscriber: 重连失败（第{}次），频道={}: {}",
+                        attempt, self.channel, e
+                    );
+                }
+            }
+        }
+    }
+
+    /// 处理单条失效消息
+    async fn handle_message(&self, msg: redis::Msg) {
+        debug!("InvalidationSubscriber: 收到消息");
+        let payload: String = match msg.get_payload() {
+            Ok(payload) => payload,
+            Err(e) => {
+                debug!("InvalidationSubscriber: 解析消息失败: {}", e);
+                return;
+            }
+        };
+
+        if let Some(epoch) = payload.strip_prefix(EPOCH_MESSAGE_PREFIX) {
+            self.handle_epoch(epoch);
+            return;
+        }
+
+        // 检查健康状态，只在Redis健康时处理失效消息
+        let state = self.health_state.read().await;
+        debug!("InvalidationSubscriber: 当前健康状态={:?}", *state);
+        match *state {
+            HealthState::Healthy => {
+                drop(state);
+                debug!("InvalidationSubscriber: 处理失效消息，key={}", payload);
+                // 只有在Redis健康时才处理失效消息，批量消息逐个键失效
+                for key in payload.split(BATCH_KEY_SEPARATOR) {
+                    let _ = self.l1.delete(key).await;
+                    debug!("L1键已失效: {}", key);
+                }
+            }
+            HealthState::Degraded { .. } | HealthState::Recovering { .. } => {
+                drop(state);
+                debug!("Skipping invalidation during Redis outage");
+            }
+            HealthState::WalReplaying { .. } => {
+                drop(state);
+                debug!("Skipping invalidation during WAL replay");
+            }
+        }
+
+        // 没有观察者时发送失败，直接忽略
+        if let Some(watchers) = &self.watchers {
+            for key in payload.split(BATCH_KEY_SEPARATOR) {
+                let _ = watchers.send(key.to_string());
+            }
+        }
+    }
+
+    /// 处理缓存纪元变更：旧纪元的键不会再被访问，无论健康状态如何都清空L1
+    fn handle_epoch(&self, epoch: &str) {
+        let Ok(epoch) = epoch.parse::<u64>() else {
+            debug!("InvalidationSubscriber: 无效的纪元消息: {}", epoch);
+            return;
+        };
+        if let Some(current) = &self.epoch {
+            current.fetch_max(epoch, Ordering::SeqCst);
+        }
+        if let Err(e) = self.l1.clear() {
+            warn!("InvalidationSubscriber: 纪元变更后清空L1失败: {}", e);
+        }
+        info!(
+            "InvalidationSubscriber: 缓存纪元变更为{}，频道={}",
+            epoch, self.channel
+        );
+    }
+
+    fn set_connected(&self, connected: bool) {
+        let service = self.service_name.as_deref().unwrap_or(&self.channel);
+        GLOBAL_METRICS.set_invalidation_subscriber_connected(service, connected);
     }
 }
 
+/// 计算第 `attempt` 次重连前的等待时间，上限为 `RECONNECT_MAX_DELAY`
+fn reconnect_delay(attempt: usize) -> Duration {
+    calculate_retry_delay(attempt.min(16), RECONNECT_BASE_DELAY_MS).min(RECONNECT_MAX_DELAY)
+}
+
 /// 缓存失效发布者
 ///
 /// 负责向Redis频道发布缓存失效消息
@@ -154,4 +320,40 @@ impl InvalidationPublisher {
         debug!("InvalidationPublisher: 失效消息发布成功，key={}", key);
         Ok(())
     }
+
+    /// 发布缓存纪元变更消息
+    ///
+    /// 订阅者收到后更新纪元并清空L1
+    ///
+    /// # 参数
+    ///
+    /// * `epoch` - 新的纪元号
+    ///
+    /// # 返回值
+    ///
+    /// 返回操作结果
+    #[instrument(skip(self), level = "debug")]
+    pub async fn publish_epoch(&self, epoch: u64) -> Result<()> {
+        self.publish(&format!("{}{}", EPOCH_MESSAGE_PREFIX, epoch))
+            .await
+    }
+
+    /// 以单条消息发布多个键的失效通知
+    ///
+    /// 消息内容为以换行符分隔的键列表，订阅者逐个处理；只有一个键时与 [`publish`](Self::publish) 相同
+    ///
+    /// # 参数
+    ///
+    /// * `keys` - 失效的键
+    ///
+    /// # 返回值
+    ///
+    /// 返回操作结果
+    #[instrument(skip(self, keys), level = "debug", fields(key_count = keys.len()))]
+    pub async fn publish_many(&self, keys: &[&str]) -> Result<()> {
+        if keys.is_empty() {
+            return Ok(());
+        }
+        self.publish(&keys.join(BATCH_KEY_SEPARATOR)).await
+    }
 }
@@ -8,6 +8,7 @@ use super::common::*;
 use crate::backend::l2::L2Backend;
 use crate::error::{CacheError, Result};
 use crate::recovery::wal::WalManager;
+use crate::utils::task::spawn_named;
 use dashmap::DashMap;
 use std::cmp::Reverse;
 use std::collections::BinaryHeap;
@@ -53,6 +54,8 @@ type PriorityQueue = Arc<RwLock<BinaryHeap<Reverse<PriorityItem>>>>;
 struct OptimizedBufferEntry {
     operation: BatchOperation,
     retry_count: Arc<AtomicUsize>,
+    /// 写入代数，用于识别刷新期间被覆盖的条目
+    generation: u64,
 }
 
 impl OptimizedBufferEntry {
@@ -60,9 +63,15 @@ impl OptimizedBufferEntry {
         Self {
             operation,
             retry_count: Arc::new(AtomicUsize::new(0)),
+            generation: 0,
         }
     }
 
+    fn with_generation(mut self, generation: u64) -> Self {
+        self.generation = generation;
+        self
+    }
+
     fn increment_retry(&self) -> usize {
         self.retry_count.fetch_add(1, Ordering::Relaxed)
     }
@@ -123,6 +132,8 @@ pub struct BatchWriterStats {
     pub average_batch_size: AtomicU64,
     pub total_bytes_written: AtomicU64,
     pub compression_ratio: AtomicU64, // 百分比 * 100
+    /// 在刷新前被同键后续写入覆盖的操作数
+    pub coalesced_operations: AtomicU64,
 }
 
 /// 优化的批量写入器
@@ -139,6 +150,8 @@ pub struct OptimizedBatchWriter {
     flush_trigger: Arc<Notify>,
     /// 背压触发器
     backpressure_trigger: Arc<Notify>,
+    /// 队列空间释放通知（用于 Block 背压策略）
+    space_available: Arc<Notify>,
     /// 配置
     config: OptimizedBatchWriterConfig,
     /// 服务名称
@@ -149,6 +162,8 @@ pub struct OptimizedBatchWriter {
     shutdown: Arc<Notify>,
     /// 背压状态
     backpressure_active: Arc<RwLock<bool>>,
+    /// 下一个条目的写入代数
+    next_generation: AtomicU64,
 }
 
 impl OptimizedBatchWriter {
@@ -166,11 +181,13 @@ impl OptimizedBatchWriter {
             wal,
             flush_trigger: Arc::new(Notify::new()),
             backpressure_trigger: Arc::new(Notify::new()),
+            space_available: Arc::new(Notify::new()),
             config,
             service_name,
             stats: Arc::new(BatchWriterStats::default()),
             shutdown: Arc::new(Notify::new()),
             backpressure_active: Arc::new(RwLock::new(false)),
+            next_generation: AtomicU64::new(0),
         }
     }
 
@@ -205,8 +222,50 @@ impl OptimizedBatchWriter {
         }
     }
 
+    /// 立即刷新缓冲区
+    ///
+    /// 循环写出所有待处理条目直到缓冲区清空，用于关闭前保证写入不丢失
+    ///
+    /// # 返回值
+    ///
+    /// 缓冲区清空时返回Ok，L2写入持续失败导致无法继续时返回错误
+    pub async fn flush(&self) -> Result<()> {
+        while !self.buffer.is_empty() {
+            let before = self.buffer.len();
+            Self::flush_batch(
+                &self.buffer,
+                &self.priority_queue,
+                &self.l2,
+                &self.config,
+                &self.stats,
+                &self.service_name,
+            )
+            .await;
+            self.space_available.notify_waiters();
+
+            if self.buffer.len() >= before {
+                return Err(CacheError::L2Error(format!(
+                    "批量写入器刷新未能取得进展，剩余 {} 个条目",
+                    self.buffer.len()
+                )));
+            }
+        }
+        Ok(())
+    }
+
     /// 将操作加入缓冲区（带背压控制）
+    ///
+    /// 缓冲区按键合并：同一键在刷新前的多次操作只保留最后一次（后写覆盖），
+    /// 刷新时对该键只产生一次L2写入
     pub async fn enqueue_operation(&self, operation: BatchOperation, priority: u8) -> Result<()> {
+        let key = match &operation {
+            BatchOperation::Set { key, .. } => key.clone(),
+            BatchOperation::Delete { key } => key.clone(),
+        };
+
+        // 队列达到最大深度时按背压策略处理（覆盖已有键不会增加队列深度）
+        self.apply_queue_depth(&key).await?;
+
         // 检查背压状态
         if self.is_backpressure_active().await {
             return Err(CacheError::L2Error("缓冲区已满，请稍后重试".to_string()));
@@ -217,12 +276,9 @@ impl OptimizedBatchWriter {
             return Err(CacheError::L2Error("缓冲区已达到最大容量".to_string()));
         }
 
-        let key = match &operation {
-            BatchOperation::Set { key, .. } => key.clone(),
-            BatchOperation::Delete { key } => key.clone(),
-        };
-
-        let entry = OptimizedBufferEntry::new(operation.clone(), priority);
+        let generation = self.next_generation.fetch_add(1, Ordering::Relaxed);
+        let entry =
+            OptimizedBufferEntry::new(operation.clone(), priority).with_generation(generation);
 
         // 写入WAL（如果启用）
         if self.config.enable_wal {
@@ -242,11 +298,13 @@ impl OptimizedBatchWriter {
             self.wal.append(entry).await?;
         }
 
-        // 添加到缓冲区
-        self.buffer.insert(key.clone(), entry);
-
-        // 添加到优先级队列（O(log n)插入）
-        {
+        // 添加到缓冲区，键已在缓冲区中时覆盖旧操作，沿用其优先级队列条目
+        if self.buffer.insert(key.clone(), entry).is_some() {
+            self.stats
+                .coalesced_operations
+                .fetch_add(1, Ordering::Relaxed);
+        } else {
+            // 添加到优先级队列（O(log n)插入）
             let mut queue = self.priority_queue.write().await;
             let item = PriorityItem {
                 key: key.clone(),
@@ -267,6 +325,98 @@ impl OptimizedBatchWriter {
         Ok(())
     }
 
+    /// 按 `base.backpressure` 策略为新键腾出队列空间
+    ///
+    /// # 参数
+    ///
+    /// * `key` - 即将加入的缓存键
+    ///
+    /// # 返回值
+    ///
+    /// 队列有空间时返回Ok；Reject策略下队列已满，或Block策略下等待超过 `base.block_timeout_ms`
+    /// 仍无空间时返回 `CacheError::Backpressure`
+    async fn apply_queue_depth(&self, key: &str) -> Result<()> {
+        let base = &self.config.base;
+        let max_depth = base.max_queue_depth;
+
+        match base.backpressure {
+            BackpressurePolicy::Block => {
+                let deadline =
+                    tokio::time::Instant::now() + Duration::from_millis(base.block_timeout_ms);
+                loop {
+                    // 先注册通知再检查条件，避免错过刷新后的唤醒
+                    let notified = self.space_available.notified();
+                    if self.buffer.len() < max_depth || self.buffer.contains_key(key) {
+                        return Ok(());
+                    }
+
+                    self.flush_trigger.notify_one();
+                    tokio::select! {
+                        _ = notified => {}
+                        _ = tokio::time::sleep_until(deadline) => {
+                            return Err(CacheError::Backpressure(format!(
+                                "批量写入队列已达到最大深度 {}，等待 {}ms 后仍无空间",
+                                max_depth, base.block_timeout_ms
+                            )));
+                        }
+                    }
+                }
+            }
+            BackpressurePolicy::DropOldest => {
+                while self.buffer.len() >= max_depth && !self.buffer.contains_key(key) {
+                    let oldest = self
+                        .buffer
+                        .iter()
+                        .min_by_key(|entry| entry.value().generation)
+                        .map(|entry| entry.key().clone());
+
+                    match oldest {
+                        Some(oldest) => {
+                            if self.buffer.remove(&oldest).is_some() {
+                                tracing::warn!(
+                                    "批量写入队列已满，丢弃最早的待写入条目: {}",
+                                    oldest
+                                );
+                                self.stats
+                                    .dropped_operations
+                                    .fetch_add(1, Ordering::Relaxed);
+                                crate::metrics::GLOBAL_METRICS
+                                    .record_batch_dropped(&self.service_name);
+                            }
+                        }
+                        None => break,
+                    }
+                }
+                Ok(())
+            }
+            BackpressurePolicy::Reject => {
+                if self.buffer.len() >= max_depth && !self.buffer.contains_key(key) {
+                    self.flush_trigger.notify_one();
+                    return Err(CacheError::Backpressure(format!(
+                        "批量写入队列已达到最大深度 {}",
+                        max_depth
+                    )));
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// 丢弃缓冲区中该键尚未写出的操作
+    ///
+    /// 用于直接删除L2中的键之前，避免稍后刷新的旧值覆盖删除结果
+    ///
+    /// # 参数
+    ///
+    /// * `key` - 缓存键
+    ///
+    /// # 返回值
+    ///
+    /// 缓冲区中存在该键的待写操作时返回true
+    pub fn discard(&self, key: &str) -> bool {
+        self.buffer.remove(key).is_some()
+    }
+
     /// 批量设置（便捷方法）
     pub async fn batch_set(
         &self,
@@ -291,21 +441,24 @@ impl OptimizedBatchWriter {
         let priority_queue = self.priority_queue.clone();
         let l2 = self.l2.clone();
         let flush_trigger = self.flush_trigger.clone();
+        let space_available = self.space_available.clone();
         let shutdown = self.shutdown.clone();
         let config = self.config.clone();
         let stats = self.stats.clone();
         let service_name = self.service_name.clone();
 
-        tokio::spawn(async move {
+        spawn_named("batch-flush", &self.service_name, async move {
             let mut interval = interval(Duration::from_millis(config.base.flush_interval_ms));
 
             loop {
                 tokio::select! {
                     _ = interval.tick() => {
                         Self::flush_batch(&buffer, &priority_queue, &l2, &config, &stats, &service_name).await;
+                        space_available.notify_waiters();
                     }
                     _ = flush_trigger.notified() => {
                         Self::flush_batch(&buffer, &priority_queue, &l2, &config, &stats, &service_name).await;
+                        space_available.notify_waiters();
                     }
                     _ = shutdown.notified() => {
                         tracing::info!("刷新任务收到关闭信号");
@@ -340,7 +493,7 @@ impl OptimizedBatchWriter {
         let config = self.config.clone();
         let shutdown = self.shutdown.clone();
 
-        tokio::spawn(async move {
+        spawn_named("batch-backpressure", &self.service_name, async move {
             let mut interval = interval(Duration::from_millis(100));
 
             loop {
@@ -372,7 +525,7 @@ impl OptimizedBatchWriter {
         let shutdown = self.shutdown.clone();
         let service_name = self.service_name.clone();
 
-        tokio::spawn(async move {
+        spawn_named("batch-stats", &self.service_name, async move {
             let mut interval = interval(Duration::from_secs(60)); // 每分钟报告一次
 
             loop {
@@ -416,7 +569,7 @@ impl OptimizedBatchWriter {
                         // 检查重试次数
                         if entry.get_retry_count() >= config.max_retry_count {
                             stats.dropped_operations.fetch_add(1, Ordering::Relaxed);
-                            keys_to_remove.push(item.key);
+                            keys_to_remove.push((item.key, entry.generation, item.priority));
                             continue;
                         }
 
@@ -433,7 +586,7 @@ impl OptimizedBatchWriter {
                         // 直接使用所有权转移，避免克隆 operation
                         batch.push((key, entry.operation.clone()));
                         batch_size += operation_size;
-                        keys_to_remove.push(item.key);
+                        keys_to_remove.push((item.key, entry.generation, item.priority));
                     }
                 }
             }
@@ -505,7 +658,7 @@ impl OptimizedBatchWriter {
                                     });
                                 } else {
                                     stats.dropped_operations.fetch_add(1, Ordering::Relaxed);
-                                    keys_to_remove.push(key.clone());
+                                    keys_to_remove.push((key.clone(), entry.generation, 255));
                                 }
                             }
                         }
@@ -530,9 +683,18 @@ impl OptimizedBatchWriter {
             }
         }
 
-        // 清理已处理的条目
-        for key in keys_to_remove {
-            buffer.remove(&key);
+        // 清理已处理的条目，刷新期间被新操作覆盖的键保留在缓冲区并重新排队
+        for (key, generation, priority) in keys_to_remove {
+            let removed = buffer
+                .remove_if(&key, |_, entry| entry.generation == generation)
+                .is_some();
+            if !removed && buffer.contains_key(&key) {
+                priority_queue.write().await.push(Reverse(PriorityItem {
+                    key,
+                    priority,
+                    timestamp: Instant::now(),
+                }));
+            }
         }
 
         // 更新统计
@@ -651,6 +813,9 @@ impl OptimizedBatchWriter {
                 self.stats.total_bytes_written.load(Ordering::Relaxed),
             ),
             compression_ratio: AtomicU64::new(self.stats.compression_ratio.load(Ordering::Relaxed)),
+            coalesced_operations: AtomicU64::new(
+                self.stats.coalesced_operations.load(Ordering::Relaxed),
+            ),
         }
     }
 }
@@ -6,10 +6,29 @@
 
 use crate::backend::{l1::L1Backend, l2::L2Backend};
 use crate::error::Result;
+use crate::metrics::GLOBAL_METRICS;
 use crate::recovery::health::HealthState;
 use dashmap::DashMap;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
 use std::sync::Arc;
-use tokio::sync::{Notify, RwLock};
+use tokio::sync::{Notify, OwnedSemaphorePermit, RwLock, Semaphore};
+
+/// 推广统计快照
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PromotionStats {
+    /// 完成的推广次数
+    pub promotions_total: u64,
+    /// 被跳过的推广次数（已在L1中、正在推广或TTL过短）
+    pub promotions_skipped_total: u64,
+    /// 当前正在处理的推广任务数
+    pub queue_depth: usize,
+    /// 因推广任务数达到上限而被丢弃的推广次数
+    pub promotions_dropped_total: u64,
+    /// 当前占用的推广任务数
+    pub active_tasks: usize,
+    /// 推广任务数的历史峰值
+    pub peak_active_tasks: usize,
+}
 
 /// 推广管理器
 ///
@@ -24,6 +43,35 @@ pub struct PromotionManager {
     /// 健康状态
     #[allow(dead_code)]
     health_state: Arc<RwLock<HealthState>>,
+    /// 服务名称，用于指标标签
+    service_name: Option<String>,
+    /// 完成的推广次数
+    promotions_total: AtomicU64,
+    /// 被跳过的推广次数
+    promotions_skipped_total: AtomicU64,
+    /// 推广任务数上限，None表示不限制
+    task_limiter: Option<Arc<Semaphore>>,
+    /// 被丢弃的推广次数
+    promotions_dropped_total: AtomicU64,
+    /// 当前占用的推广任务数
+    active_tasks: AtomicUsize,
+    /// 推广任务数的历史峰值
+    peak_active_tasks: AtomicUsize,
+}
+
+/// 推广任务占用的名额
+///
+/// 由 [`PromotionManager::try_start_task`] 获取，随推广任务结束释放
+pub struct PromotionTask {
+    manager: Arc<PromotionManager>,
+    _permit: Option<OwnedSemaphorePermit>,
+}
+
+impl Drop for PromotionTask {
+    fn drop(&mut self) {
+        let active = self.manager.active_tasks.fetch_sub(1, Ordering::Relaxed) - 1;
+        self.manager.update_active_tasks(active);
+    }
 }
 
 impl PromotionManager {
@@ -48,12 +96,127 @@ impl PromotionManager {
             l1,
             l2,
             health_state,
+            service_name: None,
+            promotions_total: AtomicU64::new(0),
+            promotions_skipped_total: AtomicU64::new(0),
+            task_limiter: None,
+            promotions_dropped_total: AtomicU64::new(0),
+            active_tasks: AtomicUsize::new(0),
+            peak_active_tasks: AtomicUsize::new(0),
+        }
+    }
+
+    /// 限制同时进行的推广任务数
+    ///
+    /// 达到上限后L2命中不再推广到L1，新的推广直接丢弃并计入 `promotions_dropped_total`，不排队等待
+    ///
+    /// # 参数
+    ///
+    /// * `max_tasks` - 推广任务数上限
+    ///
+    /// # 返回值
+    ///
+    /// 返回设置了任务数上限的推广管理器
+    pub fn with_max_tasks(mut self, max_tasks: usize) -> Self {
+        self.task_limiter = Some(Arc::new(Semaphore::new(max_tasks)));
+        self
+    }
+
+    /// 设置服务名称，推广指标以该名称为标签上报
+    ///
+    /// # 参数
+    ///
+    /// * `service_name` - 服务名称
+    ///
+    /// # 返回值
+    ///
+    /// 返回设置了服务名称的推广管理器
+    pub fn with_service_name(mut self, service_name: String) -> Self {
+        self.service_name = Some(service_name);
+        self
+    }
+
+    /// 获取推广统计
+    ///
+    /// # 返回值
+    ///
+    /// 返回当前的推广统计快照
+    pub fn stats(&self) -> PromotionStats {
+        PromotionStats {
+            promotions_total: self.promotions_total.load(Ordering::Relaxed),
+            promotions_skipped_total: self.promotions_skipped_total.load(Ordering::Relaxed),
+            queue_depth: self.in_flight.len(),
+            promotions_dropped_total: self.promotions_dropped_total.load(Ordering::Relaxed),
+            active_tasks: self.active_tasks.load(Ordering::Relaxed),
+            peak_active_tasks: self.peak_active_tasks.load(Ordering::Relaxed),
+        }
+    }
+
+    /// 为一次推广占用任务名额
+    ///
+    /// 未设置任务数上限时总是成功；名额已用尽时记录一次丢弃并返回None
+    ///
+    /// # 返回值
+    ///
+    /// 返回推广任务名额，推广结束后丢弃以释放
+    pub fn try_start_task(self: &Arc<Self>) -> Option<PromotionTask> {
+        let permit = match &self.task_limiter {
+            Some(limiter) => match limiter.clone().try_acquire_owned() {
+                Ok(permit) => Some(permit),
+                Err(_) => {
+                    self.record_dropped();
+                    return None;
+                }
+            },
+            None => None,
+        };
+        let active = self.active_tasks.fetch_add(1, Ordering::Relaxed) + 1;
+        self.peak_active_tasks.fetch_max(active, Ordering::Relaxed);
+        self.update_active_tasks(active);
+        Some(PromotionTask {
+            manager: self.clone(),
+            _permit: permit,
+        })
+    }
+
+    fn record_dropped(&self) {
+        self.promotions_dropped_total
+            .fetch_add(1, Ordering::Relaxed);
+        if let Some(service) = &self.service_name {
+            GLOBAL_METRICS.record_promotion_dropped(service);
+        }
+    }
+
+    fn update_active_tasks(&self, active: usize) {
+        if let Some(service) = &self.service_name {
+            GLOBAL_METRICS.set_promotion_active_tasks(service, active);
+        }
+    }
+
+    fn record_skipped(&self) {
+        self.promotions_skipped_total
+            .fetch_add(1, Ordering::Relaxed);
+        if let Some(service) = &self.service_name {
+            GLOBAL_METRICS.record_promotion_skipped(service);
+        }
+    }
+
+    fn record_promoted(&self) {
+        self.promotions_total.fetch_add(1, Ordering::Relaxed);
+        if let Some(service) = &self.service_name {
+            GLOBAL_METRICS.record_promotion(service);
+        }
+    }
+
+    fn update_queue_depth(&self) {
+        if let Some(service) = &self.service_name {
+            GLOBAL_METRICS.set_promotion_queue_depth(service, self.in_flight.len());
         }
     }
 
     /// 推广缓存项
     ///
-    /// 将L2缓存中的数据推广到L1缓存
+    /// 将L2缓存中的数据推广到L1缓存。键已在L1中、已有相同键正在推广或L2剩余TTL过短时跳过推广
     ///
     /// # 参数
     ///
@@ -67,12 +230,19 @@ impl PromotionManager {
     pub async fn promote(&self, key: String, value: Vec<u8>, version: u64) -> Result<()> {
         let notify = self.in_flight.get(&key).map(|r| r.value().clone());
         if let Some(notify) = notify {
+            self.record_skipped();
             notify.notified().await;
             return Ok(());
         }
 
+        if self.l1.get_bytes(&key).await?.is_some() {
+            self.record_skipped();
+            return Ok(());
+        }
+
         let notify = Arc::new(Notify::new());
         self.in_flight.insert(key.clone(), notify.clone());
+        self.update_queue_depth();
 
         let result = async {
             let l2_ttl = self.l2.ttl(&key).await?;
@@ -80,18 +250,24 @@ impl PromotionManager {
 
             let actual_ttl = match l2_ttl {
                 Some(ttl) if ttl > 5 => ttl.min(l1_default_ttl),
-                _ => return Ok(()),
+                _ => {
+                    self.record_skipped();
+                    return Ok(());
+                }
             };
 
             self.l1
                 .set_with_metadata(&key, value, actual_ttl, version)
-                .await
+                .await?;
+            self.record_promoted();
+            Ok(())
         }
         .await;
 
         if let Some((_, n)) = self.in_flight.remove(&key) {
             n.notify_waiters();
         }
+        self.update_queue_depth();
 
         result
     }
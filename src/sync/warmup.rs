@@ -12,6 +12,15 @@ use std::sync::Arc;
 use tokio::sync::RwLock;
 use tracing::{debug, info, warn};
 
+/// 记录整体预热进度所使用的状态键
+pub const WARMUP_STATUS_ALL: &str = "all";
+
+/// 预热结果中最多保留的失败明细条数，超出部分只计入 `failed`
+pub const MAX_WARMUP_FAILURES: usize = 100;
+
+/// 加载函数未返回某个键且未给出原因时记录的错误信息
+const NOT_LOADED: &str = "not returned by loader";
+
 pub struct WarmupManager {
     service_name: String,
     config: CacheWarmupConfig,
@@ -26,11 +35,16 @@ pub enum WarmupStatus {
     Failed { error: String },
 }
 
+#[derive(Debug, Clone)]
 pub struct WarmupResult {
     pub loaded: usize,
     pub failed: usize,
     pub skipped: usize,
     pub success: bool,
+    /// 失败的键及错误信息，最多保留 [`MAX_WARMUP_FAILURES`] 条
+    pub failures: Vec<(String, String)>,
+    /// 被跳过的数据源类型，如 `database`
+    pub skipped_sources: Vec<String>,
 }
 
 impl WarmupResult {
@@ -40,6 +54,8 @@ impl WarmupResult {
             failed: 0,
             skipped: 1,
             success: true,
+            failures: Vec::new(),
+            skipped_sources: Vec::new(),
         }
     }
 
@@ -49,6 +65,56 @@ impl WarmupResult {
             failed: 0,
             skipped: 0,
             success: false,
+            failures: Vec::new(),
+            skipped_sources: Vec::new(),
+        }
+    }
+
+    /// 创建空的预热结果
+    pub(crate) fn empty() -> Self {
+        Self {
+            loaded: 0,
+            failed: 0,
+            skipped: 0,
+            success: true,
+            failures: Vec::new(),
+            skipped_sources: Vec::new(),
+        }
+    }
+
+    /// 记录一个预热失败的键
+    ///
+    /// 失败计数始终累加，明细超过 [`MAX_WARMUP_FAILURES`] 条后不再保存
+    pub(crate) fn record_failure(&mut self, key: String, error: String) {
+        self.failed = self.failed.saturating_add(1);
+        self.success = false;
+        if self.failures.len() < MAX_WARMUP_FAILURES {
+            self.failures.push((key, error));
+        }
+    }
+
+    /// 记录一个被跳过的数据源
+    fn record_skipped(&mut self, source: String) {
+        self.skipped = self.skipped.saturating_add(1);
+        self.skipped_sources.push(source);
+    }
+}
+
+/// 预热加载函数返回的一批数据
+///
+/// `failures` 记录加载失败的键及原因；既未加载也未记录原因的键按未返回处理。
+/// 加载函数也可以直接返回 `HashMap<String, Vec<u8>>`，此时缺失的键不带具体原因。
+#[derive(Debug, Default)]
+pub struct WarmupBatch {
+    pub loaded: HashMap<String, Vec<u8>>,
+    pub failures: HashMap<String, String>,
+}
+
+impl From<HashMap<String, Vec<u8>>> for WarmupBatch {
+    fn from(loaded: HashMap<String, Vec<u8>>) -> Self {
+        Self {
+            loaded,
+            failures: HashMap::new(),
         }
     }
 }
@@ -62,10 +128,11 @@ impl WarmupManager {
         }
     }
 
-    pub async fn run_warmup<F, Fut>(&self, load_fn: F) -> Result<WarmupResult>
+    pub async fn run_warmup<F, Fut, B>(&self, load_fn: F) -> Result<WarmupResult>
     where
         F: Fn(Vec<String>) -> Fut + Send + 'static,
-        Fut: Future<Output = Result<HashMap<String, Vec<u8>>>> + Send,
+        Fut: Future<Output = Result<B>> + Send,
+        B: Into<WarmupBatch>,
     {
         info!(
             "Starting cache warmup for service: {}, enabled: {}",
@@ -77,6 +144,12 @@ impl WarmupManager {
             return Ok(WarmupResult::skipped());
         }
 
+        self.set_status(WarmupStatus::InProgress {
+            progress: 0,
+            total: self.static_key_count(),
+        })
+        .await;
+
         let timeout = tokio::time::Duration::from_secs(self.config.timeout_seconds);
         let result = tokio::time::timeout(timeout, self.warmup_inner(load_fn)).await;
 
@@ -86,10 +159,19 @@ impl WarmupManager {
                     "Cache warmup completed: loaded={}, failed={}, skipped={}",
                     result.loaded, result.failed, result.skipped
                 );
+                self.set_status(WarmupStatus::Completed {
+                    loaded: result.loaded,
+                    failed: result.failed,
+                })
+                .await;
                 Ok(result)
             }
             Ok(Err(e)) => {
                 warn!("Cache warmup failed: {}", e);
+                self.set_status(WarmupStatus::Failed {
+                    error: e.to_string(),
+                })
+                .await;
                 Ok(WarmupResult::failed(e.to_string()))
             }
             Err(_) => {
@@ -97,19 +179,49 @@ impl WarmupManager {
                     "Cache warmup timed out after {} seconds",
                     self.config.timeout_seconds
                 );
+                self.set_status(WarmupStatus::Failed {
+                    error: "timeout".to_string(),
+                })
+                .await;
                 Ok(WarmupResult::failed("timeout".to_string()))
             }
         }
     }
 
-    async fn warmup_inner<F, Fut>(&self, load_fn: F) -> Result<WarmupResult>
+    /// 是否应在客户端创建时自动执行预热
+    ///
+    /// 需要同时启用预热、开启 `warmup_on_init` 并配置至少一个数据源
+    pub fn should_run_on_init(&self) -> bool {
+        self.config.enabled && self.config.warmup_on_init && !self.config.data_sources.is_empty()
+    }
+
+    /// 静态数据源中的键总数，用于计算预热进度
+    fn static_key_count(&self) -> usize {
+        self.config
+            .data_sources
+            .iter()
+            .map(|source| match source {
+                WarmupDataSource::Static { keys } => keys.len(),
+                _ => 0,
+            })
+            .sum()
+    }
+
+    async fn set_status(&self, status: WarmupStatus) {
+        self.warmup_status
+            .write()
+            .await
+            .insert(WARMUP_STATUS_ALL.to_string(), status);
+    }
+
+    async fn warmup_inner<F, Fut, B>(&self, load_fn: F) -> Result<WarmupResult>
     where
         F: Fn(Vec<String>) -> Fut + Send + 'static,
-        Fut: Future<Output = Result<HashMap<String, Vec<u8>>>> + Send,
+        Fut: Future<Output = Result<B>> + Send,
+        B: Into<WarmupBatch>,
     {
-        let mut total_loaded = 0usize;
-        let mut total_failed = 0usize;
-        let mut total_skipped = 0usize;
+        let mut result = WarmupResult::empty();
+        let total = self.static_key_count();
 
         for source in &self.config.data_sources {
             info!("Loading keys from source: {:?}", source);
@@ -118,17 +230,17 @@ impl WarmupManager {
                 WarmupDataSource::Static { keys } => keys.clone(),
                 WarmupDataSource::RedisList { .. } => {
                     warn!("RedisList warmup source requires custom implementation");
-                    total_skipped = total_skipped.saturating_add(1);
+                    result.record_skipped("redis_list".to_string());
                     continue;
                 }
                 WarmupDataSource::Database { .. } => {
                     warn!("Database warmup source requires custom implementation");
-                    total_skipped = total_skipped.saturating_add(1);
+                    result.record_skipped("database".to_string());
                     continue;
                 }
                 WarmupDataSource::Api { .. } => {
                     warn!("API warmup source requires custom implementation");
-                    total_skipped = total_skipped.saturating_add(1);
+                    result.record_skipped("api".to_string());
                     continue;
                 }
             };
@@ -143,17 +255,33 @@ impl WarmupManager {
                 let chunk_keys: Vec<String> = chunk.to_vec();
 
                 match load_fn(chunk_keys.clone()).await {
-                    Ok(data_map) => {
-                        let loaded = data_map.len();
-                        let failed = chunk_keys.len().saturating_sub(loaded);
-                        total_loaded = total_loaded.saturating_add(loaded);
-                        total_failed = total_failed.saturating_add(failed);
+                    Ok(batch) => {
+                        let mut batch = batch.into();
+                        for key in chunk_keys {
+                            if batch.loaded.contains_key(&key) {
+                                result.loaded = result.loaded.saturating_add(1);
+                            } else {
+                                let error = batch
+                                    .failures
+                                    .remove(&key)
+                                    .unwrap_or_else(|| NOT_LOADED.to_string());
+                                result.record_failure(key, error);
+                            }
+                        }
                     }
                     Err(e) => {
                         warn!("Failed to load data batch: {}", e);
-                        total_failed = total_failed.saturating_add(chunk_keys.len());
+                        let error = e.to_string();
+                        for key in chunk_keys {
+                            result.record_failure(key, error.clone());
+                        }
                     }
                 }
+                self.set_status(WarmupStatus::InProgress {
+                    progress: result.loaded.saturating_add(result.failed),
+                    total,
+                })
+                .await;
 
                 if interval_ms > 0 {
                     tokio::time::sleep(tokio::time::Duration::from_millis(interval_ms)).await;
@@ -161,12 +289,7 @@ impl WarmupManager {
             }
         }
 
-        Ok(WarmupResult {
-            loaded: total_loaded,
-            failed: total_failed,
-            skipped: total_skipped,
-            success: total_failed == 0,
-        })
+        Ok(result)
     }
 
     pub async fn get_status(&self, source_type: &str) -> WarmupStatus {
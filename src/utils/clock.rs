@@ -0,0 +1,69 @@
+//! Copyright (c) 2025-2026, Kirky.X
+//!
+//! MIT License
+//!
+//! 可注入的时钟
+//!
+//! 需要判断过期或按时间间隔执行的组件通过 [`Clock`] 获取当前时间，
+//! 测试中注入 [`MockClock`] 即可手动推进时间，无需真实等待。
+
+use std::fmt::Debug;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// 时钟抽象
+pub trait Clock: Send + Sync + Debug {
+    /// 获取当前时间
+    fn now(&self) -> Instant;
+}
+
+/// 系统单调时钟
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+/// 手动推进的模拟时钟
+///
+/// 创建时记录一个基准时间，此后只有调用 [`advance`](Self::advance) 才会前进
+#[derive(Debug)]
+pub struct MockClock {
+    base: Instant,
+    offset: Mutex<Duration>,
+}
+
+impl MockClock {
+    /// 创建以当前时间为基准的模拟时钟
+    pub fn new() -> Self {
+        Self {
+            base: Instant::now(),
+            offset: Mutex::new(Duration::ZERO),
+        }
+    }
+
+    /// 将时钟向前推进指定时长
+    ///
+    /// # 参数
+    ///
+    /// * `duration` - 推进的时长
+    pub fn advance(&self, duration: Duration) {
+        let mut offset = self.offset.lock().unwrap_or_else(|e| e.into_inner());
+        *offset += duration;
+    }
+}
+
+impl Default for MockClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clock for MockClock {
+    fn now(&self) -> Instant {
+        self.base + *self.offset.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
@@ -11,30 +11,109 @@
 //! - 服务名称生成工具
 //! - 输入验证工具
 //! - 敏感信息脱敏工具
+//! - 可注入的时钟
+//! - 命名的后台任务
 
+pub mod clock;
 pub mod redaction;
+pub mod task;
 
 use crate::config::{
-    CacheType, ClusterConfig, Config, L1Config, L2Config, RedisMode, SentinelConfig, ServiceConfig,
-    TwoLevelConfig,
+    CacheType, ClusterConfig, Config, KeyMode, L1Config, L2Config, RedisMode, SentinelConfig,
+    ServiceConfig, TwoLevelConfig, DEFAULT_FALLBACK_PERMIT_TIMEOUT_MS,
+    DEFAULT_TTL_DIVERGENCE_FACTOR,
 };
 use crate::error::CacheError;
+use crate::sync::common::DEFAULT_BLOCK_TIMEOUT_MS;
 use secrecy::SecretString;
+use std::borrow::Cow;
 use std::collections::HashMap;
 use std::sync::Once;
 use std::time::Duration;
+use tracing::Subscriber;
 use tracing_subscriber::fmt::format::FmtSpan;
+use tracing_subscriber::fmt::time::ChronoUtc;
+use tracing_subscriber::fmt::MakeWriter;
 use tracing_subscriber::EnvFilter;
 
 static INIT: Once = Once::new();
 
+/// 日志级别环境变量，优先于 `RUST_LOG`
+pub const OXCACHE_LOG_ENV: &str = "OXCACHE_LOG";
+
+/// 日志输出格式
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LogFormat {
+    /// 面向终端的可读格式
+    #[default]
+    Pretty,
+    /// 每行一个JSON对象，便于日志系统采集
+    Json,
+}
+
+/// 构建日志过滤器
+///
+/// 依次读取 `OXCACHE_LOG` 和 `RUST_LOG` 环境变量，均未设置或无法解析时使用 `debug` 级别
+pub fn log_filter() -> EnvFilter {
+    EnvFilter::try_from_env(OXCACHE_LOG_ENV)
+        .or_else(|_| EnvFilter::try_from_default_env())
+        .unwrap_or_else(|_| EnvFilter::new("debug"))
+}
+
+/// 构建输出JSON日志的订阅者
+///
+/// 时间戳使用RFC3339格式，并保留span关闭事件
+///
+/// # 参数
+///
+/// * `writer` - 日志输出目标
+///
+/// # 返回值
+///
+/// 返回可安装为全局或局部默认值的订阅者
+pub fn json_subscriber<W>(writer: W) -> impl Subscriber + Send + Sync + 'static
+where
+    W: for<'writer> MakeWriter<'writer> + Send + Sync + 'static,
+{
+    tracing_subscriber::fmt()
+        .json()
+        .with_timer(ChronoUtc::rfc_3339())
+        .with_span_events(FmtSpan::CLOSE)
+        .with_env_filter(log_filter())
+        .with_writer(writer)
+        .finish()
+}
+
+/// 以可读格式初始化全局日志
 pub fn setup_logging() {
+    setup_logging_with_format(LogFormat::Pretty);
+}
+
+/// 以JSON格式初始化全局日志
+pub fn setup_logging_json() {
+    setup_logging_with_format(LogFormat::Json);
+}
+
+/// 按指定格式初始化全局日志
+///
+/// 只有第一次调用生效，已存在全局订阅者时不做任何事
+///
+/// # 参数
+///
+/// * `format` - 日志输出格式
+pub fn setup_logging_with_format(format: LogFormat) {
     INIT.call_once(|| {
-        tracing_subscriber::fmt()
-            .with_span_events(FmtSpan::CLOSE)
-            .with_env_filter(EnvFilter::new("debug"))
-            .try_init()
-            .ok();
+        match format {
+            LogFormat::Pretty => tracing_subscriber::fmt()
+                .with_timer(ChronoUtc::rfc_3339())
+                .with_span_events(FmtSpan::CLOSE)
+                .with_env_filter(log_filter())
+                .try_init()
+                .ok(),
+            LogFormat::Json => {
+                tracing::subscriber::set_global_default(json_subscriber(std::io::stdout)).ok()
+            }
+        };
     });
 }
 
@@ -47,11 +126,23 @@ pub fn create_standalone_config() -> L2Config {
         command_timeout_ms: 5000,
         password: None,
         enable_tls: false,
+        tls_client_cert_path: None,
+        tls_client_key_path: None,
+        tls_ca_cert_path: None,
+        retry: Default::default(),
         sentinel: None,
         cluster: None,
         default_ttl: Some(3600),
         max_key_length: 256,
         max_value_size: 1024 * 1024 * 10, // 10MB
+        health: Default::default(),
+        database: None,
+        enable_versioning: true,
+        sharded: None,
+        compression: None,
+        eager_connect: false,
+        command_aliases: Default::default(),
+        username: None,
     }
 }
 
@@ -64,6 +155,10 @@ pub fn create_cluster_config() -> L2Config {
         command_timeout_ms: 5000,
         password: None,
         enable_tls: false,
+        tls_client_cert_path: None,
+        tls_client_key_path: None,
+        tls_ca_cert_path: None,
+        retry: Default::default(),
         sentinel: None,
         cluster: Some(ClusterConfig {
             nodes: vec![
@@ -78,6 +173,14 @@ pub fn create_cluster_config() -> L2Config {
         default_ttl: Some(3600),
         max_key_length: 256,
         max_value_size: 1024 * 1024 * 10, // 10MB
+        health: Default::default(),
+        database: None,
+        enable_versioning: true,
+        sharded: None,
+        compression: None,
+        eager_connect: false,
+        command_aliases: Default::default(),
+        username: None,
     }
 }
 
@@ -90,6 +193,10 @@ pub fn create_sentinel_config() -> L2Config {
         command_timeout_ms: 5000,
         password: None,
         enable_tls: false,
+        tls_client_cert_path: None,
+        tls_client_key_path: None,
+        tls_ca_cert_path: None,
+        retry: Default::default(),
         sentinel: Some(SentinelConfig {
             master_name: "mymaster".to_string(),
             nodes: vec![
@@ -97,11 +204,21 @@ pub fn create_sentinel_config() -> L2Config {
                 "127.0.0.1:26380".to_string(),
                 "127.0.0.1:26381".to_string(),
             ],
+            sentinel_password: None,
+            master_discovery_timeout_ms: None,
         }),
         cluster: None,
         default_ttl: Some(3600),
         max_key_length: 256,
         max_value_size: 1024 * 1024 * 10, // 10MB
+        health: Default::default(),
+        database: None,
+        enable_versioning: true,
+        sharded: None,
+        compression: None,
+        eager_connect: false,
+        command_aliases: Default::default(),
+        username: None,
     }
 }
 
@@ -114,11 +231,17 @@ pub fn create_default_config(service_name: &str, max_capacity: usize) -> Config
             cache_type: CacheType::TwoLevel,
             ttl: Some(300),
             serialization: None,
+            read_fallback_serializers: Vec::new(),
+            encryption: None,
             l1: Some(L1Config {
                 max_capacity: max_capacity as u64,
                 cleanup_interval_secs: 60,
                 max_key_length: 256,
                 max_value_size: 1024 * 1024 * 10,
+                l1_default_ttl: None,
+                enable_eviction_listener: false,
+                auto_tune: None,
+                shards: 1,
             }),
             l2: Some(L2Config {
                 mode: RedisMode::Standalone,
@@ -135,7 +258,25 @@ pub fn create_default_config(service_name: &str, max_capacity: usize) -> Config
                 warmup: None,
                 max_key_length: Some(256),
                 max_value_size: Some(1024 * 1024 * 10),
+                write_order: Default::default(),
+                max_concurrent_fallbacks: None,
+                fallback_permit_timeout_ms: DEFAULT_FALLBACK_PERMIT_TIMEOUT_MS,
+                ttl_divergence_factor: DEFAULT_TTL_DIVERGENCE_FACTOR,
+                write_behind: false,
+                read_your_writes: None,
+                secondary: None,
+                admission_threshold: None,
+                recovering_ttl_factor: None,
+                max_promotion_tasks: None,
+                batch_max_queue_depth: None,
+                batch_backpressure: Default::default(),
+                batch_block_timeout_ms: DEFAULT_BLOCK_TIMEOUT_MS,
             }),
+            key_mode: Default::default(),
+            key_group: None,
+            enable_metrics: true,
+            force_critical_metrics: false,
+            cache_epoch: 0,
         },
     );
 
@@ -188,6 +329,25 @@ pub fn generate_unique_service_name(base: &str) -> String {
     format!("{}_{}", base, uuid::Uuid::new_v4().simple())
 }
 
+/// 生成随机数，用于退避与探测间隔的抖动
+///
+/// 使用随机种子的哈希器生成，避免引入额外的随机数依赖
+pub(crate) fn random_u64() -> u64 {
+    use std::hash::{BuildHasher, Hasher};
+    std::collections::hash_map::RandomState::new()
+        .build_hasher()
+        .finish()
+}
+
+/// 计算64位FNV-1a哈希
+///
+/// 结果不依赖进程的随机种子，可用于跨进程、跨实例保持一致的分片与指纹
+pub(crate) fn fnv1a_64(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325u64, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
 const MAX_CACHE_KEY_LENGTH: usize = 1024;
 const VALID_KEY_CHARS: &[char] = &[
     'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q', 'r', 's',
@@ -223,7 +383,12 @@ pub fn validate_cache_key(key: &str) -> Result<(), CacheError> {
     Ok(())
 }
 
-pub fn validate_key_length(key: &str, max_length: usize) -> Result<(), CacheError> {
+/// 只校验缓存键的长度，不限制字符集，字符串键和二进制原始键通用
+pub fn validate_key_length(
+    key: &(impl AsRef<[u8]> + ?Sized),
+    max_length: usize,
+) -> Result<(), CacheError> {
+    let key = key.as_ref();
     if key.is_empty() {
         return Err(CacheError::InvalidInput(
             "Cache key cannot be empty".to_string(),
@@ -239,6 +404,50 @@ pub fn validate_key_length(key: &str, max_length: usize) -> Result<(), CacheErro
     Ok(())
 }
 
+/// 按键校验模式检查并规范化缓存键
+///
+/// `HashInvalid` 模式下，含非法字符的键被替换为 `<前缀>h-<128位murmur3十六进制>`，
+/// 前缀取第一个非法字符之前最后一个 `:` 及其之前的部分，使哈希后的键仍保留服务命名空间
+///
+/// # 参数
+///
+/// * `key` - 原始缓存键
+/// * `mode` - 键校验模式
+/// * `max_length` - 键的最大长度（按规范化后的键计算）
+///
+/// # 返回值
+///
+/// 返回可直接用于后端的键，合法键原样借用；校验失败时返回 `CacheError::InvalidInput`
+pub fn sanitize_cache_key(
+    key: &str,
+    mode: KeyMode,
+    max_length: usize,
+) -> Result<Cow<'_, str>, CacheError> {
+    let key = match mode {
+        KeyMode::Strict => {
+            validate_cache_key(key)?;
+            Cow::Borrowed(key)
+        }
+        KeyMode::Permissive => Cow::Borrowed(key),
+        KeyMode::HashInvalid => match key.find(|c| !VALID_KEY_CHARS.contains(&c)) {
+            None => Cow::Borrowed(key),
+            Some(invalid_at) => {
+                let prefix = key[..invalid_at].rfind(':').map_or("", |i| &key[..=i]);
+                let hash = murmur3::murmur3_x64_128(&mut std::io::Cursor::new(key), 0)
+                    .map_err(|e| CacheError::InvalidInput(format!("Cannot hash key: {}", e)))?;
+                Cow::Owned(format!("{}h-{:032x}", prefix, hash))
+            }
+        },
+    };
+    validate_key_length(key.as_bytes(), max_length)?;
+    Ok(key)
+}
+
+/// 校验二进制原始键，只限制长度（最长1024字节），不要求UTF-8或限定字符集
+pub fn validate_raw_cache_key(key: &[u8]) -> Result<(), CacheError> {
+    validate_key_length(key, MAX_CACHE_KEY_LENGTH)
+}
+
 pub fn validate_value_size(value: &[u8], max_size: usize) -> Result<(), CacheError> {
     if value.len() > max_size {
         return Err(CacheError::InvalidInput(format!(
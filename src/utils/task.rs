@@ -0,0 +1,80 @@
+//! Copyright (c) 2025-2026, Kirky.X
+//!
+//! MIT License
+//!
+//! 后台任务工具
+//!
+//! 为后台任务命名并附加span，便于通过tokio-console定位卡住的任务。
+//! 任务命名依赖tokio的不稳定API，需要同时启用 `tokio-console` 特性并以
+//! `RUSTFLAGS="--cfg tokio_unstable"` 编译；否则退化为普通的 `tokio::spawn`，仅保留span。
+
+use std::future::Future;
+use tokio::task::JoinHandle;
+use tracing::Instrument;
+
+/// 后台任务名称前缀
+pub const TASK_NAME_PREFIX: &str = "oxcache";
+
+/// 生成后台任务名称，格式为 `oxcache:{kind}:{service}`
+///
+/// # 参数
+///
+/// * `kind` - 任务类型，如 `health-checker`
+/// * `service` - 所属服务名称
+///
+/// # 返回值
+///
+/// 返回任务名称
+///
+/// # 示例
+/// ```
+/// use oxcache::utils::task::task_name;
+/// assert_eq!(task_name("health-checker", "users"), "oxcache:health-checker:users");
+/// ```
+pub fn task_name(kind: &str, service: &str) -> String {
+    format!("{}:{}:{}", TASK_NAME_PREFIX, kind, service)
+}
+
+/// 启动命名的后台任务
+///
+/// 任务在名为 `oxcache_task` 的span中运行，span携带任务类型与服务名称。
+///
+/// # 参数
+///
+/// * `kind` - 任务类型
+/// * `service` - 所属服务名称
+/// * `future` - 任务体
+///
+/// # 返回值
+///
+/// 返回任务句柄
+pub fn spawn_named<F>(kind: &str, service: &str, future: F) -> JoinHandle<F::Output>
+where
+    F: Future + Send + 'static,
+    F::Output: Send + 'static,
+{
+    let span = tracing::debug_span!("oxcache_task", task = kind, service = service);
+    spawn_with_name(&task_name(kind, service), future.instrument(span))
+}
+
+#[cfg(all(tokio_unstable, feature = "tokio-console"))]
+fn spawn_with_name<F>(name: &str, future: F) -> JoinHandle<F::Output>
+where
+    F: Future + Send + 'static,
+    F::Output: Send + 'static,
+{
+    // 仅在运行时外调用时失败，与 `tokio::spawn` 的行为一致
+    tokio::task::Builder::new()
+        .name(name)
+        .spawn(future)
+        .expect("failed to spawn background task")
+}
+
+#[cfg(not(all(tokio_unstable, feature = "tokio-console")))]
+fn spawn_with_name<F>(_name: &str, future: F) -> JoinHandle<F::Output>
+where
+    F: Future + Send + 'static,
+    F::Output: Send + 'static,
+{
+    tokio::spawn(future)
+}
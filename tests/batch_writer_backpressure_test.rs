@@ -0,0 +1,169 @@
+//! Copyright (c) 2025-2026, Kirky.X
+//!
+//! MIT License
+//!
+//! 批量写入器背压策略测试
+
+use common::redis_test_utils::create_standalone_config;
+use oxcache::backend::l2::L2Backend;
+use oxcache::config::L2Config;
+use oxcache::error::CacheError;
+use oxcache::metrics::GLOBAL_METRICS;
+use oxcache::sync::batch_writer::BatchWriter;
+use oxcache::sync::common::{BackpressurePolicy, BatchWriterConfig};
+use oxcache::utils::clock::MockClock;
+use std::sync::Arc;
+use std::time::Duration;
+
+mod common;
+
+/// 创建未启动后台刷新任务的批量写入器，保证条目停留在队列中
+async fn create_writer(service_name: &str, policy: BackpressurePolicy) -> Option<BatchWriter> {
+    if !common::is_redis_available().await {
+        println!("跳过测试: Redis不可用");
+        return None;
+    }
+
+    let l2 = Arc::new(L2Backend::new(&create_standalone_config()).await.ok()?);
+    let config = BatchWriterConfig {
+        max_batch_size: 100,
+        flush_interval_ms: 60_000,
+        max_buffer_size: 100,
+        max_queue_depth: 2,
+        backpressure: policy,
+        block_timeout_ms: 5_000,
+    };
+    Some(BatchWriter::new(service_name.to_string(), l2, config))
+}
+
+#[tokio::test]
+async fn test_backpressure_reject() {
+    let Some(writer) = create_writer("bp_reject", BackpressurePolicy::Reject).await else {
+        return;
+    };
+
+    writer
+        .enqueue("bp_reject:0".into(), b"0".to_vec(), Some(60))
+        .await
+        .unwrap();
+    writer
+        .enqueue("bp_reject:1".into(), b"1".to_vec(), Some(60))
+        .await
+        .unwrap();
+
+    let result = writer
+        .enqueue("bp_reject:2".into(), b"2".to_vec(), Some(60))
+        .await;
+    assert!(matches!(result, Err(CacheError::Backpressure(_))));
+
+    // 覆盖已在队列中的键不受限制
+    writer
+        .enqueue("bp_reject:1".into(), b"x".to_vec(), Some(60))
+        .await
+        .unwrap();
+    assert_eq!(writer.pending_len(), 2);
+}
+
+#[tokio::test]
+async fn test_backpressure_drop_oldest() {
+    let service_name = "bp_drop_oldest";
+    let Some(writer) = create_writer(service_name, BackpressurePolicy::DropOldest).await else {
+        return;
+    };
+
+    for i in 0..5 {
+        writer
+            .enqueue(format!("{}:{}", service_name, i), vec![i], Some(60))
+            .await
+            .unwrap();
+    }
+
+    assert_eq!(writer.pending_len(), 2);
+    assert!(writer.is_pending("bp_drop_oldest:3"));
+    assert!(writer.is_pending("bp_drop_oldest:4"));
+    assert_eq!(
+        GLOBAL_METRICS
+            .batch_dropped_total
+            .get(service_name)
+            .map(|v| *v.value()),
+        Some(3)
+    );
+}
+
+#[tokio::test]
+async fn test_backpressure_block() {
+    let Some(writer) = create_writer("bp_block", BackpressurePolicy::Block).await else {
+        return;
+    };
+    let writer = Arc::new(writer);
+
+    writer
+        .enqueue("bp_block:0".into(), b"0".to_vec(), Some(60))
+        .await
+        .unwrap();
+    writer
+        .enqueue("bp_block:1".into(), b"1".to_vec(), Some(60))
+        .await
+        .unwrap();
+
+    let blocked = tokio::spawn({
+        let writer = writer.clone();
+        async move {
+            writer
+                .enqueue("bp_block:2".into(), b"2".to_vec(), Some(60))
+                .await
+        }
+    });
+
+    // 队列已满，写入应保持等待
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    assert!(!blocked.is_finished());
+
+    // 刷新后腾出空间，等待中的写入完成
+    writer.flush().await.unwrap();
+    tokio::time::timeout(Duration::from_secs(5), blocked)
+        .await
+        .expect("blocked enqueue should complete after flush")
+        .unwrap()
+        .unwrap();
+    assert!(writer.is_pending("bp_block:2"));
+
+    writer.flush().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_backpressure_block_times_out() {
+    let l2 = Arc::new(L2Backend::in_memory(
+        &L2Config::default(),
+        Arc::new(MockClock::new()),
+    ));
+    let config = BatchWriterConfig {
+        max_batch_size: 100,
+        flush_interval_ms: 60_000,
+        max_buffer_size: 100,
+        max_queue_depth: 2,
+        backpressure: BackpressurePolicy::Block,
+        block_timeout_ms: 100,
+    };
+    // 未启动后台刷新任务，队列空间不会被释放
+    let writer = BatchWriter::new("bp_block_timeout".to_string(), l2, config);
+
+    writer
+        .enqueue("bp_block_timeout:0".into(), b"0".to_vec(), Some(60))
+        .await
+        .unwrap();
+    writer
+        .enqueue("bp_block_timeout:1".into(), b"1".to_vec(), Some(60))
+        .await
+        .unwrap();
+
+    // 等待超过block_timeout_ms后按Reject处理，而不是一直等待
+    let result = tokio::time::timeout(
+        Duration::from_secs(5),
+        writer.enqueue("bp_block_timeout:2".into(), b"2".to_vec(), Some(60)),
+    )
+    .await
+    .expect("blocked enqueue should give up after block_timeout_ms");
+    assert!(matches!(result, Err(CacheError::Backpressure(_))));
+    assert!(!writer.is_pending("bp_block_timeout:2"));
+}
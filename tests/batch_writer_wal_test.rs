@@ -0,0 +1,120 @@
+//! Copyright (c) 2025-2026, Kirky.X
+//!
+//! MIT License
+//!
+//! 批量写入器刷新失败转入WAL测试，L2使用可模拟不可用的内存后端
+
+use oxcache::backend::l2::L2Backend;
+use oxcache::config::L2Config;
+use oxcache::recovery::wal::{Operation, WalManager};
+use oxcache::sync::batch_writer::BatchWriter;
+use oxcache::sync::common::{BackpressurePolicy, BatchWriterConfig};
+use oxcache::utils::clock::MockClock;
+use std::sync::Arc;
+
+fn create_backend() -> Arc<L2Backend> {
+    Arc::new(L2Backend::in_memory(
+        &L2Config::default(),
+        Arc::new(MockClock::new()),
+    ))
+}
+
+fn create_config() -> BatchWriterConfig {
+    BatchWriterConfig {
+        max_batch_size: 100,
+        flush_interval_ms: 60_000,
+        max_buffer_size: 100,
+        max_queue_depth: 100,
+        backpressure: BackpressurePolicy::Reject,
+        block_timeout_ms: 5_000,
+    }
+}
+
+#[tokio::test]
+async fn test_failed_flush_moves_items_to_wal() {
+    let service = "batch_writer_wal_test";
+    let l2 = create_backend();
+    let wal = Arc::new(WalManager::new(service).await.unwrap());
+    wal.clear().await.unwrap();
+    let writer =
+        BatchWriter::new(service.to_string(), l2.clone(), create_config()).with_wal(wal.clone());
+
+    l2.set_bytes("bw_wal:stale", b"old".to_vec(), Some(60))
+        .await
+        .unwrap();
+    l2.in_memory_store().unwrap().set_unavailable(true);
+
+    writer
+        .enqueue("bw_wal:1".into(), b"v1".to_vec(), Some(60))
+        .await
+        .unwrap();
+    writer
+        .enqueue("bw_wal:2".into(), b"v2".to_vec(), None)
+        .await
+        .unwrap();
+    writer.enqueue_delete("bw_wal:stale".into()).await.unwrap();
+
+    // L2不可用时刷新不丢弃条目，而是转入WAL
+    writer.flush().await.unwrap();
+    assert_eq!(writer.pending_len(), 0);
+
+    wal.flush().await.unwrap();
+    let mut entries = wal.get_entries().await.unwrap();
+    entries.sort_by(|a, b| a.key.cmp(&b.key));
+    let summary: Vec<_> = entries
+        .iter()
+        .map(|entry| (entry.key.as_str(), entry.value.as_deref(), entry.ttl))
+        .collect();
+    assert_eq!(
+        summary,
+        vec![
+            ("bw_wal:1", Some(&b"v1"[..]), Some(60)),
+            ("bw_wal:2", Some(&b"v2"[..]), None),
+            ("bw_wal:stale", None, None),
+        ]
+    );
+    assert!(matches!(entries[2].operation, Operation::Delete));
+
+    // L2恢复后重放WAL
+    l2.in_memory_store().unwrap().set_unavailable(false);
+    let report = wal.replay_all(l2.as_ref()).await.unwrap();
+    assert_eq!(report.replayed, 3);
+    assert_eq!(
+        l2.get_bytes("bw_wal:1").await.unwrap(),
+        Some(b"v1".to_vec())
+    );
+    assert_eq!(
+        l2.get_bytes("bw_wal:2").await.unwrap(),
+        Some(b"v2".to_vec())
+    );
+    assert_eq!(l2.get_bytes("bw_wal:stale").await.unwrap(), None);
+    assert!(wal.get_entries().await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_failed_flush_without_wal_keeps_items() {
+    let l2 = create_backend();
+    let writer = BatchWriter::new(
+        "batch_writer_no_wal_test".to_string(),
+        l2.clone(),
+        create_config(),
+    );
+
+    l2.in_memory_store().unwrap().set_unavailable(true);
+    writer
+        .enqueue("bw_no_wal:1".into(), b"v1".to_vec(), Some(60))
+        .await
+        .unwrap();
+
+    assert!(writer.flush().await.is_err());
+    assert!(writer.is_pending("bw_no_wal:1"));
+
+    // 恢复后的下一次刷新重试成功
+    l2.in_memory_store().unwrap().set_unavailable(false);
+    writer.flush().await.unwrap();
+    assert_eq!(writer.pending_len(), 0);
+    assert_eq!(
+        l2.get_bytes("bw_no_wal:1").await.unwrap(),
+        Some(b"v1".to_vec())
+    );
+}
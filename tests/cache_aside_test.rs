@@ -0,0 +1,133 @@
+//! Copyright (c) 2025-2026, Kirky.X
+//!
+//! MIT License
+//!
+//! 旁路缓存辅助方法测试
+
+use async_trait::async_trait;
+use oxcache::backend::l1::L1Backend;
+use oxcache::client::l1::L1Client;
+use oxcache::error::{CacheError, Result};
+use oxcache::serialization::{JsonSerializer, SerializerEnum};
+use oxcache::{CacheExt, CacheOps};
+use std::any::Any;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+fn create_client() -> L1Client {
+    L1Client::new(
+        "cache_aside".to_string(),
+        Arc::new(L1Backend::new(1000)),
+        SerializerEnum::Json(JsonSerializer::new()),
+    )
+}
+
+#[tokio::test]
+async fn test_cache_aside_loads_once() {
+    let client = create_client();
+    let calls = Arc::new(AtomicUsize::new(0));
+
+    for _ in 0..2 {
+        let calls = calls.clone();
+        let value: String = client
+            .cache_aside("user:1", Some(60), || async move {
+                calls.fetch_add(1, Ordering::SeqCst);
+                Ok("alice".to_string())
+            })
+            .await
+            .unwrap();
+        assert_eq!(value, "alice");
+    }
+
+    // 第二次调用命中缓存，不再执行加载函数
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn test_cache_aside_loader_error_not_cached() {
+    let client = create_client();
+
+    let result: Result<u64> = client
+        .cache_aside("user:2", Some(60), || async {
+            Err(CacheError::DatabaseError("unavailable".to_string()))
+        })
+        .await;
+    assert!(result.is_err());
+
+    let cached: Option<u64> = client.get("user:2").await.unwrap();
+    assert!(cached.is_none());
+}
+
+/// 键到（值，写入TTL）的映射
+type TtlEntries = Mutex<HashMap<String, (Vec<u8>, Option<u64>)>>;
+
+/// 记录每次写入所用TTL的内存缓存
+struct TtlRecordingCache {
+    values: TtlEntries,
+    serializer: SerializerEnum,
+}
+
+#[async_trait]
+impl CacheOps for TtlRecordingCache {
+    async fn get_bytes(&self, key: &str) -> Result<Option<Vec<u8>>> {
+        Ok(self.values.lock().unwrap().get(key).map(|(v, _)| v.clone()))
+    }
+
+    async fn set_bytes(&self, key: &str, value: Vec<u8>, ttl: Option<u64>) -> Result<()> {
+        self.values
+            .lock()
+            .unwrap()
+            .insert(key.to_string(), (value, ttl));
+        Ok(())
+    }
+
+    async fn delete(&self, key: &str) -> Result<()> {
+        self.values.lock().unwrap().remove(key);
+        Ok(())
+    }
+
+    fn serializer(&self) -> &SerializerEnum {
+        &self.serializer
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn into_any_arc(self: Arc<Self>) -> Arc<dyn Any + Send + Sync> {
+        self
+    }
+}
+
+#[tokio::test]
+async fn test_get_or_set_with_uses_loader_ttl() {
+    let cache = TtlRecordingCache {
+        values: Mutex::new(HashMap::new()),
+        serializer: SerializerEnum::Json(JsonSerializer::new()),
+    };
+
+    for (key, max_age) in [
+        ("page:a", Some(30)),
+        ("page:b", Some(600)),
+        ("page:c", None),
+    ] {
+        let value: String = cache
+            .get_or_set_with(
+                key,
+                || async move { Ok((format!("body:{}", key), max_age)) },
+            )
+            .await
+            .unwrap();
+        assert_eq!(value, format!("body:{}", key));
+        assert_eq!(cache.values.lock().unwrap()[key].1, max_age);
+    }
+
+    // 命中缓存时不调用加载函数，也不改写TTL
+    let value: String = cache
+        .get_or_set_with("page:a", || async { Ok(("fresh".to_string(), Some(1))) })
+        .await
+        .unwrap();
+    assert_eq!(value, "body:page:a");
+    assert_eq!(cache.values.lock().unwrap()["page:a"].1, Some(30));
+}
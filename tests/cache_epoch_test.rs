@@ -0,0 +1,200 @@
+//! Copyright (c) 2025-2026, Kirky.X
+//!
+//! MIT License
+//!
+//! 缓存纪元测试
+
+use common::fake_redis::FakeRedis;
+use oxcache::backend::l1::L1Backend;
+use oxcache::backend::l2::L2Backend;
+use oxcache::client::two_level::TwoLevelClient;
+use oxcache::config::{L2Config, ServiceConfig};
+use oxcache::recovery::health::HealthState;
+use oxcache::serialization::{JsonSerializer, SerializerEnum};
+use oxcache::utils::clock::MockClock;
+use oxcache::{get_client, CacheManager};
+use std::sync::Arc;
+use std::time::Instant;
+
+mod common;
+
+#[tokio::test]
+async fn test_bump_epoch_orphans_previous_keys() {
+    let l2 = Arc::new(L2Backend::in_memory(
+        &L2Config::default(),
+        Arc::new(MockClock::new()),
+    ));
+    let client = TwoLevelClient::new(
+        "cache_epoch_test".to_string(),
+        Default::default(),
+        Arc::new(L1Backend::new(100)),
+        l2.clone(),
+        SerializerEnum::Json(JsonSerializer::new()),
+    )
+    .await
+    .unwrap()
+    .with_cache_epoch(3);
+    let key = "cache_epoch_test:user";
+
+    client.set(key, &"alice", Some(60)).await.unwrap();
+    assert!(l2
+        .get_bytes("v3:cache_epoch_test:user")
+        .await
+        .unwrap()
+        .is_some());
+
+    assert_eq!(client.bump_epoch().await.unwrap(), 4);
+    assert_eq!(client.cache_epoch(), 4);
+    assert_eq!(client.get::<String>(key).await.unwrap(), None);
+    // 旧键仍留在L2中，等待按TTL过期
+    assert!(l2
+        .get_bytes("v3:cache_epoch_test:user")
+        .await
+        .unwrap()
+        .is_some());
+
+    client.set(key, &"bob", Some(60)).await.unwrap();
+    assert_eq!(
+        client.get::<String>(key).await.unwrap(),
+        Some("bob".to_string())
+    );
+    assert!(l2
+        .get_bytes("v4:cache_epoch_test:user")
+        .await
+        .unwrap()
+        .is_some());
+}
+
+#[tokio::test]
+async fn test_bumped_epoch_is_persisted_in_l2() {
+    let l2 = Arc::new(L2Backend::in_memory(
+        &L2Config::default(),
+        Arc::new(MockClock::new()),
+    ));
+    let create_client = || async {
+        TwoLevelClient::new(
+            "cache_epoch_persist_test".to_string(),
+            Default::default(),
+            Arc::new(L1Backend::new(100)),
+            l2.clone(),
+            SerializerEnum::Json(JsonSerializer::new()),
+        )
+        .await
+        .unwrap()
+    };
+
+    let client = create_client().await;
+    assert_eq!(client.cache_epoch(), 0);
+    assert_eq!(client.bump_epoch().await.unwrap(), 1);
+    assert_eq!(client.bump_epoch().await.unwrap(), 2);
+
+    // 重新创建的客户端从L2加载纪元，配置中较小的纪元不会使其回退
+    let restarted = create_client().await.with_cache_epoch(1);
+    assert_eq!(restarted.cache_epoch(), 2);
+
+    // 配置的纪元领先于L2时，递增后L2中的纪元补齐到新纪元
+    let ahead = create_client().await.with_cache_epoch(5);
+    assert_eq!(ahead.bump_epoch().await.unwrap(), 6);
+    assert_eq!(create_client().await.cache_epoch(), 6);
+}
+
+#[tokio::test]
+async fn test_layer_ops_use_epoch_key() {
+    let l1 = Arc::new(L1Backend::new(100));
+    let l2 = Arc::new(L2Backend::in_memory(
+        &L2Config::default(),
+        Arc::new(MockClock::new()),
+    ));
+    let client = TwoLevelClient::new(
+        "cache_epoch_layer_test".to_string(),
+        Default::default(),
+        l1.clone(),
+        l2.clone(),
+        SerializerEnum::Json(JsonSerializer::new()),
+    )
+    .await
+    .unwrap()
+    .with_cache_epoch(2);
+    let key = "cache_epoch_layer_test:k";
+    let versioned = "v2:cache_epoch_layer_test:k";
+
+    client.set_l1_only(key, &"l1", Some(60)).await.unwrap();
+    client.set_l2_only(key, &"l2", Some(60)).await.unwrap();
+    assert!(l1.get_bytes(versioned).await.unwrap().is_some());
+    assert!(l2.get_bytes(versioned).await.unwrap().is_some());
+    assert_eq!(
+        client.get_l1_only::<String>(key).await.unwrap(),
+        Some("l1".to_string())
+    );
+    assert_eq!(
+        client.get_l2_only::<String>(key).await.unwrap(),
+        Some("l2".to_string())
+    );
+    assert!(client.pttl(key).await.unwrap().is_some());
+    assert!(client.l2_memory_usage(key).await.unwrap() > 0);
+
+    client
+        .set_health_state(HealthState::Degraded {
+            since: Instant::now(),
+            failure_count: 3,
+        })
+        .await;
+    assert_eq!(
+        client.get_allow_stale::<String>(key).await.unwrap(),
+        Some(("l1".to_string(), false))
+    );
+}
+
+#[tokio::test]
+async fn test_manager_bump_epoch() {
+    let redis = FakeRedis::start().await;
+    let service = "cache_epoch_manager_test";
+    let config = ServiceConfig::builder()
+        .l2_standalone(redis.url.clone())
+        .build()
+        .unwrap();
+    CacheManager::register_service(service, config)
+        .await
+        .unwrap();
+    let client = get_client(service).unwrap();
+
+    client
+        .set_bytes("cache_epoch_manager_test:k", b"v".to_vec(), Some(60))
+        .await
+        .unwrap();
+    assert!(client
+        .get_bytes("cache_epoch_manager_test:k")
+        .await
+        .unwrap()
+        .is_some());
+
+    assert_eq!(CacheManager::bump_epoch(service).await.unwrap(), 1);
+    assert_eq!(
+        CacheManager::service_config(service).unwrap().cache_epoch,
+        1
+    );
+    assert!(client
+        .get_bytes("cache_epoch_manager_test:k")
+        .await
+        .unwrap()
+        .is_none());
+
+    client
+        .set_bytes("cache_epoch_manager_test:k", b"v".to_vec(), Some(60))
+        .await
+        .unwrap();
+    assert!(redis.touched("v1:cache_epoch_manager_test:k"));
+
+    // 非双层缓存服务不支持纪元
+    let l1_service = "cache_epoch_manager_l1_test";
+    CacheManager::register_service(
+        l1_service,
+        ServiceConfig::builder().l1_only().build().unwrap(),
+    )
+    .await
+    .unwrap();
+    assert!(CacheManager::bump_epoch(l1_service).await.is_err());
+
+    CacheManager::deregister_service(service).await.unwrap();
+    CacheManager::deregister_service(l1_service).await.unwrap();
+}
@@ -61,6 +61,7 @@ async fn test_random_redis_failures() {
                         default_ttl: None,
                         max_key_length: 256,
                         max_value_size: 1024 * 1024 * 10,
+                        ..Default::default()
                     }),
                     two_level: Some(TwoLevelConfig {
                         promote_on_hit: true,
@@ -68,7 +69,9 @@ async fn test_random_redis_failures() {
                         batch_size: 10,
                         batch_interval_ms: 100,
                         invalidation_channel: None,
+                        ..Default::default()
                     }),
+                    ..Default::default()
                 },
             );
             map
@@ -154,6 +157,7 @@ async fn test_distributed_lock_during_failures() {
                         default_ttl: None,
                         max_key_length: 256,
                         max_value_size: 1024 * 1024 * 10,
+                        ..Default::default()
                     }),
                     two_level: Some(TwoLevelConfig {
                         promote_on_hit: true,
@@ -161,7 +165,9 @@ async fn test_distributed_lock_during_failures() {
                         batch_size: 10,
                         batch_interval_ms: 100,
                         invalidation_channel: None,
+                        ..Default::default()
                     }),
+                    ..Default::default()
                 },
             );
             map
@@ -235,6 +241,7 @@ async fn test_concurrent_isolation_during_failures() {
                         default_ttl: None,
                         max_key_length: 256,
                         max_value_size: 1024 * 1024 * 10,
+                        ..Default::default()
                     }),
                     two_level: Some(TwoLevelConfig {
                         promote_on_hit: true,
@@ -242,7 +249,9 @@ async fn test_concurrent_isolation_during_failures() {
                         batch_size: 10,
                         batch_interval_ms: 100,
                         invalidation_channel: None,
+                        ..Default::default()
                     }),
+                    ..Default::default()
                 },
             );
             map
@@ -351,6 +360,7 @@ async fn test_network_instability_handling() {
                         default_ttl: None,
                         max_key_length: 256,
                         max_value_size: 1024 * 1024 * 10,
+                        ..Default::default()
                     }),
                     two_level: Some(TwoLevelConfig {
                         promote_on_hit: true,
@@ -362,7 +372,9 @@ async fn test_network_instability_handling() {
                         warmup: None,
                         max_key_length: Some(1024),
                         max_value_size: Some(1024 * 1024),
+                        ..Default::default()
                     }),
+                    ..Default::default()
                 },
             );
             map
@@ -445,6 +457,7 @@ async fn test_data_consistency_after_recovery() {
                         default_ttl: None,
                         max_key_length: 256,
                         max_value_size: 1024 * 1024 * 10,
+                        ..Default::default()
                     }),
                     two_level: Some(TwoLevelConfig {
                         promote_on_hit: true,
@@ -452,7 +465,9 @@ async fn test_data_consistency_after_recovery() {
                         batch_size: 10,
                         batch_interval_ms: 100,
                         invalidation_channel: None,
+                        ..Default::default()
                     }),
+                    ..Default::default()
                 },
             );
             map
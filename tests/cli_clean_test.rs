@@ -0,0 +1,282 @@
+//! Copyright (c) 2025-2026, Kirky.X
+//!
+//! MIT License
+//!
+//! CLI清理与导出命令测试
+
+use async_trait::async_trait;
+use common::client_test_utils::{self, fake_redis_l2, in_memory_l2};
+use common::fake_redis::FakeRedis;
+use futures::TryStreamExt;
+use oxcache::backend::l1::L1Backend;
+use oxcache::cli::{
+    clean_pages, export_pages, preview_clean, CleanArgs, CleanPreview, PROGRESS_INTERVAL,
+};
+use oxcache::client::two_level::TwoLevelClient;
+use oxcache::client::CacheOps;
+use oxcache::config::TwoLevelConfig;
+use oxcache::error::Result;
+use oxcache::serialization::{JsonSerializer, SerializerEnum};
+use std::any::Any;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+mod common;
+
+async fn create_client(service_name: &str) -> (TwoLevelClient, FakeRedis) {
+    let redis = FakeRedis::start().await;
+    let client = client_test_utils::create_client(
+        service_name,
+        TwoLevelConfig::default(),
+        Arc::new(L1Backend::new(1000)),
+        fake_redis_l2(&redis).await,
+    )
+    .await;
+    (client, redis)
+}
+
+fn clean_args(service: &str, pattern: Option<&str>) -> CleanArgs {
+    CleanArgs {
+        service: service.to_string(),
+        l1: true,
+        l2: true,
+        wal: false,
+        confirm: false,
+        pattern: pattern.map(str::to_string),
+        dry_run: true,
+    }
+}
+
+fn deleted(redis: &FakeRedis) -> bool {
+    redis.log.lock().unwrap().iter().any(|args| {
+        let command = args[0].to_ascii_uppercase();
+        command == "DEL" || command == "UNLINK" || command == "FLUSHDB"
+    })
+}
+
+#[tokio::test]
+async fn test_dry_run_with_pattern_reports_matches_without_deleting() {
+    let (client, redis) = create_client("clean_dry_run_test").await;
+
+    for i in 0..15 {
+        let key = format!("clean_dry_run_test:user:{}", i);
+        client
+            .hset_bytes(&key, "f", b"v".to_vec(), None)
+            .await
+            .unwrap();
+    }
+    client
+        .hset_bytes("clean_dry_run_test:order:1", "f", b"v".to_vec(), None)
+        .await
+        .unwrap();
+
+    let args = clean_args("clean_dry_run_test", Some("clean_dry_run_test:user:*"));
+    match preview_clean(&client, &args).await.unwrap() {
+        CleanPreview::Pattern { count, sample } => {
+            assert_eq!(count, 15);
+            assert_eq!(sample.len(), 10);
+            assert!(sample
+                .iter()
+                .all(|k| k.starts_with("clean_dry_run_test:user:")));
+        }
+        other => panic!("unexpected preview: {:?}", other),
+    }
+
+    assert!(!deleted(&redis));
+    for i in 0..15 {
+        let key = format!("clean_dry_run_test:user:{}", i);
+        assert!(client.hget_bytes(&key, "f").await.unwrap().is_some());
+    }
+
+    client.shutdown().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_dry_run_full_clear_reports_l1_entries_and_dbsize() {
+    let (client, redis) = create_client("clean_dry_run_full_test").await;
+
+    for i in 0..3 {
+        let key = format!("clean_dry_run_full_test:l1:{}", i);
+        client
+            .set_l1_bytes(&key, b"v".to_vec(), None)
+            .await
+            .unwrap();
+    }
+    for i in 0..4 {
+        let key = format!("clean_dry_run_full_test:l2:{}", i);
+        client
+            .hset_bytes(&key, "f", b"v".to_vec(), None)
+            .await
+            .unwrap();
+    }
+
+    let args = clean_args("clean_dry_run_full_test", None);
+    assert_eq!(
+        preview_clean(&client, &args).await.unwrap(),
+        CleanPreview::Full {
+            l1_entries: Some(3),
+            l2_dbsize: Some(4),
+        }
+    );
+
+    assert!(!deleted(&redis));
+    assert_eq!(client.l1_len().await, 3);
+
+    client.shutdown().await.unwrap();
+}
+
+/// 只记录删除数量的客户端，删除时检查键流没有超前于处理进度
+struct CountingCache {
+    /// 键流已产出的键数
+    produced: Arc<AtomicUsize>,
+    /// 已处理的键数
+    processed: AtomicUsize,
+    /// 单页键数上限
+    page_size: usize,
+    serializer: SerializerEnum,
+}
+
+impl CountingCache {
+    fn record_page(&self, len: usize) {
+        assert!(len <= self.page_size);
+        let processed = self.processed.fetch_add(len, Ordering::SeqCst);
+        // 已产出但未处理的键不超过一页，说明键流没有被整体缓冲
+        assert!(self.produced.load(Ordering::SeqCst) - processed <= self.page_size);
+    }
+}
+
+#[async_trait]
+impl CacheOps for CountingCache {
+    async fn get_bytes(&self, _key: &str) -> Result<Option<Vec<u8>>> {
+        Ok(None)
+    }
+
+    async fn get_l2_bytes(&self, key: &str) -> Result<Option<Vec<u8>>> {
+        self.record_page(1);
+        Ok(Some(key.as_bytes()[..2].to_vec()))
+    }
+
+    async fn set_bytes(&self, _key: &str, _value: Vec<u8>, _ttl: Option<u64>) -> Result<()> {
+        Ok(())
+    }
+
+    async fn delete(&self, _key: &str) -> Result<()> {
+        Ok(())
+    }
+
+    async fn delete_many(&self, keys: &[&str]) -> Result<()> {
+        self.record_page(keys.len());
+        Ok(())
+    }
+
+    fn serializer(&self) -> &SerializerEnum {
+        &self.serializer
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn into_any_arc(self: Arc<Self>) -> Arc<dyn Any + Send + Sync> {
+        self
+    }
+}
+
+/// 惰性生成 `pages * page_size` 个键的键流，每产出一个键计数一次
+fn synthetic_pages(
+    pages: usize,
+    page_size: usize,
+    produced: Arc<AtomicUsize>,
+) -> impl futures::Stream<Item = Result<Vec<String>>> {
+    futures::stream::iter((0..pages).map(move |page| {
+        let keys: Vec<String> = (0..page_size)
+            .map(|i| format!("k{}", page * page_size + i))
+            .collect();
+        produced.fetch_add(keys.len(), Ordering::SeqCst);
+        Ok(keys)
+    }))
+}
+
+#[tokio::test]
+async fn test_clean_consumes_large_key_stream_page_by_page() {
+    let (pages, page_size) = (500, 1000);
+    let produced = Arc::new(AtomicUsize::new(0));
+    let cache = CountingCache {
+        produced: produced.clone(),
+        processed: AtomicUsize::new(0),
+        page_size,
+        serializer: SerializerEnum::Json(JsonSerializer::new()),
+    };
+
+    let mut reports = Vec::new();
+    let removed = clean_pages(
+        &cache,
+        synthetic_pages(pages, page_size, produced),
+        |processed| reports.push(processed),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(removed, pages * page_size);
+    assert_eq!(reports.len(), pages * page_size / PROGRESS_INTERVAL);
+    assert_eq!(reports.first(), Some(&PROGRESS_INTERVAL));
+}
+
+#[tokio::test]
+async fn test_export_writes_each_page_before_pulling_the_next() {
+    let (pages, page_size) = (30, 1000);
+    let produced = Arc::new(AtomicUsize::new(0));
+    let cache = CountingCache {
+        produced: produced.clone(),
+        processed: AtomicUsize::new(0),
+        page_size,
+        serializer: SerializerEnum::Json(JsonSerializer::new()),
+    };
+
+    let mut out = Vec::new();
+    let mut reports = Vec::new();
+    let exported = export_pages(
+        &cache,
+        synthetic_pages(pages, page_size, produced),
+        &mut out,
+        |processed| reports.push(processed),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(exported, pages * page_size);
+    assert_eq!(reports, vec![10_000, 20_000, 30_000]);
+    let out = String::from_utf8(out).unwrap();
+    assert_eq!(out.lines().count(), pages * page_size);
+    assert_eq!(out.lines().next(), Some("k0\t6b30"));
+}
+
+#[tokio::test]
+async fn test_scan_yields_bounded_pages() {
+    let client = client_test_utils::create_client(
+        "pages_test",
+        TwoLevelConfig::default(),
+        Arc::new(L1Backend::new(100)),
+        in_memory_l2(),
+    )
+    .await;
+    for i in 0..250 {
+        let key = format!("pages_test:item:{}", i);
+        client
+            .hset_bytes(&key, "f", b"v".to_vec(), None)
+            .await
+            .unwrap();
+    }
+
+    let pages: Vec<Vec<String>> = client
+        .scan("pages_test:item:*", 100)
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(
+        pages.iter().map(Vec::len).collect::<Vec<_>>(),
+        vec![100, 100, 50]
+    );
+
+    client.shutdown().await.unwrap();
+}
@@ -0,0 +1,38 @@
+//! Copyright (c) 2025-2026, Kirky.X
+//!
+//! MIT License
+//!
+//! L2命令别名测试
+
+use common::fake_redis::FakeRedis;
+use oxcache::backend::l2::L2Backend;
+use oxcache::config::L2Config;
+use secrecy::SecretString;
+use std::collections::HashMap;
+
+mod common;
+
+#[tokio::test]
+async fn test_clear_sends_aliased_unlink() {
+    let fake = FakeRedis::start().await;
+    let config = L2Config {
+        connection_string: SecretString::from(fake.url.clone()),
+        command_aliases: HashMap::from([("unlink".to_string(), "SAFE_UNLINK".to_string())]),
+        ..Default::default()
+    };
+    let l2 = L2Backend::new(&config).await.unwrap();
+
+    l2.hset("alias_test:user:1", "name", b"alice".to_vec(), Some(60))
+        .await
+        .unwrap();
+    l2.clear("alias_test").await.unwrap();
+
+    let log = fake.log.lock().unwrap();
+    let commands: Vec<String> = log
+        .iter()
+        .map(|args| args[0].to_ascii_uppercase())
+        .collect();
+    assert!(commands.contains(&"SCAN".to_string()));
+    assert!(commands.contains(&"SAFE_UNLINK".to_string()));
+    assert!(!commands.contains(&"UNLINK".to_string()));
+}
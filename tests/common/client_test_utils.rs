@@ -0,0 +1,92 @@
+//! Copyright (c) 2025-2026, Kirky.X
+//!
+//! MIT License
+//!
+//! 双层缓存客户端测试工具（通用模块）
+//!
+//! 以内存L2或模拟Redis构造 `TwoLevelClient`，无需真实Redis。
+//! 服务名称需包含 `test`，客户端才会使用内存中的WAL
+
+#![allow(dead_code)]
+
+use super::fake_redis::FakeRedis;
+use oxcache::backend::l1::L1Backend;
+use oxcache::backend::l2::L2Backend;
+use oxcache::client::two_level::TwoLevelClient;
+use oxcache::config::{L2Config, TwoLevelConfig};
+use oxcache::serialization::{JsonSerializer, SerializerEnum};
+use oxcache::utils::clock::MockClock;
+use secrecy::SecretString;
+use std::sync::Arc;
+
+/// 创建内存L2后端，过期时间由独立的模拟时钟决定
+pub fn in_memory_l2() -> Arc<L2Backend> {
+    in_memory_l2_with_clock(Arc::new(MockClock::new()))
+}
+
+/// 创建使用指定模拟时钟的内存L2后端
+///
+/// # 参数
+///
+/// * `clock` - 决定过期时间的模拟时钟
+pub fn in_memory_l2_with_clock(clock: Arc<MockClock>) -> Arc<L2Backend> {
+    Arc::new(L2Backend::in_memory(&L2Config::default(), clock))
+}
+
+/// 创建连接到模拟Redis的L2后端
+///
+/// # 参数
+///
+/// * `fake` - 模拟Redis服务
+pub async fn fake_redis_l2(fake: &FakeRedis) -> Arc<L2Backend> {
+    let config = L2Config {
+        connection_string: SecretString::from(fake.url.clone()),
+        ..Default::default()
+    };
+    Arc::new(L2Backend::new(&config).await.unwrap())
+}
+
+/// 使用JSON序列化器创建双层缓存客户端
+///
+/// # 参数
+///
+/// * `service` - 服务名称
+/// * `config` - 双层缓存配置
+/// * `l1` - L1缓存后端
+/// * `l2` - L2缓存后端
+pub async fn create_client(
+    service: &str,
+    config: TwoLevelConfig,
+    l1: Arc<L1Backend>,
+    l2: Arc<L2Backend>,
+) -> TwoLevelClient {
+    create_client_with_serializer(
+        service,
+        config,
+        l1,
+        l2,
+        SerializerEnum::Json(JsonSerializer::new()),
+    )
+    .await
+}
+
+/// 使用指定序列化器创建双层缓存客户端
+///
+/// # 参数
+///
+/// * `service` - 服务名称
+/// * `config` - 双层缓存配置
+/// * `l1` - L1缓存后端
+/// * `l2` - L2缓存后端
+/// * `serializer` - 序列化器
+pub async fn create_client_with_serializer(
+    service: &str,
+    config: TwoLevelConfig,
+    l1: Arc<L1Backend>,
+    l2: Arc<L2Backend>,
+    serializer: SerializerEnum,
+) -> TwoLevelClient {
+    TwoLevelClient::new(service.to_string(), config, l1, l2, serializer)
+        .await
+        .unwrap()
+}
@@ -0,0 +1,285 @@
+//! Copyright (c) 2025-2026, Kirky.X
+//!
+//! MIT License
+//!
+//! 模拟Redis服务（通用模块）
+//!
+//! 只实现客户端初始化和读写路径所需的最小RESP子集，用于无需真实Redis的测试
+
+#![allow(dead_code)]
+
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::{broadcast, mpsc};
+
+/// 已接收命令的记录
+pub type CommandLog = Arc<Mutex<Vec<Vec<String>>>>;
+
+/// 模拟Redis服务
+///
+/// 命令参数中包含 `slow` 的请求延迟2秒后才响应，包含 `fail` 的请求返回错误，
+/// 其余请求立即响应；写入脚本返回成功，读取类脚本统一返回nil，哈希表命令与 `INCR` 在内存中执行，
+/// `SCAN` 与 `DBSIZE` 基于哈希表中的键，`SELECT` 按连接切换数据库，
+/// `PUBLISH` 的消息会推送给订阅了对应频道的连接，所有收到的命令都会被记录
+pub struct FakeRedis {
+    pub url: String,
+    pub log: CommandLog,
+    events: broadcast::Sender<Event>,
+}
+
+/// 推送给所有连接的服务端事件
+#[derive(Clone, Debug)]
+enum Event {
+    /// 频道消息，仅投递给订阅了该频道的连接
+    Message { channel: String, payload: String },
+    /// 关闭当前所有连接，模拟连接中断
+    Disconnect,
+}
+
+impl FakeRedis {
+    pub async fn start() -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let log = CommandLog::default();
+        let server_log = log.clone();
+        let hashes = HashStore::default();
+        let (events, _) = broadcast::channel(64);
+        let server_events = events.clone();
+        tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                tokio::spawn(serve_connection(
+                    stream,
+                    server_log.clone(),
+                    hashes.clone(),
+                    server_events.clone(),
+                ));
+            }
+        });
+        Self {
+            url: format!("redis://{}", addr),
+            log,
+            events,
+        }
+    }
+
+    /// 向订阅了频道的连接发布消息
+    pub fn publish(&self, channel: &str, payload: &str) {
+        let _ = self.events.send(Event::Message {
+            channel: channel.to_string(),
+            payload: payload.to_string(),
+        });
+    }
+
+    /// 关闭当前所有连接，之后建立的新连接不受影响
+    pub fn disconnect_all(&self) {
+        let _ = self.events.send(Event::Disconnect);
+    }
+
+    /// 判断是否收到过涉及指定键的命令
+    pub fn touched(&self, key: &str) -> bool {
+        self.log
+            .lock()
+            .unwrap()
+            .iter()
+            .any(|args| args.iter().any(|arg| arg == key))
+    }
+}
+
+/// 哈希表存储，按数据库编号划分，每个数据库内为键到字段映射
+type HashStore = Arc<Mutex<HashMap<String, HashMap<String, HashMap<String, String>>>>>;
+
+async fn serve_connection(
+    stream: TcpStream,
+    log: CommandLog,
+    hashes: HashStore,
+    events: broadcast::Sender<Event>,
+) {
+    let (reader, mut writer) = stream.into_split();
+    // 在独立任务中读取命令，使等待命令与等待服务端事件可以同时进行
+    let (command_tx, mut commands) = mpsc::unbounded_channel();
+    let reader_task = tokio::spawn(async move {
+        let mut reader = BufReader::new(reader);
+        while let Some(args) = read_command(&mut reader).await {
+            if command_tx.send(args).is_err() {
+                break;
+            }
+        }
+    });
+    let mut event_rx = events.subscribe();
+    // MULTI 之后排队的命令回复，EXEC 时一并返回
+    let mut queued: Option<Vec<Vec<u8>>> = None;
+    // 当前连接通过 SELECT 选择的数据库
+    let mut db = "0".to_string();
+    // 当前连接订阅的频道
+    let mut channels = HashSet::new();
+
+    loop {
+        let args = tokio::select! {
+            args = commands.recv() => match args {
+                Some(args) => args,
+                None => break,
+            },
+            event = event_rx.recv() => match event {
+                Ok(Event::Message { channel, payload }) if channels.contains(&channel) => {
+                    let message = format!("*3\r\n{}{}{}", bulk("message"), bulk(&channel), bulk(&payload));
+                    if writer.write_all(message.as_bytes()).await.is_err() {
+                        break;
+                    }
+                    continue;
+                }
+                Ok(Event::Disconnect) => break,
+                _ => continue,
+            },
+        };
+        if args.iter().any(|arg| arg.contains("slow")) {
+            tokio::time::sleep(Duration::from_secs(2)).await;
+        }
+        let command = args[0].to_ascii_uppercase();
+        let reply = match (command.as_str(), queued.as_mut()) {
+            ("MULTI", _) => {
+                queued = Some(Vec::new());
+                b"+OK\r\n".to_vec()
+            }
+            ("EXEC", Some(_)) => {
+                let replies = queued.take().unwrap_or_default();
+                let mut reply = format!("*{}\r\n", replies.len()).into_bytes();
+                replies.into_iter().for_each(|r| reply.extend(r));
+                reply
+            }
+            ("SELECT", _) => {
+                db = args[1].clone();
+                b"+OK\r\n".to_vec()
+            }
+            ("SUBSCRIBE", _) => {
+                channels.insert(args[1].clone());
+                format!(
+                    "*3\r\n{}{}:{}\r\n",
+                    bulk("subscribe"),
+                    bulk(&args[1]),
+                    channels.len()
+                )
+                .into_bytes()
+            }
+            ("PUBLISH", _) => {
+                let _ = events.send(Event::Message {
+                    channel: args[1].clone(),
+                    payload: args[2].clone(),
+                });
+                b":1\r\n".to_vec()
+            }
+            (_, Some(replies)) => {
+                replies.push(execute(&args, &hashes, &db));
+                b"+QUEUED\r\n".to_vec()
+            }
+            (_, None) => execute(&args, &hashes, &db),
+        };
+        log.lock().unwrap().push(args);
+        if writer.write_all(&reply).await.is_err() {
+            break;
+        }
+    }
+    reader_task.abort();
+}
+
+/// 编码RESP批量字符串
+fn bulk(value: &str) -> String {
+    format!("${}\r\n{}\r\n", value.len(), value)
+}
+
+/// 执行单条命令并生成RESP回复
+fn execute(args: &[String], hashes: &HashStore, db: &str) -> Vec<u8> {
+    let mut databases = hashes.lock().unwrap();
+    let hashes = databases.entry(db.to_string()).or_default();
+    match args[0].to_ascii_uppercase().as_str() {
+        _ if args.iter().any(|arg| arg.contains("fail")) => b"-ERR injected failure\r\n".to_vec(),
+        "PING" => b"+PONG\r\n".to_vec(),
+        // 写入脚本携带值与TTL参数，返回成功；读取脚本返回nil
+        "EVALSHA" | "EVAL" if args.len() > 4 => b":1\r\n".to_vec(),
+        "EVALSHA" | "EVAL" | "GET" => b"$-1\r\n".to_vec(),
+        "HSET" => {
+            let hash = hashes.entry(args[1].clone()).or_default();
+            let added = args[2..]
+                .chunks(2)
+                .filter(|pair| hash.insert(pair[0].clone(), pair[1].clone()).is_none())
+                .count();
+            format!(":{}\r\n", added).into_bytes()
+        }
+        "HGET" => match hashes.get(&args[1]).and_then(|hash| hash.get(&args[2])) {
+            Some(value) => bulk(value).into_bytes(),
+            None => b"$-1\r\n".to_vec(),
+        },
+        "HGETALL" => {
+            let hash = hashes.get(&args[1]).cloned().unwrap_or_default();
+            let mut reply = format!("*{}\r\n", hash.len() * 2);
+            for (field, value) in &hash {
+                reply.push_str(&bulk(field));
+                reply.push_str(&bulk(value));
+            }
+            reply.into_bytes()
+        }
+        // 计数器保存在同名哈希的空字段中
+        "INCR" => {
+            let counter = hashes
+                .entry(args[1].clone())
+                .or_default()
+                .entry(String::new())
+                .or_insert_with(|| "0".to_string());
+            let value = counter.parse::<i64>().unwrap_or(0) + 1;
+            *counter = value.to_string();
+            format!(":{}\r\n", value).into_bytes()
+        }
+        "EXPIRE" | "PERSIST" => b":1\r\n".to_vec(),
+        "TTL" => b":60\r\n".to_vec(),
+        // 单次返回所有匹配的键，游标恒为0
+        "SCAN" => {
+            let pattern = args
+                .iter()
+                .position(|arg| arg.eq_ignore_ascii_case("MATCH"))
+                .and_then(|i| args.get(i + 1))
+                .map_or("*", String::as_str);
+            let keys: Vec<&String> = hashes.keys().filter(|k| glob_match(pattern, k)).collect();
+            let mut reply = format!("*2\r\n{}*{}\r\n", bulk("0"), keys.len());
+            keys.iter().for_each(|key| reply.push_str(&bulk(key)));
+            reply.into_bytes()
+        }
+        "DBSIZE" => format!(":{}\r\n", hashes.len()).into_bytes(),
+        _ => b"+OK\r\n".to_vec(),
+    }
+}
+
+/// 仅支持 `*` 通配符的glob匹配
+fn glob_match(pattern: &str, text: &str) -> bool {
+    match pattern.split_once('*') {
+        None => pattern == text,
+        Some((head, rest)) => {
+            let Some(text) = text.strip_prefix(head) else {
+                return false;
+            };
+            (0..=text.len())
+                .filter(|&i| text.is_char_boundary(i))
+                .any(|i| glob_match(rest, &text[i..]))
+        }
+    }
+}
+
+/// 读取一条RESP数组形式的命令
+async fn read_command<R: AsyncBufReadExt + Unpin>(reader: &mut R) -> Option<Vec<String>> {
+    let mut line = String::new();
+    reader.read_line(&mut line).await.ok()?;
+    let count: usize = line.trim().strip_prefix('*')?.parse().ok()?;
+
+    let mut args = Vec::with_capacity(count);
+    for _ in 0..count {
+        line.clear();
+        reader.read_line(&mut line).await.ok()?;
+        let len: usize = line.trim().strip_prefix('$')?.parse().ok()?;
+        let mut buf = vec![0u8; len + 2];
+        reader.read_exact(&mut buf).await.ok()?;
+        buf.truncate(len);
+        args.push(String::from_utf8_lossy(&buf).into_owned());
+    }
+    Some(args)
+}
@@ -4,7 +4,9 @@
 //!
 //! 该模块定义了测试的通用工具函数和设置。
 
+pub mod client_test_utils;
 pub mod database_test_utils;
+pub mod fake_redis;
 pub mod redis_test_utils;
 
 use oxcache::{CacheManager, Config};
@@ -0,0 +1,73 @@
+//! Copyright (c) 2025-2026, Kirky.X
+//!
+//! MIT License
+//!
+//! L2值压缩帧测试
+
+use oxcache::backend::compression::{decode_frame, encode_frame};
+use oxcache::config::CompressionCodec;
+
+const CODECS: [CompressionCodec; 4] = [
+    CompressionCodec::None,
+    CompressionCodec::Zstd,
+    CompressionCodec::Lz4,
+    CompressionCodec::Gzip,
+];
+
+fn compressible_value() -> Vec<u8> {
+    "oxcache compression frame ".repeat(64).into_bytes()
+}
+
+#[test]
+fn test_frame_round_trip_for_each_codec() {
+    let value = compressible_value();
+    for codec in CODECS {
+        let frame = encode_frame(codec, &value).unwrap();
+        assert_eq!(frame[0], codec.id(), "codec {:?}", codec);
+        if codec != CompressionCodec::None {
+            assert!(frame.len() < value.len(), "codec {:?}", codec);
+        }
+        assert_eq!(decode_frame(&frame).unwrap(), value);
+    }
+}
+
+#[test]
+fn test_frames_decode_regardless_of_current_codec() {
+    let value = compressible_value();
+    let frames: Vec<Vec<u8>> = CODECS
+        .iter()
+        .map(|codec| encode_frame(*codec, &value).unwrap())
+        .collect();
+
+    // 解码只依赖帧头部的编码字节，切换编码后旧帧仍可读取
+    for frame in &frames {
+        assert_eq!(decode_frame(frame).unwrap(), value);
+    }
+}
+
+#[test]
+fn test_incompressible_value_is_stored_uncompressed() {
+    let value = b"tiny".to_vec();
+    for codec in CODECS {
+        let frame = encode_frame(codec, &value).unwrap();
+        assert_eq!(frame[0], CompressionCodec::None.id());
+        assert_eq!(&frame[1..], value.as_slice());
+        assert_eq!(decode_frame(&frame).unwrap(), value);
+    }
+}
+
+#[test]
+fn test_invalid_frames_are_rejected() {
+    assert!(decode_frame(&[]).is_err());
+    assert!(decode_frame(&[42, 1, 2, 3]).is_err());
+    assert!(decode_frame(&[CompressionCodec::Zstd.id(), 1, 2, 3]).is_err());
+}
+
+#[test]
+fn test_codec_ids_are_stable() {
+    for (id, codec) in CODECS.iter().enumerate() {
+        assert_eq!(codec.id(), id as u8);
+        assert_eq!(CompressionCodec::from_id(id as u8), Some(*codec));
+    }
+    assert_eq!(CompressionCodec::from_id(4), None);
+}
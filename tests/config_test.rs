@@ -4,7 +4,10 @@
 //!
 //! 配置单元测试
 
-use oxcache::config::{CacheType, Config, L1Config, L2Config, ServiceConfig};
+use oxcache::config::{
+    CacheType, Config, L1Config, L2Config, ServiceConfig, TwoLevelConfig, WriteOrder,
+};
+use oxcache::sync::common::{BackpressurePolicy, DEFAULT_BLOCK_TIMEOUT_MS};
 use std::collections::HashMap;
 
 /// 测试从TOML配置文件加载配置
@@ -47,6 +50,53 @@ fn test_config_load_from_toml() {
     let service = config.services.get("test_service").unwrap();
     assert_eq!(service.cache_type, CacheType::TwoLevel);
     assert_eq!(service.l1.as_ref().unwrap().max_capacity, 10000);
+    assert!(service.enable_metrics);
+    assert!(!service.force_critical_metrics);
+}
+
+/// 测试按服务关闭指标的配置解析
+#[test]
+fn test_service_metrics_opt_out_parsing() {
+    let config_str = r#"
+        [services.quiet]
+        cache_type = "l1"
+        enable_metrics = false
+        force_critical_metrics = true
+    "#;
+
+    let config: Config = toml::from_str(config_str).expect("Failed to parse TOML");
+    let service = &config.services["quiet"];
+    assert!(!service.enable_metrics);
+    assert!(service.force_critical_metrics);
+}
+
+/// 测试批量写入背压配置的解析与默认值
+#[test]
+fn test_batch_backpressure_parsing() {
+    let config_str = r#"
+        [services.bursty]
+        cache_type = "twolevel"
+
+        [services.bursty.two_level]
+        promote_on_hit = true
+        enable_batch_write = true
+        batch_size = 100
+        batch_interval_ms = 1000
+        batch_max_queue_depth = 500
+        batch_backpressure = "drop_oldest"
+        batch_block_timeout_ms = 200
+    "#;
+
+    let config: Config = toml::from_str(config_str).expect("Failed to parse TOML");
+    let two_level = config.services["bursty"].two_level.as_ref().unwrap();
+    assert_eq!(two_level.batch_max_queue_depth, Some(500));
+    assert_eq!(two_level.batch_backpressure, BackpressurePolicy::DropOldest);
+    assert_eq!(two_level.batch_block_timeout_ms, 200);
+
+    let defaults = TwoLevelConfig::default();
+    assert_eq!(defaults.batch_max_queue_depth, None);
+    assert_eq!(defaults.batch_backpressure, BackpressurePolicy::Block);
+    assert_eq!(defaults.batch_block_timeout_ms, DEFAULT_BLOCK_TIMEOUT_MS);
 }
 
 /// 测试手动创建配置结构
@@ -76,6 +126,7 @@ fn test_config_structure_manual_creation() {
                     }),
                     l2: None,
                     two_level: None,
+                    ..Default::default()
                 },
             );
             map
@@ -117,6 +168,7 @@ fn test_config_validation_ttl() {
                         ..Default::default()
                     }),
                     two_level: None,
+                    ..Default::default()
                 },
             );
             map
@@ -151,6 +203,7 @@ fn test_config_validation_ttl() {
                         ..Default::default()
                     }),
                     two_level: None,
+                    ..Default::default()
                 },
             );
             map
@@ -201,3 +254,180 @@ fn test_invalid_redis_mode_parsing() {
         );
     }
 }
+
+/// 测试服务配置构建器
+///
+/// 验证构建器生成的配置与手动构造的结构体一致
+#[test]
+fn test_service_config_builder_matches_manual() {
+    use oxcache::config::{BloomFilterConfig, RedisMode, TwoLevelConfig};
+    use secrecy::ExposeSecret;
+
+    let manual = ServiceConfig {
+        cache_type: CacheType::TwoLevel,
+        ttl: Some(600),
+        serialization: None,
+        l1: Some(L1Config {
+            max_capacity: 5000,
+            ..Default::default()
+        }),
+        l2: Some(L2Config {
+            mode: RedisMode::Standalone,
+            connection_string: "redis://127.0.0.1:6379".to_string().into(),
+            ..Default::default()
+        }),
+        two_level: Some(TwoLevelConfig {
+            bloom_filter: Some(BloomFilterConfig::default()),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+
+    let built = ServiceConfig::builder()
+        .two_level()
+        .l1_capacity(5000)
+        .l2_standalone("redis://127.0.0.1:6379")
+        .ttl(600)
+        .with_bloom(BloomFilterConfig::default())
+        .build()
+        .unwrap();
+
+    assert_eq!(built.cache_type, manual.cache_type);
+    assert_eq!(built.ttl, manual.ttl);
+    assert_eq!(
+        built.l1.as_ref().unwrap().max_capacity,
+        manual.l1.as_ref().unwrap().max_capacity
+    );
+
+    let (built_l2, manual_l2) = (built.l2.unwrap(), manual.l2.unwrap());
+    assert_eq!(built_l2.mode, manual_l2.mode);
+    assert_eq!(
+        built_l2.connection_string.expose_secret(),
+        manual_l2.connection_string.expose_secret()
+    );
+    assert_eq!(built_l2.command_timeout_ms, manual_l2.command_timeout_ms);
+
+    let (built_tl, manual_tl) = (built.two_level.unwrap(), manual.two_level.unwrap());
+    assert_eq!(built_tl.promote_on_hit, manual_tl.promote_on_hit);
+    assert_eq!(
+        built_tl.bloom_filter.unwrap().name,
+        manual_tl.bloom_filter.unwrap().name
+    );
+}
+
+/// 测试构建器缺少必需配置
+///
+/// 验证双层缓存缺少L2配置时构建失败
+#[test]
+fn test_service_config_builder_requires_l2() {
+    use oxcache::error::CacheError;
+
+    let result = ServiceConfig::builder()
+        .two_level()
+        .l1_capacity(100)
+        .build();
+    match result {
+        Err(CacheError::Configuration(msg)) => assert!(msg.contains("L2")),
+        other => panic!("expected Configuration error, got {:?}", other),
+    }
+
+    let l1_only = ServiceConfig::builder().l1_only().build().unwrap();
+    assert_eq!(l1_only.cache_type, CacheType::L1);
+    assert!(l1_only.l1.is_some());
+    assert!(l1_only.l2.is_none());
+}
+
+/// 清除所有 OXCACHE_ 环境变量
+fn clear_oxcache_env() {
+    for (name, _) in std::env::vars() {
+        if name.starts_with("OXCACHE_") && name != "OXCACHE_SKIP_REDIS_TESTS" {
+            std::env::remove_var(name);
+        }
+    }
+}
+
+/// 测试从环境变量构建配置
+#[test]
+#[serial_test::serial(oxcache_env)]
+fn test_config_from_env() {
+    use oxcache::config::{RedisMode, SerializationType};
+    use secrecy::ExposeSecret;
+
+    clear_oxcache_env();
+    std::env::set_var("OXCACHE_SERVICE_NAME", "orders");
+    std::env::set_var("OXCACHE_REDIS_URL", "redis://127.0.0.1:6379/1");
+    std::env::set_var("OXCACHE_L1_CAPACITY", "2048");
+    std::env::set_var("OXCACHE_TTL", "120");
+    std::env::set_var("OXCACHE_SERIALIZATION", "cbor");
+
+    let config = Config::from_env().unwrap();
+    clear_oxcache_env();
+
+    assert_eq!(config.services.len(), 1);
+    let service = &config.services["orders"];
+    assert_eq!(service.cache_type, CacheType::TwoLevel);
+    assert_eq!(service.ttl, Some(120));
+    assert_eq!(service.serialization, Some(SerializationType::Cbor));
+    assert_eq!(service.l1.as_ref().unwrap().max_capacity, 2048);
+
+    let l2 = service.l2.as_ref().unwrap();
+    assert_eq!(l2.mode, RedisMode::Standalone);
+    assert_eq!(
+        l2.connection_string.expose_secret(),
+        "redis://127.0.0.1:6379/1"
+    );
+    assert!(service.two_level.is_some());
+}
+
+/// 测试环境变量缺失或无效
+#[test]
+#[serial_test::serial(oxcache_env)]
+fn test_config_from_env_errors() {
+    use oxcache::error::CacheError;
+
+    clear_oxcache_env();
+    match Config::from_env() {
+        Err(CacheError::Configuration(msg)) => assert!(msg.contains("OXCACHE_REDIS_URL")),
+        other => panic!("expected missing variable error, got {:?}", other),
+    }
+
+    std::env::set_var("OXCACHE_CACHE_TYPE", "l1");
+    std::env::set_var("OXCACHE_L1_CAPACITY", "lots");
+    let result = Config::from_env();
+    clear_oxcache_env();
+    match result {
+        Err(CacheError::Configuration(msg)) => assert!(msg.contains("OXCACHE_L1_CAPACITY")),
+        other => panic!("expected invalid value error, got {:?}", other),
+    }
+
+    // L1-only 服务不需要Redis地址
+    std::env::set_var("OXCACHE_CACHE_TYPE", "l1");
+    let config = Config::from_env();
+    clear_oxcache_env();
+    let config = config.unwrap();
+    assert_eq!(config.services["default"].cache_type, CacheType::L1);
+}
+
+/// 测试写后回写模式与L2优先写入顺序互斥
+#[test]
+fn test_write_behind_requires_l1_first() {
+    let build = |write_order| {
+        let service = ServiceConfig::builder()
+            .l2_standalone("redis://127.0.0.1:6379")
+            .two_level_config(TwoLevelConfig {
+                write_behind: true,
+                write_order,
+                ..Default::default()
+            })
+            .build()
+            .unwrap();
+        Config {
+            services: HashMap::from([("write_behind".to_string(), service)]),
+            ..Default::default()
+        }
+    };
+
+    assert!(build(WriteOrder::L1First).validate().is_ok());
+    let err = build(WriteOrder::L2First).validate().unwrap_err();
+    assert!(err.contains("write_behind"));
+}
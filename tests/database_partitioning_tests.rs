@@ -4,11 +4,12 @@
 //!
 //! 数据库分区测试
 
-use chrono::Utc;
+use chrono::{TimeZone, Utc};
+use oxcache::database::common::{is_partition_expired, retention_cutoff_date};
 use oxcache::database::mysql::MySQLPartitionManager;
 use oxcache::database::postgresql::PostgresPartitionManager;
 use oxcache::database::sqlite::SQLitePartitionManager;
-use oxcache::database::{PartitionConfig, PartitionManager, PartitionStrategy};
+use oxcache::database::{PartitionConfig, PartitionInfo, PartitionManager, PartitionStrategy};
 use oxcache::error::Result;
 use std::sync::Arc;
 #[path = "./common/database_test_utils.rs"]
@@ -393,3 +394,32 @@ async fn test_concurrent_operations() -> Result<()> {
 
     Ok(())
 }
+
+/// Test retention cutoff uses calendar months around 28-day and 31-day months
+#[test]
+fn test_partition_retention_month_boundaries() {
+    let date = |y, m, d| Utc.with_ymd_and_hms(y, m, d, 0, 0, 0).unwrap();
+    let january = PartitionInfo::new(date(2023, 1, 15), "events");
+    let february = PartitionInfo::new(date(2023, 2, 15), "events");
+    let july = PartitionInfo::new(date(2023, 7, 15), "events");
+
+    // 2023年2月只有28天：3月1日保留1个月，截止到2月1日，1月分区整体过期
+    let cutoff = retention_cutoff_date(date(2023, 3, 1), 1);
+    assert_eq!(cutoff, date(2023, 2, 1));
+    assert!(is_partition_expired(&january, cutoff));
+    assert!(!is_partition_expired(&february, cutoff));
+
+    // 3月31日往前1个月落在2月最后一天，2月分区仍在保留期内
+    let cutoff = retention_cutoff_date(date(2023, 3, 31), 1);
+    assert_eq!(cutoff, date(2023, 2, 28));
+    assert!(!is_partition_expired(&february, cutoff));
+
+    // 7月有31天：8月31日保留1个月，截止到7月31日，7月分区仍包含保留期内的数据
+    let cutoff = retention_cutoff_date(date(2023, 8, 31), 1);
+    assert_eq!(cutoff, date(2023, 7, 31));
+    assert!(!is_partition_expired(&july, cutoff));
+
+    // 9月1日起7月分区整体过期
+    let cutoff = retention_cutoff_date(date(2023, 9, 1), 1);
+    assert!(is_partition_expired(&july, cutoff));
+}
@@ -0,0 +1,267 @@
+//! Copyright (c) 2025-2026, Kirky.X
+//!
+//! MIT License
+//!
+//! 数据库回源测试
+
+use async_trait::async_trait;
+use common::fake_redis::FakeRedis;
+use common::redis_test_utils::create_standalone_config;
+use oxcache::backend::{l1::L1Backend, l2::L2Backend};
+use oxcache::client::db_loader::{DbFallbackManager, DbLoader};
+use oxcache::client::two_level::TwoLevelClient;
+use oxcache::client::CacheOps;
+use oxcache::config::{L2Config, TwoLevelConfig};
+use oxcache::error::{CacheError, Result};
+use oxcache::serialization::{JsonSerializer, SerializerEnum};
+use secrecy::SecretString;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+mod common;
+
+/// 记录调用次数的批量加载器
+///
+/// 以 `missing` 结尾的键视为数据库中不存在，以 `broken` 结尾的键单独加载时失败
+#[derive(Debug, Default)]
+struct CountingLoader {
+    load_calls: AtomicUsize,
+    batch_calls: AtomicUsize,
+    fail_batch: bool,
+}
+
+#[async_trait]
+impl DbLoader for CountingLoader {
+    async fn load(&self, key: &str) -> Result<Option<Vec<u8>>> {
+        self.load_calls.fetch_add(1, Ordering::SeqCst);
+        if key.ends_with("broken") {
+            return Err(CacheError::DatabaseError(format!("cannot load {}", key)));
+        }
+        if key.ends_with("missing") {
+            return Ok(None);
+        }
+        Ok(Some(serde_json::to_vec(&format!("db:{}", key)).unwrap()))
+    }
+
+    async fn load_batch(&self, keys: Vec<String>) -> Result<Vec<(String, Vec<u8>)>> {
+        self.batch_calls.fetch_add(1, Ordering::SeqCst);
+        if self.fail_batch {
+            return Err(CacheError::DatabaseError("batch failed".to_string()));
+        }
+        Ok(keys
+            .into_iter()
+            .filter(|k| !k.ends_with("missing"))
+            .map(|k| {
+                let value = serde_json::to_vec(&format!("db:{}", k)).unwrap();
+                (k, value)
+            })
+            .collect())
+    }
+
+    fn is_healthy(&self) -> bool {
+        true
+    }
+}
+
+/// 记录最大并发调用数的慢速加载器
+#[derive(Debug, Default)]
+struct SlowLoader {
+    delay_ms: u64,
+    active: AtomicUsize,
+    max_active: AtomicUsize,
+    calls: AtomicUsize,
+}
+
+#[async_trait]
+impl DbLoader for SlowLoader {
+    async fn load(&self, key: &str) -> Result<Option<Vec<u8>>> {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+        let active = self.active.fetch_add(1, Ordering::SeqCst) + 1;
+        self.max_active.fetch_max(active, Ordering::SeqCst);
+        tokio::time::sleep(Duration::from_millis(self.delay_ms)).await;
+        self.active.fetch_sub(1, Ordering::SeqCst);
+        Ok(Some(serde_json::to_vec(&format!("db:{}", key)).unwrap()))
+    }
+
+    async fn load_batch(&self, _keys: Vec<String>) -> Result<Vec<(String, Vec<u8>)>> {
+        Ok(Vec::new())
+    }
+
+    fn is_healthy(&self) -> bool {
+        true
+    }
+}
+
+async fn create_limited_client(
+    service_name: &str,
+    max_concurrent_fallbacks: usize,
+    fallback_permit_timeout_ms: u64,
+    loader: Arc<SlowLoader>,
+) -> Arc<TwoLevelClient> {
+    let l2_config = L2Config {
+        connection_string: SecretString::from(FakeRedis::start().await.url),
+        ..Default::default()
+    };
+    let mut client = TwoLevelClient::new(
+        service_name.to_string(),
+        TwoLevelConfig {
+            promote_on_hit: false,
+            max_concurrent_fallbacks: Some(max_concurrent_fallbacks),
+            fallback_permit_timeout_ms,
+            ttl_divergence_factor: 1.0,
+            write_behind: false,
+            read_your_writes: None,
+            secondary: None,
+            admission_threshold: None,
+            recovering_ttl_factor: None,
+            max_promotion_tasks: None,
+            ..Default::default()
+        },
+        Arc::new(L1Backend::new(1000)),
+        Arc::new(L2Backend::new(&l2_config).await.unwrap()),
+        SerializerEnum::Json(JsonSerializer::new()),
+    )
+    .await
+    .unwrap();
+    client.set_db_fallback_manager(Arc::new(DbFallbackManager::new(loader, true, 1000, 0)));
+    Arc::new(client)
+}
+
+#[tokio::test]
+async fn test_concurrent_fallbacks_are_limited() {
+    let loader = Arc::new(SlowLoader {
+        delay_ms: 20,
+        ..Default::default()
+    });
+    let client = create_limited_client("fallback_limit_test", 5, 5000, loader.clone()).await;
+
+    let tasks: Vec<_> = (0..100)
+        .map(|i| {
+            let client = client.clone();
+            tokio::spawn(async move {
+                client
+                    .get_bytes(&format!("fallback_limit_test:{}", i))
+                    .await
+                    .unwrap()
+            })
+        })
+        .collect();
+    for task in tasks {
+        assert!(task.await.unwrap().is_some());
+    }
+
+    assert_eq!(loader.calls.load(Ordering::SeqCst), 100);
+    assert!(loader.max_active.load(Ordering::SeqCst) <= 5);
+}
+
+#[tokio::test]
+async fn test_fallback_permit_timeout_returns_none() {
+    let loader = Arc::new(SlowLoader {
+        delay_ms: 500,
+        ..Default::default()
+    });
+    let client = create_limited_client("fallback_timeout_test", 1, 50, loader.clone()).await;
+
+    let busy = {
+        let client = client.clone();
+        tokio::spawn(async move { client.get_bytes("fallback_timeout_test:busy").await })
+    };
+    tokio::time::sleep(Duration::from_millis(20)).await;
+
+    // 唯一的许可被占用，等待超时后按未命中返回，不调用加载器
+    let value = client
+        .get_bytes("fallback_timeout_test:waiting")
+        .await
+        .unwrap();
+    assert!(value.is_none());
+    assert!(busy.await.unwrap().unwrap().is_some());
+    assert_eq!(loader.calls.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn test_fallback_load_many_uses_single_batch() {
+    let loader = Arc::new(CountingLoader::default());
+    let mgr = DbFallbackManager::new(loader.clone(), true, 1000, 0);
+
+    let keys = ["a", "b", "c:missing"];
+    let results = mgr.fallback_load_many(&keys).await;
+
+    assert_eq!(loader.batch_calls.load(Ordering::SeqCst), 1);
+    assert_eq!(loader.load_calls.load(Ordering::SeqCst), 0);
+    assert!(results["a"].as_ref().unwrap().is_some());
+    assert!(results["b"].as_ref().unwrap().is_some());
+    assert!(results["c:missing"].as_ref().unwrap().is_none());
+}
+
+#[tokio::test]
+async fn test_fallback_load_many_isolates_key_errors() {
+    let loader = Arc::new(CountingLoader {
+        fail_batch: true,
+        ..Default::default()
+    });
+    let mgr = DbFallbackManager::new(loader.clone(), true, 1000, 0);
+
+    let keys = ["ok", "key:broken"];
+    let results = mgr.fallback_load_many(&keys).await;
+
+    assert_eq!(loader.batch_calls.load(Ordering::SeqCst), 1);
+    assert!(results["ok"].as_ref().unwrap().is_some());
+    assert!(results["key:broken"].is_err());
+}
+
+#[tokio::test]
+async fn test_get_many_batches_db_fallback() {
+    if !common::is_redis_available().await {
+        println!("跳过测试: Redis不可用");
+        return;
+    }
+
+    let service_name = common::generate_unique_service_name("get_many");
+    let l1 = Arc::new(L1Backend::new(1000));
+    let l2 = Arc::new(L2Backend::new(&create_standalone_config()).await.unwrap());
+
+    let mut client = TwoLevelClient::new(
+        service_name.clone(),
+        TwoLevelConfig {
+            enable_batch_write: true,
+            ..Default::default()
+        },
+        l1,
+        l2,
+        SerializerEnum::Json(JsonSerializer::new()),
+    )
+    .await
+    .unwrap();
+
+    let loader = Arc::new(CountingLoader::default());
+    client.set_db_fallback_manager(Arc::new(DbFallbackManager::new(
+        loader.clone(),
+        true,
+        1000,
+        0,
+    )));
+
+    let keys: Vec<String> = (0..10)
+        .map(|i| format!("{}:item:{}", service_name, i))
+        .collect();
+    let key_refs: Vec<&str> = keys.iter().map(|k| k.as_str()).collect();
+
+    let values: std::collections::HashMap<String, Option<String>> =
+        client.get_many(&key_refs).await.unwrap();
+
+    assert_eq!(loader.batch_calls.load(Ordering::SeqCst), 1);
+    assert_eq!(loader.load_calls.load(Ordering::SeqCst), 0);
+    assert_eq!(values.len(), 10);
+    for key in &keys {
+        assert_eq!(values[key], Some(format!("db:{}", key)));
+    }
+
+    // 回写后再次读取直接命中缓存，不再回源
+    let _: std::collections::HashMap<String, Option<String>> =
+        client.get_many(&key_refs).await.unwrap();
+    assert_eq!(loader.batch_calls.load(Ordering::SeqCst), 1);
+
+    client.shutdown().await.unwrap();
+    common::cleanup_service(&service_name).await;
+}
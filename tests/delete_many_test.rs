@@ -0,0 +1,84 @@
+//! Copyright (c) 2025-2026, Kirky.X
+//!
+//! MIT License
+//!
+//! 批量删除测试
+
+use common::fake_redis::FakeRedis;
+use futures::StreamExt;
+use oxcache::backend::l1::L1Backend;
+use oxcache::backend::l2::L2Backend;
+use oxcache::client::two_level::TwoLevelClient;
+use oxcache::client::CacheOps;
+use oxcache::config::{L2Config, TwoLevelConfig};
+use oxcache::serialization::{JsonSerializer, SerializerEnum};
+use secrecy::SecretString;
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::Duration;
+
+mod common;
+
+#[tokio::test]
+async fn test_delete_many_removes_keys_with_single_invalidation() {
+    let redis = FakeRedis::start().await;
+    let l2_config = L2Config {
+        connection_string: SecretString::from(redis.url.clone()),
+        ..Default::default()
+    };
+    let l1 = Arc::new(L1Backend::new(100));
+    let client = TwoLevelClient::new(
+        "delete_many_test".to_string(),
+        TwoLevelConfig::default(),
+        l1.clone(),
+        Arc::new(L2Backend::new(&l2_config).await.unwrap()),
+        SerializerEnum::Json(JsonSerializer::new()),
+    )
+    .await
+    .unwrap();
+
+    let keys: Vec<String> = (0..20).map(|i| format!("delete_many_test:{}", i)).collect();
+    for key in &keys {
+        client
+            .set_bytes(key, b"v".to_vec(), Some(60))
+            .await
+            .unwrap();
+        assert!(l1.get_bytes(key).await.unwrap().is_some());
+    }
+
+    let mut invalidations = Box::pin(client.watch_invalidations());
+    redis.log.lock().unwrap().clear();
+
+    let key_refs: Vec<&str> = keys.iter().map(String::as_str).collect();
+    client.delete_many(&key_refs).await.unwrap();
+
+    for key in &keys {
+        assert!(l1.get_bytes(key).await.unwrap().is_none());
+        assert!(client.get_bytes(key).await.unwrap().is_none());
+    }
+
+    let log = redis.log.lock().unwrap().clone();
+    let deleted: HashSet<&String> = log
+        .iter()
+        .filter(|args| args[0] == "DEL")
+        .flat_map(|args| &args[1..])
+        .collect();
+    for key in &keys {
+        assert!(deleted.contains(key), "{} not deleted from L2", key);
+    }
+    let publishes: Vec<&Vec<String>> = log.iter().filter(|args| args[0] == "PUBLISH").collect();
+    assert_eq!(publishes.len(), 1);
+
+    // 订阅者将批量消息拆分为逐个键的失效通知
+    let mut received = HashSet::new();
+    while received.len() < keys.len() {
+        let key = tokio::time::timeout(Duration::from_secs(5), invalidations.next())
+            .await
+            .expect("timed out waiting for invalidations")
+            .unwrap();
+        received.insert(key);
+    }
+    assert_eq!(received, keys.iter().cloned().collect());
+
+    client.shutdown().await.unwrap();
+}
@@ -0,0 +1,58 @@
+//! Copyright (c) 2025-2026, Kirky.X
+//!
+//! MIT License
+//!
+//! L1/L2一致性检查测试（模拟Redis中读取类脚本恒返回nil，L2视为空）
+
+use common::fake_redis::FakeRedis;
+use oxcache::backend::l1::L1Backend;
+use oxcache::backend::l2::L2Backend;
+use oxcache::client::two_level::{LayerDiff, LayerStatus, TwoLevelClient};
+use oxcache::config::{L2Config, TwoLevelConfig};
+use oxcache::serialization::{JsonSerializer, SerializerEnum};
+use secrecy::SecretString;
+use std::sync::Arc;
+
+mod common;
+
+#[tokio::test]
+async fn test_diff_layers_reports_l1_only_and_missing_keys() {
+    let l2_config = L2Config {
+        connection_string: SecretString::from(FakeRedis::start().await.url),
+        ..Default::default()
+    };
+    let client = TwoLevelClient::new(
+        "diff_layers_test".to_string(),
+        TwoLevelConfig::default(),
+        Arc::new(L1Backend::new(100)),
+        Arc::new(L2Backend::new(&l2_config).await.unwrap()),
+        SerializerEnum::Json(JsonSerializer::new()),
+    )
+    .await
+    .unwrap();
+
+    client
+        .set_l1_only("diff_layers_test:local", &1u32, Some(60))
+        .await
+        .unwrap();
+
+    let diffs = client
+        .diff_layers(&["diff_layers_test:local", "diff_layers_test:absent"])
+        .await
+        .unwrap();
+    assert_eq!(
+        diffs,
+        vec![
+            LayerDiff {
+                key: "diff_layers_test:local".to_string(),
+                status: LayerStatus::L1Only,
+            },
+            LayerDiff {
+                key: "diff_layers_test:absent".to_string(),
+                status: LayerStatus::Missing,
+            },
+        ]
+    );
+
+    client.shutdown().await.unwrap();
+}
@@ -0,0 +1,96 @@
+//! Copyright (c) 2025-2026, Kirky.X
+//!
+//! MIT License
+//!
+//! L2初始化时预先建连测试
+
+use async_trait::async_trait;
+use common::fake_redis::FakeRedis;
+use oxcache::backend::l2::L2Backend;
+use oxcache::backend::redis_provider::{DefaultRedisProvider, RedisProvider};
+use oxcache::config::L2Config;
+use oxcache::error::Result;
+use redis::aio::ConnectionManager;
+use redis::Client;
+use secrecy::SecretString;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+mod common;
+
+/// 统计建连次数的Redis提供者
+#[derive(Default)]
+struct CountingProvider {
+    connects: AtomicUsize,
+}
+
+#[async_trait]
+impl RedisProvider for CountingProvider {
+    async fn get_standalone_client(
+        &self,
+        config: &L2Config,
+    ) -> Result<(Client, ConnectionManager)> {
+        self.connects.fetch_add(1, Ordering::SeqCst);
+        DefaultRedisProvider.get_standalone_client(config).await
+    }
+
+    async fn get_cluster_client(&self, config: &L2Config) -> Result<redis::cluster::ClusterClient> {
+        DefaultRedisProvider.get_cluster_client(config).await
+    }
+
+    async fn get_sentinel_client(
+        &self,
+        config: &L2Config,
+    ) -> Result<(Client, ConnectionManager, Option<ConnectionManager>)> {
+        DefaultRedisProvider.get_sentinel_client(config).await
+    }
+}
+
+fn ping_count(fake: &FakeRedis) -> usize {
+    fake.log
+        .lock()
+        .unwrap()
+        .iter()
+        .filter(|args| {
+            args.first()
+                .is_some_and(|cmd| cmd.eq_ignore_ascii_case("PING"))
+        })
+        .count()
+}
+
+fn config(fake: &FakeRedis, eager_connect: bool) -> L2Config {
+    L2Config {
+        connection_string: SecretString::from(fake.url.clone()),
+        eager_connect,
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn test_eager_connect_pings_during_init() {
+    let fake = FakeRedis::start().await;
+    let provider = Arc::new(CountingProvider::default());
+
+    let l2 = L2Backend::new_with_provider(&config(&fake, true), provider.clone())
+        .await
+        .unwrap();
+    assert_eq!(ping_count(&fake), 1);
+
+    // 连接已就绪，首次请求不再建连
+    l2.ping().await.unwrap();
+    assert_eq!(ping_count(&fake), 2);
+    assert_eq!(provider.connects.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn test_lazy_connect_is_default() {
+    assert!(!L2Config::default().eager_connect);
+
+    let fake = FakeRedis::start().await;
+    let provider = Arc::new(CountingProvider::default());
+    let _l2 = L2Backend::new_with_provider(&config(&fake, false), provider.clone())
+        .await
+        .unwrap();
+    assert_eq!(ping_count(&fake), 0);
+    assert_eq!(provider.connects.load(Ordering::SeqCst), 1);
+}
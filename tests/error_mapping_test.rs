@@ -0,0 +1,45 @@
+//! Copyright (c) 2025-2026, Kirky.X
+//!
+//! MIT License
+//!
+//! Redis错误类型映射测试
+
+use oxcache::error::CacheError;
+use redis::{ErrorKind, RedisError};
+use std::io;
+
+#[test]
+fn test_authentication_failure_maps_to_variant() {
+    let err: CacheError =
+        RedisError::from((ErrorKind::AuthenticationFailed, "invalid password")).into();
+    assert!(matches!(err, CacheError::AuthenticationFailed(_)));
+    assert!(!err.is_transient());
+}
+
+#[test]
+fn test_io_timeout_maps_to_timeout() {
+    let err: CacheError =
+        RedisError::from(io::Error::new(io::ErrorKind::TimedOut, "timed out")).into();
+    assert!(matches!(err, CacheError::Timeout(_)));
+    assert!(err.is_transient());
+}
+
+#[test]
+fn test_connection_refused_maps_to_variant() {
+    let err: CacheError =
+        RedisError::from(io::Error::new(io::ErrorKind::ConnectionRefused, "refused")).into();
+    assert!(matches!(err, CacheError::ConnectionRefused(_)));
+    assert!(err.is_transient());
+}
+
+#[test]
+fn test_other_errors_keep_redis_variant() {
+    let err: CacheError = RedisError::from((ErrorKind::TypeError, "wrong type")).into();
+    assert!(matches!(err, CacheError::RedisError(_)));
+    assert!(!err.is_transient());
+
+    let err: CacheError =
+        RedisError::from(io::Error::new(io::ErrorKind::BrokenPipe, "broken pipe")).into();
+    assert!(matches!(err, CacheError::RedisError(_)));
+    assert!(err.is_transient());
+}
@@ -0,0 +1,54 @@
+//! Copyright (c) 2025-2026, Kirky.X
+//!
+//! MIT License
+//!
+//! 批量存在性检查测试
+
+use oxcache::backend::l1::L1Backend;
+use oxcache::backend::l2::L2Backend;
+use oxcache::client::two_level::TwoLevelClient;
+use oxcache::client::CacheOps;
+use oxcache::config::{L2Config, TwoLevelConfig};
+use oxcache::serialization::{JsonSerializer, SerializerEnum};
+use oxcache::utils::clock::MockClock;
+use std::sync::Arc;
+
+#[tokio::test]
+async fn test_exists_many_mixes_l1_l2_and_absent_keys() {
+    let l1 = Arc::new(L1Backend::new(100));
+    let l2 = Arc::new(L2Backend::in_memory(
+        &L2Config::default(),
+        Arc::new(MockClock::new()),
+    ));
+    let client = TwoLevelClient::new(
+        "exists_many_test".to_string(),
+        TwoLevelConfig::default(),
+        l1.clone(),
+        l2.clone(),
+        SerializerEnum::Json(JsonSerializer::new()),
+    )
+    .await
+    .unwrap();
+
+    // 只存在于L1
+    l1.set_bytes("exists_many:l1", b"1".to_vec(), Some(60))
+        .await
+        .unwrap();
+    // 只存在于L2
+    l2.set_bytes("exists_many:l2", b"2".to_vec(), Some(60))
+        .await
+        .unwrap();
+
+    let exists = client
+        .exists_many(&[
+            "exists_many:absent",
+            "exists_many:l2",
+            "exists_many:l1",
+            "exists_many:missing",
+        ])
+        .await
+        .unwrap();
+    assert_eq!(exists, vec![false, true, true, false]);
+
+    assert!(client.exists_many(&[]).await.unwrap().is_empty());
+}
@@ -0,0 +1,63 @@
+//! Copyright (c) 2025-2026, Kirky.X
+//!
+//! MIT License
+//!
+//! 批量读取测试（模拟Redis中GET恒返回nil）
+
+use common::fake_redis::FakeRedis;
+use oxcache::backend::l1::L1Backend;
+use oxcache::backend::l2::L2Backend;
+use oxcache::client::two_level::TwoLevelClient;
+use oxcache::config::{L2Config, TwoLevelConfig};
+use oxcache::serialization::{JsonSerializer, SerializerEnum};
+use secrecy::SecretString;
+use std::sync::Arc;
+
+mod common;
+
+#[tokio::test]
+async fn test_get_many_reads_only_l1_misses_from_l2() {
+    let fake = FakeRedis::start().await;
+    let l2_config = L2Config {
+        connection_string: SecretString::from(fake.url.clone()),
+        ..Default::default()
+    };
+    let l2 = Arc::new(L2Backend::new(&l2_config).await.unwrap());
+    let client = TwoLevelClient::new(
+        "get_many_test".to_string(),
+        TwoLevelConfig::default(),
+        Arc::new(L1Backend::new(100)),
+        l2.clone(),
+        SerializerEnum::Json(JsonSerializer::new()),
+    )
+    .await
+    .unwrap();
+
+    client
+        .set_l1_only("get_many_test:local", &1u32, Some(60))
+        .await
+        .unwrap();
+
+    let results = client
+        .get_many_bytes(&["get_many_test:local", "get_many_test:a", "get_many_test:b"])
+        .await
+        .unwrap();
+    assert!(results["get_many_test:local"].is_some());
+    assert_eq!(results["get_many_test:a"], None);
+    assert_eq!(results["get_many_test:b"], None);
+
+    assert!(!fake.touched("get_many_test:local"));
+    assert!(fake.touched("get_many_test:a"));
+    assert!(fake.touched("get_many_test:b"));
+
+    // 后端批量读取按输入顺序返回
+    assert_eq!(
+        l2.get_many_bytes(&["get_many_test:a", "get_many_test:b"])
+            .await
+            .unwrap(),
+        vec![None, None]
+    );
+    assert!(l2.get_many_bytes(&[]).await.unwrap().is_empty());
+
+    client.shutdown().await.unwrap();
+}
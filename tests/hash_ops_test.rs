@@ -0,0 +1,77 @@
+//! Copyright (c) 2025-2026, Kirky.X
+//!
+//! MIT License
+//!
+//! 哈希表类型化操作测试
+
+use common::client_test_utils::{self, fake_redis_l2};
+use common::fake_redis::FakeRedis;
+use oxcache::backend::l1::L1Backend;
+use oxcache::client::two_level::TwoLevelClient;
+use oxcache::config::TwoLevelConfig;
+use oxcache::CacheExt;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+mod common;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Profile {
+    name: String,
+    age: u32,
+}
+
+async fn create_client(service_name: &str) -> (TwoLevelClient, FakeRedis) {
+    let redis = FakeRedis::start().await;
+    let client = client_test_utils::create_client(
+        service_name,
+        TwoLevelConfig::default(),
+        Arc::new(L1Backend::new(1000)),
+        fake_redis_l2(&redis).await,
+    )
+    .await;
+    (client, redis)
+}
+
+#[tokio::test]
+async fn test_hash_typed_field_round_trip() {
+    let (client, redis) = create_client("hash_ops_test").await;
+    let key = "hash_ops_test:users";
+
+    let alice = Profile {
+        name: "alice".to_string(),
+        age: 30,
+    };
+    let bob = Profile {
+        name: "bob".to_string(),
+        age: 25,
+    };
+    client
+        .hset_value(key, "alice", &alice, Some(60))
+        .await
+        .unwrap();
+    client.hset_value(key, "bob", &bob, Some(60)).await.unwrap();
+
+    let fetched: Option<Profile> = client.hget_as(key, "alice").await.unwrap();
+    assert_eq!(fetched, Some(alice.clone()));
+    let missing: Option<Profile> = client.hget_as(key, "carol").await.unwrap();
+    assert_eq!(missing, None);
+
+    let all: HashMap<String, Profile> = client.hgetall_as(key).await.unwrap();
+    assert_eq!(all.len(), 2);
+    assert_eq!(all["alice"], alice);
+    assert_eq!(all["bob"], bob);
+
+    // 每次写入字段都会刷新整个哈希表的TTL
+    let expires = redis
+        .log
+        .lock()
+        .unwrap()
+        .iter()
+        .filter(|args| args[0] == "EXPIRE" && args[1] == key && args[2] == "60")
+        .count();
+    assert_eq!(expires, 2);
+
+    client.shutdown().await.unwrap();
+}
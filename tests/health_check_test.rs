@@ -0,0 +1,101 @@
+//! Copyright (c) 2025-2026, Kirky.X
+//!
+//! MIT License
+//!
+//! 就绪检查测试
+
+use common::fake_redis::FakeRedis;
+use oxcache::backend::l1::L1Backend;
+use oxcache::backend::l2::L2Backend;
+use oxcache::client::l1::L1Client;
+use oxcache::client::two_level::TwoLevelClient;
+use oxcache::config::{L2Config, TwoLevelConfig};
+use oxcache::manager::MANAGER;
+use oxcache::recovery::health::HealthState;
+use oxcache::serialization::{JsonSerializer, SerializerEnum};
+use oxcache::CacheManager;
+use secrecy::SecretString;
+use std::sync::Arc;
+use std::time::Instant;
+
+mod common;
+
+async fn create_two_level_client(service_name: &str) -> Arc<TwoLevelClient> {
+    let l2_config = L2Config {
+        connection_string: SecretString::from(FakeRedis::start().await.url),
+        ..Default::default()
+    };
+    let l2 = Arc::new(L2Backend::new(&l2_config).await.unwrap());
+    Arc::new(
+        TwoLevelClient::new(
+            service_name.to_string(),
+            TwoLevelConfig::default(),
+            Arc::new(L1Backend::new(1000)),
+            l2,
+            SerializerEnum::Json(JsonSerializer::new()),
+        )
+        .await
+        .unwrap(),
+    )
+}
+
+#[tokio::test]
+async fn test_degraded_service_makes_manager_not_ready() {
+    let two_level = create_two_level_client("health_test_two_level").await;
+    let l1_only = Arc::new(L1Client::new(
+        "health_test_l1".to_string(),
+        Arc::new(L1Backend::new(1000)),
+        SerializerEnum::Json(JsonSerializer::new()),
+    ));
+    MANAGER.insert("health_test_two_level".to_string(), two_level.clone());
+    MANAGER.insert("health_test_l1".to_string(), l1_only);
+
+    assert!(CacheManager::is_ready().await);
+
+    two_level
+        .set_health_state(HealthState::Degraded {
+            since: Instant::now(),
+            failure_count: 3,
+        })
+        .await;
+
+    let report = CacheManager::health_report().await;
+    assert!(matches!(
+        report["health_test_two_level"],
+        HealthState::Degraded {
+            failure_count: 3,
+            ..
+        }
+    ));
+    assert_eq!(report["health_test_l1"], HealthState::Healthy);
+    assert!(!CacheManager::is_ready().await);
+
+    #[cfg(feature = "metrics-server")]
+    {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let (addr, handle) = oxcache::metrics_server::start("127.0.0.1:0").await.unwrap();
+        let mut stream = tokio::net::TcpStream::connect(addr).await.unwrap();
+        stream
+            .write_all(b"GET /healthz HTTP/1.1\r\nHost: localhost\r\n\r\n")
+            .await
+            .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).await.unwrap();
+        assert!(response.starts_with("HTTP/1.1 503"));
+        assert!(response.contains("health_test_two_level: Degraded"));
+        handle.abort();
+    }
+
+    // 恢复中的服务重新视为就绪
+    two_level
+        .set_health_state(HealthState::Recovering {
+            since: Instant::now(),
+            success_count: 1,
+        })
+        .await;
+    assert!(CacheManager::is_ready().await);
+
+    MANAGER.clear();
+    two_level.shutdown().await.unwrap();
+}
@@ -0,0 +1,105 @@
+//! Copyright (c) 2025-2026, Kirky.X
+//!
+//! MIT License
+//!
+//! 健康检查探测间隔抖动测试
+
+use oxcache::config::HealthConfig;
+use oxcache::error::Result;
+use oxcache::recovery::health::{HealthCheckableBackend, HealthChecker, HealthState};
+use oxcache::recovery::wal::{WalEntry, WalManager, WalReplayableBackend};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::sync::RwLock;
+use tokio::time::Instant;
+
+/// 记录每次探测时间的后端
+#[derive(Clone, Default)]
+struct RecordingBackend {
+    probes: Arc<Mutex<Vec<Instant>>>,
+}
+
+impl HealthCheckableBackend for RecordingBackend {
+    async fn ping(&self) -> Result<()> {
+        self.probes.lock().unwrap().push(Instant::now());
+        Ok(())
+    }
+
+    fn command_timeout_ms(&self) -> u64 {
+        100
+    }
+}
+
+impl WalReplayableBackend for RecordingBackend {
+    async fn pipeline_replay(&self, _entries: Vec<WalEntry>) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// 使用暂停的时钟运行健康检查器，返回启动时间与各次探测时间
+async fn run_checker(
+    service_name: &str,
+    config: HealthConfig,
+    run_for: Duration,
+) -> (Instant, Vec<Instant>) {
+    let backend = RecordingBackend::default();
+    let wal = Arc::new(WalManager::new(service_name).await.unwrap());
+    let checker = HealthChecker::new(
+        Arc::new(backend.clone()),
+        Arc::new(RwLock::new(HealthState::Healthy)),
+        wal,
+        service_name.to_string(),
+        100,
+    )
+    .with_config(config);
+
+    tokio::time::pause();
+    let start = Instant::now();
+    let handle = tokio::spawn(checker.start());
+    tokio::time::sleep(run_for).await;
+    handle.abort();
+
+    let probes = backend.probes.lock().unwrap().clone();
+    (start, probes)
+}
+
+#[tokio::test]
+async fn test_probe_intervals_vary_within_jitter_band() {
+    let config = HealthConfig {
+        probe_interval_ms: 1000,
+        jitter_percent: 20,
+    };
+    let (start, probes) = run_checker("health_jitter_test", config, Duration::from_secs(30)).await;
+    assert!(probes.len() >= 20);
+
+    // 首次探测在一个探测间隔内随机延迟
+    assert!(probes[0] - start <= Duration::from_millis(1000));
+
+    let intervals: Vec<Duration> = probes.windows(2).map(|w| w[1] - w[0]).collect();
+    for interval in &intervals {
+        assert!(*interval >= Duration::from_millis(800), "{:?}", interval);
+        assert!(*interval <= Duration::from_millis(1200), "{:?}", interval);
+    }
+    assert!(intervals.iter().any(|interval| *interval != intervals[0]));
+}
+
+#[tokio::test]
+async fn test_probe_interval_fixed_without_jitter() {
+    let config = HealthConfig {
+        probe_interval_ms: 1000,
+        jitter_percent: 0,
+    };
+    let (start, probes) = run_checker(
+        "health_jitter_test_fixed",
+        config,
+        Duration::from_millis(5500),
+    )
+    .await;
+
+    // 未启用抖动时立即进行首次探测（误差为计时器的1毫秒精度）
+    assert!(probes[0] - start <= Duration::from_millis(1));
+    assert_eq!(probes.len(), 6);
+    for w in probes.windows(2) {
+        assert_eq!(w[1] - w[0], Duration::from_millis(1000));
+    }
+}
@@ -58,6 +58,7 @@ async fn test_batch_write_performance() {
                         default_ttl: None,
                         max_key_length: 256,
                         max_value_size: 1024 * 1024 * 10,
+                        ..Default::default()
                     }),
                     two_level: Some(TwoLevelConfig {
                         promote_on_hit: false,
@@ -69,7 +70,9 @@ async fn test_batch_write_performance() {
                         warmup: None,
                         max_key_length: Some(1024),
                         max_value_size: Some(1024 * 1024),
+                        ..Default::default()
                     }),
+                    ..Default::default()
                 },
             );
             map
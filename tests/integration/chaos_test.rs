@@ -52,6 +52,7 @@ async fn test_chaos_redis_outage_and_recovery() {
         default_ttl: Some(300),
         max_key_length: 256,
         max_value_size: 1024 * 1024 * 10,
+        ..Default::default()
     };
 
     let two_level_config = TwoLevelConfig {
@@ -64,6 +65,7 @@ async fn test_chaos_redis_outage_and_recovery() {
         warmup: None,
         max_key_length: Some(1024),
         max_value_size: Some(1024 * 1024),
+        ..Default::default()
     };
 
     let l1 = Arc::new(L1Backend::new(l1_config.max_capacity));
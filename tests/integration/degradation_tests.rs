@@ -8,6 +8,8 @@
 /// - tests/degradation_test.rs
 /// - tests/degradation_integration_test.rs
 /// - tests/health_state_test.rs
+/// - tests/read_your_writes_test.rs
+/// - tests/soft_ttl_test.rs
 use oxcache::config::{L2Config, RedisMode};
 use oxcache::recovery::health::{
     HealthCheckableBackend, HealthChecker, HealthState, WalReplayableBackendTrait,
@@ -78,6 +80,7 @@ fn create_test_l2_config() -> L2Config {
         default_ttl: None,
         max_key_length: 256,
         max_value_size: 1024 * 1024 * 10,
+        ..Default::default()
     }
 }
 
@@ -544,3 +547,265 @@ mod health_checker_tests {
         common::cleanup_service(&service_name).await;
     }
 }
+
+mod stale_read_tests {
+    use super::*;
+    use common::redis_test_utils::create_standalone_config;
+    use oxcache::backend::{l1::L1Backend, l2::L2Backend};
+    use oxcache::client::two_level::TwoLevelClient;
+    use oxcache::config::TwoLevelConfig;
+    use oxcache::serialization::{JsonSerializer, SerializerEnum};
+
+    #[tokio::test]
+    async fn test_degraded_client_serves_stale_l1() {
+        if !common::is_redis_available().await {
+            println!("跳过测试: Redis不可用");
+            return;
+        }
+
+        let service_name = common::generate_unique_service_name("stale_read");
+        let l1 = Arc::new(L1Backend::new(1000));
+        let l2 = Arc::new(L2Backend::new(&create_standalone_config()).await.unwrap());
+        let client = TwoLevelClient::new(
+            service_name.clone(),
+            TwoLevelConfig::default(),
+            l1,
+            l2,
+            SerializerEnum::Json(JsonSerializer::new()),
+        )
+        .await
+        .unwrap();
+
+        let key = format!("{}:profile", service_name);
+        client.set_l1_only(&key, &"cached", Some(1)).await.unwrap();
+
+        // 健康状态下未过期读取
+        assert!(!client.is_degraded().await);
+        let fresh: Option<(String, bool)> = client.get_allow_stale(&key).await.unwrap();
+        assert_eq!(fresh, Some(("cached".to_string(), false)));
+
+        tokio::time::sleep(std::time::Duration::from_millis(1100)).await;
+
+        client
+            .set_health_state(HealthState::Degraded {
+                since: std::time::Instant::now(),
+                failure_count: 1,
+            })
+            .await;
+        assert!(client.is_degraded().await);
+
+        // 常规读取视为未命中，允许过期读取时返回过期标记
+        let normal: Option<String> = client.get(&key).await.unwrap();
+        assert!(normal.is_none());
+        let stale: Option<(String, bool)> = client.get_allow_stale(&key).await.unwrap();
+        assert_eq!(stale, Some(("cached".to_string(), true)));
+
+        client.shutdown().await.unwrap();
+        common::cleanup_service(&service_name).await;
+    }
+}
+
+mod read_your_writes_tests {
+    use super::*;
+    use common::client_test_utils::{create_client, in_memory_l2};
+    use oxcache::backend::l1::L1Backend;
+    use oxcache::client::two_level::TwoLevelClient;
+    use oxcache::client::CacheOps;
+    use oxcache::config::{ReadYourWritesConfig, TwoLevelConfig, WriteOrder};
+
+    async fn degraded_client(
+        service: &str,
+        read_your_writes: Option<ReadYourWritesConfig>,
+    ) -> TwoLevelClient {
+        let client = create_client(
+            service,
+            TwoLevelConfig {
+                // L2优先写入在降级时不保留L1副本，写入只进入WAL
+                write_order: WriteOrder::L2First,
+                read_your_writes,
+                ..Default::default()
+            },
+            Arc::new(L1Backend::new(100)),
+            in_memory_l2(),
+        )
+        .await;
+        client
+            .set_health_state(HealthState::Degraded {
+                since: std::time::Instant::now(),
+                failure_count: 3,
+            })
+            .await;
+        client
+    }
+
+    #[tokio::test]
+    async fn test_degraded_write_is_readable_immediately() {
+        let client = degraded_client(
+            "read_your_writes_test",
+            Some(ReadYourWritesConfig::default()),
+        )
+        .await;
+        let key = "read_your_writes_test:user:1";
+
+        client
+            .set_bytes(key, b"alice".to_vec(), Some(60))
+            .await
+            .unwrap();
+        assert_eq!(
+            client.get_bytes(key).await.unwrap(),
+            Some(b"alice".to_vec())
+        );
+
+        // 删除后不再从缓冲返回旧值
+        client.delete(key).await.unwrap();
+        assert_eq!(client.get_bytes(key).await.unwrap(), None);
+
+        client.shutdown().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_delete_many_invalidates_buffer() {
+        let client = degraded_client(
+            "read_your_writes_delete_many_test",
+            Some(ReadYourWritesConfig::default()),
+        )
+        .await;
+        let keys = [
+            "read_your_writes_delete_many_test:user:1",
+            "read_your_writes_delete_many_test:user:2",
+        ];
+
+        for key in keys {
+            client
+                .set_bytes(key, b"alice".to_vec(), Some(60))
+                .await
+                .unwrap();
+        }
+        client.delete_many(&keys).await.unwrap();
+        for key in keys {
+            assert_eq!(client.get_bytes(key).await.unwrap(), None);
+        }
+
+        client.shutdown().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_degraded_write_is_missed_without_buffer() {
+        let client = degraded_client("read_your_writes_disabled_test", None).await;
+        let key = "read_your_writes_disabled_test:user:1";
+
+        client
+            .set_bytes(key, b"alice".to_vec(), Some(60))
+            .await
+            .unwrap();
+        assert_eq!(client.get_bytes(key).await.unwrap(), None);
+
+        client.shutdown().await.unwrap();
+    }
+}
+
+mod soft_ttl_tests {
+    use super::*;
+    use common::client_test_utils::{create_client, in_memory_l2_with_clock};
+    use oxcache::backend::l1::L1Backend;
+    use oxcache::client::two_level::TwoLevelClient;
+    use oxcache::client::CacheOps;
+    use oxcache::config::TwoLevelConfig;
+    use oxcache::error::CacheError;
+    use oxcache::utils::clock::MockClock;
+    use std::time::Duration;
+
+    /// 创建L2过期时间与软TTL共用模拟时钟的客户端
+    async fn soft_ttl_client(service: &str, clock: Arc<MockClock>) -> TwoLevelClient {
+        create_client(
+            service,
+            TwoLevelConfig::default(),
+            Arc::new(L1Backend::new(100)),
+            in_memory_l2_with_clock(clock.clone()),
+        )
+        .await
+        .with_clock(clock)
+    }
+
+    #[tokio::test]
+    async fn test_read_past_soft_ttl_is_flagged_stale() {
+        let clock = Arc::new(MockClock::new());
+        let client = soft_ttl_client("soft_ttl_test", clock.clone()).await;
+        let key = "soft_ttl_test:profile";
+
+        client
+            .set_with_soft_ttl(key, &"alice", 1, 10)
+            .await
+            .unwrap();
+        assert_eq!(
+            client.get_allow_stale::<String>(key).await.unwrap(),
+            Some(("alice".to_string(), false))
+        );
+        // 软过期时间作为头部随值保存，不写入旁路键
+        assert_eq!(
+            client
+                .get_bytes("soft_ttl_test:profile:soft_ttl")
+                .await
+                .unwrap(),
+            None
+        );
+        assert_eq!(
+            client.get_allow_stale_bytes(key).await.unwrap(),
+            Some((b"\"alice\"".to_vec(), false))
+        );
+
+        // 字节读取接口均返回去除软TTL头部后的值
+        let payload = b"\"alice\"".to_vec();
+        assert_eq!(client.get_bytes(key).await.unwrap(), Some(payload.clone()));
+        assert_eq!(
+            client.get_l1_bytes(key).await.unwrap(),
+            Some(payload.clone())
+        );
+        assert_eq!(
+            client.get_l2_bytes(key).await.unwrap(),
+            Some(payload.clone())
+        );
+        assert_eq!(
+            client.get_many_bytes(&[key]).await.unwrap().get(key),
+            Some(&Some(payload.clone()))
+        );
+        assert_eq!(
+            client
+                .get_bytes_with_timeout(key, Duration::from_secs(1))
+                .await
+                .unwrap(),
+            Some(payload)
+        );
+
+        clock.advance(Duration::from_secs(2));
+
+        // 超过软TTL但未超过硬TTL：仍返回值，并标记为过期
+        assert_eq!(
+            client.get_allow_stale::<String>(key).await.unwrap(),
+            Some(("alice".to_string(), true))
+        );
+        assert_eq!(
+            client.get::<String>(key).await.unwrap(),
+            Some("alice".to_string())
+        );
+
+        // 普通写入覆盖后旧的软TTL不再生效
+        client.set(key, &"bob", Some(10)).await.unwrap();
+        assert_eq!(
+            client.get_allow_stale::<String>(key).await.unwrap(),
+            Some(("bob".to_string(), false))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_soft_ttl_must_not_exceed_hard_ttl() {
+        let client = soft_ttl_client("soft_ttl_invalid_test", Arc::new(MockClock::new())).await;
+        let key = "soft_ttl_invalid_test:k";
+
+        let result = client.set_with_soft_ttl(key, &1, 20, 10).await;
+        assert!(matches!(result, Err(CacheError::InvalidInput(_))));
+        let result = client.set_with_soft_ttl(key, &1, 0, 10).await;
+        assert!(matches!(result, Err(CacheError::InvalidInput(_))));
+        assert_eq!(client.get::<i32>(key).await.unwrap(), None);
+    }
+}
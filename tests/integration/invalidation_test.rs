@@ -7,13 +7,18 @@
 #[path = "../common/mod.rs"]
 mod common;
 
+use common::redis_test_utils::create_standalone_config;
 use futures::stream::StreamExt;
+use oxcache::backend::{l1::L1Backend, l2::L2Backend};
+use oxcache::client::two_level::TwoLevelClient;
 use oxcache::config::{
     CacheType, Config, GlobalConfig, InvalidationChannelConfig, L1Config, L2Config, RedisMode,
     ServiceConfig, TwoLevelConfig,
 };
-use oxcache::CacheManager;
+use oxcache::serialization::{JsonSerializer, SerializerEnum};
+use oxcache::{CacheManager, CacheOps};
 use std::collections::HashMap;
+use std::sync::Arc;
 use std::time::Duration;
 use tokio::time::sleep;
 
@@ -84,7 +89,9 @@ async fn test_multi_instance_invalidation() {
                         warmup: None,
                         max_key_length: Some(1024),
                         max_value_size: Some(1024 * 1024),
+                        ..Default::default()
                     }),
+                    ..Default::default()
                 },
             );
             map
@@ -187,3 +194,56 @@ async fn test_multi_instance_invalidation() {
 
     common::cleanup_service(&service_name).await;
 }
+
+async fn create_two_level_client(service_name: &str) -> TwoLevelClient {
+    let l2 = L2Backend::new(&create_standalone_config())
+        .await
+        .expect("Failed to create L2 backend");
+    TwoLevelClient::new(
+        service_name.to_string(),
+        TwoLevelConfig::default(),
+        Arc::new(L1Backend::new(1000)),
+        Arc::new(l2),
+        SerializerEnum::Json(JsonSerializer::new()),
+    )
+    .await
+    .expect("Failed to create client")
+}
+
+/// 测试失效通知订阅
+///
+/// 验证一个客户端发布的失效消息会出现在另一个客户端的观察流中，
+/// 且同一客户端上的多个观察者都能收到。
+#[tokio::test]
+async fn test_watch_invalidations_across_clients() {
+    if !common::wait_for_redis("redis://127.0.0.1:6379").await {
+        println!("Skipping test_watch_invalidations_across_clients: Redis not available");
+        return;
+    }
+
+    let service_name = common::generate_unique_service_name("watch_invalidation_test");
+    let writer = create_two_level_client(&service_name).await;
+    let reader = create_two_level_client(&service_name).await;
+
+    let mut first = Box::pin(reader.watch_invalidations());
+    let mut second = Box::pin(reader.watch_invalidations());
+
+    let key = format!("{}:watched", service_name);
+    writer
+        .set_bytes(&key, b"value".to_vec(), Some(60))
+        .await
+        .expect("Set failed");
+    writer.delete(&key).await.expect("Delete failed");
+
+    for stream in [&mut first, &mut second] {
+        let received = tokio::time::timeout(Duration::from_secs(2), stream.next())
+            .await
+            .expect("Timeout waiting for invalidation")
+            .expect("Watch stream ended unexpectedly");
+        assert_eq!(received, key);
+    }
+
+    writer.shutdown().await.unwrap();
+    reader.shutdown().await.unwrap();
+    common::cleanup_service(&service_name).await;
+}
@@ -4,9 +4,12 @@
 //!
 //! 生命周期管理集成测试
 
+use common::client_test_utils::{create_client, fake_redis_l2};
+use common::fake_redis::FakeRedis;
 use oxcache::backend::l1::L1Backend;
 use oxcache::backend::l2::L2Backend;
 use oxcache::client::two_level::TwoLevelClient;
+use oxcache::client::CacheOps;
 use oxcache::config::{L1Config, L2Config, TwoLevelConfig};
 use oxcache::serialization::SerializerEnum;
 use std::sync::Arc;
@@ -51,6 +54,7 @@ async fn test_client_lifecycle_shutdown() {
         warmup: None,
         max_key_length: Some(1024),
         max_value_size: Some(1024 * 1024),
+        ..Default::default()
     };
 
     {
@@ -108,6 +112,7 @@ async fn test_two_level_client_shutdown() {
         warmup: None,
         max_key_length: Some(1024),
         max_value_size: Some(1024 * 1024),
+        ..Default::default()
     };
 
     let client = TwoLevelClient::new(
@@ -139,3 +144,171 @@ async fn test_two_level_client_shutdown() {
     // 验证关闭后客户端状态（这里主要验证不panic）
     // 注意：由于客户端已关闭，某些操作可能会失败，这是预期的行为
 }
+
+#[tokio::test]
+async fn test_shutdown_flushes_pending_batch_writes() {
+    common::setup_logging();
+
+    if !common::is_redis_available().await {
+        println!(
+            "Skipping test_shutdown_flushes_pending_batch_writes because Redis is not available"
+        );
+        return;
+    }
+
+    let service_name = common::generate_unique_service_name("shutdown_flush");
+
+    let l1 = Arc::new(L1Backend::new(1000));
+    let l2_config = L2Config {
+        connection_string: std::env::var("REDIS_URL")
+            .unwrap_or_else(|_| "redis://127.0.0.1:6379".to_string())
+            .into(),
+        ..Default::default()
+    };
+    let l2 = Arc::new(L2Backend::new(&l2_config).await.unwrap());
+
+    // 批量大小和刷新间隔足够大，保证关闭前条目仍停留在缓冲区
+    let config = TwoLevelConfig {
+        promote_on_hit: false,
+        enable_batch_write: true,
+        batch_size: 1000,
+        batch_interval_ms: 60_000,
+        invalidation_channel: None,
+        bloom_filter: None,
+        warmup: None,
+        max_key_length: Some(1024),
+        max_value_size: Some(1024 * 1024),
+        ..Default::default()
+    };
+
+    let client = TwoLevelClient::new(
+        service_name.clone(),
+        config,
+        l1.clone(),
+        l2.clone(),
+        SerializerEnum::Json(oxcache::serialization::json::JsonSerializer::new()),
+    )
+    .await
+    .expect("Failed to create client");
+
+    let keys: Vec<String> = (0..20)
+        .map(|i| format!("{}:pending:{}", service_name, i))
+        .collect();
+    for (i, key) in keys.iter().enumerate() {
+        client.set(key, &i, Some(60)).await.unwrap();
+    }
+
+    client.shutdown().await.expect("Failed to shutdown client");
+
+    for key in &keys {
+        assert!(
+            l2.get_bytes(key).await.unwrap().is_some(),
+            "key {} should be flushed to L2 on shutdown",
+            key
+        );
+        l2.delete(key).await.unwrap();
+    }
+
+    cleanup_service(&service_name).await;
+}
+
+/// 写后回写配置，窗口足够长，写入只在关闭时的刷新中写出
+fn write_behind_config() -> TwoLevelConfig {
+    TwoLevelConfig {
+        write_behind: true,
+        batch_interval_ms: 60_000,
+        ..Default::default()
+    }
+}
+
+/// 收集涉及指定键的SET命令
+fn set_commands(fake: &FakeRedis, key: &str) -> Vec<Vec<String>> {
+    fake.log
+        .lock()
+        .unwrap()
+        .iter()
+        .filter(|args| args[0].eq_ignore_ascii_case("SET") && args[1] == key)
+        .cloned()
+        .collect()
+}
+
+#[tokio::test]
+async fn test_writes_within_window_are_coalesced() {
+    let fake = FakeRedis::start().await;
+    let client = create_client(
+        "write_behind_test",
+        write_behind_config(),
+        Arc::new(L1Backend::new(100)),
+        fake_redis_l2(&fake).await,
+    )
+    .await;
+    let key = "write_behind_test:counter";
+
+    for i in 0..100 {
+        client
+            .set_bytes(key, i.to_string().into_bytes(), Some(60))
+            .await
+            .unwrap();
+    }
+
+    // L1立即可见最新值，L2尚未写入
+    assert_eq!(client.get_bytes(key).await.unwrap(), Some(b"99".to_vec()));
+    assert!(set_commands(&fake, key).is_empty());
+
+    client.shutdown().await.unwrap();
+
+    let sets = set_commands(&fake, key);
+    assert_eq!(sets.len(), 1, "{:?}", sets);
+    assert_eq!(sets[0][2], "99");
+}
+
+#[tokio::test]
+async fn test_delete_discards_pending_write() {
+    let fake = FakeRedis::start().await;
+    let client = create_client(
+        "write_behind_delete_test",
+        write_behind_config(),
+        Arc::new(L1Backend::new(100)),
+        fake_redis_l2(&fake).await,
+    )
+    .await;
+    let key = "write_behind_delete_test:key";
+
+    client
+        .set_bytes(key, b"v".to_vec(), Some(60))
+        .await
+        .unwrap();
+    client.delete(key).await.unwrap();
+    client.shutdown().await.unwrap();
+
+    assert!(set_commands(&fake, key).is_empty());
+}
+
+#[tokio::test]
+async fn test_delete_many_discards_pending_writes() {
+    let fake = FakeRedis::start().await;
+    let client = create_client(
+        "write_behind_delete_many_test",
+        write_behind_config(),
+        Arc::new(L1Backend::new(100)),
+        fake_redis_l2(&fake).await,
+    )
+    .await;
+    let keys = [
+        "write_behind_delete_many_test:a",
+        "write_behind_delete_many_test:b",
+    ];
+
+    for key in keys {
+        client
+            .set_bytes(key, b"v".to_vec(), Some(60))
+            .await
+            .unwrap();
+    }
+    client.delete_many(&keys).await.unwrap();
+    client.shutdown().await.unwrap();
+
+    for key in keys {
+        assert!(set_commands(&fake, key).is_empty());
+    }
+}
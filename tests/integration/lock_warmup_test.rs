@@ -4,9 +4,7 @@
 //!
 //! 锁预热功能集成测试
 
-use crate::common::{
-    cleanup_service, generate_unique_service_name, is_redis_available, setup_cache,
-};
+use crate::common::{cleanup_service, generate_unique_service_name, is_redis_available};
 use oxcache::backend::l1::L1Backend;
 use oxcache::backend::l2::L2Backend;
 use oxcache::client::two_level::TwoLevelClient;
@@ -15,6 +13,7 @@ use oxcache::config::{
 };
 use oxcache::serialization::json::JsonSerializer;
 use oxcache::serialization::SerializerEnum;
+use oxcache::CacheManager;
 use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Duration;
@@ -60,8 +59,10 @@ async fn test_distributed_lock() {
                         enable_tls: false,
                         max_key_length: 256,
                         max_value_size: 1024 * 1024 * 10,
+                        ..Default::default()
                     }),
                     two_level: Some(TwoLevelConfig::default()),
+                    ..Default::default()
                 },
             );
             map
@@ -149,6 +150,7 @@ async fn test_cache_preheating() {
         enable_tls: false,
         max_key_length: 256,
         max_value_size: 1024 * 1024 * 10,
+        ..Default::default()
     };
     let l2 = Arc::new(
         L2Backend::new(&l2_config)
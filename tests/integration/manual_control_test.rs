@@ -4,10 +4,15 @@
 //!
 //! 手动控制集成测试
 
+use common::client_test_utils::{create_client, fake_redis_l2, in_memory_l2};
+use common::fake_redis::FakeRedis;
 use oxcache::{
     backend::{l1::L1Backend, l2::L2Backend},
-    client::two_level::TwoLevelClient,
-    config::{L2Config, TwoLevelConfig},
+    client::two_level::{PrimeLayer, TwoLevelClient},
+    client::CacheOps,
+    config::{
+        BloomFilterBackend, BloomFilterConfig, InvalidationChannelConfig, L2Config, TwoLevelConfig,
+    },
     serialization::SerializerEnum,
 };
 use std::sync::Arc;
@@ -115,3 +120,218 @@ async fn test_manual_control_api() {
     let _ = l2.delete("manual_key").await;
     common::cleanup_service(&service_name_for_cleanup).await;
 }
+
+#[tokio::test]
+async fn test_prime_l1_only_skips_l2_but_get_finds_value() {
+    let fake = FakeRedis::start().await;
+    let client = create_client(
+        "prime_test",
+        TwoLevelConfig::default(),
+        Arc::new(L1Backend::new(100)),
+        fake_redis_l2(&fake).await,
+    )
+    .await;
+
+    let key = "prime_test:speculative";
+    client
+        .prime(key, &"hot".to_string(), Some(60), PrimeLayer::L1, false)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        client.get::<String>(key).await.unwrap(),
+        Some("hot".to_string())
+    );
+    assert!(!fake.touched(key));
+
+    client.shutdown().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_prime_both_layers_writes_l2() {
+    let fake = FakeRedis::start().await;
+    let client = create_client(
+        "prime_test",
+        TwoLevelConfig::default(),
+        Arc::new(L1Backend::new(100)),
+        fake_redis_l2(&fake).await,
+    )
+    .await;
+
+    let key = "prime_test:both";
+    client
+        .prime(key, &7u32, Some(60), PrimeLayer::Both, false)
+        .await
+        .unwrap();
+
+    assert_eq!(client.get_l1_only::<u32>(key).await.unwrap(), Some(7));
+    assert!(fake.touched(key));
+
+    client.shutdown().await.unwrap();
+}
+
+/// 启用本地布隆过滤器的配置
+fn bloom_config(service: &str) -> TwoLevelConfig {
+    TwoLevelConfig {
+        bloom_filter: Some(BloomFilterConfig {
+            expected_elements: 1000,
+            false_positive_rate: 0.001,
+            auto_add_keys: true,
+            name: service.to_string(),
+            backend: BloomFilterBackend::Local,
+            mirror_local: false,
+        }),
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn test_never_inserted_key_is_definitely_absent() {
+    let client = create_client(
+        "bloom_contains_absent_test",
+        bloom_config("bloom_contains_absent_test"),
+        Arc::new(L1Backend::new(100)),
+        in_memory_l2(),
+    )
+    .await;
+    let key = "bloom_contains_absent_test:user:1";
+
+    assert!(!client.probably_contains(key).await);
+    assert!(!client.definitely_contains(key).await.unwrap());
+
+    client.shutdown().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_inserted_key_is_confirmed_by_l1_then_l2() {
+    let l1 = Arc::new(L1Backend::new(100));
+    let client = create_client(
+        "bloom_contains_present_test",
+        bloom_config("bloom_contains_present_test"),
+        l1.clone(),
+        in_memory_l2(),
+    )
+    .await;
+    let key = "bloom_contains_present_test:user:1";
+
+    client
+        .set_bytes(key, b"seen".to_vec(), Some(60))
+        .await
+        .unwrap();
+    assert!(client.probably_contains(key).await);
+    assert!(client.definitely_contains(key).await.unwrap());
+
+    // L1为空时由L2确认
+    l1.clear().unwrap();
+    assert!(client.definitely_contains(key).await.unwrap());
+
+    // 删除后过滤器仍判定可能存在，但确认结果为不存在
+    client.delete(key).await.unwrap();
+    assert!(client.probably_contains(key).await);
+    assert!(!client.definitely_contains(key).await.unwrap());
+
+    client.shutdown().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_take_returns_value_and_removes_it() {
+    let l1 = Arc::new(L1Backend::new(100));
+    let l2 = in_memory_l2();
+    let client = create_client(
+        "take_test",
+        TwoLevelConfig::default(),
+        l1.clone(),
+        l2.clone(),
+    )
+    .await;
+    let key = "take_test:token";
+
+    client.set(key, &"one-shot", Some(60)).await.unwrap();
+    assert!(l1.get_bytes(key).await.unwrap().is_some());
+
+    assert_eq!(
+        client.take::<String>(key).await.unwrap(),
+        Some("one-shot".to_string())
+    );
+    // 值、版本键与L1副本都被删除
+    assert!(l1.get_bytes(key).await.unwrap().is_none());
+    assert!(l2.get_bytes(key).await.unwrap().is_none());
+    assert!(!l2.exists("take_test:token:version").await.unwrap());
+    assert_eq!(client.get::<String>(key).await.unwrap(), None);
+
+    // 再次取走时已不存在
+    assert_eq!(client.take::<String>(key).await.unwrap(), None);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_concurrent_take_yields_single_value() {
+    let l2 = in_memory_l2();
+    let client = Arc::new(
+        create_client(
+            "take_concurrent_test",
+            TwoLevelConfig::default(),
+            Arc::new(L1Backend::new(100)),
+            l2,
+        )
+        .await,
+    );
+
+    for round in 0..50u64 {
+        let key = format!("take_concurrent_test:token{}", round);
+        client.set(&key, &round, Some(60)).await.unwrap();
+
+        let (first, second) = tokio::join!(
+            {
+                let client = client.clone();
+                let key = key.clone();
+                tokio::spawn(async move { client.take::<u64>(&key).await.unwrap() })
+            },
+            {
+                let client = client.clone();
+                let key = key.clone();
+                tokio::spawn(async move { client.take::<u64>(&key).await.unwrap() })
+            }
+        );
+        let mut results = vec![first.unwrap(), second.unwrap()];
+        results.sort();
+        assert_eq!(results, vec![None, Some(round)]);
+    }
+}
+
+#[tokio::test]
+async fn test_take_publishes_on_configured_channel_and_mirrors_delete() {
+    let redis = FakeRedis::start().await;
+    let secondary = in_memory_l2();
+    let client = create_client(
+        "take_publish_test",
+        TwoLevelConfig {
+            invalidation_channel: Some(InvalidationChannelConfig::Custom(
+                "take_test:channel".to_string(),
+            )),
+            ..Default::default()
+        },
+        Arc::new(L1Backend::new(100)),
+        fake_redis_l2(&redis).await,
+    )
+    .await
+    .with_secondary(secondary.clone());
+    let key = "take_publish_test:token";
+    secondary
+        .set_bytes(key, br#""one-shot""#.to_vec(), Some(60))
+        .await
+        .unwrap();
+
+    client.take::<String>(key).await.unwrap();
+
+    // 失效通知只发布到配置的频道，次级L2中的副本同时被删除
+    let published: Vec<String> = redis
+        .log
+        .lock()
+        .unwrap()
+        .iter()
+        .filter(|args| args[0].eq_ignore_ascii_case("PUBLISH") && args[2] == key)
+        .map(|args| args[1].clone())
+        .collect();
+    assert_eq!(published, vec!["take_test:channel".to_string()]);
+    assert!(secondary.get_bytes(key).await.unwrap().is_none());
+}
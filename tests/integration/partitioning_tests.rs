@@ -517,6 +517,8 @@ mod sqlite_manager_tests {
             precreate_months: 3,
             retention_months: Some(12),
             table_prefix: "partitioned_".to_string(),
+            sqlite_wal_mode: None,
+            sqlite_busy_timeout_ms: None,
         };
 
         let connection_string = format!("sqlite:{}", db_path);
@@ -582,6 +584,8 @@ mod sqlite_manager_tests {
             precreate_months: 3,
             retention_months: Some(12),
             table_prefix: "partitioned_".to_string(),
+            sqlite_wal_mode: None,
+            sqlite_busy_timeout_ms: None,
         };
 
         let connection_string = format!("sqlite:{}", db_path);
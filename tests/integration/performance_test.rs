@@ -61,6 +61,7 @@ async fn test_backfill_latency() {
                         enable_tls: false,
                         max_key_length: 256,
                         max_value_size: 1024 * 1024 * 10,
+                        ..Default::default()
                     }),
                     two_level: Some(TwoLevelConfig {
                         invalidation_channel: None,
@@ -72,7 +73,9 @@ async fn test_backfill_latency() {
                         warmup: None,
                         max_key_length: Some(1024),
                         max_value_size: Some(1024 * 1024),
+                        ..Default::default()
                     }),
+                    ..Default::default()
                 },
             );
             map
@@ -168,8 +171,10 @@ async fn test_redis_outage_resilience() {
                         enable_tls: false,
                         max_key_length: 256,
                         max_value_size: 1024 * 1024 * 10,
+                        ..Default::default()
                     }),
                     two_level: Some(Default::default()),
+                    ..Default::default()
                 },
             );
             map
@@ -315,6 +315,8 @@ async fn test_redis_sentinel_config() {
                 std::env::var("REDIS_SENTINEL_URL_2")
                     .unwrap_or_else(|_| "redis://127.0.0.1:26380".to_string()),
             ],
+            sentinel_password: None,
+            master_discovery_timeout_ms: None,
         }),
         ..Default::default()
     };
@@ -546,6 +548,9 @@ async fn test_real_redis_keys() {
                 .await
                 .expect("KEYS失败")
         }
+        L2Backend::Sharded { .. } | L2Backend::InMemory { .. } => {
+            backend.scan_keys(pattern).await.expect("SCAN失败")
+        }
     };
     assert!(keys.len() >= 3, "应找到至少3个键，实际找到: {}", keys.len());
     println!("✓ KEYS功能正常，找到 {} 个键", keys.len());
@@ -801,6 +806,9 @@ async fn test_concurrent_redis_operations() {
                 .await
                 .expect("KEYS失败")
         }
+        L2Backend::Sharded { .. } | L2Backend::InMemory { .. } => {
+            backend_ref.scan_keys(pattern).await.expect("SCAN失败")
+        }
     };
     assert!(
         keys.len() >= 50,
@@ -873,8 +881,10 @@ async fn test_cluster_basic_operations() {
                         default_ttl: None,
                         max_key_length: 256,
                         max_value_size: 1024 * 1024 * 10,
+                        ..Default::default()
                     }),
                     two_level: Some(TwoLevelConfig::default()),
+                    ..Default::default()
                 },
             );
             map
@@ -903,6 +913,72 @@ async fn test_cluster_basic_operations() {
     println!("Cluster basic operations test passed!");
 }
 
+#[tokio::test]
+async fn test_cluster_get_many_bytes_spans_slots() {
+    setup_logging();
+
+    if env::var("ENABLE_CLUSTER_TEST").is_err() {
+        println!("Skipping test_cluster_get_many_bytes_spans_slots (ENABLE_CLUSTER_TEST not set)");
+        return;
+    }
+
+    let cluster_urls = vec![
+        "redis://127.0.0.1:7000",
+        "redis://127.0.0.1:7001",
+        "redis://127.0.0.1:7002",
+    ];
+    if !wait_for_redis_cluster(&cluster_urls).await {
+        panic!("Failed to wait for Redis Cluster");
+    }
+
+    let backend = L2Backend::new(&L2Config {
+        mode: RedisMode::Cluster,
+        connection_string: "redis://127.0.0.1:7000".to_string().into(),
+        cluster: Some(ClusterConfig {
+            nodes: cluster_urls.iter().map(|url| url.to_string()).collect(),
+        }),
+        ..Default::default()
+    })
+    .await
+    .expect("集群连接失败");
+
+    let prefix = generate_unique_service_name("cluster_get_many");
+    let keys: Vec<String> = (0..50).map(|i| format!("{}:{}", prefix, i)).collect();
+    let slots: std::collections::HashSet<u16> = keys
+        .iter()
+        .map(|key| redis::cluster_routing::get_slot(key.as_bytes()))
+        .collect();
+    assert!(slots.len() > 1, "测试键应分布在多个槽位");
+
+    // 偶数下标的键写入，奇数下标的键保持不存在
+    let items = keys
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| i % 2 == 0)
+        .map(|(i, key)| (key.clone(), i.to_string().into_bytes(), Some(60)))
+        .collect();
+    backend
+        .pipeline_set_batch(items)
+        .await
+        .expect("批量写入失败");
+
+    let key_refs: Vec<&str> = keys.iter().map(String::as_str).collect();
+    let values = backend
+        .get_many_bytes(&key_refs)
+        .await
+        .expect("批量读取失败");
+    assert_eq!(values.len(), keys.len());
+    for (i, value) in values.iter().enumerate() {
+        let expected = (i % 2 == 0).then(|| i.to_string().into_bytes());
+        assert_eq!(value, &expected, "key {}", keys[i]);
+    }
+
+    backend
+        .pipeline_del_batch(keys.clone())
+        .await
+        .expect("批量删除失败");
+}
+
 #[tokio::test]
 async fn test_cluster_data_distribution() {
     setup_logging();
@@ -963,8 +1039,10 @@ async fn test_cluster_data_distribution() {
                         default_ttl: None,
                         max_key_length: 256,
                         max_value_size: 1024 * 1024 * 10,
+                        ..Default::default()
                     }),
                     two_level: Some(TwoLevelConfig::default()),
+                    ..Default::default()
                 },
             );
             map
@@ -1059,8 +1137,10 @@ async fn test_cluster_distributed_lock() {
                         default_ttl: None,
                         max_key_length: 256,
                         max_value_size: 1024 * 1024 * 10,
+                        ..Default::default()
                     }),
                     two_level: Some(TwoLevelConfig::default()),
+                    ..Default::default()
                 },
             );
             map
@@ -1155,13 +1235,17 @@ async fn test_sentinel_basic_operations() {
                                 "127.0.0.1:26380".to_string(),
                                 "127.0.0.1:26381".to_string(),
                             ],
+                            sentinel_password: None,
+                            master_discovery_timeout_ms: None,
                         }),
                         cluster: None,
                         default_ttl: None,
                         max_key_length: 256,
                         max_value_size: 1024 * 1024 * 10,
+                        ..Default::default()
                     }),
                     two_level: Some(TwoLevelConfig::default()),
+                    ..Default::default()
                 },
             );
             map
@@ -1234,13 +1318,17 @@ async fn test_sentinel_failover() {
                                 "127.0.0.1:26380".to_string(),
                                 "127.0.0.1:26381".to_string(),
                             ],
+                            sentinel_password: None,
+                            master_discovery_timeout_ms: None,
                         }),
                         cluster: None,
                         default_ttl: None,
                         max_key_length: 256,
                         max_value_size: 1024 * 1024 * 10,
+                        ..Default::default()
                     }),
                     two_level: Some(TwoLevelConfig::default()),
+                    ..Default::default()
                 },
             );
             map
@@ -1311,13 +1399,17 @@ async fn test_sentinel_distributed_lock() {
                                 "127.0.0.1:26380".to_string(),
                                 "127.0.0.1:26381".to_string(),
                             ],
+                            sentinel_password: None,
+                            master_discovery_timeout_ms: None,
                         }),
                         cluster: None,
                         default_ttl: None,
                         max_key_length: 256,
                         max_value_size: 1024 * 1024 * 10,
+                        ..Default::default()
                     }),
                     two_level: Some(TwoLevelConfig::default()),
+                    ..Default::default()
                 },
             );
             map
@@ -1367,3 +1459,641 @@ async fn test_sentinel_distributed_lock() {
 
     println!("Sentinel distributed lock test passed!");
 }
+
+#[tokio::test]
+async fn test_l2_client_reads_cbor_written_bytes() {
+    use oxcache::client::l2::L2Client;
+    use oxcache::serialization::{CborSerializer, SerializerEnum};
+    use serde::{Deserialize, Serialize};
+
+    println!("测试L2Client读取外部写入的CBOR数据...");
+
+    if !is_redis_available().await {
+        println!("跳过测试: Redis不可用");
+        return;
+    }
+
+    #[derive(Serialize, Deserialize, PartialEq, Debug)]
+    struct Profile {
+        id: u64,
+        name: String,
+    }
+
+    let config = create_standalone_config();
+    let backend = Arc::new(L2Backend::new(&config).await.unwrap());
+
+    let test_key = "oxcache:test:cbor:profile";
+    let profile = Profile {
+        id: 42,
+        name: "gopher".to_string(),
+    };
+
+    // 模拟非Rust服务：直接将CBOR编码的字节写入L2
+    let mut bytes = Vec::new();
+    ciborium::into_writer(&profile, &mut bytes).unwrap();
+    backend
+        .set_bytes(test_key, bytes, Some(60))
+        .await
+        .expect("写入CBOR字节失败");
+
+    let client = L2Client::new(
+        generate_unique_service_name("cbor"),
+        backend.clone(),
+        SerializerEnum::Cbor(CborSerializer::new()),
+    )
+    .await
+    .unwrap();
+
+    let retrieved: Option<Profile> = client.get(test_key).await.expect("读取CBOR值失败");
+    assert_eq!(retrieved, Some(profile));
+
+    backend.delete(test_key).await.unwrap();
+    println!("✓ CBOR互通读取测试通过");
+}
+
+#[tokio::test]
+async fn test_two_level_client_size_metrics() {
+    use oxcache::backend::l1::L1Backend;
+    use oxcache::client::two_level::TwoLevelClient;
+    use oxcache::metrics::GLOBAL_METRICS;
+    use oxcache::serialization::{JsonSerializer, SerializerEnum};
+    use oxcache::CacheOps;
+
+    if !is_redis_available().await {
+        println!("跳过测试: Redis不可用");
+        return;
+    }
+
+    let service_name = generate_unique_service_name("size_metrics");
+    let l1 = Arc::new(L1Backend::new(1000));
+    let l2 = Arc::new(L2Backend::new(&create_standalone_config()).await.unwrap());
+    let client = TwoLevelClient::new(
+        service_name.clone(),
+        TwoLevelConfig::default(),
+        l1,
+        l2,
+        SerializerEnum::Json(JsonSerializer::new()),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(client.l1_len().await, 0);
+    for i in 0..3 {
+        client
+            .set(&format!("{}:key:{}", service_name, i), &i, Some(60))
+            .await
+            .unwrap();
+    }
+    assert_eq!(client.l1_len().await, 3);
+    assert!(client.l1_weighted_size().await > 0);
+    assert!(client.l2_dbsize().await.unwrap() >= 3);
+    assert_eq!(
+        GLOBAL_METRICS
+            .l1_entries
+            .get(&service_name)
+            .map(|v| *v.value()),
+        Some(3)
+    );
+
+    client.clear_l1().await.unwrap();
+    assert_eq!(client.l1_len().await, 0);
+
+    client.clear_l2().await.unwrap();
+    client.shutdown().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_l2_persistent_set_has_no_expiry() {
+    use oxcache::backend::PERSISTENT_TTL;
+
+    if !is_redis_available().await {
+        println!("跳过测试: Redis不可用");
+        return;
+    }
+
+    let service_name = generate_unique_service_name("persistent");
+    let backend = L2Backend::new(&create_standalone_config()).await.unwrap();
+    let persistent_key = format!("{}:persistent", service_name);
+    let short_key = format!("{}:short", service_name);
+
+    // 先以短TTL写入，再持久化覆盖，版本键的过期时间也应被清除
+    backend
+        .set_bytes(&persistent_key, b"v0".to_vec(), Some(1))
+        .await
+        .unwrap();
+    backend
+        .set_bytes(&persistent_key, b"v1".to_vec(), Some(PERSISTENT_TTL))
+        .await
+        .unwrap();
+    backend
+        .set_bytes(&short_key, b"v2".to_vec(), Some(1))
+        .await
+        .unwrap();
+
+    tokio::time::sleep(std::time::Duration::from_millis(1500)).await;
+
+    assert!(backend.get_bytes(&short_key).await.unwrap().is_none());
+    assert_eq!(
+        backend.get_with_version(&persistent_key).await.unwrap(),
+        Some((b"v1".to_vec(), 2))
+    );
+    assert_eq!(backend.ttl(&persistent_key).await.unwrap(), None);
+    assert_eq!(
+        backend
+            .ttl(&format!("{}:version", persistent_key))
+            .await
+            .unwrap(),
+        None
+    );
+
+    backend.delete(&persistent_key).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_l2_info_and_memory_usage() {
+    if !is_redis_available().await {
+        println!("跳过测试: Redis不可用");
+        return;
+    }
+
+    let service_name = generate_unique_service_name("info");
+    let backend = L2Backend::new(&create_standalone_config()).await.unwrap();
+
+    let info = backend.info(None).await.unwrap();
+    assert!(info.contains_key("redis_version"));
+
+    let memory = backend.info(Some("memory")).await.unwrap();
+    assert!(memory.contains_key("used_memory"));
+
+    let key = format!("{}:sized", service_name);
+    assert_eq!(backend.memory_usage(&key).await.unwrap(), 0);
+    backend
+        .set_bytes(&key, vec![0u8; 1024], Some(60))
+        .await
+        .unwrap();
+    assert!(backend.memory_usage(&key).await.unwrap() >= 1024);
+
+    backend.delete(&key).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_l2_clear_is_scoped_to_service_prefix() {
+    if !is_redis_available().await {
+        println!("跳过测试: Redis不可用");
+        return;
+    }
+
+    let target = generate_unique_service_name("clear_target");
+    let other = generate_unique_service_name("clear_other");
+    let backend = L2Backend::new(&create_standalone_config()).await.unwrap();
+
+    for i in 0..20 {
+        backend
+            .set_bytes(&format!("{}:k{}", target, i), b"v".to_vec(), Some(60))
+            .await
+            .unwrap();
+        backend
+            .set_bytes(&format!("{}:k{}", other, i), b"v".to_vec(), Some(60))
+            .await
+            .unwrap();
+    }
+
+    backend.clear(&target).await.unwrap();
+
+    for i in 0..20 {
+        assert!(!backend.exists(&format!("{}:k{}", target, i)).await.unwrap());
+        assert!(backend.exists(&format!("{}:k{}", other, i)).await.unwrap());
+    }
+
+    backend.clear(&other).await.unwrap();
+    assert!(!backend.exists(&format!("{}:k0", other)).await.unwrap());
+}
+
+#[tokio::test]
+async fn test_two_level_client_millisecond_ttl() {
+    use oxcache::backend::l1::L1Backend;
+    use oxcache::client::two_level::TwoLevelClient;
+    use oxcache::serialization::{JsonSerializer, SerializerEnum};
+    use oxcache::CacheOps;
+    use std::time::Duration;
+
+    if !is_redis_available().await {
+        println!("跳过测试: Redis不可用");
+        return;
+    }
+
+    let service_name = generate_unique_service_name("pttl_test");
+    let l2 = Arc::new(L2Backend::new(&create_standalone_config()).await.unwrap());
+    let client = TwoLevelClient::new(
+        service_name.clone(),
+        TwoLevelConfig::default(),
+        Arc::new(L1Backend::new(1000)),
+        l2.clone(),
+        SerializerEnum::Json(JsonSerializer::new()),
+    )
+    .await
+    .unwrap();
+
+    let key = format!("{}:token", service_name);
+    client
+        .set_bytes_ms(&key, b"short".to_vec(), Duration::from_millis(500))
+        .await
+        .unwrap();
+
+    // TTL命令会把亚秒级剩余时间舍入为0，PTTL保留毫秒精度
+    let pttl = client.pttl(&key).await.unwrap().unwrap();
+    assert!(pttl > 0 && pttl < 1000);
+    assert_eq!(
+        client.get_bytes(&key).await.unwrap(),
+        Some(b"short".to_vec())
+    );
+
+    tokio::time::sleep(Duration::from_millis(600)).await;
+    assert_eq!(client.pttl(&key).await.unwrap(), None);
+    assert!(!l2.exists(&key).await.unwrap());
+    assert_eq!(client.get_bytes(&key).await.unwrap(), None);
+
+    client.shutdown().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_two_level_client_touch_refreshes_ttl() {
+    use oxcache::backend::l1::L1Backend;
+    use oxcache::client::two_level::TwoLevelClient;
+    use oxcache::serialization::{JsonSerializer, SerializerEnum};
+    use oxcache::CacheOps;
+    use std::time::Duration;
+
+    if !is_redis_available().await {
+        println!("跳过测试: Redis不可用");
+        return;
+    }
+
+    let service_name = generate_unique_service_name("touch_test");
+    let l2 = Arc::new(L2Backend::new(&create_standalone_config()).await.unwrap());
+    let client = TwoLevelClient::new(
+        service_name.clone(),
+        TwoLevelConfig::default(),
+        Arc::new(L1Backend::new(1000)),
+        l2.clone(),
+        SerializerEnum::Json(JsonSerializer::new()),
+    )
+    .await
+    .unwrap();
+
+    let key = format!("{}:session", service_name);
+    client
+        .set_bytes(&key, b"session".to_vec(), Some(10))
+        .await
+        .unwrap();
+    tokio::time::sleep(Duration::from_secs(2)).await;
+    assert!(l2.ttl(&key).await.unwrap().unwrap() <= 8);
+
+    assert!(client.touch(&key, 100).await.unwrap());
+    assert!(l2.ttl(&key).await.unwrap().unwrap() > 90);
+    if l2.versioning_enabled() {
+        assert!(l2.ttl(&format!("{}:version", key)).await.unwrap().unwrap() > 90);
+    }
+    assert_eq!(
+        client.get_bytes(&key).await.unwrap(),
+        Some(b"session".to_vec())
+    );
+
+    assert!(!client
+        .touch(&format!("{}:missing", service_name), 100)
+        .await
+        .unwrap());
+
+    client.shutdown().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_two_level_client_get_l2_fresh_refreshes_l1() {
+    use oxcache::backend::l1::L1Backend;
+    use oxcache::client::two_level::TwoLevelClient;
+    use oxcache::serialization::{JsonSerializer, SerializerEnum};
+
+    if !is_redis_available().await {
+        println!("跳过测试: Redis不可用");
+        return;
+    }
+
+    let service_name = generate_unique_service_name("fresh_test");
+    let l2 = Arc::new(L2Backend::new(&create_standalone_config()).await.unwrap());
+    let client = TwoLevelClient::new(
+        service_name.clone(),
+        TwoLevelConfig::default(),
+        Arc::new(L1Backend::new(1000)),
+        l2,
+        SerializerEnum::Json(JsonSerializer::new()),
+    )
+    .await
+    .unwrap();
+
+    let key = format!("{}:config", service_name);
+    client.set_l1_only(&key, &"stale", Some(60)).await.unwrap();
+    client.set_l2_only(&key, &"fresh", Some(60)).await.unwrap();
+    assert_eq!(
+        client.get_l1_only::<String>(&key).await.unwrap(),
+        Some("stale".to_string())
+    );
+
+    assert_eq!(
+        client.get_l2_fresh::<String>(&key).await.unwrap(),
+        Some("fresh".to_string())
+    );
+    assert_eq!(
+        client.get_l1_only::<String>(&key).await.unwrap(),
+        Some("fresh".to_string())
+    );
+
+    // L2中不存在的键会清除L1中残留的副本
+    let missing = format!("{}:missing", service_name);
+    client
+        .set_l1_only(&missing, &"stale", Some(60))
+        .await
+        .unwrap();
+    assert_eq!(client.get_l2_fresh::<String>(&missing).await.unwrap(), None);
+    assert_eq!(client.get_l1_only::<String>(&missing).await.unwrap(), None);
+
+    client.shutdown().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_two_level_client_get_set_returns_previous_value() {
+    use oxcache::backend::l1::L1Backend;
+    use oxcache::client::two_level::TwoLevelClient;
+    use oxcache::serialization::{JsonSerializer, SerializerEnum};
+
+    if !is_redis_available().await {
+        println!("跳过测试: Redis不可用");
+        return;
+    }
+
+    let service_name = generate_unique_service_name("getset_test");
+    let l2 = Arc::new(L2Backend::new(&create_standalone_config()).await.unwrap());
+    let client = TwoLevelClient::new(
+        service_name.clone(),
+        TwoLevelConfig::default(),
+        Arc::new(L1Backend::new(1000)),
+        l2.clone(),
+        SerializerEnum::Json(JsonSerializer::new()),
+    )
+    .await
+    .unwrap();
+
+    let key = format!("{}:counter", service_name);
+    assert_eq!(client.get_set(&key, &1u32, Some(60)).await.unwrap(), None);
+    assert_eq!(
+        client.get_set(&key, &2u32, Some(60)).await.unwrap(),
+        Some(1)
+    );
+
+    assert_eq!(client.get_l1_only::<u32>(&key).await.unwrap(), Some(2));
+    assert_eq!(client.get_l2_only::<u32>(&key).await.unwrap(), Some(2));
+    let ttl = l2.ttl(&key).await.unwrap().unwrap();
+    assert!(ttl > 0 && ttl <= 60);
+
+    client.shutdown().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_l2_compression_frames_readable_after_codec_change() {
+    use oxcache::config::CompressionCodec;
+
+    if !is_redis_available().await {
+        println!("跳过测试: Redis不可用");
+        return;
+    }
+
+    let service_name = generate_unique_service_name("compression_test");
+    let value = "compressible payload ".repeat(100).into_bytes();
+    let codecs = [
+        CompressionCodec::None,
+        CompressionCodec::Zstd,
+        CompressionCodec::Lz4,
+        CompressionCodec::Gzip,
+    ];
+
+    for codec in codecs {
+        let mut config = create_standalone_config();
+        config.compression = Some(codec);
+        let writer = L2Backend::new(&config).await.unwrap();
+        let key = format!("{}:{:?}", service_name, codec);
+        writer
+            .set_with_version(&key, value.clone(), Some(60))
+            .await
+            .unwrap();
+    }
+
+    // 切换默认编码后，所有编码写入的值仍可读取
+    let mut config = create_standalone_config();
+    config.compression = Some(CompressionCodec::Lz4);
+    let reader = L2Backend::new(&config).await.unwrap();
+    for codec in codecs {
+        let key = format!("{}:{:?}", service_name, codec);
+        let (read, _) = reader.get_with_version(&key).await.unwrap().unwrap();
+        assert_eq!(read, value, "codec {:?}", codec);
+        reader.delete(&key).await.unwrap();
+    }
+}
+
+#[tokio::test]
+async fn test_two_level_client_diff_layers_reports_divergence() {
+    use oxcache::backend::l1::L1Backend;
+    use oxcache::client::two_level::{LayerStatus, TwoLevelClient};
+    use oxcache::serialization::{JsonSerializer, SerializerEnum};
+
+    if !is_redis_available().await {
+        println!("跳过测试: Redis不可用");
+        return;
+    }
+
+    let service_name = generate_unique_service_name("diff_layers_test");
+    let l1 = Arc::new(L1Backend::new(1000));
+    let l2 = Arc::new(L2Backend::new(&create_standalone_config()).await.unwrap());
+    let client = TwoLevelClient::new(
+        service_name.clone(),
+        TwoLevelConfig::default(),
+        l1.clone(),
+        l2,
+        SerializerEnum::Json(JsonSerializer::new()),
+    )
+    .await
+    .unwrap();
+
+    let key = |name: &str| format!("{}:{}", service_name, name);
+    let (same, l1_only, l2_only, missing, value, version) = (
+        key("same"),
+        key("l1_only"),
+        key("l2_only"),
+        key("missing"),
+        key("value"),
+        key("version"),
+    );
+
+    client.set(&same, &1u32, Some(60)).await.unwrap();
+    client.set_l1_only(&l1_only, &1u32, Some(60)).await.unwrap();
+    client.set_l2_only(&l2_only, &1u32, Some(60)).await.unwrap();
+    client.set_l1_only(&value, &1u32, Some(60)).await.unwrap();
+    client.set_l2_only(&value, &2u32, Some(60)).await.unwrap();
+    client.set_l2_only(&version, &1u32, Some(60)).await.unwrap();
+    l1.set_with_metadata(&version, b"1".to_vec(), 60, 42)
+        .await
+        .unwrap();
+
+    let keys = [
+        same.as_str(),
+        l1_only.as_str(),
+        l2_only.as_str(),
+        missing.as_str(),
+        value.as_str(),
+        version.as_str(),
+    ];
+    let statuses: Vec<LayerStatus> = client
+        .diff_layers(&keys)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|diff| diff.status)
+        .collect();
+    assert_eq!(statuses[0], LayerStatus::Consistent);
+    assert_eq!(statuses[1], LayerStatus::L1Only);
+    assert_eq!(statuses[2], LayerStatus::L2Only);
+    assert_eq!(statuses[3], LayerStatus::Missing);
+    assert_eq!(statuses[4], LayerStatus::ValueMismatch);
+    assert!(matches!(
+        statuses[5],
+        LayerStatus::VersionMismatch { l1_version: 42, .. }
+    ));
+
+    client.shutdown().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_l2_backend_authenticates_with_acl_user() {
+    use secrecy::SecretString;
+
+    if !is_redis_available().await {
+        println!("跳过测试: Redis不可用");
+        return;
+    }
+
+    let admin = redis::Client::open("redis://127.0.0.1:6379").unwrap();
+    let mut admin = admin.get_multiplexed_async_connection().await.unwrap();
+    let username = generate_unique_service_name("acl_user");
+    let created: redis::RedisResult<()> = redis::cmd("ACL")
+        .arg("SETUSER")
+        .arg(&username)
+        .arg("on")
+        .arg(">acl-secret")
+        .arg("~*")
+        .arg("+@all")
+        .query_async(&mut admin)
+        .await;
+    if created.is_err() {
+        println!("跳过测试: Redis不支持ACL");
+        return;
+    }
+
+    let mut config = create_standalone_config();
+    config.username = Some(username.clone());
+    config.password = Some(SecretString::from("acl-secret".to_string()));
+    let l2 = L2Backend::new(&config).await.unwrap();
+    let key = format!("{}:key", username);
+    l2.set_bytes(&key, b"v".to_vec(), Some(60)).await.unwrap();
+    assert_eq!(l2.get_bytes(&key).await.unwrap(), Some(b"v".to_vec()));
+    l2.delete(&key).await.unwrap();
+
+    // 错误的密码无法通过认证
+    config.password = Some(SecretString::from("wrong".to_string()));
+    assert!(L2Backend::new(&config).await.is_err());
+
+    let _: () = redis::cmd("ACL")
+        .arg("DELUSER")
+        .arg(&username)
+        .query_async(&mut admin)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_redis_bloom_filter_shared_between_clients() {
+    use oxcache::backend::l1::L1Backend;
+    use oxcache::bloom_filter::{BloomFilterOptions, RedisBloomFilter};
+    use oxcache::client::two_level::TwoLevelClient;
+    use oxcache::config::{BloomFilterBackend, BloomFilterConfig};
+    use oxcache::serialization::{JsonSerializer, SerializerEnum};
+    use oxcache::CacheOps;
+
+    if !is_redis_available().await {
+        println!("跳过测试: Redis不可用");
+        return;
+    }
+
+    let service_name = generate_unique_service_name("bloom_test");
+    let options = BloomFilterOptions::new(service_name.clone(), 1000, 0.01);
+    let first = RedisBloomFilter::new(
+        options.clone(),
+        Arc::new(L2Backend::new(&create_standalone_config()).await.unwrap()),
+        false,
+    )
+    .await
+    .unwrap();
+    let second = RedisBloomFilter::new(
+        options,
+        Arc::new(L2Backend::new(&create_standalone_config()).await.unwrap()),
+        true,
+    )
+    .await
+    .unwrap();
+    assert_eq!(first.key(), second.key());
+
+    assert!(!second.contains(b"user:1").await.unwrap());
+    first.add(b"user:1").await.unwrap();
+    assert!(second.contains(b"user:1").await.unwrap());
+    assert!(!second.contains(b"user:2").await.unwrap());
+
+    // 双层客户端通过共享过滤器放行其他实例写入的键
+    let config = TwoLevelConfig {
+        bloom_filter: Some(BloomFilterConfig {
+            name: format!("{}_clients", service_name),
+            backend: BloomFilterBackend::Redis,
+            mirror_local: true,
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let mut clients = Vec::new();
+    for _ in 0..2 {
+        clients.push(
+            TwoLevelClient::new(
+                service_name.clone(),
+                config.clone(),
+                Arc::new(L1Backend::new(1000)),
+                Arc::new(L2Backend::new(&create_standalone_config()).await.unwrap()),
+                SerializerEnum::Json(JsonSerializer::new()),
+            )
+            .await
+            .unwrap(),
+        );
+    }
+    let key = format!("{}:shared", service_name);
+    clients[0]
+        .set_bytes(&key, b"value".to_vec(), Some(60))
+        .await
+        .unwrap();
+    assert_eq!(
+        clients[1].get_bytes(&key).await.unwrap(),
+        Some(b"value".to_vec())
+    );
+
+    let l2 = L2Backend::new(&create_standalone_config()).await.unwrap();
+    l2.delete(first.key()).await.unwrap();
+    l2.delete(&format!("oxcache:bloom:{}_clients", service_name))
+        .await
+        .unwrap();
+    for client in clients {
+        client.shutdown().await.unwrap();
+    }
+}
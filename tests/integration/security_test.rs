@@ -4,12 +4,20 @@
 //!
 //! 安全性集成测试
 
+use common::client_test_utils::{create_client, in_memory_l2};
+use oxcache::backend::l1::L1Backend;
 use oxcache::backend::l2::L2Backend;
-use oxcache::config::{L2Config, RedisMode};
+use oxcache::client::CacheOps;
+use oxcache::config::{KeyMode, L2Config, RedisMode, TwoLevelConfig};
+use oxcache::error::CacheError;
+use oxcache::utils::sanitize_cache_key;
+use std::sync::Arc;
 
 #[path = "../common/mod.rs"]
 mod common;
 
+const URL_KEY: &str = "key_mode_test:https://example.com/search?q=rust&page=2";
+
 #[tokio::test]
 async fn test_redis_tls_config_parsing() {
     common::setup_logging();
@@ -55,3 +63,148 @@ async fn test_redis_tls_config_parsing() {
         }
     }
 }
+
+#[tokio::test]
+async fn test_strict_mode_rejects_url_key() {
+    let client = create_client(
+        "key_mode_test",
+        TwoLevelConfig::default(),
+        Arc::new(L1Backend::new(100)),
+        in_memory_l2(),
+    )
+    .await
+    .with_key_mode(KeyMode::Strict);
+
+    let result = client.set_bytes(URL_KEY, b"v".to_vec(), Some(60)).await;
+    assert!(matches!(result, Err(CacheError::InvalidInput(_))));
+    assert!(matches!(
+        client.get_bytes(URL_KEY).await,
+        Err(CacheError::InvalidInput(_))
+    ));
+
+    client.shutdown().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_hash_invalid_mode_hashes_url_key_stably() {
+    let hashed = sanitize_cache_key(URL_KEY, KeyMode::HashInvalid, 256).unwrap();
+    let again = sanitize_cache_key(URL_KEY, KeyMode::HashInvalid, 256).unwrap();
+    assert_eq!(hashed, again);
+    assert_ne!(hashed, URL_KEY);
+    // 哈希后的键保留第一个非法字符之前的命名空间，且满足严格模式的字符要求
+    assert!(hashed.starts_with("key_mode_test:https:"));
+    assert!(sanitize_cache_key(&hashed, KeyMode::Strict, 256).is_ok());
+
+    // 合法键保持不变
+    assert_eq!(
+        sanitize_cache_key("key_mode_test:plain", KeyMode::HashInvalid, 256).unwrap(),
+        "key_mode_test:plain"
+    );
+
+    let l1 = Arc::new(L1Backend::new(100));
+    let client = create_client(
+        "key_mode_test",
+        TwoLevelConfig::default(),
+        l1.clone(),
+        in_memory_l2(),
+    )
+    .await
+    .with_key_mode(KeyMode::HashInvalid);
+    client
+        .set_bytes(URL_KEY, b"v".to_vec(), Some(60))
+        .await
+        .unwrap();
+    assert_eq!(l1.get_bytes(&hashed).await.unwrap(), Some(b"v".to_vec()));
+    assert_eq!(
+        client.get_bytes(URL_KEY).await.unwrap(),
+        Some(b"v".to_vec())
+    );
+
+    client.shutdown().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_permissive_mode_passes_key_through() {
+    let l1 = Arc::new(L1Backend::new(100));
+    let client = create_client(
+        "key_mode_test",
+        TwoLevelConfig::default(),
+        l1.clone(),
+        in_memory_l2(),
+    )
+    .await
+    .with_key_mode(KeyMode::Permissive);
+
+    client
+        .set_bytes(URL_KEY, b"v".to_vec(), Some(60))
+        .await
+        .unwrap();
+    assert_eq!(l1.get_bytes(URL_KEY).await.unwrap(), Some(b"v".to_vec()));
+    assert_eq!(
+        client.get_bytes(URL_KEY).await.unwrap(),
+        Some(b"v".to_vec())
+    );
+
+    // 宽松模式仍然限制键长度
+    let long_key = "x".repeat(300);
+    assert!(matches!(
+        client.get_bytes(&long_key).await,
+        Err(CacheError::InvalidInput(_))
+    ));
+
+    client.shutdown().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_hash_invalid_mode_applies_to_layer_ops() {
+    let hashed = sanitize_cache_key(URL_KEY, KeyMode::HashInvalid, 256).unwrap();
+    let l1 = Arc::new(L1Backend::new(100));
+    let l2 = in_memory_l2();
+    let client = create_client(
+        "key_mode_test",
+        TwoLevelConfig::default(),
+        l1.clone(),
+        l2.clone(),
+    )
+    .await
+    .with_key_mode(KeyMode::HashInvalid);
+
+    client.set_l1_only(URL_KEY, &"l1", Some(60)).await.unwrap();
+    client.set_l2_only(URL_KEY, &"l2", Some(60)).await.unwrap();
+    assert!(l1.get_bytes(&hashed).await.unwrap().is_some());
+    assert!(l2.get_bytes(&hashed).await.unwrap().is_some());
+    assert_eq!(
+        client.get_l1_only::<String>(URL_KEY).await.unwrap(),
+        Some("l1".to_string())
+    );
+    assert_eq!(
+        client.get_l2_only::<String>(URL_KEY).await.unwrap(),
+        Some("l2".to_string())
+    );
+    assert!(client.pttl(URL_KEY).await.unwrap().is_some());
+    assert!(client.l2_memory_usage(URL_KEY).await.unwrap() > 0);
+
+    // 严格模式下这些操作同样拒绝非法键
+    let strict = create_client(
+        "key_mode_test",
+        TwoLevelConfig::default(),
+        Arc::new(L1Backend::new(100)),
+        in_memory_l2(),
+    )
+    .await
+    .with_key_mode(KeyMode::Strict);
+    assert!(matches!(
+        strict.pttl(URL_KEY).await,
+        Err(CacheError::InvalidInput(_))
+    ));
+    assert!(matches!(
+        strict.get_l1_only::<String>(URL_KEY).await,
+        Err(CacheError::InvalidInput(_))
+    ));
+    assert!(matches!(
+        CacheOps::set_l2_bytes(&strict, URL_KEY, b"v".to_vec(), Some(60)).await,
+        Err(CacheError::InvalidInput(_))
+    ));
+
+    strict.shutdown().await.unwrap();
+}
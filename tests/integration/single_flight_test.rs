@@ -63,6 +63,7 @@ async fn test_single_flight_deduplication() {
         warmup: None,
         max_key_length: Some(1024),
         max_value_size: Some(1024 * 1024),
+        ..Default::default()
     };
 
     let client = Arc::new(
@@ -214,6 +214,8 @@ mod manager_tests {
             precreate_months: 3,
             retention_months: Some(12),
             table_prefix: "partitioned_".to_string(),
+            sqlite_wal_mode: None,
+            sqlite_busy_timeout_ms: None,
         };
 
         let connection_string = format!("sqlite:{}", db_path);
@@ -279,6 +281,8 @@ mod manager_tests {
             precreate_months: 3,
             retention_months: Some(12),
             table_prefix: "partitioned_".to_string(),
+            sqlite_wal_mode: None,
+            sqlite_busy_timeout_ms: None,
         };
 
         let connection_string = format!("sqlite:{}", db_path);
@@ -315,3 +319,157 @@ mod manager_tests {
         let _ = std::fs::remove_file(db_path);
     }
 }
+
+mod stream_tests {
+    use super::*;
+    use futures::TryStreamExt;
+
+    #[tokio::test]
+    async fn test_sqlite_partitions_stream_matches_vec() -> Result<()> {
+        let temp_dir = TempDir::new().unwrap();
+        let db_path = temp_dir.path().join("stream.db");
+        File::create(&db_path).unwrap();
+        let connection_string = format!("sqlite:{}", db_path.to_str().unwrap());
+        let manager =
+            SQLitePartitionManager::new(&connection_string, PartitionConfig::default()).await?;
+
+        let table = "stream_cache";
+        for month in 1..=6 {
+            let date = Utc.with_ymd_and_hms(2024, month, 1, 0, 0, 0).unwrap();
+            manager
+                .create_partition(&PartitionInfo::new(date, table))
+                .await?;
+        }
+
+        let from_vec: Vec<String> = manager
+            .get_partitions(table)
+            .await?
+            .into_iter()
+            .map(|p| p.name)
+            .collect();
+        let from_stream: Vec<String> = manager
+            .get_partitions_stream(table)
+            .map_ok(|p| p.name)
+            .try_collect()
+            .await?;
+
+        assert!(from_vec.len() >= 6);
+        assert_eq!(from_stream, from_vec);
+        Ok(())
+    }
+}
+
+mod maintenance_tests {
+    use super::*;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn test_sqlite_maintenance_precreates_partitions() -> Result<()> {
+        let temp_dir = TempDir::new().unwrap();
+        let db_path = temp_dir.path().join("maintenance.db");
+        File::create(&db_path).unwrap();
+        let connection_string = format!("sqlite:{}", db_path.to_str().unwrap());
+        let config = PartitionConfig {
+            precreate_months: 2,
+            ..Default::default()
+        };
+        let manager = Arc::new(SQLitePartitionManager::new(&connection_string, config).await?);
+
+        let table = "maintained_cache";
+        let expected =
+            PartitionInfo::new(Utc::now() + chrono::Duration::days(30), table).table_name;
+        assert!(manager.get_partitions(table).await?.is_empty());
+
+        let handle = manager
+            .clone()
+            .start_maintenance(table, Duration::from_millis(50));
+
+        let mut found = false;
+        for _ in 0..40 {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            if manager
+                .get_partitions(table)
+                .await?
+                .iter()
+                .any(|p| p.table_name == expected)
+            {
+                found = true;
+                break;
+            }
+        }
+
+        assert!(handle.is_running());
+        handle.stop().await;
+        assert!(found, "future partition {} was not precreated", expected);
+        Ok(())
+    }
+}
+
+mod concurrency_tests {
+    use super::*;
+    use chrono::Datelike;
+
+    #[tokio::test]
+    async fn test_sqlite_concurrent_partition_creation_with_wal() -> Result<()> {
+        let temp_dir = TempDir::new().unwrap();
+        let db_path = temp_dir.path().join("concurrent_wal.db");
+        File::create(&db_path).unwrap();
+        let connection_string = format!("sqlite:{}", db_path.to_str().unwrap());
+
+        let mut handles = Vec::new();
+        for i in 0..8u32 {
+            let connection_string = connection_string.clone();
+            handles.push(tokio::spawn(async move {
+                let partition_config = PartitionConfig {
+                    sqlite_wal_mode: Some(true),
+                    sqlite_busy_timeout_ms: Some(10_000),
+                    ..Default::default()
+                };
+                let manager =
+                    SQLitePartitionManager::new(&connection_string, partition_config).await?;
+
+                let table = format!("wal_cache_{}", i);
+                for month in 1..=3 {
+                    let date = Utc.with_ymd_and_hms(2025, month, 1, 0, 0, 0).unwrap();
+                    let partition = PartitionInfo::new(date, &table);
+                    manager.create_partition(&partition).await?;
+                    assert_eq!(partition.start_date.month(), month);
+                }
+                Ok::<_, CacheError>(())
+            }));
+        }
+
+        for handle in handles {
+            if let Err(e) = handle.await.unwrap() {
+                assert!(
+                    !e.to_string().contains("database is locked"),
+                    "unexpected lock error: {}",
+                    e
+                );
+                return Err(e);
+            }
+        }
+
+        // 验证数据库已切换为 WAL 模式
+        let manager = SQLitePartitionManager::new(
+            &connection_string,
+            PartitionConfig {
+                sqlite_wal_mode: Some(true),
+                ..Default::default()
+            },
+        )
+        .await?;
+        for i in 0..8 {
+            let partitions = manager.get_partitions(&format!("wal_cache_{}", i)).await?;
+            let monthly = partitions
+                .iter()
+                .filter(|p| p.table_name.contains("_y2025m"))
+                .count();
+            assert_eq!(monthly, 3);
+        }
+        assert!(temp_dir.path().join("concurrent_wal.db-wal").exists());
+
+        Ok(())
+    }
+}
@@ -59,6 +59,7 @@ async fn test_two_level_cache_flow() {
                         default_ttl: None,
                         max_key_length: 256,
                         max_value_size: 1024 * 1024 * 10,
+                        ..Default::default()
                     }),
                     two_level: Some(TwoLevelConfig {
                         promote_on_hit: true,
@@ -70,7 +71,9 @@ async fn test_two_level_cache_flow() {
                         warmup: None,
                         max_key_length: Some(1024),
                         max_value_size: Some(1024 * 1024),
+                        ..Default::default()
                     }),
+                    ..Default::default()
                 },
             );
             map
@@ -0,0 +1,87 @@
+//! Copyright (c) 2025-2026, Kirky.X
+//!
+//! MIT License
+//!
+//! 失效订阅断线重连测试
+
+use common::fake_redis::FakeRedis;
+use oxcache::backend::l1::L1Backend;
+use oxcache::backend::l2::L2Backend;
+use oxcache::client::two_level::TwoLevelClient;
+use oxcache::config::{L2Config, TwoLevelConfig};
+use oxcache::metrics::GLOBAL_METRICS;
+use oxcache::serialization::{JsonSerializer, SerializerEnum};
+use secrecy::SecretString;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+mod common;
+
+const SERVICE: &str = "invalidation_reconnect_test";
+const CHANNEL: &str = "cache:invalidate:invalidation_reconnect_test";
+
+fn connected() -> Option<u8> {
+    GLOBAL_METRICS
+        .invalidation_subscriber_connected
+        .get(SERVICE)
+        .map(|v| *v.value())
+}
+
+/// 轮询直到条件成立或超时
+async fn wait_until<F, Fut>(mut condition: F) -> bool
+where
+    F: FnMut() -> Fut,
+    Fut: std::future::Future<Output = bool>,
+{
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while Instant::now() < deadline {
+        if condition().await {
+            return true;
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    false
+}
+
+/// 写入L1后反复发布失效消息，直到L1中的键被删除
+async fn invalidation_delivered(redis: &FakeRedis, l1: &L1Backend, key: &str) -> bool {
+    l1.set_bytes(key, b"stale".to_vec(), None).await.unwrap();
+    wait_until(|| async {
+        redis.publish(CHANNEL, key);
+        l1.get_bytes(key).await.unwrap().is_none()
+    })
+    .await
+}
+
+#[tokio::test]
+async fn test_subscriber_resumes_after_connection_loss() {
+    let redis = FakeRedis::start().await;
+    let l2_config = L2Config {
+        connection_string: SecretString::from(redis.url.clone()),
+        ..Default::default()
+    };
+    let l2 = Arc::new(L2Backend::new(&l2_config).await.unwrap());
+    let l1 = Arc::new(L1Backend::new(1000));
+    let client = TwoLevelClient::new(
+        SERVICE.to_string(),
+        TwoLevelConfig::default(),
+        l1.clone(),
+        l2,
+        SerializerEnum::Json(JsonSerializer::new()),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(connected(), Some(1));
+    let key = format!("{}:key", SERVICE);
+    assert!(invalidation_delivered(&redis, &l1, &key).await);
+
+    // 断开所有连接后订阅者标记为断开，随后自动重连
+    redis.disconnect_all();
+    assert!(wait_until(|| async { connected() == Some(0) }).await);
+    assert!(wait_until(|| async { connected() == Some(1) }).await);
+
+    assert!(invalidation_delivered(&redis, &l1, &key).await);
+
+    client.shutdown().await.unwrap();
+}
@@ -0,0 +1,199 @@
+//! Copyright (c) 2025-2026, Kirky.X
+//!
+//! MIT License
+//!
+//! 指标键分组与按服务关闭指标测试
+
+use common::client_test_utils::{create_client, in_memory_l2};
+use common::fake_redis::FakeRedis;
+use oxcache::backend::l1::L1Backend;
+use oxcache::backend::l2::L2Backend;
+use oxcache::client::two_level::TwoLevelClient;
+use oxcache::client::CacheOps;
+use oxcache::config::{KeyGroupExtractor, L2Config, TwoLevelConfig};
+use oxcache::metrics::{KeyGrouper, GLOBAL_METRICS};
+use oxcache::serialization::{JsonSerializer, SerializerEnum};
+use secrecy::SecretString;
+use std::sync::Arc;
+
+mod common;
+
+const SERVICE: &str = "key_group_metrics_test";
+
+fn group_count(group: &str, result: &str) -> u64 {
+    GLOBAL_METRICS
+        .snapshot()
+        .key_group_requests_total
+        .get(&format!("{}:L1:get:{}:{}", SERVICE, result, group))
+        .copied()
+        .unwrap_or(0)
+}
+
+#[test]
+fn test_key_grouper_extracts_groups() {
+    let prefix = KeyGrouper::new(&KeyGroupExtractor::Prefix {
+        delimiter: ":".to_string(),
+        segments: 2,
+    })
+    .unwrap();
+    assert_eq!(prefix.group("app:user:1"), "app:user");
+    assert_eq!(prefix.group("app:user:2:profile"), "app:user");
+    assert_eq!(prefix.group("app"), "other");
+
+    let regex = KeyGrouper::new(&KeyGroupExtractor::Regex {
+        pattern: r"^[^:]+:([a-z]+):".to_string(),
+    })
+    .unwrap();
+    assert_eq!(regex.group("app:order:42"), "order");
+    assert_eq!(regex.group("42"), "other");
+
+    assert!(KeyGrouper::new(&KeyGroupExtractor::Regex {
+        pattern: "(".to_string()
+    })
+    .is_err());
+    assert!(KeyGrouper::new(&KeyGroupExtractor::Prefix {
+        delimiter: String::new(),
+        segments: 1,
+    })
+    .is_err());
+}
+
+#[tokio::test]
+async fn test_keys_in_same_group_share_metric_bucket() {
+    let l2_config = L2Config {
+        connection_string: SecretString::from(FakeRedis::start().await.url),
+        ..Default::default()
+    };
+    let grouper = KeyGrouper::new(&KeyGroupExtractor::Prefix {
+        delimiter: ":".to_string(),
+        segments: 2,
+    })
+    .unwrap();
+    let client = TwoLevelClient::new(
+        SERVICE.to_string(),
+        TwoLevelConfig::default(),
+        Arc::new(L1Backend::new(100)),
+        Arc::new(L2Backend::new(&l2_config).await.unwrap()),
+        SerializerEnum::Json(JsonSerializer::new()),
+    )
+    .await
+    .unwrap()
+    .with_key_grouper(grouper);
+
+    let user_hits = group_count("kg:user", "hit");
+    let order_hits = group_count("kg:order", "hit");
+
+    for key in ["kg:user:1", "kg:user:2", "kg:order:1"] {
+        client
+            .set_bytes(key, b"v".to_vec(), Some(60))
+            .await
+            .unwrap();
+        assert!(client.get_bytes(key).await.unwrap().is_some());
+    }
+
+    assert_eq!(group_count("kg:user", "hit") - user_hits, 2);
+    assert_eq!(group_count("kg:order", "hit") - order_hits, 1);
+    let snapshot = GLOBAL_METRICS.snapshot();
+    assert!(!snapshot
+        .key_group_requests_total
+        .keys()
+        .any(|k| k.ends_with(":kg:user:1") || k.ends_with(":kg:order:1")));
+
+    client.shutdown().await.unwrap();
+}
+
+/// 默认TTL超过L2默认TTL的L1，无TTL写入会触发TTL偏离指标
+fn diverging_l1() -> Arc<L1Backend> {
+    Arc::new(L1Backend::new_with_default_ttl(100, Some(7200)))
+}
+
+/// 统计快照中属于该服务的指标记录数
+fn service_records(service: &str) -> usize {
+    let snapshot = GLOBAL_METRICS.snapshot();
+    let prefix = format!("{}:", service);
+    let keyed = |key: &String| key.starts_with(&prefix);
+    snapshot.requests_total.keys().filter(|k| keyed(k)).count()
+        + snapshot
+            .operation_duration
+            .keys()
+            .filter(|k| keyed(k))
+            .count()
+        + snapshot
+            .get_results_total
+            .keys()
+            .filter(|k| keyed(k))
+            .count()
+        + snapshot
+            .key_group_requests_total
+            .keys()
+            .filter(|k| keyed(k))
+            .count()
+        + usize::from(snapshot.l1_entries.contains_key(service))
+        + usize::from(snapshot.ttl_divergence_total.contains_key(service))
+}
+
+async fn exercise(client: &TwoLevelClient, service: &str) {
+    let key = format!("{}:key", service);
+    client.set_bytes(&key, b"v".to_vec(), None).await.unwrap();
+    client.get_bytes(&key).await.unwrap();
+    client
+        .get_bytes(&format!("{}:absent", service))
+        .await
+        .unwrap();
+    client.l1_len().await;
+}
+
+#[tokio::test]
+async fn test_metrics_disabled_service_records_nothing() {
+    let service = "metrics_opt_out_test";
+    let client = create_client(
+        service,
+        TwoLevelConfig::default(),
+        diverging_l1(),
+        in_memory_l2(),
+    )
+    .await
+    .with_metrics(false, false);
+
+    exercise(&client, service).await;
+    assert_eq!(service_records(service), 0);
+
+    client.shutdown().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_critical_metrics_can_be_forced_on() {
+    let service = "metrics_opt_out_critical_test";
+    let client = create_client(
+        service,
+        TwoLevelConfig::default(),
+        diverging_l1(),
+        in_memory_l2(),
+    )
+    .await
+    .with_metrics(false, true);
+
+    exercise(&client, service).await;
+    let snapshot = GLOBAL_METRICS.snapshot();
+    assert_eq!(snapshot.ttl_divergence_total.get(service), Some(&1));
+    assert_eq!(service_records(service), 1);
+
+    client.shutdown().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_metrics_enabled_by_default() {
+    let service = "metrics_opt_out_default_test";
+    let client = create_client(
+        service,
+        TwoLevelConfig::default(),
+        diverging_l1(),
+        in_memory_l2(),
+    )
+    .await;
+
+    exercise(&client, service).await;
+    assert!(service_records(service) > 1);
+
+    client.shutdown().await.unwrap();
+}
@@ -0,0 +1,91 @@
+//! Copyright (c) 2025-2026, Kirky.X
+//!
+//! MIT License
+//!
+//! L1容量自适应调优测试
+
+use oxcache::backend::l1::{L1AutoTuner, L1Backend};
+use oxcache::config::L1AutoTuneConfig;
+use oxcache::metrics::GLOBAL_METRICS;
+use oxcache::utils::clock::MockClock;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+#[tokio::test]
+async fn test_capacity_shrinks_toward_min_under_pressure() {
+    let service = "l1_auto_tune_test";
+    let config = L1AutoTuneConfig {
+        min_capacity: 100,
+        max_capacity: 1000,
+        interval_secs: 10,
+        target_hit_ratio: 0.8,
+        step_ratio: 0.25,
+    };
+    let l1 = Arc::new(L1Backend::new(config.max_capacity).with_initial_capacity(800));
+    for i in 0..800 {
+        l1.set_bytes(&format!("key:{}", i), b"v".to_vec(), None)
+            .await
+            .unwrap();
+    }
+
+    let clock = Arc::new(MockClock::new());
+    let pressure = Arc::new(AtomicBool::new(true));
+    let tuner = L1AutoTuner::new(&l1, config.clone())
+        .with_clock(clock.clone())
+        .with_service_name(service.to_string())
+        .with_pressure_signal({
+            let pressure = pressure.clone();
+            Arc::new(move || pressure.load(Ordering::SeqCst))
+        });
+
+    // 周期未到时不调整
+    assert_eq!(tuner.tick().await, None);
+    assert_eq!(l1.capacity(), 800);
+
+    let mut capacities = vec![l1.capacity()];
+    for round in 0..10 {
+        // 命中率逐周期下降：命中次数固定，未命中次数递增
+        for i in 0..10 {
+            l1.get_bytes(&format!("key:{}", i)).await.unwrap();
+        }
+        for i in 0..(round + 1) * 10 {
+            l1.get_bytes(&format!("missing:{}", i)).await.unwrap();
+        }
+
+        clock.advance(Duration::from_secs(config.interval_secs));
+        let capacity = tuner.tick().await.unwrap();
+        assert!(capacity <= *capacities.last().unwrap());
+        assert!(capacity >= config.min_capacity);
+        assert!(l1.len().await <= capacity);
+        capacities.push(capacity);
+    }
+
+    assert_eq!(capacities[1], 600);
+    assert_eq!(*capacities.last().unwrap(), config.min_capacity);
+    assert_eq!(
+        GLOBAL_METRICS.snapshot().l1_capacity.get(service),
+        Some(&config.min_capacity)
+    );
+
+    // 压力解除后命中率低于目标，容量回升
+    pressure.store(false, Ordering::SeqCst);
+    l1.get_bytes("missing:again").await.unwrap();
+    clock.advance(Duration::from_secs(config.interval_secs));
+    assert_eq!(tuner.tick().await, Some(125));
+}
+
+#[tokio::test]
+async fn test_inserts_respect_shrunk_capacity() {
+    let l1 = L1Backend::new(1000);
+    assert_eq!(l1.set_capacity(100).await, 100);
+
+    // 收缩后继续写入，条目数不超过新容量，刚写入的键不被淘汰
+    for i in 0..500 {
+        l1.set_bytes(&format!("after:{}", i), b"v".to_vec(), None)
+            .await
+            .unwrap();
+    }
+    assert!(l1.len().await <= 100);
+    assert!(l1.get_bytes("after:499").await.unwrap().is_some());
+}
@@ -0,0 +1,375 @@
+//! Copyright (c) 2025-2026, Kirky.X
+//!
+//! MIT License
+//!
+//! L1后端测试
+
+use oxcache::backend::l1::{EvictionListener, L1Backend, DEFAULT_L1_TTL_SECS};
+use oxcache::backend::PERSISTENT_TTL;
+use oxcache::config::L1Config;
+use oxcache::metrics::GLOBAL_METRICS;
+use oxcache::{get_client, CacheExt, CacheManager, Config};
+use std::collections::HashSet;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+#[tokio::test]
+async fn test_l1_per_entry_ttl_expires() {
+    let l1 = L1Backend::new(1000);
+
+    l1.set_bytes("short", b"v1".to_vec(), Some(1))
+        .await
+        .unwrap();
+    l1.set_bytes("long", b"v2".to_vec(), None).await.unwrap();
+
+    assert!(l1.get_bytes("short").await.unwrap().is_some());
+
+    tokio::time::sleep(Duration::from_millis(1100)).await;
+
+    // 带TTL的条目应过期，未指定TTL的条目使用默认值保留
+    assert!(l1.get_bytes("short").await.unwrap().is_none());
+    assert_eq!(l1.get_bytes("long").await.unwrap(), Some(b"v2".to_vec()));
+}
+
+#[tokio::test]
+async fn test_l1_default_ttl_applies_when_unspecified() {
+    let l1 = L1Backend::new_with_default_ttl(1000, Some(1));
+    assert_eq!(l1.default_ttl(), 1);
+
+    l1.set_bytes("default", b"v1".to_vec(), None).await.unwrap();
+    l1.set_bytes("explicit", b"v2".to_vec(), Some(60))
+        .await
+        .unwrap();
+
+    tokio::time::sleep(Duration::from_millis(1100)).await;
+
+    assert!(l1.get_bytes("default").await.unwrap().is_none());
+    assert_eq!(
+        l1.get_bytes("explicit").await.unwrap(),
+        Some(b"v2".to_vec())
+    );
+}
+
+#[tokio::test]
+async fn test_l1_persistent_ttl_outlives_default() {
+    let l1 = L1Backend::new_with_default_ttl(1000, Some(1));
+
+    l1.set_bytes("default", b"v1".to_vec(), None).await.unwrap();
+    l1.set_bytes("persistent", b"v2".to_vec(), Some(PERSISTENT_TTL))
+        .await
+        .unwrap();
+
+    tokio::time::sleep(Duration::from_millis(1100)).await;
+
+    // None使用默认TTL过期，持久化条目不受默认TTL影响
+    assert!(l1.get_bytes("default").await.unwrap().is_none());
+    assert_eq!(
+        l1.get_bytes("persistent").await.unwrap(),
+        Some(b"v2".to_vec())
+    );
+}
+
+#[tokio::test]
+async fn test_l1_default_ttl_fallback() {
+    let l1 = L1Backend::new(1000);
+    assert_eq!(l1.default_ttl(), DEFAULT_L1_TTL_SECS);
+    assert!(L1Config::default().l1_default_ttl.is_none());
+}
+
+#[tokio::test]
+async fn test_l1_len_tracks_sets_and_clear() {
+    let l1 = L1Backend::new(1000);
+    assert!(l1.is_empty().await);
+
+    for i in 0..5 {
+        l1.set_bytes(&format!("key:{}", i), vec![0u8; 10], None)
+            .await
+            .unwrap();
+    }
+    assert_eq!(l1.len().await, 5);
+    // 5个键各5字节 + 5个值各10字节
+    assert_eq!(l1.weighted_size().await, 75);
+
+    l1.delete("key:0").await.unwrap();
+    assert_eq!(l1.len().await, 4);
+
+    l1.clear().unwrap();
+    assert_eq!(l1.len().await, 0);
+    assert_eq!(l1.weighted_size().await, 0);
+}
+
+#[tokio::test]
+async fn test_l1_len_decreases_after_eviction() {
+    let l1 = L1Backend::new(10);
+
+    for i in 0..100 {
+        l1.set_bytes(&format!("key:{}", i), b"v".to_vec(), None)
+            .await
+            .unwrap();
+    }
+
+    // 超出容量的条目被淘汰
+    assert!(l1.len().await <= 10);
+}
+
+#[tokio::test]
+async fn test_l1_eviction_listener_fires_on_capacity_overflow() {
+    let evicted = Arc::new(Mutex::new(Vec::new()));
+    let listener: EvictionListener = {
+        let evicted = evicted.clone();
+        Arc::new(move |key: &str| evicted.lock().unwrap().push(key.to_string()))
+    };
+    let l1 = L1Backend::new_with_eviction_listener(10, None, Some(listener));
+
+    for i in 0..50 {
+        l1.set_bytes(&format!("key:{}", i), b"v".to_vec(), None)
+            .await
+            .unwrap();
+    }
+    // 显式删除不触发监听器
+    l1.delete("key:49").await.unwrap();
+
+    let remaining = l1.len().await;
+    let evicted = evicted.lock().unwrap().clone();
+    assert_eq!(evicted.len() as u64, 49 - remaining);
+
+    let unique: HashSet<_> = evicted.iter().collect();
+    assert_eq!(unique.len(), evicted.len());
+    assert!(!evicted.contains(&"key:49".to_string()));
+    for key in &evicted {
+        assert!(l1.get_bytes(key).await.unwrap().is_none());
+    }
+}
+
+#[tokio::test]
+async fn test_l1_eviction_metrics_from_config() {
+    let config: Config = toml::from_str(
+        r#"
+        [services.l1_eviction_svc]
+        cache_type = "l1"
+
+        [services.l1_eviction_svc.l1]
+        max_capacity = 5
+        enable_eviction_listener = true
+        "#,
+    )
+    .expect("Failed to parse TOML");
+    CacheManager::init(config).await.expect("init failed");
+
+    let client = get_client("l1_eviction_svc").unwrap();
+    // 写入足够多的条目，使底层缓存在写入过程中执行维护任务并处理淘汰
+    for i in 0..1000 {
+        client.set(&format!("key:{}", i), &i, None).await.unwrap();
+    }
+
+    let evictions = GLOBAL_METRICS
+        .l1_evictions_total
+        .get("l1_eviction_svc")
+        .map(|v| *v.value())
+        .unwrap_or(0);
+    assert!(evictions > 0, "expected evictions to be recorded");
+    assert!(evictions <= 1000);
+}
+
+#[tokio::test]
+async fn test_l1_get_allow_stale_after_ttl() {
+    let l1 = L1Backend::new(1000);
+    l1.set_bytes("key", b"v".to_vec(), Some(1)).await.unwrap();
+
+    assert_eq!(
+        l1.get_allow_stale("key").await.unwrap(),
+        Some((b"v".to_vec(), false))
+    );
+
+    tokio::time::sleep(Duration::from_millis(1100)).await;
+
+    // 超过TTL后常规读取未命中，但数据仍在保留期内
+    assert!(l1.get_bytes("key").await.unwrap().is_none());
+    assert_eq!(
+        l1.get_allow_stale("key").await.unwrap(),
+        Some((b"v".to_vec(), true))
+    );
+    assert!(l1.get_allow_stale("missing").await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn test_l1_touch_extends_ttl_without_rewriting() {
+    let l1 = L1Backend::new(1000);
+
+    l1.set_with_metadata("session", b"v1".to_vec(), 1, 7)
+        .await
+        .unwrap();
+    tokio::time::sleep(Duration::from_millis(600)).await;
+    assert!(l1.touch("session", 100).await.unwrap());
+    tokio::time::sleep(Duration::from_millis(600)).await;
+
+    // 原TTL已过，刷新后的条目仍然存在，值与版本号不变
+    assert_eq!(
+        l1.get_with_metadata("session").await.unwrap(),
+        Some((b"v1".to_vec(), 7))
+    );
+    assert!(!l1.touch("missing", 100).await.unwrap());
+
+    l1.set_bytes("expired", b"v2".to_vec(), Some(1))
+        .await
+        .unwrap();
+    tokio::time::sleep(Duration::from_millis(1100)).await;
+    assert!(!l1.touch("expired", 100).await.unwrap());
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_l1_concurrent_get_set_consistency() {
+    let l1 = Arc::new(L1Backend::new(100_000));
+
+    // 每个任务独占一组键，写入后立即读取，版本号与值必须匹配
+    let mut handles = Vec::new();
+    for task in 0..16u64 {
+        let l1 = l1.clone();
+        handles.push(tokio::spawn(async move {
+            for round in 0..200u64 {
+                let key = format!("task{}:key{}", task, round % 20);
+                let value = format!("{}:{}", task, round).into_bytes();
+                l1.set_with_metadata(&key, value.clone(), 60, round)
+                    .await
+                    .unwrap();
+                let (got, version) = l1.get_with_metadata(&key).await.unwrap().unwrap();
+                assert_eq!(got, value);
+                assert_eq!(version, round);
+            }
+        }));
+    }
+
+    // 共享键上的并发写入，最终值必须是某次完整写入的结果
+    for task in 0..16u64 {
+        let l1 = l1.clone();
+        handles.push(tokio::spawn(async move {
+            for round in 0..200u64 {
+                l1.set_with_metadata("shared", vec![task as u8; 64], 60, round)
+                    .await
+                    .unwrap();
+                if let Some((got, _)) = l1.get_with_metadata("shared").await.unwrap() {
+                    assert_eq!(got.len(), 64);
+                    assert!(got.iter().all(|b| *b == got[0]));
+                }
+                if round % 50 == 0 {
+                    l1.delete("shared").await.unwrap();
+                }
+            }
+        }));
+    }
+
+    for handle in handles {
+        handle.await.unwrap();
+    }
+
+    for task in 0..16u64 {
+        for slot in 0..20u64 {
+            let key = format!("task{}:key{}", task, slot);
+            let (got, version) = l1.get_with_metadata(&key).await.unwrap().unwrap();
+            assert_eq!(version, 180 + slot);
+            assert_eq!(got, format!("{}:{}", task, 180 + slot).into_bytes());
+        }
+    }
+
+    l1.clear().unwrap();
+    assert!(l1.get_bytes("task0:key0").await.unwrap().is_none());
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 8)]
+async fn test_l1_sharded_concurrent_correctness() {
+    let l1 = Arc::new(L1Backend::new_sharded(100_000, None, None, 16));
+    assert_eq!(l1.shard_count(), 16);
+    assert_eq!(l1.max_capacity(), 100_000);
+
+    // 多个任务并发写入互不重叠的键，每次写入后立即读回
+    let mut handles = Vec::new();
+    for task in 0..32u64 {
+        let l1 = l1.clone();
+        handles.push(tokio::spawn(async move {
+            for i in 0..500u64 {
+                let key = format!("shard:{}:{}", task, i);
+                let value = (task * 1000 + i).to_be_bytes().to_vec();
+                l1.set_with_metadata(&key, value.clone(), 60, i)
+                    .await
+                    .unwrap();
+                assert_eq!(l1.get_bytes(&key).await.unwrap(), Some(value));
+            }
+        }));
+    }
+    for handle in handles {
+        handle.await.unwrap();
+    }
+
+    // 计数与占用大小汇总所有分片
+    assert_eq!(l1.len().await, 32 * 500);
+    for task in 0..32u64 {
+        for i in (0..500u64).step_by(37) {
+            let key = format!("shard:{}:{}", task, i);
+            let (got, version) = l1.get_with_metadata(&key).await.unwrap().unwrap();
+            assert_eq!(got, (task * 1000 + i).to_be_bytes().to_vec());
+            assert_eq!(version, i);
+        }
+    }
+    assert!(l1.weighted_size().await > 32 * 500 * 8);
+
+    // 删除只影响所在分片的对应键
+    l1.delete("shard:0:0").await.unwrap();
+    assert!(l1.get_bytes("shard:0:0").await.unwrap().is_none());
+    assert_eq!(l1.len().await, 32 * 500 - 1);
+
+    // 收缩容量时跨分片淘汰
+    assert_eq!(l1.set_capacity(1000).await, 1000);
+    assert!(l1.len().await <= 1000);
+
+    // 收缩后继续写入，条目数仍不超过新容量
+    for i in 0..2000u64 {
+        l1.set_bytes(&format!("shard:after:{}", i), vec![0], None)
+            .await
+            .unwrap();
+    }
+    assert!(l1.len().await <= 1000);
+    assert!(l1.get_bytes("shard:after:1999").await.unwrap().is_some());
+
+    // 清空作用于所有分片
+    l1.clear().unwrap();
+    assert!(l1.is_empty().await);
+    assert!(l1.get_bytes("shard:1:1").await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn test_l1_shards_round_up_to_power_of_two() {
+    assert_eq!(L1Backend::new(1000).shard_count(), 1);
+    assert_eq!(L1Backend::new_sharded(1000, None, None, 0).shard_count(), 1);
+    assert_eq!(L1Backend::new_sharded(1000, None, None, 6).shard_count(), 8);
+
+    // 分片与否不影响字符串键和原始键指向同一条目
+    let l1 = L1Backend::new_sharded(1000, None, None, 4);
+    l1.set_bytes("same", b"v".to_vec(), Some(60)).await.unwrap();
+    assert_eq!(
+        l1.get_bytes_by_raw(b"same").await.unwrap(),
+        Some(b"v".to_vec())
+    );
+}
+
+#[test]
+fn test_l1_shards_config_validation() {
+    let parse = |shards: usize| {
+        toml::from_str::<Config>(&format!(
+            r#"
+            [services.l1_shards_svc]
+            cache_type = "l1"
+
+            [services.l1_shards_svc.l1]
+            max_capacity = 1000
+            shards = {}
+            "#,
+            shards
+        ))
+        .unwrap()
+    };
+
+    assert!(parse(8).validate().is_ok());
+    assert!(parse(0).validate().is_err());
+    assert!(parse(6).validate().is_err());
+    assert_eq!(L1Config::default().shards, 1);
+}
@@ -0,0 +1,100 @@
+//! Copyright (c) 2025-2026, Kirky.X
+//!
+//! MIT License
+//!
+//! L2命令重试策略测试
+
+use oxcache::backend::retry::{backoff_delay, retry_with_backoff};
+use oxcache::config::RetryConfig;
+use oxcache::error::CacheError;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::time::{Duration, Instant};
+
+fn policy(max_retries: u32) -> RetryConfig {
+    RetryConfig {
+        max_retries,
+        base_backoff_ms: 10,
+        jitter: false,
+    }
+}
+
+#[tokio::test]
+async fn test_flaky_operation_succeeds_after_retry() {
+    let attempts = AtomicU32::new(0);
+
+    let result = retry_with_backoff(&policy(2), Duration::from_secs(1), || async {
+        if attempts.fetch_add(1, Ordering::SeqCst) == 0 {
+            Err(CacheError::Timeout("connection reset".to_string()))
+        } else {
+            Ok("value")
+        }
+    })
+    .await;
+
+    assert_eq!(result.unwrap(), "value");
+    assert_eq!(attempts.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn test_non_transient_error_is_not_retried() {
+    let attempts = AtomicU32::new(0);
+
+    let result: oxcache::error::Result<()> =
+        retry_with_backoff(&policy(3), Duration::from_secs(1), || async {
+            attempts.fetch_add(1, Ordering::SeqCst);
+            Err(CacheError::AuthenticationFailed("WRONGPASS".to_string()))
+        })
+        .await;
+
+    assert!(matches!(result, Err(CacheError::AuthenticationFailed(_))));
+    assert_eq!(attempts.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn test_retries_stop_at_max_retries() {
+    let attempts = AtomicU32::new(0);
+
+    let result: oxcache::error::Result<()> =
+        retry_with_backoff(&policy(2), Duration::from_secs(1), || async {
+            attempts.fetch_add(1, Ordering::SeqCst);
+            Err(CacheError::ConnectionRefused("refused".to_string()))
+        })
+        .await;
+
+    assert!(matches!(result, Err(CacheError::ConnectionRefused(_))));
+    assert_eq!(attempts.load(Ordering::SeqCst), 3);
+}
+
+#[tokio::test]
+async fn test_retries_respect_overall_budget() {
+    let attempts = AtomicU32::new(0);
+    let start = Instant::now();
+
+    let result: oxcache::error::Result<()> =
+        retry_with_backoff(&policy(10), Duration::from_millis(100), || async {
+            attempts.fetch_add(1, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(60)).await;
+            Err(CacheError::Timeout("slow".to_string()))
+        })
+        .await;
+
+    assert!(matches!(result, Err(CacheError::Timeout(_))));
+    assert!(attempts.load(Ordering::SeqCst) <= 2);
+    assert!(start.elapsed() < Duration::from_millis(200));
+}
+
+#[test]
+fn test_backoff_delay() {
+    let fixed = policy(3);
+    assert_eq!(backoff_delay(&fixed, 0), Duration::from_millis(10));
+    assert_eq!(backoff_delay(&fixed, 2), Duration::from_millis(40));
+
+    let jittered = RetryConfig {
+        jitter: true,
+        ..fixed
+    };
+    for _ in 0..20 {
+        let delay = backoff_delay(&jittered, 2);
+        assert!(delay >= Duration::from_millis(20) && delay <= Duration::from_millis(40));
+    }
+}
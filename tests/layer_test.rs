@@ -4,8 +4,25 @@
 //!
 //! 分层缓存测试
 
-use oxcache::config::{CacheType, Config, L1Config, L2Config, RedisMode, ServiceConfig};
+use common::client_test_utils::{
+    create_client, fake_redis_l2, in_memory_l2, in_memory_l2_with_clock,
+};
+use common::fake_redis::FakeRedis;
+use common::redis_test_utils::create_standalone_config;
+use oxcache::backend::l1::L1Backend;
+use oxcache::backend::l2::L2Backend;
+use oxcache::client::two_level::TwoLevelClient;
+use oxcache::client::CacheOps;
+use oxcache::config::{
+    CacheType, Config, L1Config, L2Config, RedisMode, ServiceConfig, TwoLevelConfig, WriteOrder,
+};
+use oxcache::error::CacheError;
+use oxcache::metrics::GLOBAL_METRICS;
+use oxcache::utils::clock::MockClock;
+use oxcache::CacheExt;
 use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
 
 mod common;
 
@@ -33,6 +50,7 @@ async fn test_l1_only_mode() {
                         ..Default::default()
                     }),
                     l2: None,
+                    ..Default::default()
                 },
             );
             map
@@ -100,6 +118,7 @@ async fn test_l2_only_mode() {
                         enable_tls: false,
                         ..Default::default()
                     }),
+                    ..Default::default()
                 },
             );
             map
@@ -157,3 +176,726 @@ async fn test_l2_only_mode() {
         );
     }
 }
+
+#[tokio::test]
+async fn test_set_get_delete_round_trip() {
+    let l2 = in_memory_l2();
+    let client = create_client(
+        "in_memory_round_trip_test",
+        TwoLevelConfig::default(),
+        Arc::new(L1Backend::new(100)),
+        l2.clone(),
+    )
+    .await;
+    let key = "in_memory_round_trip_test:user";
+
+    client
+        .set(key, &"alice".to_string(), Some(60))
+        .await
+        .unwrap();
+    assert_eq!(
+        client.get::<String>(key).await.unwrap().as_deref(),
+        Some("alice")
+    );
+    assert!(client.get_l2_bytes(key).await.unwrap().is_some());
+    assert_eq!(l2.get_with_version(key).await.unwrap().unwrap().1, 1);
+
+    client.delete(key).await.unwrap();
+    assert_eq!(client.get::<String>(key).await.unwrap(), None);
+    assert!(!l2.exists(key).await.unwrap());
+    assert!(!l2.exists(&format!("{}:version", key)).await.unwrap());
+
+    client.shutdown().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_l1_miss_promotes_from_shared_l2() {
+    let l2 = in_memory_l2();
+    let writer = create_client(
+        "in_memory_promote_test",
+        TwoLevelConfig::default(),
+        Arc::new(L1Backend::new(100)),
+        l2.clone(),
+    )
+    .await;
+    let reader = create_client(
+        "in_memory_promote_test",
+        TwoLevelConfig::default(),
+        Arc::new(L1Backend::new(100)),
+        l2,
+    )
+    .await;
+    let key = "in_memory_promote_test:item";
+
+    writer.set(key, &42u32, Some(60)).await.unwrap();
+    assert_eq!(reader.get_l1_bytes(key).await.unwrap(), None);
+    assert_eq!(reader.get::<u32>(key).await.unwrap(), Some(42));
+
+    // 回填L1在后台任务中完成
+    let mut promoted = false;
+    for _ in 0..100 {
+        if reader.get_l1_bytes(key).await.unwrap().is_some() {
+            promoted = true;
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    assert!(promoted);
+
+    writer.shutdown().await.unwrap();
+    reader.shutdown().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_l2_entries_expire_with_mock_clock() {
+    let clock = Arc::new(MockClock::new());
+    let l2 = in_memory_l2_with_clock(clock.clone());
+    let client = create_client(
+        "in_memory_expiry_test",
+        TwoLevelConfig::default(),
+        Arc::new(L1Backend::new(100)),
+        l2.clone(),
+    )
+    .await;
+    let key = "in_memory_expiry_test:session";
+
+    client
+        .set(key, &"token".to_string(), Some(10))
+        .await
+        .unwrap();
+    assert_eq!(l2.ttl(key).await.unwrap(), Some(10));
+
+    clock.advance(Duration::from_secs(9));
+    assert!(client.get_l2_bytes(key).await.unwrap().is_some());
+    assert_eq!(l2.pttl(key).await.unwrap(), Some(1000));
+
+    clock.advance(Duration::from_secs(1));
+    assert_eq!(client.get_l2_bytes(key).await.unwrap(), None);
+    assert_eq!(l2.ttl(key).await.unwrap(), None);
+
+    client.shutdown().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_persistent_and_touched_entries() {
+    let clock = Arc::new(MockClock::new());
+    let l2 = in_memory_l2_with_clock(clock.clone());
+    let client = create_client(
+        "in_memory_touch_test",
+        TwoLevelConfig::default(),
+        Arc::new(L1Backend::new(100)),
+        l2.clone(),
+    )
+    .await;
+    let persistent = "in_memory_touch_test:persistent";
+    let touched = "in_memory_touch_test:touched";
+
+    client.set_persistent(persistent, &1u8).await.unwrap();
+    client.set(touched, &2u8, Some(5)).await.unwrap();
+    assert!(client.touch(touched, 60).await.unwrap());
+
+    clock.advance(Duration::from_secs(30));
+    assert!(l2.exists(persistent).await.unwrap());
+    assert_eq!(l2.ttl(persistent).await.unwrap(), None);
+    assert_eq!(l2.ttl(touched).await.unwrap(), Some(30));
+
+    client.shutdown().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_lock_and_counters() {
+    let clock = Arc::new(MockClock::new());
+    let l2 = in_memory_l2_with_clock(clock.clone());
+    let client = create_client(
+        "in_memory_lock_test",
+        TwoLevelConfig::default(),
+        Arc::new(L1Backend::new(100)),
+        l2.clone(),
+    )
+    .await;
+    let lock_key = "in_memory_lock_test:lock";
+
+    assert!(client.lock(lock_key, "owner-a", 5).await.unwrap());
+    assert!(!client.lock(lock_key, "owner-b", 5).await.unwrap());
+    assert!(!client.unlock(lock_key, "owner-b").await.unwrap());
+    assert!(client.unlock(lock_key, "owner-a").await.unwrap());
+
+    // 锁过期后可被其他持有者获取
+    assert!(client.lock(lock_key, "owner-a", 5).await.unwrap());
+    clock.advance(Duration::from_secs(5));
+    assert!(client.lock(lock_key, "owner-b", 5).await.unwrap());
+
+    assert!(l2
+        .set_nx("in_memory_lock_test:nx", "1", Some(1))
+        .await
+        .unwrap());
+    assert!(!l2
+        .set_nx("in_memory_lock_test:nx", "2", None)
+        .await
+        .unwrap());
+    assert_eq!(l2.incr("in_memory_lock_test:counter").await.unwrap(), 1);
+    assert_eq!(l2.incr("in_memory_lock_test:counter").await.unwrap(), 2);
+
+    client.shutdown().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_get_set_hash_and_clear() {
+    let l2 = in_memory_l2();
+    let client = create_client(
+        "in_memory_clear_test",
+        TwoLevelConfig::default(),
+        Arc::new(L1Backend::new(100)),
+        l2.clone(),
+    )
+    .await;
+    let key = "in_memory_clear_test:value";
+
+    assert_eq!(client.get_set(key, &1u32, Some(60)).await.unwrap(), None);
+    assert_eq!(client.get_set(key, &2u32, Some(60)).await.unwrap(), Some(1));
+
+    let hash = "in_memory_clear_test:hash";
+    client.hset_value(hash, "a", &1u32, Some(60)).await.unwrap();
+    client.hset_value(hash, "b", &2u32, Some(60)).await.unwrap();
+    assert_eq!(client.hget_as::<u32>(hash, "b").await.unwrap(), Some(2));
+    assert_eq!(client.hgetall_as::<u32>(hash).await.unwrap().len(), 2);
+    assert_eq!(l2.get_type(hash).await.unwrap(), "hash");
+
+    l2.set_bytes("other_service:key", b"keep".to_vec(), Some(60))
+        .await
+        .unwrap();
+    client.clear_l2().await.unwrap();
+    assert!(l2
+        .scan_keys("in_memory_clear_test:*")
+        .await
+        .unwrap()
+        .is_empty());
+    assert!(l2.exists("other_service:key").await.unwrap());
+
+    client.shutdown().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_l2_first_skips_l1_when_l2_write_fails() {
+    let l1 = Arc::new(L1Backend::new(1000));
+    let fake = FakeRedis::start().await;
+    let client = create_client(
+        "write_order_test_l2",
+        TwoLevelConfig {
+            write_order: WriteOrder::L2First,
+            ..Default::default()
+        },
+        l1.clone(),
+        fake_redis_l2(&fake).await,
+    )
+    .await;
+
+    // L2确认后才写入L1
+    client
+        .set_bytes("write_order_test_l2:ok", b"new".to_vec(), Some(60))
+        .await
+        .unwrap();
+    assert_eq!(
+        l1.get_bytes("write_order_test_l2:ok").await.unwrap(),
+        Some(b"new".to_vec())
+    );
+
+    // L2写入失败时转入WAL，L1中既没有新值也不保留旧值
+    let key = "write_order_test_l2:fail";
+    l1.set_bytes(key, b"old".to_vec(), Some(60)).await.unwrap();
+    client
+        .set_bytes(key, b"new".to_vec(), Some(60))
+        .await
+        .unwrap();
+    assert_eq!(l1.get_bytes(key).await.unwrap(), None);
+
+    client.shutdown().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_l1_first_keeps_l1_value_when_l2_write_fails() {
+    let l1 = Arc::new(L1Backend::new(1000));
+    let fake = FakeRedis::start().await;
+    let client = create_client(
+        "write_order_test_l1",
+        TwoLevelConfig {
+            write_order: WriteOrder::L1First,
+            ..Default::default()
+        },
+        l1.clone(),
+        fake_redis_l2(&fake).await,
+    )
+    .await;
+
+    let key = "write_order_test_l1:fail";
+    client
+        .set_bytes(key, b"new".to_vec(), Some(60))
+        .await
+        .unwrap();
+    assert_eq!(l1.get_bytes(key).await.unwrap(), Some(b"new".to_vec()));
+
+    client.shutdown().await.unwrap();
+}
+
+fn divergence_count(service: &str) -> u64 {
+    GLOBAL_METRICS
+        .snapshot()
+        .ttl_divergence_total
+        .get(service)
+        .copied()
+        .unwrap_or(0)
+}
+
+#[tokio::test]
+async fn test_l1_ttl_longer_than_l2_records_divergence() {
+    let service = "ttl_divergence_test";
+    // L1默认TTL为两小时，超过L2默认的3600秒
+    let client = create_client(
+        service,
+        TwoLevelConfig::default(),
+        Arc::new(L1Backend::new_with_default_ttl(100, Some(7200))),
+        in_memory_l2(),
+    )
+    .await;
+
+    client
+        .set_bytes("ttl_divergence_test:default", b"v".to_vec(), None)
+        .await
+        .unwrap();
+    assert_eq!(divergence_count(service), 1);
+
+    // 显式TTL对两层一致，不产生偏差
+    client
+        .set_bytes("ttl_divergence_test:explicit", b"v".to_vec(), Some(60))
+        .await
+        .unwrap();
+    assert_eq!(divergence_count(service), 1);
+
+    client.shutdown().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_l1_ttl_shorter_than_l2_is_not_reported() {
+    let service = "ttl_divergence_ok_test";
+    let client = create_client(
+        service,
+        TwoLevelConfig::default(),
+        Arc::new(L1Backend::new_with_default_ttl(100, Some(300))),
+        in_memory_l2(),
+    )
+    .await;
+
+    client
+        .set_bytes("ttl_divergence_ok_test:default", b"v".to_vec(), None)
+        .await
+        .unwrap();
+    assert_eq!(divergence_count(service), 0);
+
+    client.shutdown().await.unwrap();
+}
+
+fn raw_key() -> Vec<u8> {
+    let mut key = b"raw_key_test:".to_vec();
+    key.extend_from_slice(&[0xff, 0x00, 0x80, 0xfe, 0xc3, 0x28, 0xa0, 0xa1]);
+    key.resize(32, 0x9f);
+    key
+}
+
+#[tokio::test]
+async fn test_non_utf8_key_round_trip_in_both_layers() {
+    let key = raw_key();
+    assert_eq!(key.len(), 32);
+    assert!(std::str::from_utf8(&key).is_err());
+
+    let l1 = Arc::new(L1Backend::new(100));
+    let l2 = in_memory_l2();
+    let client = create_client(
+        "raw_key_test",
+        TwoLevelConfig::default(),
+        l1.clone(),
+        l2.clone(),
+    )
+    .await;
+
+    client
+        .set_bytes_by_raw(&key, b"binary-value".to_vec(), Some(60))
+        .await
+        .unwrap();
+
+    assert_eq!(
+        client.get_bytes_by_raw(&key).await.unwrap().as_deref(),
+        Some(&b"binary-value"[..])
+    );
+    assert_eq!(
+        l1.get_bytes_by_raw(&key).await.unwrap().as_deref(),
+        Some(&b"binary-value"[..])
+    );
+    assert_eq!(
+        l2.get_bytes_by_raw(&key).await.unwrap().as_deref(),
+        Some(&b"binary-value"[..])
+    );
+
+    // 另一个实例的L1为空，从共享L2读取后回写自己的L1
+    let other_l1 = Arc::new(L1Backend::new(100));
+    let other = create_client(
+        "raw_key_test",
+        TwoLevelConfig::default(),
+        other_l1.clone(),
+        l2,
+    )
+    .await;
+    assert_eq!(
+        other.get_bytes_by_raw(&key).await.unwrap().as_deref(),
+        Some(&b"binary-value"[..])
+    );
+    assert!(other_l1.get_bytes_by_raw(&key).await.unwrap().is_some());
+
+    client.shutdown().await.unwrap();
+    other.shutdown().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_raw_key_length_is_validated() {
+    let l2 = in_memory_l2();
+    let client = create_client(
+        "raw_key_test",
+        TwoLevelConfig::default(),
+        Arc::new(L1Backend::new(100)),
+        l2,
+    )
+    .await;
+
+    let too_long = vec![0xffu8; 257];
+    assert!(client
+        .set_bytes_by_raw(&too_long, b"v".to_vec(), None)
+        .await
+        .is_err());
+
+    client.shutdown().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_incr_by_checked_normal_increment() {
+    let client = create_client(
+        "incr_checked_normal_test",
+        TwoLevelConfig::default(),
+        Arc::new(L1Backend::new(100)),
+        in_memory_l2(),
+    )
+    .await;
+    let key = "incr_checked_normal_test:counter";
+
+    assert_eq!(client.incr_by_checked(key, 5, None).await.unwrap(), 5);
+    assert_eq!(client.incr_by_checked(key, 3, Some(100)).await.unwrap(), 8);
+    assert_eq!(
+        client.incr_by_checked(key, -10, Some(100)).await.unwrap(),
+        -2
+    );
+    assert_eq!(client.get::<i64>(key).await.unwrap(), Some(-2));
+}
+
+#[tokio::test]
+async fn test_incr_by_checked_reaches_ceiling_exactly() {
+    let client = create_client(
+        "incr_checked_exact_test",
+        TwoLevelConfig::default(),
+        Arc::new(L1Backend::new(100)),
+        in_memory_l2(),
+    )
+    .await;
+    let key = "incr_checked_exact_test:counter";
+
+    assert_eq!(client.incr_by_checked(key, 7, Some(10)).await.unwrap(), 7);
+    assert_eq!(client.incr_by_checked(key, 3, Some(10)).await.unwrap(), 10);
+    // 增量为0不越过上限
+    assert_eq!(client.incr_by_checked(key, 0, Some(10)).await.unwrap(), 10);
+}
+
+#[tokio::test]
+async fn test_incr_by_checked_rejects_exceeding_ceiling() {
+    let client = create_client(
+        "incr_checked_exceed_test",
+        TwoLevelConfig::default(),
+        Arc::new(L1Backend::new(100)),
+        in_memory_l2(),
+    )
+    .await;
+    let key = "incr_checked_exceed_test:counter";
+
+    client.incr_by_checked(key, 9, Some(10)).await.unwrap();
+    let result = client.incr_by_checked(key, 2, Some(10)).await;
+    assert!(matches!(result, Err(CacheError::LimitExceeded(_))));
+    // 被拒绝的自增不修改计数器
+    assert_eq!(client.get::<i64>(key).await.unwrap(), Some(9));
+
+    // 不设置上限时，超出i64范围同样被拒绝而不是由服务端报错
+    let key = "incr_checked_exceed_test:max";
+    client.incr_by_checked(key, i64::MAX, None).await.unwrap();
+    let result = client.incr_by_checked(key, 1, None).await;
+    assert!(matches!(result, Err(CacheError::LimitExceeded(_))));
+    assert_eq!(client.get::<i64>(key).await.unwrap(), Some(i64::MAX));
+
+    // 上限减去增量溢出时直接拒绝
+    let result = client.incr_by_checked(key, 1, Some(i64::MIN)).await;
+    assert!(matches!(result, Err(CacheError::LimitExceeded(_))));
+}
+
+#[tokio::test]
+async fn test_incr_by_checked_mirrors_to_secondary() {
+    let secondary = in_memory_l2();
+    let client = create_client(
+        "incr_checked_mirror_test",
+        TwoLevelConfig::default(),
+        Arc::new(L1Backend::new(100)),
+        in_memory_l2(),
+    )
+    .await
+    .with_secondary(secondary.clone());
+    let key = "incr_checked_mirror_test:counter";
+
+    client.incr_by_checked(key, 5, Some(10)).await.unwrap();
+    assert_eq!(secondary.get_bytes(key).await.unwrap(), Some(b"5".to_vec()));
+
+    // 被拒绝的自增不镜像
+    assert!(client.incr_by_checked(key, 6, Some(10)).await.is_err());
+    assert_eq!(secondary.get_bytes(key).await.unwrap(), Some(b"5".to_vec()));
+}
+
+#[tokio::test]
+async fn test_incr_by_checked_script_near_i64_max() {
+    if !common::is_redis_available().await {
+        println!("跳过测试: Redis不可用");
+        return;
+    }
+
+    let l2 = L2Backend::new(&create_standalone_config()).await.unwrap();
+    let key = "incr_checked_redis_test:counter";
+    l2.delete(key).await.unwrap();
+
+    // 接近i64::MAX时Lua双精度数无法区分相邻整数，上限比较必须精确
+    let ceiling = i64::MAX - 1;
+    l2.incr_by_checked(key, ceiling - 1, None).await.unwrap();
+    assert_eq!(
+        l2.incr_by_checked(key, 1, Some(ceiling)).await.unwrap(),
+        ceiling
+    );
+    let result = l2.incr_by_checked(key, 1, Some(ceiling)).await;
+    assert!(matches!(result, Err(CacheError::LimitExceeded(_))));
+    assert_eq!(l2.incr_by_checked(key, 1, None).await.unwrap(), i64::MAX);
+    let result = l2.incr_by_checked(key, 1, None).await;
+    assert!(matches!(result, Err(CacheError::LimitExceeded(_))));
+
+    l2.delete(key).await.unwrap();
+}
+
+/// 创建带次级L2的客户端
+async fn mirrored_client(
+    service: &str,
+    primary: Arc<L2Backend>,
+    secondary: Arc<L2Backend>,
+) -> TwoLevelClient {
+    create_client(
+        service,
+        TwoLevelConfig::default(),
+        Arc::new(L1Backend::new(100)),
+        primary,
+    )
+    .await
+    .with_secondary(secondary)
+}
+
+#[tokio::test]
+async fn test_writes_mirror_to_secondary_and_reads_use_primary() {
+    let primary = in_memory_l2();
+    let secondary = in_memory_l2();
+    let client = mirrored_client("secondary_l2_test", primary.clone(), secondary.clone()).await;
+
+    client.set("mirror:1", &"alice", Some(60)).await.unwrap();
+    let expected = Some(br#""alice""#.to_vec());
+    assert_eq!(primary.get_bytes("mirror:1").await.unwrap(), expected);
+    assert_eq!(secondary.get_bytes("mirror:1").await.unwrap(), expected);
+
+    // 只存在于次级L2的键对读取不可见
+    secondary
+        .set_bytes("mirror:2", br#""bob""#.to_vec(), Some(60))
+        .await
+        .unwrap();
+    assert_eq!(client.get::<String>("mirror:2").await.unwrap(), None);
+
+    client.delete("mirror:1").await.unwrap();
+    assert_eq!(primary.get_bytes("mirror:1").await.unwrap(), None);
+    assert_eq!(secondary.get_bytes("mirror:1").await.unwrap(), None);
+
+    // 批量删除同样镜像到次级L2
+    client.set("mirror:3", &"carol", Some(60)).await.unwrap();
+    client.set("mirror:4", &"dave", Some(60)).await.unwrap();
+    client.delete_many(&["mirror:3", "mirror:4"]).await.unwrap();
+    for key in ["mirror:3", "mirror:4"] {
+        assert_eq!(primary.get_bytes(key).await.unwrap(), None);
+        assert_eq!(secondary.get_bytes(key).await.unwrap(), None);
+    }
+
+    // 毫秒级TTL的写入同样镜像到次级L2
+    client
+        .set_bytes_ms("mirror:5", b"erin".to_vec(), Duration::from_millis(500))
+        .await
+        .unwrap();
+    assert_eq!(
+        secondary.get_bytes("mirror:5").await.unwrap(),
+        Some(b"erin".to_vec())
+    );
+
+    // GETSET写入的新值同样镜像到次级L2
+    let previous: Option<String> = client
+        .get_set("mirror:6", &"frank".to_string(), Some(60))
+        .await
+        .unwrap();
+    assert_eq!(previous, None);
+    assert_eq!(
+        secondary.get_bytes("mirror:6").await.unwrap(),
+        Some(br#""frank""#.to_vec())
+    );
+}
+
+#[tokio::test]
+async fn test_secondary_failure_is_not_fatal() {
+    let service = "secondary_l2_failure_test";
+    let primary = in_memory_l2();
+    let secondary = in_memory_l2();
+    let client = mirrored_client(service, primary.clone(), secondary.clone()).await;
+
+    secondary.in_memory_store().unwrap().set_unavailable(true);
+    client.set("mirror:3", &"carol", Some(60)).await.unwrap();
+    assert_eq!(
+        primary.get_bytes("mirror:3").await.unwrap(),
+        Some(br#""carol""#.to_vec())
+    );
+    client.delete("mirror:3").await.unwrap();
+
+    let failures = |op: &str| {
+        GLOBAL_METRICS
+            .requests_total
+            .get(&format!("{}:L2Secondary:{}:error", service, op))
+            .map(|count| *count)
+    };
+    assert_eq!(failures("set"), Some(1));
+    assert_eq!(failures("delete"), Some(1));
+}
+
+#[tokio::test]
+async fn test_promote_secondary_swaps_backends() {
+    let primary = in_memory_l2();
+    let secondary = in_memory_l2();
+    let mut client = mirrored_client(
+        "secondary_l2_promote_test",
+        primary.clone(),
+        secondary.clone(),
+    )
+    .await;
+
+    secondary
+        .set_bytes("promote:1", br#""migrated""#.to_vec(), Some(60))
+        .await
+        .unwrap();
+    client.promote_secondary().await.unwrap();
+    assert!(Arc::ptr_eq(client.secondary().unwrap(), &primary));
+
+    // 读取来自原次级L2，写入镜像到原主L2
+    assert_eq!(
+        client.get::<String>("promote:1").await.unwrap(),
+        Some("migrated".to_string())
+    );
+    client.set("promote:2", &"dave", Some(60)).await.unwrap();
+    let expected = Some(br#""dave""#.to_vec());
+    assert_eq!(secondary.get_bytes("promote:2").await.unwrap(), expected);
+    assert_eq!(primary.get_bytes("promote:2").await.unwrap(), expected);
+}
+
+#[tokio::test]
+async fn test_promote_without_secondary_is_rejected() {
+    let mut client = create_client(
+        "secondary_l2_missing_test",
+        TwoLevelConfig::default(),
+        Arc::new(L1Backend::new(100)),
+        in_memory_l2(),
+    )
+    .await;
+    assert!(client.promote_secondary().await.is_err());
+}
+
+/// 创建关闭命中推广、使用指定准入阈值的客户端
+async fn admission_client(
+    service: &str,
+    l1: Arc<L1Backend>,
+    l2: Arc<L2Backend>,
+    admission_threshold: Option<f64>,
+) -> TwoLevelClient {
+    create_client(
+        service,
+        TwoLevelConfig {
+            // 关闭命中推广，避免L2命中在后台回填L1
+            promote_on_hit: false,
+            admission_threshold,
+            ..Default::default()
+        },
+        l1,
+        l2,
+    )
+    .await
+}
+
+#[tokio::test]
+async fn test_l1_headroom_tracks_entry_count() {
+    let l1 = Arc::new(L1Backend::new(8));
+    let client = admission_client("l1_headroom_test", l1.clone(), in_memory_l2(), None).await;
+    assert_eq!(client.l1_headroom().await, 1.0);
+
+    for i in 0..4 {
+        client
+            .set(&format!("headroom:{}", i), &i, Some(60))
+            .await
+            .unwrap();
+    }
+    assert_eq!(client.l1_headroom().await, 0.5);
+
+    // 自适应调优收缩容量后按当前生效容量计算
+    l1.set_capacity(4).await;
+    assert_eq!(client.l1_headroom().await, 0.0);
+}
+
+#[tokio::test]
+async fn test_admission_threshold_skips_l1_but_writes_l2() {
+    let l1 = Arc::new(L1Backend::new(10));
+    let l2 = in_memory_l2();
+    let client = admission_client("l1_admission_test", l1.clone(), l2.clone(), Some(0.5)).await;
+
+    for i in 0..5 {
+        client
+            .set(&format!("admission:{}", i), &i, Some(60))
+            .await
+            .unwrap();
+    }
+    assert_eq!(client.l1_headroom().await, 0.5);
+
+    // 剩余容量恰好等于阈值时仍然准入
+    client.set("admission:5", &5, Some(60)).await.unwrap();
+    assert!(l1.get_bytes("admission:5").await.unwrap().is_some());
+
+    // 低于阈值后只写入L2
+    client.set("admission:6", &6, Some(60)).await.unwrap();
+    assert!(l1.get_bytes("admission:6").await.unwrap().is_none());
+    assert!(l2.get_bytes("admission:6").await.unwrap().is_some());
+    assert_eq!(client.get::<i32>("admission:6").await.unwrap(), Some(6));
+
+    // 直接写入L1同样受准入阈值约束
+    client
+        .set_l1_bytes("admission:7", b"7".to_vec(), Some(60))
+        .await
+        .unwrap();
+    assert!(l1.get_bytes("admission:7").await.unwrap().is_none());
+
+    // 跳过L1时移除旧值，读取不会得到过时的副本
+    client.set("admission:0", &100, Some(60)).await.unwrap();
+    assert!(l1.get_bytes("admission:0").await.unwrap().is_none());
+    assert_eq!(client.get::<i32>("admission:0").await.unwrap(), Some(100));
+}
@@ -0,0 +1,78 @@
+//! Copyright (c) 2025-2026, Kirky.X
+//!
+//! MIT License
+//!
+//! JSON日志格式测试
+
+use oxcache::utils::{json_subscriber, log_filter};
+use serial_test::serial;
+use std::io::Write;
+use std::sync::{Arc, Mutex};
+use tracing_subscriber::fmt::MakeWriter;
+
+#[derive(Clone, Default)]
+struct CapturedWriter(Arc<Mutex<Vec<u8>>>);
+
+impl Write for CapturedWriter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+impl<'a> MakeWriter<'a> for CapturedWriter {
+    type Writer = CapturedWriter;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        self.clone()
+    }
+}
+
+#[test]
+#[serial]
+fn test_json_logging_emits_valid_json_lines() {
+    let writer = CapturedWriter::default();
+    tracing::subscriber::with_default(json_subscriber(writer.clone()), || {
+        let span = tracing::info_span!("cache_op", service = "logging_test");
+        let _guard = span.enter();
+        tracing::info!(key = "user:1", "cache hit");
+    });
+
+    let output = String::from_utf8(writer.0.lock().unwrap().clone()).unwrap();
+    let lines: Vec<serde_json::Value> = output
+        .lines()
+        .map(|line| serde_json::from_str(line).expect("log line is not valid JSON"))
+        .collect();
+    assert_eq!(lines.len(), 2, "unexpected output: {}", output);
+
+    let event = &lines[0];
+    assert_eq!(event["level"], "INFO");
+    assert_eq!(event["target"], "logging_test");
+    assert_eq!(event["fields"]["message"], "cache hit");
+    assert_eq!(event["fields"]["key"], "user:1");
+    assert_eq!(event["span"]["name"], "cache_op");
+    assert_eq!(event["span"]["service"], "logging_test");
+    let timestamp = event["timestamp"].as_str().unwrap();
+    assert!(chrono::DateTime::parse_from_rfc3339(timestamp).is_ok());
+
+    // span关闭事件同样以JSON输出
+    let close = &lines[1];
+    assert_eq!(close["fields"]["message"], "close");
+    assert_eq!(close["span"]["name"], "cache_op");
+}
+
+#[test]
+#[serial]
+fn test_log_filter_prefers_oxcache_log() {
+    std::env::set_var("OXCACHE_LOG", "warn");
+    std::env::set_var("RUST_LOG", "trace");
+    assert_eq!(log_filter().to_string(), "warn");
+    std::env::remove_var("OXCACHE_LOG");
+    assert_eq!(log_filter().to_string(), "trace");
+    std::env::remove_var("RUST_LOG");
+    assert_eq!(log_filter().to_string(), "debug");
+}
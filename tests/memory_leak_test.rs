@@ -201,6 +201,35 @@ async fn test_two_level_cache_memory_leak() {
     sleep(Duration::from_millis(100)).await;
 }
 
+#[tokio::test]
+async fn test_dropped_client_releases_l1() {
+    use oxcache::client::two_level::TwoLevelClient;
+    use oxcache::config::{L2Config, TwoLevelConfig};
+    use oxcache::serialization::{JsonSerializer, SerializerEnum};
+    use oxcache::utils::clock::MockClock;
+
+    let l1 = Arc::new(L1Backend::new(100));
+    let l1_weak = Arc::downgrade(&l1);
+    let l2 = Arc::new(L2Backend::in_memory(
+        &L2Config::default(),
+        Arc::new(MockClock::new()),
+    ));
+    let client = TwoLevelClient::new(
+        "memory_leak_test_dropped_client".to_string(),
+        TwoLevelConfig::default(),
+        l1,
+        l2,
+        SerializerEnum::Json(JsonSerializer::new()),
+    )
+    .await
+    .unwrap();
+
+    // 后台的L1指标采集任务只持有弱引用，未调用shutdown直接释放客户端时L1随之释放
+    drop(client);
+
+    assert!(l1_weak.upgrade().is_none());
+}
+
 #[tokio::test]
 async fn test_batch_operation_memory_leak() {
     if !is_redis_available().await {
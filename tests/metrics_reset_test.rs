@@ -0,0 +1,73 @@
+//! Copyright (c) 2025-2026, Kirky.X
+//!
+//! MIT License
+//!
+//! 指标重置测试
+
+use oxcache::metrics::{Metrics, GLOBAL_METRICS};
+
+#[test]
+fn test_snapshot_and_reset_keeps_previous_values() {
+    let metrics = Metrics::default();
+    metrics.record_request("reset_test", "L1", "get", "hit");
+    metrics.record_request("reset_test", "L1", "get", "hit");
+    metrics.record_request("reset_test", "L2", "get", "miss");
+    metrics.record_request("reset_test", "DB", "fallback", "attempt");
+    metrics.record_duration("reset_test", "L2", "get", 0.5);
+    metrics.set_l1_entries("reset_test", 42);
+
+    let snapshot = metrics.snapshot_and_reset();
+    assert_eq!(snapshot.l1_get_hits, 2);
+    assert_eq!(snapshot.l2_get_misses, 1);
+    assert_eq!(snapshot.total_operations, 3);
+    assert_eq!(
+        snapshot
+            .requests_total
+            .get("reset_test:DB:fallback:attempt"),
+        Some(&1)
+    );
+    assert_eq!(
+        snapshot.operation_duration.get("reset_test:L2:get"),
+        Some(&(0.5, 1))
+    );
+    assert_eq!(snapshot.l1_entries.get("reset_test"), Some(&42));
+
+    assert_eq!(metrics.get_counters(), (0, 0, 0, 0, 0, 0, 0, 0, 0));
+    assert!(metrics.requests_total.is_empty());
+    assert!(metrics.operation_duration.is_empty());
+    assert!(metrics.l1_entries.is_empty());
+
+    // 重置后继续从零开始计数
+    metrics.record_request("reset_test", "L1", "get", "hit");
+    assert_eq!(metrics.get_counters().0, 1);
+    metrics.reset();
+    assert_eq!(metrics.get_counters().0, 0);
+}
+
+#[test]
+fn test_reset_service_only_clears_that_service() {
+    GLOBAL_METRICS.record_request("reset_service_a", "DB", "fallback", "hit");
+    GLOBAL_METRICS.record_request("reset_service_b", "DB", "fallback", "hit");
+    GLOBAL_METRICS.record_l1_eviction("reset_service_a");
+    GLOBAL_METRICS.record_l1_eviction("reset_service_b");
+
+    GLOBAL_METRICS.reset_service("reset_service_a");
+
+    let requests = &GLOBAL_METRICS.requests_total;
+    assert!(requests.get("reset_service_a:DB:fallback:hit").is_none());
+    assert_eq!(
+        requests.get("reset_service_b:DB:fallback:hit").map(|v| *v),
+        Some(1)
+    );
+    assert!(GLOBAL_METRICS
+        .l1_evictions_total
+        .get("reset_service_a")
+        .is_none());
+    assert_eq!(
+        GLOBAL_METRICS
+            .l1_evictions_total
+            .get("reset_service_b")
+            .map(|v| *v),
+        Some(1)
+    );
+}
@@ -0,0 +1,45 @@
+//! Copyright (c) 2025-2026, Kirky.X
+//!
+//! MIT License
+//!
+//! 指标快照订阅测试
+
+use futures::StreamExt;
+use oxcache::metrics::{subscribe, GLOBAL_METRICS};
+use std::time::{Duration, Instant};
+
+#[tokio::test]
+async fn test_subscribe_emits_snapshots_reflecting_recorded_requests() {
+    let service = "metrics_stream_test";
+    let mut stream = Box::pin(subscribe(Duration::from_millis(100)));
+
+    let first = stream.next().await.unwrap();
+    assert_eq!(first.hit_ratio(service, "L1"), None);
+    assert_eq!(first.average_latency(service, "L2", "get"), None);
+
+    for _ in 0..3 {
+        GLOBAL_METRICS.record_request(service, "L1", "get", "hit");
+    }
+    GLOBAL_METRICS.record_request(service, "L1", "get", "miss");
+    GLOBAL_METRICS.record_request(service, "L2", "get", "hit");
+    GLOBAL_METRICS.record_duration(service, "L2", "get", 0.2);
+    GLOBAL_METRICS.record_duration(service, "L2", "get", 0.4);
+    GLOBAL_METRICS.set_promotion_queue_depth(service, 2);
+
+    let start = Instant::now();
+    let second = stream.next().await.unwrap();
+    // 第二个快照等到下一个周期才产生
+    assert!(start.elapsed() >= Duration::from_millis(50));
+    assert_eq!(second.hit_ratio(service, "L1"), Some(0.75));
+    assert_eq!(second.hit_ratio(service, "L2"), Some(1.0));
+    let latency = second.average_latency(service, "L2", "get").unwrap();
+    assert!((latency - 0.3).abs() < 1e-9);
+    assert_eq!(second.promotion_queue_depth.get(service), Some(&2));
+    assert!(second.l1_get_hits >= first.l1_get_hits + 3);
+
+    // 订阅只读取指标，不会重置
+    assert_eq!(
+        GLOBAL_METRICS.snapshot().hit_ratio(service, "L1"),
+        Some(0.75)
+    );
+}
@@ -0,0 +1,71 @@
+//! Copyright (c) 2025-2026, Kirky.X
+//!
+//! MIT License
+//!
+//! 命名后台任务测试
+//!
+//! 任务名称仅在启用 `tokio-console` 特性并以 `--cfg tokio_unstable` 编译时写入运行时，
+//! 此处验证名称格式与任务span，两种编译方式下均可运行
+
+use oxcache::utils::task::{spawn_named, task_name};
+use std::io::Write;
+use std::sync::{Arc, Mutex};
+use tracing_subscriber::fmt::MakeWriter;
+
+#[derive(Clone, Default)]
+struct CapturedWriter(Arc<Mutex<Vec<u8>>>);
+
+impl Write for CapturedWriter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+impl<'a> MakeWriter<'a> for CapturedWriter {
+    type Writer = CapturedWriter;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        self.clone()
+    }
+}
+
+#[test]
+fn test_task_name_format() {
+    assert_eq!(
+        task_name("batch-writer", "orders"),
+        "oxcache:batch-writer:orders"
+    );
+}
+
+#[tokio::test]
+async fn test_spawn_named_runs_task_in_span() {
+    let writer = CapturedWriter::default();
+    let subscriber = tracing_subscriber::fmt()
+        .json()
+        .with_max_level(tracing::Level::DEBUG)
+        .with_writer(writer.clone())
+        .finish();
+    let _default = tracing::subscriber::set_default(subscriber);
+
+    // 单线程运行时中任务在当前线程执行，使用线程局部的订阅者
+    let handle = spawn_named("health-checker", "named_task_test", async {
+        tracing::info!("probe");
+        42
+    });
+    assert_eq!(handle.await.unwrap(), 42);
+
+    let output = String::from_utf8(writer.0.lock().unwrap().clone()).unwrap();
+    let event: serde_json::Value = output
+        .lines()
+        .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap())
+        .find(|line| line["fields"]["message"] == "probe")
+        .expect("task event not logged");
+    assert_eq!(event["span"]["name"], "oxcache_task");
+    assert_eq!(event["span"]["task"], "health-checker");
+    assert_eq!(event["span"]["service"], "named_task_test");
+}
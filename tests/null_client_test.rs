@@ -0,0 +1,44 @@
+//! Copyright (c) 2025-2026, Kirky.X
+//!
+//! MIT License
+//!
+//! 禁用缓存服务测试
+
+use oxcache::client::null::NullClient;
+use oxcache::{get_client, CacheExt, CacheManager, Config};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+#[tokio::test]
+async fn test_disabled_service_never_stores() {
+    let config: Config = toml::from_str(
+        r#"
+        [services.disabled_svc]
+        cache_type = "disabled"
+        "#,
+    )
+    .expect("Failed to parse TOML");
+    CacheManager::init(config).await.expect("init failed");
+
+    let client = get_client("disabled_svc").unwrap();
+    assert!(client.as_any().downcast_ref::<NullClient>().is_some());
+
+    client.set("key", &"value", Some(60)).await.unwrap();
+    let value: Option<String> = client.get("key").await.unwrap();
+    assert!(value.is_none());
+    client.delete("key").await.unwrap();
+
+    // 每次调用都会重新计算
+    let calls = Arc::new(AtomicUsize::new(0));
+    for _ in 0..3 {
+        let calls = calls.clone();
+        let value: u64 = client
+            .cache_aside("computed", Some(60), || async move {
+                Ok(calls.fetch_add(1, Ordering::SeqCst) as u64)
+            })
+            .await
+            .unwrap();
+        assert!(value < 3);
+    }
+    assert_eq!(calls.load(Ordering::SeqCst), 3);
+}
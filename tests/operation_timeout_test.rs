@@ -0,0 +1,67 @@
+//! Copyright (c) 2025-2026, Kirky.X
+//!
+//! MIT License
+//!
+//! 单次操作超时测试
+
+use common::client_test_utils::{self, fake_redis_l2};
+use common::fake_redis::FakeRedis;
+use oxcache::backend::l1::L1Backend;
+use oxcache::client::two_level::TwoLevelClient;
+use oxcache::config::TwoLevelConfig;
+use oxcache::error::CacheError;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+mod common;
+
+async fn create_client(service_name: &str, l1: Arc<L1Backend>) -> TwoLevelClient {
+    client_test_utils::create_client(
+        service_name,
+        TwoLevelConfig::default(),
+        l1,
+        fake_redis_l2(&FakeRedis::start().await).await,
+    )
+    .await
+}
+
+#[tokio::test]
+async fn test_per_operation_timeout_triggers_on_slow_l2() {
+    let client = create_client("op_timeout_test", Arc::new(L1Backend::new(1000))).await;
+
+    // 快速操作在相同服务下正常完成
+    let fast = client
+        .get_bytes_with_timeout("op_timeout_test:fast", Duration::from_millis(500))
+        .await;
+    assert!(matches!(fast, Ok(None)));
+
+    // 慢操作超过单次超时时间后返回超时错误，而不是等待服务级超时
+    let start = Instant::now();
+    let slow = client
+        .get_bytes_with_timeout("op_timeout_test:slow", Duration::from_millis(100))
+        .await;
+    assert!(matches!(slow, Err(CacheError::Timeout(_))));
+    assert!(start.elapsed() < Duration::from_millis(1000));
+
+    client.shutdown().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_timed_out_read_falls_back_to_stale_l1() {
+    let l1 = Arc::new(L1Backend::new(1000));
+    let client = create_client("op_timeout_test_l1", l1.clone()).await;
+
+    // L1条目过期后读取会穿透到慢速L2，超时后返回L1中保留的过期值
+    l1.set_bytes("op_timeout_test_l1:slow", b"cached".to_vec(), Some(1))
+        .await
+        .unwrap();
+    tokio::time::sleep(Duration::from_millis(1100)).await;
+
+    let value = client
+        .get_bytes_with_timeout("op_timeout_test_l1:slow", Duration::from_millis(100))
+        .await
+        .unwrap();
+    assert_eq!(value, Some(b"cached".to_vec()));
+
+    client.shutdown().await.unwrap();
+}
@@ -0,0 +1,112 @@
+//! Copyright (c) 2025-2026, Kirky.X
+//!
+//! MIT License
+//!
+//! 暂停L2写入（维护模式）测试
+
+use oxcache::backend::l1::L1Backend;
+use oxcache::backend::l2::L2Backend;
+use oxcache::client::two_level::TwoLevelClient;
+use oxcache::client::CacheOps;
+use oxcache::config::{L2Config, TwoLevelConfig};
+use oxcache::metrics::GLOBAL_METRICS;
+use oxcache::recovery::wal::WalManager;
+use oxcache::serialization::{JsonSerializer, SerializerEnum};
+use oxcache::utils::clock::MockClock;
+use std::sync::Arc;
+
+#[tokio::test]
+async fn test_paused_writes_go_to_wal_and_replay_on_resume() {
+    let service = "pause_writes_test";
+    WalManager::new(service)
+        .await
+        .unwrap()
+        .clear()
+        .await
+        .unwrap();
+    let l2 = Arc::new(L2Backend::in_memory(
+        &L2Config::default(),
+        Arc::new(MockClock::new()),
+    ));
+    let client = TwoLevelClient::new(
+        service.to_string(),
+        TwoLevelConfig::default(),
+        Arc::new(L1Backend::new(100)),
+        l2.clone(),
+        SerializerEnum::Json(JsonSerializer::new()),
+    )
+    .await
+    .unwrap();
+
+    // 暂停前写入的键只存在于L2
+    l2.set_bytes("pause:existing", b"old".to_vec(), Some(60))
+        .await
+        .unwrap();
+    client
+        .set_bytes("pause:removed", b"gone".to_vec(), Some(60))
+        .await
+        .unwrap();
+
+    client.pause_l2_writes();
+    assert!(client.is_l2_writes_paused());
+    assert_eq!(
+        GLOBAL_METRICS.snapshot().l2_writes_paused.get(service),
+        Some(&1)
+    );
+
+    client
+        .set_bytes("pause:new", b"v1".to_vec(), Some(60))
+        .await
+        .unwrap();
+    client.delete("pause:removed").await.unwrap();
+
+    // 写入与删除没有到达L2，服务也没有被标记为降级
+    assert_eq!(l2.get_bytes("pause:new").await.unwrap(), None);
+    assert_eq!(
+        l2.get_bytes("pause:removed").await.unwrap(),
+        Some(b"gone".to_vec())
+    );
+    assert!(!client.is_degraded().await);
+
+    // GETSET需要L2原子地返回旧值，暂停期间无法经由WAL完成
+    assert!(client
+        .get_set("pause:existing", &"new".to_string(), Some(60))
+        .await
+        .is_err());
+
+    // 读取照常进行：新值来自L1，暂停前的值来自L2
+    assert_eq!(
+        client.get_bytes("pause:new").await.unwrap(),
+        Some(b"v1".to_vec())
+    );
+    assert_eq!(
+        client.get_bytes("pause:existing").await.unwrap(),
+        Some(b"old".to_vec())
+    );
+
+    // 恢复后重放暂停期间的写入与删除
+    let report = client.resume_l2_writes().await.unwrap();
+    assert_eq!(report.replayed, 2);
+    assert!(!client.is_l2_writes_paused());
+    assert_eq!(
+        GLOBAL_METRICS.snapshot().l2_writes_paused.get(service),
+        Some(&0)
+    );
+    assert_eq!(
+        l2.get_bytes("pause:new").await.unwrap(),
+        Some(b"v1".to_vec())
+    );
+    assert_eq!(l2.get_bytes("pause:removed").await.unwrap(), None);
+
+    // 恢复后直接写入L2
+    client
+        .set_bytes("pause:after", b"v2".to_vec(), Some(60))
+        .await
+        .unwrap();
+    assert_eq!(
+        l2.get_bytes("pause:after").await.unwrap(),
+        Some(b"v2".to_vec())
+    );
+
+    client.shutdown().await.unwrap();
+}
@@ -0,0 +1,75 @@
+//! Copyright (c) 2025-2026, Kirky.X
+//!
+//! MIT License
+//!
+//! 推广任务数上限测试
+
+use oxcache::backend::l1::L1Backend;
+use oxcache::backend::l2::L2Backend;
+use oxcache::client::two_level::TwoLevelClient;
+use oxcache::config::{L2Config, TwoLevelConfig};
+use oxcache::metrics::GLOBAL_METRICS;
+use oxcache::serialization::{JsonSerializer, SerializerEnum};
+use oxcache::utils::clock::MockClock;
+use std::sync::Arc;
+use std::time::Duration;
+
+#[tokio::test]
+async fn test_burst_of_l2_hits_respects_promotion_task_bound() {
+    let service = "promotion_limit_test";
+    let l2 = Arc::new(L2Backend::in_memory(
+        &L2Config::default(),
+        Arc::new(MockClock::new()),
+    ));
+    let client = TwoLevelClient::new(
+        service.to_string(),
+        TwoLevelConfig {
+            max_promotion_tasks: Some(2),
+            ..Default::default()
+        },
+        Arc::new(L1Backend::new(1000)),
+        l2.clone(),
+        SerializerEnum::Json(JsonSerializer::new()),
+    )
+    .await
+    .unwrap();
+
+    let keys: Vec<String> = (0..50).map(|i| format!("promotion_limit:{}", i)).collect();
+    for key in &keys {
+        l2.set_bytes(key, b"v".to_vec(), Some(60)).await.unwrap();
+    }
+
+    // 一次批量读取的所有L2命中同时触发推广，超出上限的推广被丢弃
+    let keys: Vec<&str> = keys.iter().map(String::as_str).collect();
+    let values = client.get_many_bytes(&keys).await.unwrap();
+    assert!(values.values().all(Option::is_some));
+
+    let stats = client.promotion_stats().unwrap();
+    assert_eq!(stats.active_tasks, 2);
+    assert_eq!(stats.promotions_dropped_total, 48);
+    assert_eq!(
+        GLOBAL_METRICS
+            .promotions_dropped_total
+            .get(service)
+            .map(|v| *v),
+        Some(48)
+    );
+
+    for _ in 0..100 {
+        if client.promotion_stats().unwrap().active_tasks == 0 {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    let stats = client.promotion_stats().unwrap();
+    assert_eq!(stats.active_tasks, 0);
+    assert_eq!(stats.peak_active_tasks, 2);
+    assert_eq!(stats.promotions_total, 2);
+    assert_eq!(
+        GLOBAL_METRICS
+            .promotion_active_tasks
+            .get(service)
+            .map(|v| *v),
+        Some(0)
+    );
+}
@@ -0,0 +1,80 @@
+//! Copyright (c) 2025-2026, Kirky.X
+//!
+//! MIT License
+//!
+//! 推广统计测试
+
+use common::fake_redis::FakeRedis;
+use oxcache::backend::l1::L1Backend;
+use oxcache::backend::l2::L2Backend;
+use oxcache::config::L2Config;
+use oxcache::metrics::GLOBAL_METRICS;
+use oxcache::recovery::health::HealthState;
+use oxcache::sync::promotion::{PromotionManager, PromotionStats};
+use secrecy::SecretString;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+mod common;
+
+#[tokio::test]
+async fn test_promotion_stats_count_promoted_and_skipped() {
+    let l2_config = L2Config {
+        connection_string: SecretString::from(FakeRedis::start().await.url),
+        ..Default::default()
+    };
+    let l1 = Arc::new(L1Backend::new(1000));
+    let l2 = Arc::new(L2Backend::new(&l2_config).await.unwrap());
+    let mgr = PromotionManager::new(l1.clone(), l2, Arc::new(RwLock::new(HealthState::Healthy)))
+        .with_service_name("promotion_stats_test".to_string());
+
+    // 首次L2命中推广到L1
+    mgr.promote("promotion_stats_test:key".to_string(), b"v".to_vec(), 1)
+        .await
+        .unwrap();
+    assert_eq!(
+        l1.get_bytes("promotion_stats_test:key").await.unwrap(),
+        Some(b"v".to_vec())
+    );
+    assert_eq!(
+        mgr.stats(),
+        PromotionStats {
+            promotions_total: 1,
+            promotions_skipped_total: 0,
+            queue_depth: 0,
+            promotions_dropped_total: 0,
+            active_tasks: 0,
+            peak_active_tasks: 0,
+        }
+    );
+
+    // 已推广的键再次命中时跳过
+    mgr.promote("promotion_stats_test:key".to_string(), b"v".to_vec(), 1)
+        .await
+        .unwrap();
+    assert_eq!(mgr.stats().promotions_total, 1);
+    assert_eq!(mgr.stats().promotions_skipped_total, 1);
+
+    let metrics = &*GLOBAL_METRICS;
+    assert_eq!(
+        metrics
+            .promotions_total
+            .get("promotion_stats_test")
+            .map(|v| *v),
+        Some(1)
+    );
+    assert_eq!(
+        metrics
+            .promotions_skipped_total
+            .get("promotion_stats_test")
+            .map(|v| *v),
+        Some(1)
+    );
+    assert_eq!(
+        metrics
+            .promotion_queue_depth
+            .get("promotion_stats_test")
+            .map(|v| *v),
+        Some(0)
+    );
+}
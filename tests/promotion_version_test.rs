@@ -0,0 +1,102 @@
+//! Copyright (c) 2025-2026, Kirky.X
+//!
+//! MIT License
+//!
+//! 推广到L1的条目保留L2版本号测试
+
+use oxcache::backend::l1::L1Backend;
+use oxcache::backend::l2::L2Backend;
+use oxcache::client::two_level::TwoLevelClient;
+use oxcache::client::CacheOps;
+use oxcache::config::{L2Config, TwoLevelConfig};
+use oxcache::serialization::{JsonSerializer, SerializerEnum};
+use oxcache::utils::clock::MockClock;
+use std::sync::Arc;
+use std::time::Duration;
+
+#[tokio::test]
+async fn test_promoted_l1_entry_carries_l2_version() {
+    let l1 = Arc::new(L1Backend::new(100));
+    let l2 = Arc::new(L2Backend::in_memory(
+        &L2Config::default(),
+        Arc::new(MockClock::new()),
+    ));
+    let client = TwoLevelClient::new(
+        "promotion_version_test".to_string(),
+        TwoLevelConfig::default(),
+        l1.clone(),
+        l2.clone(),
+        SerializerEnum::Json(JsonSerializer::new()),
+    )
+    .await
+    .unwrap();
+    let key = "promotion_version_test:user:1";
+
+    // 多次写入L2使版本号大于1
+    for value in [b"v1", b"v2", b"v3"] {
+        l2.set_with_version(key, value.to_vec(), Some(60))
+            .await
+            .unwrap();
+    }
+    let (_, l2_version) = l2.get_with_version(key).await.unwrap().unwrap();
+    assert!(l2_version > 1);
+
+    assert_eq!(client.get_bytes(key).await.unwrap(), Some(b"v3".to_vec()));
+
+    // 推广在后台执行
+    let mut promoted = None;
+    for _ in 0..100 {
+        promoted = l1.get_with_metadata(key).await.unwrap();
+        if promoted.is_some() {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    assert_eq!(promoted, Some((b"v3".to_vec(), l2_version)));
+
+    client.shutdown().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_get_many_promotion_carries_l2_version() {
+    let l1 = Arc::new(L1Backend::new(100));
+    let l2 = Arc::new(L2Backend::in_memory(
+        &L2Config::default(),
+        Arc::new(MockClock::new()),
+    ));
+    let client = TwoLevelClient::new(
+        "promotion_version_many_test".to_string(),
+        TwoLevelConfig::default(),
+        l1.clone(),
+        l2.clone(),
+        SerializerEnum::Json(JsonSerializer::new()),
+    )
+    .await
+    .unwrap();
+    let key = "promotion_version_many_test:user:1";
+    let missing = "promotion_version_many_test:user:2";
+
+    for value in [b"v1", b"v2"] {
+        l2.set_with_version(key, value.to_vec(), Some(60))
+            .await
+            .unwrap();
+    }
+    let (_, l2_version) = l2.get_with_version(key).await.unwrap().unwrap();
+    assert!(l2_version > 1);
+
+    let values = client.get_many_bytes(&[key, missing]).await.unwrap();
+    assert_eq!(values[key], Some(b"v2".to_vec()));
+    assert_eq!(values[missing], None);
+
+    let mut promoted = None;
+    for _ in 0..100 {
+        promoted = l1.get_with_metadata(key).await.unwrap();
+        if promoted.is_some() {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    assert_eq!(promoted, Some((b"v2".to_vec(), l2_version)));
+
+    client.shutdown().await.unwrap();
+}
@@ -0,0 +1,138 @@
+//! Copyright (c) 2025-2026, Kirky.X
+//!
+//! MIT License
+//!
+//! L2恢复期间自适应TTL测试
+
+use oxcache::backend::l1::L1Backend;
+use oxcache::backend::l2::L2Backend;
+use oxcache::client::two_level::{PrimeLayer, TwoLevelClient};
+use oxcache::client::CacheOps;
+use oxcache::config::{Config, L2Config, ServiceConfig, TwoLevelConfig};
+use oxcache::recovery::health::HealthState;
+use oxcache::serialization::{JsonSerializer, SerializerEnum};
+use oxcache::utils::clock::MockClock;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+#[tokio::test]
+async fn test_recovering_state_extends_ttl() {
+    let l2 = Arc::new(L2Backend::in_memory(
+        &L2Config::default(),
+        Arc::new(MockClock::new()),
+    ));
+    let client = TwoLevelClient::new(
+        "recovering_ttl_test".to_string(),
+        TwoLevelConfig {
+            recovering_ttl_factor: Some(3.0),
+            ..Default::default()
+        },
+        Arc::new(L1Backend::new(100)),
+        l2.clone(),
+        SerializerEnum::Json(JsonSerializer::new()),
+    )
+    .await
+    .unwrap();
+
+    client
+        .set_health_state(HealthState::Recovering {
+            since: Instant::now(),
+            success_count: 1,
+        })
+        .await;
+    client
+        .set_bytes("recovering_ttl:a", b"a".to_vec(), Some(60))
+        .await
+        .unwrap();
+    assert_eq!(l2.ttl("recovering_ttl:a").await.unwrap(), Some(180));
+
+    // 恢复为健康状态后使用原TTL
+    client.set_health_state(HealthState::Healthy).await;
+    client
+        .set_bytes("recovering_ttl:b", b"b".to_vec(), Some(60))
+        .await
+        .unwrap();
+    assert_eq!(l2.ttl("recovering_ttl:b").await.unwrap(), Some(60));
+}
+
+#[tokio::test]
+async fn test_recovering_ttl_uses_configured_l2_default_ttl() {
+    let l2 = Arc::new(L2Backend::in_memory(
+        &L2Config {
+            default_ttl: Some(100),
+            ..Default::default()
+        },
+        Arc::new(MockClock::new()),
+    ));
+    let client = TwoLevelClient::new(
+        "recovering_ttl_default_test".to_string(),
+        TwoLevelConfig {
+            recovering_ttl_factor: Some(2.0),
+            ..Default::default()
+        },
+        Arc::new(L1Backend::new(100)),
+        l2.clone(),
+        SerializerEnum::Json(JsonSerializer::new()),
+    )
+    .await
+    .unwrap();
+
+    client
+        .set_health_state(HealthState::Recovering {
+            since: Instant::now(),
+            success_count: 1,
+        })
+        .await;
+
+    // 未指定TTL时以L2配置的默认TTL为基准放大
+    client
+        .set_bytes("recovering_ttl_default:a", b"a".to_vec(), None)
+        .await
+        .unwrap();
+    assert_eq!(l2.ttl("recovering_ttl_default:a").await.unwrap(), Some(200));
+
+    // 毫秒级TTL与预热写入同样放大
+    client
+        .set_bytes_ms(
+            "recovering_ttl_default:b",
+            b"b".to_vec(),
+            Duration::from_millis(1500),
+        )
+        .await
+        .unwrap();
+    assert_eq!(
+        l2.pttl("recovering_ttl_default:b").await.unwrap(),
+        Some(3000)
+    );
+    client
+        .prime(
+            "recovering_ttl_default:c",
+            &"c",
+            Some(30),
+            PrimeLayer::Both,
+            false,
+        )
+        .await
+        .unwrap();
+    assert_eq!(l2.ttl("recovering_ttl_default:c").await.unwrap(), Some(60));
+}
+
+#[test]
+fn test_recovering_ttl_factor_validation() {
+    let service = ServiceConfig::builder()
+        .l2_standalone("redis://127.0.0.1:6379")
+        .two_level_config(TwoLevelConfig {
+            recovering_ttl_factor: Some(0.5),
+            ..Default::default()
+        })
+        .build()
+        .unwrap();
+    let config = Config {
+        config_version: None,
+        global: Default::default(),
+        services: HashMap::from([("recovering_ttl_svc".to_string(), service)]),
+    };
+    let err = config.validate().unwrap_err();
+    assert!(err.contains("recovering_ttl_factor"));
+}
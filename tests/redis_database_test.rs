@@ -0,0 +1,75 @@
+//! Copyright (c) 2025-2026, Kirky.X
+//!
+//! MIT License
+//!
+//! Redis数据库编号隔离测试
+
+use common::fake_redis::FakeRedis;
+use oxcache::backend::l1::L1Backend;
+use oxcache::backend::l2::L2Backend;
+use oxcache::client::two_level::TwoLevelClient;
+use oxcache::client::CacheOps;
+use oxcache::config::{ClusterConfig, L2Config, RedisMode, TwoLevelConfig};
+use oxcache::error::CacheError;
+use oxcache::serialization::{JsonSerializer, SerializerEnum};
+use secrecy::SecretString;
+use std::sync::Arc;
+
+mod common;
+
+async fn create_client(service_name: &str, url: &str, database: u8) -> TwoLevelClient {
+    let l2_config = L2Config {
+        connection_string: SecretString::from(url.to_string()),
+        database: Some(database),
+        ..Default::default()
+    };
+    let l2 = Arc::new(L2Backend::new(&l2_config).await.unwrap());
+    TwoLevelClient::new(
+        service_name.to_string(),
+        TwoLevelConfig::default(),
+        Arc::new(L1Backend::new(1000)),
+        l2,
+        SerializerEnum::Json(JsonSerializer::new()),
+    )
+    .await
+    .unwrap()
+}
+
+#[tokio::test]
+async fn test_services_on_different_databases_are_isolated() {
+    let redis = FakeRedis::start().await;
+    let first = create_client("db_first_test", &redis.url, 1).await;
+    let second = create_client("db_second_test", &redis.url, 2).await;
+
+    first
+        .hset_bytes("shared:key", "f", b"one".to_vec(), None)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        first.hget_bytes("shared:key", "f").await.unwrap(),
+        Some(b"one".to_vec())
+    );
+    assert_eq!(second.hget_bytes("shared:key", "f").await.unwrap(), None);
+    assert_eq!(first.l2_dbsize().await.unwrap(), 1);
+    assert_eq!(second.l2_dbsize().await.unwrap(), 0);
+
+    first.shutdown().await.unwrap();
+    second.shutdown().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_database_rejected_in_cluster_mode() {
+    let config = L2Config {
+        mode: RedisMode::Cluster,
+        cluster: Some(ClusterConfig {
+            nodes: vec!["redis://127.0.0.1:7000".to_string()],
+        }),
+        database: Some(1),
+        ..Default::default()
+    };
+    assert!(matches!(
+        L2Backend::new(&config).await,
+        Err(CacheError::Configuration(_))
+    ));
+}
@@ -59,6 +59,7 @@ pub fn create_standalone_config() -> L2Config {
         default_ttl: Some(3600),
         max_key_length: 256,
         max_value_size: 1024 * 1024 * 10,
+        ..Default::default()
     }
 }
 
@@ -84,6 +85,7 @@ pub fn create_cluster_config() -> L2Config {
         default_ttl: Some(3600),
         max_key_length: 256,
         max_value_size: 1024 * 1024 * 10,
+        ..Default::default()
     }
 }
 
@@ -102,11 +104,14 @@ pub fn create_sentinel_config() -> L2Config {
                 "127.0.0.1:26380".to_string(),
                 "127.0.0.1:26381".to_string(),
             ],
+            sentinel_password: None,
+            master_discovery_timeout_ms: None,
         }),
         cluster: None,
         default_ttl: Some(3600),
         max_key_length: 256,
         max_value_size: 1024 * 1024 * 10,
+        ..Default::default()
     }
 }
 
@@ -0,0 +1,132 @@
+//! Copyright (c) 2025-2026, Kirky.X
+//!
+//! MIT License
+//!
+//! 基于sea-orm的数据库加载器测试（SQLite）
+
+use oxcache::backend::{l1::L1Backend, l2::L2Backend};
+use oxcache::client::db_loader::{DbFallbackManager, DbLoader, SeaOrmDbLoader};
+use oxcache::client::two_level::TwoLevelClient;
+use oxcache::config::{L2Config, TwoLevelConfig};
+use oxcache::serialization::{JsonSerializer, SerializerEnum};
+use oxcache::utils::clock::MockClock;
+use sea_orm::{ConnectOptions, ConnectionTrait, Database, DatabaseConnection, Statement};
+use serde::Deserialize;
+use std::sync::Arc;
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct Profile {
+    name: String,
+}
+
+/// 创建包含用户表的内存SQLite数据库
+async fn create_database() -> Arc<DatabaseConnection> {
+    let mut options = ConnectOptions::new("sqlite::memory:".to_string());
+    // 内存数据库按连接隔离，只使用一个连接
+    options.max_connections(1).min_connections(1);
+    let db = Database::connect(options).await.unwrap();
+    for sql in [
+        "CREATE TABLE users (id INTEGER PRIMARY KEY, profile TEXT)",
+        r#"INSERT INTO users (id, profile) VALUES (1, '{"name":"alice"}'), (2, NULL)"#,
+    ] {
+        db.execute(Statement::from_string(
+            sea_orm::DatabaseBackend::Sqlite,
+            sql.to_string(),
+        ))
+        .await
+        .unwrap();
+    }
+    Arc::new(db)
+}
+
+/// `user:<id>` 形式的键映射为整数主键
+fn create_loader(db: Arc<DatabaseConnection>) -> SeaOrmDbLoader {
+    SeaOrmDbLoader::new(
+        db,
+        "users".to_string(),
+        "id".to_string(),
+        "profile".to_string(),
+        |key| {
+            key.strip_prefix("user:")?
+                .parse::<i64>()
+                .ok()
+                .map(Into::into)
+        },
+    )
+    .unwrap()
+}
+
+#[tokio::test]
+async fn test_get_miss_loads_row_and_caches_it() {
+    let db = create_database().await;
+    let mut client = TwoLevelClient::new(
+        "sea_orm_loader_test".to_string(),
+        TwoLevelConfig::default(),
+        Arc::new(L1Backend::new(100)),
+        Arc::new(L2Backend::in_memory(
+            &L2Config::default(),
+            Arc::new(MockClock::new()),
+        )),
+        SerializerEnum::Json(JsonSerializer::new()),
+    )
+    .await
+    .unwrap();
+    client.set_db_fallback_manager(Arc::new(DbFallbackManager::new(
+        Arc::new(create_loader(db.clone())),
+        true,
+        1000,
+        0,
+    )));
+
+    let profile: Option<Profile> = client.get("user:1").await.unwrap();
+    assert_eq!(
+        profile,
+        Some(Profile {
+            name: "alice".to_string()
+        })
+    );
+
+    // 加载的值已回写缓存，删除数据库中的行后仍能命中
+    db.execute(Statement::from_string(
+        sea_orm::DatabaseBackend::Sqlite,
+        "DELETE FROM users WHERE id = 1".to_string(),
+    ))
+    .await
+    .unwrap();
+    let cached: Option<Profile> = client.get_l2_only("user:1").await.unwrap();
+    assert_eq!(cached.map(|p| p.name), Some("alice".to_string()));
+    let cached: Option<Profile> = client.get("user:1").await.unwrap();
+    assert_eq!(cached.map(|p| p.name), Some("alice".to_string()));
+}
+
+#[tokio::test]
+async fn test_not_found_returns_none() {
+    let loader = create_loader(create_database().await);
+
+    // 行不存在、值为NULL、键无法映射为主键都视为未命中
+    assert_eq!(loader.load("user:42").await.unwrap(), None);
+    assert_eq!(loader.load("user:2").await.unwrap(), None);
+    assert_eq!(loader.load("order:1").await.unwrap(), None);
+
+    let loaded = loader
+        .load_batch(vec!["user:1".to_string(), "user:42".to_string()])
+        .await
+        .unwrap();
+    assert_eq!(
+        loaded,
+        vec![("user:1".to_string(), br#"{"name":"alice"}"#.to_vec())]
+    );
+}
+
+#[tokio::test]
+async fn test_rejects_invalid_identifiers() {
+    let db = create_database().await;
+    let result = SeaOrmDbLoader::new(
+        db,
+        "users; DROP TABLE users".to_string(),
+        "id".to_string(),
+        "profile".to_string(),
+        |_| None,
+    );
+    assert!(result.is_err());
+}
@@ -0,0 +1,172 @@
+//! Copyright (c) 2025-2026, Kirky.X
+//!
+//! MIT License
+//!
+//! 服务级序列化器配置与读取回退测试
+
+use common::client_test_utils::{create_client_with_serializer, in_memory_l2};
+use oxcache::backend::l1::L1Backend;
+use oxcache::config::TwoLevelConfig;
+use oxcache::error::CacheError;
+use oxcache::serialization::{CborSerializer, FallbackSerializer, JsonSerializer, SerializerEnum};
+use oxcache::{get_client, CacheExt, CacheManager, Config};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+mod common;
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+struct User {
+    id: u64,
+    name: String,
+}
+
+#[tokio::test]
+async fn test_services_use_configured_serializers() {
+    let config: Config = toml::from_str(
+        r#"
+        [global]
+        default_ttl = 300
+        health_check_interval = 5
+        serialization = "json"
+        enable_metrics = false
+
+        [services.serializer_json_svc]
+        cache_type = "l1"
+
+        [services.serializer_json_svc.l1]
+        max_capacity = 1000
+
+        [services.serializer_cbor_svc]
+        cache_type = "l1"
+        serialization = "cbor"
+
+        [services.serializer_cbor_svc.l1]
+        max_capacity = 1000
+        "#,
+    )
+    .expect("Failed to parse TOML");
+
+    CacheManager::init(config).await.expect("init failed");
+
+    let user = User {
+        id: 1,
+        name: "alice".to_string(),
+    };
+
+    let json_client = get_client("serializer_json_svc").unwrap();
+    assert!(matches!(json_client.serializer(), SerializerEnum::Json(_)));
+    json_client.set("user:1", &user, None).await.unwrap();
+    let got: Option<User> = json_client.get("user:1").await.unwrap();
+    assert_eq!(got, Some(user.clone()));
+
+    let cbor_client = get_client("serializer_cbor_svc").unwrap();
+    assert!(matches!(cbor_client.serializer(), SerializerEnum::Cbor(_)));
+    cbor_client.set("user:1", &user, None).await.unwrap();
+    let got: Option<User> = cbor_client.get("user:1").await.unwrap();
+    assert_eq!(got, Some(user));
+}
+
+#[tokio::test]
+async fn test_unknown_serializer_fails_init() {
+    let config: Config = toml::from_str(
+        r#"
+        [services.serializer_unknown_svc]
+        cache_type = "l1"
+        serialization = "xml"
+
+        [services.serializer_unknown_svc.l1]
+        max_capacity = 1000
+        "#,
+    )
+    .expect("Failed to parse TOML");
+
+    match CacheManager::init(config).await {
+        Err(CacheError::Configuration(msg)) => assert!(msg.contains("xml")),
+        other => panic!("expected Configuration error, got {:?}", other.err()),
+    }
+    assert!(get_client("serializer_unknown_svc").is_err());
+}
+
+#[tokio::test]
+async fn test_old_json_value_reads_through_fallback() {
+    let l2 = in_memory_l2();
+    let user = User {
+        id: 1,
+        name: "alice".to_string(),
+    };
+
+    let json_client = create_client_with_serializer(
+        "serializer_fallback_test",
+        TwoLevelConfig::default(),
+        Arc::new(L1Backend::new(100)),
+        l2.clone(),
+        SerializerEnum::Json(JsonSerializer::new()),
+    )
+    .await;
+    json_client.set("user:1", &user, Some(60)).await.unwrap();
+
+    // 只使用CBOR时无法读取JSON写入的值
+    let cbor_client = create_client_with_serializer(
+        "serializer_fallback_test",
+        TwoLevelConfig::default(),
+        Arc::new(L1Backend::new(100)),
+        l2.clone(),
+        SerializerEnum::Cbor(CborSerializer::new()),
+    )
+    .await;
+    let result = cbor_client.get::<User>("user:1").await;
+    assert!(matches!(result, Err(CacheError::Serialization(_))));
+
+    let migrated_client = create_client_with_serializer(
+        "serializer_fallback_test",
+        TwoLevelConfig::default(),
+        Arc::new(L1Backend::new(100)),
+        l2.clone(),
+        SerializerEnum::WithFallback(FallbackSerializer::new(
+            SerializerEnum::Cbor(CborSerializer::new()),
+            vec![SerializerEnum::Json(JsonSerializer::new())],
+        )),
+    )
+    .await;
+    let got: Option<User> = migrated_client.get("user:1").await.unwrap();
+    assert_eq!(got, Some(user.clone()));
+
+    // 写入使用主序列化器
+    migrated_client
+        .set("user:2", &user, Some(60))
+        .await
+        .unwrap();
+    let got: Option<User> = cbor_client.get("user:2").await.unwrap();
+    assert_eq!(got, Some(user));
+}
+
+#[tokio::test]
+async fn test_read_fallback_serializers_from_config() {
+    let config: Config = toml::from_str(
+        r#"
+        [services.serializer_fallback_svc]
+        cache_type = "l1"
+        serialization = "cbor"
+        read_fallback_serializers = ["json"]
+
+        [services.serializer_fallback_svc.l1]
+        max_capacity = 1000
+        "#,
+    )
+    .expect("Failed to parse TOML");
+    CacheManager::init(config).await.expect("init failed");
+
+    let client = get_client("serializer_fallback_svc").unwrap();
+    let SerializerEnum::WithFallback(serializer) = client.serializer() else {
+        panic!("expected a serializer with read fallback");
+    };
+    assert!(matches!(serializer.primary(), SerializerEnum::Cbor(_)));
+    assert!(matches!(serializer.fallbacks(), [SerializerEnum::Json(_)]));
+
+    // JSON写入的字节经回退读取
+    let json = serde_json::to_vec(&"legacy").unwrap();
+    client.set_bytes("legacy:1", json, None).await.unwrap();
+    let got: Option<String> = client.get("legacy:1").await.unwrap();
+    assert_eq!(got, Some("legacy".to_string()));
+}
@@ -0,0 +1,143 @@
+//! Copyright (c) 2025-2026, Kirky.X
+//!
+//! MIT License
+//!
+//! 客户端一致性哈希分片测试
+
+use common::fake_redis::FakeRedis;
+use oxcache::backend::l2::L2Backend;
+use oxcache::backend::sharding::HashRing;
+use oxcache::config::{L2Config, RedisMode, ShardedConfig, DEFAULT_VIRTUAL_NODES};
+use secrecy::SecretString;
+
+mod common;
+
+async fn start_nodes(count: usize) -> Vec<FakeRedis> {
+    let mut nodes = Vec::with_capacity(count);
+    for _ in 0..count {
+        nodes.push(FakeRedis::start().await);
+    }
+    nodes
+}
+
+fn sharded_config(nodes: &[FakeRedis]) -> L2Config {
+    L2Config {
+        mode: RedisMode::Sharded,
+        sharded: Some(ShardedConfig {
+            nodes: nodes.iter().map(|node| node.url.clone()).collect(),
+            virtual_nodes: DEFAULT_VIRTUAL_NODES,
+        }),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_hash_ring_distributes_keys_and_colocates_version_keys() {
+    let nodes: Vec<String> = (0..3).map(|i| format!("redis://node{}:6379", i)).collect();
+    let ring = HashRing::new(&nodes, DEFAULT_VIRTUAL_NODES);
+    assert_eq!(ring.node_count(), 3);
+
+    let mut counts = [0usize; 3];
+    for i in 0..3000 {
+        let key = format!("sharding_test:{}", i);
+        let node = ring.node_for(&key);
+        counts[node] += 1;
+        assert_eq!(ring.node_for(&format!("{}:version", key)), node);
+        assert_eq!(ring.node_for(&key), node);
+    }
+    // 160个虚拟节点下每个节点应分得大致三分之一的键
+    for count in counts {
+        assert!((600..=1400).contains(&count), "unbalanced: {:?}", counts);
+    }
+
+    // 增加节点只迁移部分键
+    let mut grown = nodes.clone();
+    grown.push("redis://node3:6379".to_string());
+    let grown_ring = HashRing::new(&grown, DEFAULT_VIRTUAL_NODES);
+    let moved = (0..3000)
+        .map(|i| format!("sharding_test:{}", i))
+        .filter(|key| ring.node_for(key) != grown_ring.node_for(key))
+        .count();
+    assert!(moved < 1500, "too many keys moved: {}", moved);
+}
+
+#[tokio::test]
+async fn test_sharded_backend_distributes_and_round_trips() {
+    let nodes = start_nodes(3).await;
+    let backend = L2Backend::new(&sharded_config(&nodes)).await.unwrap();
+
+    for i in 0..60 {
+        let key = format!("sharding_test:{}", i);
+        backend
+            .hset(&key, "value", format!("v{}", i).into_bytes(), Some(60))
+            .await
+            .unwrap();
+    }
+    for i in 0..60 {
+        let key = format!("sharding_test:{}", i);
+        assert_eq!(
+            backend.hget(&key, "value").await.unwrap(),
+            Some(format!("v{}", i).into_bytes())
+        );
+    }
+
+    // 每个节点都分到了键，总数等于写入的键数
+    let mut total = 0;
+    for node in &nodes {
+        let single = L2Backend::new(&L2Config {
+            connection_string: SecretString::from(node.url.clone()),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+        let size = single.dbsize().await.unwrap();
+        assert!(size > 0, "node {} received no keys", node.url);
+        total += size;
+    }
+    assert_eq!(total, 60);
+    assert_eq!(backend.dbsize().await.unwrap(), 60);
+    assert_eq!(
+        backend.scan_keys("sharding_test:*").await.unwrap().len(),
+        60
+    );
+    backend.ping().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_sharded_batch_operations_group_by_node() {
+    let nodes = start_nodes(3).await;
+    let config = sharded_config(&nodes);
+    let backend = L2Backend::new(&config).await.unwrap();
+    let ring = HashRing::new(
+        &config.sharded.as_ref().unwrap().nodes,
+        DEFAULT_VIRTUAL_NODES,
+    );
+
+    let keys: Vec<String> = (0..30).map(|i| format!("sharding_batch:{}", i)).collect();
+    backend
+        .pipeline_set_batch(
+            keys.iter()
+                .map(|key| (key.clone(), b"v".to_vec(), Some(60)))
+                .collect(),
+        )
+        .await
+        .unwrap();
+    backend.pipeline_del_batch(keys.clone()).await.unwrap();
+
+    for (index, node) in nodes.iter().enumerate() {
+        let log = node.log.lock().unwrap();
+        let touched: Vec<&String> = log
+            .iter()
+            .filter(|args| matches!(args[0].as_str(), "SET" | "DEL"))
+            .map(|args| &args[1])
+            .collect();
+        assert!(
+            !touched.is_empty(),
+            "node {} received no batch commands",
+            index
+        );
+        for key in touched {
+            assert_eq!(ring.node_for(key), index, "key {} sent to wrong node", key);
+        }
+    }
+}
@@ -0,0 +1,80 @@
+//! Copyright (c) 2025-2026, Kirky.X
+//!
+//! MIT License
+//!
+//! 关闭守卫测试
+
+use common::client_test_utils::{self, fake_redis_l2};
+use common::fake_redis::FakeRedis;
+use oxcache::backend::l1::L1Backend;
+use oxcache::client::two_level::TwoLevelClient;
+use oxcache::client::CacheOps;
+use oxcache::config::TwoLevelConfig;
+use oxcache::ShutdownGuard;
+use std::sync::Arc;
+use std::time::Duration;
+
+mod common;
+
+async fn create_client(service: &str) -> Arc<TwoLevelClient> {
+    let client = client_test_utils::create_client(
+        service,
+        TwoLevelConfig {
+            enable_batch_write: true,
+            ..Default::default()
+        },
+        Arc::new(L1Backend::new(100)),
+        fake_redis_l2(&FakeRedis::start().await).await,
+    )
+    .await;
+    Arc::new(client)
+}
+
+/// 等待后台任务全部结束
+async fn wait_for_tasks_stopped(client: &TwoLevelClient) -> bool {
+    for _ in 0..100 {
+        if client.running_background_tasks() == 0 {
+            return true;
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+    false
+}
+
+#[tokio::test]
+async fn test_dropping_guard_stops_background_tasks() {
+    let client = create_client("shutdown_guard_test_drop").await;
+    assert!(client.running_background_tasks() > 0);
+
+    let guard = ShutdownGuard::for_client(
+        "shutdown_guard_test_drop",
+        client.clone() as Arc<dyn CacheOps>,
+    );
+    drop(guard);
+
+    assert!(
+        wait_for_tasks_stopped(&client).await,
+        "background tasks still running: {}",
+        client.running_background_tasks()
+    );
+}
+
+#[tokio::test]
+async fn test_guard_shutdown_is_graceful() {
+    let client = create_client("shutdown_guard_test_graceful").await;
+    client
+        .set_bytes("shutdown_guard_test_graceful:key", b"v".to_vec(), Some(60))
+        .await
+        .unwrap();
+    assert!(client.running_background_tasks() > 0);
+
+    ShutdownGuard::for_client(
+        "shutdown_guard_test_graceful",
+        client.clone() as Arc<dyn CacheOps>,
+    )
+    .shutdown()
+    .await
+    .unwrap();
+
+    assert!(wait_for_tasks_stopped(&client).await);
+}
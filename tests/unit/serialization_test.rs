@@ -4,7 +4,7 @@
 //!
 //! 序列化单元测试
 
-use oxcache::serialization::{json::JsonSerializer, Serializer};
+use oxcache::serialization::{json::JsonSerializer, CborSerializer, Serializer};
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, PartialEq, Debug)]
@@ -49,3 +49,21 @@ fn test_json_serializer_with_compression() {
 
     assert_eq!(data, deserialized);
 }
+
+/// 测试CBOR序列化器的往返操作
+///
+/// 验证数据能否被正确序列化为CBOR格式并成功反序列化回原始数据
+#[test]
+fn test_cbor_serializer_round_trip() {
+    let serializer = CborSerializer::new();
+    let data = TestStruct {
+        id: 7,
+        name: "cbor".to_string(),
+        tags: vec!["go".into(), "rust".into()],
+    };
+
+    let bytes = serializer.serialize(&data).unwrap();
+    let deserialized: TestStruct = serializer.deserialize(&bytes).unwrap();
+
+    assert_eq!(data, deserialized);
+}
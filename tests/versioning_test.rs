@@ -0,0 +1,105 @@
+//! Copyright (c) 2025-2026, Kirky.X
+//!
+//! MIT License
+//!
+//! 版本键开关测试
+
+use common::fake_redis::FakeRedis;
+use oxcache::backend::l1::L1Backend;
+use oxcache::backend::l2::L2Backend;
+use oxcache::client::two_level::TwoLevelClient;
+use oxcache::client::CacheOps;
+use oxcache::config::{L2Config, TwoLevelConfig};
+use oxcache::serialization::{JsonSerializer, SerializerEnum};
+use secrecy::SecretString;
+use std::sync::Arc;
+
+mod common;
+
+async fn create_backend(redis: &FakeRedis, enable_versioning: bool) -> Arc<L2Backend> {
+    let l2_config = L2Config {
+        connection_string: SecretString::from(redis.url.clone()),
+        enable_versioning,
+        ..Default::default()
+    };
+    Arc::new(L2Backend::new(&l2_config).await.unwrap())
+}
+
+/// 返回收到的所有命令名称
+fn commands(redis: &FakeRedis) -> Vec<String> {
+    redis
+        .log
+        .lock()
+        .unwrap()
+        .iter()
+        .map(|args| args[0].to_ascii_uppercase())
+        .collect()
+}
+
+fn mentions_version_key(redis: &FakeRedis) -> bool {
+    redis
+        .log
+        .lock()
+        .unwrap()
+        .iter()
+        .any(|args| args.iter().any(|arg| arg.ends_with(":version")))
+}
+
+#[tokio::test]
+async fn test_disabled_versioning_writes_no_version_keys() {
+    let redis = FakeRedis::start().await;
+    let l2 = create_backend(&redis, false).await;
+    let client = TwoLevelClient::new(
+        "versioning_off_test".to_string(),
+        TwoLevelConfig::default(),
+        Arc::new(L1Backend::new(1000)),
+        l2.clone(),
+        SerializerEnum::Json(JsonSerializer::new()),
+    )
+    .await
+    .unwrap();
+
+    client
+        .set_bytes("versioning_off_test:a", b"1".to_vec(), Some(60))
+        .await
+        .unwrap();
+    client
+        .set_bytes("versioning_off_test:b", b"2".to_vec(), Some(0))
+        .await
+        .unwrap();
+    client.delete("versioning_off_test:a").await.unwrap();
+    assert_eq!(l2.get_bytes("versioning_off_test:c").await.unwrap(), None);
+    l2.pipeline_set_batch(vec![(
+        "versioning_off_test:d".to_string(),
+        b"4".to_vec(),
+        None,
+    )])
+    .await
+    .unwrap();
+    l2.pipeline_del_batch(vec!["versioning_off_test:d".to_string()])
+        .await
+        .unwrap();
+
+    let commands = commands(&redis);
+    assert!(commands.contains(&"SET".to_string()));
+    assert!(commands.contains(&"DEL".to_string()));
+    assert!(!commands.iter().any(|c| c == "EVALSHA" || c == "EVAL"));
+    assert!(!mentions_version_key(&redis));
+
+    client.shutdown().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_enabled_versioning_maintains_version_keys() {
+    let redis = FakeRedis::start().await;
+    let l2 = create_backend(&redis, true).await;
+    assert!(l2.versioning_enabled());
+
+    l2.set_bytes("versioning_on_test:a", b"1".to_vec(), Some(60))
+        .await
+        .unwrap();
+    l2.delete("versioning_on_test:a").await.unwrap();
+
+    assert!(commands(&redis).iter().any(|c| c == "EVALSHA"));
+    assert!(mentions_version_key(&redis));
+}
@@ -0,0 +1,115 @@
+//! Copyright (c) 2025-2026, Kirky.X
+//!
+//! MIT License
+//!
+//! 手动触发WAL重放测试
+
+use common::fake_redis::FakeRedis;
+use oxcache::backend::l1::L1Backend;
+use oxcache::backend::l2::L2Backend;
+use oxcache::client::two_level::TwoLevelClient;
+use oxcache::client::CacheOps;
+use oxcache::config::{L2Config, TwoLevelConfig};
+use oxcache::error::CacheError;
+use oxcache::recovery::health::HealthState;
+use oxcache::serialization::{JsonSerializer, SerializerEnum};
+use secrecy::SecretString;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+mod common;
+
+#[tokio::test]
+async fn test_replay_wal_now_flushes_pending_writes_to_l2() {
+    let fake = FakeRedis::start().await;
+    let l2_config = L2Config {
+        connection_string: SecretString::from(fake.url.clone()),
+        ..Default::default()
+    };
+    let client = TwoLevelClient::new(
+        "wal_replay_now_test".to_string(),
+        TwoLevelConfig::default(),
+        Arc::new(L1Backend::new(100)),
+        Arc::new(L2Backend::new(&l2_config).await.unwrap()),
+        SerializerEnum::Json(JsonSerializer::new()),
+    )
+    .await
+    .unwrap();
+
+    client
+        .set_health_state(HealthState::Degraded {
+            since: Instant::now(),
+            failure_count: 3,
+        })
+        .await;
+    client
+        .set_bytes("wal_replay_now_test:key", b"1".to_vec(), Some(60))
+        .await
+        .unwrap();
+    assert!(!fake.touched("wal_replay_now_test:key"));
+
+    // 降级期间拒绝重放
+    assert!(matches!(
+        client.replay_wal_now().await,
+        Err(CacheError::L2Error(_))
+    ));
+
+    client.set_health_state(HealthState::Healthy).await;
+
+    // 并发调用只会重放一次
+    let (first, second) = tokio::join!(client.replay_wal_now(), client.replay_wal_now());
+    let (first, second) = (first.unwrap(), second.unwrap());
+    assert_eq!(first.replayed + second.replayed, 1);
+    assert_eq!(first.failed + second.failed, 0);
+    assert!(fake.touched("wal_replay_now_test:key"));
+
+    client.shutdown().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_millisecond_ttl_write_goes_to_wal_when_degraded() {
+    let fake = FakeRedis::start().await;
+    let l2_config = L2Config {
+        connection_string: SecretString::from(fake.url.clone()),
+        ..Default::default()
+    };
+    let client = TwoLevelClient::new(
+        "wal_replay_ms_test".to_string(),
+        TwoLevelConfig::default(),
+        Arc::new(L1Backend::new(100)),
+        Arc::new(L2Backend::new(&l2_config).await.unwrap()),
+        SerializerEnum::Json(JsonSerializer::new()),
+    )
+    .await
+    .unwrap();
+
+    assert!(matches!(
+        client
+            .set_bytes_ms("wal_replay_ms_test:key", b"1".to_vec(), Duration::ZERO)
+            .await,
+        Err(CacheError::InvalidInput(_))
+    ));
+
+    client
+        .set_health_state(HealthState::Degraded {
+            since: Instant::now(),
+            failure_count: 3,
+        })
+        .await;
+    client
+        .set_bytes_ms(
+            "wal_replay_ms_test:key",
+            b"1".to_vec(),
+            Duration::from_millis(1500),
+        )
+        .await
+        .unwrap();
+    assert!(!fake.touched("wal_replay_ms_test:key"));
+
+    client.set_health_state(HealthState::Healthy).await;
+    let report = client.replay_wal_now().await.unwrap();
+    assert_eq!(report.replayed, 1);
+    assert!(fake.touched("wal_replay_ms_test:key"));
+
+    client.shutdown().await.unwrap();
+}
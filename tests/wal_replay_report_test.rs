@@ -0,0 +1,199 @@
+//! Copyright (c) 2025-2026, Kirky.X
+//!
+//! MIT License
+//!
+//! WAL重放结果统计测试
+
+use oxcache::error::{CacheError, Result};
+use oxcache::metrics::GLOBAL_METRICS;
+use oxcache::recovery::wal::{
+    Operation, WalEntry, WalManager, WalReplayReport, WalReplayableBackend,
+};
+use std::sync::{Arc, Mutex};
+use std::time::SystemTime;
+
+/// 拒绝包含 `bad` 的键的后端，记录成功重放的键
+#[derive(Clone, Default)]
+struct PickyBackend {
+    applied: Arc<Mutex<Vec<String>>>,
+}
+
+impl WalReplayableBackend for PickyBackend {
+    async fn pipeline_replay(&self, entries: Vec<WalEntry>) -> Result<()> {
+        if entries.iter().any(|entry| entry.key.contains("bad")) {
+            return Err(CacheError::BackendError("rejected entry".to_string()));
+        }
+        self.applied
+            .lock()
+            .unwrap()
+            .extend(entries.into_iter().map(|entry| entry.key));
+        Ok(())
+    }
+}
+
+fn entry(key: &str, operation: Operation, value: Option<&[u8]>, ttl: Option<i64>) -> WalEntry {
+    WalEntry {
+        timestamp: SystemTime::now(),
+        operation,
+        key: key.to_string(),
+        value: value.map(<[u8]>::to_vec),
+        ttl,
+    }
+}
+
+#[tokio::test]
+async fn test_replay_report_counts_mixed_entries() {
+    let service = "wal_replay_report_test";
+    let wal = WalManager::new(service).await.unwrap();
+    wal.clear().await.unwrap();
+    assert_eq!(wal.last_replay(), None);
+
+    let entries = [
+        entry("good:1", Operation::Set, Some(b"v1"), Some(60)),
+        entry("bad:1", Operation::Set, Some(b"v2"), Some(60)),
+        entry("good:2", Operation::Set, Some(b"v3"), None),
+        entry("missing_value", Operation::Set, None, Some(60)),
+        entry("good:3", Operation::Delete, None, None),
+        entry("negative_ttl", Operation::Set, Some(b"v4"), Some(-5)),
+        entry("bad:2", Operation::Delete, None, None),
+        entry("good:4", Operation::Set, Some(b"v5"), Some(0)),
+    ];
+    for entry in &entries {
+        wal.append(entry.clone()).await.unwrap();
+    }
+    wal.flush().await.unwrap();
+
+    let backend = PickyBackend::default();
+    let report = wal.replay_all(&backend).await.unwrap();
+    assert_eq!(
+        report,
+        WalReplayReport {
+            replayed: 4,
+            failed: 2,
+            skipped: 2,
+        }
+    );
+    assert_eq!(wal.last_replay(), Some(report));
+
+    // 单条失败不影响后续条目
+    let mut applied = backend.applied.lock().unwrap().clone();
+    applied.sort();
+    assert_eq!(applied, ["good:1", "good:2", "good:3", "good:4"]);
+
+    // 只有失败的条目留在WAL中
+    let mut remaining: Vec<String> = wal
+        .get_entries()
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|entry| entry.key)
+        .collect();
+    remaining.sort();
+    assert_eq!(remaining, ["bad:1", "bad:2"]);
+
+    let metrics = &GLOBAL_METRICS.wal_replay_entries_total;
+    assert_eq!(
+        metrics.get(&format!("{}:replayed", service)).map(|v| *v),
+        Some(4)
+    );
+    assert_eq!(
+        metrics.get(&format!("{}:failed", service)).map(|v| *v),
+        Some(2)
+    );
+    assert_eq!(
+        metrics.get(&format!("{}:skipped", service)).map(|v| *v),
+        Some(2)
+    );
+
+    // 后端恢复后，保留的条目在下一次重放中完成
+    #[derive(Clone)]
+    struct AcceptingBackend;
+    impl WalReplayableBackend for AcceptingBackend {
+        async fn pipeline_replay(&self, _entries: Vec<WalEntry>) -> Result<()> {
+            Ok(())
+        }
+    }
+    let report = wal.replay_all(&AcceptingBackend).await.unwrap();
+    assert_eq!(
+        report,
+        WalReplayReport {
+            replayed: 2,
+            failed: 0,
+            skipped: 0,
+        }
+    );
+    assert!(wal.get_entries().await.unwrap().is_empty());
+}
+
+/// 拒绝值为 `poison` 的条目的后端，按顺序记录成功重放的操作
+#[derive(Clone, Default)]
+struct PoisonBackend {
+    applied: Arc<Mutex<Vec<(String, Operation)>>>,
+}
+
+impl WalReplayableBackend for PoisonBackend {
+    async fn pipeline_replay(&self, entries: Vec<WalEntry>) -> Result<()> {
+        if entries
+            .iter()
+            .any(|entry| entry.value.as_deref() == Some(b"poison"))
+        {
+            return Err(CacheError::BackendError("rejected entry".to_string()));
+        }
+        self.applied.lock().unwrap().extend(
+            entries
+                .into_iter()
+                .map(|entry| (entry.key, entry.operation)),
+        );
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn test_replay_retains_later_entries_for_failed_key() {
+    let wal = WalManager::new("wal_replay_order_test").await.unwrap();
+    wal.clear().await.unwrap();
+
+    let entries = [
+        entry("order:k", Operation::Set, Some(b"poison"), Some(60)),
+        entry("order:other", Operation::Set, Some(b"v"), Some(60)),
+        entry("order:k", Operation::Delete, None, None),
+        entry("order:k", Operation::Set, Some(b"newer"), Some(60)),
+    ];
+    for entry in &entries {
+        wal.append(entry.clone()).await.unwrap();
+    }
+    wal.flush().await.unwrap();
+
+    let backend = PoisonBackend::default();
+    let report = wal.replay_all(&backend).await.unwrap();
+    assert_eq!(
+        report,
+        WalReplayReport {
+            replayed: 1,
+            failed: 3,
+            skipped: 0,
+        }
+    );
+
+    // 失败键之后的条目不被应用，其他键不受影响
+    let applied = backend.applied.lock().unwrap().clone();
+    assert_eq!(applied.len(), 1);
+    assert_eq!(applied[0].0, "order:other");
+
+    // 失败键的所有条目按原顺序保留
+    let remaining: Vec<(String, Option<Vec<u8>>)> = wal
+        .get_entries()
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|entry| (entry.key, entry.value))
+        .collect();
+    assert_eq!(
+        remaining,
+        [
+            ("order:k".to_string(), Some(b"poison".to_vec())),
+            ("order:k".to_string(), None),
+            ("order:k".to_string(), Some(b"newer".to_vec())),
+        ]
+    );
+}
@@ -0,0 +1,96 @@
+//! Copyright (c) 2025-2026, Kirky.X
+//!
+//! MIT License
+//!
+//! 初始化时自动预热测试
+
+use common::client_test_utils::{self, fake_redis_l2};
+use common::fake_redis::FakeRedis;
+use oxcache::backend::l1::L1Backend;
+use oxcache::client::two_level::TwoLevelClient;
+use oxcache::config::{CacheWarmupConfig, TwoLevelConfig, WarmupDataSource};
+use oxcache::sync::warmup::{WarmupStatus, WARMUP_STATUS_ALL};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+mod common;
+
+async fn create_client(warmup: CacheWarmupConfig, l1: Arc<L1Backend>) -> TwoLevelClient {
+    client_test_utils::create_client(
+        "warmup_init_test",
+        TwoLevelConfig {
+            warmup: Some(warmup),
+            ..Default::default()
+        },
+        l1,
+        fake_redis_l2(&FakeRedis::start().await).await,
+    )
+    .await
+}
+
+fn warmup_config(warmup_on_init: bool) -> CacheWarmupConfig {
+    CacheWarmupConfig {
+        enabled: true,
+        batch_size: 1,
+        batch_interval_ms: 100,
+        data_sources: vec![WarmupDataSource::Static {
+            keys: (0..4).map(|i| format!("warmup_init_test:{}", i)).collect(),
+        }],
+        warmup_on_init,
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn test_warmup_runs_in_background_after_init() {
+    // 前两个键已在L1中，预热时命中；其余键在L2中不存在
+    let l1 = Arc::new(L1Backend::new(1000));
+    for i in 0..2 {
+        l1.set_bytes(&format!("warmup_init_test:{}", i), b"v".to_vec(), None)
+            .await
+            .unwrap();
+    }
+
+    let start = Instant::now();
+    let client = create_client(warmup_config(true), l1).await;
+    // 4个批次各间隔100ms，创建客户端不会等待预热完成
+    assert!(start.elapsed() < Duration::from_millis(400));
+
+    let mgr = client.warmup_manager().unwrap().clone();
+    assert!(!matches!(
+        mgr.get_status(WARMUP_STATUS_ALL).await,
+        WarmupStatus::Completed { .. }
+    ));
+
+    let deadline = Instant::now() + Duration::from_secs(5);
+    let status = loop {
+        let status = mgr.get_status(WARMUP_STATUS_ALL).await;
+        if matches!(status, WarmupStatus::Completed { .. }) || Instant::now() > deadline {
+            break status;
+        }
+        tokio::time::sleep(Duration::from_millis(20)).await;
+    };
+    assert_eq!(
+        status,
+        WarmupStatus::Completed {
+            loaded: 2,
+            failed: 2
+        }
+    );
+
+    client.shutdown().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_warmup_not_started_without_flag() {
+    let client = create_client(warmup_config(false), Arc::new(L1Backend::new(1000))).await;
+
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    let mgr = client.warmup_manager().unwrap();
+    assert_eq!(
+        mgr.get_status(WARMUP_STATUS_ALL).await,
+        WarmupStatus::Pending
+    );
+
+    client.shutdown().await.unwrap();
+}
@@ -0,0 +1,139 @@
+//! Copyright (c) 2025-2026, Kirky.X
+//!
+//! MIT License
+//!
+//! 预热结果按键报告失败测试
+
+use oxcache::backend::l1::L1Backend;
+use oxcache::backend::l2::L2Backend;
+use oxcache::client::two_level::TwoLevelClient;
+use oxcache::config::{CacheWarmupConfig, L2Config, WarmupDataSource};
+use oxcache::error::CacheError;
+use oxcache::serialization::{JsonSerializer, SerializerEnum};
+use oxcache::sync::warmup::MAX_WARMUP_FAILURES;
+use oxcache::utils::clock::MockClock;
+use oxcache::{WarmupBatch, WarmupManager};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+fn warmup_config(keys: Vec<String>, batch_size: usize) -> CacheWarmupConfig {
+    CacheWarmupConfig {
+        enabled: true,
+        batch_size,
+        batch_interval_ms: 0,
+        data_sources: vec![
+            WarmupDataSource::Static { keys },
+            WarmupDataSource::Database {
+                query: "SELECT id FROM users".to_string(),
+                key_field: "id".to_string(),
+                value_field: "profile".to_string(),
+            },
+        ],
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn test_warmup_reports_failed_keys() {
+    let keys = ["user:1", "bad:2", "user:3", "missing:4"]
+        .map(String::from)
+        .to_vec();
+    let manager = WarmupManager::new("warmup_report_test".to_string(), warmup_config(keys, 2));
+
+    let result = manager
+        .run_warmup(|keys: Vec<String>| async move {
+            let mut batch = WarmupBatch::default();
+            for key in keys {
+                if key.starts_with("user:") {
+                    batch.loaded.insert(key, b"v".to_vec());
+                } else if key.starts_with("bad:") {
+                    batch.failures.insert(key, "row is corrupted".to_string());
+                }
+            }
+            Ok(batch)
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(result.loaded, 2);
+    assert_eq!(result.failed, 2);
+    assert!(!result.success);
+    assert_eq!(
+        result.failures,
+        vec![
+            ("bad:2".to_string(), "row is corrupted".to_string()),
+            (
+                "missing:4".to_string(),
+                "not returned by loader".to_string()
+            ),
+        ]
+    );
+    assert_eq!(result.skipped, 1);
+    assert_eq!(result.skipped_sources, vec!["database".to_string()]);
+}
+
+#[tokio::test]
+async fn test_warmup_failure_list_is_capped() {
+    let total = MAX_WARMUP_FAILURES + 50;
+    let keys = (0..total).map(|i| format!("key:{}", i)).collect();
+    let manager = WarmupManager::new(
+        "warmup_report_cap_test".to_string(),
+        warmup_config(keys, 10),
+    );
+
+    // 整批加载失败时，批内每个键都记录同一错误
+    let result = manager
+        .run_warmup(|_keys: Vec<String>| async move {
+            Err::<HashMap<String, Vec<u8>>, _>(CacheError::BackendError(
+                "database unavailable".to_string(),
+            ))
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(result.failed, total);
+    assert_eq!(result.failures.len(), MAX_WARMUP_FAILURES);
+    assert_eq!(result.failures[0].0, "key:0");
+    assert!(result.failures[0].1.contains("database unavailable"));
+}
+
+#[tokio::test]
+async fn test_client_warmup_reports_keys_missing_from_loader() {
+    let client = TwoLevelClient::new(
+        "warmup_report_client_test".to_string(),
+        Default::default(),
+        Arc::new(L1Backend::new(100)),
+        Arc::new(L2Backend::in_memory(
+            &L2Config::default(),
+            Arc::new(MockClock::new()),
+        )),
+        SerializerEnum::Json(JsonSerializer::new()),
+    )
+    .await
+    .unwrap();
+
+    let keys = vec!["warm:1".to_string(), "warm:2".to_string()];
+    let result = client
+        .warmup(
+            keys,
+            |keys: Vec<String>| async move {
+                Ok::<_, CacheError>(
+                    keys.into_iter()
+                        .filter(|key| key != "warm:2")
+                        .map(|key| (key, "value".to_string()))
+                        .collect::<Vec<_>>(),
+                )
+            },
+            Some(60),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(result.loaded, 1);
+    assert_eq!(
+        result.failures,
+        vec![("warm:2".to_string(), "not returned by loader".to_string())]
+    );
+    let cached: Option<String> = client.get("warm:1").await.unwrap();
+    assert_eq!(cached, Some("value".to_string()));
+}
@@ -0,0 +1,70 @@
+//! Copyright (c) 2025-2026, Kirky.X
+//!
+//! MIT License
+//!
+//! 写入大小限制测试
+
+use common::client_test_utils::{self, fake_redis_l2};
+use common::fake_redis::FakeRedis;
+use oxcache::backend::l1::L1Backend;
+use oxcache::client::two_level::TwoLevelClient;
+use oxcache::client::CacheOps;
+use oxcache::config::TwoLevelConfig;
+use oxcache::error::CacheError;
+use std::sync::Arc;
+
+mod common;
+
+async fn create_client(service_name: &str, l1: Arc<L1Backend>) -> (TwoLevelClient, FakeRedis) {
+    let redis = FakeRedis::start().await;
+    let client = client_test_utils::create_client(
+        service_name,
+        TwoLevelConfig {
+            max_key_length: Some(32),
+            max_value_size: Some(16),
+            ..Default::default()
+        },
+        l1,
+        fake_redis_l2(&redis).await,
+    )
+    .await;
+    (client, redis)
+}
+
+#[tokio::test]
+async fn test_oversized_value_rejected_before_backend_write() {
+    let l1 = Arc::new(L1Backend::new(1000));
+    let (client, redis) = create_client("write_limits_test_value", l1.clone()).await;
+
+    let key = "write_limits_test:big";
+    let result = client.set_bytes(key, vec![0u8; 17], Some(60)).await;
+    assert!(matches!(result, Err(CacheError::InvalidInput(_))));
+
+    assert!(!redis.touched(key));
+    assert_eq!(l1.get_bytes(key).await.unwrap(), None);
+
+    // 限制范围内的写入正常到达L2
+    let key = "write_limits_test:ok";
+    client
+        .set_bytes(key, vec![0u8; 16], Some(60))
+        .await
+        .unwrap();
+    assert!(redis.touched(key));
+
+    client.shutdown().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_overlong_key_rejected_before_backend_write() {
+    let l1 = Arc::new(L1Backend::new(1000));
+    let (client, redis) = create_client("write_limits_test_key", l1.clone()).await;
+
+    let key = format!("write_limits_test:{}", "k".repeat(32));
+    let result = client.set_bytes(&key, b"v".to_vec(), Some(60)).await;
+    assert!(matches!(result, Err(CacheError::InvalidInput(_))));
+
+    assert!(!redis.touched(&key));
+    assert_eq!(l1.get_bytes(&key).await.unwrap(), None);
+
+    client.shutdown().await.unwrap();
+}